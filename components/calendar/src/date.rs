@@ -125,6 +125,13 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().day_of_year_info(&self.inner)
     }
 
+    /// The Julian day number of `self`, counting days from January 1, 4713 BCE in the proleptic
+    /// Julian calendar. Unlike an astronomical Julian day, the day begins at local midnight.
+    #[inline]
+    pub fn julian_day(&self) -> i64 {
+        Iso::julian_day(*self.to_iso().inner())
+    }
+
     /// Construct a date from raw values for a given calendar. This does not check any
     /// invariants for the date and calendar, and should only be called by calendar implementations.
     ///
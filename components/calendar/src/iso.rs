@@ -363,6 +363,17 @@ impl Iso {
 
         prev_month_days + date.day.0 as u32
     }
+
+    /// The Julian day number of the date, as described in [`Date::julian_day()`].
+    pub(crate) fn julian_day(date: IsoDateInner) -> i64 {
+        // The Julian day number of December 31, 1 BCE (ISO year 0)
+        const JULIAN_DAY_OF_YEAR_0_END: i64 = 1_721_425;
+        let prev_year = date.year.0 as i64 - 1;
+        let days_before_year = 365 * prev_year + prev_year.div_euclid(4)
+            - prev_year.div_euclid(100)
+            + prev_year.div_euclid(400);
+        JULIAN_DAY_OF_YEAR_0_END + days_before_year + Self::day_of_year(date) as i64
+    }
 }
 
 #[cfg(test)]
@@ -423,6 +434,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_julian_day() {
+        // January 1, 2000 was Julian day 2451545
+        assert_eq!(
+            Date::new_iso_date_from_integers(2000, 1, 1)
+                .unwrap()
+                .julian_day(),
+            2_451_545,
+        );
+        // November 17, 1858 was modified Julian day 0
+        assert_eq!(
+            Date::new_iso_date_from_integers(1858, 11, 17)
+                .unwrap()
+                .julian_day(),
+            2_400_001,
+        );
+        // Julian day 0 was November 24, 4714 BCE (ISO year -4713) in the proleptic Gregorian calendar
+        assert_eq!(
+            Date::new_iso_date_from_integers(-4713, 11, 24)
+                .unwrap()
+                .julian_day(),
+            0,
+        );
+        assert_eq!(
+            Date::new_iso_date_from_integers(0, 1, 1)
+                .unwrap()
+                .julian_day(),
+            1_721_060,
+        );
+    }
+
     fn simple_subtract(a: &Date<Iso>, b: &Date<Iso>) -> DateDuration<Iso> {
         let a = a.inner();
         let b = b.inner();
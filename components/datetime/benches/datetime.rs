@@ -90,6 +90,34 @@ fn datetime_benches(c: &mut Criterion) {
         let mut group = c.benchmark_group("datetime");
 
         let fxs = fixtures::get_fixture("lengths").unwrap();
+        // Measures loading the patterns and symbols, which are borrowed from the static blob.
+        group.bench_function("DateTimeFormat/try_new", |b| {
+            b.iter(|| {
+                for fx in &fxs.0 {
+                    for setup in &fx.setups {
+                        let locale: Locale = setup.locale.parse().unwrap();
+                        let options = fixtures::get_options(&setup.options);
+                        let _ = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+                    }
+                }
+            })
+        });
+
+        let components_fxs = fixtures::get_fixture("components").unwrap();
+        // Measures matching the skeletons of components bags, which should borrow the matched
+        // patterns instead of cloning them.
+        group.bench_function("DateTimeFormat/try_new/components", |b| {
+            b.iter(|| {
+                for fx in &components_fxs.0 {
+                    for setup in &fx.setups {
+                        let locale: Locale = setup.locale.parse().unwrap();
+                        let options = fixtures::get_options(&setup.options);
+                        let _ = DateTimeFormat::try_new(locale, &provider, &options).unwrap();
+                    }
+                }
+            })
+        });
+
         group.bench_function("DateTimeFormat/format_to_write", |b| {
             b.iter(|| {
                 for fx in &fxs.0 {
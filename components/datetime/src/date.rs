@@ -5,9 +5,12 @@
 //! A collection of utilities for representing and working with dates as an input to
 //! formatting operations.
 
+use crate::options::preferences;
+use alloc::string::ToString;
 use icu_calendar::{arithmetic::week_of, AsCalendar, Date, DateTime, Gregorian};
+use icu_locid::extensions::unicode::Key;
 use icu_locid::Locale;
-use tinystr::TinyStr8;
+use tinystr::{tinystr4, TinyStr8};
 
 // TODO (Manishearth) fix up imports to directly import from icu_calendar
 pub use icu_calendar::types::*;
@@ -35,6 +38,9 @@ pub trait DateInput {
 
     /// Gets information on the position of the day within the year.
     fn day_of_year_info(&self) -> Option<DayOfYearInfo>;
+
+    /// Gets the Julian day number, counting days from local midnight.
+    fn julian_day(&self) -> Option<i64>;
 }
 
 /// Representation of a time of day according to ISO-8601 conventions. Always indexed from
@@ -159,13 +165,26 @@ fn week_of_year<T: DateInput>(
     Ok(WeekOfYear(u32::from(week.week)))
 }
 
+/// Returns the first day of the week used in week computations: the preference if set, or else
+/// the `-u-fw` keyword of the locale, or else Monday.
+pub(crate) fn resolve_first_weekday(locale: &Locale, preference: Option<IsoWeekday>) -> IsoWeekday {
+    let fw_key = Key::from_tinystr4_unchecked(tinystr4!("fw"));
+    preference
+        .or_else(|| {
+            let value = locale.extensions.unicode.keywords.get(fw_key)?;
+            preferences::first_weekday_from_bcp47(&value.to_string())
+        })
+        // TODO(#488): Use the first day of the week of the region.
+        .unwrap_or(IsoWeekday::Monday)
+}
+
 impl<'data, T: DateTimeInput> DateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
+    pub fn new(data: &'data T, first_weekday: IsoWeekday, _locale: &Locale) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
+            // TODO(#488): Use the minimal days in the first week of the region.
             calendar: week_of::CalendarInfo {
-                first_weekday: IsoWeekday::Monday,
+                first_weekday,
                 min_week_days: 4,
             },
         }
@@ -178,12 +197,12 @@ pub(crate) struct ZonedDateTimeInputWithLocale<'data, T: ZonedDateTimeInput> {
 }
 
 impl<'data, T: ZonedDateTimeInput> ZonedDateTimeInputWithLocale<'data, T> {
-    pub fn new(data: &'data T, _locale: &Locale) -> Self {
+    pub fn new(data: &'data T, first_weekday: IsoWeekday, _locale: &Locale) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
+            // TODO(#488): Use the minimal days in the first week of the region.
             calendar: week_of::CalendarInfo {
-                first_weekday: IsoWeekday::Monday,
+                first_weekday,
                 min_week_days: 4,
             },
         }
@@ -261,6 +280,11 @@ impl<A: AsCalendar<Calendar = Gregorian>> DateInput for Date<A> {
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
         Some(self.day_of_year_info())
     }
+
+    /// Gets the Julian day number, counting days from local midnight.
+    fn julian_day(&self) -> Option<i64> {
+        Some(self.julian_day())
+    }
 }
 
 impl<A: AsCalendar<Calendar = Gregorian>> DateInput for DateTime<A> {
@@ -288,6 +312,11 @@ impl<A: AsCalendar<Calendar = Gregorian>> DateInput for DateTime<A> {
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
         Some(self.date.day_of_year_info())
     }
+
+    /// Gets the Julian day number, counting days from local midnight.
+    fn julian_day(&self) -> Option<i64> {
+        Some(self.date.julian_day())
+    }
 }
 
 impl<A: AsCalendar<Calendar = Gregorian>> IsoTimeInput for DateTime<A> {
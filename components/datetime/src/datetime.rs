@@ -11,13 +11,14 @@ use crate::{
     provider::gregory::{DatePatternsV1Marker, DateSkeletonPatternsV1Marker, DateSymbolsV1Marker},
 };
 use alloc::string::String;
-use icu_locid::Locale;
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_plurals::{provider::PluralRuleStringsV1Marker, PluralRuleType, PluralRules};
 use icu_provider::prelude::*;
 
 use crate::{
-    date::DateTimeInput, pattern::reference::PatternPlurals, provider, DateTimeFormatError,
-    FormattedDateTime,
+    date::{self, DateInput, DateTimeInput, IsoWeekday},
+    pattern::reference::PatternPlurals,
+    provider, DateTimeFormatError, FormattedDateTime,
 };
 
 /// [`DateTimeFormat`] is the main structure of the [`icu_datetime`] component.
@@ -63,8 +64,11 @@ use crate::{
 pub struct DateTimeFormat<'data> {
     pub(super) locale: Locale,
     pub(super) patterns: PatternPlurals,
+    pub(super) era_patterns: Option<PatternPlurals>,
     pub(super) symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
     pub(super) ordinal_rules: Option<PluralRules>,
+    pub(super) first_weekday: IsoWeekday,
+    pub(super) resolved_locale: Option<LanguageIdentifier>,
 }
 
 impl<'data> DateTimeFormat<'data> {
@@ -102,15 +106,25 @@ impl<'data> DateTimeFormat<'data> {
     {
         let locale = locale.into();
 
-        let patterns = provider::date_time::patterns_for_options(data_provider, &locale, options)?
-            .unwrap_or_default();
+        let (patterns, resolved_locale) =
+            provider::date_time::patterns_for_options(data_provider, &locale, options)?;
+        let patterns = patterns.unwrap_or_default();
+        let era_patterns =
+            provider::date_time::era_patterns_for_options(data_provider, &locale, options)?;
 
-        let requires_data = datetime::analyze_patterns(&patterns, false)
-            .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        let requires_data = datetime::analyze_patterns(&patterns, false)?
+            || era_patterns
+                .as_ref()
+                .map(|era_patterns| datetime::analyze_patterns(era_patterns, false))
+                .transpose()?
+                .unwrap_or(false);
 
-        let langid: icu_locid::LanguageIdentifier = locale.clone().into();
+        let langid: LanguageIdentifier = locale.clone().into();
 
-        let ordinal_rules = if let PatternPlurals::MultipleVariants(_) = &patterns {
+        let ordinal_rules = if let (PatternPlurals::MultipleVariants(_), _)
+        | (_, Some(PatternPlurals::MultipleVariants(_))) =
+            (&patterns, &era_patterns)
+        {
             Some(PluralRules::try_new(
                 langid.clone(),
                 data_provider,
@@ -123,7 +137,7 @@ impl<'data> DateTimeFormat<'data> {
         let symbols_data = if requires_data {
             Some(
                 data_provider
-                    .load_payload(&DataRequest {
+                    .load_payload_with_context(&DataRequest {
                         resource_path: ResourcePath {
                             key: provider::key::GREGORY_DATE_SYMBOLS_V1,
                             options: ResourceOptions {
@@ -138,7 +152,20 @@ impl<'data> DateTimeFormat<'data> {
             None
         };
 
-        Ok(Self::new(locale, patterns, symbols_data, ordinal_rules))
+        let first_weekday = date::resolve_first_weekday(
+            &locale,
+            options.preferences().and_then(|prefs| prefs.first_weekday),
+        );
+
+        Ok(Self::new(
+            locale,
+            patterns,
+            era_patterns,
+            symbols_data,
+            ordinal_rules,
+            first_weekday,
+            resolved_locale,
+        ))
     }
 
     /// Creates a new [`DateTimeFormat`] regardless of whether there are time-zone symbols in the pattern.
@@ -157,16 +184,63 @@ impl<'data> DateTimeFormat<'data> {
     pub(super) fn new<T: Into<Locale>>(
         locale: T,
         patterns: PatternPlurals,
+        era_patterns: Option<PatternPlurals>,
         symbols: Option<DataPayload<'data, DateSymbolsV1Marker>>,
         ordinal_rules: Option<PluralRules>,
+        first_weekday: IsoWeekday,
+        resolved_locale: Option<LanguageIdentifier>,
     ) -> Self {
         let locale = locale.into();
 
         Self {
             locale,
             patterns,
+            era_patterns,
             symbols,
             ordinal_rules,
+            first_weekday,
+            resolved_locale,
+        }
+    }
+
+    /// Returns the locale of the patterns loaded by [`DateTimeFormat::try_new`], which differs
+    /// from the requested locale if the data provider fell back to a more general locale, such as
+    /// `en-001` for `en-IN`.
+    ///
+    /// Returns `None` if the data provider did not report the locale of its data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::{options::length, DateTimeFormat};
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu_provider::fallback::LocaleFallbackProvider;
+    ///
+    /// let provider = LocaleFallbackProvider::new(icu_testdata::get_provider());
+    /// let locale: Locale = langid!("en-IN").into();
+    /// let options = length::Bag {
+    ///     date: Some(length::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// assert_eq!(dtf.resolved_locale(), Some(&langid!("en-001")));
+    /// ```
+    pub fn resolved_locale(&self) -> Option<&LanguageIdentifier> {
+        self.resolved_locale.as_ref()
+    }
+
+    /// Returns the patterns to format the given value with, which include the era if the era
+    /// is displayed automatically and the year is ambiguous without it.
+    pub(super) fn patterns_for(&self, value: &impl DateInput) -> &PatternPlurals {
+        match &self.era_patterns {
+            Some(era_patterns) if matches!(value.year(), Some(year) if year.era.0.as_str() == "bce") => {
+                era_patterns
+            }
+            _ => &self.patterns,
         }
     }
 
@@ -203,10 +277,11 @@ impl<'data> DateTimeFormat<'data> {
         T: DateTimeInput,
     {
         FormattedDateTime {
-            patterns: &self.patterns,
+            patterns: self.patterns_for(value),
             symbols: self.symbols.as_ref().map(|s| s.get()),
             datetime: value,
             locale: &self.locale,
+            first_weekday: self.first_weekday,
             ordinal_rules: self.ordinal_rules.as_ref(),
         }
     }
@@ -243,11 +318,12 @@ impl<'data> DateTimeFormat<'data> {
         value: &impl DateTimeInput,
     ) -> core::fmt::Result {
         datetime::write_pattern_plurals(
-            &self.patterns,
+            self.patterns_for(value),
             self.symbols.as_ref().map(|s| s.get()),
             value,
             self.ordinal_rules.as_ref(),
             &self.locale,
+            self.first_weekday,
             w,
         )
         .map_err(|_| core::fmt::Error)
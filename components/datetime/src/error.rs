@@ -6,6 +6,8 @@ use crate::date::DateTimeError;
 use crate::fields::FieldSymbol;
 use crate::pattern::PatternError;
 use crate::skeleton::SkeletonError;
+use alloc::string::{String, ToString};
+use core::fmt;
 use displaydoc::Display;
 use icu_plurals::PluralRulesError;
 use icu_provider::prelude::DataError;
@@ -16,22 +18,40 @@ pub enum DateTimeFormatError {
     /// An error originating from parsing a pattern.
     #[displaydoc("{0}")]
     Pattern(PatternError),
+    /// An error originating from a field of a parsed pattern, such as a time zone field with a
+    /// length that cannot be formatted.
+    #[displaydoc("{source} in pattern '{pattern}'")]
+    InvalidPattern {
+        /// The error in the pattern.
+        source: PatternError,
+        /// The pattern in which the error was found.
+        pattern: String,
+    },
     /// An error originating from the [`Write`](std::fmt::Write) trait.
     #[displaydoc("{0}")]
-    Format(core::fmt::Error),
+    Format(fmt::Error),
     /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
     #[displaydoc("{0}")]
     DataProvider(DataError),
-    /// An error originating from a missing field in datetime input.
-    /// TODO: How can we return which field was missing?
-    #[displaydoc("Missing input field")]
-    MissingInputField,
+    /// An error originating from a missing field in datetime input, such as the hour of a
+    /// date-only input formatted with a time pattern.
+    #[displaydoc("Missing input field: {0}")]
+    MissingInputField(&'static str),
     /// An error originating from skeleton matching.
     #[displaydoc("{0}")]
     Skeleton(SkeletonError),
+    /// An error originating from a symbol missing from the data, such as a month name for a
+    /// month beyond the end of the list of month names.
+    #[displaydoc("Missing date symbol")]
+    MissingDateSymbol,
     /// An error originating from an unsupported field in a datetime format.
-    #[displaydoc("Unsupported field: {0:?}")]
-    UnsupportedField(FieldSymbol),
+    #[displaydoc("field {field:?} unsupported in pattern '{pattern}'")]
+    UnsupportedField {
+        /// The unsupported field.
+        field: FieldSymbol,
+        /// The pattern containing the field.
+        pattern: String,
+    },
     /// An error originating from [`PluralRules`][icu_plural::PluralRules].
     #[displaydoc("{0}")]
     PluralRules(PluralRulesError),
@@ -40,8 +60,38 @@ pub enum DateTimeFormatError {
     DateTimeInput(DateTimeError),
 }
 
+impl DateTimeFormatError {
+    pub(crate) fn invalid_pattern(source: PatternError, pattern: &impl fmt::Display) -> Self {
+        Self::InvalidPattern {
+            source,
+            pattern: pattern.to_string(),
+        }
+    }
+
+    pub(crate) fn unsupported_field(field: FieldSymbol, pattern: &impl fmt::Display) -> Self {
+        Self::UnsupportedField {
+            field,
+            pattern: pattern.to_string(),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
-impl std::error::Error for DateTimeFormatError {}
+impl std::error::Error for DateTimeFormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Pattern(e) | Self::InvalidPattern { source: e, .. } => Some(e),
+            Self::Format(e) => Some(e),
+            Self::DataProvider(e) => Some(e),
+            Self::Skeleton(e) => Some(e),
+            Self::PluralRules(e) => Some(e),
+            Self::DateTimeInput(e) => Some(e),
+            Self::MissingInputField(_)
+            | Self::MissingDateSymbol
+            | Self::UnsupportedField { .. } => None,
+        }
+    }
+}
 
 impl From<PatternError> for DateTimeFormatError {
     fn from(e: PatternError) -> Self {
@@ -55,8 +105,8 @@ impl From<DataError> for DateTimeFormatError {
     }
 }
 
-impl From<core::fmt::Error> for DateTimeFormatError {
-    fn from(e: core::fmt::Error) -> Self {
+impl From<fmt::Error> for DateTimeFormatError {
+    fn from(e: fmt::Error) -> Self {
         DateTimeFormatError::Format(e)
     }
 }
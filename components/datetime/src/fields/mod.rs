@@ -49,6 +49,7 @@ impl Field {
             FieldSymbol::Minute => TextOrNumeric::Numeric,
             FieldSymbol::Second(second) => second.get_length_type(self.length),
             FieldSymbol::TimeZone(zone) => zone.get_length_type(self.length),
+            FieldSymbol::Era => TextOrNumeric::Text,
         }
     }
 
@@ -37,6 +37,7 @@ pub enum FieldSymbol {
     Minute,
     Second(Second),
     TimeZone(TimeZone),
+    Era,
 }
 
 impl FieldSymbol {
@@ -89,6 +90,7 @@ impl FieldSymbol {
             7 => symbol == 0,
             8 => Second::idx_in_range(&symbol),
             9 => TimeZone::idx_in_range(&symbol),
+            10 => symbol == 0,
             _ => false,
         }
     }
@@ -106,6 +108,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => (7, 0),
             FieldSymbol::Second(second) => (8, second.idx()),
             FieldSymbol::TimeZone(tz) => (9, tz.idx()),
+            FieldSymbol::Era => (10, 0),
         };
         let result = high << 4;
         result | low
@@ -129,6 +132,7 @@ impl FieldSymbol {
             7 if low == 0 => Self::Minute,
             8 => Self::Second(Second::from_idx(low)?),
             9 => Self::TimeZone(TimeZone::from_idx(low)?),
+            10 if low == 0 => Self::Era,
             _ => return Err(SymbolError::InvalidIndex(idx)),
         })
     }
@@ -157,36 +161,37 @@ impl FieldSymbol {
     /// ordered most significant to least significant.
     fn get_canonical_order(&self) -> u8 {
         match self {
-            Self::Year(Year::Calendar) => 0,
-            Self::Year(Year::WeekOf) => 1,
-            Self::Month(Month::Format) => 2,
-            Self::Month(Month::StandAlone) => 3,
-            Self::Week(Week::WeekOfYear) => 4,
-            Self::Week(Week::WeekOfMonth) => 5,
-            Self::Day(Day::DayOfMonth) => 6,
-            Self::Day(Day::DayOfYear) => 7,
-            Self::Day(Day::DayOfWeekInMonth) => 8,
-            Self::Day(Day::ModifiedJulianDay) => 9,
-            Self::Weekday(Weekday::Format) => 10,
-            Self::Weekday(Weekday::Local) => 11,
-            Self::Weekday(Weekday::StandAlone) => 12,
-            Self::DayPeriod(DayPeriod::AmPm) => 13,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 14,
-            Self::Hour(Hour::H11) => 15,
-            Self::Hour(Hour::H12) => 16,
-            Self::Hour(Hour::H23) => 17,
-            Self::Hour(Hour::H24) => 18,
-            Self::Minute => 19,
-            Self::Second(Second::Second) => 20,
-            Self::Second(Second::FractionalSecond) => 21,
-            Self::Second(Second::Millisecond) => 22,
-            Self::TimeZone(TimeZone::LowerZ) => 23,
-            Self::TimeZone(TimeZone::UpperZ) => 24,
-            Self::TimeZone(TimeZone::UpperO) => 25,
-            Self::TimeZone(TimeZone::LowerV) => 26,
-            Self::TimeZone(TimeZone::UpperV) => 27,
-            Self::TimeZone(TimeZone::LowerX) => 28,
-            Self::TimeZone(TimeZone::UpperX) => 29,
+            Self::Era => 0,
+            Self::Year(Year::Calendar) => 1,
+            Self::Year(Year::WeekOf) => 2,
+            Self::Month(Month::Format) => 3,
+            Self::Month(Month::StandAlone) => 4,
+            Self::Week(Week::WeekOfYear) => 5,
+            Self::Week(Week::WeekOfMonth) => 6,
+            Self::Day(Day::DayOfMonth) => 7,
+            Self::Day(Day::DayOfYear) => 8,
+            Self::Day(Day::DayOfWeekInMonth) => 9,
+            Self::Day(Day::ModifiedJulianDay) => 10,
+            Self::Weekday(Weekday::Format) => 11,
+            Self::Weekday(Weekday::Local) => 12,
+            Self::Weekday(Weekday::StandAlone) => 13,
+            Self::DayPeriod(DayPeriod::AmPm) => 14,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
+            Self::Hour(Hour::H11) => 16,
+            Self::Hour(Hour::H12) => 17,
+            Self::Hour(Hour::H23) => 18,
+            Self::Hour(Hour::H24) => 19,
+            Self::Minute => 20,
+            Self::Second(Second::Second) => 21,
+            Self::Second(Second::FractionalSecond) => 22,
+            Self::Second(Second::Millisecond) => 23,
+            Self::TimeZone(TimeZone::LowerZ) => 24,
+            Self::TimeZone(TimeZone::UpperZ) => 25,
+            Self::TimeZone(TimeZone::UpperO) => 26,
+            Self::TimeZone(TimeZone::LowerV) => 27,
+            Self::TimeZone(TimeZone::UpperV) => 28,
+            Self::TimeZone(TimeZone::LowerX) => 29,
+            Self::TimeZone(TimeZone::UpperX) => 30,
         }
     }
 }
@@ -197,6 +202,9 @@ impl TryFrom<char> for FieldSymbol {
         if !ch.is_ascii_alphanumeric() {
             return Err(SymbolError::Invalid(ch as u8));
         }
+        if ch == 'G' {
+            return Ok(Self::Era);
+        }
         Year::try_from(ch)
             .map(Self::Year)
             .or_else(|_| Month::try_from(ch).map(Self::Month))
@@ -237,6 +245,7 @@ impl From<FieldSymbol> for char {
             FieldSymbol::Minute => 'm',
             FieldSymbol::Second(second) => second.into(),
             FieldSymbol::TimeZone(time_zone) => time_zone.into(),
+            FieldSymbol::Era => 'G',
         }
     }
 }
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::date::{DateTimeInput, DateTimeInputWithLocale, LocalizedDateTimeInput};
+use crate::date::{DateTimeInput, DateTimeInputWithLocale, IsoWeekday, LocalizedDateTimeInput};
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, Field, FieldLength, FieldSymbol, Week};
 use crate::pattern::{
@@ -16,7 +16,7 @@ use alloc::string::ToString;
 use core::fmt;
 use icu_locid::Locale;
 use icu_plurals::PluralRules;
-use writeable::Writeable;
+use writeable::{LengthHint, Writeable};
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`DateTimeFormat`](crate::DateTimeFormat).
@@ -51,9 +51,10 @@ where
     T: DateTimeInput,
 {
     pub(crate) patterns: &'l PatternPlurals,
-    pub(crate) symbols: Option<&'l provider::gregory::DateSymbolsV1>,
+    pub(crate) symbols: Option<&'l provider::gregory::DateSymbolsV1<'l>>,
     pub(crate) datetime: &'l T,
     pub(crate) locale: &'l Locale,
+    pub(crate) first_weekday: IsoWeekday,
     pub(crate) ordinal_rules: Option<&'l PluralRules>,
 }
 
@@ -68,12 +69,15 @@ where
             self.datetime,
             self.ordinal_rules,
             self.locale,
+            self.first_weekday,
             sink,
         )
         .map_err(|_| core::fmt::Error)
     }
 
-    // TODO(#489): Implement write_len
+    fn write_len(&self) -> LengthHint {
+        super::pattern_plurals_write_len(self.patterns)
+    }
 }
 
 impl<'l, T> fmt::Display for FormattedDateTime<'l, T>
@@ -130,13 +134,14 @@ pub fn write_pattern_plurals<T, W>(
     datetime: &T,
     ordinal_rules: Option<&PluralRules>,
     locale: &Locale,
+    first_weekday: IsoWeekday,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let loc_datetime = DateTimeInputWithLocale::new(datetime, locale);
+    let loc_datetime = DateTimeInputWithLocale::new(datetime, first_weekday, locale);
     let pattern = patterns.select(&loc_datetime, ordinal_rules)?;
     write_pattern(pattern, symbols, &loc_datetime, w)
 }
@@ -158,12 +163,25 @@ where
     W: fmt::Write + ?Sized,
 {
     match field.symbol {
+        FieldSymbol::Era => {
+            let symbol = symbols
+                .expect("Expect symbols to be present")
+                .get_symbol_for_era(
+                    field.length,
+                    &datetime
+                        .datetime()
+                        .year()
+                        .ok_or(Error::MissingInputField("DateTimeInput::year"))?
+                        .era,
+                )?;
+            w.write_str(symbol)?
+        }
         FieldSymbol::Year(..) => format_number(
             w,
             datetime
                 .datetime()
                 .year()
-                .ok_or(Error::MissingInputField)?
+                .ok_or(Error::MissingInputField("DateTimeInput::year"))?
                 .number as isize,
             field.length,
         )?,
@@ -173,7 +191,7 @@ where
                 datetime
                     .datetime()
                     .month()
-                    .ok_or(Error::MissingInputField)?
+                    .ok_or(Error::MissingInputField("DateTimeInput::month"))?
                     .number as isize,
                 field.length,
             )?,
@@ -186,39 +204,73 @@ where
                         datetime
                             .datetime()
                             .month()
-                            .ok_or(Error::MissingInputField)?
+                            .ok_or(Error::MissingInputField("DateTimeInput::month"))?
                             .number as usize
                             - 1,
-                    );
+                    )?;
                 w.write_str(symbol)?
             }
         },
         FieldSymbol::Week(Week::WeekOfYear) => {
             format_number(w, datetime.week_of_year()?.0 as isize, field.length)?
         }
-        field @ FieldSymbol::Week(_) => return Err(Error::UnsupportedField(field)),
+        field @ FieldSymbol::Week(_) => return Err(Error::unsupported_field(field, pattern)),
         FieldSymbol::Weekday(weekday) => {
             let dow = datetime
                 .datetime()
                 .iso_weekday()
-                .ok_or(Error::MissingInputField)?;
+                .ok_or(Error::MissingInputField("DateTimeInput::iso_weekday"))?;
             let symbol = symbols
                 .expect("Expect symbols to be present")
-                .get_symbol_for_weekday(weekday, field.length, dow);
+                .get_symbol_for_weekday(weekday, field.length, dow)?;
             w.write_str(symbol)?
         }
-        FieldSymbol::Day(..) => format_number(
+        FieldSymbol::Day(fields::Day::DayOfMonth) => format_number(
             w,
             datetime
                 .datetime()
                 .day_of_month()
-                .ok_or(Error::MissingInputField)?
+                .ok_or(Error::MissingInputField("DateTimeInput::day_of_month"))?
                 .0 as isize,
             field.length,
         )?,
+        FieldSymbol::Day(fields::Day::DayOfYear) => format_number(
+            w,
+            datetime
+                .datetime()
+                .day_of_year_info()
+                .ok_or(Error::MissingInputField("DateTimeInput::day_of_year_info"))?
+                .day_of_year as isize,
+            field.length,
+        )?,
+        FieldSymbol::Day(fields::Day::DayOfWeekInMonth) => format_number(
+            w,
+            // The first seven days of the month are the first occurrence of their weekday.
+            (datetime
+                .datetime()
+                .day_of_month()
+                .ok_or(Error::MissingInputField("DateTimeInput::day_of_month"))?
+                .0 as isize
+                - 1)
+                / 7
+                + 1,
+            field.length,
+        )?,
+        FieldSymbol::Day(fields::Day::ModifiedJulianDay) => format_number(
+            w,
+            datetime
+                .datetime()
+                .julian_day()
+                .ok_or(Error::MissingInputField("DateTimeInput::julian_day"))? as isize,
+            field.length,
+        )?,
         FieldSymbol::Hour(hour) => {
-            let h =
-                usize::from(datetime.datetime().hour().ok_or(Error::MissingInputField)?) as isize;
+            let h = usize::from(
+                datetime
+                    .datetime()
+                    .hour()
+                    .ok_or(Error::MissingInputField("DateTimeInput::hour"))?,
+            ) as isize;
             let value = match hour {
                 fields::Hour::H11 => h % 12,
                 fields::Hour::H12 => {
@@ -246,7 +298,7 @@ where
                 datetime
                     .datetime()
                     .minute()
-                    .ok_or(Error::MissingInputField)?,
+                    .ok_or(Error::MissingInputField("DateTimeInput::minute"))?,
             ) as isize,
             field.length,
         )?,
@@ -256,7 +308,7 @@ where
                 datetime
                     .datetime()
                     .second()
-                    .ok_or(Error::MissingInputField)?,
+                    .ok_or(Error::MissingInputField("DateTimeInput::second"))?,
             ) as isize,
             field.length,
         )?,
@@ -266,15 +318,18 @@ where
                 .get_symbol_for_day_period(
                     period,
                     field.length,
-                    datetime.datetime().hour().ok_or(Error::MissingInputField)?,
+                    datetime
+                        .datetime()
+                        .hour()
+                        .ok_or(Error::MissingInputField("DateTimeInput::hour"))?,
                     pattern.time_granularity.is_top_of_hour(
                         datetime.datetime().minute().map(u8::from).unwrap_or(0),
                         datetime.datetime().second().map(u8::from).unwrap_or(0),
                     ),
-                );
+                )?;
             w.write_str(symbol)?
         }
-        field @ FieldSymbol::TimeZone(_) => return Err(Error::UnsupportedField(field)),
+        field @ FieldSymbol::TimeZone(_) => return Err(Error::unsupported_field(field, pattern)),
     };
     Ok(())
 }
@@ -295,7 +350,7 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
                 FieldSymbol::Month(_) => {
                     !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                 }
-                FieldSymbol::Weekday(_) | FieldSymbol::DayPeriod(_) => true,
+                FieldSymbol::Era | FieldSymbol::Weekday(_) | FieldSymbol::DayPeriod(_) => true,
                 _ => false,
             }
         }
@@ -320,9 +375,11 @@ pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<b
 pub fn analyze_patterns(
     patterns: &PatternPlurals,
     supports_time_zones: bool,
-) -> Result<bool, &Field> {
+) -> Result<bool, Error> {
     patterns.patterns_iter().try_fold(false, |a, pattern| {
-        analyze_pattern(pattern, supports_time_zones).map(|b| a || b)
+        analyze_pattern(pattern, supports_time_zones)
+            .map(|b| a || b)
+            .map_err(|field| Error::unsupported_field(field.symbol, pattern))
     })
 }
 
@@ -339,7 +396,7 @@ mod tests {
 
         let provider = icu_testdata::get_provider();
         let data: DataPayload<DateSymbolsV1Marker> = provider
-            .load_payload(&DataRequest {
+            .load_payload_with_context(&DataRequest {
                 resource_path: ResourcePath {
                     key: provider::key::GREGORY_DATE_SYMBOLS_V1,
                     options: ResourceOptions {
@@ -355,11 +412,68 @@ mod tests {
         let datetime =
             DateTime::new_gregorian_datetime_from_integers(2020, 8, 1, 12, 34, 28).unwrap();
         let mut sink = String::new();
-        let loc_datetime = DateTimeInputWithLocale::new(&datetime, &"und".parse().unwrap());
+        let loc_datetime =
+            DateTimeInputWithLocale::new(&datetime, IsoWeekday::Monday, &"und".parse().unwrap());
         write_pattern(&pattern, Some(data.get()), &loc_datetime, &mut sink).unwrap();
         println!("{}", sink);
     }
 
+    #[test]
+    fn test_numeric_day_fields() {
+        use icu_calendar::DateTime;
+
+        let datetime =
+            DateTime::new_gregorian_datetime_from_integers(2020, 8, 15, 12, 34, 28).unwrap();
+        let loc_datetime =
+            DateTimeInputWithLocale::new(&datetime, IsoWeekday::Monday, &"und".parse().unwrap());
+        let samples = &[("D", "228"), ("DDDD", "0228"), ("F", "3"), ("g", "2459077")];
+        for (pattern, expected) in samples {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            let mut sink = String::new();
+            write_pattern(&pattern, None, &loc_datetime, &mut sink).unwrap();
+            assert_eq!(sink, *expected);
+        }
+    }
+
+    #[test]
+    fn test_first_weekday() {
+        use crate::date::resolve_first_weekday;
+        use icu_calendar::DateTime;
+
+        let en: Locale = "en".parse().unwrap();
+        let en_fw_sun: Locale = "en-u-fw-sun".parse().unwrap();
+        assert_eq!(resolve_first_weekday(&en, None), IsoWeekday::Monday);
+        assert_eq!(resolve_first_weekday(&en_fw_sun, None), IsoWeekday::Sunday);
+        assert_eq!(
+            resolve_first_weekday(&en_fw_sun, Some(IsoWeekday::Saturday)),
+            IsoWeekday::Saturday
+        );
+
+        // January 3, 2021 is a Sunday, which ends the last week of 2020 when weeks start on
+        // Monday, and starts the first week of 2021 when weeks start on Sunday.
+        let datetime = DateTime::new_gregorian_datetime_from_integers(2021, 1, 3, 0, 0, 0).unwrap();
+        let pattern = Pattern::from_bytes("w").unwrap();
+        for (first_weekday, expected) in &[(IsoWeekday::Monday, "53"), (IsoWeekday::Sunday, "1")] {
+            let loc_datetime = DateTimeInputWithLocale::new(&datetime, *first_weekday, &en);
+            let mut sink = String::new();
+            write_pattern(&pattern, None, &loc_datetime, &mut sink).unwrap();
+            assert_eq!(sink, *expected);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_field() {
+        let pattern = Pattern::from_bytes("HH:mm z").unwrap();
+        let err = analyze_patterns(&pattern.into(), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "field TimeZone(LowerZ) unsupported in pattern 'HH:mm z'"
+        );
+
+        let pattern = Pattern::from_bytes("MMM d").unwrap();
+        assert_eq!(analyze_patterns(&pattern.into(), false).ok(), Some(true));
+    }
+
     #[test]
     fn test_format_number() {
         let values = &[2, 20, 201, 2017, 20173];
@@ -5,3 +5,54 @@
 pub mod datetime;
 pub mod time_zone;
 pub mod zoned_datetime;
+
+use crate::fields::{FieldLength, FieldSymbol};
+use crate::pattern::{
+    reference::{Pattern, PatternPlurals},
+    PatternItem,
+};
+use writeable::LengthHint;
+
+/// Returns a lower bound on the length of `pattern` once formatted.
+///
+/// Literals and numeric fields are counted; fields formatted with symbols or time zone data
+/// are not, since they depend on the value being formatted.
+// TODO(#489): Compute the exact length from the selected symbols.
+pub(crate) fn pattern_write_len(pattern: &Pattern) -> usize {
+    pattern
+        .items()
+        .iter()
+        .map(|item| match item {
+            PatternItem::Literal(ch) => ch.len_utf8(),
+            PatternItem::Field(field) => match field.symbol {
+                FieldSymbol::Month(_)
+                    if !matches!(field.length, FieldLength::One | FieldLength::TwoDigit) =>
+                {
+                    0
+                }
+                FieldSymbol::Year(_)
+                | FieldSymbol::Month(_)
+                | FieldSymbol::Week(_)
+                | FieldSymbol::Day(_)
+                | FieldSymbol::Hour(_)
+                | FieldSymbol::Minute
+                | FieldSymbol::Second(_) => field.length as usize,
+                FieldSymbol::Era
+                | FieldSymbol::Weekday(_)
+                | FieldSymbol::DayPeriod(_)
+                | FieldSymbol::TimeZone(_) => 0,
+            },
+        })
+        .sum()
+}
+
+/// Returns a lower bound on the length of whichever of `patterns` is selected for formatting.
+pub(crate) fn pattern_plurals_write_len(patterns: &PatternPlurals) -> LengthHint {
+    LengthHint::AtLeast(
+        patterns
+            .patterns_iter()
+            .map(pattern_write_len)
+            .min()
+            .unwrap_or(0),
+    )
+}
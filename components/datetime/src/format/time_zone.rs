@@ -11,7 +11,7 @@ use crate::{
     date::TimeZoneInput,
     time_zone::{IsoFormat, IsoMinutes, IsoSeconds, TimeZoneFormat},
 };
-use writeable::Writeable;
+use writeable::{LengthHint, Writeable};
 
 pub struct FormattedTimeZone<'l, T>
 where
@@ -29,7 +29,9 @@ where
         write_pattern(self.time_zone_format, self.time_zone, sink).map_err(|_| core::fmt::Error)
     }
 
-    // TODO(#489): Implement write_len
+    fn write_len(&self) -> LengthHint {
+        LengthHint::AtLeast(super::pattern_write_len(&self.time_zone_format.pattern))
+    }
 }
 
 impl<'l, T> fmt::Display for FormattedTimeZone<'l, T>
@@ -83,9 +85,10 @@ where
                     .long_specific_non_location_format(w, time_zone)
                     .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::UpperZ => match field.length.idx() {
@@ -105,17 +108,19 @@ where
                     IsoSeconds::Optional,
                 )?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::UpperO => match field.length.idx() {
                 1..=4 => time_zone_format.localized_gmt_format(w, time_zone)?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::LowerV => match field.length.idx() {
@@ -128,9 +133,10 @@ where
                     .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
                     .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::UpperV => match field.length.idx() {
@@ -143,9 +149,10 @@ where
                     .generic_location_format(w, time_zone)
                     .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::LowerX => match field.length.idx() {
@@ -185,9 +192,10 @@ where
                     IsoSeconds::Optional,
                 )?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
             fields::TimeZone::UpperX => match field.length.idx() {
@@ -227,9 +235,10 @@ where
                     IsoSeconds::Optional,
                 )?,
                 _ => {
-                    return Err(Error::Pattern(PatternError::FieldLengthInvalid(
-                        FieldSymbol::TimeZone(zone_symbol),
-                    )))
+                    return Err(Error::invalid_pattern(
+                        PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(zone_symbol)),
+                        &time_zone_format.pattern,
+                    ))
                 }
             },
         }
@@ -10,7 +10,7 @@ use crate::fields::{self, FieldSymbol};
 use crate::pattern::{reference::Pattern, PatternItem};
 use crate::{date::ZonedDateTimeInput, zoned_datetime::ZonedDateTimeFormat};
 use core::fmt;
-use writeable::Writeable;
+use writeable::{LengthHint, Writeable};
 
 use super::datetime;
 use super::time_zone;
@@ -33,7 +33,13 @@ where
             .map_err(|_| core::fmt::Error)
     }
 
-    // TODO(#489): Implement write_len
+    fn write_len(&self) -> LengthHint {
+        super::pattern_plurals_write_len(
+            self.zoned_datetime_format
+                .datetime_format
+                .patterns_for(self.zoned_datetime),
+        )
+    }
 }
 
 impl<'l, 'd, T> fmt::Display for FormattedZonedDateTime<'l, 'd, T>
@@ -56,8 +62,14 @@ where
     W: fmt::Write + ?Sized,
 {
     let locale = &zoned_datetime_format.datetime_format.locale;
-    let patterns = &zoned_datetime_format.datetime_format.patterns;
-    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, locale);
+    let patterns = zoned_datetime_format
+        .datetime_format
+        .patterns_for(zoned_datetime);
+    let loc_datetime = ZonedDateTimeInputWithLocale::new(
+        zoned_datetime,
+        zoned_datetime_format.datetime_format.first_weekday,
+        locale,
+    );
 
     let pattern = patterns.select(
         &loc_datetime,
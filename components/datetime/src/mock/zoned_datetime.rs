@@ -114,6 +114,10 @@ impl DateInput for MockZonedDateTime {
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
         self.datetime.day_of_year_info()
     }
+
+    fn julian_day(&self) -> Option<i64> {
+        self.datetime.julian_day()
+    }
 }
 
 impl IsoTimeInput for MockZonedDateTime {
@@ -47,7 +47,7 @@
 //! let bag = components::Bag {
 //!     year: Some(components::Numeric::Numeric),
 //!     month: Some(components::Month::Long),
-//!     day: Some(components::Numeric::Numeric),
+//!     day: Some(components::Day::NumericDayOfMonth),
 //!
 //!     hour: Some(components::Numeric::TwoDigit),
 //!     minute: Some(components::Numeric::TwoDigit),
@@ -76,7 +76,7 @@ use crate::fields::{self, Field, FieldLength, FieldSymbol};
 
 use alloc::vec::Vec;
 
-use super::preferences;
+use super::{preferences, OptionsError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -86,6 +86,8 @@ use serde::{Deserialize, Serialize};
 pub struct Bag {
     /// Include the era, such as "AD" or "CE".
     pub era: Option<Text>,
+    /// Control when the era is displayed. If unset, the era is displayed only when `era` is set.
+    pub era_display: Option<EraDisplay>,
     /// Include the year, such as "1970" or "70".
     pub year: Option<Numeric>,
     /// Include the month, such as "April" or "Apr".
@@ -95,7 +97,7 @@ pub struct Bag {
     // TODO(#488): make visible once fully supported.
     pub week: Option<Week>,
     /// Include the day, such as "07" or "7".
-    pub day: Option<Numeric>,
+    pub day: Option<Day>,
     /// Include the weekday, such as "Wednesday" or "Wed".
     pub weekday: Option<Text>,
 
@@ -119,8 +121,24 @@ impl Bag {
     /// the UTS 35 table - https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
     pub(crate) fn to_vec_fields(&self) -> Vec<Field> {
         let mut fields = Vec::new();
-        if let Some(_era) = self.era {
-            unimplemented!("FieldSymbol::Era is needed. See issue #486.")
+        let display_era = match self.era_display {
+            Some(EraDisplay::Always) => true,
+            // The era for the "auto" display is added by the formatter when it is needed.
+            Some(EraDisplay::Auto) | Some(EraDisplay::Never) => false,
+            None => self.era.is_some(),
+        };
+        if display_era {
+            fields.push(Field {
+                symbol: FieldSymbol::Era,
+                length: match self.era.unwrap_or(Text::Short) {
+                    // Abbreviated, e.g. "AD".
+                    Text::Short => FieldLength::Abbreviated,
+                    // Wide, e.g. "Anno Domini".
+                    Text::Long => FieldLength::Wide,
+                    // Narrow, e.g. "A".
+                    Text::Narrow => FieldLength::Narrow,
+                },
+            });
         }
 
         if let Some(year) = self.year {
@@ -182,18 +200,23 @@ impl Bag {
         }
 
         if let Some(day) = self.day {
-            // TODO(#591,#592) Unimplemented day fields:
-            // D - Day of year
-            // F - Day of week in month
-            // g - Modified Julian day.
             fields.push(Field {
-                symbol: FieldSymbol::Day(fields::Day::DayOfMonth),
+                symbol: FieldSymbol::Day(match day {
+                    Day::NumericDayOfMonth | Day::TwoDigitDayOfMonth => fields::Day::DayOfMonth,
+                    Day::DayOfYear => fields::Day::DayOfYear,
+                    Day::DayOfWeekInMonth => fields::Day::DayOfWeekInMonth,
+                    Day::JulianDay => fields::Day::ModifiedJulianDay,
+                }),
                 length: match day {
                     // Day of month (numeric).
                     // d    1 	  Numeric: minimum digits
                     // dd   01 	  Numeric: 2 digits, zero pad if needed
-                    Numeric::Numeric => FieldLength::One,
-                    Numeric::TwoDigit => FieldLength::TwoDigit,
+                    Day::NumericDayOfMonth => FieldLength::One,
+                    Day::TwoDigitDayOfMonth => FieldLength::TwoDigit,
+                    // D    345       Day of year, numeric: minimum digits
+                    // F    2         Day of week in month, numeric
+                    // g    2451334   Modified Julian day, numeric: minimum digits
+                    Day::DayOfYear | Day::DayOfWeekInMonth | Day::JulianDay => FieldLength::One,
                 },
             });
         }
@@ -236,6 +259,7 @@ impl Bag {
                 symbol: FieldSymbol::Hour(match self.preferences {
                     Some(preferences::Bag {
                         hour_cycle: Some(hour_cycle),
+                        ..
                     }) => match hour_cycle {
                         // Skeletons only contain the h12, not h11. The pattern that is matched
                         // is free to use h11 or h12.
@@ -309,10 +333,152 @@ impl Bag {
     }
 }
 
+impl Bag {
+    /// Returns a [`BagBuilder`] for a [`Bag`] with no components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::options::{components, preferences};
+    ///
+    /// let bag = components::Bag::builder()
+    ///     .year(components::Numeric::Numeric)
+    ///     .month(components::Month::Long)
+    ///     .day(components::Day::NumericDayOfMonth)
+    ///     .hour(components::Numeric::Numeric)
+    ///     .minute(components::Numeric::TwoDigit)
+    ///     .hour_cycle(preferences::HourCycle::H12)
+    ///     .build()
+    ///     .expect("The options are compatible");
+    ///
+    /// assert_eq!(bag.month, Some(components::Month::Long));
+    /// assert_eq!(bag.second, None);
+    /// ```
+    pub fn builder() -> BagBuilder {
+        BagBuilder::default()
+    }
+}
+
+/// A builder for a components::[`Bag`], which checks that the options can be combined.
+///
+/// See [`Bag::builder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BagBuilder {
+    bag: Bag,
+    hour_cycle: Option<preferences::HourCycle>,
+}
+
+impl BagBuilder {
+    /// Includes the era, such as "AD" or "CE".
+    pub fn era(mut self, era: Text) -> Self {
+        self.bag.era = Some(era);
+        self
+    }
+
+    /// Sets when the era is displayed.
+    pub fn era_display(mut self, era_display: EraDisplay) -> Self {
+        self.bag.era_display = Some(era_display);
+        self
+    }
+
+    /// Includes the year, such as "1970" or "70".
+    pub fn year(mut self, year: Numeric) -> Self {
+        self.bag.year = Some(year);
+        self
+    }
+
+    /// Includes the month, such as "April" or "Apr".
+    pub fn month(mut self, month: Month) -> Self {
+        self.bag.month = Some(month);
+        self
+    }
+
+    /// Includes the day, such as "07" or "7".
+    pub fn day(mut self, day: Day) -> Self {
+        self.bag.day = Some(day);
+        self
+    }
+
+    /// Includes the weekday, such as "Wednesday" or "Wed".
+    pub fn weekday(mut self, weekday: Text) -> Self {
+        self.bag.weekday = Some(weekday);
+        self
+    }
+
+    /// Includes the hour, such as "2" or "14".
+    pub fn hour(mut self, hour: Numeric) -> Self {
+        self.bag.hour = Some(hour);
+        self
+    }
+
+    /// Includes the minute, such as "3" or "03".
+    pub fn minute(mut self, minute: Numeric) -> Self {
+        self.bag.minute = Some(minute);
+        self
+    }
+
+    /// Includes the second, such as "3" or "03".
+    pub fn second(mut self, second: Numeric) -> Self {
+        self.bag.second = Some(second);
+        self
+    }
+
+    /// Includes the time zone, such as "GMT+05:00".
+    pub fn time_zone_name(mut self, time_zone_name: TimeZoneName) -> Self {
+        self.bag.time_zone_name = Some(time_zone_name);
+        self
+    }
+
+    /// Sets the preferred hour cycle of the hour.
+    pub fn hour_cycle(mut self, hour_cycle: preferences::HourCycle) -> Self {
+        self.hour_cycle = Some(hour_cycle);
+        self
+    }
+
+    /// Returns the [`Bag`], or an error if the components cannot be combined: at least one
+    /// component must be set, the era can only be set with the year, and the hour cycle can
+    /// only be set with the hour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::options::{components, preferences, OptionsError};
+    ///
+    /// assert_eq!(components::Bag::builder().build(), Err(OptionsError::NoFields));
+    ///
+    /// let minutes = components::Bag::builder()
+    ///     .minute(components::Numeric::TwoDigit)
+    ///     .hour_cycle(preferences::HourCycle::H23)
+    ///     .build();
+    /// assert_eq!(minutes, Err(OptionsError::HourCycleWithoutHour));
+    /// ```
+    pub fn build(self) -> Result<Bag, OptionsError> {
+        let mut bag = self.bag;
+        if bag == Bag::default() {
+            return Err(OptionsError::NoFields);
+        }
+        let displays_era = !matches!(bag.era_display, None | Some(EraDisplay::Never));
+        if (bag.era.is_some() || displays_era) && bag.year.is_none() {
+            return Err(OptionsError::EraWithoutYear);
+        }
+        if let Some(hour_cycle) = self.hour_cycle {
+            if bag.hour.is_none() {
+                return Err(OptionsError::HourCycleWithoutHour);
+            }
+            bag.preferences = Some(preferences::Bag {
+                hour_cycle: Some(hour_cycle),
+                first_weekday: None,
+            });
+        }
+        Ok(bag)
+    }
+}
+
 impl Default for Bag {
     fn default() -> Self {
         Self {
             era: None,
+            era_display: None,
             year: None,
             month: None,
             week: None,
@@ -330,7 +496,7 @@ impl Default for Bag {
     }
 }
 
-/// A numeric component for the `components::`[`Bag`]. It is used for the year, day, hour, minute,
+/// A numeric component for the `components::`[`Bag`]. It is used for the year, hour, minute,
 /// and second.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -358,6 +524,23 @@ pub enum Text {
     Narrow,
 }
 
+/// Options for when to display the era for the `components::`[`Bag`], analogous to the
+/// proposed ECMA-402 `eraDisplay` option.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EraDisplay {
+    /// Display the era only when the year would be ambiguous without it, such as "44 BC",
+    /// while contemporary dates are displayed without it, such as "2021".
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
+    Auto,
+    /// Always display the era, such as "2021 AD".
+    #[cfg_attr(feature = "serde", serde(rename = "always"))]
+    Always,
+    /// Never display the era, even if the `era` component is set.
+    #[cfg_attr(feature = "serde", serde(rename = "never"))]
+    Never,
+}
+
 /// Options for displaying a Month for the `components::`[`Bag`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -379,6 +562,28 @@ pub enum Month {
     Narrow,
 }
 
+/// Options for displaying the day for the `components::`[`Bag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Day {
+    /// The numeric value of the day of the month, such as "7".
+    #[cfg_attr(feature = "serde", serde(rename = "numeric"))]
+    NumericDayOfMonth,
+    /// The two-digit value of the day of the month, such as "07".
+    #[cfg_attr(feature = "serde", serde(rename = "two-digit"))]
+    TwoDigitDayOfMonth,
+    /// The day of the year, such as "345".
+    #[cfg_attr(feature = "serde", serde(rename = "day-of-year"))]
+    DayOfYear,
+    /// The occurrence of the weekday within the month, such as the "2" in "the 2nd Wednesday
+    /// in July".
+    #[cfg_attr(feature = "serde", serde(rename = "day-of-week-in-month"))]
+    DayOfWeekInMonth,
+    /// The Julian day number counted from local midnight, such as "2451334".
+    #[cfg_attr(feature = "serde", serde(rename = "julian-day"))]
+    JulianDay,
+}
+
 // Each enum variant is documented with the UTS 35 field information from:
 // https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
 //
@@ -478,13 +683,34 @@ mod test {
     type Symbol = FieldSymbol;
     type Length = FieldLength;
 
+    #[test]
+    fn test_builder() {
+        let bag = Bag::builder()
+            .era(Text::Short)
+            .year(Numeric::Numeric)
+            .time_zone_name(TimeZoneName::ShortSpecific)
+            .build();
+        assert_eq!(
+            bag,
+            Ok(Bag {
+                era: Some(Text::Short),
+                year: Some(Numeric::Numeric),
+                time_zone_name: Some(TimeZoneName::ShortSpecific),
+                ..Default::default()
+            })
+        );
+
+        let bag = Bag::builder().era(Text::Short).month(Month::Long).build();
+        assert_eq!(bag, Err(OptionsError::EraWithoutYear));
+    }
+
     #[test]
     fn test_component_bag_to_vec_field() {
         let bag = Bag {
             year: Some(Numeric::Numeric),
             month: Some(Month::Long),
             week: Some(Week::WeekOfMonth),
-            day: Some(Numeric::Numeric),
+            day: Some(Day::NumericDayOfMonth),
 
             hour: Some(Numeric::Numeric),
             minute: Some(Numeric::Numeric),
@@ -511,7 +737,7 @@ mod test {
         let bag = Bag {
             year: Some(Numeric::Numeric),
             month: Some(Month::TwoDigit),
-            day: Some(Numeric::Numeric),
+            day: Some(Day::NumericDayOfMonth),
             ..Default::default()
         };
         assert_eq!(
@@ -523,4 +749,34 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_component_bag_era_display() {
+        let year_fields = vec![(Symbol::Year(fields::Year::Calendar), Length::One).into()];
+        let era_year_fields = vec![
+            (Symbol::Era, Length::Wide).into(),
+            (Symbol::Year(fields::Year::Calendar), Length::One).into(),
+        ];
+        let bag = Bag {
+            era: Some(Text::Long),
+            year: Some(Numeric::Numeric),
+            ..Default::default()
+        };
+        assert_eq!(bag.to_vec_fields(), era_year_fields);
+
+        for (era_display, expected) in &[
+            (EraDisplay::Always, &era_year_fields),
+            (EraDisplay::Auto, &year_fields),
+            (EraDisplay::Never, &year_fields),
+        ] {
+            let bag = Bag {
+                era_display: Some(*era_display),
+                ..bag.clone()
+            };
+            assert_eq!(&bag.to_vec_fields(), *expected, "{:?}", era_display);
+        }
+
+        let bag = Bag::builder().era_display(EraDisplay::Always).build();
+        assert_eq!(bag, Err(OptionsError::EraWithoutYear));
+    }
 }
@@ -13,7 +13,8 @@
 //! The type of the connector is determined by the length of the [`Date`] field.
 //!
 //! Additionally, the bag contains an optional set of `Preferences` which represent user preferred adjustments
-//! that can be applied onto the pattern right before formatting.
+//! that can be applied onto the pattern right before formatting, and optional [`Width`]s which override the
+//! width of the month and weekday names of the pattern.
 //!
 //! # Examples
 //!
@@ -24,7 +25,7 @@
 //! let bag = length::Bag {
 //!      date: Some(length::Date::Medium), // `Medium` length connector will be used
 //!      time: Some(length::Time::Short),
-//!      preferences: None,
+//!      ..Default::default()
 //! };
 //!
 //! let options = DateTimeFormatOptions::Length(bag);
@@ -42,7 +43,8 @@
 //! time. Formatted result should be treated as opaque and displayed to the user as-is,
 //! and it is strongly recommended to never write tests that expect a particular formatted output.
 
-use super::preferences;
+use super::{preferences, OptionsError};
+use crate::fields::FieldLength;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -61,7 +63,7 @@ use serde::{Deserialize, Serialize};
 /// let bag = length::Bag {
 ///      date: Some(length::Date::Medium),
 ///      time: Some(length::Time::Short),
-///      preferences: None,
+///      ..Default::default()
 /// };
 ///
 /// let options = DateTimeFormatOptions::Length(bag);
@@ -86,6 +88,11 @@ pub struct Bag {
     pub time: Option<Time>,
     /// Configure the preferences for the datetime, such as the hour cycle.
     pub preferences: Option<preferences::Bag>,
+    /// Override the width of the month names of the date, such as "S" instead of "Sep".
+    /// Numeric months are not affected.
+    pub month_width: Option<Width>,
+    /// Override the width of the weekday names of the date, such as "T" instead of "Tuesday".
+    pub weekday_width: Option<Width>,
 }
 
 impl Default for Bag {
@@ -94,10 +101,120 @@ impl Default for Bag {
             date: Some(Date::Long),
             time: Some(Time::Long),
             preferences: None,
+            month_width: None,
+            weekday_width: None,
         }
     }
 }
 
+impl Bag {
+    /// Returns a [`BagBuilder`] for a [`Bag`] with no date or time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::options::{length, preferences};
+    ///
+    /// let bag = length::Bag::builder()
+    ///     .date(length::Date::Long)
+    ///     .time(length::Time::Short)
+    ///     .hour_cycle(preferences::HourCycle::H23)
+    ///     .build()
+    ///     .expect("The options are compatible");
+    ///
+    /// assert_eq!(bag.date, Some(length::Date::Long));
+    /// assert_eq!(
+    ///     bag.preferences,
+    ///     Some(preferences::Bag {
+    ///         hour_cycle: Some(preferences::HourCycle::H23),
+    ///         first_weekday: None,
+    ///     })
+    /// );
+    /// ```
+    pub fn builder() -> BagBuilder {
+        BagBuilder::default()
+    }
+}
+
+/// A builder for a length::[`Bag`], which checks that the options can be combined.
+///
+/// See [`Bag::builder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BagBuilder {
+    date: Option<Date>,
+    time: Option<Time>,
+    hour_cycle: Option<preferences::HourCycle>,
+    month_width: Option<Width>,
+    weekday_width: Option<Width>,
+}
+
+impl BagBuilder {
+    /// Sets the length of the date.
+    pub fn date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the length of the time.
+    pub fn time(mut self, time: Time) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the preferred hour cycle of the time.
+    pub fn hour_cycle(mut self, hour_cycle: preferences::HourCycle) -> Self {
+        self.hour_cycle = Some(hour_cycle);
+        self
+    }
+
+    /// Sets the width of the month names of the date.
+    pub fn month_width(mut self, month_width: Width) -> Self {
+        self.month_width = Some(month_width);
+        self
+    }
+
+    /// Sets the width of the weekday names of the date.
+    pub fn weekday_width(mut self, weekday_width: Width) -> Self {
+        self.weekday_width = Some(weekday_width);
+        self
+    }
+
+    /// Returns the [`Bag`], or an error if the options cannot be combined: at least one of the
+    /// date and the time must be set, and the hour cycle can only be set with the time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::options::{length, preferences, OptionsError};
+    ///
+    /// assert_eq!(length::Bag::builder().build(), Err(OptionsError::NoFields));
+    ///
+    /// let date_only = length::Bag::builder()
+    ///     .date(length::Date::Medium)
+    ///     .hour_cycle(preferences::HourCycle::H12)
+    ///     .build();
+    /// assert_eq!(date_only, Err(OptionsError::HourCycleWithoutHour));
+    /// ```
+    pub fn build(self) -> Result<Bag, OptionsError> {
+        if self.date.is_none() && self.time.is_none() {
+            return Err(OptionsError::NoFields);
+        }
+        if self.hour_cycle.is_some() && self.time.is_none() {
+            return Err(OptionsError::HourCycleWithoutHour);
+        }
+        Ok(Bag {
+            date: self.date,
+            time: self.time,
+            preferences: self.hour_cycle.map(|hour_cycle| preferences::Bag {
+                hour_cycle: Some(hour_cycle),
+                first_weekday: None,
+            }),
+            month_width: self.month_width,
+            weekday_width: self.weekday_width,
+        })
+    }
+}
+
 /// Represents different lengths a [`DateTimeInput`] implementer can be formatted into.
 /// Each length has associated best pattern for it for a given locale.
 ///
@@ -111,8 +228,7 @@ impl Default for Bag {
 /// let bag = length::Bag {
 ///     date: Some(length::Date::Long),
 ///     time: None,
-///
-///     preferences: None,
+///     ..Default::default()
 /// };
 /// ```
 ///
@@ -195,8 +311,7 @@ pub enum Date {
 /// let bag = length::Bag {
 ///     date: None,
 ///     time: Some(length::Time::Medium),
-///
-///     preferences: None,
+///     ..Default::default()
 /// };
 /// ```
 ///
@@ -265,3 +380,47 @@ pub enum Time {
     #[cfg_attr(feature = "serde", serde(rename = "short"))]
     Short,
 }
+
+/// The width of the month and weekday names for the length [`Bag`], which overrides the width
+/// used by the pattern of the selected length.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::options::length;
+///
+/// // A full date with short weekday names, such as "Tu, January 21, 2020" in en-US.
+/// let bag = length::Bag {
+///     date: Some(length::Date::Full),
+///     time: None,
+///     weekday_width: Some(length::Width::Short),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Width {
+    /// The wide name, such as "September" or "Tuesday".
+    #[cfg_attr(feature = "serde", serde(rename = "wide"))]
+    Wide,
+    /// The abbreviated name, such as "Sep" or "Tue".
+    #[cfg_attr(feature = "serde", serde(rename = "abbreviated"))]
+    Abbreviated,
+    /// The short name, such as "Tu". Months have no short names, and use the abbreviated ones.
+    #[cfg_attr(feature = "serde", serde(rename = "short"))]
+    Short,
+    /// The narrow name, such as "S" or "T".
+    #[cfg_attr(feature = "serde", serde(rename = "narrow"))]
+    Narrow,
+}
+
+impl From<Width> for FieldLength {
+    fn from(width: Width) -> Self {
+        match width {
+            Width::Wide => FieldLength::Wide,
+            Width::Abbreviated => FieldLength::Abbreviated,
+            Width::Short => FieldLength::Six,
+            Width::Narrow => FieldLength::Narrow,
+        }
+    }
+}
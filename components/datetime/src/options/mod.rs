@@ -32,6 +32,26 @@
 pub mod components;
 pub mod length;
 pub mod preferences;
+
+use displaydoc::Display;
+
+/// An error returned when building a bag of options from options that cannot be combined.
+#[derive(Display, Debug, Clone, Copy, PartialEq)]
+pub enum OptionsError {
+    /// Neither date nor time fields were requested.
+    #[displaydoc("No date or time fields were requested")]
+    NoFields,
+    /// An hour cycle was requested for a format without hours.
+    #[displaydoc("An hour cycle was requested without the hour")]
+    HourCycleWithoutHour,
+    /// The era was requested without the year.
+    #[displaydoc("The era was requested without the year")]
+    EraWithoutYear,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OptionsError {}
+
 /// A bag of options which, together with [`Locale`](icu_locid::Locale), defines how
 /// dates will be formatted with a [`DateTimeFormat`](crate::DateTimeFormat) instance.
 ///
@@ -63,6 +83,16 @@ pub enum DateTimeFormatOptions {
     Components(components::Bag),
 }
 
+impl DateTimeFormatOptions {
+    /// The user preferences of either bag.
+    pub(crate) fn preferences(&self) -> Option<&preferences::Bag> {
+        match self {
+            Self::Length(bag) => bag.preferences.as_ref(),
+            Self::Components(bag) => bag.preferences.as_ref(),
+        }
+    }
+}
+
 impl Default for DateTimeFormatOptions {
     fn default() -> Self {
         Self::Length(length::Bag::default())
@@ -21,10 +21,14 @@
 //! use icu::datetime::options::preferences;
 //!
 //! let prefs = preferences::Bag {
-//!     hour_cycle: Some(preferences::HourCycle::H23)
+//!     hour_cycle: Some(preferences::HourCycle::H23),
+//!     first_weekday: None,
 //! };
 //! ```
+use crate::date::IsoWeekday;
 use crate::fields;
+use crate::pattern::{reference::Pattern, PatternItem};
+use icu_provider::yoke::{self, *};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -37,7 +41,8 @@ use serde::{Deserialize, Serialize};
 /// use icu::datetime::options::preferences;
 ///
 /// let prefs = preferences::Bag {
-///     hour_cycle: Some(preferences::HourCycle::H23)
+///     hour_cycle: Some(preferences::HourCycle::H23),
+///     first_weekday: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -48,10 +53,75 @@ pub struct Bag {
     /// given locale.
     #[cfg_attr(feature = "serde", serde(rename = "hourCycle"))]
     pub hour_cycle: Option<HourCycle>,
+    /// The first day of the week, used when computing week numbers. It takes precedence over
+    /// the `-u-fw` keyword of the locale, such as `en-GB-u-fw-sun`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "firstWeekday", default, with = "first_weekday_serde")
+    )]
+    pub first_weekday: Option<IsoWeekday>,
+}
+
+/// Parses a value of the `-u-fw` keyword, such as "sun", into an [`IsoWeekday`].
+pub(crate) fn first_weekday_from_bcp47(value: &str) -> Option<IsoWeekday> {
+    Some(match value {
+        "mon" => IsoWeekday::Monday,
+        "tue" => IsoWeekday::Tuesday,
+        "wed" => IsoWeekday::Wednesday,
+        "thu" => IsoWeekday::Thursday,
+        "fri" => IsoWeekday::Friday,
+        "sat" => IsoWeekday::Saturday,
+        "sun" => IsoWeekday::Sunday,
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "serde")]
+mod first_weekday_serde {
+    use super::first_weekday_from_bcp47;
+    use crate::date::IsoWeekday;
+    use alloc::string::String;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        first_weekday: &Option<IsoWeekday>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        first_weekday
+            .map(|weekday| match weekday {
+                IsoWeekday::Monday => "mon",
+                IsoWeekday::Tuesday => "tue",
+                IsoWeekday::Wednesday => "wed",
+                IsoWeekday::Thursday => "thu",
+                IsoWeekday::Friday => "fri",
+                IsoWeekday::Saturday => "sat",
+                IsoWeekday::Sunday => "sun",
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<IsoWeekday>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| {
+                first_weekday_from_bcp47(&value).ok_or_else(|| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Str(&value),
+                        &"a weekday such as \"sun\"",
+                    )
+                })
+            })
+            .transpose()
+    }
 }
 
 /// A user preference for adjusting how the hour component is displayed.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// This is also the hour cycle preferred by a locale in the provider data for [`length::Bag`].
+///
+/// [`length::Bag`]: crate::options::length::Bag
+#[derive(Debug, Clone, Copy, PartialEq, Yokeable, ZeroCopyFrom)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HourCycle {
     /// Hour is formatted to be in range 1-24 where midnight is 24:00.
@@ -104,6 +174,14 @@ pub enum HourCycle {
     H11,
 }
 
+/// Default is required for serialization. H23 is the more locale-agnostic choice, as it's
+/// less likely to have a day period in it.
+impl Default for HourCycle {
+    fn default() -> Self {
+        Self::H23
+    }
+}
+
 impl HourCycle {
     /// Convert the HourCycle preference to a field.
     pub fn field(self) -> fields::Hour {
@@ -114,4 +192,30 @@ impl HourCycle {
             Self::H24 => fields::Hour::H24,
         }
     }
+
+    /// Figure out the hour cycle given a pattern, which is useful for generating the provider
+    /// patterns for `length::Bag`.
+    pub fn determine(pattern: &Pattern) -> Option<Self> {
+        pattern.items().iter().find_map(|item| match item {
+            PatternItem::Field(fields::Field {
+                symbol: fields::FieldSymbol::Hour(pattern_hour),
+                length: _,
+            }) => Some(match pattern_hour {
+                fields::Hour::H11 => Self::H11,
+                fields::Hour::H12 => Self::H12,
+                fields::Hour::H23 => Self::H23,
+                fields::Hour::H24 => Self::H24,
+            }),
+            _ => None,
+        })
+    }
+
+    /// Replace the hour symbols of a pattern with the symbol of this hour cycle. This is used by
+    /// transforms to precompute the patterns of each hour cycle, which is only valid between h11
+    /// and h12, or between h23 and h24.
+    #[doc(hidden)]
+    #[cfg(feature = "provider_transform_internals")]
+    pub fn apply_on_pattern(self, pattern: &mut Pattern) {
+        crate::pattern::hour_cycle::naively_apply_hour_cycle(pattern, self);
+    }
 }
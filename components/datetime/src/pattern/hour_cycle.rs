@@ -6,10 +6,11 @@ use super::{reference::Pattern, PatternItem};
 use crate::{fields, options::preferences};
 #[cfg(feature = "provider_transform_internals")]
 use crate::{provider, skeleton};
+use icu_provider::yoke::{self, *};
 
 /// Used to represent either H11/H12, or H23/H24. Skeletons only store these
 /// hour cycles as H12 or H23.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Yokeable, ZeroCopyFrom)]
 #[cfg_attr(
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
@@ -55,7 +56,7 @@ impl CoarseHourCycle {
     #[cfg(feature = "provider_transform_internals")]
     pub fn apply_on_pattern(
         &self,
-        date_time: &provider::gregory::patterns::LengthPatternsV1,
+        date_time: &provider::gregory::patterns::LengthPatternsV1<'_>,
         skeletons: &provider::gregory::DateSkeletonPatternsV1,
         pattern_str: &str,
         mut pattern: Pattern,
@@ -104,7 +105,7 @@ impl CoarseHourCycle {
                 "{}",
                 patterns
                     .0
-                    .expect_pattern("Only week-of patterns have plural variants")
+                    .expect_pattern_ref("Only week-of patterns have plural variants")
             )),
             skeleton::BestSkeleton::NoMatch => None,
         }
@@ -121,13 +122,19 @@ pub(crate) fn naively_apply_preferences(
     // If there is a preference overiding the hour cycle, apply it now.
     if let Some(preferences::Bag {
         hour_cycle: Some(hour_cycle),
+        ..
     }) = preferences
     {
-        for item in pattern.items_mut() {
-            if let PatternItem::Field(fields::Field { symbol, length: _ }) = item {
-                if let fields::FieldSymbol::Hour(_) = symbol {
-                    *symbol = fields::FieldSymbol::Hour(hour_cycle.field());
-                }
+        naively_apply_hour_cycle(pattern, *hour_cycle);
+    }
+}
+
+/// Replaces the symbol of every hour field in a pattern with the symbol of the given hour cycle.
+pub(crate) fn naively_apply_hour_cycle(pattern: &mut Pattern, hour_cycle: preferences::HourCycle) {
+    for item in pattern.items_mut() {
+        if let PatternItem::Field(fields::Field { symbol, length: _ }) = item {
+            if let fields::FieldSymbol::Hour(_) = symbol {
+                *symbol = fields::FieldSymbol::Hour(hour_cycle.field());
             }
         }
     }
@@ -7,14 +7,13 @@ use crate::error::DateTimeFormatError;
 use crate::fields;
 use crate::options::{components, length, preferences, DateTimeFormatOptions};
 use crate::pattern::{
-    hour_cycle,
     reference::{Pattern, PatternPlurals},
+    PatternItem,
 };
 use crate::provider;
 use crate::provider::gregory::{DatePatternsV1Marker, DateSkeletonPatternsV1Marker};
 use crate::skeleton;
-use alloc::borrow::Cow;
-use icu_locid::Locale;
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
 
 type Result<T> = core::result::Result<T, DateTimeFormatError>;
@@ -24,17 +23,54 @@ type Result<T> = core::result::Result<T, DateTimeFormatError>;
 ///
 /// It uses a temporary structure `PatternSelector` to lazily load data as needed
 /// as it traverses the decision tree based on the provided options.
+///
+/// The patterns are returned together with the locale of the loaded data, as reported by the
+/// data provider.
 pub(crate) fn patterns_for_options<'data, D>(
     data_provider: &D,
     locale: &Locale,
     options: &DateTimeFormatOptions,
-) -> Result<Option<PatternPlurals>>
+) -> Result<(Option<PatternPlurals>, Option<LanguageIdentifier>)>
 where
     D: DataProvider<'data, DatePatternsV1Marker>
         + DataProvider<'data, DateSkeletonPatternsV1Marker>,
 {
     let mut selector = PatternSelector::new(data_provider, locale);
-    selector.patterns_for_options(options)
+    let patterns = selector.patterns_for_options(options)?;
+    Ok((patterns, selector.date_patterns.1))
+}
+
+/// Selects the patterns to use in place of the ones from [`patterns_for_options`] for dates whose
+/// year is ambiguous without the era, such as dates before the common era. These are only needed
+/// for a [`components::Bag`] with an [`EraDisplay::Auto`](components::EraDisplay::Auto) era.
+pub(crate) fn era_patterns_for_options<'data, D>(
+    data_provider: &D,
+    locale: &Locale,
+    options: &DateTimeFormatOptions,
+) -> Result<Option<PatternPlurals>>
+where
+    D: DataProvider<'data, DatePatternsV1Marker>
+        + DataProvider<'data, DateSkeletonPatternsV1Marker>,
+{
+    match options {
+        DateTimeFormatOptions::Components(
+            bag
+            @
+            components::Bag {
+                era_display: Some(components::EraDisplay::Auto),
+                year: Some(_),
+                ..
+            },
+        ) => {
+            let bag = components::Bag {
+                era_display: Some(components::EraDisplay::Always),
+                ..bag.clone()
+            };
+            let mut selector = PatternSelector::new(data_provider, locale);
+            selector.patterns_for_components_bag(&bag)
+        }
+        _ => Ok(None),
+    }
 }
 
 /// Private temporary structure used to cache lazily loaded data from the data provider.
@@ -64,8 +100,12 @@ pub struct PatternSelector<'a, 'data, D> {
     skeletons: DateSkeletonPatternsOption<'data>,
 }
 
+/// The lazily loaded date patterns, and the locale of their data as reported by the data provider.
 #[derive(Default)]
-struct DatePatternsOption<'data>(Option<DataPayload<'data, DatePatternsV1Marker>>);
+struct DatePatternsOption<'data>(
+    Option<DataPayload<'data, DatePatternsV1Marker>>,
+    Option<LanguageIdentifier>,
+);
 
 impl<'data> DatePatternsOption<'data> {
     fn retrieve<D>(
@@ -79,18 +119,17 @@ impl<'data> DatePatternsOption<'data> {
         if let Some(ref value) = self.0 {
             Ok(value)
         } else {
-            let patterns_data = data_provider
-                .load_payload(&DataRequest {
-                    resource_path: ResourcePath {
-                        key: provider::key::GREGORY_DATE_PATTERNS_V1,
-                        options: ResourceOptions {
-                            variant: None,
-                            langid: Some(locale.clone().into()),
-                        },
+            let response = data_provider.load_payload_with_context(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::GREGORY_DATE_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
                     },
-                })?
-                .take_payload()?;
-            Ok(self.0.insert(patterns_data))
+                },
+            })?;
+            self.1 = response.metadata.data_langid.clone();
+            Ok(self.0.insert(response.take_payload()?))
         }
     }
 }
@@ -111,7 +150,7 @@ impl<'data> DateSkeletonPatternsOption<'data> {
             Ok(value)
         } else {
             let patterns_data = data_provider
-                .load_payload(&DataRequest {
+                .load_payload_with_context(&DataRequest {
                     resource_path: ResourcePath {
                         key: provider::key::GREGORY_DATE_SKELETON_PATTERNS_V1,
                         options: ResourceOptions {
@@ -156,6 +195,15 @@ where
 
     /// Determine the appropriate `Pattern` for a given `options::Length` bag.
     fn pattern_for_length_bag(&mut self, length: &length::Bag) -> Result<Option<Pattern>> {
+        let mut pattern = self.pattern_for_lengths(length)?;
+        if let Some(pattern) = &mut pattern {
+            apply_length_widths(pattern, length);
+        }
+        Ok(pattern)
+    }
+
+    /// Determine the `Pattern` of the date and time lengths of a given `options::Length` bag.
+    fn pattern_for_lengths(&mut self, length: &length::Bag) -> Result<Option<Pattern>> {
         match (length.date, length.time) {
             (None, None) => Ok(None),
             (None, Some(time_length)) => self
@@ -189,8 +237,8 @@ where
     }
 
     /// Determine the appropriate `Pattern` for a given `options::length::Time` bag.
-    /// If a preference for an hour cycle is set, it will look look up a pattern in the time_h11_12 or
-    /// time_h23_h24 provider data, and then manually modify the symbol in the pattern if needed.
+    /// If a preference for an hour cycle is set, it will look up a pattern in the provider data
+    /// for that hour cycle, otherwise it uses the preferred hour cycle of the locale.
     fn pattern_for_time_length(
         &mut self,
         length: length::Time,
@@ -200,33 +248,26 @@ where
             .date_patterns
             .retrieve(self.data_provider, self.locale)?
             .get();
-        // Determine the coarse hour cycle patterns to use from either the preference bag,
-        // or the preferred hour cycle for the locale.
-        let time = if let Some(preferences::Bag {
-            hour_cycle: Some(hour_cycle_pref),
-        }) = preferences
-        {
-            match hour_cycle_pref {
-                preferences::HourCycle::H11 | preferences::HourCycle::H12 => &patterns.time_h11_h12,
-                preferences::HourCycle::H23 | preferences::HourCycle::H24 => &patterns.time_h23_h24,
-            }
-        } else {
-            match patterns.preferred_hour_cycle {
-                crate::pattern::CoarseHourCycle::H11H12 => &patterns.time_h11_h12,
-                crate::pattern::CoarseHourCycle::H23H24 => &patterns.time_h23_h24,
-            }
+        let hour_cycle = match preferences {
+            Some(preferences::Bag {
+                hour_cycle: Some(hour_cycle_pref),
+                ..
+            }) => *hour_cycle_pref,
+            _ => patterns.preferred_hour_cycle,
+        };
+        let time = match hour_cycle {
+            preferences::HourCycle::H11 => &patterns.time_h11,
+            preferences::HourCycle::H12 => &patterns.time_h12,
+            preferences::HourCycle::H23 => &patterns.time_h23,
+            preferences::HourCycle::H24 => &patterns.time_h24,
         };
 
-        let mut pattern = Pattern::from_bytes(match length {
+        Ok(Pattern::from_bytes(match length {
             length::Time::Full => &time.full,
             length::Time::Long => &time.long,
             length::Time::Medium => &time.medium,
             length::Time::Short => &time.short,
-        })?;
-
-        hour_cycle::naively_apply_preferences(&mut pattern, preferences);
-
-        Ok(pattern)
+        })?)
     }
 
     /// Determine the appropriate `Pattern` for a given `options::length::Date` and
@@ -274,42 +315,68 @@ where
                 false, // Prefer the requested fields over the matched pattern.
             ) {
                 skeleton::BestSkeleton::AllFieldsMatch(pattern)
-                | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(pattern.0),
+                | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => {
+                    Some(pattern.into_owned().0)
+                }
                 skeleton::BestSkeleton::NoMatch => None,
             },
         )
     }
 }
 
+/// Overrides the width of the month and weekday names in a pattern selected for the given
+/// `options::Length` bag, leaving numeric fields unchanged.
+fn apply_length_widths(pattern: &mut Pattern, length: &length::Bag) {
+    if length.month_width.is_none() && length.weekday_width.is_none() {
+        return;
+    }
+    for item in pattern.items_mut() {
+        if let PatternItem::Field(field) = item {
+            if field.get_length_type() != fields::TextOrNumeric::Text {
+                continue;
+            }
+            let width = match field.symbol {
+                fields::FieldSymbol::Month(_) => length.month_width,
+                fields::FieldSymbol::Weekday(_) => length.weekday_width,
+                _ => None,
+            };
+            if let Some(width) = width {
+                field.length = width.into();
+            }
+        }
+    }
+}
+
 pub trait DateTimeSymbols {
     fn get_symbol_for_month(
         &self,
         month: fields::Month,
         length: fields::FieldLength,
         num: usize,
-    ) -> &Cow<str>;
+    ) -> Result<&str>;
     fn get_symbol_for_weekday(
         &self,
         weekday: fields::Weekday,
         length: fields::FieldLength,
         day: date::IsoWeekday,
-    ) -> &Cow<str>;
+    ) -> Result<&str>;
     fn get_symbol_for_day_period(
         &self,
         day_period: fields::DayPeriod,
         length: fields::FieldLength,
         hour: date::IsoHour,
         is_top_of_hour: bool,
-    ) -> &Cow<str>;
+    ) -> Result<&str>;
+    fn get_symbol_for_era(&self, length: fields::FieldLength, era: &date::Era) -> Result<&str>;
 }
 
-impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
+impl DateTimeSymbols for provider::gregory::DateSymbolsV1<'_> {
     fn get_symbol_for_weekday(
         &self,
         weekday: fields::Weekday,
         length: fields::FieldLength,
         day: date::IsoWeekday,
-    ) -> &Cow<str> {
+    ) -> Result<&str> {
         let widths = match weekday {
             fields::Weekday::Format => &self.weekdays.format,
             fields::Weekday::StandAlone => {
@@ -324,7 +391,10 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
                         _ => widths.abbreviated.as_ref(),
                     };
                     if let Some(symbols) = symbols {
-                        return &symbols.0[(day as usize) % 7];
+                        return symbols
+                            .0
+                            .get((day as usize) % 7)
+                            .ok_or(DateTimeFormatError::MissingDateSymbol);
                     } else {
                         return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                     }
@@ -340,7 +410,10 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
             fields::FieldLength::Six => widths.short.as_ref().unwrap_or(&widths.abbreviated),
             _ => &widths.abbreviated,
         };
-        &symbols.0[(day as usize) % 7]
+        symbols
+            .0
+            .get((day as usize) % 7)
+            .ok_or(DateTimeFormatError::MissingDateSymbol)
     }
 
     fn get_symbol_for_month(
@@ -348,7 +421,7 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         month: fields::Month,
         length: fields::FieldLength,
         num: usize,
-    ) -> &Cow<str> {
+    ) -> Result<&str> {
         // TODO(#493): Support symbols for non-Gregorian calendars.
         debug_assert!(num < 12);
         let widths = match month {
@@ -361,7 +434,10 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
                         _ => widths.abbreviated.as_ref(),
                     };
                     if let Some(symbols) = symbols {
-                        return &symbols.0[num];
+                        return symbols
+                            .0
+                            .get(num)
+                            .ok_or(DateTimeFormatError::MissingDateSymbol);
                     } else {
                         return self.get_symbol_for_month(fields::Month::Format, length, num);
                     }
@@ -375,7 +451,10 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
             fields::FieldLength::Narrow => &widths.narrow,
             _ => &widths.abbreviated,
         };
-        &symbols.0[num]
+        symbols
+            .0
+            .get(num)
+            .ok_or(DateTimeFormatError::MissingDateSymbol)
     }
 
     fn get_symbol_for_day_period(
@@ -384,7 +463,7 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         length: fields::FieldLength,
         hour: date::IsoHour,
         is_top_of_hour: bool,
-    ) -> &Cow<str> {
+    ) -> Result<&str> {
         use fields::{DayPeriod::NoonMidnight, FieldLength};
         let widths = &self.day_periods.format;
         let symbols = match length {
@@ -392,11 +471,30 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
             FieldLength::Narrow => &widths.narrow,
             _ => &widths.abbreviated,
         };
-        match (day_period, u8::from(hour), is_top_of_hour) {
-            (NoonMidnight, 00, true) => symbols.midnight.as_ref().unwrap_or(&symbols.am),
-            (NoonMidnight, 12, true) => symbols.noon.as_ref().unwrap_or(&symbols.pm),
+        let symbol: &str = match (day_period, u8::from(hour), is_top_of_hour) {
+            (NoonMidnight, 00, true) => symbols.midnight.as_deref().unwrap_or(&symbols.am),
+            (NoonMidnight, 12, true) => symbols.noon.as_deref().unwrap_or(&symbols.pm),
             (_, hour, _) if hour < 12 => &symbols.am,
             _ => &symbols.pm,
-        }
+        };
+        Ok(symbol)
+    }
+
+    fn get_symbol_for_era(&self, length: fields::FieldLength, era: &date::Era) -> Result<&str> {
+        // TODO(#493): Support eras of non-Gregorian calendars.
+        let idx = match era.0.as_str() {
+            "bce" => 0,
+            "ce" => 1,
+            _ => return Err(DateTimeFormatError::MissingDateSymbol),
+        };
+        let symbols = match length {
+            fields::FieldLength::Wide => &self.eras.wide,
+            fields::FieldLength::Narrow => &self.eras.narrow,
+            _ => &self.eras.abbreviated,
+        };
+        symbols
+            .0
+            .get(idx)
+            .ok_or(DateTimeFormatError::MissingDateSymbol)
     }
 }
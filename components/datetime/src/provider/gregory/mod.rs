@@ -7,6 +7,7 @@
 mod skeletons;
 mod symbols;
 
+use crate::options::preferences;
 use crate::pattern;
 use alloc::borrow::Cow;
 use icu_provider::yoke::{self, *};
@@ -19,25 +20,34 @@ pub use symbols::*;
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
 )]
-#[yoke(cloning_zcf)]
-pub struct DatePatternsV1 {
-    pub date: patterns::LengthPatternsV1,
+pub struct DatePatternsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub date: patterns::LengthPatternsV1<'data>,
 
     /// These patterns are common uses of time formatting, broken down by the length of the
-    /// pattern. Users can override the hour cycle with a preference, so there are two
-    /// pattern groups stored here. Note that the pattern will contain either h11 or h12.
-    pub time_h11_h12: patterns::LengthPatternsV1,
+    /// pattern. Users can override the hour cycle with a preference, so the patterns are
+    /// stored for each hour cycle. These contain the h11 hour symbol (K).
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub time_h11: patterns::LengthPatternsV1<'data>,
 
-    /// These patterns are common uses of time formatting, broken down by the length of the
-    /// pattern. Users can override the hour cycle with a preference, so there are two
-    /// pattern groups stored here. Note that the pattern will contain either h23 or h24.
-    pub time_h23_h24: patterns::LengthPatternsV1,
+    /// The time patterns with the h12 hour symbol (h).
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub time_h12: patterns::LengthPatternsV1<'data>,
+
+    /// The time patterns with the h23 hour symbol (H).
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub time_h23: patterns::LengthPatternsV1<'data>,
+
+    /// The time patterns with the h24 hour symbol (k).
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub time_h24: patterns::LengthPatternsV1<'data>,
 
     /// By default a locale will prefer one hour cycle type over another.
-    pub preferred_hour_cycle: pattern::CoarseHourCycle,
+    pub preferred_hour_cycle: preferences::HourCycle,
 
     /// Patterns used to combine date and time length patterns into full date_time patterns.
-    pub length_combinations: patterns::LengthPatternsV1,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub length_combinations: patterns::LengthPatternsV1<'data>,
 }
 
 pub mod patterns {
@@ -53,16 +63,20 @@ pub mod patterns {
         ser, Deserialize, Deserializer, Serialize,
     };
 
-    #[derive(Debug, PartialEq, Clone, Default)]
+    #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
     #[cfg_attr(
         feature = "provider_serde",
         derive(serde::Serialize, serde::Deserialize)
     )]
-    pub struct LengthPatternsV1 {
-        pub full: Cow<'static, str>,
-        pub long: Cow<'static, str>,
-        pub medium: Cow<'static, str>,
-        pub short: Cow<'static, str>,
+    pub struct LengthPatternsV1<'data> {
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub full: Cow<'data, str>,
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub long: Cow<'data, str>,
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub medium: Cow<'data, str>,
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub short: Cow<'data, str>,
     }
 
     /// This struct is a public wrapper around the internal [`Pattern`] struct. This allows
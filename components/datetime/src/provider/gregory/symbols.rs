@@ -6,6 +6,7 @@
 
 use alloc::borrow::Cow;
 use icu_provider::yoke::{self, *};
+use zerovec::VarZeroVec;
 
 #[icu_provider::data_struct]
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -13,13 +14,18 @@ use icu_provider::yoke::{self, *};
     feature = "provider_serde",
     derive(serde::Serialize, serde::Deserialize)
 )]
-#[yoke(cloning_zcf)]
-pub struct DateSymbolsV1 {
-    pub months: months::ContextsV1,
+pub struct DateSymbolsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub months: months::ContextsV1<'data>,
 
-    pub weekdays: weekdays::ContextsV1,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub weekdays: weekdays::ContextsV1<'data>,
 
-    pub day_periods: day_periods::ContextsV1,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub day_periods: day_periods::ContextsV1<'data>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub eras: eras::ErasV1<'data>,
 }
 
 macro_rules! symbols {
@@ -27,9 +33,14 @@ macro_rules! symbols {
             pub mod $name {
                 use super::*;
 
-                #[derive(Debug, PartialEq, Clone, Default)]
+                /// The symbols, one per month or weekday, stored contiguously so that they can
+                /// be borrowed from the serialized data.
+                #[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
                 #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
-                pub struct SymbolsV1(pub $expr);
+                pub struct SymbolsV1<'data>(
+                    #[cfg_attr(feature="provider_serde", serde(borrow))]
+                    pub $expr
+                );
 
                 symbols!();
             }
@@ -40,24 +51,28 @@ macro_rules! symbols {
         ($name: ident { $element: ident: Option<$ty: ty>, $($tokens: tt)+ } -> ($($members:tt)*)) => {
             symbols!($name { $($tokens)* } -> (
                 $($members)*
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
                 pub $element: Option<$ty>,
             ));
         };
         ($name: ident { $element: ident: $ty: ty, $($tokens: tt)+ } -> ($($members:tt)*)) => {
             symbols!($name { $($tokens)* } -> (
                 $($members)*
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
                 pub $element: $ty,
             ));
         };
         ($name: ident { $element: ident: Option<$ty: ty> $(,)? } -> ($($members:tt)*)) => {
             symbols!($name { } -> (
                 $($members)*
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
                 pub $element: Option<$ty>,
             ));
         };
         ($name: ident { $element: ident: $ty: ty $(,)? } -> ($($members:tt)*)) => {
             symbols!($name { } -> (
                 $($members)*
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
                 pub $element: $ty,
             ));
         };
@@ -66,9 +81,8 @@ macro_rules! symbols {
                 use super::*;
 
                 #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
-                #[yoke(cloning_zcf)]
                 #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
-                pub struct SymbolsV1 {
+                pub struct SymbolsV1<'data> {
                     $($members)*
                 }
                 symbols!();
@@ -78,45 +92,102 @@ macro_rules! symbols {
             // UTS 35 specifies that `format` widths are mandatory
             // except of `short`.
             #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
-            #[yoke(cloning_zcf)]
             #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct FormatWidthsV1 {
-                pub abbreviated: SymbolsV1,
-                pub narrow: SymbolsV1,
-                pub short: Option<SymbolsV1>,
-                pub wide: SymbolsV1,
+            pub struct FormatWidthsV1<'data> {
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub abbreviated: SymbolsV1<'data>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub narrow: SymbolsV1<'data>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub short: Option<SymbolsV1<'data>>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub wide: SymbolsV1<'data>,
             }
 
             // UTS 35 specifies that `stand_alone` widths are optional
             #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
-            #[yoke(cloning_zcf)]
             #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct StandAloneWidthsV1 {
-                pub abbreviated: Option<SymbolsV1>,
-                pub narrow: Option<SymbolsV1>,
-                pub short: Option<SymbolsV1>,
-                pub wide: Option<SymbolsV1>,
+            pub struct StandAloneWidthsV1<'data> {
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub abbreviated: Option<SymbolsV1<'data>>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub narrow: Option<SymbolsV1<'data>>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub short: Option<SymbolsV1<'data>>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub wide: Option<SymbolsV1<'data>>,
             }
 
             #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
-            #[yoke(cloning_zcf)]
             #[cfg_attr(feature="provider_serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct ContextsV1 {
-                pub format: FormatWidthsV1,
-                pub stand_alone: Option<StandAloneWidthsV1>,
+            pub struct ContextsV1<'data> {
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub format: FormatWidthsV1<'data>,
+                #[cfg_attr(feature="provider_serde", serde(borrow))]
+                pub stand_alone: Option<StandAloneWidthsV1<'data>>,
             }
         };
     }
 
-symbols!(months, [Cow<'static, str>; 12]);
+symbols!(months, VarZeroVec<'data, str>);
 
-symbols!(weekdays, [Cow<'static, str>; 7]);
+symbols!(weekdays, VarZeroVec<'data, str>);
 
 symbols!(
     day_periods {
-        am: Cow<'static, str>,
-        pm: Cow<'static, str>,
-        noon: Option<Cow<'static, str>>,
-        midnight: Option<Cow<'static, str>>,
+        am: Cow<'data, str>,
+        pm: Cow<'data, str>,
+        noon: Option<Cow<'data, str>>,
+        midnight: Option<Cow<'data, str>>,
     }
 );
+
+pub mod eras {
+    use super::*;
+
+    /// The era names, indexed by the era code: `0` is BCE and `1` is CE.
+    #[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct SymbolsV1<'data>(
+        #[cfg_attr(feature = "provider_serde", serde(borrow))] pub VarZeroVec<'data, str>,
+    );
+
+    // Eras are only used in format context, and CLDR has no `short` width for them.
+    #[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroCopyFrom)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct ErasV1<'data> {
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub abbreviated: SymbolsV1<'data>,
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub narrow: SymbolsV1<'data>,
+        #[cfg_attr(feature = "provider_serde", serde(borrow))]
+        pub wide: SymbolsV1<'data>,
+    }
+}
+
+// The era names, starting with BCE.
+impl Default for eras::SymbolsV1<'_> {
+    fn default() -> Self {
+        Self((&[""; 2][..]).into())
+    }
+}
+
+// The month names, starting with January.
+impl Default for months::SymbolsV1<'_> {
+    fn default() -> Self {
+        Self((&[""; 12][..]).into())
+    }
+}
+
+// The weekday names, starting with Sunday.
+impl Default for weekdays::SymbolsV1<'_> {
+    fn default() -> Self {
+        Self((&[""; 7][..]).into())
+    }
+}
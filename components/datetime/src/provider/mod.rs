@@ -8,6 +8,7 @@
 
 #[cfg(doc)]
 use icu_provider::prelude::ResourceKey;
+use icu_provider::registry::RegisteredKeys;
 
 /// Data providers for the Gregorian Calendar.
 pub mod gregory;
@@ -58,3 +59,41 @@ pub mod key {
     pub const TIMEZONE_SPECIFIC_NAMES_SHORT_V1: ResourceKey =
         resource_key!(TimeZone, "specific_short", 1);
 }
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_datetime`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(
+        gregory::DatePatternsV1Marker,
+        [key::GREGORY_DATE_PATTERNS_V1]
+    ),
+    icu_provider::registered_keys!(
+        gregory::DateSkeletonPatternsV1Marker,
+        [key::GREGORY_DATE_SKELETON_PATTERNS_V1]
+    ),
+    icu_provider::registered_keys!(gregory::DateSymbolsV1Marker, [key::GREGORY_DATE_SYMBOLS_V1]),
+    icu_provider::registered_keys!(
+        time_zones::TimeZoneFormatsV1Marker,
+        [key::TIMEZONE_FORMATS_V1]
+    ),
+    icu_provider::registered_keys!(
+        time_zones::ExemplarCitiesV1Marker,
+        [key::TIMEZONE_EXEMPLAR_CITIES_V1]
+    ),
+    icu_provider::registered_keys!(
+        time_zones::MetaZoneGenericNamesLongV1Marker,
+        [key::TIMEZONE_GENERIC_NAMES_LONG_V1]
+    ),
+    icu_provider::registered_keys!(
+        time_zones::MetaZoneGenericNamesShortV1Marker,
+        [key::TIMEZONE_GENERIC_NAMES_SHORT_V1]
+    ),
+    icu_provider::registered_keys!(
+        time_zones::MetaZoneSpecificNamesLongV1Marker,
+        [key::TIMEZONE_SPECIFIC_NAMES_LONG_V1]
+    ),
+    icu_provider::registered_keys!(
+        time_zones::MetaZoneSpecificNamesShortV1Marker,
+        [key::TIMEZONE_SPECIFIC_NAMES_SHORT_V1]
+    ),
+];
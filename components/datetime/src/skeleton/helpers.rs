@@ -2,11 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use alloc::vec::Vec;
+use alloc::borrow::Cow;
 
 use crate::{
     fields::{self, Field, FieldLength, FieldSymbol},
-    options::{components, length},
+    options::{components, length, preferences},
     pattern::{
         hour_cycle,
         reference::{Pattern, PatternPlurals},
@@ -90,12 +90,14 @@ pub enum BestSkeleton<T> {
 
 /// This function swaps out the the time zone name field for the appropriate one. Skeleton matching
 /// only needs to find a single "v" field, and then the time zone name can expand from there.
+/// Patterns without a time zone field get the requested one appended, following the CLDR
+/// appendItems pattern "{0} {1}" for time zones.
 fn naively_apply_time_zone_name(
     pattern: &mut Pattern,
     time_zone_name: &Option<components::TimeZoneName>,
 ) {
-    // If there is a preference overiding the hour cycle, apply it now.
     if let Some(time_zone_name) = time_zone_name {
+        let mut has_time_zone = false;
         for item in pattern.items_mut() {
             if let PatternItem::Field(fields::Field {
                 symbol: fields::FieldSymbol::TimeZone(_),
@@ -103,18 +105,46 @@ fn naively_apply_time_zone_name(
             }) = item
             {
                 *item = PatternItem::Field((*time_zone_name).into());
+                has_time_zone = true;
             }
         }
+        if !has_time_zone {
+            // TODO(#586) - Use the appendItems data from the CLDR rather than a space.
+            pattern.items.push(PatternItem::Literal(' '));
+            pattern
+                .items
+                .push(PatternItem::Field((*time_zone_name).into()));
+        }
     }
 }
 
-// TODO - This could return a Cow<'a, Pattern>, but it affects every other part of the API to
-// add a lifetime here. The pattern returned here could be one that we've already constructed in
-// the CLDR as an exotic type, or it could be one that was modified to meet the requirements of
-// the components bag.
+/// Applies the hour cycle preference and the time zone name of the components bag to the
+/// patterns, only cloning borrowed patterns if the bag changes them.
+fn apply_components_to_patterns(
+    pattern_plurals: &mut Cow<'_, PatternPluralsV1>,
+    components: &components::Bag,
+) {
+    let has_hour_cycle = matches!(
+        components.preferences,
+        Some(preferences::Bag {
+            hour_cycle: Some(_),
+            ..
+        })
+    );
+    if !has_hour_cycle && components.time_zone_name.is_none() {
+        return;
+    }
+    for pattern in pattern_plurals.to_mut().0.patterns_iter_mut() {
+        hour_cycle::naively_apply_preferences(pattern, &components.preferences);
+        naively_apply_time_zone_name(pattern, &components.time_zone_name);
+    }
+}
 
 /// Given a set of fields (which represents a skeleton), try to create a best localized pattern
-// for those fields.
+/// for those fields.
+///
+/// The returned patterns are borrowed from `skeletons` unless they had to be modified to match
+/// the fields or the components bag, or were combined from a date and a time pattern.
 ///
 /// * `skeletons` - The skeletons that will be matched against
 /// * `length_patterns` - Contains information on how to combine date and time patterns.
@@ -125,24 +155,21 @@ fn naively_apply_time_zone_name(
 ///         done to mutate it to match the fields. It will prefer the actual matched pattern.
 pub fn create_best_pattern_for_fields<'a>(
     skeletons: &'a DateSkeletonPatternsV1,
-    length_patterns: &LengthPatternsV1,
+    length_patterns: &LengthPatternsV1<'_>,
     fields: &[Field],
     components: &components::Bag,
     prefer_matched_pattern: bool,
-) -> BestSkeleton<PatternPluralsV1> {
+) -> BestSkeleton<Cow<'a, PatternPluralsV1>> {
     let first_pattern_match =
         get_best_available_format_pattern(skeletons, fields, prefer_matched_pattern);
 
     // Try to match a skeleton to all of the fields.
     if let BestSkeleton::AllFieldsMatch(mut pattern_plurals) = first_pattern_match {
-        for pattern in pattern_plurals.0.patterns_iter_mut() {
-            hour_cycle::naively_apply_preferences(pattern, &components.preferences);
-            naively_apply_time_zone_name(pattern, &components.time_zone_name);
-        }
+        apply_components_to_patterns(&mut pattern_plurals, components);
         return BestSkeleton::AllFieldsMatch(pattern_plurals);
     }
 
-    let FieldsByType { date, time } = group_fields_by_type(fields);
+    let (date, time) = split_fields_by_type(fields);
 
     if date.is_empty() || time.is_empty() {
         return match first_pattern_match {
@@ -151,10 +178,7 @@ pub fn create_best_pattern_for_fields<'a>(
             }
             BestSkeleton::MissingOrExtraFields(mut pattern_plurals) => {
                 if date.is_empty() {
-                    for pattern in pattern_plurals.0.patterns_iter_mut() {
-                        hour_cycle::naively_apply_preferences(pattern, &components.preferences);
-                        naively_apply_time_zone_name(pattern, &components.time_zone_name);
-                    }
+                    apply_components_to_patterns(&mut pattern_plurals, components);
                 }
                 BestSkeleton::MissingOrExtraFields(pattern_plurals)
             }
@@ -163,22 +187,45 @@ pub fn create_best_pattern_for_fields<'a>(
     }
 
     // Match the date and time, and then simplify the combinatorial logic of the results into
-    // an optional values of the results, and a boolean value.
+    // an optional values of the results, and a boolean value. The patterns are combined below,
+    // which requires owning them.
     let (date_patterns, date_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &date, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields.0), true),
-            BestSkeleton::AllFieldsMatch(fields) => (Some(fields.0), false),
+        match get_best_available_format_pattern(skeletons, date, prefer_matched_pattern) {
+            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields.into_owned().0), true),
+            BestSkeleton::AllFieldsMatch(fields) => (Some(fields.into_owned().0), false),
             BestSkeleton::NoMatch => (None, true),
         };
 
+    // No skeleton consists of only a time zone, so a lone time zone is appended to the date
+    // pattern instead.
+    if let [Field {
+        symbol: FieldSymbol::TimeZone(_),
+        ..
+    }] = time
+    {
+        return match date_patterns {
+            Some(date_patterns) => {
+                let mut pattern_plurals = Cow::Owned(PatternPluralsV1(date_patterns));
+                apply_components_to_patterns(&mut pattern_plurals, components);
+                if date_missing_or_extra {
+                    BestSkeleton::MissingOrExtraFields(pattern_plurals)
+                } else {
+                    BestSkeleton::AllFieldsMatch(pattern_plurals)
+                }
+            }
+            None => BestSkeleton::NoMatch,
+        };
+    }
+
     let (time_patterns, time_missing_or_extra) =
-        match get_best_available_format_pattern(skeletons, &time, prefer_matched_pattern) {
+        match get_best_available_format_pattern(skeletons, time, prefer_matched_pattern) {
             BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
             BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
             BestSkeleton::NoMatch => (None, true),
         };
     let time_pattern = time_patterns.map(|pattern_plurals| {
         let mut pattern = pattern_plurals
+            .into_owned()
             .0
             .expect_pattern("Only date patterns can contain plural variants");
         hour_cycle::naively_apply_preferences(&mut pattern, &components.preferences);
@@ -231,7 +278,7 @@ pub fn create_best_pattern_for_fields<'a>(
                     for date_pattern in date_patterns.patterns_iter_mut() {
                         *date_pattern = Pattern::from_bytes_combination(
                             bytes,
-                            date_pattern.clone(),
+                            core::mem::take(date_pattern),
                             time_pattern.clone(),
                         )
                         .expect("Failed to create a Pattern from bytes");
@@ -253,51 +300,69 @@ pub fn create_best_pattern_for_fields<'a>(
     match patterns {
         Some(patterns) => {
             if date_missing_or_extra || time_missing_or_extra {
-                BestSkeleton::MissingOrExtraFields(PatternPluralsV1(patterns))
+                BestSkeleton::MissingOrExtraFields(Cow::Owned(PatternPluralsV1(patterns)))
             } else {
-                BestSkeleton::AllFieldsMatch(PatternPluralsV1(patterns))
+                BestSkeleton::AllFieldsMatch(Cow::Owned(PatternPluralsV1(patterns)))
             }
         }
         None => BestSkeleton::NoMatch,
     }
 }
 
-struct FieldsByType {
-    pub date: Vec<Field>,
-    pub time: Vec<Field>,
-}
-
-fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
-    let mut date = Vec::new();
-    let mut time = Vec::new();
-
-    for field in fields {
-        match field.symbol {
+/// Splits the requested fields into the date fields and the time fields.
+///
+/// The fields are sorted by their symbol, and all date symbols sort before all time symbols, so
+/// both are subslices of the fields and no allocation is needed.
+fn split_fields_by_type(fields: &[Field]) -> (&[Field], &[Field]) {
+    debug_assert!(
+        fields.windows(2).all(|f| f[0].symbol <= f[1].symbol),
+        "The fields are sorted."
+    );
+    let time_start = fields
+        .iter()
+        .position(|field| match field.symbol {
             // Date components:
             // Note: Weekdays are included in both time and date skeletons.
             //  - Time examples: "EBhm" "EBhms" "Ed" "Ehm" "EHm" "Ehms" "EHms"
             //  - Date examples: "GyMMMEd" "MEd" "MMMEd" "MMMMEd" "yMEd" "yMMMEd"
             //  - Solo example: "E"
-            FieldSymbol::Year(_)
+            FieldSymbol::Era
+            | FieldSymbol::Year(_)
             | FieldSymbol::Month(_)
             | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
-            | FieldSymbol::Weekday(_) => date.push(*field),
+            | FieldSymbol::Weekday(_) => false,
 
             // Time components:
             FieldSymbol::DayPeriod(_)
             | FieldSymbol::Hour(_)
             | FieldSymbol::Minute
             | FieldSymbol::Second(_)
-            | FieldSymbol::TimeZone(_) => time.push(*field),
-            // Other components
-            // TODO(#486)
-            // FieldSymbol::Era(_) => other.push(*field),
-            // Plus others...
-        };
-    }
+            | FieldSymbol::TimeZone(_) => true,
+        })
+        .unwrap_or_else(|| fields.len());
+    fields.split_at(time_start)
+}
+
+/// Returns the requested field that a field of a pattern should be replaced with to have the
+/// requested length, if any.
+fn adjusted_field(fields: &[Field], pattern_field: &Field) -> Option<Field> {
+    fields
+        .iter()
+        .find(|field| field.symbol == pattern_field.symbol)
+        .filter(|requested_field| {
+            requested_field.length != pattern_field.length
+                && requested_field.get_length_type() == pattern_field.get_length_type()
+        })
+        .copied()
+}
 
-    FieldsByType { date, time }
+/// Returns whether [`adjust_pattern_field_lengths`] would change the given Pattern.
+fn needs_field_length_adjustment(fields: &[Field], pattern: &Pattern) -> bool {
+    pattern.items().iter().any(|item| match item {
+        PatternItem::Field(pattern_field) => adjusted_field(fields, pattern_field).is_some(),
+        PatternItem::Literal(_) => false,
+    })
 }
 
 /// Alters given Pattern so that its fields have the same length as 'fields'.
@@ -306,15 +371,8 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
 fn adjust_pattern_field_lengths(fields: &[Field], pattern: &mut Pattern) {
     for item in pattern.items_mut() {
         if let PatternItem::Field(pattern_field) = item {
-            if let Some(requested_field) = fields
-                .iter()
-                .find(|field| field.symbol == pattern_field.symbol)
-            {
-                if requested_field.length != pattern_field.length
-                    && requested_field.get_length_type() == pattern_field.get_length_type()
-                {
-                    *pattern_field = *requested_field;
-                }
+            if let Some(requested_field) = adjusted_field(fields, pattern_field) {
+                *pattern_field = requested_field;
             }
         }
     }
@@ -336,11 +394,13 @@ fn adjust_pattern_field_lengths(fields: &[Field], pattern: &mut Pattern) {
 ///  * 2.6.2.2 Missing Skeleton Fields
 ///    - TODO(#586) - Using the CLDR appendItems field. Note: There is not agreement yet on how
 ///      much of this step to implement. See the issue for more information.
-pub fn get_best_available_format_pattern(
-    skeletons: &DateSkeletonPatternsV1,
+///
+/// The returned pattern is borrowed from `skeletons` unless its field lengths were adjusted.
+pub fn get_best_available_format_pattern<'a>(
+    skeletons: &'a DateSkeletonPatternsV1,
     fields: &[Field],
     prefer_matched_pattern: bool,
-) -> BestSkeleton<PatternPluralsV1> {
+) -> BestSkeleton<Cow<'a, PatternPluralsV1>> {
     let mut closest_format_pattern = None;
     let mut closest_distance: u32 = u32::MAX;
     let mut closest_missing_fields = 0;
@@ -423,14 +483,15 @@ pub fn get_best_available_format_pattern(
         }
     }
 
-    let mut closest_format_pattern = closest_format_pattern
-        .expect("At least one closest format pattern will always be found.")
-        .clone();
+    let closest_format_pattern =
+        closest_format_pattern.expect("At least one closest format pattern will always be found.");
 
     if closest_missing_fields == fields.len() {
         return BestSkeleton::NoMatch;
     }
 
+    let mut closest_format_pattern = Cow::Borrowed(closest_format_pattern);
+
     if closest_distance == NO_DISTANCE {
         return BestSkeleton::AllFieldsMatch(closest_format_pattern);
     }
@@ -439,8 +500,12 @@ pub fn get_best_available_format_pattern(
     if prefer_matched_pattern {
         #[cfg(not(feature = "provider_transform_internals"))]
         panic!("This code branch should only be run when transforming provider code.");
-    } else {
-        for pattern in closest_format_pattern.0.patterns_iter_mut() {
+    } else if closest_format_pattern
+        .0
+        .patterns_iter()
+        .any(|pattern| needs_field_length_adjustment(fields, pattern))
+    {
+        for pattern in closest_format_pattern.to_mut().0.patterns_iter_mut() {
             adjust_pattern_field_lengths(fields, pattern);
         }
     }
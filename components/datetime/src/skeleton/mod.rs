@@ -42,7 +42,7 @@ mod test {
         let provider = icu_testdata::get_provider();
         let langid = langid!("en");
         let patterns = provider
-            .load_payload(&DataRequest {
+            .load_payload_with_context(&DataRequest {
                 resource_path: ResourcePath {
                     key: GREGORY_DATE_PATTERNS_V1,
                     options: ResourceOptions {
@@ -55,7 +55,7 @@ mod test {
             .take_payload()
             .unwrap();
         let skeletons = provider
-            .load_payload(&DataRequest {
+            .load_payload_with_context(&DataRequest {
                 resource_path: ResourcePath {
                     key: GREGORY_DATE_SKELETON_PATTERNS_V1,
                     options: ResourceOptions {
@@ -77,7 +77,7 @@ mod test {
         let components = components::Bag {
             year: Some(components::Numeric::Numeric),
             month: Some(components::Month::Long),
-            day: Some(components::Numeric::Numeric),
+            day: Some(components::Day::NumericDayOfMonth),
 
             hour: Some(components::Numeric::Numeric),
             minute: Some(components::Numeric::Numeric),
@@ -94,7 +94,7 @@ mod test {
                 assert_eq!(
                     available_format_pattern
                         .0
-                        .expect_pattern("pattern should not have plural variants")
+                        .expect_pattern_ref("pattern should not have plural variants")
                         .to_string(),
                     String::from("MMMM d, y")
                 )
@@ -120,7 +120,7 @@ mod test {
                 assert_eq!(
                     available_format_pattern
                         .0
-                        .expect_pattern("pattern should not have plural variants")
+                        .expect_pattern_ref("pattern should not have plural variants")
                         .to_string(),
                     String::from("L")
                 )
@@ -129,14 +129,12 @@ mod test {
         };
     }
 
-    // TODO(#586) - Append items support needs to be added.
     #[test]
-    #[should_panic]
-    fn test_missing_append_items_support() {
+    fn test_skeleton_appends_time_zone() {
         let components = components::Bag {
             year: Some(components::Numeric::Numeric),
             month: Some(components::Month::Long),
-            day: Some(components::Numeric::Numeric),
+            day: Some(components::Day::NumericDayOfMonth),
             // This will be appended.
             time_zone_name: Some(components::TimeZoneName::LongSpecific),
             ..Default::default()
@@ -148,17 +146,16 @@ mod test {
             skeletons.get(),
             &patterns.get().length_combinations,
             &requested_fields,
-            &Default::default(),
+            &components,
             false,
         ) {
             BestSkeleton::AllFieldsMatch(available_format_pattern) => {
-                // TODO - Append items are needed here.
                 assert_eq!(
                     available_format_pattern
                         .0
-                        .expect_pattern("pattern should not have plural variants")
+                        .expect_pattern_ref("pattern should not have plural variants")
                         .to_string(),
-                    String::from("MMMM d, y vvvv")
+                    String::from("MMMM d, y zzzz")
                 )
             }
             best => panic!("Unexpected {:?}", best),
@@ -201,12 +198,14 @@ mod test {
     // https://gist.github.com/gregtatum/1d76bbdb87132f71a969a10f0c1d2d9c
 
     #[rustfmt::skip]
-    const SUPPORTED_STRING_SKELETONS: [&str; 61] = [
+    const SUPPORTED_STRING_SKELETONS: [&str; 70] = [
         "E", "dEEEE", "EHm", "EHms", "dE", "Ehm", "Ehms", "H", "HHmm", "HHmmss", "Hm", "Hms", "M",
         "MdEEEE", "MdE", "MMM", "MMMdEEEE", "MMMdE", "MMMM", "MMMMdEEEE", "MMMMdE", "MMMMd",
         "MMMMdd", "MMMd", "MMMdd", "MMd", "MMdd", "Md", "Mdd", "d", "h", "hm", "hms", "mmss", "ms",
         "y", "yM", "yMdEEEE", "yMdE", "yMM", "yMMM", "yMMMdEEEE", "yMMMdE", "yMMMM", "yMMMMdEEEE",
         "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd", "yw",
+        // Eras
+        "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // Timezones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
     ];
@@ -216,11 +215,9 @@ mod test {
     //       and then regenerate the test data.
     //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
     #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: [&str; 18] = [
+    const UNSUPPORTED_STRING_SKELETONS: [&str; 9] = [
         // TODO(#487) - Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
-        // TODO(#486) - Era
-        "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // TODO(#502) - Week of month
         "MMMMW",
         // TODO(#501) - Quarters
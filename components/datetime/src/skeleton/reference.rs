@@ -95,7 +95,8 @@ impl From<&Pattern> for Skeleton {
                     }
 
                     // Pass through all of the following preferences unchanged.
-                    FieldSymbol::Minute
+                    FieldSymbol::Era
+                    | FieldSymbol::Minute
                     | FieldSymbol::Second(_)
                     | FieldSymbol::TimeZone(_)
                     | FieldSymbol::Year(_)
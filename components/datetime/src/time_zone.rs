@@ -33,7 +33,7 @@ where
     if destination.is_none() {
         *destination = Some(
             provider
-                .load_payload(&DataRequest {
+                .load_payload_with_context(&DataRequest {
                     resource_path: ResourcePath {
                         key: resource_key,
                         options: ResourceOptions {
@@ -150,7 +150,7 @@ impl<'data> TimeZoneFormat<'data> {
         let locale = locale.into();
 
         let zone_formats: DataPayload<TimeZoneFormatsV1Marker> = zone_provider
-            .load_payload(&DataRequest {
+            .load_payload_with_context(&DataRequest {
                 resource_path: ResourcePath {
                     key: provider::key::TIMEZONE_FORMATS_V1,
                     options: ResourceOptions {
@@ -200,8 +200,9 @@ impl<'data> TimeZoneFormat<'data> {
                         zone_provider,
                     )?,
                     _ => {
-                        return Err(DateTimeFormatError::Pattern(
+                        return Err(DateTimeFormatError::invalid_pattern(
                             PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
+                            &time_zone_format.pattern,
                         ))
                     }
                 },
@@ -235,8 +236,9 @@ impl<'data> TimeZoneFormat<'data> {
                         )?;
                     }
                     _ => {
-                        return Err(DateTimeFormatError::Pattern(
+                        return Err(DateTimeFormatError::invalid_pattern(
                             PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
+                            &time_zone_format.pattern,
                         ))
                     }
                 },
@@ -250,8 +252,9 @@ impl<'data> TimeZoneFormat<'data> {
                         zone_provider,
                     )?,
                     _ => {
-                        return Err(DateTimeFormatError::Pattern(
+                        return Err(DateTimeFormatError::invalid_pattern(
                             PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
+                            &time_zone_format.pattern,
                         ))
                     }
                 },
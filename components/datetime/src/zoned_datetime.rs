@@ -8,7 +8,7 @@ use icu_plurals::{provider::PluralRuleStringsV1Marker, PluralRuleType, PluralRul
 use icu_provider::{DataProvider, DataRequest, ResourceOptions, ResourcePath};
 
 use crate::{
-    date::ZonedDateTimeInput,
+    date::{self, ZonedDateTimeInput},
     datetime::DateTimeFormat,
     format::{
         datetime,
@@ -123,13 +123,23 @@ impl<'data> ZonedDateTimeFormat<'data> {
         let locale = locale.into();
         let langid: LanguageIdentifier = locale.clone().into();
 
-        let patterns = provider::date_time::patterns_for_options(date_provider, &locale, options)?
-            .unwrap_or_default();
+        let (patterns, resolved_locale) =
+            provider::date_time::patterns_for_options(date_provider, &locale, options)?;
+        let patterns = patterns.unwrap_or_default();
+        let era_patterns =
+            provider::date_time::era_patterns_for_options(date_provider, &locale, options)?;
 
-        let requires_data = datetime::analyze_patterns(&patterns, true)
-            .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
+        let requires_data = datetime::analyze_patterns(&patterns, true)?
+            || era_patterns
+                .as_ref()
+                .map(|era_patterns| datetime::analyze_patterns(era_patterns, true))
+                .transpose()?
+                .unwrap_or(false);
 
-        let ordinal_rules = if let PatternPlurals::MultipleVariants(_) = &patterns {
+        let ordinal_rules = if let (PatternPlurals::MultipleVariants(_), _)
+        | (_, Some(PatternPlurals::MultipleVariants(_))) =
+            (&patterns, &era_patterns)
+        {
             Some(PluralRules::try_new(
                 langid.clone(),
                 plural_provider,
@@ -142,7 +152,7 @@ impl<'data> ZonedDateTimeFormat<'data> {
         let symbols_data = if requires_data {
             Some(
                 date_provider
-                    .load_payload(&DataRequest {
+                    .load_payload_with_context(&DataRequest {
                         resource_path: ResourcePath {
                             key: provider::key::GREGORY_DATE_SYMBOLS_V1,
                             options: ResourceOptions {
@@ -157,7 +167,20 @@ impl<'data> ZonedDateTimeFormat<'data> {
             None
         };
 
-        let datetime_format = DateTimeFormat::new(locale, patterns, symbols_data, ordinal_rules);
+        let first_weekday = date::resolve_first_weekday(
+            &locale,
+            options.preferences().and_then(|prefs| prefs.first_weekday),
+        );
+
+        let datetime_format = DateTimeFormat::new(
+            locale,
+            patterns,
+            era_patterns,
+            symbols_data,
+            ordinal_rules,
+            first_weekday,
+            resolved_locale,
+        );
         let time_zone_format = TimeZoneFormat::try_new(
             datetime_format.locale.clone(),
             datetime_format
@@ -176,6 +199,36 @@ impl<'data> ZonedDateTimeFormat<'data> {
         })
     }
 
+    /// Returns the locale of the patterns loaded by [`ZonedDateTimeFormat::try_new`], which
+    /// differs from the requested locale if the data provider fell back to a more general locale,
+    /// such as `en-001` for `en-IN`.
+    ///
+    /// Returns `None` if the data provider did not report the locale of its data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::{options::length, ZonedDateTimeFormat};
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu_provider::fallback::LocaleFallbackProvider;
+    ///
+    /// let provider = LocaleFallbackProvider::new(icu_testdata::get_provider());
+    /// let locale: Locale = langid!("en-IN").into();
+    /// let options = length::Bag {
+    ///     date: Some(length::Date::Medium),
+    ///     time: Some(length::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let zdtf = ZonedDateTimeFormat::try_new(locale, &provider, &provider, &provider, &options.into())
+    ///     .expect("Failed to create ZonedDateTimeFormat instance.");
+    ///
+    /// assert_eq!(zdtf.resolved_locale(), Some(&langid!("en-001")));
+    /// ```
+    pub fn resolved_locale(&self) -> Option<&LanguageIdentifier> {
+        self.datetime_format.resolved_locale()
+    }
+
     /// Takes a [`ZonedDateTimeInput`] implementer and returns an instance of a [`FormattedZonedDateTime`]
     /// that contains all information necessary to display a formatted zoned datetime and operate on it.
     ///
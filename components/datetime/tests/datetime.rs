@@ -34,6 +34,7 @@ use patterns::{
 use std::borrow::Cow;
 use std::fmt::Write;
 use tinystr::tinystr8;
+use writeable::assert_writeable_eq;
 
 struct MultiKeyStructProvider<'data> {
     pub symbols: StructProvider<'data, DateSymbolsV1Marker>,
@@ -110,6 +111,7 @@ fn test_fixture(fixture_name: &str) {
             let fdt = dtf.format(&input_value);
             let s = fdt.to_string();
             assert_eq!(s, output_value, "{}", description);
+            assert_writeable_eq!(output_value, &fdt, "{}", description);
 
             let mut s = String::new();
             write!(s, "{}", fdt).unwrap();
@@ -157,6 +159,7 @@ fn test_fixture_with_time_zones(fixture_name: &str, config: TimeZoneConfig) {
             let fdt = dtf.format(&input_value);
             let s = fdt.to_string();
             assert_eq!(s, output_value, "{}", description);
+            assert_writeable_eq!(output_value, &fdt, "{}", description);
 
             let mut s = String::new();
             write!(s, "{}", fdt).unwrap();
@@ -218,11 +221,12 @@ fn test_dayperiod_patterns() {
                 let datetime = parse_gregorian_from_str(dt_input).unwrap();
                 for DayPeriodExpectation { patterns, expected } in &test_case.expectations {
                     for pattern_input in patterns {
-                        let new_pattern_cow1 = Cow::Owned(pattern_input.to_string());
-                        let new_pattern_cow2 = Cow::Owned(pattern_input.to_string());
+                        let new_pattern_cow: Cow<str> = Cow::Owned(pattern_input.to_string());
                         patterns_data.with_mut(move |data| {
-                            data.time_h11_h12.long = new_pattern_cow1;
-                            data.time_h23_h24.long = new_pattern_cow2;
+                            data.time_h11.long = new_pattern_cow.clone();
+                            data.time_h12.long = new_pattern_cow.clone();
+                            data.time_h23.long = new_pattern_cow.clone();
+                            data.time_h24.long = new_pattern_cow;
                         });
                         let local_provider = MultiKeyStructProvider {
                             symbols: StructProvider {
@@ -323,11 +327,12 @@ fn test_time_zone_patterns() {
 
         for TimeZoneExpectation { patterns, expected } in &test.expectations {
             for pattern_input in patterns {
-                let new_pattern_cow1 = Cow::Owned(pattern_input.to_string());
-                let new_pattern_cow2 = Cow::Owned(pattern_input.to_string());
+                let new_pattern_cow: Cow<str> = Cow::Owned(pattern_input.to_string());
                 patterns_data.with_mut(move |data| {
-                    data.time_h11_h12.long = new_pattern_cow1;
-                    data.time_h23_h24.long = new_pattern_cow2;
+                    data.time_h11.long = new_pattern_cow.clone();
+                    data.time_h12.long = new_pattern_cow.clone();
+                    data.time_h23.long = new_pattern_cow.clone();
+                    data.time_h24.long = new_pattern_cow;
                 });
                 let local_provider = MultiKeyStructProvider {
                     symbols: StructProvider {
@@ -390,6 +395,12 @@ fn test_lengths_with_preferences() {
     test_fixture("lengths_with_preferences");
 }
 
+#[test]
+fn test_lengths_with_widths() {
+    // components/datetime/tests/fixtures/tests/lengths_with_widths.json
+    test_fixture("lengths_with_widths");
+}
+
 /// Tests component::Bag configurations that have exact matches to CLDR skeletons.
 #[test]
 fn test_components_exact_matches() {
@@ -424,6 +435,13 @@ fn test_components_combine_datetime() {
     test_fixture("components-combine-datetime");
 }
 
+/// Tests that component::Bags display the era according to the era display option.
+#[test]
+fn test_components_era_display() {
+    // components/datetime/tests/fixtures/tests/components-era-display.json
+    test_fixture("components-era-display");
+}
+
 #[test]
 fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
     use icu_datetime::{
@@ -0,0 +1,277 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compact decimal formatting, such as "1.2K" or "1.2 thousand".
+
+use crate::error::Error;
+use crate::format::FormattedFixedDecimal;
+use crate::options::*;
+use crate::provider::*;
+use crate::range::FormattedCompactDecimalRange;
+use crate::FixedDecimalFormat;
+use core::convert::TryFrom;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::{LengthHint, Writeable};
+
+/// A formatter for [`FixedDecimal`] in compact notation, such as "1.2K" or "1.2 thousand".
+///
+/// The number is scaled according to the compact decimal patterns of the locale, rounded, and
+/// formatted with the pattern matching its plural category.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::CompactDecimalFormat;
+/// use icu::locid::Locale;
+/// use writeable::{LengthHint, Writeable};
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let cdf = CompactDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!("999", cdf.format(&999.into()).write_to_string());
+/// assert_eq!("1.2K", cdf.format(&1234.into()).write_to_string());
+/// assert_eq!("12K", cdf.format(&12345.into()).write_to_string());
+/// assert_eq!("1M", cdf.format(&999999.into()).write_to_string());
+/// assert_eq!("-5.7G", cdf.format(&(-5_678_000_000i64).into()).write_to_string());
+/// ```
+///
+/// The number of significant digits can be set in the options:
+///
+/// ```
+/// use icu::decimal::options::CompactDecimalFormatOptions;
+/// use icu::decimal::CompactDecimalFormat;
+/// use icu::locid::Locale;
+/// use writeable::{LengthHint, Writeable};
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let options = CompactDecimalFormatOptions {
+///     significant_digits: Some(3),
+///     ..Default::default()
+/// };
+/// let cdf = CompactDecimalFormat::try_new(Locale::und(), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!("1.23K", cdf.format(&1234.into()).write_to_string());
+/// assert_eq!("123K", cdf.format(&123456.into()).write_to_string());
+/// assert_eq!("2M", cdf.format(&2_000_000.into()).write_to_string());
+/// ```
+pub struct CompactDecimalFormat<'data> {
+    options: CompactDecimalFormatOptions,
+    fdf: FixedDecimalFormat<'data>,
+    plural_rules: PluralRules,
+    patterns: DataPayload<'data, CompactDecimalPatternDataV1Marker>,
+}
+
+impl<'data> CompactDecimalFormat<'data> {
+    /// Creates a new [`CompactDecimalFormat`] from locale data and an options bag.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: CompactDecimalFormatOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DecimalSymbolsV1Marker>
+            + DataProvider<'data, CompactDecimalPatternDataV1Marker>
+            + DataProvider<'data, PluralRuleStringsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let fdf = FixedDecimalFormat::try_new(
+            locale.clone(),
+            data_provider,
+            FixedDecimalFormatOptions {
+                grouping_strategy: options.grouping_strategy,
+                sign_display: options.sign_display,
+                numbering_system: options.numbering_system,
+            },
+        )?;
+        let plural_rules =
+            PluralRules::try_new(locale.id.clone(), data_provider, PluralRuleType::Cardinal)?;
+        let key = match options.style {
+            CompactStyle::Short => key::COMPACT_SHORT_V1,
+            CompactStyle::Long => key::COMPACT_LONG_V1,
+        };
+        let patterns = data_provider
+            .load_payload_with_context(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.id),
+                    },
+                },
+            })?
+            .take_payload()?;
+        Ok(Self {
+            options,
+            fdf,
+            plural_rules,
+            patterns,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] in compact notation, returning a [`FormattedCompactDecimal`].
+    pub fn format(&self, value: &FixedDecimal) -> FormattedCompactDecimal<'_> {
+        let patterns = self.patterns.get();
+        let mut magnitude = value.nonzero_magnitude_start();
+        let mut exponent = self.exponent_for(magnitude);
+        let mut scaled = self.scale_and_round(value, exponent);
+        // Rounding may carry into the next magnitude, such as 999,999 becoming "1000K"; in
+        // that case, the number is formatted again with the patterns of that magnitude.
+        let rounded_magnitude = scaled.nonzero_magnitude_start().saturating_add(exponent);
+        if rounded_magnitude > magnitude && self.exponent_for(rounded_magnitude) != exponent {
+            magnitude = rounded_magnitude;
+            exponent = self.exponent_for(magnitude);
+            scaled = self.scale_and_round(value, exponent);
+        }
+
+        let operands = u8::try_from(exponent)
+            .ok()
+            .and_then(|exponent| PluralOperands::try_from_compact(&scaled, exponent).ok())
+            .unwrap_or_else(|| PluralOperands::from(&scaled));
+        let category = self.plural_rules.select(operands);
+        let pattern = patterns.patterns_for(magnitude).and_then(|patterns| {
+            patterns
+                .get(&category)
+                .or_else(|| patterns.get(&PluralCategory::Other))
+        });
+
+        FormattedCompactDecimal {
+            value: scaled,
+            pattern,
+            options: &self.fdf.options,
+            symbols: self.fdf.symbols.get(),
+        }
+    }
+
+    /// Formats a range of [`FixedDecimal`]s in compact notation, returning a
+    /// [`FormattedCompactDecimalRange`].
+    ///
+    /// Compact affixes shared by both numbers are written once, and a range whose numbers are
+    /// formatted the same is formatted as an approximate number. See the [`range`](crate::range)
+    /// module for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::CompactDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_provider::inv::InvariantDataProvider;
+    /// let cdf = CompactDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// let format_range = |start: i64, end: i64| {
+    ///     cdf.format_range(&start.into(), &end.into()).write_to_string()
+    /// };
+    /// assert_eq!("3–5K", format_range(3_000, 5_000));
+    /// assert_eq!("999–1.2K", format_range(999, 1_234));
+    /// assert_eq!("~1.2K", format_range(1_230, 1_240));
+    /// ```
+    pub fn format_range(
+        &self,
+        start: &FixedDecimal,
+        end: &FixedDecimal,
+    ) -> FormattedCompactDecimalRange<'_> {
+        FormattedCompactDecimalRange {
+            start: self.format(start),
+            end: self.format(end),
+        }
+    }
+
+    /// Returns the power of 10 by which a number of the given magnitude is divided.
+    fn exponent_for(&self, magnitude: i16) -> i16 {
+        self.patterns
+            .get()
+            .patterns_for(magnitude)
+            .and_then(|patterns| patterns.get(&PluralCategory::Other))
+            .map(|pattern| pattern.exponent)
+            .unwrap_or(0)
+    }
+
+    /// Divides the number by 10^`exponent` and rounds it according to the options.
+    fn scale_and_round(&self, value: &FixedDecimal, exponent: i16) -> FixedDecimal {
+        let mut scaled = value
+            .clone()
+            .multiplied_pow10(-exponent)
+            .unwrap_or_else(|_| value.clone());
+        let magnitude = scaled.nonzero_magnitude_start();
+        let position = match self.options.significant_digits {
+            Some(digits) => magnitude.saturating_sub(i16::from(digits.max(1)) - 1),
+            None => core::cmp::min(0, magnitude.saturating_sub(1)),
+        };
+        scaled.half_even(position);
+        scaled.trim_end();
+        scaled
+    }
+}
+
+/// An intermediate structure returned by [`CompactDecimalFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedCompactDecimal<'l> {
+    pub(crate) value: FixedDecimal,
+    pub(crate) pattern: Option<&'l CompactPatternV1<'l>>,
+    pub(crate) options: &'l FixedDecimalFormatOptions,
+    pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+}
+
+impl<'l> FormattedCompactDecimal<'l> {
+    pub(crate) fn formatted_number(&self) -> FormattedFixedDecimal<'_> {
+        FormattedFixedDecimal {
+            value: &self.value,
+            options: self.options,
+            symbols: self.symbols,
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedCompactDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        // The sign goes around the compact pattern, such as "-US$1K", rather than between the
+        // pattern and the digits.
+        let number = self.formatted_number();
+        let sign = number.get_affixes();
+        if let Some(sign) = sign {
+            sink.write_str(&sign.prefix)?;
+        }
+        if let Some(pattern) = self.pattern {
+            sink.write_str(&pattern.affixes.prefix)?;
+        }
+        number.write_digits(sink)?;
+        if let Some(pattern) = self.pattern {
+            sink.write_str(&pattern.affixes.suffix)?;
+        }
+        if let Some(sign) = sign {
+            sink.write_str(&sign.suffix)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let affixes_len = self.pattern.map_or(0, |pattern| {
+            pattern.affixes.prefix.len() + pattern.affixes.suffix.len()
+        });
+        self.formatted_number().write_len() + affixes_len
+    }
+}
+
+impl<'l> fmt::Display for FormattedCompactDecimal<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
@@ -11,6 +11,8 @@ use displaydoc::Display;
 pub enum Error {
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
 }
 
 #[cfg(feature = "std")]
@@ -21,3 +23,9 @@ impl From<icu_provider::DataError> for Error {
         Error::Data(e)
     }
 }
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
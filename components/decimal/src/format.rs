@@ -8,8 +8,9 @@ use crate::grouper;
 use crate::options::*;
 use crate::provider::*;
 use crate::sign_selector;
-use fixed_decimal::FixedDecimal;
-use writeable::Writeable;
+use core::fmt;
+use fixed_decimal::{FixedDecimal, FloatingDecimal};
+use writeable::{LengthHint, Writeable};
 
 /// An intermediate structure returned by [`FixedDecimalFormat`](crate::FixedDecimalFormat).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
@@ -21,7 +22,8 @@ pub struct FormattedFixedDecimal<'l> {
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
-    fn get_affixes(&self) -> Option<&AffixesV1> {
+    /// Returns the affixes of the sign of the number, if it is displayed.
+    pub(crate) fn get_affixes(&self) -> Option<&AffixesV1> {
         use sign_selector::SignSelection::*;
         match sign_selector::select(self.value.signum(), self.options.sign_display) {
             Minus => Some(&self.symbols.minus_sign_affixes),
@@ -29,17 +31,12 @@ impl<'l> FormattedFixedDecimal<'l> {
             Plus => Some(&self.symbols.plus_sign_affixes),
         }
     }
-}
 
-impl<'l> Writeable for FormattedFixedDecimal<'l> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    /// Writes the digits and separators of the number, without the sign.
+    pub(crate) fn write_digits<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
     where
         W: core::fmt::Write + ?Sized,
     {
-        let affixes = self.get_affixes();
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.prefix)?;
-        }
         let range = self.value.magnitude_range();
         let upper_magnitude = *range.end();
         for m in range.rev() {
@@ -57,9 +54,136 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
                 sink.write_str(&self.symbols.grouping_separator)?;
             }
         }
+        Ok(())
+    }
+
+    /// Returns the length of [`Self::write_digits()`].
+    pub(crate) fn digits_len(&self) -> usize {
+        let mut len = 0;
+        let range = self.value.magnitude_range();
+        let upper_magnitude = *range.end();
+        for m in range {
+            if m == -1 {
+                len += self.symbols.decimal_separator.len();
+            }
+            let d = self.value.digit_at(m);
+            len += self.symbols.digits[d as usize].len_utf8();
+            if grouper::check(
+                upper_magnitude,
+                m,
+                self.options.grouping_strategy,
+                &self.symbols.grouping_sizes,
+            ) {
+                len += self.symbols.grouping_separator.len();
+            }
+        }
+        len
+    }
+}
+
+impl<'l> Writeable for FormattedFixedDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let affixes = self.get_affixes();
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.prefix)?;
+        }
+        self.write_digits(sink)?;
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.suffix)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let affixes_len = self
+            .get_affixes()
+            .map_or(0, |affixes| affixes.prefix.len() + affixes.suffix.len());
+        LengthHint::Exact(self.digits_len() + affixes_len)
+    }
+}
+
+impl<'l> fmt::Display for FormattedFixedDecimal<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// An intermediate structure returned by
+/// [`FixedDecimalFormat::format_floating`](crate::FixedDecimalFormat::format_floating).
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedFloatingDecimal<'l> {
+    pub(crate) value: &'l FloatingDecimal,
+    pub(crate) options: &'l FixedDecimalFormatOptions,
+    pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+}
+
+impl<'l> FormattedFloatingDecimal<'l> {
+    fn get_affixes(&self) -> Option<&AffixesV1> {
+        use sign_selector::SignSelection::*;
+        // NaN has no sign.
+        let signum = self.value.signum()?;
+        match sign_selector::select(signum, self.options.sign_display) {
+            Minus => Some(&self.symbols.minus_sign_affixes),
+            Neither => None,
+            Plus => Some(&self.symbols.plus_sign_affixes),
+        }
+    }
+
+    fn get_symbol(&self) -> &str {
+        match self.value {
+            FloatingDecimal::NaN => &self.symbols.nan,
+            _ => &self.symbols.infinity,
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedFloatingDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        if let FloatingDecimal::Finite(value) = self.value {
+            return FormattedFixedDecimal {
+                value,
+                options: self.options,
+                symbols: self.symbols,
+            }
+            .write_to(sink);
+        }
+        let affixes = self.get_affixes();
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.prefix)?;
+        }
+        sink.write_str(self.get_symbol())?;
         if let Some(affixes) = affixes {
             sink.write_str(&affixes.suffix)?;
         }
         Ok(())
     }
+
+    fn write_len(&self) -> LengthHint {
+        if let FloatingDecimal::Finite(value) = self.value {
+            return FormattedFixedDecimal {
+                value,
+                options: self.options,
+                symbols: self.symbols,
+            }
+            .write_len();
+        }
+        let mut len = self.get_symbol().len();
+        if let Some(affixes) = self.get_affixes() {
+            len += affixes.prefix.len() + affixes.suffix.len();
+        }
+        LengthHint::Exact(len)
+    }
+}
+
+impl<'l> fmt::Display for FormattedFloatingDecimal<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
 }
@@ -125,7 +125,7 @@ fn test_grouper() {
             };
             let fdf =
                 FixedDecimalFormat::try_new(LanguageIdentifier::und(), &provider, options).unwrap();
-            let actual = fdf.format(&dec).writeable_to_string();
+            let actual = fdf.format(&dec).write_to_string();
             assert_eq!(cas.expected[i], actual, "{:?}", cas);
         }
     }
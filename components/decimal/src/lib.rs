@@ -7,9 +7,12 @@
 //! [`icu_decimal`](crate) offers localized decimal number formatting.
 //!
 //! Currently, [`icu_decimal`](crate) provides [`FixedDecimalFormat`], which renders basic decimal numbers
-//! in a locale-sensitive way.
+//! in a locale-sensitive way, [`CompactDecimalFormat`], which renders numbers in compact notation
+//! such as "1.2K", and [`OrdinalFormat`], which renders ordinal numbers such as "2nd".
+//! Ranges of numbers, such as "3–5", can be formatted with [`FixedDecimalFormat::format_range`]
+//! and [`CompactDecimalFormat::format_range`].
 //!
-//! Support for currencies, measurement units, and compact notation is planned. To track progress,
+//! Support for currencies and measurement units is planned. To track progress,
 //! follow this issue:
 //!
 //! https://github.com/unicode-org/icu4x/issues/275
@@ -31,7 +34,7 @@
 //!
 //! let fixed_decimal = 1000007.into();
 //! let formatted_value = fdf.format(&fixed_decimal);
-//! let formatted_str = formatted_value.writeable_to_string();
+//! let formatted_str = formatted_value.write_to_string();
 //!
 //! assert_eq!("১০,০০,০০৭", formatted_str);
 //! ```
@@ -53,28 +56,54 @@
 //!     .multiplied_pow10(-2)
 //!     .expect("Operation is fully in range");
 //!
-//! assert_eq!("2,000.50", fdf.format(&fixed_decimal).writeable_to_string());
+//! assert_eq!("2,000.50", fdf.format(&fixed_decimal).write_to_string());
+//! ```
+//!
+//! ## Format a number in compact notation
+//!
+//! ```
+//! use icu::decimal::CompactDecimalFormat;
+//! use icu::locid::Locale;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_provider::inv::InvariantDataProvider;
+//! let cdf = CompactDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!("1.2M", cdf.format(&1234567.into()).write_to_string());
 //! ```
 //!
 //! [`FixedDecimalFormat`]: FixedDecimalFormat
+//! [`CompactDecimalFormat`]: CompactDecimalFormat
+//! [`OrdinalFormat`]: OrdinalFormat
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 extern crate alloc;
 
+pub mod compact;
 pub mod error;
 pub mod format;
 mod grouper;
 pub mod options;
+pub mod ordinal;
 pub mod provider;
+pub mod range;
 mod sign_selector;
 
+pub use compact::{CompactDecimalFormat, FormattedCompactDecimal};
 pub use error::Error as FixedDecimalFormatError;
-pub use format::FormattedFixedDecimal;
+pub use format::{FormattedFixedDecimal, FormattedFloatingDecimal};
+pub use ordinal::{FormattedOrdinal, OrdinalFormat};
+pub use range::{FormattedCompactDecimalRange, FormattedDecimalRange};
 
-use fixed_decimal::FixedDecimal;
-use icu_locid::Locale;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use fixed_decimal::{FixedDecimal, FloatingDecimal};
+use icu_locid::extensions::unicode::Key;
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
+use tinystr::tinystr4;
 
 /// A formatter for [`FixedDecimal`], rendering decimal digits in an i18n-friendly way.
 ///
@@ -90,10 +119,16 @@ use icu_provider::prelude::*;
 pub struct FixedDecimalFormat<'data> {
     options: options::FixedDecimalFormatOptions,
     symbols: DataPayload<'data, provider::DecimalSymbolsV1Marker>,
+    resolved_locale: Option<LanguageIdentifier>,
 }
 
 impl<'data> FixedDecimalFormat<'data> {
     /// Creates a new [`FixedDecimalFormat`] from locale data and an options bag.
+    ///
+    /// The numbering system is selected by [`FixedDecimalFormatOptions::numbering_system`],
+    /// or else by the `-u-nu-` keyword of the locale, such as `th-u-nu-thai`.
+    ///
+    /// [`FixedDecimalFormatOptions::numbering_system`]: options::FixedDecimalFormatOptions::numbering_system
     pub fn try_new<
         T: Into<Locale>,
         D: DataProvider<'data, provider::DecimalSymbolsV1Marker> + ?Sized,
@@ -102,18 +137,71 @@ impl<'data> FixedDecimalFormat<'data> {
         data_provider: &D,
         options: options::FixedDecimalFormatOptions,
     ) -> Result<Self, FixedDecimalFormatError> {
-        let symbols = data_provider
-            .load_payload(&DataRequest {
+        let locale = locale.into();
+        let load = |variant: Option<Cow<'static, str>>| -> Result<
+            DataResponse<'data, provider::DecimalSymbolsV1Marker>,
+            DataError,
+        > {
+            data_provider.load_payload_with_context(&DataRequest {
                 resource_path: ResourcePath {
                     key: provider::key::SYMBOLS_V1,
                     options: ResourceOptions {
-                        variant: None,
-                        langid: Some(locale.into().into()),
+                        variant,
+                        langid: Some(locale.id.clone()),
                     },
                 },
-            })?
-            .take_payload()?;
-        Ok(Self { options, symbols })
+            })
+        };
+        let nu_key = Key::from_tinystr4_unchecked(tinystr4!("nu"));
+        let response = match options.numbering_system {
+            Some(numbering_system) => load(Some(Cow::Owned(numbering_system.to_string())))?,
+            None => match locale.extensions.unicode.keywords.get(nu_key) {
+                Some(value) => match load(Some(Cow::Owned(value.to_string()))) {
+                    // Numbering systems that are not supported by the data are ignored.
+                    Err(err)
+                        if matches!(
+                            err.without_context(),
+                            DataError::MissingResourceOptions(_)
+                        ) =>
+                    {
+                        load(None)?
+                    }
+                    result => result?,
+                },
+                None => load(None)?,
+            },
+        };
+        let resolved_locale = response.metadata.data_langid.clone();
+        Ok(Self {
+            options,
+            symbols: response.take_payload()?,
+            resolved_locale,
+        })
+    }
+
+    /// Returns the locale of the symbols loaded by [`FixedDecimalFormat::try_new`], which differs
+    /// from the requested locale if the data provider fell back to a more general locale, such as
+    /// `en-001` for `en-IN`.
+    ///
+    /// Returns `None` if the data provider did not report the locale of its data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu_provider::fallback::LocaleFallbackProvider;
+    ///
+    /// let provider = LocaleFallbackProvider::new(icu_testdata::get_provider());
+    /// let locale: Locale = langid!("en-IN").into();
+    /// let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// assert_eq!(fdf.resolved_locale(), Some(&langid!("en-001")));
+    /// ```
+    pub fn resolved_locale(&self) -> Option<&LanguageIdentifier> {
+        self.resolved_locale.as_ref()
     }
 
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
@@ -124,4 +212,96 @@ impl<'data> FixedDecimalFormat<'data> {
             symbols: self.symbols.get(),
         }
     }
+
+    /// Returns the symbols used by this formatter, such as the decimal separator and the digits
+    /// of the numbering system.
+    ///
+    /// This is useful for code that needs to agree with the formatter, such as a parser of
+    /// user input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    ///
+    /// let locale: Locale = langid!("bn").into();
+    /// let provider = icu_testdata::get_provider();
+    /// let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// let symbols = fdf.symbols();
+    /// assert_eq!(".", symbols.decimal_separator);
+    /// assert_eq!(",", symbols.grouping_separator);
+    /// assert_eq!('৭', symbols.digits[7]);
+    /// assert_eq!("-", symbols.minus_sign_affixes.prefix);
+    /// ```
+    pub fn symbols(&self) -> &provider::DecimalSymbolsV1 {
+        self.symbols.get()
+    }
+
+    /// Formats a range of [`FixedDecimal`]s, returning a [`FormattedDecimalRange`].
+    ///
+    /// The numbers are joined with the range pattern of the locale, such as "3–5". If the
+    /// numbers are equal, the number is formatted once with the approximately sign of the
+    /// locale, such as "~5". See the [`range`] module for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_provider::inv::InvariantDataProvider;
+    /// let fdf = FixedDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// assert_eq!("3–5", fdf.format_range(&3.into(), &5.into()).write_to_string());
+    /// assert_eq!("-5–-3", fdf.format_range(&(-5).into(), &(-3).into()).write_to_string());
+    /// assert_eq!("~5", fdf.format_range(&5.into(), &5.into()).write_to_string());
+    /// ```
+    pub fn format_range<'l>(
+        &'l self,
+        start: &'l FixedDecimal,
+        end: &'l FixedDecimal,
+    ) -> FormattedDecimalRange<'l> {
+        FormattedDecimalRange {
+            start: self.format(start),
+            end: self.format(end),
+        }
+    }
+
+    /// Formats a [`FloatingDecimal`], returning a [`FormattedFloatingDecimal`].
+    ///
+    /// Finite values are formatted like with [`FixedDecimalFormat::format`]. Infinite and NaN
+    /// values are rendered with the symbols of the locale, such as "∞" and "NaN", where infinity
+    /// takes a plus or minus sign like a finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FloatingDecimal;
+    /// use icu::decimal::FixedDecimalFormat;
+    /// use icu::locid::Locale;
+    /// use writeable::Writeable;
+    ///
+    /// let provider = icu_provider::inv::InvariantDataProvider;
+    /// let fdf = FixedDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+    ///     .expect("Data should load successfully");
+    ///
+    /// let value = FloatingDecimal::NegativeInfinity;
+    /// assert_eq!("-∞", fdf.format_floating(&value).write_to_string());
+    /// ```
+    pub fn format_floating<'l>(
+        &'l self,
+        value: &'l FloatingDecimal,
+    ) -> FormattedFloatingDecimal<'l> {
+        FormattedFloatingDecimal {
+            value,
+            options: &self.options,
+            symbols: self.symbols.get(),
+        }
+    }
 }
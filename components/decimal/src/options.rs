@@ -2,7 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for [`FixedDecimalFormat`](crate::FixedDecimalFormat).
+//! Options for [`FixedDecimalFormat`](crate::FixedDecimalFormat) and
+//! [`CompactDecimalFormat`](crate::CompactDecimalFormat).
+
+use tinystr::TinyStr8;
 
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormat`](crate::FixedDecimalFormat).
@@ -12,6 +15,64 @@ pub struct FixedDecimalFormatOptions {
     pub grouping_strategy: GroupingStrategy,
     /// When to render the sign.
     pub sign_display: SignDisplay,
+    /// The numbering system whose digits and symbols are used, such as `arab` or `deva`.
+    ///
+    /// If `None`, the numbering system is taken from the `-u-nu-` keyword of the locale, or
+    /// the default numbering system of the locale is used. Numbering systems in the `-u-nu-`
+    /// keyword that are not supported by the data are ignored, whereas loading fails if this
+    /// option names a numbering system that is not supported.
+    pub numbering_system: Option<TinyStr8>,
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`CompactDecimalFormat`](crate::CompactDecimalFormat).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CompactDecimalFormatOptions {
+    /// Whether to use the short ("1.2K") or the long ("1.2 thousand") patterns.
+    pub style: CompactStyle,
+    /// The number of significant digits to display in the compacted number.
+    ///
+    /// If `None`, numbers with two or more integer digits are rounded to an integer, and other
+    /// numbers are rounded to two significant digits. For example, 1234 is displayed as "1.2K"
+    /// and 12345 as "12K".
+    pub significant_digits: Option<u8>,
+    /// When to render grouping separators.
+    pub grouping_strategy: GroupingStrategy,
+    /// When to render the sign.
+    pub sign_display: SignDisplay,
+    /// The numbering system whose digits and symbols are used.
+    ///
+    /// See [`FixedDecimalFormatOptions::numbering_system`].
+    pub numbering_system: Option<TinyStr8>,
+}
+
+impl Default for CompactDecimalFormatOptions {
+    fn default() -> Self {
+        Self {
+            style: CompactStyle::Short,
+            significant_digits: None,
+            grouping_strategy: GroupingStrategy::Min2,
+            sign_display: SignDisplay::Auto,
+            numbering_system: None,
+        }
+    }
+}
+
+/// The length of the patterns used by [`CompactDecimalFormat`](crate::CompactDecimalFormat).
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CompactStyle {
+    /// Abbreviated patterns, such as "1.2K".
+    Short,
+
+    /// Spelled-out patterns, such as "1.2 thousand".
+    Long,
+}
+
+impl Default for CompactStyle {
+    fn default() -> Self {
+        Self::Short
+    }
 }
 
 /// Configuration for how often to render grouping separators.
@@ -33,10 +94,10 @@ pub struct FixedDecimalFormatOptions {
 ///     .expect("Data should load successfully");
 ///
 /// let one_thousand = 1000.into();
-/// assert_eq!("1000", fdf.format(&one_thousand).writeable_to_string());
+/// assert_eq!("1000", fdf.format(&one_thousand).write_to_string());
 ///
 /// let ten_thousand = 10000.into();
-/// assert_eq!("10,000", fdf.format(&ten_thousand).writeable_to_string());
+/// assert_eq!("10,000", fdf.format(&ten_thousand).write_to_string());
 /// ```
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -84,13 +145,13 @@ impl Default for GroupingStrategy {
 ///     .expect("Data should load successfully");
 ///
 /// let pos_thousand = 1000.into();
-/// assert_eq!("+1,000", fdf.format(&pos_thousand).writeable_to_string());
+/// assert_eq!("+1,000", fdf.format(&pos_thousand).write_to_string());
 ///
 /// let zero = 0.into();
-/// assert_eq!("0", fdf.format(&zero).writeable_to_string());
+/// assert_eq!("0", fdf.format(&zero).write_to_string());
 ///
 /// let neg_thousand = (-1000).into();
-/// assert_eq!("-1,000", fdf.format(&neg_thousand).writeable_to_string());
+/// assert_eq!("-1,000", fdf.format(&neg_thousand).write_to_string());
 /// ```
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -0,0 +1,127 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Ordinal number formatting, such as "1st" or "2nd".
+
+use crate::error::Error;
+use crate::format::FormattedFixedDecimal;
+use crate::options::*;
+use crate::provider::*;
+use crate::FixedDecimalFormat;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_plurals::{PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::{LengthHint, Writeable};
+
+/// A formatter for [`FixedDecimal`] as an ordinal number, such as "1st", "2nd", or "3e".
+///
+/// The number is formatted like with [`FixedDecimalFormat`] and decorated with the affixes
+/// of its [`Ordinal`](PluralRuleType::Ordinal) plural category. Numbers in languages whose
+/// ordinals are separate words, such as Japanese, are not decorated.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::OrdinalFormat;
+/// use icu::locid::Locale;
+/// use writeable::Writeable;
+///
+/// let provider = icu_provider::inv::InvariantDataProvider;
+/// let of = OrdinalFormat::try_new(Locale::und(), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// // The root locale has no ordinal affixes.
+/// assert_eq!("1,000", of.format(&1000.into()).write_to_string());
+/// ```
+pub struct OrdinalFormat<'data> {
+    fdf: FixedDecimalFormat<'data>,
+    plural_rules: PluralRules,
+    affixes: DataPayload<'data, OrdinalAffixesV1Marker>,
+}
+
+impl<'data> OrdinalFormat<'data> {
+    /// Creates a new [`OrdinalFormat`] from locale data and an options bag.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: FixedDecimalFormatOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DecimalSymbolsV1Marker>
+            + DataProvider<'data, OrdinalAffixesV1Marker>
+            + DataProvider<'data, PluralRuleStringsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let fdf = FixedDecimalFormat::try_new(locale.clone(), data_provider, options)?;
+        let plural_rules =
+            PluralRules::try_new(locale.id.clone(), data_provider, PluralRuleType::Ordinal)?;
+        let affixes = data_provider
+            .load_payload_with_context(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::ORDINALS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.id),
+                    },
+                },
+            })?
+            .take_payload()?;
+        Ok(Self {
+            fdf,
+            plural_rules,
+            affixes,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] as an ordinal number, returning a [`FormattedOrdinal`].
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedOrdinal<'l> {
+        let category = self.plural_rules.select(value);
+        FormattedOrdinal {
+            number: self.fdf.format(value),
+            affixes: self.affixes.get().get(category),
+        }
+    }
+}
+
+/// An intermediate structure returned by [`OrdinalFormat`].
+/// Use [`Writeable`][Writeable] to render the formatted ordinal to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedOrdinal<'l> {
+    number: FormattedFixedDecimal<'l>,
+    affixes: Option<&'l AffixesV1<'l>>,
+}
+
+impl<'l> Writeable for FormattedOrdinal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        if let Some(affixes) = self.affixes {
+            sink.write_str(&affixes.prefix)?;
+        }
+        self.number.write_to(sink)?;
+        if let Some(affixes) = self.affixes {
+            sink.write_str(&affixes.suffix)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let affixes_len = self
+            .affixes
+            .map_or(0, |affixes| affixes.prefix.len() + affixes.suffix.len());
+        self.number.write_len() + affixes_len
+    }
+}
+
+impl<'l> fmt::Display for FormattedOrdinal<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
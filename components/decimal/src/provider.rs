@@ -7,16 +7,44 @@
 //! Read more about data providers: [`icu_provider`]
 
 use alloc::borrow::Cow;
+use core::fmt;
+use icu_plurals::PluralCategory;
+use icu_provider::baked::Bake;
+use icu_provider::registry::RegisteredKeys;
 use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
 
 pub mod key {
     //! Resource keys for [`icu_decimal`](crate).
     use icu_provider::{resource_key, ResourceKey};
 
     /// Resource key: symbols used for basic decimal formatting.
+    ///
+    /// The variant of the request selects a numbering system, such as `"arab"`; without a
+    /// variant, the default numbering system of the locale is used.
     pub const SYMBOLS_V1: ResourceKey = resource_key!(Decimal, "symbols", 1);
+
+    /// Resource key: patterns for short compact decimal formatting, such as "1.2K".
+    pub const COMPACT_SHORT_V1: ResourceKey = resource_key!(Decimal, "compact_short", 1);
+
+    /// Resource key: patterns for long compact decimal formatting, such as "1.2 thousand".
+    pub const COMPACT_LONG_V1: ResourceKey = resource_key!(Decimal, "compact_long", 1);
+
+    /// Resource key: affixes for ordinal numbers, such as the "st" of "1st".
+    pub const ORDINALS_V1: ResourceKey = resource_key!(Decimal, "ordinals", 1);
 }
 
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_decimal`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(DecimalSymbolsV1Marker, [key::SYMBOLS_V1]),
+    icu_provider::registered_keys!(
+        CompactDecimalPatternDataV1Marker,
+        [key::COMPACT_SHORT_V1, key::COMPACT_LONG_V1]
+    ),
+    icu_provider::registered_keys!(OrdinalAffixesV1Marker, [key::ORDINALS_V1]),
+];
+
 /// A collection of strings to affix to a decimal number.
 #[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
 #[cfg_attr(
@@ -33,6 +61,26 @@ pub struct AffixesV1<'data> {
     pub suffix: Cow<'data, str>,
 }
 
+/// A collection of strings to place around and between the two numbers of a range.
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct RangePatternV1<'data> {
+    /// String to prepend before the first number.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub prefix: Cow<'data, str>,
+
+    /// String to place between the two numbers, such as an en dash.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub separator: Cow<'data, str>,
+
+    /// String to append after the second number.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub suffix: Cow<'data, str>,
+}
+
 /// A collection of settings expressing where to put grouping separators in a decimal number.
 /// For example, `1,000,000` has two grouping separators, positioned along every 3 digits.
 #[derive(Debug, PartialEq, Clone, Yokeable, Copy, ZeroCopyFrom)]
@@ -82,6 +130,22 @@ pub struct DecimalSymbolsV1<'data> {
     /// Digit characters for the current numbering system. In most systems, these digits are
     /// contiguous, but in some systems, such as *hanidec*, they are not contiguous.
     pub digits: [char; 10],
+
+    /// String used to represent an infinite number, such as "∞".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub infinity: Cow<'data, str>,
+
+    /// String used to represent a value that is not a number, such as "NaN".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub nan: Cow<'data, str>,
+
+    /// Strings used to format a range of numbers, such as "3–5".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub range_pattern: RangePatternV1<'data>,
+
+    /// Prefix and suffix to apply to a range whose numbers are equal, such as "~5".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub approximately_affixes: AffixesV1<'data>,
 }
 
 impl Default for DecimalSymbolsV1<'static> {
@@ -103,6 +167,182 @@ impl Default for DecimalSymbolsV1<'static> {
                 min_grouping: 1,
             },
             digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            infinity: "∞".into(),
+            nan: "NaN".into(),
+            range_pattern: RangePatternV1 {
+                prefix: Cow::Borrowed(""),
+                separator: Cow::Borrowed("–"),
+                suffix: Cow::Borrowed(""),
+            },
+            approximately_affixes: AffixesV1 {
+                prefix: Cow::Borrowed("~"),
+                suffix: Cow::Borrowed(""),
+            },
         }
     }
 }
+
+impl Bake for AffixesV1<'_> {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_decimal::provider::AffixesV1 { prefix: ")?;
+        self.prefix.bake(sink)?;
+        sink.write_str(", suffix: ")?;
+        self.suffix.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
+impl Bake for RangePatternV1<'_> {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_decimal::provider::RangePatternV1 { prefix: ")?;
+        self.prefix.bake(sink)?;
+        sink.write_str(", separator: ")?;
+        self.separator.bake(sink)?;
+        sink.write_str(", suffix: ")?;
+        self.suffix.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
+impl Bake for GroupingSizesV1 {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_decimal::provider::GroupingSizesV1 { primary: ")?;
+        self.primary.bake(sink)?;
+        sink.write_str(", secondary: ")?;
+        self.secondary.bake(sink)?;
+        sink.write_str(", min_grouping: ")?;
+        self.min_grouping.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
+impl Bake for DecimalSymbolsV1<'_> {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_decimal::provider::DecimalSymbolsV1 { minus_sign_affixes: ")?;
+        self.minus_sign_affixes.bake(sink)?;
+        sink.write_str(", plus_sign_affixes: ")?;
+        self.plus_sign_affixes.bake(sink)?;
+        sink.write_str(", decimal_separator: ")?;
+        self.decimal_separator.bake(sink)?;
+        sink.write_str(", grouping_separator: ")?;
+        self.grouping_separator.bake(sink)?;
+        sink.write_str(", grouping_sizes: ")?;
+        self.grouping_sizes.bake(sink)?;
+        sink.write_str(", digits: ")?;
+        self.digits.bake(sink)?;
+        sink.write_str(", infinity: ")?;
+        self.infinity.bake(sink)?;
+        sink.write_str(", nan: ")?;
+        self.nan.bake(sink)?;
+        sink.write_str(", range_pattern: ")?;
+        self.range_pattern.bake(sink)?;
+        sink.write_str(", approximately_affixes: ")?;
+        self.approximately_affixes.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
+/// A pattern for compact decimal formatting, such as "0K" or "00 thousand".
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct CompactPatternV1<'data> {
+    /// The power of 10 by which the number is divided before being formatted. For example,
+    /// the pattern "00K" for numbers in the ten thousands has an exponent of 3.
+    ///
+    /// An exponent of 0 with empty affixes means that the number is not compacted.
+    pub exponent: i16,
+
+    /// Strings to place around the scaled number, such as an empty prefix and a "K" suffix.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub affixes: AffixesV1<'data>,
+}
+
+/// Patterns for compact decimal formatting in one style, either short or long.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Compact_Number_Formats>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CompactDecimalPatternDataV1<'data> {
+    /// Mapping from the magnitude of the smallest number a pattern applies to, such as 3 for
+    /// the thousands, to the patterns for each plural category. A pattern applies to numbers
+    /// up to the next magnitude in the map.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub patterns: LiteMap<i16, LiteMap<PluralCategory, CompactPatternV1<'data>>>,
+}
+
+impl<'data> CompactDecimalPatternDataV1<'data> {
+    /// Gets the patterns applying to a number whose most significant digit has the given
+    /// magnitude, if the number is large enough to be compacted.
+    pub fn patterns_for(
+        &self,
+        magnitude: i16,
+    ) -> Option<&LiteMap<PluralCategory, CompactPatternV1<'data>>> {
+        self.patterns
+            .iter()
+            .take_while(|(m, _)| **m <= magnitude)
+            .last()
+            .map(|(_, patterns)| patterns)
+    }
+}
+
+impl Default for CompactDecimalPatternDataV1<'static> {
+    /// Returns the root patterns: "0K", "00K", "000K", "0M", …, "000T".
+    fn default() -> Self {
+        let mut patterns = LiteMap::new();
+        for (i, suffix) in ["K", "M", "G", "T"].iter().enumerate() {
+            let exponent = 3 * (i as i16 + 1);
+            for magnitude in exponent..exponent + 3 {
+                let mut by_category = LiteMap::new();
+                by_category.insert(
+                    PluralCategory::Other,
+                    CompactPatternV1 {
+                        exponent,
+                        affixes: AffixesV1 {
+                            prefix: Cow::Borrowed(""),
+                            suffix: Cow::Borrowed(*suffix),
+                        },
+                    },
+                );
+                patterns.insert(magnitude, by_category);
+            }
+        }
+        Self { patterns }
+    }
+}
+
+/// Affixes decorating ordinal numbers, such as "1st" or "2nd", for each ordinal plural category.
+///
+/// The affixes are those of the number in the ordinal minimal pairs of the CLDR, such as
+/// "Take the {0}st right."; languages whose ordinals are separate words have no affixes.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Minimal_Pairs>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct OrdinalAffixesV1<'data> {
+    /// Mapping from the ordinal plural category of a number to the strings to place around it.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub affixes: LiteMap<PluralCategory, AffixesV1<'data>>,
+}
+
+impl<'data> OrdinalAffixesV1<'data> {
+    /// Gets the affixes for the ordinal plural category, or those for
+    /// [`PluralCategory::Other`] if the category has none.
+    pub fn get(&self, category: PluralCategory) -> Option<&AffixesV1<'data>> {
+        self.affixes
+            .get(&category)
+            .or_else(|| self.affixes.get(&PluralCategory::Other))
+    }
+}
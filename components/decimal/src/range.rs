@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Range formatting, such as "3–5" or "~5".
+//!
+//! The behavior follows the [ECMA-402 `formatRange` proposal]: if the two numbers of a range
+//! are formatted the same, the number is formatted once with an approximately sign, and affixes
+//! shared by both numbers, such as the "K" of "3K–5K", are only written once. Plus and minus
+//! signs are never collapsed, since "-5 – -3" and "-5–3" have different meanings.
+//!
+//! [ECMA-402 `formatRange` proposal]: https://github.com/tc39/proposal-intl-numberformat-v3
+
+use crate::compact::FormattedCompactDecimal;
+use crate::format::FormattedFixedDecimal;
+use crate::provider::*;
+use core::fmt;
+use writeable::{LengthHint, Writeable};
+
+/// An intermediate structure returned by
+/// [`FixedDecimalFormat::format_range`](crate::FixedDecimalFormat::format_range).
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedDecimalRange<'l> {
+    pub(crate) start: FormattedFixedDecimal<'l>,
+    pub(crate) end: FormattedFixedDecimal<'l>,
+}
+
+impl<'l> FormattedDecimalRange<'l> {
+    fn is_approximately(&self) -> bool {
+        self.start.value == self.end.value
+    }
+}
+
+impl<'l> Writeable for FormattedDecimalRange<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let symbols = self.start.symbols;
+        if self.is_approximately() {
+            return write_approximately(sink, symbols, &self.start);
+        }
+        sink.write_str(&symbols.range_pattern.prefix)?;
+        self.start.write_to(sink)?;
+        sink.write_str(&symbols.range_pattern.separator)?;
+        self.end.write_to(sink)?;
+        sink.write_str(&symbols.range_pattern.suffix)
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let symbols = self.start.symbols;
+        if self.is_approximately() {
+            return approximately_len(symbols, &self.start);
+        }
+        self.start.write_len() + self.end.write_len() + range_pattern_len(symbols)
+    }
+}
+
+impl<'l> fmt::Display for FormattedDecimalRange<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// An intermediate structure returned by
+/// [`CompactDecimalFormat::format_range`](crate::CompactDecimalFormat::format_range).
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedCompactDecimalRange<'l> {
+    pub(crate) start: FormattedCompactDecimal<'l>,
+    pub(crate) end: FormattedCompactDecimal<'l>,
+}
+
+impl<'l> FormattedCompactDecimalRange<'l> {
+    fn is_approximately(&self) -> bool {
+        self.start.value == self.end.value && self.start.pattern == self.end.pattern
+    }
+
+    /// Returns the compact affixes shared by both numbers, if any.
+    ///
+    /// A shared prefix is only written once if neither number displays a sign, which goes
+    /// before the prefix.
+    fn shared_affixes(&self) -> Option<&AffixesV1> {
+        let start = &self.start.pattern?.affixes;
+        let end = &self.end.pattern?.affixes;
+        let has_sign = self.start.formatted_number().get_affixes().is_some()
+            || self.end.formatted_number().get_affixes().is_some();
+        let is_empty = start.prefix.is_empty() && start.suffix.is_empty();
+        if start == end && !is_empty && (start.prefix.is_empty() || !has_sign) {
+            Some(start)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedCompactDecimalRange<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let symbols = self.start.symbols;
+        if self.is_approximately() {
+            return write_approximately(sink, symbols, &self.start);
+        }
+        sink.write_str(&symbols.range_pattern.prefix)?;
+        match self.shared_affixes() {
+            Some(affixes) => {
+                sink.write_str(&affixes.prefix)?;
+                self.start.formatted_number().write_to(sink)?;
+                sink.write_str(&symbols.range_pattern.separator)?;
+                self.end.formatted_number().write_to(sink)?;
+                sink.write_str(&affixes.suffix)?;
+            }
+            None => {
+                self.start.write_to(sink)?;
+                sink.write_str(&symbols.range_pattern.separator)?;
+                self.end.write_to(sink)?;
+            }
+        }
+        sink.write_str(&symbols.range_pattern.suffix)
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let symbols = self.start.symbols;
+        if self.is_approximately() {
+            return approximately_len(symbols, &self.start);
+        }
+        let numbers_len = match self.shared_affixes() {
+            Some(affixes) => {
+                self.start.formatted_number().write_len()
+                    + self.end.formatted_number().write_len()
+                    + affixes.prefix.len()
+                    + affixes.suffix.len()
+            }
+            None => self.start.write_len() + self.end.write_len(),
+        };
+        numbers_len + range_pattern_len(symbols)
+    }
+}
+
+impl<'l> fmt::Display for FormattedCompactDecimalRange<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn write_approximately<W>(
+    sink: &mut W,
+    symbols: &DecimalSymbolsV1,
+    value: &impl Writeable,
+) -> core::result::Result<(), core::fmt::Error>
+where
+    W: core::fmt::Write + ?Sized,
+{
+    sink.write_str(&symbols.approximately_affixes.prefix)?;
+    value.write_to(sink)?;
+    sink.write_str(&symbols.approximately_affixes.suffix)
+}
+
+fn approximately_len(symbols: &DecimalSymbolsV1, value: &impl Writeable) -> LengthHint {
+    value.write_len()
+        + symbols.approximately_affixes.prefix.len()
+        + symbols.approximately_affixes.suffix.len()
+}
+
+fn range_pattern_len(symbols: &DecimalSymbolsV1) -> usize {
+    symbols.range_pattern.prefix.len()
+        + symbols.range_pattern.separator.len()
+        + symbols.range_pattern.suffix.len()
+}
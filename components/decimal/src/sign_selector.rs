@@ -42,3 +42,28 @@ pub fn select(signum: Signum, sign_display: SignDisplay) -> SignSelection {
         },
     }
 }
+
+#[test]
+fn test_sign_display() {
+    use SignDisplay::*;
+    use Signum::*;
+    // Expected signs for -1, -0, 0, and 1, as in ECMA-402.
+    let cases = [
+        (Auto, ["-", "-", "", ""]),
+        (Always, ["-", "-", "+", "+"]),
+        (Never, ["", "", "", ""]),
+        (ExceptZero, ["-", "", "", "+"]),
+        (Negative, ["-", "", "", ""]),
+    ];
+    for (sign_display, expected) in cases.iter() {
+        let signums = [BelowZero, NegativeZero, PositiveZero, AboveZero];
+        for (signum, expected) in signums.iter().zip(expected.iter()) {
+            let actual = match select(*signum, *sign_display) {
+                SignSelection::Minus => "-",
+                SignSelection::Neither => "",
+                SignSelection::Plus => "+",
+            };
+            assert_eq!(*expected, actual, "{:?} {:?}", sign_display, signum);
+        }
+    }
+}
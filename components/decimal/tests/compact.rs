@@ -0,0 +1,215 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::options::{CompactDecimalFormatOptions, CompactStyle, SignDisplay};
+use icu_decimal::provider::*;
+use icu_decimal::CompactDecimalFormat;
+use icu_locid_macros::langid;
+use icu_plurals::provider::{PluralRuleStringsV1, PluralRuleStringsV1Marker};
+use icu_plurals::PluralCategory;
+use icu_provider::inv::InvariantDataProvider;
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use writeable::{LengthHint, Writeable};
+
+/// A provider with French-like long compact patterns.
+///
+/// The plural rules use the compact exponent operand `e`: exactly one thousand or one million
+/// is in the category "one", and other millions are in the category "many".
+struct LongPatternsProvider;
+
+impl DataProvider<'static, DecimalSymbolsV1Marker> for LongPatternsProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, DecimalSymbolsV1Marker>, DataError> {
+        InvariantDataProvider.load_payload(req)
+    }
+}
+
+impl DataProvider<'static, PluralRuleStringsV1Marker> for LongPatternsProvider {
+    fn load_payload(
+        &self,
+        _req: &DataRequest,
+    ) -> Result<DataResponse<'static, PluralRuleStringsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(PluralRuleStringsV1 {
+                one: Some("i = 1000 and e = 3 or i = 1000000 and e = 6".into()),
+                many: Some("e = 6..7 and i != 1000000".into()),
+                ..Default::default()
+            })),
+        })
+    }
+}
+
+impl DataProvider<'static, CompactDecimalPatternDataV1Marker> for LongPatternsProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, CompactDecimalPatternDataV1Marker>, DataError> {
+        if req.resource_path.key != key::COMPACT_LONG_V1 {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+        let pattern = |exponent, suffix: &'static str| CompactPatternV1 {
+            exponent,
+            affixes: AffixesV1 {
+                prefix: "".into(),
+                suffix: suffix.into(),
+            },
+        };
+        let mut thousands = LiteMap::new();
+        thousands.insert(PluralCategory::One, pattern(3, " millier"));
+        thousands.insert(PluralCategory::Other, pattern(3, " mille"));
+        let mut millions = LiteMap::new();
+        millions.insert(PluralCategory::One, pattern(6, " million"));
+        millions.insert(PluralCategory::Other, pattern(6, " millions"));
+        let mut ten_millions = LiteMap::new();
+        ten_millions.insert(PluralCategory::Other, pattern(6, " millions"));
+        let mut patterns = LiteMap::new();
+        patterns.insert(3, thousands);
+        patterns.insert(6, millions);
+        patterns.insert(7, ten_millions);
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CompactDecimalPatternDataV1 {
+                patterns,
+            })),
+        })
+    }
+}
+
+fn format_long(value: i64) -> String {
+    let options = CompactDecimalFormatOptions {
+        style: CompactStyle::Long,
+        ..Default::default()
+    };
+    let cdf = CompactDecimalFormat::try_new(langid!("fr"), &LongPatternsProvider, options)
+        .expect("Data should load successfully");
+    let value = value.into();
+    let formatted = cdf.format(&value);
+    let result = formatted.write_to_string();
+    assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+    result
+}
+
+#[test]
+fn test_compact_long_plurals() {
+    assert_eq!("1 millier", format_long(1000));
+    // The plural category is selected for the whole number, 1500 (1.5c3), not for 1.5.
+    assert_eq!("1.5 mille", format_long(1499));
+    assert_eq!("2 mille", format_long(2000));
+    assert_eq!("1 million", format_long(1_000_000));
+    // Other millions are in the plural category "many", which falls back to "other".
+    assert_eq!("1.2 millions", format_long(1_234_567));
+    assert_eq!("2 millions", format_long(2_000_000));
+    assert_eq!("12 millions", format_long(12_000_000));
+}
+
+#[test]
+fn test_compact_rounding_to_next_magnitude() {
+    // 999,999 rounds to 1000 thousands, which is displayed with the pattern for millions.
+    assert_eq!("1 million", format_long(999_999));
+    assert_eq!("999 mille", format_long(999_499));
+}
+
+#[test]
+fn test_compact_missing_style() {
+    let result = CompactDecimalFormat::try_new(
+        langid!("fr"),
+        &LongPatternsProvider,
+        CompactDecimalFormatOptions::default(),
+    );
+    assert!(result.is_err());
+}
+
+/// A provider with short compact patterns that have a prefix, like those of currencies.
+struct PrefixPatternsProvider;
+
+impl DataProvider<'static, DecimalSymbolsV1Marker> for PrefixPatternsProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, DecimalSymbolsV1Marker>, DataError> {
+        InvariantDataProvider.load_payload(req)
+    }
+}
+
+impl DataProvider<'static, PluralRuleStringsV1Marker> for PrefixPatternsProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, PluralRuleStringsV1Marker>, DataError> {
+        InvariantDataProvider.load_payload(req)
+    }
+}
+
+impl DataProvider<'static, CompactDecimalPatternDataV1Marker> for PrefixPatternsProvider {
+    fn load_payload(
+        &self,
+        _req: &DataRequest,
+    ) -> Result<DataResponse<'static, CompactDecimalPatternDataV1Marker>, DataError> {
+        let mut thousands = LiteMap::new();
+        thousands.insert(
+            PluralCategory::Other,
+            CompactPatternV1 {
+                exponent: 3,
+                affixes: AffixesV1 {
+                    prefix: "US$".into(),
+                    suffix: "K".into(),
+                },
+            },
+        );
+        let mut patterns = LiteMap::new();
+        patterns.insert(3, thousands);
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CompactDecimalPatternDataV1 {
+                patterns,
+            })),
+        })
+    }
+}
+
+#[test]
+fn test_compact_negative() {
+    let format = |options, value: i64| {
+        let cdf = CompactDecimalFormat::try_new(langid!("en"), &PrefixPatternsProvider, options)
+            .expect("Data should load successfully");
+        let value = value.into();
+        let formatted = cdf.format(&value);
+        let result = formatted.write_to_string();
+        assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+        result
+    };
+
+    // The sign goes before the prefix of the pattern.
+    assert_eq!("US$1.2K", format(Default::default(), 1234));
+    assert_eq!("-US$1.2K", format(Default::default(), -1234));
+    let always = CompactDecimalFormatOptions {
+        sign_display: SignDisplay::Always,
+        ..Default::default()
+    };
+    assert_eq!("+US$1.2K", format(always, 1234));
+    assert_eq!("-999", format(Default::default(), -999));
+}
+
+#[test]
+fn test_compact_range_negative() {
+    let cdf =
+        CompactDecimalFormat::try_new(langid!("en"), &PrefixPatternsProvider, Default::default())
+            .expect("Data should load successfully");
+    let format_range = |start: i64, end: i64| {
+        let (start, end) = (start.into(), end.into());
+        let formatted = cdf.format_range(&start, &end);
+        let result = formatted.write_to_string();
+        assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+        result
+    };
+
+    assert_eq!("US$1–2K", format_range(1000, 2000));
+    // A shared prefix is not written once if a sign goes before it.
+    assert_eq!("-US$2K–-US$1K", format_range(-2000, -1000));
+    assert_eq!("-US$1K–US$2K", format_range(-1000, 2000));
+}
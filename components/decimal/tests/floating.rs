@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, FloatingDecimal};
+use icu_decimal::options::{FixedDecimalFormatOptions, SignDisplay};
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use writeable::{LengthHint, Writeable};
+
+fn format(locale: &str, sign_display: SignDisplay, value: &FloatingDecimal) -> String {
+    let locale: Locale = locale.parse().unwrap();
+    let provider = icu_testdata::get_provider();
+    let options = FixedDecimalFormatOptions {
+        sign_display,
+        ..Default::default()
+    };
+    let fdf = FixedDecimalFormat::try_new(locale, &provider, options)
+        .expect("Data should load successfully");
+    let formatted = fdf.format_floating(value);
+    let result = formatted.write_to_string();
+    assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+    result
+}
+
+#[test]
+fn test_floating() {
+    let finite = FloatingDecimal::from(FixedDecimal::from(-1234));
+    assert_eq!("-1,234", format("en", SignDisplay::Auto, &finite));
+    assert_eq!(
+        "-∞",
+        format("en", SignDisplay::Auto, &FloatingDecimal::NegativeInfinity)
+    );
+    assert_eq!(
+        "∞",
+        format("en", SignDisplay::Auto, &FloatingDecimal::Infinity)
+    );
+    assert_eq!(
+        "+∞",
+        format("en", SignDisplay::Always, &FloatingDecimal::Infinity)
+    );
+    assert_eq!(
+        "NaN",
+        format("en", SignDisplay::Always, &FloatingDecimal::NaN)
+    );
+    assert_eq!(
+        "ليس\u{a0}رقم",
+        format("ar-EG", SignDisplay::Auto, &FloatingDecimal::NaN)
+    );
+    assert_eq!(
+        "\u{061C}-∞",
+        format(
+            "ar-EG",
+            SignDisplay::Auto,
+            &FloatingDecimal::NegativeInfinity
+        )
+    );
+}
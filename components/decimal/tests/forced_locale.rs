@@ -0,0 +1,22 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::key;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid_macros::langid;
+use icu_provider::forced_locale::ForcedLocaleProvider;
+use writeable::Writeable;
+
+#[test]
+fn test_forced_symbols() {
+    let provider = ForcedLocaleProvider::new(icu_testdata::get_provider())
+        .force_key(key::SYMBOLS_V1, langid!("fr"));
+    let fdf = FixedDecimalFormat::try_new(langid!("en"), &provider, Default::default())
+        .expect("Data should load successfully");
+    let mut decimal = FixedDecimal::from(12345);
+    decimal.multiply_pow10(-1).unwrap();
+    // French symbols in English formatting.
+    assert_eq!("1\u{202f}234,5", fdf.format(&decimal).write_to_string());
+}
@@ -0,0 +1,76 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::options::FixedDecimalFormatOptions;
+use icu_decimal::provider::*;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use writeable::{LengthHint, Writeable};
+
+/// A provider with Latin digits by default and Thai digits for the `thai` variant.
+struct ThaiProvider;
+
+impl DataProvider<'static, DecimalSymbolsV1Marker> for ThaiProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, DecimalSymbolsV1Marker>, DataError> {
+        let mut symbols = DecimalSymbolsV1::default();
+        match req.resource_path.options.variant.as_deref() {
+            None | Some("latn") => (),
+            Some("thai") => {
+                symbols.digits = ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'];
+            }
+            Some(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        }
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(symbols)),
+        })
+    }
+}
+
+fn format(locale: &str, options: FixedDecimalFormatOptions) -> String {
+    let locale: Locale = locale.parse().unwrap();
+    let fdf = FixedDecimalFormat::try_new(locale, &ThaiProvider, options)
+        .expect("Data should load successfully");
+    let value = 1234.into();
+    let formatted = fdf.format(&value);
+    let result = formatted.write_to_string();
+    // The length of the digits depends on the numbering system.
+    assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+    result
+}
+
+#[test]
+fn test_default_numbering_system() {
+    assert_eq!("1,234", format("th", Default::default()));
+}
+
+#[test]
+fn test_unicode_extension() {
+    assert_eq!("๑,๒๓๔", format("th-u-nu-thai", Default::default()));
+    assert_eq!("1,234", format("th-u-nu-latn", Default::default()));
+    // A numbering system without data for the locale falls back to the default one.
+    assert_eq!("1,234", format("th-u-nu-deva", Default::default()));
+}
+
+#[test]
+fn test_option() {
+    let options = FixedDecimalFormatOptions {
+        numbering_system: Some("thai".parse().unwrap()),
+        ..Default::default()
+    };
+    assert_eq!("๑,๒๓๔", format("en", options.clone()));
+    // The option takes precedence over the Unicode extension.
+    assert_eq!("๑,๒๓๔", format("th-u-nu-latn", options));
+
+    let options = FixedDecimalFormatOptions {
+        numbering_system: Some("deva".parse().unwrap()),
+        ..Default::default()
+    };
+    let result = FixedDecimalFormat::try_new(Locale::und(), &ThaiProvider, options);
+    assert!(result.is_err());
+}
@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_decimal::provider::*;
+use icu_decimal::OrdinalFormat;
+use icu_locid_macros::langid;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use writeable::{LengthHint, Writeable};
+
+/// A provider with English ordinal affixes, and the symbols and plural rules of the test data.
+struct OrdinalsProvider<P>(P);
+
+impl<P> DataProvider<'static, DecimalSymbolsV1Marker> for OrdinalsProvider<P>
+where
+    P: DataProvider<'static, DecimalSymbolsV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, DecimalSymbolsV1Marker>, DataError> {
+        self.0.load_payload(req)
+    }
+}
+
+impl<P> DataProvider<'static, PluralRuleStringsV1Marker> for OrdinalsProvider<P>
+where
+    P: DataProvider<'static, PluralRuleStringsV1Marker>,
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, PluralRuleStringsV1Marker>, DataError> {
+        self.0.load_payload(req)
+    }
+}
+
+impl<P> DataProvider<'static, OrdinalAffixesV1Marker> for OrdinalsProvider<P> {
+    fn load_payload(
+        &self,
+        _req: &DataRequest,
+    ) -> Result<DataResponse<'static, OrdinalAffixesV1Marker>, DataError> {
+        let suffix = |suffix: &'static str| AffixesV1 {
+            prefix: "".into(),
+            suffix: suffix.into(),
+        };
+        let mut affixes = LiteMap::new();
+        affixes.insert(PluralCategory::One, suffix("st"));
+        affixes.insert(PluralCategory::Two, suffix("nd"));
+        affixes.insert(PluralCategory::Few, suffix("rd"));
+        affixes.insert(PluralCategory::Other, suffix("th"));
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(OrdinalAffixesV1 { affixes })),
+        })
+    }
+}
+
+fn format(value: i64) -> String {
+    let provider = OrdinalsProvider(icu_testdata::get_provider());
+    let of = OrdinalFormat::try_new(langid!("en"), &provider, Default::default())
+        .expect("Data should load successfully");
+    let value = value.into();
+    let formatted = of.format(&value);
+    let result = formatted.write_to_string();
+    assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+    result
+}
+
+#[test]
+fn test_ordinal_suffixes() {
+    assert_eq!("1st", format(1));
+    assert_eq!("2nd", format(2));
+    assert_eq!("3rd", format(3));
+    assert_eq!("4th", format(4));
+    assert_eq!("11th", format(11));
+    assert_eq!("12th", format(12));
+    assert_eq!("13th", format(13));
+    assert_eq!("21st", format(21));
+    assert_eq!("102nd", format(102));
+    assert_eq!("1,003rd", format(1003));
+}
@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use writeable::{LengthHint, Writeable};
+
+fn format_range(locale: &str, start: &str, end: &str) -> String {
+    let locale: Locale = locale.parse().unwrap();
+    let provider = icu_testdata::get_provider();
+    let fdf = FixedDecimalFormat::try_new(locale, &provider, Default::default())
+        .expect("Data should load successfully");
+    let start: FixedDecimal = start.parse().unwrap();
+    let end: FixedDecimal = end.parse().unwrap();
+    let formatted = fdf.format_range(&start, &end);
+    let result = formatted.write_to_string();
+    assert_eq!(LengthHint::Exact(result.len()), formatted.write_len());
+    result
+}
+
+#[test]
+fn test_range() {
+    assert_eq!("3–5", format_range("en", "3", "5"));
+    assert_eq!("1,000–2,500.5", format_range("en", "1000", "2500.5"));
+    assert_eq!("-5–-3", format_range("en", "-5", "-3"));
+    assert_eq!("~5", format_range("en", "5", "5"));
+    assert_eq!("5–5.0", format_range("en", "5", "5.0"));
+    assert_eq!("3-5", format_range("es", "3", "5"));
+    assert_eq!("≈5", format_range("fr", "5", "5"));
+    assert_eq!("3～5", format_range("ja", "3", "5"));
+    assert_eq!("約 5", format_range("ja", "5", "5"));
+}
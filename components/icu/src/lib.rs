@@ -145,7 +145,9 @@ pub mod decimal {
     //! This API provides necessary functionality for formatting of numbers with decimal digits.
     //!
     //! [`FixedDecimalFormat`] is the main structure of the component. It formats a
-    //! [`FixedDecimal`] to a [`FormattedFixedDecimal`].
+    //! [`FixedDecimal`] to a [`FormattedFixedDecimal`]. [`CompactDecimalFormat`] formats numbers
+    //! in compact notation, such as "1.2K", and [`OrdinalFormat`] formats ordinal numbers, such
+    //! as "2nd".
     //!
     //! # Examples
     //!
@@ -164,7 +166,7 @@ pub mod decimal {
     //!
     //! let fixed_decimal = 1000007.into();
     //! let formatted_value = fdf.format(&fixed_decimal);
-    //! let formatted_str = formatted_value.writeable_to_string();
+    //! let formatted_str = formatted_value.write_to_string();
     //!
     //! assert_eq!("১০,০০,০০৭", formatted_str);
     //! ```
@@ -186,7 +188,7 @@ pub mod decimal {
     //!     .multiplied_pow10(-2)
     //!     .expect("Operation is fully in range");
     //!
-    //! assert_eq!("2,000.50", fdf.format(&fixed_decimal).writeable_to_string());
+    //! assert_eq!("2,000.50", fdf.format(&fixed_decimal).write_to_string());
     //! ```
     //!
     //! [`FixedDecimal`]: fixed_decimal::FixedDecimal
@@ -382,3 +384,47 @@ pub mod properties {
     //! [`sets`]: sets
     pub use icu_properties::*;
 }
+
+pub mod provider {
+    //! Registry of the resource keys of the components in this crate.
+    //!
+    //! Tools that operate over all keys, such as data generators, can use [`all_keys()`]
+    //! instead of maintaining their own lists of keys. See [`icu_provider::registry`] for more
+    //! details.
+    //!
+    //! The experimental components are not part of this crate, so their keys are not listed
+    //! here. Each of them has a `KEY_REGISTRY` of its own in its `provider` module, which
+    //! `icu4x-datagen` adds to [`all_keys()`].
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use icu::decimal::provider::key::SYMBOLS_V1;
+    //!
+    //! let info = icu::provider::all_keys()
+    //!     .find(|info| info.key == SYMBOLS_V1)
+    //!     .expect("The decimal symbols key is registered");
+    //! assert_eq!(info.marker, "icu_decimal::provider::DecimalSymbolsV1Marker");
+    //! assert_eq!(info.version(), 1);
+    //! ```
+    //!
+    //! [`icu_provider::registry`]: ../../icu_provider/registry/index.html
+    use icu_provider::registry::{self, KeyInfo, RegisteredKeys};
+
+    /// The key registries of the components in this crate.
+    pub const KEY_REGISTRY: &[&[RegisteredKeys]] = &[
+        icu_datetime::provider::KEY_REGISTRY,
+        icu_decimal::provider::KEY_REGISTRY,
+        icu_locale_canonicalizer::provider::KEY_REGISTRY,
+        icu_plurals::provider::KEY_REGISTRY,
+        icu_properties::provider::KEY_REGISTRY,
+    ];
+
+    /// Returns every resource key of the components in this crate along with the marker of
+    /// its data struct.
+    pub fn all_keys() -> impl Iterator<Item = KeyInfo> {
+        KEY_REGISTRY
+            .iter()
+            .flat_map(|component_registry| registry::iter_keys(component_registry))
+    }
+}
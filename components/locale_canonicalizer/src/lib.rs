@@ -19,6 +19,10 @@
 //! This minimize method returns a new Locale that is the result of running the
 //! 'Remove Likely Subtags' algorithm from [`UTS #35: Unicode LDML 3. Likely Subtags`].
 //!
+//! It also provides [`RegionContainment`], to find the regions that are part of a
+//! macroregion or a grouping such as the European Union, and [`RegionInfo`], with
+//! the population, literacy rate, and official languages of regions.
+//!
 //! # Examples
 //!
 //! ```
@@ -85,5 +89,7 @@ extern crate alloc;
 
 pub mod locale_canonicalizer;
 pub mod provider;
+pub mod regions;
 
 pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+pub use regions::{RegionContainment, RegionInfo};
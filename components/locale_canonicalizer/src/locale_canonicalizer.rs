@@ -225,11 +225,11 @@ impl<'data> LocaleCanonicalizer<'data> {
             Key::from_tinystr4_unchecked(tinystr4!("sd")),
         ];
         let aliases: DataPayload<AliasesV1Marker> = provider
-            .load_payload(&DataRequest::from(key::ALIASES_V1))?
+            .load_payload_with_context(&DataRequest::from(key::ALIASES_V1))?
             .take_payload()?;
 
         let likely_subtags: DataPayload<LikelySubtagsV1Marker> = provider
-            .load_payload(&DataRequest::from(key::LIKELY_SUBTAGS_V1))?
+            .load_payload_with_context(&DataRequest::from(key::LIKELY_SUBTAGS_V1))?
             .take_payload()?;
 
         Ok(LocaleCanonicalizer {
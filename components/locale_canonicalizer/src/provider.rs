@@ -8,6 +8,7 @@
 
 use alloc::vec::Vec;
 use icu_locid::LanguageIdentifier;
+use icu_provider::registry::RegisteredKeys;
 use icu_provider::yoke::{self, *};
 use tinystr::{TinyStr4, TinyStr8};
 
@@ -19,8 +20,22 @@ pub mod key {
     /// Key for likely subtags data.
     pub const LIKELY_SUBTAGS_V1: ResourceKey =
         resource_key!(LocaleCanonicalizer, "likelysubtags", 1);
+    /// Key for region containment data.
+    pub const REGION_CONTAINMENT_V1: ResourceKey =
+        resource_key!(LocaleCanonicalizer, "regioncontains", 1);
+    /// Key for region info data.
+    pub const REGION_INFO_V1: ResourceKey = resource_key!(LocaleCanonicalizer, "regioninfo", 1);
 }
 
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_locale_canonicalizer`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(AliasesV1Marker, [key::ALIASES_V1]),
+    icu_provider::registered_keys!(LikelySubtagsV1Marker, [key::LIKELY_SUBTAGS_V1]),
+    icu_provider::registered_keys!(RegionContainmentV1Marker, [key::REGION_CONTAINMENT_V1]),
+    icu_provider::registered_keys!(RegionInfoV1Marker, [key::REGION_INFO_V1]),
+];
+
 #[icu_provider::data_struct]
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(
@@ -102,3 +117,47 @@ pub struct LikelySubtagsV1 {
     /// Undefined.
     pub und: LanguageIdentifier,
 }
+
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+/// This region containment data is used to find the regions that are part of
+/// a macroregion or a grouping, based upon the `territoryContainment`
+/// supplemental data of CLDR, see
+/// https://www.unicode.org/reports/tr35/tr35-info.html#Territory_Containment_Data.
+///
+/// The data is stored in sorted order by the containing region, allowing for
+/// binary search.
+pub struct RegionContainmentV1 {
+    /// Regions, such as `150` (Europe) or `EU`, and the regions that they
+    /// directly contain.
+    pub contains: Vec<(TinyStr4, Vec<TinyStr4>)>,
+}
+
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+/// This region info data contains the population, literacy, and official
+/// languages of regions, based upon the `territoryInfo` supplemental data of
+/// CLDR, see https://www.unicode.org/reports/tr35/tr35-info.html#Territory_Data.
+///
+/// The data is stored in sorted order by region, allowing for binary search.
+/// Regions are missing from a field if CLDR has no data for them.
+pub struct RegionInfoV1 {
+    /// The estimated population of each region.
+    pub population: Vec<(TinyStr4, u64)>,
+    /// The literacy rate of each region, in tenths of a percent.
+    pub literacy_permille: Vec<(TinyStr4, u16)>,
+    /// The official languages of each region, including those that are
+    /// official de facto, in descending order of the share of the population
+    /// that speaks them.
+    pub official_languages: Vec<(TinyStr4, Vec<LanguageIdentifier>)>,
+}
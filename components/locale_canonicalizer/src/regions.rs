@@ -0,0 +1,196 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collection of code for region containment and region info.
+
+use crate::provider::*;
+use alloc::vec;
+use icu_locid::subtags::Region;
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use tinystr::TinyStr4;
+
+/// RegionContainment implementation.
+///
+/// The RegionContainment provides the regions that are part of a macroregion,
+/// such as `150` (Europe), or of a grouping, such as `EU` (the European Union),
+/// based upon the `territoryContainment` supplemental data of [`CLDR`].
+///
+/// This is useful to group regions by continent, for example in a locale picker.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_canonicalizer::provider::*;
+/// use icu_locale_canonicalizer::RegionContainment;
+/// use icu_locid_macros::region;
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+/// use tinystr::tinystr4;
+///
+/// // An excerpt of the data, as generated from CLDR.
+/// let provider = StructProvider {
+///     key: key::REGION_CONTAINMENT_V1,
+///     data: DataPayload::from_owned(RegionContainmentV1 {
+///         contains: vec![
+///             (tinystr4!("001"), vec![tinystr4!("150"), tinystr4!("142")]),
+///             (tinystr4!("150"), vec![tinystr4!("154"), tinystr4!("155")]),
+///             (tinystr4!("155"), vec![tinystr4!("CH"), tinystr4!("DE"), tinystr4!("FR")]),
+///             (tinystr4!("EU"), vec![tinystr4!("DE"), tinystr4!("FR")]),
+///         ],
+///     }),
+/// };
+/// let containment = RegionContainment::new(&provider).expect("create failed");
+///
+/// assert_eq!(
+///     containment.regions_in(region!("EU")).collect::<Vec<_>>(),
+///     vec![region!("DE"), region!("FR")]
+/// );
+/// assert_eq!(containment.regions_in(region!("CH")).count(), 0);
+///
+/// assert!(containment.contains(region!("001"), region!("CH")));
+/// assert!(containment.contains(region!("EU"), region!("DE")));
+/// assert!(!containment.contains(region!("EU"), region!("CH")));
+/// assert!(!containment.contains(region!("150"), region!("150")));
+/// ```
+///
+/// [`CLDR`]: http://cldr.unicode.org/
+pub struct RegionContainment<'data> {
+    containment: DataPayload<'data, RegionContainmentV1Marker>,
+}
+
+impl<'data> RegionContainment<'data> {
+    /// A constructor which takes a [`DataProvider`] and creates a [`RegionContainment`].
+    pub fn new<P>(provider: &P) -> Result<RegionContainment<'data>, DataError>
+    where
+        P: DataProvider<'data, RegionContainmentV1Marker> + ?Sized,
+    {
+        let containment: DataPayload<RegionContainmentV1Marker> = provider
+            .load_payload_with_context(&DataRequest::from(key::REGION_CONTAINMENT_V1))?
+            .take_payload()?;
+        Ok(RegionContainment { containment })
+    }
+
+    /// Returns the regions that the region directly contains, which is none for a
+    /// region that is a country or a territory.
+    ///
+    /// For example, the regions in `EU` are its member states, and the regions
+    /// in `150` (Europe) are `154` (Northern Europe), `155` (Western Europe),
+    /// and so on, which in turn contain countries.
+    pub fn regions_in(&self, region: Region) -> impl Iterator<Item = Region> + '_ {
+        let contains = &self.containment.get().contains;
+        contains
+            .binary_search_by_key(&region.into(), |entry| entry.0)
+            .map_or(&[][..], |index| &contains[index].1[..])
+            .iter()
+            .filter_map(|region| Region::from_bytes(region.as_bytes()).ok())
+    }
+
+    /// Returns whether the region `container` contains `region`, directly or
+    /// through the regions it contains. A region does not contain itself.
+    pub fn contains(&self, container: Region, region: Region) -> bool {
+        let contains = &self.containment.get().contains;
+        let region: TinyStr4 = region.into();
+        // The containment data is acyclic, so the search terminates.
+        let mut stack = vec![TinyStr4::from(container)];
+        while let Some(current) = stack.pop() {
+            if let Ok(index) = contains.binary_search_by_key(&current, |entry| entry.0) {
+                for contained in contains[index].1.iter() {
+                    if *contained == region {
+                        return true;
+                    }
+                    stack.push(*contained);
+                }
+            }
+        }
+        false
+    }
+}
+
+/// RegionInfo implementation.
+///
+/// The RegionInfo provides the population, literacy rate, and official
+/// languages of regions, based upon the `territoryInfo` supplemental data of
+/// [`CLDR`]. The figures are estimates, which CLDR updates regularly.
+///
+/// This is useful to suggest languages for a region, for example in a locale
+/// picker, or to weigh regions in analytics.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_canonicalizer::provider::*;
+/// use icu_locale_canonicalizer::RegionInfo;
+/// use icu_locid_macros::{langid, region};
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+/// use tinystr::tinystr4;
+///
+/// // An excerpt of the data, as generated from CLDR.
+/// let provider = StructProvider {
+///     key: key::REGION_INFO_V1,
+///     data: DataPayload::from_owned(RegionInfoV1 {
+///         population: vec![(tinystr4!("AQ"), 300), (tinystr4!("IN"), 1_389_637_446)],
+///         literacy_permille: vec![(tinystr4!("AQ"), 990), (tinystr4!("IN"), 744)],
+///         official_languages: vec![(tinystr4!("IN"), vec![langid!("hi"), langid!("en")])],
+///     }),
+/// };
+/// let info = RegionInfo::new(&provider).expect("create failed");
+///
+/// assert_eq!(info.population(region!("IN")), Some(1_389_637_446));
+/// assert_eq!(info.population(region!("ZZ")), None);
+/// assert_eq!(info.literacy_percent(region!("IN")), Some(74.4));
+/// assert_eq!(info.literacy_percent(region!("ZZ")), None);
+/// assert_eq!(info.languages_of(region!("IN")), [langid!("hi"), langid!("en")]);
+/// assert!(info.languages_of(region!("AQ")).is_empty());
+/// ```
+///
+/// [`CLDR`]: http://cldr.unicode.org/
+pub struct RegionInfo<'data> {
+    info: DataPayload<'data, RegionInfoV1Marker>,
+}
+
+impl<'data> RegionInfo<'data> {
+    /// A constructor which takes a [`DataProvider`] and creates a [`RegionInfo`].
+    pub fn new<P>(provider: &P) -> Result<RegionInfo<'data>, DataError>
+    where
+        P: DataProvider<'data, RegionInfoV1Marker> + ?Sized,
+    {
+        let info: DataPayload<RegionInfoV1Marker> = provider
+            .load_payload_with_context(&DataRequest::from(key::REGION_INFO_V1))?
+            .take_payload()?;
+        Ok(RegionInfo { info })
+    }
+
+    /// Returns the estimated population of the region.
+    pub fn population(&self, region: Region) -> Option<u64> {
+        let population = &self.info.get().population;
+        population
+            .binary_search_by_key(&region.into(), |entry| entry.0)
+            .ok()
+            .map(|index| population[index].1)
+    }
+
+    /// Returns the literacy rate of the region, in percent.
+    pub fn literacy_percent(&self, region: Region) -> Option<f64> {
+        let literacy = &self.info.get().literacy_permille;
+        literacy
+            .binary_search_by_key(&region.into(), |entry| entry.0)
+            .ok()
+            .map(|index| f64::from(literacy[index].1) / 10.0)
+    }
+
+    /// Returns the official languages of the region, including those that are
+    /// official de facto, such as English in the United States, in descending
+    /// order of the share of the population that speaks them.
+    ///
+    /// Languages that are only official in a part of the region, such as
+    /// Bengali in India, are not included.
+    pub fn languages_of(&self, region: Region) -> &[LanguageIdentifier] {
+        let languages = &self.info.get().official_languages;
+        languages
+            .binary_search_by_key(&region.into(), |entry| entry.0)
+            .map_or(&[][..], |index| &languages[index].1[..])
+    }
+}
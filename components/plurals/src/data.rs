@@ -8,6 +8,7 @@ use crate::rules;
 use crate::rules::ast;
 use crate::{PluralCategory, PluralRulesError};
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use core::convert::TryInto;
 
 /// A raw function pointer to a `PluralRulesFn`
@@ -51,6 +52,25 @@ impl PluralRuleList {
     }
 }
 
+impl From<Vec<(PluralCategory, ast::Rule)>> for PluralRuleList {
+    fn from(rules: Vec<(PluralCategory, ast::Rule)>) -> Self {
+        let mut result = Self::default();
+        for (category, rule) in rules {
+            let condition = Some(rule.condition);
+            match category {
+                PluralCategory::Zero => result.zero = condition,
+                PluralCategory::One => result.one = condition,
+                PluralCategory::Two => result.two = condition,
+                PluralCategory::Few => result.few = condition,
+                PluralCategory::Many => result.many = condition,
+                // "Other" is implicitly selected when no other rule matches.
+                PluralCategory::Other => (),
+            }
+        }
+        result
+    }
+}
+
 fn parse_rule(input: &Option<Cow<str>>) -> Result<Option<ast::Condition>, PluralRulesError> {
     Ok(if let Some(input) = input {
         Some(rules::parse_condition((input).as_bytes())?)
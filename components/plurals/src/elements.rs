@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{PluralCategory, PluralOperands};
+
+/// A set of values, such as the variants of a message, with one value per [`Plural Category`].
+///
+/// The value for [`PluralCategory::Other`] is required and is used for every category that
+/// has no value of its own. In addition, values can be given for the exact numbers `0` and
+/// `1`, which take precedence over the categories, as in "no items" or "a single item".
+///
+/// Use [`PluralRules::select_element()`] to pick the value for a number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::{PluralCategory, PluralElements};
+///
+/// let elements = PluralElements::new("{0} items")
+///     .with_category(PluralCategory::One, "{0} item")
+///     .with_explicit_zero("no items");
+///
+/// assert_eq!(elements.get(PluralCategory::One), &"{0} item");
+/// assert_eq!(elements.get(PluralCategory::Few), &"{0} items");
+/// assert_eq!(elements.explicit_zero(), Some(&"no items"));
+/// assert_eq!(elements.explicit_one(), None);
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+/// [`PluralRules::select_element()`]: crate::PluralRules::select_element()
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PluralElements<T> {
+    zero: Option<T>,
+    one: Option<T>,
+    two: Option<T>,
+    few: Option<T>,
+    many: Option<T>,
+    other: T,
+    explicit_zero: Option<T>,
+    explicit_one: Option<T>,
+}
+
+impl<T> PluralElements<T> {
+    /// Creates a new [`PluralElements`] with the value for [`PluralCategory::Other`], which is
+    /// used for all numbers until other values are added.
+    pub fn new(other: T) -> Self {
+        Self {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other,
+            explicit_zero: None,
+            explicit_one: None,
+        }
+    }
+
+    /// Sets the value for the given [`Plural Category`], replacing the previous value.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn with_category(mut self, category: PluralCategory, value: T) -> Self {
+        match category {
+            PluralCategory::Zero => self.zero = Some(value),
+            PluralCategory::One => self.one = Some(value),
+            PluralCategory::Two => self.two = Some(value),
+            PluralCategory::Few => self.few = Some(value),
+            PluralCategory::Many => self.many = Some(value),
+            PluralCategory::Other => self.other = value,
+        }
+        self
+    }
+
+    /// Sets the value for the exact number `0`, such as `=0` in a message.
+    pub fn with_explicit_zero(mut self, value: T) -> Self {
+        self.explicit_zero = Some(value);
+        self
+    }
+
+    /// Sets the value for the exact number `1`, such as `=1` in a message.
+    pub fn with_explicit_one(mut self, value: T) -> Self {
+        self.explicit_one = Some(value);
+        self
+    }
+
+    /// Returns the value for the given [`Plural Category`], or the value for
+    /// [`PluralCategory::Other`] if the category has none.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn get(&self, category: PluralCategory) -> &T {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+
+    /// Returns the value for [`PluralCategory::Other`].
+    pub fn other(&self) -> &T {
+        &self.other
+    }
+
+    /// Returns the value for the exact number `0`, if any.
+    pub fn explicit_zero(&self) -> Option<&T> {
+        self.explicit_zero.as_ref()
+    }
+
+    /// Returns the value for the exact number `1`, if any.
+    pub fn explicit_one(&self) -> Option<&T> {
+        self.explicit_one.as_ref()
+    }
+
+    /// Returns the value for the exact number the operands represent, if any.
+    ///
+    /// Visible fraction digits do not prevent a match, so `1.0` matches the value for `1`.
+    pub(crate) fn get_explicit(&self, operands: &PluralOperands) -> Option<&T> {
+        if operands.f != 0 {
+            return None;
+        }
+        match operands.i {
+            0 => self.explicit_zero(),
+            1 => self.explicit_one(),
+            _ => None,
+        }
+    }
+}
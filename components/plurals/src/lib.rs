@@ -59,6 +59,17 @@
 //! * [`Cardinal`](PluralRuleType::Cardinal): `3 doors`, `1 month`, `10 dollars`
 //! * [`Ordinal`](PluralRuleType::Ordinal): `1st place`, `10th day`, `11th floor`
 //!
+//! ## Plural Elements
+//!
+//! Messages that vary with a number, such as `1 item` and `2 items`, can be stored in a
+//! [`PluralElements`], and the variant for a number is picked with
+//! [`PluralRules::select_element()`].
+//!
+//! ## Plural Ranges
+//!
+//! The [`Plural Category`] of a range of numbers, such as `1–2 Tage`, is determined from the
+//! categories of its start and its end by [`PluralRulesWithRanges`].
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`Plural Type`]: PluralRuleType
 //! [`Plural Category`]: PluralCategory
@@ -71,18 +82,22 @@
 extern crate alloc;
 
 mod data;
+mod elements;
 mod error;
 mod operands;
 pub mod provider;
+mod ranges;
 pub mod rules;
 
 use core::cmp::{Ord, PartialOrd};
 use core::convert::TryInto;
+pub use elements::PluralElements;
 pub use error::PluralRulesError;
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 pub use operands::PluralOperands;
 use provider::{resolver, PluralRuleStringsV1, PluralRuleStringsV1Marker};
+pub use ranges::PluralRulesWithRanges;
 
 /// A type of a plural rule which can be associated with the [`PluralRules`] struct.
 ///
@@ -272,6 +287,7 @@ impl PluralCategory {
 /// [`Plural Category`]: PluralCategory
 pub struct PluralRules {
     _langid: LanguageIdentifier,
+    resolved_locale: Option<LanguageIdentifier>,
     selector: data::RulesSelector,
 }
 
@@ -301,8 +317,37 @@ impl PluralRules {
         data_provider: &D,
         type_: PluralRuleType,
     ) -> Result<Self, PluralRulesError> {
-        let data = resolver::resolve_plural_data(langid.clone(), data_provider, type_)?;
-        Self::new_from_data(langid, data.get())
+        let response = resolver::load_plural_data(langid.clone(), data_provider, type_)?;
+        let resolved_locale = response.metadata.data_langid.clone();
+        let data = response.take_payload()?;
+        let mut rules = Self::new_from_data(langid, data.get())?;
+        rules.resolved_locale = resolved_locale;
+        Ok(rules)
+    }
+
+    /// Returns the locale of the plural rules loaded by [`PluralRules::try_new`], which differs
+    /// from the requested locale if the data provider fell back to a more general locale, such as
+    /// `en` for `en-IN`.
+    ///
+    /// Returns `None` if the rules were not loaded from a data provider, or if the data provider
+    /// did not report the locale of its data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralRuleType};
+    /// use icu_provider::fallback::LocaleFallbackProvider;
+    ///
+    /// let provider = LocaleFallbackProvider::new(icu_testdata::get_provider());
+    ///
+    /// let pr = PluralRules::try_new(langid!("en-IN"), &provider, PluralRuleType::Cardinal)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// assert_eq!(pr.resolved_locale(), Some(&langid!("en")));
+    /// ```
+    pub fn resolved_locale(&self) -> Option<&LanguageIdentifier> {
+        self.resolved_locale.as_ref()
     }
 
     /// Returns the [`Plural Category`] appropriate for the given number.
@@ -367,6 +412,47 @@ impl PluralRules {
         self.selector.select(&input.into())
     }
 
+    /// Returns the value of the [`PluralElements`] appropriate for the given number.
+    ///
+    /// The value for an exact number, such as `0`, takes precedence over the value for the
+    /// [`Plural Category`] of the number, which falls back to the value for
+    /// [`PluralCategory::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralCategory, PluralElements, PluralRules, PluralRuleType};
+    ///
+    /// let lid = langid!("en");
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new(lid, &dp, PluralRuleType::Cardinal)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// let elements = PluralElements::new("{0} items")
+    ///     .with_category(PluralCategory::One, "{0} item")
+    ///     .with_explicit_zero("no items");
+    ///
+    /// assert_eq!(pr.select_element(0_usize, &elements), &"no items");
+    /// assert_eq!(pr.select_element(1_usize, &elements), &"{0} item");
+    /// assert_eq!(pr.select_element(5_usize, &elements), &"{0} items");
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn select_element<'a, T, I: Into<PluralOperands>>(
+        &self,
+        input: I,
+        elements: &'a PluralElements<T>,
+    ) -> &'a T {
+        let operands = input.into();
+        match elements.get_explicit(&operands) {
+            Some(value) => value,
+            None => elements.get(self.selector.select(&operands)),
+        }
+    }
+
     /// Returns all [`Plural Categories`] appropriate for a [`PluralRules`] object
     /// based on the [`LanguageIdentifier`] and [`PluralRuleType`].
     ///
@@ -400,6 +486,47 @@ impl PluralRules {
         self.selector.categories()
     }
 
+    /// Constructs a new `PluralRules` from a set of plural rules in [`UTS #35`] syntax,
+    /// without loading any data from a data provider.
+    ///
+    /// The input is a `;`-separated list of rules, each prefixed by the name of its
+    /// [`Plural Category`], as accepted by [`parse_rules()`](rules::parse_rules()).
+    /// Samples are allowed and ignored. If several rules are provided for the same
+    /// category, the last one is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralCategory};
+    ///
+    /// let pr = PluralRules::try_new_from_rules(
+    ///     langid!("pl"),
+    ///     "one: i = 1 and v = 0; \
+    ///      few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14; \
+    ///      many: v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14",
+    /// )
+    /// .expect("Failed to parse the plural rules.");
+    ///
+    /// assert_eq!(pr.select(1_usize), PluralCategory::One);
+    /// assert_eq!(pr.select(23_usize), PluralCategory::Few);
+    /// assert_eq!(pr.select(12_usize), PluralCategory::Many);
+    /// ```
+    ///
+    /// [`UTS #35`]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+    /// [`Plural Category`]: PluralCategory
+    pub fn try_new_from_rules(
+        langid: LanguageIdentifier,
+        rules: &str,
+    ) -> Result<Self, PluralRulesError> {
+        let data: data::PluralRuleList = rules::parse_rules(rules.as_bytes())?.into();
+        Ok(Self {
+            _langid: langid,
+            resolved_locale: None,
+            selector: data.into(),
+        })
+    }
+
     /// Lower-level constructor that allows constructing a [`PluralRules`] directly from
     /// data obtained from a provider.
     pub fn new_from_data(
@@ -409,6 +536,7 @@ impl PluralRules {
         let data: data::PluralRuleList = data.try_into()?;
         Ok(Self {
             _langid: langid,
+            resolved_locale: None,
             selector: data.into(),
         })
     }
@@ -83,7 +83,8 @@ pub struct PluralOperands {
     pub f: u64,
     /// Visible fraction digits without trailing zeros
     pub t: u64,
-    /// Exponent of the power of 10 used in compact decimal formatting
+    /// Exponent of the power of 10 used in compact decimal formatting, such as `6` for "1.2M".
+    /// Also available as the `e` operand in plural rules.
     pub c: usize,
 }
 
@@ -98,6 +99,41 @@ impl PluralOperands {
         let fraction = self.t as f64 / 10_f64.powi(self.v as i32);
         self.i as f64 + fraction
     }
+
+    /// Returns the [`PluralOperands`] of a number displayed in compact decimal notation, given
+    /// the significand and the exponent of the compact form. For example, "1.2M" has the
+    /// significand `1.2` and the exponent `6`.
+    ///
+    /// The operands describe the full number, `1200000`, with [`c`](PluralOperands::c) set to
+    /// the exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// let significand = FixedDecimal::from(12).multiplied_pow10(-1).unwrap();
+    /// let operands = PluralOperands::try_from_compact(&significand, 6)
+    ///     .expect("The number fits in the operands.");
+    ///
+    /// assert_eq!(operands, "1.2c6".parse().unwrap());
+    /// assert_eq!(operands.i, 1_200_000);
+    /// assert_eq!(operands.c, 6);
+    /// ```
+    pub fn try_from_compact(
+        significand: &FixedDecimal,
+        exponent: u8,
+    ) -> Result<Self, OperandsError> {
+        let number = significand
+            .clone()
+            .multiplied_pow10(exponent as i16)
+            .map_err(|_| OperandsError::Invalid)?;
+        Ok(Self {
+            c: exponent as usize,
+            ..Self::from(&number)
+        })
+    }
 }
 
 #[derive(Display, Debug, PartialEq, Eq)]
@@ -126,8 +162,9 @@ impl From<std::io::Error> for OperandsError {
     }
 }
 
+/// Splits the compact decimal exponent, introduced by `c` or `e`, from the rest of the input.
 fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
-    if let Some(e_idx) = input.find('e') {
+    if let Some(e_idx) = input.find(|c| c == 'c' || c == 'e') {
         let e = usize::from_str(&input[e_idx + 1..])?;
         Ok((&input[..e_idx], e))
     } else {
@@ -138,52 +175,67 @@ fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
 impl FromStr for PluralOperands {
     type Err = OperandsError;
 
+    /// Parses a decimal number, optionally followed by a compact decimal exponent introduced
+    /// by `c` or `e`, such as `"1.2c6"` for "1.2M".
+    ///
+    /// As in the CLDR plural rules samples, the exponent shifts the decimal separator, so
+    /// `"1.2c6"` has the same operands as `"1200000"`, except for [`c`](PluralOperands::c).
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         if input.is_empty() {
             return Err(OperandsError::Empty);
         }
 
         let abs_str = input.strip_prefix('-').unwrap_or(input);
+        let (abs_str, exponent) = get_exponent(abs_str)?;
 
-        let (
-            integer_digits,
-            num_fraction_digits0,
-            num_fraction_digits,
-            fraction_digits0,
-            fraction_digits,
-            exponent,
-        ) = if let Some(sep_idx) = abs_str.find('.') {
-            let int_str = &abs_str[..sep_idx];
-            let (dec_str, exponent) = get_exponent(&abs_str[(sep_idx + 1)..])?;
+        let (int_str, dec_str) = match abs_str.find('.') {
+            Some(sep_idx) => (&abs_str[..sep_idx], &abs_str[(sep_idx + 1)..]),
+            None => (abs_str, ""),
+        };
 
-            let integer_digits = u64::from_str(int_str)?;
+        // Move the first `exponent` fraction digits into the integer part.
+        let (shifted_str, dec_str) = dec_str.split_at(core::cmp::min(exponent, dec_str.len()));
+        let mut integer_digits = u64::from_str(int_str)?;
+        for digit in shifted_str.bytes() {
+            if !digit.is_ascii_digit() {
+                return Err(OperandsError::Invalid);
+            }
+            integer_digits = integer_digits
+                .checked_mul(10)
+                .and_then(|i| i.checked_add((digit - b'0') as u64))
+                .ok_or(OperandsError::Invalid)?;
+        }
+        let remaining_exponent =
+            u32::try_from(exponent - shifted_str.len()).map_err(|_| OperandsError::Invalid)?;
+        integer_digits = 10_u64
+            .checked_pow(remaining_exponent)
+            .and_then(|scale| integer_digits.checked_mul(scale))
+            .ok_or(OperandsError::Invalid)?;
 
-            let dec_str_no_zeros = dec_str.trim_end_matches('0');
+        let (num_fraction_digits0, num_fraction_digits, fraction_digits0, fraction_digits) =
+            if dec_str.is_empty() {
+                (0, 0, 0, 0)
+            } else {
+                let dec_str_no_zeros = dec_str.trim_end_matches('0');
 
-            let num_fraction_digits0 = dec_str.len() as usize;
-            let num_fraction_digits = dec_str_no_zeros.len() as usize;
+                let num_fraction_digits0 = dec_str.len() as usize;
+                let num_fraction_digits = dec_str_no_zeros.len() as usize;
 
-            let fraction_digits0 = u64::from_str(dec_str)?;
-            let fraction_digits =
-                if num_fraction_digits == 0 || num_fraction_digits == num_fraction_digits0 {
-                    fraction_digits0
-                } else {
-                    u64::from_str(dec_str_no_zeros)?
-                };
+                let fraction_digits0 = u64::from_str(dec_str)?;
+                let fraction_digits =
+                    if num_fraction_digits == 0 || num_fraction_digits == num_fraction_digits0 {
+                        fraction_digits0
+                    } else {
+                        u64::from_str(dec_str_no_zeros)?
+                    };
 
-            (
-                integer_digits,
-                num_fraction_digits0,
-                num_fraction_digits,
-                fraction_digits0,
-                fraction_digits,
-                exponent,
-            )
-        } else {
-            let (abs_str, exponent) = get_exponent(abs_str)?;
-            let integer_digits = u64::from_str(abs_str)?;
-            (integer_digits, 0, 0, 0, 0, exponent)
-        };
+                (
+                    num_fraction_digits0,
+                    num_fraction_digits,
+                    fraction_digits0,
+                    fraction_digits,
+                )
+            };
 
         Ok(Self {
             i: integer_digits,
@@ -6,16 +6,32 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use crate::PluralCategory;
 use alloc::borrow::Cow;
+use core::fmt;
+use icu_provider::baked::Bake;
+use icu_provider::registry::RegisteredKeys;
 use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
 
 #[allow(missing_docs)] // TODO(#1029) - Add missing docs.
 pub mod key {
     use icu_provider::{resource_key, ResourceKey};
     pub const CARDINAL_V1: ResourceKey = resource_key!(Plurals, "cardinal", 1);
     pub const ORDINAL_V1: ResourceKey = resource_key!(Plurals, "ordinal", 1);
+    pub const RANGES_V1: ResourceKey = resource_key!(Plurals, "ranges", 1);
 }
 
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_plurals`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(
+        PluralRuleStringsV1Marker,
+        [key::CARDINAL_V1, key::ORDINAL_V1]
+    ),
+    icu_provider::registered_keys!(PluralRangesV1Marker, [key::RANGES_V1]),
+];
+
 pub mod resolver;
 
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
@@ -36,3 +52,58 @@ pub struct PluralRuleStringsV1<'data> {
     pub few: Option<Cow<'data, str>>,
     pub many: Option<Cow<'data, str>>,
 }
+
+impl Bake for PluralRuleStringsV1<'_> {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_plurals::provider::PluralRuleStringsV1 { zero: ")?;
+        self.zero.bake(sink)?;
+        sink.write_str(", one: ")?;
+        self.one.bake(sink)?;
+        sink.write_str(", two: ")?;
+        self.two.bake(sink)?;
+        sink.write_str(", few: ")?;
+        self.few.bake(sink)?;
+        sink.write_str(", many: ")?;
+        self.many.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
+/// Plural categories for ranges of numbers, such as "1–2 days". Maps the [`PluralCategory`] of
+/// the start of a range and the [`PluralCategory`] of its end to the category of the range.
+///
+/// Combinations missing from the data resolve to the category of the end of the range.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct PluralRangesV1 {
+    /// Mapping from the start category, then the end category, to the category of the range.
+    pub ranges: LiteMap<PluralCategory, LiteMap<PluralCategory, PluralCategory>>,
+}
+
+impl PluralRangesV1 {
+    /// Returns the category of a range from `start` to `end`, if present in the data.
+    pub fn get(&self, start: PluralCategory, end: PluralCategory) -> Option<PluralCategory> {
+        self.ranges.get(&start)?.get(&end).copied()
+    }
+
+    /// Sets the category of a range from `start` to `end`.
+    pub fn insert(&mut self, start: PluralCategory, end: PluralCategory, result: PluralCategory) {
+        match self.ranges.get_mut(&start) {
+            Some(ends) => {
+                ends.insert(end, result);
+            }
+            None => {
+                let mut ends = LiteMap::new();
+                ends.insert(end, result);
+                self.ranges.insert(start, ends);
+            }
+        }
+    }
+}
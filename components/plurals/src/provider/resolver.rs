@@ -4,7 +4,7 @@
 
 //! Code for resolving plural data.
 
-use super::PluralRuleStringsV1Marker;
+use super::{PluralRangesV1Marker, PluralRuleStringsV1Marker};
 use crate::{PluralRuleType, PluralRulesError};
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
@@ -15,14 +15,40 @@ pub fn resolve_plural_data<'data, D: DataProvider<'data, PluralRuleStringsV1Mark
     data_provider: &D,
     type_: PluralRuleType,
 ) -> Result<DataPayload<'data, PluralRuleStringsV1Marker>, PluralRulesError> {
+    Ok(load_plural_data(langid, data_provider, type_)?.take_payload()?)
+}
+
+/// Loads the plural rules for the given [`LanguageIdentifier`], along with the metadata of the
+/// response, such as the locale of the data.
+pub fn load_plural_data<'data, D: DataProvider<'data, PluralRuleStringsV1Marker> + ?Sized>(
+    langid: LanguageIdentifier,
+    data_provider: &D,
+    type_: PluralRuleType,
+) -> Result<DataResponse<'data, PluralRuleStringsV1Marker>, PluralRulesError> {
     let key = match type_ {
         PluralRuleType::Cardinal => super::key::CARDINAL_V1,
         PluralRuleType::Ordinal => super::key::ORDINAL_V1,
     };
+    Ok(data_provider.load_payload_with_context(&DataRequest {
+        resource_path: ResourcePath {
+            key,
+            options: ResourceOptions {
+                variant: None,
+                langid: Some(langid),
+            },
+        },
+    })?)
+}
+
+/// Loads the plural range data for the given [`LanguageIdentifier`].
+pub fn resolve_plural_ranges<'data, D: DataProvider<'data, PluralRangesV1Marker> + ?Sized>(
+    langid: LanguageIdentifier,
+    data_provider: &D,
+) -> Result<DataPayload<'data, PluralRangesV1Marker>, PluralRulesError> {
     Ok(data_provider
-        .load_payload(&DataRequest {
+        .load_payload_with_context(&DataRequest {
             resource_path: ResourcePath {
-                key,
+                key: super::key::RANGES_V1,
                 options: ResourceOptions {
                     variant: None,
                     langid: Some(langid),
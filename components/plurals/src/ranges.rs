@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{
+    resolver, PluralRangesV1, PluralRangesV1Marker, PluralRuleStringsV1, PluralRuleStringsV1Marker,
+};
+use crate::{PluralCategory, PluralOperands, PluralRuleType, PluralRules, PluralRulesError};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+
+/// A [`PluralRules`] extended with the ability to select the [`Plural Category`] of a range
+/// of numbers, such as "1–2 days".
+///
+/// The category of a range is determined from the categories of its start and its end,
+/// according to the [`Plural Ranges`] data of the language. Plural ranges are defined for
+/// [`Cardinal`](PluralRuleType::Cardinal) plural rules only.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::macros::langid;
+/// use icu::plurals::provider::{PluralRangesV1, PluralRuleStringsV1};
+/// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+///
+/// // Plural rules and range data for Latvian.
+/// let rules = PluralRuleStringsV1 {
+///     zero: Some("n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19".into()),
+///     one: Some("n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1".into()),
+///     ..Default::default()
+/// };
+/// let mut ranges = PluralRangesV1::default();
+/// ranges.insert(PluralCategory::Zero, PluralCategory::Zero, PluralCategory::Other);
+/// ranges.insert(PluralCategory::Zero, PluralCategory::One, PluralCategory::One);
+/// ranges.insert(PluralCategory::One, PluralCategory::Zero, PluralCategory::Other);
+/// ranges.insert(PluralCategory::Other, PluralCategory::Zero, PluralCategory::Other);
+///
+/// let pr = PluralRulesWithRanges::new_from_data(langid!("lv"), &rules, &ranges)
+///     .expect("Failed to construct a PluralRulesWithRanges struct.");
+///
+/// assert_eq!(pr.resolve_range(PluralCategory::Zero, PluralCategory::Zero), PluralCategory::Other);
+/// assert_eq!(pr.select_range(10_usize, 21_usize), PluralCategory::One);
+/// // Combinations missing from the data resolve to the category of the end.
+/// assert_eq!(pr.select_range(1_usize, 2_usize), PluralCategory::Other);
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+/// [`Plural Ranges`]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges
+pub struct PluralRulesWithRanges {
+    rules: PluralRules,
+    ranges: PluralRangesV1,
+}
+
+impl PluralRulesWithRanges {
+    /// Constructs a new `PluralRulesWithRanges` for cardinal plural rules of a given locale,
+    /// loading both the plural rules and the plural ranges from the [`data provider`].
+    ///
+    /// This constructor will fail if the [`data provider`] does not have the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let pr = PluralRulesWithRanges::try_new(langid!("en"), &InvariantDataProvider)
+    ///     .expect("Failed to construct a PluralRulesWithRanges struct.");
+    ///
+    /// assert_eq!(pr.select_range(1_usize, 2_usize), PluralCategory::Other);
+    /// ```
+    ///
+    /// [`data provider`]: icu_provider::DataProvider
+    pub fn try_new<'data, D>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<Self, PluralRulesError>
+    where
+        D: DataProvider<'data, PluralRuleStringsV1Marker>
+            + DataProvider<'data, PluralRangesV1Marker>
+            + ?Sized,
+    {
+        let rules = PluralRules::try_new(langid.clone(), data_provider, PluralRuleType::Cardinal)?;
+        let ranges = resolver::resolve_plural_ranges(langid, data_provider)?;
+        Ok(Self {
+            rules,
+            ranges: ranges.get().clone(),
+        })
+    }
+
+    /// Lower-level constructor that allows constructing a [`PluralRulesWithRanges`] directly
+    /// from data obtained from a provider.
+    pub fn new_from_data(
+        langid: LanguageIdentifier,
+        rules: &PluralRuleStringsV1,
+        ranges: &PluralRangesV1,
+    ) -> Result<Self, PluralRulesError> {
+        Ok(Self {
+            rules: PluralRules::new_from_data(langid, rules)?,
+            ranges: ranges.clone(),
+        })
+    }
+
+    /// Returns the underlying [`PluralRules`], used for selecting the category of a single
+    /// number.
+    pub fn rules(&self) -> &PluralRules {
+        &self.rules
+    }
+
+    /// Returns the [`Plural Category`] appropriate for the given number.
+    ///
+    /// See [`PluralRules::select()`].
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn select<I: Into<PluralOperands>>(&self, input: I) -> PluralCategory {
+        self.rules.select(input)
+    }
+
+    /// Returns the [`Plural Category`] appropriate for a range from `start` to `end`.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn select_range<S, E>(&self, start: S, end: E) -> PluralCategory
+    where
+        S: Into<PluralOperands>,
+        E: Into<PluralOperands>,
+    {
+        self.resolve_range(self.rules.select(start), self.rules.select(end))
+    }
+
+    /// Returns the [`Plural Category`] of a range, given the categories of its start and
+    /// its end.
+    ///
+    /// If the data has no entry for the combination, the category of the end is returned.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn resolve_range(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        self.ranges.get(start, end).unwrap_or(end)
+    }
+}
@@ -4,6 +4,7 @@
 
 use super::ast;
 use super::lexer::{Lexer, Token};
+use crate::PluralCategory;
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
@@ -11,21 +12,31 @@ use alloc::vec::Vec;
 use core::iter::Peekable;
 use displaydoc::Display;
 
+/// A list of possible error outcomes of parsing plural rules.
 #[derive(Display, Debug, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 pub enum ParserError {
+    /// An `or` was not followed by a condition.
     #[displaydoc("expected 'AND' condition")]
     ExpectedAndCondition,
+    /// An `and` was not followed by a relation.
     #[displaydoc("expected relation")]
     ExpectedRelation,
+    /// An expression was not followed by an operator.
     #[displaydoc("expected operator")]
     ExpectedOperator,
+    /// An operand was expected.
     #[displaydoc("expected operand")]
     ExpectedOperand,
+    /// A numeric value was expected.
     #[displaydoc("expected value")]
     ExpectedValue,
+    /// An `@` was not followed by `integer` or `decimal`.
     #[displaydoc("expected sample type")]
     ExpectedSampleType,
+    /// A rule in a set of rules was not prefixed by a known plural category and a `:`.
+    #[displaydoc("expected plural category")]
+    ExpectedCategory,
 }
 
 #[cfg(feature = "std")]
@@ -91,6 +102,48 @@ pub fn parse_condition(input: &[u8]) -> Result<ast::Condition, ParserError> {
     parser.parse_condition()
 }
 
+/// Parses a complete set of plural rules, as used by the plural rule descriptions of ICU
+/// and translation management systems, into a list of [`PluralCategory`]-[`Rule`] pairs.
+///
+/// The input is a `;`-separated list of rules, each prefixed by the name of its
+/// [`PluralCategory`] and a `:`. Each rule follows the [`parse`] syntax and may include
+/// samples.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::rules::parse_rules;
+/// use icu::plurals::PluralCategory;
+///
+/// let input = b"one: i = 1 and v = 0 @integer 1; other: @integer 0, 2~16";
+/// let rules = parse_rules(input).expect("Parsing failed.");
+///
+/// assert_eq!(rules.len(), 2);
+/// assert_eq!(rules[0].0, PluralCategory::One);
+/// assert_eq!(rules[1].0, PluralCategory::Other);
+/// ```
+///
+/// [`Rule`]: super::ast::Rule
+/// [`PluralCategory`]: crate::PluralCategory
+pub fn parse_rules(input: &[u8]) -> Result<Vec<(PluralCategory, ast::Rule)>, ParserError> {
+    let mut result = vec![];
+    for rule in input.split(|b| *b == b';') {
+        if rule.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let separator = rule
+            .iter()
+            .position(|b| *b == b':')
+            .ok_or(ParserError::ExpectedCategory)?;
+        let category = core::str::from_utf8(&rule[..separator])
+            .ok()
+            .and_then(|name| PluralCategory::from_tr35_string(name.trim()))
+            .ok_or(ParserError::ExpectedCategory)?;
+        result.push((category, parse(&rule[separator + 1..])?));
+    }
+    Ok(result)
+}
+
 struct Parser<'p> {
     lexer: Peekable<Lexer<'p>>,
 }
@@ -303,8 +356,16 @@ impl<'p> Parser<'p> {
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
+        // The compact decimal exponent is written as `c` since CLDR 38, and as `e` before.
+        let exponent = if self.take_if(Token::E) {
+            Some('e')
+        } else if self.take_if(Token::Operand(ast::Operand::C)) {
+            Some('c')
+        } else {
+            None
+        };
+        if let Some(exponent) = exponent {
+            s.push(exponent);
             match self.lexer.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
@@ -4,7 +4,7 @@
 
 use icu_locid_macros::langid;
 use icu_plurals::provider::{self, PluralRuleStringsV1};
-use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::{PluralCategory, PluralElements, PluralOperands, PluralRuleType, PluralRules};
 use icu_provider::prelude::*;
 use icu_provider::struct_provider::StructProvider;
 use std::borrow::Cow;
@@ -68,3 +68,72 @@ fn test_plural_rules_non_static_lifetime() {
     assert_eq!(pr.select(5_usize), PluralCategory::Other);
     assert_eq!(pr.select(11_usize), PluralCategory::One);
 }
+
+#[test]
+fn test_plural_rules_from_rules() {
+    let pr = PluralRules::try_new_from_rules(
+        langid!("en"),
+        "one: i = 1 and v = 0 @integer 1; other: @integer 0, 2~16, 100, 1000, …",
+    )
+    .unwrap();
+
+    assert_eq!(pr.select(1_usize), PluralCategory::One);
+    assert_eq!(pr.select(2_usize), PluralCategory::Other);
+
+    let mut categories = pr.categories();
+    assert_eq!(categories.next(), Some(&PluralCategory::One));
+    assert_eq!(categories.next(), Some(&PluralCategory::Other));
+    assert_eq!(categories.next(), None);
+}
+
+#[test]
+fn test_plural_rules_from_rules_invalid() {
+    assert!(PluralRules::try_new_from_rules(langid!("en"), "i = 1").is_err());
+    assert!(PluralRules::try_new_from_rules(langid!("en"), "single: i = 1").is_err());
+    assert!(PluralRules::try_new_from_rules(langid!("en"), "one: i = ").is_err());
+}
+
+#[test]
+fn test_plural_rules_compact_exponent() {
+    // French cardinal rules, which select "many" for millions in compact notation.
+    let pr = PluralRules::try_new_from_rules(
+        langid!("fr"),
+        "one: i = 0,1; \
+         many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5",
+    )
+    .unwrap();
+
+    let operands = |s: &str| -> PluralOperands { s.parse().unwrap() };
+
+    assert_eq!(pr.select(operands("1")), PluralCategory::One);
+    assert_eq!(pr.select(operands("1c3")), PluralCategory::Other);
+    assert_eq!(pr.select(operands("1000000")), PluralCategory::Many);
+    assert_eq!(pr.select(operands("1.2c6")), PluralCategory::Many);
+}
+
+#[test]
+fn test_plural_rules_select_element() {
+    let provider = icu_testdata::get_provider();
+
+    let pr = PluralRules::try_new(langid!("en"), &provider, PluralRuleType::Cardinal).unwrap();
+
+    let elements = PluralElements::new("other")
+        .with_category(PluralCategory::One, "one")
+        .with_explicit_one("explicit one");
+
+    assert_eq!(pr.select_element(1_usize, &elements), &"explicit one");
+    assert_eq!(
+        pr.select_element("1.0".parse::<PluralOperands>().unwrap(), &elements),
+        &"explicit one"
+    );
+    // There is no value for exactly zero.
+    assert_eq!(pr.select_element(0_usize, &elements), &"other");
+    assert_eq!(pr.select_element(21_usize, &elements), &"other");
+
+    let elements = PluralElements::new("other").with_category(PluralCategory::One, "one");
+    assert_eq!(pr.select_element(1_usize, &elements), &"one");
+    assert_eq!(
+        pr.select_element("1.5".parse::<PluralOperands>().unwrap(), &elements),
+        &"other"
+    );
+}
@@ -10,13 +10,20 @@
 //! Currently, only binary property APIs are supported, with APIs that return
 //! a [`UnicodeSet`]. See the [`sets`] module for more details.
 //!
+//! The names of the values of enumerated properties, such as `Script=Old_Hungarian`, can be
+//! mapped to and from their enum values with the APIs in the [`names`] module.
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [Unicode Properties]: https://unicode-org.github.io/icu/userguide/strings/properties.html
 //! [`UnicodeSet`]: icu_uniset::UnicodeSet
 //! [`sets`]: crate::sets
+//! [`names`]: crate::names
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
-#![no_std]
+extern crate alloc;
 
+pub mod names;
 mod props;
 pub mod provider;
 pub mod sets;
@@ -0,0 +1,132 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Mappings between the values of enumerated properties and their names.
+//!
+//! [`PropertyValueNameToEnumMapper`] parses a property value name, such as one entered by a
+//! user, into the corresponding enum value. [`PropertyEnumToValueNameMapper`] goes the other
+//! way, returning the short or long name of an enum value.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_properties::names::{PropertyEnumToValueNameMapper, PropertyValueNameToEnumMapper};
+//! use icu_properties::Script;
+//!
+//! let provider = icu_testdata::get_static_properties_provider();
+//!
+//! let parser = PropertyValueNameToEnumMapper::<Script>::try_new(&provider)
+//!     .expect("The data should be valid");
+//! assert_eq!(parser.get_loose("Old_Hungarian"), Some(Script::OldHungarian));
+//! assert_eq!(parser.get_loose("old hungarian"), Some(Script::OldHungarian));
+//! assert_eq!(parser.get_loose("Hung"), Some(Script::OldHungarian));
+//! assert_eq!(parser.get_loose("Hungarian"), None);
+//!
+//! let names = PropertyEnumToValueNameMapper::<Script>::try_new(&provider)
+//!     .expect("The data should be valid");
+//! assert_eq!(names.get_long(Script::OldHungarian), Some("Old_Hungarian"));
+//! assert_eq!(names.get_short(Script::OldHungarian), Some("Hung"));
+//! ```
+
+use crate::provider::*;
+use crate::Script;
+use core::marker::PhantomData;
+use icu_provider::prelude::*;
+
+/// An enumerated property whose value names are available from a data provider.
+pub trait NamedEnumeratedProperty: Copy + From<u16> + Into<u16> {
+    /// The resource key for the [`PropertyValueNamesV1`] of this property.
+    const NAMES_KEY: ResourceKey;
+}
+
+impl NamedEnumeratedProperty for Script {
+    const NAMES_KEY: ResourceKey = key::SCRIPT_NAMES_V1;
+}
+
+// helper fn
+fn get_names<'data, D>(
+    provider: &D,
+    resc_key: ResourceKey,
+) -> Result<DataPayload<'data, PropertyValueNamesV1Marker>, DataError>
+where
+    D: DataProvider<'data, PropertyValueNamesV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: resc_key,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    provider.load_payload(&data_req)?.take_payload()
+}
+
+/// Parses names of the values of the enumerated property `T` into enum values.
+pub struct PropertyValueNameToEnumMapper<'data, T> {
+    data: DataPayload<'data, PropertyValueNamesV1Marker>,
+    _property: PhantomData<T>,
+}
+
+impl<'data, T: NamedEnumeratedProperty> PropertyValueNameToEnumMapper<'data, T> {
+    /// Loads the value names of `T` from a data provider.
+    pub fn try_new<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<'data, PropertyValueNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            data: get_names(provider, T::NAMES_KEY)?,
+            _property: PhantomData,
+        })
+    }
+
+    /// Returns the value whose short name, long name, or other alias matches `name`, using the
+    /// loose matching rule of [`UAX44-LM3`]: case, spaces, underscores, hyphens, and an initial
+    /// "is" are ignored.
+    ///
+    /// [`UAX44-LM3`]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+    pub fn get_loose(&self, name: &str) -> Option<T> {
+        let names = self.data.get();
+        let normalized = PropertyValueNamesV1::normalize_name(name);
+        names
+            .get_value(&normalized)
+            .or_else(|| names.get_value(normalized.strip_prefix("is")?))
+            .map(T::from)
+    }
+}
+
+/// Returns the short and long names of the values of the enumerated property `T`.
+pub struct PropertyEnumToValueNameMapper<'data, T> {
+    data: DataPayload<'data, PropertyValueNamesV1Marker>,
+    _property: PhantomData<T>,
+}
+
+impl<'data, T: NamedEnumeratedProperty> PropertyEnumToValueNameMapper<'data, T> {
+    /// Loads the value names of `T` from a data provider.
+    pub fn try_new<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<'data, PropertyValueNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            data: get_names(provider, T::NAMES_KEY)?,
+            _property: PhantomData,
+        })
+    }
+
+    /// Returns the short name of `value`, such as "Hung" for [`Script::OldHungarian`], or
+    /// `None` if the data has no name for it.
+    pub fn get_short(&self, value: T) -> Option<&str> {
+        let name = self.data.get().short_names.get(value.into() as usize)?;
+        Some(name).filter(|name| !name.is_empty())
+    }
+
+    /// Returns the long name of `value`, such as "Old_Hungarian" for
+    /// [`Script::OldHungarian`], or `None` if the data has no name for it.
+    pub fn get_long(&self, value: T) -> Option<&str> {
+        let name = self.data.get().long_names.get(value.into() as usize)?;
+        Some(name).filter(|name| !name.is_empty())
+    }
+}
@@ -23,7 +23,11 @@ pub enum EnumeratedProperty {
 /// Enumerated Unicode general category types.
 /// GeneralSubcategory only supports specific subcategories (eg `UppercaseLetter`).
 /// It does not support grouped categories (eg `Letter`). For grouped categories, use [`GeneralCategory`].
-#[derive(Copy, Clone, PartialEq, Debug, TryFromPrimitive, UnsafeFromPrimitive)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, TryFromPrimitive, UnsafeFromPrimitive)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[repr(u8)]
 pub enum GeneralSubcategory {
     /// A reserved unassigned code point or a noncharacter
@@ -234,9 +238,26 @@ impl From<GeneralSubcategory> for GeneralCategory {
 /// For more information, see UAX #24: <http://www.unicode.org/reports/tr24/>.
 /// See UScriptCode in ICU4C.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[repr(transparent)]
 pub struct Script(pub(crate) u16);
 
+impl From<u16> for Script {
+    /// Creates a [`Script`] from its `UScriptCode` value in ICU4C.
+    fn from(value: u16) -> Self {
+        Script(value)
+    }
+}
+
+impl From<Script> for u16 {
+    fn from(script: Script) -> Self {
+        script.0
+    }
+}
+
 #[allow(missing_docs)] // These constants don't need individual documentation.
 #[allow(non_upper_case_globals)]
 impl Script {
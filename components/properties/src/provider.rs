@@ -6,9 +6,12 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::string::String;
+use icu_provider::registry::RegisteredKeys;
 use icu_provider::yoke::{self, *};
 use icu_uniset::UnicodeSet;
 use icu_uniset::UnicodeSetBuilder;
+use zerovec::{VarZeroVec, ZeroVec};
 
 //
 // resource key structs - the structs used directly by users of data provider
@@ -32,7 +35,7 @@ pub mod key {
         };
     }
 
-    define_resource_keys!(265;
+    define_resource_keys!(427;
 
         //
         // Binary properties
@@ -312,9 +315,243 @@ pub mod key {
         (SCRIPT_YEZIDI_V1, "sc=Yezi"),
         (SCRIPT_YI_V1, "sc=Yiii"),
         (SCRIPT_ZANABAZAR_SQUARE_V1, "sc=Zanb"),
+        (SCRIPT_EXTENSIONS_ADLAM_V1, "scx=Adlm"),
+        (SCRIPT_EXTENSIONS_AHOM_V1, "scx=Ahom"),
+        (SCRIPT_EXTENSIONS_ANATOLIAN_HIEROGLYPHS_V1, "scx=Hluw"),
+        (SCRIPT_EXTENSIONS_ARABIC_V1, "scx=Arab"),
+        (SCRIPT_EXTENSIONS_ARMENIAN_V1, "scx=Armn"),
+        (SCRIPT_EXTENSIONS_AVESTAN_V1, "scx=Avst"),
+        (SCRIPT_EXTENSIONS_BALINESE_V1, "scx=Bali"),
+        (SCRIPT_EXTENSIONS_BAMUM_V1, "scx=Bamu"),
+        (SCRIPT_EXTENSIONS_BASSA_VAH_V1, "scx=Bass"),
+        (SCRIPT_EXTENSIONS_BATAK_V1, "scx=Batk"),
+        (SCRIPT_EXTENSIONS_BENGALI_V1, "scx=Beng"),
+        (SCRIPT_EXTENSIONS_BHAIKSUKI_V1, "scx=Bhks"),
+        (SCRIPT_EXTENSIONS_BOPOMOFO_V1, "scx=Bopo"),
+        (SCRIPT_EXTENSIONS_BRAHMI_V1, "scx=Brah"),
+        (SCRIPT_EXTENSIONS_BRAILLE_V1, "scx=Brai"),
+        (SCRIPT_EXTENSIONS_BUGINESE_V1, "scx=Bugi"),
+        (SCRIPT_EXTENSIONS_BUHID_V1, "scx=Buhd"),
+        (SCRIPT_EXTENSIONS_CANADIAN_ABORIGINAL_V1, "scx=Cans"),
+        (SCRIPT_EXTENSIONS_CARIAN_V1, "scx=Cari"),
+        (SCRIPT_EXTENSIONS_CAUCASIAN_ALBANIAN_V1, "scx=Aghb"),
+        (SCRIPT_EXTENSIONS_CHAKMA_V1, "scx=Cakm"),
+        (SCRIPT_EXTENSIONS_CHAM_V1, "scx=Cham"),
+        (SCRIPT_EXTENSIONS_CHEROKEE_V1, "scx=Cher"),
+        (SCRIPT_EXTENSIONS_CHORASMIAN_V1, "scx=Chrs"),
+        (SCRIPT_EXTENSIONS_COMMON_V1, "scx=Zyyy"),
+        (SCRIPT_EXTENSIONS_COPTIC_V1, "scx=Copt"),
+        (SCRIPT_EXTENSIONS_CUNEIFORM_V1, "scx=Xsux"),
+        (SCRIPT_EXTENSIONS_CYPRIOT_V1, "scx=Cprt"),
+        (SCRIPT_EXTENSIONS_CYPRO_MINOAN_V1, "scx=Cpmn"),
+        (SCRIPT_EXTENSIONS_CYRILLIC_V1, "scx=Cyrl"),
+        (SCRIPT_EXTENSIONS_DESERET_V1, "scx=Dsrt"),
+        (SCRIPT_EXTENSIONS_DEVANAGARI_V1, "scx=Deva"),
+        (SCRIPT_EXTENSIONS_DIVES_AKURU_V1, "scx=Diak"),
+        (SCRIPT_EXTENSIONS_DOGRA_V1, "scx=Dogr"),
+        (SCRIPT_EXTENSIONS_DUPLOYAN_V1, "scx=Dupl"),
+        (SCRIPT_EXTENSIONS_EGYPTIAN_HIEROGLYPHS_V1, "scx=Egyp"),
+        (SCRIPT_EXTENSIONS_ELBASAN_V1, "scx=Elba"),
+        (SCRIPT_EXTENSIONS_ELYMAIC_V1, "scx=Elym"),
+        (SCRIPT_EXTENSIONS_ETHIOPIC_V1, "scx=Ethi"),
+        (SCRIPT_EXTENSIONS_GEORGIAN_V1, "scx=Geor"),
+        (SCRIPT_EXTENSIONS_GLAGOLITIC_V1, "scx=Glag"),
+        (SCRIPT_EXTENSIONS_GOTHIC_V1, "scx=Goth"),
+        (SCRIPT_EXTENSIONS_GRANTHA_V1, "scx=Gran"),
+        (SCRIPT_EXTENSIONS_GREEK_V1, "scx=Grek"),
+        (SCRIPT_EXTENSIONS_GUJARATI_V1, "scx=Gujr"),
+        (SCRIPT_EXTENSIONS_GUNJALA_GONDI_V1, "scx=Gong"),
+        (SCRIPT_EXTENSIONS_GURMUKHI_V1, "scx=Guru"),
+        (SCRIPT_EXTENSIONS_HAN_V1, "scx=Hani"),
+        (SCRIPT_EXTENSIONS_HANGUL_V1, "scx=Hang"),
+        (SCRIPT_EXTENSIONS_HANIFI_ROHINGYA_V1, "scx=Rohg"),
+        (SCRIPT_EXTENSIONS_HANUNOO_V1, "scx=Hano"),
+        (SCRIPT_EXTENSIONS_HATRAN_V1, "scx=Hatr"),
+        (SCRIPT_EXTENSIONS_HEBREW_V1, "scx=Hebr"),
+        (SCRIPT_EXTENSIONS_HIRAGANA_V1, "scx=Hira"),
+        (SCRIPT_EXTENSIONS_IMPERIAL_ARAMAIC_V1, "scx=Armi"),
+        (SCRIPT_EXTENSIONS_INHERITED_V1, "scx=Zinh"),
+        (SCRIPT_EXTENSIONS_INSCRIPTIONAL_PAHLAVI_V1, "scx=Phli"),
+        (SCRIPT_EXTENSIONS_INSCRIPTIONAL_PARTHIAN_V1, "scx=Prti"),
+        (SCRIPT_EXTENSIONS_JAVANESE_V1, "scx=Java"),
+        (SCRIPT_EXTENSIONS_KAITHI_V1, "scx=Kthi"),
+        (SCRIPT_EXTENSIONS_KANNADA_V1, "scx=Knda"),
+        (SCRIPT_EXTENSIONS_KATAKANA_V1, "scx=Kana"),
+        (SCRIPT_EXTENSIONS_KAYAH_LI_V1, "scx=Kali"),
+        (SCRIPT_EXTENSIONS_KHAROSHTHI_V1, "scx=Khar"),
+        (SCRIPT_EXTENSIONS_KHITAN_SMALL_SCRIPT_V1, "scx=Kits"),
+        (SCRIPT_EXTENSIONS_KHMER_V1, "scx=Khmr"),
+        (SCRIPT_EXTENSIONS_KHOJKI_V1, "scx=Khoj"),
+        (SCRIPT_EXTENSIONS_KHUDAWADI_V1, "scx=Sind"),
+        (SCRIPT_EXTENSIONS_LAO_V1, "scx=Laoo"),
+        (SCRIPT_EXTENSIONS_LATIN_V1, "scx=Latn"),
+        (SCRIPT_EXTENSIONS_LEPCHA_V1, "scx=Lepc"),
+        (SCRIPT_EXTENSIONS_LIMBU_V1, "scx=Limb"),
+        (SCRIPT_EXTENSIONS_LINEAR_A_V1, "scx=Lina"),
+        (SCRIPT_EXTENSIONS_LINEAR_B_V1, "scx=Linb"),
+        (SCRIPT_EXTENSIONS_LISU_V1, "scx=Lisu"),
+        (SCRIPT_EXTENSIONS_LYCIAN_V1, "scx=Lyci"),
+        (SCRIPT_EXTENSIONS_LYDIAN_V1, "scx=Lydi"),
+        (SCRIPT_EXTENSIONS_MAHAJANI_V1, "scx=Mahj"),
+        (SCRIPT_EXTENSIONS_MAKASAR_V1, "scx=Maka"),
+        (SCRIPT_EXTENSIONS_MALAYALAM_V1, "scx=Mlym"),
+        (SCRIPT_EXTENSIONS_MANDAIC_V1, "scx=Mand"),
+        (SCRIPT_EXTENSIONS_MANICHAEAN_V1, "scx=Mani"),
+        (SCRIPT_EXTENSIONS_MARCHEN_V1, "scx=Marc"),
+        (SCRIPT_EXTENSIONS_MASARAM_GONDI_V1, "scx=Gonm"),
+        (SCRIPT_EXTENSIONS_MEDEFAIDRIN_V1, "scx=Medf"),
+        (SCRIPT_EXTENSIONS_MEETEI_MAYEK_V1, "scx=Mtei"),
+        (SCRIPT_EXTENSIONS_MENDE_KIKAKUI_V1, "scx=Mend"),
+        (SCRIPT_EXTENSIONS_MEROITIC_CURSIVE_V1, "scx=Merc"),
+        (SCRIPT_EXTENSIONS_MEROITIC_HIEROGLYPHS_V1, "scx=Mero"),
+        (SCRIPT_EXTENSIONS_MIAO_V1, "scx=Plrd"),
+        (SCRIPT_EXTENSIONS_MODI_V1, "scx=Modi"),
+        (SCRIPT_EXTENSIONS_MONGOLIAN_V1, "scx=Mong"),
+        (SCRIPT_EXTENSIONS_MRO_V1, "scx=Mroo"),
+        (SCRIPT_EXTENSIONS_MULTANI_V1, "scx=Mult"),
+        (SCRIPT_EXTENSIONS_MYANMAR_V1, "scx=Mymr"),
+        (SCRIPT_EXTENSIONS_NABATAEAN_V1, "scx=Nbat"),
+        (SCRIPT_EXTENSIONS_NANDINAGARI_V1, "scx=Nand"),
+        (SCRIPT_EXTENSIONS_NEW_TAI_LUE_V1, "scx=Talu"),
+        (SCRIPT_EXTENSIONS_NEWA_V1, "scx=Newa"),
+        (SCRIPT_EXTENSIONS_NKO_V1, "scx=Nkoo"),
+        (SCRIPT_EXTENSIONS_NUSHU_V1, "scx=Nshu"),
+        (SCRIPT_EXTENSIONS_NYIAKENG_PUACHUE_HMONG_V1, "scx=Hmnp"),
+        (SCRIPT_EXTENSIONS_OGHAM_V1, "scx=Ogam"),
+        (SCRIPT_EXTENSIONS_OL_CHIKI_V1, "scx=Olck"),
+        (SCRIPT_EXTENSIONS_OLD_HUNGARIAN_V1, "scx=Hung"),
+        (SCRIPT_EXTENSIONS_OLD_ITALIC_V1, "scx=Ital"),
+        (SCRIPT_EXTENSIONS_OLD_NORTH_ARABIAN_V1, "scx=Narb"),
+        (SCRIPT_EXTENSIONS_OLD_PERMIC_V1, "scx=Perm"),
+        (SCRIPT_EXTENSIONS_OLD_PERSIAN_V1, "scx=Xpeo"),
+        (SCRIPT_EXTENSIONS_OLD_SOGDIAN_V1, "scx=Sogo"),
+        (SCRIPT_EXTENSIONS_OLD_SOUTH_ARABIAN_V1, "scx=Sarb"),
+        (SCRIPT_EXTENSIONS_OLD_TURKIC_V1, "scx=Orkh"),
+        (SCRIPT_EXTENSIONS_OLD_UYGHUR_V1, "scx=Ougr"),
+        (SCRIPT_EXTENSIONS_ORIYA_V1, "scx=Orya"),
+        (SCRIPT_EXTENSIONS_OSAGE_V1, "scx=Osge"),
+        (SCRIPT_EXTENSIONS_OSMANYA_V1, "scx=Osma"),
+        (SCRIPT_EXTENSIONS_PAHAWH_HMONG_V1, "scx=Hmng"),
+        (SCRIPT_EXTENSIONS_PALMYRENE_V1, "scx=Palm"),
+        (SCRIPT_EXTENSIONS_PAU_CIN_HAU_V1, "scx=Pauc"),
+        (SCRIPT_EXTENSIONS_PHAGS_PA_V1, "scx=Phag"),
+        (SCRIPT_EXTENSIONS_PHOENICIAN_V1, "scx=Phnx"),
+        (SCRIPT_EXTENSIONS_PSALTER_PAHLAVI_V1, "scx=Phlp"),
+        (SCRIPT_EXTENSIONS_REJANG_V1, "scx=Rjng"),
+        (SCRIPT_EXTENSIONS_RUNIC_V1, "scx=Runr"),
+        (SCRIPT_EXTENSIONS_SAMARITAN_V1, "scx=Samr"),
+        (SCRIPT_EXTENSIONS_SAURASHTRA_V1, "scx=Saur"),
+        (SCRIPT_EXTENSIONS_SHARADA_V1, "scx=Shrd"),
+        (SCRIPT_EXTENSIONS_SHAVIAN_V1, "scx=Shaw"),
+        (SCRIPT_EXTENSIONS_SIDDHAM_V1, "scx=Sidd"),
+        (SCRIPT_EXTENSIONS_SIGNWRITING_V1, "scx=Sgnw"),
+        (SCRIPT_EXTENSIONS_SINHALA_V1, "scx=Sinh"),
+        (SCRIPT_EXTENSIONS_SOGDIAN_V1, "scx=Sogd"),
+        (SCRIPT_EXTENSIONS_SORA_SOMPENG_V1, "scx=Sora"),
+        (SCRIPT_EXTENSIONS_SOYOMBO_V1, "scx=Soyo"),
+        (SCRIPT_EXTENSIONS_SUNDANESE_V1, "scx=Sund"),
+        (SCRIPT_EXTENSIONS_SYLOTI_NAGRI_V1, "scx=Sylo"),
+        (SCRIPT_EXTENSIONS_SYRIAC_V1, "scx=Syrc"),
+        (SCRIPT_EXTENSIONS_TAGALOG_V1, "scx=Tglg"),
+        (SCRIPT_EXTENSIONS_TAGBANWA_V1, "scx=Tagb"),
+        (SCRIPT_EXTENSIONS_TAI_LE_V1, "scx=Tale"),
+        (SCRIPT_EXTENSIONS_TAI_THAM_V1, "scx=Lana"),
+        (SCRIPT_EXTENSIONS_TAI_VIET_V1, "scx=Tavt"),
+        (SCRIPT_EXTENSIONS_TAKRI_V1, "scx=Takr"),
+        (SCRIPT_EXTENSIONS_TAMIL_V1, "scx=Taml"),
+        (SCRIPT_EXTENSIONS_TANGSA_V1, "scx=Tnsa"),
+        (SCRIPT_EXTENSIONS_TANGUT_V1, "scx=Tang"),
+        (SCRIPT_EXTENSIONS_TELUGU_V1, "scx=Telu"),
+        (SCRIPT_EXTENSIONS_THAANA_V1, "scx=Thaa"),
+        (SCRIPT_EXTENSIONS_THAI_V1, "scx=Thai"),
+        (SCRIPT_EXTENSIONS_TIBETAN_V1, "scx=Tibt"),
+        (SCRIPT_EXTENSIONS_TIFINAGH_V1, "scx=Tfng"),
+        (SCRIPT_EXTENSIONS_TIRHUTA_V1, "scx=Tirh"),
+        (SCRIPT_EXTENSIONS_TOTO_V1, "scx=Toto"),
+        (SCRIPT_EXTENSIONS_UGARITIC_V1, "scx=Ugar"),
+        (SCRIPT_EXTENSIONS_UNKNOWN_V1, "scx=Zzzz"),
+        (SCRIPT_EXTENSIONS_VAI_V1, "scx=Vaii"),
+        (SCRIPT_EXTENSIONS_VITHKUQI_V1, "scx=Vith"),
+        (SCRIPT_EXTENSIONS_WANCHO_V1, "scx=Wcho"),
+        (SCRIPT_EXTENSIONS_WARANG_CITI_V1, "scx=Wara"),
+        (SCRIPT_EXTENSIONS_YEZIDI_V1, "scx=Yezi"),
+        (SCRIPT_EXTENSIONS_YI_V1, "scx=Yiii"),
+        (SCRIPT_EXTENSIONS_ZANABAZAR_SQUARE_V1, "scx=Zanb"),
     );
+
+    /// Resource key for the General_Category property as a map from code points to
+    /// [`GeneralSubcategory`](crate::GeneralSubcategory).
+    pub const GENERAL_CATEGORY_V1: ResourceKey = resource_key!(UnicodeSet, "gc", 1);
+
+    /// Resource key for the Script property as a map from code points to
+    /// [`Script`](crate::Script).
+    pub const SCRIPT_V1: ResourceKey = resource_key!(UnicodeSet, "sc", 1);
+
+    /// The set of all resource keys for code point maps, whose data struct is
+    /// [`UnicodePropertyMapV1`](icu_codepointtrie::provider::UnicodePropertyMapV1).
+    pub const ALL_MAP_KEYS: [ResourceKey; 2] = [GENERAL_CATEGORY_V1, SCRIPT_V1];
+
+    /// Resource key for the names of the values of the Script property.
+    pub const SCRIPT_NAMES_V1: ResourceKey = resource_key!(UnicodeSet, "sc_names", 1);
+
+    /// The set of all resource keys for the names of property values, whose data struct is
+    /// [`PropertyValueNamesV1`](super::PropertyValueNamesV1).
+    pub const ALL_NAMES_KEYS: [ResourceKey; 1] = [SCRIPT_NAMES_V1];
+
+    /// Resource key for the Basic_Emoji property of [`UTS #51`].
+    ///
+    /// [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+    pub const BASIC_EMOJI_V1: ResourceKey = resource_key!(UnicodeSet, "Basic_Emoji", 1);
+
+    /// Resource key for the Emoji_Keycap_Sequence property of [`UTS #51`].
+    ///
+    /// [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+    pub const EMOJI_KEYCAP_SEQUENCE_V1: ResourceKey = resource_key!(UnicodeSet, "EKeycap_Seq", 1);
+
+    /// Resource key for the RGI_Emoji_Modifier_Sequence property of [`UTS #51`].
+    ///
+    /// [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+    pub const RGI_EMOJI_MODIFIER_SEQUENCE_V1: ResourceKey =
+        resource_key!(UnicodeSet, "RGI_EMod_Seq", 1);
+
+    /// Resource key for the RGI_Emoji_Flag_Sequence property of [`UTS #51`].
+    ///
+    /// [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+    pub const RGI_EMOJI_FLAG_SEQUENCE_V1: ResourceKey =
+        resource_key!(UnicodeSet, "RGI_EFlag_Seq", 1);
+
+    /// Resource key for the RGI_Emoji_ZWJ_Sequence property of [`UTS #51`].
+    ///
+    /// [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+    pub const RGI_EMOJI_ZWJ_SEQUENCE_V1: ResourceKey = resource_key!(UnicodeSet, "RGI_EZWJ_Seq", 1);
+
+    /// The set of all resource keys for properties of strings, whose data struct is
+    /// [`UnicodePropertyStringsV1`](super::UnicodePropertyStringsV1).
+    pub const ALL_STRINGS_KEYS: [ResourceKey; 5] = [
+        BASIC_EMOJI_V1,
+        EMOJI_KEYCAP_SEQUENCE_V1,
+        RGI_EMOJI_MODIFIER_SEQUENCE_V1,
+        RGI_EMOJI_FLAG_SEQUENCE_V1,
+        RGI_EMOJI_ZWJ_SEQUENCE_V1,
+    ];
 }
 
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_properties`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(UnicodePropertyV1Marker, key::ALL_KEYS),
+    RegisteredKeys {
+        marker: "icu_codepointtrie::provider::UnicodePropertyMapV1Marker<icu_properties::GeneralSubcategory>",
+        keys: &[key::GENERAL_CATEGORY_V1],
+    },
+    RegisteredKeys {
+        marker: "icu_codepointtrie::provider::UnicodePropertyMapV1Marker<icu_properties::Script>",
+        keys: &[key::SCRIPT_V1],
+    },
+    icu_provider::registered_keys!(PropertyValueNamesV1Marker, key::ALL_NAMES_KEYS),
+    icu_provider::registered_keys!(UnicodePropertyStringsV1Marker, key::ALL_STRINGS_KEYS),
+];
+
 /// A set of characters with a particular property.
 #[icu_provider::data_struct]
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -349,3 +586,107 @@ impl<'data> From<UnicodePropertyV1<'data>> for UnicodeSet<'data> {
         prop.inv_list
     }
 }
+
+/// A set of strings with a particular property, such as the emoji sequences of
+/// [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+///
+/// Strings of a single code point are stored in a [`UnicodeSet`], and longer strings in a
+/// sorted list.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UnicodePropertyStringsV1<'data> {
+    /// The strings of a single code point, represented as an inversion list
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub inv_list: UnicodeSet<'data>,
+
+    /// The strings of more than one code point, sorted
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub strings: VarZeroVec<'data, str>,
+}
+
+impl<'data> UnicodePropertyStringsV1<'data> {
+    /// Returns whether the set contains `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::sets;
+    ///
+    /// let provider = icu_testdata::get_static_properties_provider();
+    /// let payload = sets::get_emoji_keycap_sequence(&provider).expect("The data should be valid");
+    /// let keycaps = payload.get();
+    ///
+    /// assert!(keycaps.contains("#\u{FE0F}\u{20E3}")); // #️⃣
+    /// assert!(!keycaps.contains("#"));
+    /// assert!(!keycaps.contains("A\u{FE0F}\u{20E3}"));
+    /// ```
+    pub fn contains(&self, s: &str) -> bool {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.inv_list.contains(c),
+            _ => self.strings.binary_search(s).is_ok(),
+        }
+    }
+
+    /// Returns an iterator over the strings of more than one code point in the set.
+    pub fn iter_strings(&self) -> impl Iterator<Item = &str> + '_ {
+        (0..self.strings.len()).filter_map(move |i| self.strings.get(i))
+    }
+}
+
+/// The names of the values of an enumerated property, from `PropertyValueAliases.txt` of the
+/// Unicode Character Database.
+///
+/// Values are identified by their integer representation, such as the `UScriptCode` of a
+/// [`Script`](crate::Script).
+#[icu_provider::data_struct]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PropertyValueNamesV1<'data> {
+    /// The short name, the long name, and the other aliases of each value, in the form returned
+    /// by [`normalize_name`](Self::normalize_name), sorted.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub normalized_names: VarZeroVec<'data, str>,
+
+    /// The value named by each entry of `normalized_names`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub normalized_name_values: ZeroVec<'data, u16>,
+
+    /// The short name of each value, indexed by value. Integers that are not values of the
+    /// property have an empty name.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub short_names: VarZeroVec<'data, str>,
+
+    /// The long name of each value, indexed by value. Integers that are not values of the
+    /// property have an empty name.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub long_names: VarZeroVec<'data, str>,
+}
+
+impl PropertyValueNamesV1<'_> {
+    /// Returns the value with the given name, which must be in the form returned by
+    /// [`normalize_name`](Self::normalize_name).
+    pub fn get_value(&self, normalized_name: &str) -> Option<u16> {
+        let index = self.normalized_names.binary_search(normalized_name).ok()?;
+        self.normalized_name_values.get(index)
+    }
+
+    /// Returns the form of a property value name used for loose matching, as described in
+    /// [`UAX44-LM3`]: ASCII letters are lowercased, and spaces, underscores, and hyphens are
+    /// removed.
+    ///
+    /// [`UAX44-LM3`]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+    pub fn normalize_name(name: &str) -> String {
+        name.chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    }
+}
@@ -10,6 +10,20 @@
 //! documentation for Unicode regular expressions. In particular, Annex C of this document
 //! defines properties for POSIX compatibility.
 //!
+//! # Examples
+//!
+//! ```
+//! use icu_properties::sets;
+//!
+//! let provider = icu_testdata::get_static_properties_provider();
+//! let payload = sets::get_white_space(&provider).expect("The data should be valid");
+//! let white_space = &payload.get().inv_list;
+//!
+//! assert!(white_space.contains(' '));
+//! assert!(white_space.contains('\u{3000}')); // IDEOGRAPHIC SPACE
+//! assert!(!white_space.contains('A'));
+//! ```
+//!
 //! [`UnicodeSet`]: icu_uniset::UnicodeSet
 //! [`TR44`]: https://www.unicode.org/reports/tr44
 //! [`TR18`]: https://www.unicode.org/reports/tr18
@@ -20,6 +34,8 @@ use icu_provider::prelude::*;
 use icu_uniset::UnicodeSetError;
 
 type UnisetResult<'data> = Result<DataPayload<'data, UnicodePropertyV1Marker>, UnicodeSetError>;
+type StringsResult<'data> =
+    Result<DataPayload<'data, UnicodePropertyStringsV1Marker>, UnicodeSetError>;
 
 // helper fn
 fn get_uniset<'data, D>(provider: &D, resc_key: ResourceKey) -> UnisetResult<'data>
@@ -36,7 +52,8 @@ where
         },
     };
 
-    let resp: DataResponse<UnicodePropertyV1Marker> = provider.load_payload(&data_req)?;
+    let resp: DataResponse<UnicodePropertyV1Marker> =
+        provider.load_payload_with_context(&data_req)?;
 
     let property_payload: DataPayload<UnicodePropertyV1Marker> = resp.take_payload()?;
     Ok(property_payload)
@@ -604,6 +621,96 @@ where
     get_uniset(provider, key::XID_START_V1)
 }
 
+//
+// Property of strings getter fns
+//
+
+// helper fn
+fn get_strings<'data, D>(provider: &D, resc_key: ResourceKey) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: resc_key,
+            options: ResourceOptions {
+                variant: None,
+                langid: None,
+            },
+        },
+    };
+
+    let resp: DataResponse<UnicodePropertyStringsV1Marker> =
+        provider.load_payload_with_context(&data_req)?;
+
+    Ok(resp.take_payload()?)
+}
+
+/// Emoji characters and emoji presentation sequences that are displayed as emoji by default,
+/// such as U+231A WATCH and U+00A9 COPYRIGHT SIGN followed by U+FE0F VARIATION SELECTOR-16.
+/// See [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+///
+/// # Examples
+///
+/// ```
+/// use icu_properties::sets;
+///
+/// let provider = icu_testdata::get_static_properties_provider();
+/// let payload = sets::get_basic_emoji(&provider).expect("The data should be valid");
+/// let basic_emoji = payload.get();
+///
+/// assert!(basic_emoji.contains("\u{231A}")); // ⌚ WATCH
+/// assert!(basic_emoji.contains("\u{00A9}\u{FE0F}")); // ©️
+/// assert!(!basic_emoji.contains("\u{00A9}")); // © without emoji presentation
+/// assert!(!basic_emoji.contains("A"));
+/// ```
+pub fn get_basic_emoji<'data, D>(provider: &D) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    get_strings(provider, key::BASIC_EMOJI_V1)
+}
+
+/// Keycap sequences, such as `#️⃣`: one of `#`, `*`, or an ASCII digit, followed by U+FE0F
+/// VARIATION SELECTOR-16 and U+20E3 COMBINING ENCLOSING KEYCAP.
+/// See [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+pub fn get_emoji_keycap_sequence<'data, D>(provider: &D) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    get_strings(provider, key::EMOJI_KEYCAP_SEQUENCE_V1)
+}
+
+/// Emoji modifier sequences recommended for general interchange (RGI): an emoji modifier base
+/// followed by a skin tone modifier.
+/// See [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+pub fn get_rgi_emoji_modifier_sequence<'data, D>(provider: &D) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    get_strings(provider, key::RGI_EMOJI_MODIFIER_SEQUENCE_V1)
+}
+
+/// Flag sequences recommended for general interchange (RGI): pairs of regional indicators
+/// that stand for a region code, such as 🇨🇭.
+/// See [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+pub fn get_rgi_emoji_flag_sequence<'data, D>(provider: &D) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    get_strings(provider, key::RGI_EMOJI_FLAG_SEQUENCE_V1)
+}
+
+/// Zero width joiner sequences recommended for general interchange (RGI): emoji joined with
+/// U+200D ZERO WIDTH JOINER, such as 👩‍🔬.
+/// See [`UTS #51`](https://www.unicode.org/reports/tr51/#Emoji_Sets).
+pub fn get_rgi_emoji_zwj_sequence<'data, D>(provider: &D) -> StringsResult<'data>
+where
+    D: DataProvider<'data, UnicodePropertyStringsV1Marker> + ?Sized,
+{
+    get_strings(provider, key::RGI_EMOJI_ZWJ_SEQUENCE_V1)
+}
+
 //
 // Enumerated property getter fns
 //
@@ -839,3 +946,203 @@ where
     };
     get_uniset(provider, key)
 }
+
+/// Return a [`UnicodeSet`] for the code points whose Script_Extensions Unicode property
+/// contains a particular script. See <https://www.unicode.org/reports/tr24/> .
+///
+/// Unlike [`get_for_script`], which only contains the code points whose Script is the given
+/// script, this is the set matched by `\p{scx=...}` in regular expressions. For example, the
+/// Arabic comma U+060C is used by several scripts, so its Script is Common, but it is in the
+/// Script_Extensions set of Arabic.
+///
+/// # Examples
+///
+/// ```
+/// use icu_properties::{sets, Script};
+///
+/// let provider = icu_testdata::get_static_properties_provider();
+/// let payload = sets::get_for_script_extensions(&provider, Script::Arabic)
+///     .expect("The data should be valid");
+/// let arabic = &payload.get().inv_list;
+///
+/// assert!(arabic.contains('\u{0627}')); // ARABIC LETTER ALEF
+/// assert!(arabic.contains('\u{060C}')); // ARABIC COMMA
+/// assert!(!arabic.contains('A'));
+///
+/// let payload = sets::get_for_script(&provider, Script::Arabic)
+///     .expect("The data should be valid");
+/// assert!(!payload.get().inv_list.contains('\u{060C}'));
+/// ```
+///
+/// [`UnicodeSet`]: icu_uniset::UnicodeSet
+pub fn get_for_script_extensions<'data, D>(provider: &'data D, enum_val: Script) -> UnisetResult
+where
+    D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized,
+{
+    let key = match enum_val {
+        Script::Adlam => key::SCRIPT_EXTENSIONS_ADLAM_V1,
+        Script::Ahom => key::SCRIPT_EXTENSIONS_AHOM_V1,
+        Script::AnatolianHieroglyphs => key::SCRIPT_EXTENSIONS_ANATOLIAN_HIEROGLYPHS_V1,
+        Script::Arabic => key::SCRIPT_EXTENSIONS_ARABIC_V1,
+        Script::Armenian => key::SCRIPT_EXTENSIONS_ARMENIAN_V1,
+        Script::Avestan => key::SCRIPT_EXTENSIONS_AVESTAN_V1,
+        Script::Balinese => key::SCRIPT_EXTENSIONS_BALINESE_V1,
+        Script::Bamum => key::SCRIPT_EXTENSIONS_BAMUM_V1,
+        Script::BassaVah => key::SCRIPT_EXTENSIONS_BASSA_VAH_V1,
+        Script::Batak => key::SCRIPT_EXTENSIONS_BATAK_V1,
+        Script::Bengali => key::SCRIPT_EXTENSIONS_BENGALI_V1,
+        Script::Bhaiksuki => key::SCRIPT_EXTENSIONS_BHAIKSUKI_V1,
+        Script::Bopomofo => key::SCRIPT_EXTENSIONS_BOPOMOFO_V1,
+        Script::Brahmi => key::SCRIPT_EXTENSIONS_BRAHMI_V1,
+        Script::Braille => key::SCRIPT_EXTENSIONS_BRAILLE_V1,
+        Script::Buginese => key::SCRIPT_EXTENSIONS_BUGINESE_V1,
+        Script::Buhid => key::SCRIPT_EXTENSIONS_BUHID_V1,
+        Script::CanadianAboriginal => key::SCRIPT_EXTENSIONS_CANADIAN_ABORIGINAL_V1,
+        Script::Carian => key::SCRIPT_EXTENSIONS_CARIAN_V1,
+        Script::CaucasianAlbanian => key::SCRIPT_EXTENSIONS_CAUCASIAN_ALBANIAN_V1,
+        Script::Chakma => key::SCRIPT_EXTENSIONS_CHAKMA_V1,
+        Script::Cham => key::SCRIPT_EXTENSIONS_CHAM_V1,
+        Script::Cherokee => key::SCRIPT_EXTENSIONS_CHEROKEE_V1,
+        Script::Chorasmian => key::SCRIPT_EXTENSIONS_CHORASMIAN_V1,
+        Script::Common => key::SCRIPT_EXTENSIONS_COMMON_V1,
+        Script::Coptic => key::SCRIPT_EXTENSIONS_COPTIC_V1,
+        Script::Cuneiform => key::SCRIPT_EXTENSIONS_CUNEIFORM_V1,
+        Script::Cypriot => key::SCRIPT_EXTENSIONS_CYPRIOT_V1,
+        Script::CyproMinoan => key::SCRIPT_EXTENSIONS_CYPRO_MINOAN_V1,
+        Script::Cyrillic => key::SCRIPT_EXTENSIONS_CYRILLIC_V1,
+        Script::Deseret => key::SCRIPT_EXTENSIONS_DESERET_V1,
+        Script::Devanagari => key::SCRIPT_EXTENSIONS_DEVANAGARI_V1,
+        Script::DivesAkuru => key::SCRIPT_EXTENSIONS_DIVES_AKURU_V1,
+        Script::Dogra => key::SCRIPT_EXTENSIONS_DOGRA_V1,
+        Script::Duployan => key::SCRIPT_EXTENSIONS_DUPLOYAN_V1,
+        Script::EgyptianHieroglyphs => key::SCRIPT_EXTENSIONS_EGYPTIAN_HIEROGLYPHS_V1,
+        Script::Elbasan => key::SCRIPT_EXTENSIONS_ELBASAN_V1,
+        Script::Elymaic => key::SCRIPT_EXTENSIONS_ELYMAIC_V1,
+        Script::Ethiopic => key::SCRIPT_EXTENSIONS_ETHIOPIC_V1,
+        Script::Georgian => key::SCRIPT_EXTENSIONS_GEORGIAN_V1,
+        Script::Glagolitic => key::SCRIPT_EXTENSIONS_GLAGOLITIC_V1,
+        Script::Gothic => key::SCRIPT_EXTENSIONS_GOTHIC_V1,
+        Script::Grantha => key::SCRIPT_EXTENSIONS_GRANTHA_V1,
+        Script::Greek => key::SCRIPT_EXTENSIONS_GREEK_V1,
+        Script::Gujarati => key::SCRIPT_EXTENSIONS_GUJARATI_V1,
+        Script::GunjalaGondi => key::SCRIPT_EXTENSIONS_GUNJALA_GONDI_V1,
+        Script::Gurmukhi => key::SCRIPT_EXTENSIONS_GURMUKHI_V1,
+        Script::Han => key::SCRIPT_EXTENSIONS_HAN_V1,
+        Script::Hangul => key::SCRIPT_EXTENSIONS_HANGUL_V1,
+        Script::HanifiRohingya => key::SCRIPT_EXTENSIONS_HANIFI_ROHINGYA_V1,
+        Script::Hanunoo => key::SCRIPT_EXTENSIONS_HANUNOO_V1,
+        Script::Hatran => key::SCRIPT_EXTENSIONS_HATRAN_V1,
+        Script::Hebrew => key::SCRIPT_EXTENSIONS_HEBREW_V1,
+        Script::Hiragana => key::SCRIPT_EXTENSIONS_HIRAGANA_V1,
+        Script::ImperialAramaic => key::SCRIPT_EXTENSIONS_IMPERIAL_ARAMAIC_V1,
+        Script::Inherited => key::SCRIPT_EXTENSIONS_INHERITED_V1,
+        Script::InscriptionalPahlavi => key::SCRIPT_EXTENSIONS_INSCRIPTIONAL_PAHLAVI_V1,
+        Script::InscriptionalParthian => key::SCRIPT_EXTENSIONS_INSCRIPTIONAL_PARTHIAN_V1,
+        Script::Javanese => key::SCRIPT_EXTENSIONS_JAVANESE_V1,
+        Script::Kaithi => key::SCRIPT_EXTENSIONS_KAITHI_V1,
+        Script::Kannada => key::SCRIPT_EXTENSIONS_KANNADA_V1,
+        Script::Katakana => key::SCRIPT_EXTENSIONS_KATAKANA_V1,
+        Script::KayahLi => key::SCRIPT_EXTENSIONS_KAYAH_LI_V1,
+        Script::Kharoshthi => key::SCRIPT_EXTENSIONS_KHAROSHTHI_V1,
+        Script::KhitanSmallScript => key::SCRIPT_EXTENSIONS_KHITAN_SMALL_SCRIPT_V1,
+        Script::Khmer => key::SCRIPT_EXTENSIONS_KHMER_V1,
+        Script::Khojki => key::SCRIPT_EXTENSIONS_KHOJKI_V1,
+        Script::Khudawadi => key::SCRIPT_EXTENSIONS_KHUDAWADI_V1,
+        Script::Lao => key::SCRIPT_EXTENSIONS_LAO_V1,
+        Script::Latin => key::SCRIPT_EXTENSIONS_LATIN_V1,
+        Script::Lepcha => key::SCRIPT_EXTENSIONS_LEPCHA_V1,
+        Script::Limbu => key::SCRIPT_EXTENSIONS_LIMBU_V1,
+        Script::LinearA => key::SCRIPT_EXTENSIONS_LINEAR_A_V1,
+        Script::LinearB => key::SCRIPT_EXTENSIONS_LINEAR_B_V1,
+        Script::Lisu => key::SCRIPT_EXTENSIONS_LISU_V1,
+        Script::Lycian => key::SCRIPT_EXTENSIONS_LYCIAN_V1,
+        Script::Lydian => key::SCRIPT_EXTENSIONS_LYDIAN_V1,
+        Script::Mahajani => key::SCRIPT_EXTENSIONS_MAHAJANI_V1,
+        Script::Makasar => key::SCRIPT_EXTENSIONS_MAKASAR_V1,
+        Script::Malayalam => key::SCRIPT_EXTENSIONS_MALAYALAM_V1,
+        Script::Mandaic => key::SCRIPT_EXTENSIONS_MANDAIC_V1,
+        Script::Manichaean => key::SCRIPT_EXTENSIONS_MANICHAEAN_V1,
+        Script::Marchen => key::SCRIPT_EXTENSIONS_MARCHEN_V1,
+        Script::MasaramGondi => key::SCRIPT_EXTENSIONS_MASARAM_GONDI_V1,
+        Script::Medefaidrin => key::SCRIPT_EXTENSIONS_MEDEFAIDRIN_V1,
+        Script::MeeteiMayek => key::SCRIPT_EXTENSIONS_MEETEI_MAYEK_V1,
+        Script::MendeKikakui => key::SCRIPT_EXTENSIONS_MENDE_KIKAKUI_V1,
+        Script::MeroiticCursive => key::SCRIPT_EXTENSIONS_MEROITIC_CURSIVE_V1,
+        Script::MeroiticHieroglyphs => key::SCRIPT_EXTENSIONS_MEROITIC_HIEROGLYPHS_V1,
+        Script::Miao => key::SCRIPT_EXTENSIONS_MIAO_V1,
+        Script::Modi => key::SCRIPT_EXTENSIONS_MODI_V1,
+        Script::Mongolian => key::SCRIPT_EXTENSIONS_MONGOLIAN_V1,
+        Script::Mro => key::SCRIPT_EXTENSIONS_MRO_V1,
+        Script::Multani => key::SCRIPT_EXTENSIONS_MULTANI_V1,
+        Script::Myanmar => key::SCRIPT_EXTENSIONS_MYANMAR_V1,
+        Script::Nabataean => key::SCRIPT_EXTENSIONS_NABATAEAN_V1,
+        Script::Nandinagari => key::SCRIPT_EXTENSIONS_NANDINAGARI_V1,
+        Script::NewTaiLue => key::SCRIPT_EXTENSIONS_NEW_TAI_LUE_V1,
+        Script::Newa => key::SCRIPT_EXTENSIONS_NEWA_V1,
+        Script::Nko => key::SCRIPT_EXTENSIONS_NKO_V1,
+        Script::Nushu => key::SCRIPT_EXTENSIONS_NUSHU_V1,
+        Script::NyiakengPuachueHmong => key::SCRIPT_EXTENSIONS_NYIAKENG_PUACHUE_HMONG_V1,
+        Script::Ogham => key::SCRIPT_EXTENSIONS_OGHAM_V1,
+        Script::OlChiki => key::SCRIPT_EXTENSIONS_OL_CHIKI_V1,
+        Script::OldHungarian => key::SCRIPT_EXTENSIONS_OLD_HUNGARIAN_V1,
+        Script::OldItalic => key::SCRIPT_EXTENSIONS_OLD_ITALIC_V1,
+        Script::OldNorthArabian => key::SCRIPT_EXTENSIONS_OLD_NORTH_ARABIAN_V1,
+        Script::OldPermic => key::SCRIPT_EXTENSIONS_OLD_PERMIC_V1,
+        Script::OldPersian => key::SCRIPT_EXTENSIONS_OLD_PERSIAN_V1,
+        Script::OldSogdian => key::SCRIPT_EXTENSIONS_OLD_SOGDIAN_V1,
+        Script::OldSouthArabian => key::SCRIPT_EXTENSIONS_OLD_SOUTH_ARABIAN_V1,
+        Script::OldTurkic => key::SCRIPT_EXTENSIONS_OLD_TURKIC_V1,
+        Script::OldUyghur => key::SCRIPT_EXTENSIONS_OLD_UYGHUR_V1,
+        Script::Oriya => key::SCRIPT_EXTENSIONS_ORIYA_V1,
+        Script::Osage => key::SCRIPT_EXTENSIONS_OSAGE_V1,
+        Script::Osmanya => key::SCRIPT_EXTENSIONS_OSMANYA_V1,
+        Script::PahawhHmong => key::SCRIPT_EXTENSIONS_PAHAWH_HMONG_V1,
+        Script::Palmyrene => key::SCRIPT_EXTENSIONS_PALMYRENE_V1,
+        Script::PauCinHau => key::SCRIPT_EXTENSIONS_PAU_CIN_HAU_V1,
+        Script::PhagsPa => key::SCRIPT_EXTENSIONS_PHAGS_PA_V1,
+        Script::Phoenician => key::SCRIPT_EXTENSIONS_PHOENICIAN_V1,
+        Script::PsalterPahlavi => key::SCRIPT_EXTENSIONS_PSALTER_PAHLAVI_V1,
+        Script::Rejang => key::SCRIPT_EXTENSIONS_REJANG_V1,
+        Script::Runic => key::SCRIPT_EXTENSIONS_RUNIC_V1,
+        Script::Samaritan => key::SCRIPT_EXTENSIONS_SAMARITAN_V1,
+        Script::Saurashtra => key::SCRIPT_EXTENSIONS_SAURASHTRA_V1,
+        Script::Sharada => key::SCRIPT_EXTENSIONS_SHARADA_V1,
+        Script::Shavian => key::SCRIPT_EXTENSIONS_SHAVIAN_V1,
+        Script::Siddham => key::SCRIPT_EXTENSIONS_SIDDHAM_V1,
+        Script::SignWriting => key::SCRIPT_EXTENSIONS_SIGNWRITING_V1,
+        Script::Sinhala => key::SCRIPT_EXTENSIONS_SINHALA_V1,
+        Script::Sogdian => key::SCRIPT_EXTENSIONS_SOGDIAN_V1,
+        Script::SoraSompeng => key::SCRIPT_EXTENSIONS_SORA_SOMPENG_V1,
+        Script::Soyombo => key::SCRIPT_EXTENSIONS_SOYOMBO_V1,
+        Script::Sundanese => key::SCRIPT_EXTENSIONS_SUNDANESE_V1,
+        Script::SylotiNagri => key::SCRIPT_EXTENSIONS_SYLOTI_NAGRI_V1,
+        Script::Syriac => key::SCRIPT_EXTENSIONS_SYRIAC_V1,
+        Script::Tagalog => key::SCRIPT_EXTENSIONS_TAGALOG_V1,
+        Script::Tagbanwa => key::SCRIPT_EXTENSIONS_TAGBANWA_V1,
+        Script::TaiLe => key::SCRIPT_EXTENSIONS_TAI_LE_V1,
+        Script::TaiTham => key::SCRIPT_EXTENSIONS_TAI_THAM_V1,
+        Script::TaiViet => key::SCRIPT_EXTENSIONS_TAI_VIET_V1,
+        Script::Takri => key::SCRIPT_EXTENSIONS_TAKRI_V1,
+        Script::Tamil => key::SCRIPT_EXTENSIONS_TAMIL_V1,
+        Script::Tangsa => key::SCRIPT_EXTENSIONS_TANGSA_V1,
+        Script::Tangut => key::SCRIPT_EXTENSIONS_TANGUT_V1,
+        Script::Telugu => key::SCRIPT_EXTENSIONS_TELUGU_V1,
+        Script::Thaana => key::SCRIPT_EXTENSIONS_THAANA_V1,
+        Script::Thai => key::SCRIPT_EXTENSIONS_THAI_V1,
+        Script::Tibetan => key::SCRIPT_EXTENSIONS_TIBETAN_V1,
+        Script::Tifinagh => key::SCRIPT_EXTENSIONS_TIFINAGH_V1,
+        Script::Tirhuta => key::SCRIPT_EXTENSIONS_TIRHUTA_V1,
+        Script::Toto => key::SCRIPT_EXTENSIONS_TOTO_V1,
+        Script::Ugaritic => key::SCRIPT_EXTENSIONS_UGARITIC_V1,
+        Script::Unknown => key::SCRIPT_EXTENSIONS_UNKNOWN_V1,
+        Script::Vai => key::SCRIPT_EXTENSIONS_VAI_V1,
+        Script::Vithkuqi => key::SCRIPT_EXTENSIONS_VITHKUQI_V1,
+        Script::Wancho => key::SCRIPT_EXTENSIONS_WANCHO_V1,
+        Script::WarangCiti => key::SCRIPT_EXTENSIONS_WARANG_CITI_V1,
+        Script::Yezidi => key::SCRIPT_EXTENSIONS_YEZIDI_V1,
+        Script::Yi => key::SCRIPT_EXTENSIONS_YI_V1,
+        Script::ZanabazarSquare => key::SCRIPT_EXTENSIONS_ZANABAZAR_SQUARE_V1,
+        _ => return Err(UnicodeSetError::UnknownScriptId(enum_val.0)),
+    };
+    get_uniset(provider, key)
+}
@@ -5,6 +5,7 @@
 use crate::{GeneralSubcategory, Script};
 
 use core::convert::TryFrom;
+use icu_codepointtrie::codepointtrie::TrieValue;
 use num_enum::TryFromPrimitiveError;
 use zerovec::ule::{AsULE, PlainOldULE, ULE};
 
@@ -59,3 +60,11 @@ impl AsULE for Script {
         Script(u16::from_le_bytes(unaligned.0))
     }
 }
+
+impl TrieValue for GeneralSubcategory {
+    const DATA_GET_ERROR_VALUE: GeneralSubcategory = GeneralSubcategory::Unassigned;
+}
+
+impl TrieValue for Script {
+    const DATA_GET_ERROR_VALUE: Script = Script::Unknown;
+}
@@ -0,0 +1,471 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The resolution of the embedding levels of a paragraph, following the rules of
+//! <https://www.unicode.org/reports/tr9/#The_Paragraph_Level> up to rule I2.
+
+use crate::props::{BidiClass, Level};
+use crate::provider::BidiBracketsV1;
+use alloc::vec;
+use alloc::vec::Vec;
+use zerovec::ule::AsULE;
+
+use BidiClass::{
+    ArabicLetter as AL, ArabicNumber as AN, BoundaryNeutral as BN, CommonSeparator as CS,
+    EuropeanNumber as EN, EuropeanSeparator as ES, EuropeanTerminator as ET,
+    FirstStrongIsolate as FSI, LeftToRight as L, LeftToRightEmbedding as LRE,
+    LeftToRightIsolate as LRI, LeftToRightOverride as LRO, NonspacingMark as NSM,
+    OtherNeutral as ON, ParagraphSeparator as B, PopDirectionalFormat as PDF,
+    PopDirectionalIsolate as PDI, RightToLeft as R, RightToLeftEmbedding as RLE,
+    RightToLeftIsolate as RLI, RightToLeftOverride as RLO, SegmentSeparator as S, WhiteSpace as WS,
+};
+
+/// The maximum number of nested opening brackets that are paired, see BD16.
+const MAX_BRACKET_DEPTH: usize = 63;
+
+pub(crate) fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(class, LRI | RLI | FSI)
+}
+
+/// Returns whether the class is removed by rule X9.
+pub(crate) fn is_removed_by_x9(class: BidiClass) -> bool {
+    matches!(class, RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+/// Returns the level of the first strong character, skipping the characters between isolate
+/// initiators and their matching PDIs, see rules P2 and P3.
+///
+/// If `isolate` is set, the classes are those after an FSI, and the search stops at the PDI
+/// that matches it.
+pub(crate) fn first_strong_level(classes: &[BidiClass], isolate: bool) -> Option<Level> {
+    let mut isolates = 0usize;
+    for &class in classes {
+        match class {
+            LRI | RLI | FSI => isolates += 1,
+            PDI if isolates > 0 => isolates -= 1,
+            PDI if isolate => break,
+            B => break,
+            L if isolates == 0 => return Some(Level::ltr()),
+            R | AL if isolates == 0 => return Some(Level::rtl()),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// An entry of the directional status stack of rules X1 to X8.
+#[derive(Copy, Clone)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Returns the least odd or even level greater than `level`.
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Returns the embedding levels of the characters of a paragraph, resolved with the rules up
+/// to I2. The characters removed by rule X9 get the level of the preceding character.
+pub(crate) fn resolve_levels(
+    chars: &[char],
+    original_classes: &[BidiClass],
+    paragraph_level: Level,
+    brackets: &BidiBracketsV1,
+) -> Vec<u8> {
+    let len = original_classes.len();
+    let mut classes = original_classes.to_vec();
+    let mut levels = vec![paragraph_level.0; len];
+
+    // BD9: match the isolate initiators with PDIs.
+    let mut matching_pdi = vec![None; len];
+    let mut open_isolates = Vec::new();
+    for (i, &class) in original_classes.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            open_isolates.push(i);
+        } else if class == PDI {
+            if let Some(initiator) = open_isolates.pop() {
+                matching_pdi[initiator] = Some(i);
+            }
+        }
+    }
+
+    // X1-X8: explicit levels and directions.
+    let mut stack = Vec::with_capacity(usize::from(Level::MAX_DEPTH) + 2);
+    stack.push(Status {
+        level: paragraph_level.0,
+        override_class: None,
+        isolate: false,
+    });
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+    for i in 0..len {
+        let last = *stack
+            .last()
+            .expect("The stack always contains the paragraph level");
+        match original_classes[i] {
+            class @ (RLE | LRE | RLO | LRO) => {
+                levels[i] = last.level;
+                let level = next_level(last.level, matches!(class, RLE | RLO));
+                if level <= Level::MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(Status {
+                        level,
+                        override_class: match class {
+                            RLO => Some(R),
+                            LRO => Some(L),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            class @ (RLI | LRI | FSI) => {
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[i] = override_class;
+                }
+                let rtl = match class {
+                    RLI => true,
+                    LRI => false,
+                    _ => {
+                        let end = matching_pdi[i].unwrap_or(len);
+                        first_strong_level(&original_classes[i + 1..end], true)
+                            == Some(Level::rtl())
+                    }
+                };
+                let level = next_level(last.level, rtl);
+                if level <= Level::MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while matches!(stack.last(), Some(status) if !status.isolate) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let last = *stack
+                    .last()
+                    .expect("The isolate entries are above the paragraph");
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[i] = override_class;
+                }
+            }
+            PDF => {
+                levels[i] = last.level;
+                if overflow_isolates > 0 {
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !last.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            B => levels[i] = paragraph_level.0,
+            BN => levels[i] = last.level,
+            _ => {
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    classes[i] = override_class;
+                }
+            }
+        }
+    }
+
+    // X9 and X10: the level runs of the characters that are not removed, and the isolating run
+    // sequences that they form.
+    let retained: Vec<usize> = (0..len)
+        .filter(|&i| !is_removed_by_x9(original_classes[i]))
+        .collect();
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut run_of = vec![usize::MAX; len];
+    for &i in &retained {
+        match runs.last_mut() {
+            Some(run) if levels[run[0]] == levels[i] => run.push(i),
+            _ => runs.push(vec![i]),
+        }
+        run_of[i] = runs.len() - 1;
+    }
+    let embedding_levels = levels.clone();
+    let mut appended = vec![false; runs.len()];
+    for start in 0..runs.len() {
+        if appended[start] {
+            continue;
+        }
+        let mut sequence = runs[start].clone();
+        let mut current = start;
+        loop {
+            let last = runs[current][runs[current].len() - 1];
+            let next = match matching_pdi[last] {
+                Some(pdi) if is_isolate_initiator(original_classes[last]) => run_of[pdi],
+                _ => break,
+            };
+            if runs[next][0] != matching_pdi[last].unwrap_or(usize::MAX) {
+                break;
+            }
+            appended[next] = true;
+            sequence.extend_from_slice(&runs[next]);
+            current = next;
+        }
+        let sequence_levels = resolve_sequence(
+            &sequence,
+            chars,
+            original_classes,
+            &classes,
+            &embedding_levels,
+            paragraph_level.0,
+            brackets,
+        );
+        for (i, level) in sequence.into_iter().zip(sequence_levels) {
+            levels[i] = level;
+        }
+    }
+
+    // The removed characters get the level of the preceding character.
+    for i in 0..len {
+        if is_removed_by_x9(original_classes[i]) {
+            levels[i] = if i > 0 {
+                levels[i - 1]
+            } else {
+                paragraph_level.0
+            };
+        }
+    }
+    levels
+}
+
+/// Returns the strong direction of a resolved class for rules N0 to N2, in which numbers are
+/// treated as right-to-left.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+/// Applies the rules W1 to I2 to an isolating run sequence, given by the indices of its
+/// characters in the paragraph, and returns the levels of its characters.
+///
+/// `classes` are the classes after the overrides of the rules X1 to X8, and `embedding_levels`
+/// the levels resolved by these rules.
+fn resolve_sequence(
+    sequence: &[usize],
+    chars: &[char],
+    original_classes: &[BidiClass],
+    classes: &[BidiClass],
+    embedding_levels: &[u8],
+    paragraph_level: u8,
+    brackets: &BidiBracketsV1,
+) -> Vec<u8> {
+    let first = sequence[0];
+    let last = sequence[sequence.len() - 1];
+    let level = embedding_levels[first];
+    let direction = |level: u8| if level % 2 == 1 { R } else { L };
+    let embedding_direction = direction(level);
+
+    // The start-of-sequence and end-of-sequence types, from the levels of the adjacent
+    // characters that are not removed by X9.
+    let before = (0..first)
+        .rev()
+        .find(|&i| !is_removed_by_x9(original_classes[i]))
+        .map_or(paragraph_level, |i| embedding_levels[i]);
+    let after = if is_isolate_initiator(original_classes[last]) {
+        paragraph_level
+    } else {
+        (last + 1..original_classes.len())
+            .find(|&i| !is_removed_by_x9(original_classes[i]))
+            .map_or(paragraph_level, |i| embedding_levels[i])
+    };
+    let sos = direction(level.max(before));
+    let eos = direction(level.max(after));
+
+    let mut types: Vec<BidiClass> = sequence.iter().map(|&i| classes[i]).collect();
+    let len = types.len();
+
+    // W1: nonspacing marks take the type of the previous character.
+    for k in 0..len {
+        if types[k] == NSM {
+            types[k] = match k.checked_sub(1).map(|k| types[k]) {
+                None => sos,
+                Some(LRI | RLI | FSI | PDI) => ON,
+                Some(previous) => previous,
+            };
+        }
+    }
+    // W2: European numbers after Arabic letters are Arabic numbers.
+    let mut last_strong = sos;
+    for t in types.iter_mut() {
+        match *t {
+            L | R | AL => last_strong = *t,
+            EN if last_strong == AL => *t = AN,
+            _ => {}
+        }
+    }
+    // W3
+    for t in types.iter_mut() {
+        if *t == AL {
+            *t = R;
+        }
+    }
+    // W4: single separators between numbers of the same type.
+    for k in 1..len.saturating_sub(1) {
+        let (previous, next) = (types[k - 1], types[k + 1]);
+        types[k] = match (types[k], previous, next) {
+            (ES, EN, EN) => EN,
+            (CS, EN, EN) => EN,
+            (CS, AN, AN) => AN,
+            (t, _, _) => t,
+        };
+    }
+    // W5: terminators adjacent to European numbers.
+    let mut k = 0;
+    while k < len {
+        if types[k] != ET {
+            k += 1;
+            continue;
+        }
+        let end = (k..len).find(|&j| types[j] != ET).unwrap_or(len);
+        if (k > 0 && types[k - 1] == EN) || (end < len && types[end] == EN) {
+            types[k..end].iter_mut().for_each(|t| *t = EN);
+        }
+        k = end;
+    }
+    // W6
+    for t in types.iter_mut() {
+        if matches!(*t, ES | ET | CS) {
+            *t = ON;
+        }
+    }
+    // W7: European numbers after left-to-right text.
+    let mut last_strong = sos;
+    for t in types.iter_mut() {
+        match *t {
+            L | R => last_strong = *t,
+            EN if last_strong == L => *t = L,
+            _ => {}
+        }
+    }
+
+    // N0: paired brackets.
+    let mut openers: Vec<(char, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+    for k in 0..len {
+        if types[k] != ON {
+            continue;
+        }
+        let c = chars[sequence[k]];
+        if let Some(closing) = brackets.opening.get(&c) {
+            if openers.len() == MAX_BRACKET_DEPTH {
+                break;
+            }
+            openers.push((char::from_unaligned(closing), k));
+        } else if let Some(closing) = brackets.closing.get(&c) {
+            let closing = char::from_unaligned(closing);
+            if let Some(index) = openers.iter().rposition(|&(c, _)| c == closing) {
+                pairs.push((openers[index].1, k));
+                openers.truncate(index);
+            }
+        }
+    }
+    pairs.sort_unstable();
+    for (open, close) in pairs {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for t in &types[open + 1..close] {
+            match strong_direction(*t) {
+                Some(d) if d == embedding_direction => {
+                    found_embedding = true;
+                    break;
+                }
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+        let resolved = if found_embedding {
+            embedding_direction
+        } else if found_opposite {
+            let context = types[..open]
+                .iter()
+                .rev()
+                .find_map(|&t| strong_direction(t))
+                .unwrap_or(sos);
+            if context != embedding_direction {
+                context
+            } else {
+                embedding_direction
+            }
+        } else {
+            continue;
+        };
+        for bracket in [open, close] {
+            types[bracket] = resolved;
+            // Nonspacing marks after a bracket take its new type.
+            for k in bracket + 1..len {
+                if original_classes[sequence[k]] != NSM {
+                    break;
+                }
+                types[k] = resolved;
+            }
+        }
+    }
+
+    // N1 and N2: sequences of neutrals take the direction of the surrounding text if it is the
+    // same on both sides, and the embedding direction otherwise.
+    let is_neutral = |t: BidiClass| matches!(t, B | S | WS | ON | LRI | RLI | FSI | PDI);
+    let mut k = 0;
+    while k < len {
+        if !is_neutral(types[k]) {
+            k += 1;
+            continue;
+        }
+        let end = (k..len).find(|&j| !is_neutral(types[j])).unwrap_or(len);
+        let previous = match k {
+            0 => sos,
+            _ => strong_direction(types[k - 1]).unwrap_or(embedding_direction),
+        };
+        let next = match end {
+            _ if end == len => eos,
+            _ => strong_direction(types[end]).unwrap_or(embedding_direction),
+        };
+        let resolved = if previous == next {
+            previous
+        } else {
+            embedding_direction
+        };
+        types[k..end].iter_mut().for_each(|t| *t = resolved);
+        k = end;
+    }
+
+    // I1 and I2: implicit levels.
+    types
+        .into_iter()
+        .map(|t| match (level % 2, t) {
+            (0, R) => level + 1,
+            (0, AN | EN) => level + 2,
+            (1, L | EN | AN) => level + 1,
+            _ => level,
+        })
+        .collect()
+}
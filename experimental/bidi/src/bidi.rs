@@ -0,0 +1,279 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::algorithm::{self, first_strong_level, is_isolate_initiator, is_removed_by_x9};
+use crate::error::Error;
+use crate::props::{BidiClass, Level};
+use crate::provider::*;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+use icu_provider::prelude::*;
+
+/// The bidi algorithm, with the character data that drives it.
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::{Bidi, BidiClass, Level};
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let bidi = Bidi::try_new(&provider).expect("Data should load successfully");
+/// assert_eq!(bidi.bidi_class('\u{05D0}'), BidiClass::RightToLeft);
+///
+/// // An Arabic paragraph that ends with a number: the digits stay in left-to-right order.
+/// let text = "\u{0627}\u{0644}\u{0639}\u{062F}\u{062F} 123";
+/// let info = bidi.analyze(text, None);
+/// let paragraph = &info.paragraphs[0];
+/// assert_eq!(paragraph.level, Level::rtl());
+/// assert_eq!(
+///     info.reorder_line(paragraph, paragraph.range.clone()),
+///     "123 \u{062F}\u{062F}\u{0639}\u{0644}\u{0627}"
+/// );
+/// ```
+pub struct Bidi<'data> {
+    classes: DataPayload<'data, BidiClassV1Marker>,
+    brackets: DataPayload<'data, BidiBracketsV1Marker>,
+}
+
+impl<'data> Bidi<'data> {
+    /// Creates the bidi algorithm with the data of the given provider.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, BidiClassV1Marker>
+            + DataProvider<'data, BidiBracketsV1Marker>
+            + ?Sized,
+    {
+        let classes: DataPayload<BidiClassV1Marker> = data_provider
+            .load_payload(&DataRequest::from(key::BIDI_CLASS_V1))?
+            .take_payload()?;
+        let brackets: DataPayload<BidiBracketsV1Marker> = data_provider
+            .load_payload(&DataRequest::from(key::BIDI_BRACKETS_V1))?
+            .take_payload()?;
+        Ok(Self { classes, brackets })
+    }
+
+    /// Returns the Bidi_Class of a character.
+    pub fn bidi_class(&self, c: char) -> BidiClass {
+        let data = self.classes.get();
+        let index = match data.starts.binary_search(&u32::from(c)) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+        data.classes
+            .get(index)
+            .and_then(BidiClass::from_u8)
+            .unwrap_or(BidiClass::LeftToRight)
+    }
+
+    /// Splits a text into paragraphs and resolves the embedding levels of its characters.
+    ///
+    /// Each paragraph gets the level `default_level` if it is given, and otherwise the level of
+    /// its first strong character, or [`Level::ltr()`] if it has none.
+    pub fn analyze<'text>(
+        &self,
+        text: &'text str,
+        default_level: Option<Level>,
+    ) -> BidiInfo<'text> {
+        let mut original_classes = Vec::with_capacity(text.len());
+        let mut levels = Vec::with_capacity(text.len());
+        let mut paragraphs = Vec::new();
+
+        // P1: the paragraphs end after paragraph separators, and after CR LF.
+        let mut chars = Vec::new();
+        let mut classes = Vec::new();
+        let mut start = 0;
+        let mut iter = text.char_indices().peekable();
+        while let Some((index, c)) = iter.next() {
+            let class = self.bidi_class(c);
+            chars.push(c);
+            classes.push(class);
+            let end = index + c.len_utf8();
+            let is_end = match iter.peek() {
+                None => true,
+                Some(&(_, next)) if c == '\r' => next != '\n',
+                Some(_) => class == BidiClass::ParagraphSeparator,
+            };
+            if !is_end {
+                continue;
+            }
+            // P2 and P3
+            let level = default_level
+                .or_else(|| first_strong_level(&classes, false))
+                .unwrap_or_else(Level::ltr);
+            let paragraph_levels =
+                algorithm::resolve_levels(&chars, &classes, level, self.brackets.get());
+            for ((&c, &class), &char_level) in chars.iter().zip(&classes).zip(&paragraph_levels) {
+                for _ in 0..c.len_utf8() {
+                    original_classes.push(class);
+                    levels.push(Level(char_level));
+                }
+            }
+            paragraphs.push(ParagraphInfo {
+                range: start..end,
+                level,
+            });
+            chars.clear();
+            classes.clear();
+            start = end;
+        }
+
+        BidiInfo {
+            text,
+            original_classes,
+            levels,
+            paragraphs,
+        }
+    }
+}
+
+/// A paragraph of a [`BidiInfo`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParagraphInfo {
+    /// The byte range of the paragraph in the text, including its paragraph separator.
+    pub range: Range<usize>,
+    /// The paragraph embedding level.
+    pub level: Level,
+}
+
+/// A run of characters with the same embedding level on a line, returned by
+/// [`BidiInfo::visual_runs()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VisualRun {
+    /// The byte range of the run in the text.
+    pub range: Range<usize>,
+    /// The level of the run; the characters of a run with an odd level are displayed from
+    /// right to left.
+    pub level: Level,
+}
+
+/// The result of [`Bidi::analyze()`]: the paragraphs of a text and the resolved embedding
+/// levels of its characters.
+#[derive(Clone, Debug)]
+pub struct BidiInfo<'text> {
+    /// The text.
+    pub text: &'text str,
+    /// The resolved level of each byte of the text, before the line-based rule L1 is applied.
+    pub levels: Vec<Level>,
+    /// The paragraphs of the text.
+    pub paragraphs: Vec<ParagraphInfo>,
+    /// The Bidi_Class of each byte of the text.
+    original_classes: Vec<BidiClass>,
+}
+
+impl<'text> BidiInfo<'text> {
+    /// Returns whether the text contains right-to-left characters, i.e. whether any level is
+    /// odd.
+    pub fn has_rtl(&self) -> bool {
+        self.levels.iter().any(|level| level.is_rtl())
+    }
+
+    /// Returns the levels of the bytes of a line, after the whitespace at the end of the line
+    /// and before separators is reset to the paragraph level (rule L1).
+    ///
+    /// `line` is a byte range within `paragraph`, on character boundaries.
+    pub fn reordered_levels(&self, paragraph: &ParagraphInfo, line: Range<usize>) -> Vec<Level> {
+        debug_assert!(paragraph.range.start <= line.start && line.end <= paragraph.range.end);
+        let mut levels = self.levels[line.clone()].to_vec();
+        let classes = &self.original_classes[line.clone()];
+        // The start of the trailing whitespace, which is reset if a separator or the end of the
+        // line follows.
+        let mut reset_start = None;
+        for (i, c) in self.text[line].char_indices() {
+            let class = classes[i];
+            match class {
+                BidiClass::SegmentSeparator | BidiClass::ParagraphSeparator => {
+                    let start = reset_start.take().unwrap_or(i);
+                    for level in &mut levels[start..i + c.len_utf8()] {
+                        *level = paragraph.level;
+                    }
+                }
+                BidiClass::WhiteSpace | BidiClass::PopDirectionalIsolate => {
+                    reset_start.get_or_insert(i);
+                }
+                _ if is_isolate_initiator(class) || is_removed_by_x9(class) => {
+                    reset_start.get_or_insert(i);
+                }
+                _ => reset_start = None,
+            }
+        }
+        if let Some(start) = reset_start {
+            for level in &mut levels[start..] {
+                *level = paragraph.level;
+            }
+        }
+        levels
+    }
+
+    /// Returns the runs of a line in display order from left to right (rule L2).
+    ///
+    /// `line` is a byte range within `paragraph`, on character boundaries.
+    pub fn visual_runs(&self, paragraph: &ParagraphInfo, line: Range<usize>) -> Vec<VisualRun> {
+        let levels = self.reordered_levels(paragraph, line.clone());
+        let mut runs: Vec<VisualRun> = Vec::new();
+        for (i, c) in self.text[line.clone()].char_indices() {
+            let start = line.start + i;
+            let end = start + c.len_utf8();
+            match runs.last_mut() {
+                Some(run) if run.level == levels[i] => run.range.end = end,
+                _ => runs.push(VisualRun {
+                    range: start..end,
+                    level: levels[i],
+                }),
+            }
+        }
+
+        // From the highest level to the lowest odd level, reverse the sequences of runs at that
+        // level or higher.
+        let max_level = runs.iter().map(|run| run.level.0).max().unwrap_or(0);
+        let min_odd_level = runs
+            .iter()
+            .map(|run| run.level.0 | 1)
+            .min()
+            .unwrap_or(max_level + 1);
+        for level in (min_odd_level..=max_level).rev() {
+            let mut i = 0;
+            while i < runs.len() {
+                if runs[i].level.0 < level {
+                    i += 1;
+                    continue;
+                }
+                let end = (i..runs.len())
+                    .find(|&j| runs[j].level.0 < level)
+                    .unwrap_or(runs.len());
+                runs[i..end].reverse();
+                i = end;
+            }
+        }
+        runs
+    }
+
+    /// Returns the characters of a line in display order from left to right.
+    ///
+    /// `line` is a byte range within `paragraph`, on character boundaries. Characters are not
+    /// mirrored, so an opening parenthesis in a right-to-left run remains an opening
+    /// parenthesis.
+    pub fn reorder_line(&self, paragraph: &ParagraphInfo, line: Range<usize>) -> Cow<'text, str> {
+        let runs = self.visual_runs(paragraph, line.clone());
+        if runs.iter().all(|run| run.level.is_ltr()) {
+            return Cow::Borrowed(&self.text[line]);
+        }
+        let mut result = String::with_capacity(line.len());
+        for run in runs {
+            let run_text = &self.text[run.range];
+            if run.level.is_rtl() {
+                result.extend(run_text.chars().rev());
+            } else {
+                result.push_str(run_text);
+            }
+        }
+        Cow::Owned(result)
+    }
+}
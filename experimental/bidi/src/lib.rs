@@ -0,0 +1,61 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_bidi`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API implements the [`Unicode Bidirectional Algorithm`] (UBA), which determines the order
+//! in which the characters of right-to-left and mixed-direction text, such as Arabic or Hebrew
+//! with embedded numbers and English words, are displayed.
+//!
+//! [`Bidi::analyze()`] splits a text into paragraphs and resolves the embedding [`Level`] of
+//! each character. Once the text has been broken into lines, [`BidiInfo::visual_runs()`] returns
+//! the directional runs of a line in display order, and [`BidiInfo::reorder_line()`] returns the
+//! characters of a line in display order. Mirroring characters such as parentheses in
+//! right-to-left runs (rule L4) is left to the renderer.
+//!
+//! The algorithm is driven by the Bidi_Class and paired bracket data in [`provider`]. The `data`
+//! directory of this crate contains that data for the `FsDataProvider`; it is generated by
+//! `tools/generate_data.py`.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_bidi::Bidi;
+//!
+//! let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+//!     env!("CARGO_MANIFEST_DIR"),
+//!     "/data"
+//! ))
+//! .expect("Data directory should exist");
+//!
+//! let bidi = Bidi::try_new(&provider).expect("Data should load successfully");
+//!
+//! // "Hello" followed by "world" in Hebrew
+//! let text = "Hello \u{05E2}\u{05D5}\u{05DC}\u{05DD}";
+//! let info = bidi.analyze(text, None);
+//! let paragraph = &info.paragraphs[0];
+//! assert!(paragraph.level.is_ltr());
+//! assert_eq!(
+//!     info.reorder_line(paragraph, paragraph.range.clone()),
+//!     "Hello \u{05DD}\u{05DC}\u{05D5}\u{05E2}"
+//! );
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`Unicode Bidirectional Algorithm`]: https://www.unicode.org/reports/tr9/
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod algorithm;
+mod bidi;
+pub mod error;
+mod props;
+pub mod provider;
+
+pub use bidi::{Bidi, BidiInfo, ParagraphInfo, VisualRun};
+pub use error::Error as BidiError;
+pub use props::{BidiClass, Level};
@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+/// The Bidi_Class property, which determines how a character is ordered by the bidi algorithm,
+/// see <https://www.unicode.org/reports/tr9/#Bidirectional_Character_Types>.
+///
+/// The values are those of `UCharDirection` in ICU4C.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum BidiClass {
+    /// Strong left-to-right characters, such as Latin letters (`L`).
+    LeftToRight = 0,
+    /// Strong right-to-left characters, such as Hebrew letters (`R`).
+    RightToLeft = 1,
+    /// European digits (`EN`).
+    EuropeanNumber = 2,
+    /// Plus and minus signs (`ES`).
+    EuropeanSeparator = 3,
+    /// Currency symbols and other terminators of numbers, such as the percent sign (`ET`).
+    EuropeanTerminator = 4,
+    /// Arabic-Indic digits (`AN`).
+    ArabicNumber = 5,
+    /// Separators within numbers, such as commas and periods (`CS`).
+    CommonSeparator = 6,
+    /// Paragraph separators, such as line feeds (`B`).
+    ParagraphSeparator = 7,
+    /// Segment separators, such as tabs (`S`).
+    SegmentSeparator = 8,
+    /// Whitespace (`WS`).
+    WhiteSpace = 9,
+    /// Other neutral characters, such as most punctuation (`ON`).
+    OtherNeutral = 10,
+    /// U+202A LEFT-TO-RIGHT EMBEDDING (`LRE`).
+    LeftToRightEmbedding = 11,
+    /// U+202D LEFT-TO-RIGHT OVERRIDE (`LRO`).
+    LeftToRightOverride = 12,
+    /// Strong right-to-left characters of Arabic and related scripts (`AL`).
+    ArabicLetter = 13,
+    /// U+202B RIGHT-TO-LEFT EMBEDDING (`RLE`).
+    RightToLeftEmbedding = 14,
+    /// U+202E RIGHT-TO-LEFT OVERRIDE (`RLO`).
+    RightToLeftOverride = 15,
+    /// U+202C POP DIRECTIONAL FORMATTING (`PDF`).
+    PopDirectionalFormat = 16,
+    /// Nonspacing and enclosing marks (`NSM`).
+    NonspacingMark = 17,
+    /// Characters that are ignored by the bidi algorithm, such as most format characters
+    /// (`BN`).
+    BoundaryNeutral = 18,
+    /// U+2068 FIRST STRONG ISOLATE (`FSI`).
+    FirstStrongIsolate = 19,
+    /// U+2066 LEFT-TO-RIGHT ISOLATE (`LRI`).
+    LeftToRightIsolate = 20,
+    /// U+2067 RIGHT-TO-LEFT ISOLATE (`RLI`).
+    RightToLeftIsolate = 21,
+    /// U+2069 POP DIRECTIONAL ISOLATE (`PDI`).
+    PopDirectionalIsolate = 22,
+}
+
+impl BidiClass {
+    const ALL: [BidiClass; 23] = [
+        Self::LeftToRight,
+        Self::RightToLeft,
+        Self::EuropeanNumber,
+        Self::EuropeanSeparator,
+        Self::EuropeanTerminator,
+        Self::ArabicNumber,
+        Self::CommonSeparator,
+        Self::ParagraphSeparator,
+        Self::SegmentSeparator,
+        Self::WhiteSpace,
+        Self::OtherNeutral,
+        Self::LeftToRightEmbedding,
+        Self::LeftToRightOverride,
+        Self::ArabicLetter,
+        Self::RightToLeftEmbedding,
+        Self::RightToLeftOverride,
+        Self::PopDirectionalFormat,
+        Self::NonspacingMark,
+        Self::BoundaryNeutral,
+        Self::FirstStrongIsolate,
+        Self::LeftToRightIsolate,
+        Self::RightToLeftIsolate,
+        Self::PopDirectionalIsolate,
+    ];
+
+    /// Returns the [`BidiClass`] with the given value, or `None` if there is none.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(value)).copied()
+    }
+}
+
+/// An embedding level, which determines the direction of a character: characters on even
+/// levels are displayed left-to-right, and characters on odd levels right-to-left.
+///
+/// See <https://www.unicode.org/reports/tr9/#BD2>.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Level(pub(crate) u8);
+
+impl Level {
+    /// The maximum explicit embedding level. Implicit levels may be one higher.
+    pub const MAX_DEPTH: u8 = 125;
+
+    /// Returns the left-to-right level 0, the level of left-to-right paragraphs.
+    pub const fn ltr() -> Self {
+        Self(0)
+    }
+
+    /// Returns the right-to-left level 1, the level of right-to-left paragraphs.
+    pub const fn rtl() -> Self {
+        Self(1)
+    }
+
+    /// Returns the level with the given number, or `None` if it is higher than
+    /// [`Level::MAX_DEPTH`]` + 1`.
+    pub fn new(number: u8) -> Option<Self> {
+        if number <= Self::MAX_DEPTH + 1 {
+            Some(Self(number))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of the level.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether the level is left-to-right, i.e. even.
+    pub fn is_ltr(self) -> bool {
+        self.0 % 2 == 0
+    }
+
+    /// Returns whether the level is right-to-left, i.e. odd.
+    pub fn is_rtl(self) -> bool {
+        self.0 % 2 == 1
+    }
+}
@@ -0,0 +1,74 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use zerovec::{ZeroMap, ZeroVec};
+
+pub mod key {
+    //! Resource keys for [`icu_bidi`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the Bidi_Class property.
+    pub const BIDI_CLASS_V1: ResourceKey = resource_key!(Bidi, "class", 1);
+
+    /// Resource key: the paired brackets.
+    pub const BIDI_BRACKETS_V1: ResourceKey = resource_key!(Bidi, "brackets", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_bidi`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(BidiClassV1Marker, [key::BIDI_CLASS_V1]),
+    icu_provider::registered_keys!(BidiBracketsV1Marker, [key::BIDI_BRACKETS_V1]),
+];
+
+/// The Bidi_Class property of all code points, as ranges of code points with the same value.
+///
+/// Unassigned code points have the default values of their blocks, such as
+/// [`BidiClass::RightToLeft`](crate::BidiClass::RightToLeft) in the Hebrew block.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct BidiClassV1<'data> {
+    /// The first code point of each range, in ascending order, starting with U+0000.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub starts: ZeroVec<'data, u32>,
+
+    /// The Bidi_Class of each range, as the value of the corresponding
+    /// [`BidiClass`](crate::BidiClass).
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub classes: ZeroVec<'data, u8>,
+}
+
+/// The paired brackets, i.e. the characters with a Bidi_Paired_Bracket_Type of Open or Close,
+/// which are resolved together by the bidi algorithm.
+///
+/// Brackets are matched up to canonical equivalence, so that U+2329 LEFT-POINTING ANGLE BRACKET
+/// is closed by U+3009 RIGHT ANGLE BRACKET; both maps therefore contain the canonically
+/// decomposed closing brackets.
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct BidiBracketsV1<'data> {
+    /// Map from an opening bracket to its closing bracket.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub opening: ZeroMap<'data, char, char>,
+
+    /// Map from a closing bracket to its canonical decomposition, which is the bracket itself
+    /// unless it has a singleton decomposition.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub closing: ZeroMap<'data, char, char>,
+}
@@ -0,0 +1,199 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_bidi::{Bidi, BidiClass, BidiInfo, Level, VisualRun};
+use icu_provider_fs::FsDataProvider;
+
+fn get_provider() -> FsDataProvider {
+    FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist")
+}
+
+/// Returns the levels of the characters of the first paragraph, after rule L1.
+fn char_levels(info: &BidiInfo) -> Vec<u8> {
+    let paragraph = &info.paragraphs[0];
+    let levels = info.reordered_levels(paragraph, paragraph.range.clone());
+    info.text[paragraph.range.clone()]
+        .char_indices()
+        .map(|(i, _)| levels[i].number())
+        .collect()
+}
+
+/// Returns the first paragraph in display order.
+fn reorder(info: &BidiInfo) -> String {
+    let paragraph = &info.paragraphs[0];
+    info.reorder_line(paragraph, paragraph.range.clone())
+        .into_owned()
+}
+
+#[test]
+fn test_bidi_class() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    assert_eq!(bidi.bidi_class('a'), BidiClass::LeftToRight);
+    assert_eq!(bidi.bidi_class('\u{05D0}'), BidiClass::RightToLeft);
+    assert_eq!(bidi.bidi_class('\u{0627}'), BidiClass::ArabicLetter);
+    assert_eq!(bidi.bidi_class('7'), BidiClass::EuropeanNumber);
+    assert_eq!(bidi.bidi_class('\u{0663}'), BidiClass::ArabicNumber);
+    assert_eq!(bidi.bidi_class(' '), BidiClass::WhiteSpace);
+    assert_eq!(bidi.bidi_class('\u{2029}'), BidiClass::ParagraphSeparator);
+    assert_eq!(bidi.bidi_class('\u{0301}'), BidiClass::NonspacingMark);
+    assert_eq!(bidi.bidi_class('\u{2067}'), BidiClass::RightToLeftIsolate);
+    // Unassigned code points in the Hebrew block default to R
+    assert_eq!(bidi.bidi_class('\u{05FF}'), BidiClass::RightToLeft);
+    // Characters outside of the BMP
+    assert_eq!(bidi.bidi_class('\u{10900}'), BidiClass::RightToLeft);
+    assert_eq!(bidi.bidi_class('\u{1F600}'), BidiClass::OtherNeutral);
+}
+
+#[test]
+fn test_paragraphs() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    let text = "abc\n\u{05D0}\u{05D1}\r\n\u{2067}\u{05D0}\u{2069}x";
+    let info = bidi.analyze(text, None);
+    let paragraphs: Vec<_> = info
+        .paragraphs
+        .iter()
+        .map(|paragraph| (&text[paragraph.range.clone()], paragraph.level))
+        .collect();
+    assert_eq!(
+        paragraphs,
+        [
+            ("abc\n", Level::ltr()),
+            ("\u{05D0}\u{05D1}\r\n", Level::rtl()),
+            // The characters of isolates are skipped when determining the paragraph level
+            ("\u{2067}\u{05D0}\u{2069}x", Level::ltr()),
+        ]
+    );
+
+    // A paragraph without strong characters is left-to-right
+    let info = bidi.analyze("123", None);
+    assert_eq!(info.paragraphs[0].level, Level::ltr());
+    assert!(!info.has_rtl());
+
+    // An explicit paragraph level
+    let info = bidi.analyze("abc", Some(Level::rtl()));
+    assert_eq!(info.paragraphs[0].level, Level::rtl());
+    assert_eq!(char_levels(&info), [2, 2, 2]);
+    assert_eq!(reorder(&info), "abc");
+
+    assert_eq!(bidi.analyze("", None).paragraphs, []);
+}
+
+#[test]
+fn test_numbers() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    // European numbers in Hebrew text keep their order
+    let info = bidi.analyze("\u{05D0} 12.5% \u{05D1}", None);
+    assert_eq!(char_levels(&info), [1, 1, 2, 2, 2, 2, 2, 1, 1]);
+    assert_eq!(reorder(&info), "\u{05D1} 12.5% \u{05D0}");
+
+    // European digits after an Arabic letter are Arabic numbers (W2)
+    let info = bidi.analyze("a \u{0627}1", None);
+    assert_eq!(char_levels(&info), [0, 0, 1, 2]);
+    assert_eq!(reorder(&info), "a 1\u{0627}");
+
+    // A minus sign between numbers is part of the number (W4)
+    let info = bidi.analyze("\u{05D0} 1-2", None);
+    assert_eq!(char_levels(&info), [1, 1, 2, 2, 2]);
+    assert_eq!(reorder(&info), "1-2 \u{05D0}");
+}
+
+#[test]
+fn test_brackets() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    // The brackets take the direction of their content when it matches the embedding direction
+    let info = bidi.analyze("\u{05D0}(b)c", None);
+    assert_eq!(info.paragraphs[0].level, Level::rtl());
+    assert_eq!(char_levels(&info), [1, 1, 2, 1, 2]);
+
+    // Otherwise, they take the direction of the preceding context
+    let info = bidi.analyze("a \u{05D0}(\u{05D1}) c", None);
+    assert_eq!(char_levels(&info), [0, 0, 1, 1, 1, 1, 0, 0]);
+    assert_eq!(reorder(&info), "a )\u{05D1}(\u{05D0} c");
+
+    // Without brackets, the closing parenthesis would be between R and L, hence left-to-right
+    let info = bidi.analyze("a \u{05D0}[\u{05D1}) c", None);
+    assert_eq!(char_levels(&info), [0, 0, 1, 1, 1, 0, 0, 0]);
+
+    // Brackets are matched up to canonical equivalence
+    let info = bidi.analyze("a \u{05D0}\u{2329}\u{05D1}\u{3009} c", None);
+    assert_eq!(char_levels(&info), [0, 0, 1, 1, 1, 1, 0, 0]);
+}
+
+#[test]
+fn test_explicit_formatting() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    // A right-to-left override reverses left-to-right text
+    let info = bidi.analyze("a\u{202E}bc\u{202C}d", None);
+    assert_eq!(reorder(&info), "a\u{202E}\u{202C}cbd");
+
+    // Numbers in a left-to-right isolate do not affect the surrounding Arabic text
+    let info = bidi.analyze("\u{0627} \u{2066}a 1\u{2069} \u{0628}", None);
+    assert_eq!(char_levels(&info), [1, 1, 1, 2, 2, 2, 1, 1, 1]);
+    assert_eq!(reorder(&info), "\u{0628} \u{2069}a 1\u{2066} \u{0627}");
+
+    // A first strong isolate takes the direction of its content
+    let info = bidi.analyze("a \u{2068}\u{05D0} b\u{2069}", None);
+    assert_eq!(char_levels(&info), [0, 0, 0, 1, 1, 2, 0]);
+}
+
+#[test]
+fn test_lines() {
+    let provider = get_provider();
+    let bidi = Bidi::try_new(&provider).expect("Data should load");
+
+    let text = "\u{05D0}\u{05D1} abc def \u{05D2} ";
+    let info = bidi.analyze(text, None);
+    let paragraph = &info.paragraphs[0];
+    assert_eq!(paragraph.level, Level::rtl());
+
+    // The whitespace at the end of the paragraph is at the paragraph level
+    let first_line = 0..info.text.find('d').expect("The text contains 'd'");
+    let second_line = first_line.end..text.len();
+    assert_eq!(
+        info.visual_runs(paragraph, first_line.clone()),
+        [
+            VisualRun {
+                range: 8..9,
+                level: Level::rtl(),
+            },
+            VisualRun {
+                range: 5..8,
+                level: Level::new(2).expect("Valid level"),
+            },
+            VisualRun {
+                range: 0..5,
+                level: Level::rtl(),
+            },
+        ]
+    );
+    assert_eq!(
+        info.reorder_line(paragraph, first_line),
+        " abc \u{05D1}\u{05D0}"
+    );
+    assert_eq!(info.reorder_line(paragraph, second_line), " \u{05D2} def");
+
+    // Tabs are at the paragraph level, and separate the runs
+    let info = bidi.analyze("ab\tcd \u{05D0}", Some(Level::rtl()));
+    assert_eq!(char_levels(&info), [2, 2, 1, 2, 2, 1, 1]);
+    assert_eq!(reorder(&info), "\u{05D0} cd\tab");
+
+    // Left-to-right text is returned as is
+    let info = bidi.analyze("abc def", None);
+    let paragraph = &info.paragraphs[0];
+    assert!(matches!(
+        info.reorder_line(paragraph, paragraph.range.clone()),
+        std::borrow::Cow::Borrowed("abc def")
+    ));
+}
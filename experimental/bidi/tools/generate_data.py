@@ -0,0 +1,130 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the bidi data in ../data.
+#
+# Usage: generate_data.py <unicore directory>
+#
+# The Bidi_Class, Bidi_Paired_Bracket, and Bidi_Paired_Bracket_Type properties
+# are read from the unicore directory of a Perl installation, e.g.
+# /usr/share/perl/5.36.0/unicore, whose tables already include the default
+# values of unassigned code points, such as R in the Hebrew block. The
+# canonical decompositions of the brackets are taken from Python's unicodedata
+# module.
+#
+# The Unicode version of the unicore directory must match the version used by
+# the rest of the ICU4X data.
+
+import json
+import os
+import sys
+import unicodedata
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..")
+OUT_DIR = os.path.join(ROOT_DIR, "experimental", "bidi", "data")
+
+# The values of BidiClass, which are those of UCharDirection in ICU4C.
+BIDI_CLASSES = [
+    "L", "R", "EN", "ES", "ET", "AN", "CS", "B", "S", "WS", "ON", "LRE", "LRO",
+    "AL", "RLE", "RLO", "PDF", "NSM", "BN", "FSI", "LRI", "RLI", "PDI",
+]  # fmt: skip
+
+
+def read_table(unicore_dir, name):
+    """Returns the (first, last, value) ranges of a unicore/To table, and the
+    value of the code points that are not listed."""
+    with open(os.path.join(unicore_dir, "To", name + ".pl")) as f:
+        source = f.read()
+    missing = source.split("{'missing'} = '")[1].split("'")[0]
+    table = source.split("return <<'END';\n")[1].split("\nEND")[0]
+    ranges = []
+    for line in table.splitlines():
+        # 590\t\tR, with an empty end for single code points
+        first, last, value = line.split("\t")
+        ranges.append((int(first, 16), int(last or first, 16), value))
+    return ranges, missing
+
+
+def bidi_classes(unicore_dir):
+    """Returns the BidiClassV1 struct."""
+    ranges, missing = read_table(unicore_dir, "Bc")
+    starts = []
+    classes = []
+
+    def add(start, bidi_class):
+        if classes and classes[-1] == bidi_class:
+            return
+        starts.append(start)
+        classes.append(bidi_class)
+
+    next_cp = 0
+    for first, last, value in ranges:
+        if first > next_cp:
+            add(next_cp, BIDI_CLASSES.index(missing))
+        add(first, BIDI_CLASSES.index(value))
+        next_cp = last + 1
+    if next_cp <= 0x10FFFF:
+        add(next_cp, BIDI_CLASSES.index(missing))
+    return {"starts": starts, "classes": classes}
+
+
+def canonical(c):
+    """Returns the canonical equivalent of a bracket; some brackets, such as
+    U+2329 LEFT-POINTING ANGLE BRACKET, have singleton decompositions."""
+    return unicodedata.normalize("NFD", c)
+
+
+def bidi_brackets(unicore_dir):
+    """Returns the BidiBracketsV1 struct."""
+    pairs, _ = read_table(unicore_dir, "Bpb")
+    types, _ = read_table(unicore_dir, "Bpt")
+    bracket_types = {
+        chr(cp): value for first, last, value in types for cp in range(first, last + 1)
+    }
+    opening = {}
+    closing = {}
+    for first, last, value in pairs:
+        for cp in range(first, last + 1):
+            c = chr(cp)
+            if bracket_types[c] == "o":
+                opening[c] = canonical(chr(int(value, 16)))
+            else:
+                assert bracket_types[c] == "c"
+                closing[c] = canonical(c)
+    return {
+        "opening": dict(sorted(opening.items())),
+        "closing": dict(sorted(closing.items())),
+    }
+
+
+def write_json(path, data):
+    path = os.path.join(OUT_DIR, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        json.dump(data, f, indent=2)
+        f.write("\n")
+
+
+def main(unicore_dir):
+    with open(os.path.join(unicore_dir, "version")) as f:
+        version = f.read().strip()
+
+    os.makedirs(OUT_DIR, exist_ok=True)
+    with open(os.path.join(OUT_DIR, "manifest.json"), "w") as f:
+        json.dump(
+            {"aliasing": "NoAliases", "syntax": "Json", "schema_version": "0.3"},
+            f,
+            indent=2,
+        )
+        f.write("\n")
+    write_json(os.path.join("bidi", "class@1.json"), bidi_classes(unicore_dir))
+    write_json(os.path.join("bidi", "brackets@1.json"), bidi_brackets(unicore_dir))
+
+    print("Generated bidi data for Unicode", version)
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
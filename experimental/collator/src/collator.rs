@@ -0,0 +1,725 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::elements::{self, CollationElement};
+use crate::error::Error;
+use crate::fallback::load_with_fallback;
+use crate::options::*;
+use crate::provider::*;
+use crate::reordering::Reordering;
+use crate::rules;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_locid::extensions::unicode::Key;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_normalizer::provider::{CanonicalCombiningClassV1Marker, CanonicalDecompositionV1Marker};
+use icu_normalizer::DecomposingNormalizer;
+use icu_provider::prelude::*;
+use tinystr::tinystr4;
+use zerovec::ule::AsULE;
+
+/// A collator compares strings according to the [`Unicode Collation Algorithm`], using the
+/// collation conventions of a locale.
+///
+/// By default, [`Collator::compare()`] compares the strings on three levels: base letters,
+/// accents, and case and variants, so that a difference on a lower level only matters if the
+/// strings are equal on the higher levels. The levels and other aspects of the comparison are
+/// configured with [`CollatorOptions`] or with the Unicode extension keywords of the locale.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::options::CollatorOptions;
+/// use icu_collator::Collator;
+/// use icu_locid::Locale;
+/// use icu_locid_macros::langid;
+/// use icu_provider::fork::MultiForkByKeyProvider;
+/// use icu_provider_fs::FsDataProvider;
+///
+/// let provider = MultiForkByKeyProvider {
+///     providers: vec![
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///             .expect("Data directory should exist"),
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+///             .expect("Data directory should exist"),
+///     ],
+/// };
+///
+/// let collator = Collator::try_new(langid!("sv"), &provider, CollatorOptions::default())
+///     .expect("Data should load successfully");
+///
+/// let mut words = vec!["ängel", "zebra", "Apa", "apa", "öga"];
+/// words.sort_by(|a, b| collator.compare(a, b));
+/// assert_eq!(words, ["apa", "Apa", "zebra", "ängel", "öga"]);
+///
+/// // Sort uppercase letters first.
+/// let locale: Locale = "sv-u-kf-upper".parse().expect("Valid locale");
+/// let collator = Collator::try_new(locale, &provider, CollatorOptions::default())
+///     .expect("Data should load successfully");
+///
+/// words.sort_by(|a, b| collator.compare(a, b));
+/// assert_eq!(words, ["Apa", "apa", "zebra", "ängel", "öga"]);
+/// ```
+///
+/// [`Unicode Collation Algorithm`]: https://www.unicode.org/reports/tr10/
+pub struct Collator<'data> {
+    decomposer: DecomposingNormalizer<'data>,
+    root: DataPayload<'data, CollationDataV1Marker>,
+    tailoring: Option<DataPayload<'data, CollationDataV1Marker>>,
+    implicit_weights: DataPayload<'data, ImplicitWeightsV1Marker>,
+    /// The decimal digits, which are only loaded for the numeric collation.
+    digits: Option<DataPayload<'data, DecimalDigitsV1Marker>>,
+    /// The reordering of the primary weights requested with `-u-kr`, if any.
+    reordering: Option<Reordering>,
+    /// The number of characters of the longest key of the collation data.
+    max_key_len: usize,
+    strength: Strength,
+    alternate_handling: AlternateHandling,
+    case_first: CaseFirst,
+    case_level: CaseLevel,
+    backward_second_level: bool,
+}
+
+/// The comparison levels, in the order of the weights returned by [`Collator::weights()`].
+#[derive(Copy, Clone, PartialEq)]
+enum Level {
+    Primary,
+    Secondary,
+    Case,
+    Tertiary,
+    Quaternary,
+}
+
+impl<'data> Collator<'data> {
+    /// Creates a collator for the given locale.
+    ///
+    /// The tailoring of the locale is looked up with fallback to less specific locales. If there
+    /// is none, the collator uses the root collation. Alternative collation types, such as
+    /// `-u-co-phonebk` for the German phonebook ordering, are loaded if they are supported by
+    /// the data, and are ignored otherwise.
+    ///
+    /// Scripts and special groups of characters such as digits can be moved before the others
+    /// with the `-u-kr` keyword: with `-u-kr-grek-digit`, Greek letters sort before the other
+    /// scripts, and digits sort after Greek letters. The keyword is ignored if it contains an
+    /// unsupported code.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: CollatorOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let root: DataPayload<CollationDataV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::ROOT_V1))?
+            .take_payload()?;
+        let co_key = Key::from_tinystr4_unchecked(tinystr4!("co"));
+        let collation_type = locale
+            .extensions
+            .unicode
+            .keywords
+            .get(co_key)
+            .map(|value| value.to_string());
+        let tailoring = match collation_type {
+            // Collation types that are not supported by the data are ignored.
+            Some(collation_type) => match Self::load_tailoring(
+                locale.id.clone(),
+                Some(Cow::Owned(collation_type)),
+                data_provider,
+            )? {
+                Some(tailoring) => Some(tailoring),
+                None => Self::load_tailoring(locale.id.clone(), None, data_provider)?,
+            },
+            None => Self::load_tailoring(locale.id.clone(), None, data_provider)?,
+        };
+        Self::try_new_with_data(&locale, root, tailoring, data_provider, options)
+    }
+
+    /// Creates a collator for the given locale that only compares base letters, such that
+    /// "resume" and "Résumé" are equal, for example to match names or search terms regardless of
+    /// how they are typed.
+    ///
+    /// This is [`Strength::Primary`] without the case level, which take precedence over the
+    /// `-u-ks` and `-u-kc` keywords of the locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_collator::Collator;
+    /// use icu_locid_macros::langid;
+    /// use icu_provider::fork::MultiForkByKeyProvider;
+    /// use icu_provider_fs::FsDataProvider;
+    /// use std::cmp::Ordering;
+    ///
+    /// let provider = MultiForkByKeyProvider {
+    ///     providers: vec![
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+    ///             .expect("Data directory should exist"),
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+    ///             .expect("Data directory should exist"),
+    ///     ],
+    /// };
+    ///
+    /// let collator = Collator::try_new_ignoring_accents_and_case(langid!("fr"), &provider)
+    ///     .expect("Data should load successfully");
+    /// assert_eq!(collator.compare("resume", "Résumé"), Ordering::Equal);
+    /// assert_eq!(collator.compare("resume", "résumés"), Ordering::Less);
+    /// ```
+    pub fn try_new_ignoring_accents_and_case<T, D>(
+        locale: T,
+        data_provider: &D,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let options = CollatorOptions {
+            strength: Some(Strength::Primary),
+            case_level: Some(CaseLevel::Off),
+            ..Default::default()
+        };
+        Self::try_new(locale, data_provider, options)
+    }
+
+    /// Creates a collator for the given locale that compares base letters and accents but not
+    /// case, such that "resume" and "Resume" are equal, but not "resume" and "résumé".
+    ///
+    /// This is [`Strength::Secondary`] without the case level, which take precedence over the
+    /// `-u-ks` and `-u-kc` keywords of the locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_collator::Collator;
+    /// use icu_locid_macros::langid;
+    /// use icu_provider::fork::MultiForkByKeyProvider;
+    /// use icu_provider_fs::FsDataProvider;
+    /// use std::cmp::Ordering;
+    ///
+    /// let provider = MultiForkByKeyProvider {
+    ///     providers: vec![
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+    ///             .expect("Data directory should exist"),
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+    ///             .expect("Data directory should exist"),
+    ///     ],
+    /// };
+    ///
+    /// let collator = Collator::try_new_ignoring_case(langid!("fr"), &provider)
+    ///     .expect("Data should load successfully");
+    /// assert_eq!(collator.compare("resume", "Resume"), Ordering::Equal);
+    /// assert_eq!(collator.compare("resume", "Résumé"), Ordering::Less);
+    /// ```
+    pub fn try_new_ignoring_case<T, D>(locale: T, data_provider: &D) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let options = CollatorOptions {
+            strength: Some(Strength::Secondary),
+            case_level: Some(CaseLevel::Off),
+            ..Default::default()
+        };
+        Self::try_new(locale, data_provider, options)
+    }
+
+    /// Creates a collator from tailoring rules in the syntax of ICU, such as `"&c < č <<< Č"`,
+    /// for sort orders that no locale provides, such as those of dictionaries or library
+    /// catalogs.
+    ///
+    /// The rules are compiled against the root collation. A rule starts with a reset `&` to a
+    /// string, and continues with relations that place strings after it: `<`, `<<`, and `<<<`
+    /// for a difference on the primary, secondary, and tertiary level, and `=` for no
+    /// difference. The forms `<*`, `<<*`, `<<<*`, and `=*` apply a relation to each character of
+    /// a list, which may contain ranges such as `a-d`. Syntax characters, i.e. ASCII characters
+    /// other than letters, digits, and whitespace, are quoted with apostrophes or escaped with a
+    /// backslash, and `\uXXXX` escapes a code point.
+    ///
+    /// The settings `[alternate shifted]`, `[alternate non-ignorable]`, `[backwards 2]`,
+    /// `[caseFirst upper]`, `[caseFirst lower]`, and `[caseFirst off]` set the defaults of the
+    /// options. Other settings, reset positions such as `[before 1]`, and contexts and
+    /// expansions, are not supported.
+    ///
+    /// The differences made by the rules are independent of the case options: for example,
+    /// [`CaseFirst::UpperFirst`] does not reorder the strings of a tertiary relation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_collator::options::CollatorOptions;
+    /// use icu_collator::Collator;
+    /// use icu_provider::fork::MultiForkByKeyProvider;
+    /// use icu_provider_fs::FsDataProvider;
+    ///
+    /// let provider = MultiForkByKeyProvider {
+    ///     providers: vec![
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+    ///             .expect("Data directory should exist"),
+    ///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+    ///             .expect("Data directory should exist"),
+    ///     ],
+    /// };
+    ///
+    /// // Sort "č" after "c", and "ch" after "h", as in Czech.
+    /// let collator = Collator::try_new_with_rules(
+    ///     "&c < č <<< Č &h < ch <<< cH <<< Ch <<< CH",
+    ///     &provider,
+    ///     CollatorOptions::default(),
+    /// )
+    /// .expect("Rules should be valid");
+    ///
+    /// let mut words = vec!["ihned", "chata", "hrad", "čaj", "cukr"];
+    /// words.sort_by(|a, b| collator.compare(a, b));
+    /// assert_eq!(words, ["cukr", "čaj", "hrad", "chata", "ihned"]);
+    /// ```
+    pub fn try_new_with_rules<D>(
+        rules: &str,
+        data_provider: &D,
+        options: CollatorOptions,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let locale = Locale::default();
+        let root: DataPayload<CollationDataV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::ROOT_V1))?
+            .take_payload()?;
+        let base = Self::try_new_with_data(
+            &locale,
+            root,
+            None,
+            data_provider,
+            CollatorOptions::default(),
+        )?;
+        let tailoring = rules::compile(rules, &base)?;
+        Self::try_new_with_data(
+            &locale,
+            base.root,
+            Some(DataPayload::from_owned(tailoring)),
+            data_provider,
+            options,
+        )
+    }
+
+    /// Creates a collator from the root collation and a tailoring, with the options set by
+    /// `options` or else by the keywords of `locale`.
+    fn try_new_with_data<D>(
+        locale: &Locale,
+        root: DataPayload<'data, CollationDataV1Marker>,
+        tailoring: Option<DataPayload<'data, CollationDataV1Marker>>,
+        data_provider: &D,
+        options: CollatorOptions,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let keyword = |key: Key| {
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .get(key)
+                .map(|value| value.to_string())
+        };
+
+        let decomposer = DecomposingNormalizer::try_new_nfd(data_provider)?;
+        let implicit_weights: DataPayload<ImplicitWeightsV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::IMPLICIT_WEIGHTS_V1))?
+            .take_payload()?;
+
+        let max_key_len = core::iter::once(&root)
+            .chain(tailoring.as_ref())
+            .flat_map(|data| data.get().contractions.iter_keys())
+            .map(|key| key.chars().count())
+            .max()
+            .unwrap_or(1);
+
+        let defaults = tailoring.as_ref().unwrap_or(&root).get();
+        let ks_key = Key::from_tinystr4_unchecked(tinystr4!("ks"));
+        let ka_key = Key::from_tinystr4_unchecked(tinystr4!("ka"));
+        let kf_key = Key::from_tinystr4_unchecked(tinystr4!("kf"));
+        let kc_key = Key::from_tinystr4_unchecked(tinystr4!("kc"));
+        let kn_key = Key::from_tinystr4_unchecked(tinystr4!("kn"));
+        let kr_key = Key::from_tinystr4_unchecked(tinystr4!("kr"));
+        let strength = options
+            .strength
+            .or_else(|| keyword(ks_key).and_then(|value| Strength::from_keyword(&value)))
+            .unwrap_or_default();
+        let alternate_handling = options
+            .alternate_handling
+            .or_else(|| keyword(ka_key).and_then(|value| AlternateHandling::from_keyword(&value)))
+            .unwrap_or(if defaults.alternate_shifted {
+                AlternateHandling::Shifted
+            } else {
+                AlternateHandling::NonIgnorable
+            });
+        let case_first = options
+            .case_first
+            .or_else(|| keyword(kf_key).and_then(|value| CaseFirst::from_keyword(&value)))
+            .unwrap_or(if defaults.upper_first {
+                CaseFirst::UpperFirst
+            } else {
+                CaseFirst::Off
+            });
+        let case_level = options
+            .case_level
+            .or_else(|| keyword(kc_key).and_then(|value| CaseLevel::from_keyword(&value)))
+            .unwrap_or(CaseLevel::Off);
+        let backward_second_level = defaults.backward_second_level;
+        let numeric = options
+            .numeric
+            .or_else(|| keyword(kn_key).and_then(|value| Numeric::from_keyword(&value)))
+            .unwrap_or(Numeric::Off);
+        let digits = match numeric {
+            Numeric::On => Some(
+                data_provider
+                    .load_payload_with_context(&DataRequest::from(key::DECIMAL_DIGITS_V1))?
+                    .take_payload()?,
+            ),
+            _ => None,
+        };
+        let reordering = match keyword(kr_key) {
+            Some(value) => {
+                let groups: DataPayload<ReorderingGroupsV1Marker> = data_provider
+                    .load_payload_with_context(&DataRequest::from(key::REORDERING_GROUPS_V1))?
+                    .take_payload()?;
+                Reordering::try_new(&value, groups.get())
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            decomposer,
+            root,
+            tailoring,
+            implicit_weights,
+            digits,
+            reordering,
+            max_key_len,
+            strength,
+            alternate_handling,
+            case_first,
+            case_level,
+            backward_second_level,
+        })
+    }
+
+    fn load_tailoring<D>(
+        langid: LanguageIdentifier,
+        variant: Option<Cow<'static, str>>,
+        data_provider: &D,
+    ) -> Result<Option<DataPayload<'data, CollationDataV1Marker>>, Error>
+    where
+        D: DataProvider<'data, CollationDataV1Marker> + ?Sized,
+    {
+        // The root collation is not tailored.
+        Ok(load_with_fallback(
+            key::TAILORING_V1,
+            langid,
+            variant,
+            false,
+            data_provider,
+        )?)
+    }
+
+    /// Compares two strings.
+    ///
+    /// Strings that only differ in ways that the collation ignores, such as canonically
+    /// equivalent strings, compare as [`Ordering::Equal`].
+    pub fn compare(&self, left: &str, right: &str) -> Ordering {
+        if left == right {
+            return Ordering::Equal;
+        }
+        self.compare_weights(&self.text_weights(left), &self.text_weights(right))
+            .then_with(|| self.compare_identical(left, right))
+    }
+
+    /// Returns the weights of the collation elements of `text` on each [`Level`].
+    pub(crate) fn text_weights(&self, text: &str) -> Vec<[u16; 5]> {
+        self.weights(&self.collation_elements(text))
+    }
+
+    /// Compares the weights of two strings on the levels that are compared with the strength of
+    /// the collator, except for the identical level.
+    pub(crate) fn compare_weights(&self, left: &[[u16; 5]], right: &[[u16; 5]]) -> Ordering {
+        let mut levels = Vec::with_capacity(5);
+        levels.push(Level::Primary);
+        if self.strength >= Strength::Secondary {
+            levels.push(Level::Secondary);
+        }
+        if self.case_level == CaseLevel::On {
+            levels.push(Level::Case);
+        }
+        if self.strength >= Strength::Tertiary {
+            levels.push(Level::Tertiary);
+        }
+        if self.strength >= Strength::Quaternary {
+            levels.push(Level::Quaternary);
+        }
+
+        for level in levels {
+            // Zero weights are ignorable on their level.
+            let left = left
+                .iter()
+                .map(|weights| weights[level as usize])
+                .filter(|&w| w != 0);
+            let right = right
+                .iter()
+                .map(|weights| weights[level as usize])
+                .filter(|&w| w != 0);
+            let ordering = if level == Level::Secondary && self.backward_second_level {
+                left.rev().cmp(right.rev())
+            } else {
+                left.cmp(right)
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Compares two strings on the identical level, if it is compared with the strength of the
+    /// collator.
+    pub(crate) fn compare_identical(&self, left: &str, right: &str) -> Ordering {
+        if self.strength == Strength::Identical {
+            // Strings that are equal on all other levels are ordered by the code points of their
+            // decompositions.
+            self.decomposer
+                .normalize_iter(left.chars())
+                .cmp(self.decomposer.normalize_iter(right.chars()))
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Returns the Canonical_Combining_Class property of `c`.
+    pub(crate) fn canonical_combining_class(&self, c: char) -> u8 {
+        self.decomposer.canonical_combining_class(c)
+    }
+
+    /// Returns the Normalization Form D of `text`, in which the keys of the collation data are.
+    pub(crate) fn decompose(&self, text: &str) -> String {
+        self.decomposer.normalize_iter(text.chars()).collect()
+    }
+
+    /// Returns the weights of each collation element on each [`Level`], see
+    /// <https://www.unicode.org/reports/tr10/#Variable_Weighting> for the handling of the
+    /// variable collation elements.
+    fn weights(&self, elements: &[CollationElement]) -> Vec<[u16; 5]> {
+        let shifted = self.alternate_handling == AlternateHandling::Shifted;
+        let upper_first = self.case_first == CaseFirst::UpperFirst;
+        let mut after_variable = false;
+        elements
+            .iter()
+            .map(|&element| {
+                if shifted && element.is_variable() {
+                    after_variable = true;
+                    return [0, 0, 0, 0, element.primary()];
+                }
+                if element.primary() != 0 {
+                    after_variable = false;
+                } else if after_variable {
+                    // Ignorables after a variable collation element are ignored as well.
+                    return [0; 5];
+                }
+                let tertiary = match element.tertiary() {
+                    0 => 0,
+                    _ if upper_first => element.tertiary_upper_first(),
+                    tertiary => tertiary,
+                };
+                // Only the case of the base letters is compared if accents are not.
+                let has_case = if self.strength == Strength::Primary {
+                    element.primary() != 0
+                } else {
+                    element.secondary() != 0
+                };
+                let case = match (has_case, element.is_uppercase() == upper_first) {
+                    (false, _) => 0,
+                    (true, true) => 1,
+                    (true, false) => 2,
+                };
+                let quaternary = if shifted && element.0 != 0 { 0xFFFF } else { 0 };
+                [
+                    element.primary(),
+                    element.secondary(),
+                    case,
+                    tertiary,
+                    quaternary,
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the collation element array of `text`, see
+    /// <https://www.unicode.org/reports/tr10/#Main_Algorithm>.
+    pub(crate) fn collation_elements(&self, text: &str) -> Vec<CollationElement> {
+        let mut chars: Vec<(char, u8)> = self
+            .decomposer
+            .normalize_iter(text.chars())
+            .map(|c| (c, self.decomposer.canonical_combining_class(c)))
+            .collect();
+        let mut elements = Vec::with_capacity(chars.len());
+        let mut key = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            // With the numeric collation, a sequence of digits is collated as a number.
+            if let (Some(digits), Some(zero)) = (&self.digits, self.zero_element()) {
+                let number: Vec<u8> = chars[i..]
+                    .iter()
+                    .map(|&(c, _)| elements::digit_value(c, digits.get()))
+                    .take_while(Option::is_some)
+                    .flatten()
+                    .collect();
+                if !number.is_empty() {
+                    elements::push_numeric_elements(self.reorder(zero), &number, &mut elements);
+                    i += number.len();
+                    continue;
+                }
+            }
+
+            // Find the longest string starting at i that has a mapping.
+            let mut found = None;
+            for len in (1..=self.max_key_len.min(chars.len() - i)).rev() {
+                key.clear();
+                key.extend(chars[i..i + len].iter().map(|&(c, _)| c));
+                if let Some(mapping) = self.lookup(&key) {
+                    found = Some((len, mapping));
+                    break;
+                }
+            }
+            let (len, mut mapping) = match found {
+                Some(found) => found,
+                None => {
+                    // Only the first implicit weight determines the reordering group.
+                    let [first, second] =
+                        elements::implicit_elements(chars[i].0, self.implicit_weights.get());
+                    elements.push(self.reorder(first));
+                    elements.push(second);
+                    i += 1;
+                    continue;
+                }
+            };
+
+            // Extend the match with the following non-starters that are not blocked, which
+            // forms discontiguous contractions. The matched non-starters are removed.
+            let mut j = i + len;
+            let mut last_ccc = 0;
+            while let Some(&(c, ccc)) = chars.get(j) {
+                if ccc == 0 {
+                    break;
+                }
+                if last_ccc < ccc {
+                    key.push(c);
+                    if let Some(extended) = self.lookup(&key) {
+                        mapping = extended;
+                        chars.remove(j);
+                        continue;
+                    }
+                    key.pop();
+                }
+                last_ccc = ccc;
+                j += 1;
+            }
+
+            let (data, value) = mapping;
+            let offset = (value >> 8) as usize;
+            let count = (value & 0xFF) as usize;
+            elements.extend(
+                (offset..offset + count)
+                    .filter_map(|index| data.elements.get(index))
+                    .map(|element| self.reorder(CollationElement(element))),
+            );
+            i += len;
+        }
+        elements
+    }
+
+    /// Returns `element` with its primary weight reordered with `-u-kr`.
+    fn reorder(&self, element: CollationElement) -> CollationElement {
+        match &self.reordering {
+            Some(reordering) if element.primary() != 0 => {
+                element.with_primary(reordering.reorder(element.primary()))
+            }
+            _ => element,
+        }
+    }
+
+    /// Returns the first non-zero primary weight of `text` before the reordering with `-u-kr`,
+    /// which determines the reordering group of `text`.
+    pub(crate) fn first_primary(&self, text: &str) -> Option<u16> {
+        let primary = self
+            .collation_elements(text)
+            .into_iter()
+            .map(CollationElement::primary)
+            .find(|&primary| primary != 0)?;
+        Some(match &self.reordering {
+            Some(reordering) => reordering.restore(primary),
+            None => primary,
+        })
+    }
+
+    /// Returns the collation element of the digit zero, whose primary weight is followed by those
+    /// of the other digits.
+    fn zero_element(&self) -> Option<CollationElement> {
+        let (data, value) = self.lookup("0")?;
+        data.elements
+            .get((value >> 8) as usize)
+            .map(CollationElement)
+    }
+
+    /// Returns the mapping of `key` in the tailoring or else in the root collation, along with
+    /// the data it was found in.
+    pub(crate) fn lookup(&self, key: &str) -> Option<(&CollationDataV1<'_>, u32)> {
+        let mut chars = key.chars();
+        let single = match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        };
+        self.tailoring
+            .iter()
+            .chain(core::iter::once(&self.root))
+            .find_map(|data| {
+                let data = data.get();
+                let value = match single {
+                    Some(c) => data.mappings.get(&c),
+                    None => data.contractions.get(key),
+                };
+                value.map(|value| (data, u32::from_unaligned(value)))
+            })
+    }
+}
@@ -0,0 +1,142 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{DecimalDigitsV1, ImplicitWeightsV1};
+use alloc::vec::Vec;
+use zerovec::ZeroVec;
+
+/// A collation element, encoded as in [`CollationDataV1`](crate::provider::CollationDataV1).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CollationElement(pub u32);
+
+impl CollationElement {
+    pub fn new(primary: u16, secondary: u16, tertiary: u16) -> Self {
+        Self(u32::from(primary) << 16 | u32::from(secondary) << 6 | u32::from(tertiary) << 1)
+    }
+
+    /// Returns the collation element with its primary weight replaced by `primary`.
+    pub fn with_primary(self, primary: u16) -> Self {
+        Self(self.0 & 0xFFFF | u32::from(primary) << 16)
+    }
+
+    pub fn primary(self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    pub fn secondary(self) -> u16 {
+        ((self.0 >> 6) & 0x3FF) as u16
+    }
+
+    pub fn tertiary(self) -> u16 {
+        ((self.0 >> 1) & 0x1F) as u16
+    }
+
+    pub fn is_variable(self) -> bool {
+        self.0 & 1 != 0
+    }
+
+    /// Returns whether the tertiary weight is one of those of uppercase letters, see
+    /// <https://www.unicode.org/reports/tr10/#Tertiary_Weight_Table>.
+    pub fn is_uppercase(self) -> bool {
+        matches!(self.tertiary(), 0x08..=0x0C | 0x1D)
+    }
+
+    /// Returns the tertiary weight with the weights of uppercase letters swapped with the
+    /// corresponding weights of lowercase letters.
+    pub fn tertiary_upper_first(self) -> u16 {
+        match self.tertiary() {
+            t @ 0x02..=0x06 => t + 6,
+            t @ 0x08..=0x0C => t - 6,
+            0x1C => 0x1D,
+            0x1D => 0x1C,
+            t => t,
+        }
+    }
+}
+
+/// Returns the collation elements of a character that has no mapping in the collation data,
+/// see <https://www.unicode.org/reports/tr10/#Implicit_Weights>.
+pub(crate) fn implicit_elements(c: char, weights: &ImplicitWeightsV1) -> [CollationElement; 2] {
+    let cp = u32::from(c);
+    let (aaaa, bbbb) = if let Some(base) = find_range(&weights.ranges, cp) {
+        // The characters of a script may be in several ranges; the low weight is the offset
+        // from the start of the first one.
+        let start = triples(&weights.ranges)
+            .filter(|&(_, _, b)| b == base)
+            .map(|(first, _, _)| first)
+            .min()
+            .unwrap_or(cp);
+        (base, (cp - start) | 0x8000)
+    } else {
+        let base = find_range(&weights.han, cp).unwrap_or(0xFBC0);
+        (base + (cp >> 15), (cp & 0x7FFF) | 0x8000)
+    };
+    // The weights fit in 16 bits since code points have at most 21 bits.
+    [
+        CollationElement::new(aaaa as u16, 0x0020, 0x0002),
+        CollationElement::new(bbbb as u16, 0, 0),
+    ]
+}
+
+/// Returns the numeric value of `c` if it is a decimal digit.
+pub(crate) fn digit_value(c: char, digits: &DecimalDigitsV1) -> Option<u8> {
+    let cp = u32::from(c);
+    match digits.zeros.binary_search(&cp) {
+        Ok(_) => Some(0),
+        Err(0) => None,
+        Err(index) => {
+            let value = cp - digits.zeros.get(index - 1)?;
+            if value <= 9 {
+                Some(value as u8)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Appends the collation elements of a number with the given decimal digits to `elements`, for
+/// the numeric collation. `zero` is the collation element of the digit zero, whose primary
+/// weight is followed by those of the digits one to nine.
+///
+/// The first collation element carries the secondary and tertiary weights of the number. It is
+/// followed by the number of significant digits, so that longer numbers sort after shorter ones,
+/// and then by the primary weights of the significant digits.
+pub(crate) fn push_numeric_elements(
+    zero: CollationElement,
+    digits: &[u8],
+    elements: &mut Vec<CollationElement>,
+) {
+    let first_significant = digits
+        .iter()
+        .position(|&digit| digit != 0)
+        .unwrap_or_else(|| digits.len().saturating_sub(1));
+    let significant = &digits[first_significant..];
+    // Numbers with more than 65535 digits are not ordered by their length.
+    let len = significant.len().min(usize::from(u16::MAX)) as u16;
+    elements.push(CollationElement::new(
+        zero.primary(),
+        zero.secondary(),
+        zero.tertiary(),
+    ));
+    elements.push(CollationElement::new(len, 0, 0));
+    elements.extend(
+        significant
+            .iter()
+            .map(|&digit| CollationElement::new(zero.primary() + u16::from(digit), 0, 0)),
+    );
+}
+
+/// Returns the base weight of the range containing `cp`.
+fn find_range(ranges: &ZeroVec<u32>, cp: u32) -> Option<u32> {
+    triples(ranges)
+        .find(|&(first, last, _)| (first..=last).contains(&cp))
+        .map(|(_, _, base)| base)
+}
+
+/// Returns the (first, last, base) triples of `ranges`.
+fn triples<'a>(ranges: &'a ZeroVec<u32>) -> impl Iterator<Item = (u32, u32, u32)> + 'a {
+    let mut iter = ranges.iter();
+    core::iter::from_fn(move || Some((iter.next()?, iter.next()?, iter.next()?)))
+}
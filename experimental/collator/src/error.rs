@@ -0,0 +1,39 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for the collator.
+
+use displaydoc::Display;
+
+/// A list of possible error outcomes for the collator.
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+
+    /// An error creating the normalizer that the collator uses to decompose strings.
+    #[displaydoc("{0}")]
+    Normalizer(icu_normalizer::NormalizerError),
+
+    /// The tailoring rules of [`Collator::try_new_with_rules()`](crate::Collator::try_new_with_rules)
+    /// are invalid or unsupported at the given byte offset.
+    #[displaydoc("invalid collation rules at byte {0}")]
+    InvalidRules(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_normalizer::NormalizerError> for Error {
+    fn from(e: icu_normalizer::NormalizerError) -> Self {
+        Error::Normalizer(e)
+    }
+}
@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use icu_locid::LanguageIdentifier;
+use icu_provider::fallback::ParentLocales;
+use icu_provider::prelude::*;
+
+/// Loads the data of `key` for `langid` or, if it is missing, for the closest of its CLDR parent
+/// locales that has data.
+///
+/// The root locale is only tried if `include_root` is set; otherwise `None` is returned when no
+/// other locale has data. A missing root locale is an error.
+pub(crate) fn load_with_fallback<'data, M, D>(
+    key: ResourceKey,
+    langid: LanguageIdentifier,
+    variant: Option<Cow<'static, str>>,
+    include_root: bool,
+    data_provider: &D,
+) -> Result<Option<DataPayload<'data, M>>, DataError>
+where
+    M: DataMarker<'data>,
+    D: DataProvider<'data, M> + ?Sized,
+{
+    let parent_locales = ParentLocales::default();
+    let mut next = Some(langid);
+    while let Some(langid) = next {
+        if !include_root && langid == LanguageIdentifier::default() {
+            break;
+        }
+        next = parent_locales.parent(&langid);
+        let result = data_provider
+            .load_payload_with_context(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: variant.clone(),
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .and_then(|response| response.take_payload());
+        match result {
+            Ok(payload) => return Ok(Some(payload)),
+            Err(err)
+                if matches!(err.without_context(), DataError::MissingResourceOptions(_))
+                    && next.is_some() => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::collator::Collator;
+use crate::error::Error;
+use crate::fallback::load_with_fallback;
+use crate::options::*;
+use crate::provider::*;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_normalizer::provider::{CanonicalCombiningClassV1Marker, CanonicalDecompositionV1Marker};
+use icu_provider::prelude::*;
+
+/// A bucket of an [`AlphabeticIndex`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Bucket<'a> {
+    /// The strings that sort before the first label, such as those that start with punctuation
+    /// or digits.
+    Underflow,
+
+    /// The strings that sort after the label on the primary level, and before the next label.
+    Label(&'a str),
+
+    /// The strings that sort after a label but belong to a script without labels.
+    Overflow,
+}
+
+impl<'a> Bucket<'a> {
+    /// Returns the label to display for the bucket, which is "…" for the underflow and overflow
+    /// buckets.
+    pub fn label(&self) -> &'a str {
+        match *self {
+            Bucket::Label(label) => label,
+            Bucket::Underflow | Bucket::Overflow => "\u{2026}",
+        }
+    }
+}
+
+/// An alphabetic index, which assigns strings to buckets labeled with the index characters of a
+/// locale, such as the letters of the alphabet, like the index of a contact list.
+///
+/// Strings are assigned to buckets by their base letters, so "Ärger" goes to the bucket "A" in
+/// German, while it goes to the bucket "Ä" in Swedish, which sorts "Ä" as a separate letter at
+/// the end of the alphabet.
+///
+/// In Chinese, Chinese characters go to the bucket of the initial letter of their pinyin, such as
+/// "B" for "北京" (Běijīng). The stroke-count index of Traditional Chinese and the index
+/// characters of Japanese and Korean are not supported yet, since their collations are not;
+/// strings of their scripts go to the overflow bucket.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::options::CollatorOptions;
+/// use icu_collator::{AlphabeticIndex, Bucket};
+/// use icu_locid_macros::langid;
+/// use icu_provider::fork::MultiForkByKeyProvider;
+/// use icu_provider_fs::FsDataProvider;
+///
+/// let provider = MultiForkByKeyProvider {
+///     providers: vec![
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///             .expect("Data directory should exist"),
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+///             .expect("Data directory should exist"),
+///     ],
+/// };
+///
+/// let index = AlphabeticIndex::try_new(langid!("sv"), &provider, CollatorOptions::default())
+///     .expect("Data should load successfully");
+///
+/// let contacts = ["Östen", "anna", "Åsa", "Zoe", "Adam", "+46 8 123 456"];
+/// let buckets: Vec<(&str, Vec<&str>)> = index
+///     .group(contacts)
+///     .into_iter()
+///     .map(|(bucket, contacts)| (bucket.label(), contacts))
+///     .collect();
+/// assert_eq!(
+///     buckets,
+///     [
+///         ("…", vec!["+46 8 123 456"]),
+///         ("A", vec!["Adam", "anna"]),
+///         ("Z", vec!["Zoe"]),
+///         ("Å", vec!["Åsa"]),
+///         ("Ö", vec!["Östen"]),
+///     ]
+/// );
+///
+/// assert_eq!(index.bucket("ängel"), Bucket::Label("Ä"));
+/// assert_eq!(index.bucket("Ωμέγα"), Bucket::Overflow);
+///
+/// let chinese = AlphabeticIndex::try_new(langid!("zh"), &provider, CollatorOptions::default())
+///     .expect("Data should load successfully");
+/// assert_eq!(chinese.bucket("北京"), Bucket::Label("B"));
+/// assert_eq!(chinese.bucket("上海"), Bucket::Label("S"));
+/// ```
+pub struct AlphabeticIndex<'data> {
+    /// The collator that sorts the strings of a bucket.
+    collator: Collator<'data>,
+    /// The collator that assigns strings to buckets, which only compares base letters.
+    primary_collator: Collator<'data>,
+    /// The first primary weight of each reordering group, followed by the end of the last group.
+    group_starts: Vec<u16>,
+    /// The labels, in order, without labels that are equal on the primary level.
+    labels: Vec<String>,
+    /// The pinyin initials of Chinese characters, for Simplified Chinese.
+    pinyin: Option<DataPayload<'data, PinyinInitialsV1Marker>>,
+}
+
+impl<'data> AlphabeticIndex<'data> {
+    /// Creates an alphabetic index for the given locale, whose strings are sorted with `options`.
+    ///
+    /// The index characters of the locale are looked up with fallback to less specific locales,
+    /// and to the letters "A" to "Z" of the root locale.
+    ///
+    /// Chinese, unless it is written in Traditional Chinese characters, i.e. with the script
+    /// `Hant` or, without a script, in Taiwan, Hong Kong, or Macao, also loads the pinyin
+    /// initials of Chinese characters.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: CollatorOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, ImplicitWeightsV1Marker>
+            + DataProvider<'data, DecimalDigitsV1Marker>
+            + DataProvider<'data, ReorderingGroupsV1Marker>
+            + DataProvider<'data, IndexCharactersV1Marker>
+            + DataProvider<'data, PinyinInitialsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let collator = Collator::try_new(locale.clone(), data_provider, options)?;
+        let primary_collator = Collator::try_new(
+            locale.clone(),
+            data_provider,
+            CollatorOptions {
+                strength: Some(Strength::Primary),
+                case_level: Some(CaseLevel::Off),
+                ..options
+            },
+        )?;
+        let groups: DataPayload<ReorderingGroupsV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::REORDERING_GROUPS_V1))?
+            .take_payload()?;
+        let pinyin = if is_traditional_chinese(&locale.id) {
+            None
+        } else {
+            Self::load_pinyin(locale.id.clone(), data_provider)?
+        };
+        let labels = Self::load_labels(locale.id, data_provider)?;
+
+        let mut index = Self {
+            collator,
+            primary_collator,
+            group_starts: groups.get().starts.iter().collect(),
+            labels: Vec::new(),
+            pinyin,
+        };
+        index.add_labels(labels.get().labels.iter());
+        Ok(index)
+    }
+
+    fn load_labels<D>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<DataPayload<'data, IndexCharactersV1Marker>, Error>
+    where
+        D: DataProvider<'data, IndexCharactersV1Marker> + ?Sized,
+    {
+        let labels =
+            load_with_fallback(key::INDEX_CHARACTERS_V1, langid, None, true, data_provider)?;
+        Ok(labels.ok_or(DataError::MissingPayload)?)
+    }
+
+    fn load_pinyin<D>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<Option<DataPayload<'data, PinyinInitialsV1Marker>>, Error>
+    where
+        D: DataProvider<'data, PinyinInitialsV1Marker> + ?Sized,
+    {
+        // Only Chinese has pinyin initials, so there is no fallback to the root locale.
+        Ok(load_with_fallback(
+            key::PINYIN_INITIALS_V1,
+            langid,
+            None,
+            false,
+            data_provider,
+        )?)
+    }
+
+    /// Adds labels to the index, such as the letters of another script, which are sorted with
+    /// the other labels. Labels that are equal to another label on the primary level are
+    /// ignored.
+    pub fn add_labels<I, S>(&mut self, labels: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.labels
+            .extend(labels.into_iter().map(|label| String::from(label.as_ref())));
+        let collator = &self.primary_collator;
+        // The sort is stable, so the first of the labels that are equal is kept.
+        self.labels.sort_by(|a, b| collator.compare(a, b));
+        self.labels
+            .dedup_by(|a, b| collator.compare(a, b) == Ordering::Equal);
+    }
+
+    /// Returns all the buckets in order: the underflow bucket, the bucket of each label, and the
+    /// overflow bucket.
+    pub fn buckets(&self) -> impl Iterator<Item = Bucket<'_>> + '_ {
+        // Only the labels are captured, since the returned type may not depend on `'data`.
+        let labels = &self.labels[..];
+        (0..labels.len() + 2).map(move |index| bucket_at(labels, index))
+    }
+
+    /// Returns the bucket of `text`.
+    pub fn bucket(&self, text: &str) -> Bucket<'_> {
+        bucket_at(&self.labels, self.bucket_index(text))
+    }
+
+    /// Assigns `items` to buckets, and returns the buckets that are not empty in order, along
+    /// with their items sorted by the collator.
+    pub fn group<I, T>(&self, items: I) -> Vec<(Bucket<'_>, Vec<T>)>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut buckets: BTreeMap<usize, Vec<T>> = BTreeMap::new();
+        for item in items {
+            buckets
+                .entry(self.bucket_index(item.as_ref()))
+                .or_default()
+                .push(item);
+        }
+        buckets
+            .into_iter()
+            .map(|(index, mut items)| {
+                items.sort_by(|a, b| self.collator.compare(a.as_ref(), b.as_ref()));
+                (bucket_at(&self.labels, index), items)
+            })
+            .collect()
+    }
+
+    /// Returns the index of the bucket of `text` in [`Self::buckets()`].
+    fn bucket_index(&self, text: &str) -> usize {
+        if let Some(initial) = self.pinyin_initial(text) {
+            return self.bucket_index(initial.encode_utf8(&mut [0; 4]));
+        }
+        let count = self.labels.partition_point(|label| {
+            self.primary_collator.compare(label, text) != Ordering::Greater
+        });
+        if count == 0 {
+            0
+        } else if self.reorder_group(text) != self.reorder_group(&self.labels[count - 1]) {
+            self.labels.len() + 1
+        } else {
+            count
+        }
+    }
+
+    /// Returns the initial letter of the pinyin of the first character of `text`, if it is a
+    /// Chinese character and the index has pinyin initials.
+    fn pinyin_initial(&self, text: &str) -> Option<char> {
+        let pinyin = self.pinyin.as_ref()?.get();
+        let offset = u32::from(text.chars().next()?).checked_sub(pinyin.first)?;
+        // The initials are ASCII, so the offset of a character is the offset of its byte.
+        match *pinyin
+            .initials
+            .as_bytes()
+            .get(usize::try_from(offset).ok()?)?
+        {
+            b' ' => None,
+            initial => Some(char::from(initial)),
+        }
+    }
+
+    /// Returns the reordering group of the first base letter of `text`, such as its script.
+    fn reorder_group(&self, text: &str) -> Option<usize> {
+        let primary = self.primary_collator.first_primary(text)?;
+        Some(self.group_starts.partition_point(|&start| start <= primary))
+    }
+}
+
+/// Returns whether `langid` is Chinese written in Traditional Chinese characters, whose index is
+/// by stroke count instead of pinyin.
+fn is_traditional_chinese(langid: &LanguageIdentifier) -> bool {
+    langid.language == "zh"
+        && match langid.script {
+            Some(script) => script == "Hant",
+            None => {
+                matches!(langid.region, Some(region) if region == "TW" || region == "HK" || region == "MO")
+            }
+        }
+}
+
+/// Returns the bucket at `index` in [`AlphabeticIndex::buckets()`], given the labels of the index.
+fn bucket_at(labels: &[String], index: usize) -> Bucket<'_> {
+    match index.checked_sub(1).map(|index| labels.get(index)) {
+        None => Bucket::Underflow,
+        Some(Some(label)) => Bucket::Label(label),
+        Some(None) => Bucket::Overflow,
+    }
+}
@@ -0,0 +1,88 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_collator`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API compares strings according to the sorting conventions of a locale, as specified by
+//! the [`Unicode Collation Algorithm`] (UCA). It is meant for sorting strings for display to
+//! users: unlike comparing strings by their code points, which orders "Äpfel" after "zebra", the
+//! [`Collator`] orders it before "banana", or after "zebra" only in languages such as Swedish,
+//! where "ä" is a separate letter at the end of the alphabet.
+//!
+//! The [`Collator`] is driven by the collation data in [`provider`]: the root collation, which
+//! is the Default Unicode Collation Element Table (DUCET), and a tailoring for each locale that
+//! changes the order of some characters. The collator decomposes strings with the Normalization
+//! Form D normalizer of [`icu_normalizer`], so it also needs its data.
+//!
+//! The `data` directory of this crate contains the collation data for the `FsDataProvider`; it
+//! is generated by `tools/generate_data.py` from the DUCET and the CLDR tailorings of the
+//! `Unicode::Collate::Locale` Perl module. Tailorings that order Chinese, Japanese, and Korean
+//! characters are not supported yet.
+//!
+//! Sort orders that no locale provides can be defined with tailoring rules in the syntax of ICU,
+//! such as `"&c < č <<< Č"`, which [`Collator::try_new_with_rules()`] compiles against the root
+//! collation.
+//!
+//! How strings are compared, for example whether accents or punctuation are ignored or whether
+//! numbers are compared by their value, is configured with the [`options`], which can also be set
+//! with the Unicode extension keywords of the locale. The same options apply to
+//! [`StringSearch`], which finds the substrings of a text that the collator considers equal to
+//! a pattern.
+//!
+//! An [`AlphabeticIndex`] assigns strings to buckets labeled with the index characters of a
+//! locale, such as the letters of its alphabet, for example for the index of a contact list.
+//! Chinese characters are assigned to the initial letters of their pinyin.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_collator::options::CollatorOptions;
+//! use icu_collator::Collator;
+//! use icu_locid_macros::langid;
+//! use icu_provider::fork::MultiForkByKeyProvider;
+//! use icu_provider_fs::FsDataProvider;
+//! use std::cmp::Ordering;
+//!
+//! let provider = MultiForkByKeyProvider {
+//!     providers: vec![
+//!         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+//!             .expect("Data directory should exist"),
+//!         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+//!             .expect("Data directory should exist"),
+//!     ],
+//! };
+//!
+//! let root = Collator::try_new(langid!("und"), &provider, CollatorOptions::default())
+//!     .expect("Data should load successfully");
+//! assert_eq!(root.compare("apfel", "Äpfel"), Ordering::Less);
+//! assert_eq!(root.compare("Äpfel", "banana"), Ordering::Less);
+//!
+//! let swedish = Collator::try_new(langid!("sv"), &provider, CollatorOptions::default())
+//!     .expect("Data should load successfully");
+//! assert_eq!(swedish.compare("Äpfel", "zebra"), Ordering::Greater);
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`Unicode Collation Algorithm`]: https://www.unicode.org/reports/tr10/
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod collator;
+mod elements;
+pub mod error;
+mod fallback;
+mod index;
+pub mod options;
+pub mod provider;
+mod reordering;
+mod rules;
+mod search;
+
+pub use collator::Collator;
+pub use error::Error as CollatorError;
+pub use index::{AlphabeticIndex, Bucket};
+pub use search::{Matches, StringSearch};
@@ -0,0 +1,211 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`Collator`](crate::Collator).
+//!
+//! The options correspond to the collation settings of [`UTS #35`]. Each of them can also be set
+//! with a Unicode extension keyword of the locale, such as `-u-ks-level1`; options that are set
+//! in [`CollatorOptions`] take precedence over the keywords.
+//!
+//! The common cases of comparing strings regardless of case, or of accents and case, have the
+//! constructors [`Collator::try_new_ignoring_case()`](crate::Collator::try_new_ignoring_case)
+//! and
+//! [`Collator::try_new_ignoring_accents_and_case()`](crate::Collator::try_new_ignoring_accents_and_case).
+//!
+//! [`UTS #35`]: https://www.unicode.org/reports/tr35/tr35-collation.html#Setting_Options
+
+/// A bag of options defining how strings are compared by [`Collator`](crate::Collator).
+///
+/// Options that are `None` are taken from the keywords of the locale, or else from the defaults
+/// of its tailoring.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::options::{AlternateHandling, CollatorOptions, Strength};
+/// use icu_collator::Collator;
+/// use icu_locid_macros::langid;
+/// use icu_provider::fork::MultiForkByKeyProvider;
+/// use icu_provider_fs::FsDataProvider;
+/// use std::cmp::Ordering;
+///
+/// let provider = MultiForkByKeyProvider {
+///     providers: vec![
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///             .expect("Data directory should exist"),
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+///             .expect("Data directory should exist"),
+///     ],
+/// };
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Primary);
+/// options.alternate_handling = Some(AlternateHandling::Shifted);
+/// let collator = Collator::try_new(langid!("en"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// // Ignores case, accents, and punctuation.
+/// assert_eq!(collator.compare("Resume", "résumé"), Ordering::Equal);
+/// assert_eq!(collator.compare("e-mail", "email"), Ordering::Equal);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct CollatorOptions {
+    /// The number of levels on which strings are compared, set with `-u-ks`.
+    ///
+    /// If no strength is set, it is [`Strength::Tertiary`].
+    pub strength: Option<Strength>,
+    /// Whether whitespace, punctuation, and symbols are ignored, set with `-u-ka`.
+    pub alternate_handling: Option<AlternateHandling>,
+    /// Whether uppercase letters sort before lowercase letters, set with `-u-kf`.
+    pub case_first: Option<CaseFirst>,
+    /// Whether strings are compared on a separate level for case, set with `-u-kc`.
+    pub case_level: Option<CaseLevel>,
+    /// Whether sequences of decimal digits are compared by their numeric value, set with
+    /// `-u-kn`.
+    pub numeric: Option<Numeric>,
+}
+
+/// The number of levels on which strings are compared. The levels are compared in order, so
+/// that a difference on a level only matters if the strings are equal on the previous levels.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone, Copy)]
+pub enum Strength {
+    /// Compare the base letters only, such that "a" and "á" are equal (`-u-ks-level1`).
+    Primary,
+
+    /// Also compare accents, such that "a" and "A" are equal (`-u-ks-level2`).
+    Secondary,
+
+    /// Also compare case and variants of the letters, such as the full-width "ａ"
+    /// (`-u-ks-level3`).
+    Tertiary,
+
+    /// Also compare the ignored characters with [`AlternateHandling::Shifted`]
+    /// (`-u-ks-level4`).
+    Quaternary,
+
+    /// Also compare the code points of the strings, such that only strings that are canonically
+    /// equivalent are equal (`-u-ks-identic`).
+    Identical,
+}
+
+impl Default for Strength {
+    fn default() -> Self {
+        Self::Tertiary
+    }
+}
+
+impl Strength {
+    pub(crate) fn from_keyword(value: &str) -> Option<Self> {
+        match value {
+            "level1" => Some(Self::Primary),
+            "level2" => Some(Self::Secondary),
+            "level3" => Some(Self::Tertiary),
+            "level4" => Some(Self::Quaternary),
+            "identic" => Some(Self::Identical),
+            _ => None,
+        }
+    }
+}
+
+/// How variable characters, i.e. whitespace, punctuation, and symbols, are compared.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AlternateHandling {
+    /// Compare variable characters like letters, such that "e-mail" sorts after "email"
+    /// (`-u-ka-noignore`).
+    NonIgnorable,
+
+    /// Ignore variable characters on the first three levels, such that "e-mail" and "email"
+    /// are equal with [`Strength::Tertiary`]; they are only compared with
+    /// [`Strength::Quaternary`] (`-u-ka-shifted`).
+    Shifted,
+}
+
+impl AlternateHandling {
+    pub(crate) fn from_keyword(value: &str) -> Option<Self> {
+        match value {
+            "noignore" => Some(Self::NonIgnorable),
+            "shifted" => Some(Self::Shifted),
+            _ => None,
+        }
+    }
+}
+
+/// The order of uppercase and lowercase letters.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CaseFirst {
+    /// The order of the root collation, in which lowercase letters sort first (`-u-kf-false`).
+    Off,
+
+    /// Lowercase letters sort first (`-u-kf-lower`).
+    LowerFirst,
+
+    /// Uppercase letters sort first (`-u-kf-upper`).
+    UpperFirst,
+}
+
+impl CaseFirst {
+    pub(crate) fn from_keyword(value: &str) -> Option<Self> {
+        match value {
+            "false" => Some(Self::Off),
+            "lower" => Some(Self::LowerFirst),
+            "upper" => Some(Self::UpperFirst),
+            _ => None,
+        }
+    }
+}
+
+/// Whether case is compared on a separate level between the secondary and the tertiary level.
+///
+/// With [`Strength::Primary`] and the case level, strings are compared by their base letters
+/// and case but not by their accents.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CaseLevel {
+    /// Case is compared on the tertiary level (`-u-kc-false`).
+    Off,
+
+    /// Case is compared on its own level (`-u-kc-true`).
+    On,
+}
+
+impl CaseLevel {
+    pub(crate) fn from_keyword(value: &str) -> Option<Self> {
+        match value {
+            // The value "true" is omitted in canonical locales.
+            "" | "true" => Some(Self::On),
+            "false" => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Whether sequences of decimal digits are compared by their numeric value.
+///
+/// With the numeric collation, "item9" sorts before "item10", and leading zeros are ignored, so
+/// that "007" and "7" are equal on the first three levels. Digits of all scripts are supported,
+/// but a sequence of digits of several scripts is compared as a single number.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Numeric {
+    /// Digits are compared one by one, such that "item10" sorts before "item9"
+    /// (`-u-kn-false`).
+    Off,
+
+    /// Sequences of digits are compared by their numeric value (`-u-kn-true`).
+    On,
+}
+
+impl Numeric {
+    pub(crate) fn from_keyword(value: &str) -> Option<Self> {
+        match value {
+            // The value "true" is omitted in canonical locales.
+            "" | "true" => Some(Self::On),
+            "false" => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
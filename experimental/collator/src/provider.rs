@@ -0,0 +1,204 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+
+pub mod key {
+    //! Resource keys for [`icu_collator`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: collation elements of the root collation.
+    pub const ROOT_V1: ResourceKey = resource_key!(Collator, "root", 1);
+
+    /// Resource key: collation elements that a locale tailors.
+    pub const TAILORING_V1: ResourceKey = resource_key!(Collator, "tailoring", 1);
+
+    /// Resource key: implicit weights of the characters without collation elements.
+    pub const IMPLICIT_WEIGHTS_V1: ResourceKey = resource_key!(Collator, "implicit", 1);
+
+    /// Resource key: decimal digits, for the numeric collation.
+    pub const DECIMAL_DIGITS_V1: ResourceKey = resource_key!(Collator, "digits", 1);
+
+    /// Resource key: reordering groups of the primary weights, for script reordering.
+    pub const REORDERING_GROUPS_V1: ResourceKey = resource_key!(Collator, "reordering", 1);
+
+    /// Resource key: index characters, the labels of the buckets of an alphabetic index.
+    pub const INDEX_CHARACTERS_V1: ResourceKey = resource_key!(Collator, "index", 1);
+
+    /// Resource key: initial letters of the pinyin of Chinese characters, for an alphabetic
+    /// index.
+    pub const PINYIN_INITIALS_V1: ResourceKey = resource_key!(Collator, "pinyin", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_collator`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(CollationDataV1Marker, [key::ROOT_V1, key::TAILORING_V1]),
+    icu_provider::registered_keys!(ImplicitWeightsV1Marker, [key::IMPLICIT_WEIGHTS_V1]),
+    icu_provider::registered_keys!(DecimalDigitsV1Marker, [key::DECIMAL_DIGITS_V1]),
+    icu_provider::registered_keys!(ReorderingGroupsV1Marker, [key::REORDERING_GROUPS_V1]),
+    icu_provider::registered_keys!(IndexCharactersV1Marker, [key::INDEX_CHARACTERS_V1]),
+    icu_provider::registered_keys!(PinyinInitialsV1Marker, [key::PINYIN_INITIALS_V1]),
+];
+
+/// A table of collation elements, either the root collation, i.e. the Default Unicode Collation
+/// Element Table of [`UTS #10`], or the tailoring of a locale, which takes precedence over it.
+///
+/// A collation element is encoded in a `u32`, from the most significant bit: 16 bits of primary
+/// weight, 10 bits of secondary weight, 5 bits of tertiary weight, and a bit that is set for
+/// variable collation elements.
+///
+/// The mappings refer to their collation elements in [`Self::elements`] by the offset of the
+/// first collation element shifted left by 8 bits, with the number of collation elements in the
+/// lowest 8 bits.
+///
+/// A tailoring may also change the defaults of the collation options. They are not set in the
+/// root collation.
+///
+/// [`UTS #10`]: https://www.unicode.org/reports/tr10/
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CollationDataV1<'data> {
+    /// Map from a character to its collation elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub mappings: ZeroMap<'data, char, u32>,
+
+    /// Map from a string of more than one character to its collation elements.
+    ///
+    /// The strings are in Normalization Form D. All their prefixes have mappings as well, either
+    /// in the root collation or in the same tailoring.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub contractions: ZeroMap<'data, str, u32>,
+
+    /// The collation elements of all the mappings.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub elements: ZeroVec<'data, u32>,
+
+    /// Whether variable collation elements are ignored by default, see
+    /// [`AlternateHandling::Shifted`](crate::options::AlternateHandling::Shifted).
+    pub alternate_shifted: bool,
+
+    /// Whether the secondary weights are compared from the end of the strings, as in Canadian
+    /// French.
+    pub backward_second_level: bool,
+
+    /// Whether uppercase letters sort before lowercase letters by default, see
+    /// [`CaseFirst::UpperFirst`](crate::options::CaseFirst::UpperFirst).
+    pub upper_first: bool,
+}
+
+/// The ranges of characters that get implicit weights, see
+/// <https://www.unicode.org/reports/tr10/#Implicit_Weights>.
+///
+/// Both fields contain triples of the first code point of a range, the last code point of the
+/// range, and the base primary weight of the range. Characters outside of these ranges that have
+/// no collation elements get the base weight `FBC0` of unassigned code points.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ImplicitWeightsV1<'data> {
+    /// The ranges of Unified_Ideograph characters, whose implicit weights are computed from the
+    /// high and low bits of the code point.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub han: ZeroVec<'data, u32>,
+
+    /// The ranges of characters of the scripts that are listed with `@implicitweights` in the
+    /// DUCET, whose implicit weights are computed from their offset from the start of the first
+    /// range of the script. The ranges are whole blocks, so they include unassigned code points.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ranges: ZeroVec<'data, u32>,
+}
+
+/// The decimal digits, i.e. the characters with General_Category=Nd, which are compared by their
+/// numeric value with [`Numeric::On`](crate::options::Numeric::On).
+///
+/// The decimal digits of a script are encoded contiguously from zero to nine, so only the code
+/// points of the zeros are stored.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct DecimalDigitsV1<'data> {
+    /// The code points of the digits zero, in ascending order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub zeros: ZeroVec<'data, u32>,
+}
+
+/// The reordering groups of the root collation, i.e. the ranges of primary weights of the
+/// special groups, such as punctuation and digits, and of the scripts, which can be reordered
+/// with the `-u-kr` keyword, see
+/// <https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering>.
+///
+/// The groups are contiguous and in the order of the root collation. Primary weights outside
+/// of them, such as those of unassigned code points, are not reordered.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ReorderingGroupsV1<'data> {
+    /// The first primary weight of each group, followed by the primary weight after the end of
+    /// the last group.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub starts: ZeroVec<'data, u16>,
+
+    /// Map from a reordering code, i.e. the code of a special group or the ISO 15924 code of a
+    /// script in lowercase, to the index of its group. Scripts that are sorted together, such
+    /// as Hiragana and Katakana, share a group.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub codes: ZeroMap<'data, str, u16>,
+}
+
+/// The index characters of a locale, i.e. the labels of the buckets of an
+/// [`AlphabeticIndex`](crate::AlphabeticIndex), such as the letters "A" to "Z" for English, from
+/// the `index` exemplar characters of CLDR.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct IndexCharactersV1<'data> {
+    /// The labels, in the order of the collation of the locale. A label may have more than one
+    /// character, such as "CH" in Czech.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub labels: VarZeroVec<'data, str>,
+}
+
+/// The initial letters of the pinyin of Chinese characters, which are the buckets of an
+/// [`AlphabeticIndex`](crate::AlphabeticIndex) of Chinese, such as "B" for "北" (běi), from the
+/// pinyin collation of CLDR. A character with more than one reading gets the initial of the
+/// reading that the collation sorts it by.
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct PinyinInitialsV1<'data> {
+    /// The code point of the first character of [`Self::initials`].
+    pub first: u32,
+
+    /// The initial letter of each code point from [`Self::first`] on, or a space for a code point
+    /// without a reading.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub initials: Cow<'data, str>,
+}
@@ -0,0 +1,113 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::ReorderingGroupsV1;
+use alloc::vec;
+use alloc::vec::Vec;
+use zerovec::ule::AsULE;
+
+/// The codes of the special reordering groups, which come before the scripts.
+const SPECIAL_GROUPS: [&str; 5] = ["space", "punct", "symbol", "currency", "digit"];
+
+/// The reordering codes that stand for all the scripts that are not listed.
+const OTHERS: [&str; 2] = ["others", "zzzz"];
+
+/// A permutation of the reordering groups of the primary weights, see
+/// <https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering>.
+pub(crate) struct Reordering {
+    /// The first primary weight of each group in the root collation, followed by the end of the
+    /// last group.
+    starts: Vec<u16>,
+    /// The first primary weight of each group after the reordering.
+    new_starts: Vec<u16>,
+}
+
+impl Reordering {
+    /// Returns the reordering for the value of a `-u-kr` keyword, such as `latn-digit`, or
+    /// `None` if a code is not supported or if no group is moved.
+    ///
+    /// The listed groups are moved, in order, after the special groups that are not listed. The
+    /// groups that are not listed keep their order, and follow the listed groups unless their
+    /// place is given by `others`.
+    pub fn try_new(value: &str, groups: &ReorderingGroupsV1) -> Option<Self> {
+        let starts: Vec<u16> = groups.starts.iter().collect();
+        let group_count = starts.len().checked_sub(1)?;
+        let group = |code: &str| {
+            groups
+                .codes
+                .get(code)
+                .map(|index| usize::from(u16::from_unaligned(index)))
+                .filter(|&index| index < group_count)
+        };
+
+        let mut listed = vec![false; group_count];
+        let mut order = Vec::with_capacity(group_count);
+        let mut others_position = None;
+        for code in value.split('-') {
+            if OTHERS.contains(&code) {
+                others_position.get_or_insert(order.len());
+                continue;
+            }
+            let index = group(code)?;
+            // Codes of scripts that share a group, such as `hira` and `kana`, may both be listed.
+            if !listed[index] {
+                listed[index] = true;
+                order.push(index);
+            }
+        }
+
+        let mut new_order: Vec<usize> = SPECIAL_GROUPS
+            .iter()
+            .filter_map(|code| group(code))
+            .filter(|&index| !listed[index])
+            .collect();
+        let unlisted: Vec<usize> = (0..group_count)
+            .filter(|&index| !listed[index] && !new_order.contains(&index))
+            .collect();
+        let others_position = others_position.unwrap_or(order.len());
+        new_order.extend_from_slice(&order[..others_position]);
+        new_order.extend(unlisted);
+        new_order.extend_from_slice(&order[others_position..]);
+        if new_order.iter().enumerate().all(|(i, &index)| i == index) {
+            return None;
+        }
+
+        // The groups keep their sizes, so the reordered weights cover the same range.
+        let mut new_starts = vec![0; group_count];
+        let mut next = starts[0];
+        for index in new_order {
+            new_starts[index] = next;
+            next += starts[index + 1] - starts[index];
+        }
+        Some(Self { starts, new_starts })
+    }
+
+    /// Returns the reordered primary weight.
+    pub fn reorder(&self, primary: u16) -> u16 {
+        let index = match self.starts.binary_search(&primary) {
+            Ok(index) => index,
+            Err(0) => return primary,
+            Err(index) => index - 1,
+        };
+        match self.new_starts.get(index) {
+            Some(new_start) => new_start + (primary - self.starts[index]),
+            // The primary weight is after the last group.
+            None => primary,
+        }
+    }
+
+    /// Returns the primary weight that is reordered to `primary`, i.e. the inverse of
+    /// [`Self::reorder()`].
+    pub fn restore(&self, primary: u16) -> u16 {
+        self.new_starts
+            .iter()
+            .zip(self.starts.windows(2))
+            .find(|&(&new_start, range)| {
+                (new_start..new_start + (range[1] - range[0])).contains(&primary)
+            })
+            .map_or(primary, |(&new_start, range)| {
+                range[0] + (primary - new_start)
+            })
+    }
+}
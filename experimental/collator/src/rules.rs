@@ -0,0 +1,402 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compilation of tailoring rules, see
+//! <https://unicode-org.github.io/icu/userguide/collation/customization/>.
+//!
+//! The strings tailored after a reset string get the collation elements of the reset string
+//! followed by one collation element per relation, whose weight on the level of the relation is
+//! greater than all the weights of the root collation. A string tailored with `&a < b` thus sorts
+//! after all the strings that start with "a", and before "b" in the root collation, while strings
+//! that start with "b" still sort after it.
+
+use crate::collator::Collator;
+use crate::elements::CollationElement;
+use crate::error::Error;
+use crate::provider::CollationDataV1;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use zerovec::{ZeroMap, ZeroVec};
+
+/// The level on which a tailored string differs from the string before it, from the strongest
+/// to the weakest.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Relation {
+    Primary,
+    Secondary,
+    Tertiary,
+    Identical,
+}
+
+/// A tailored string, with the byte offset of its relation in the rules.
+struct Node {
+    text: String,
+    relation: Relation,
+    offset: usize,
+}
+
+/// The strings tailored after a reset string, in their order.
+struct Chain {
+    reset: String,
+    nodes: Vec<Node>,
+}
+
+/// Compiles tailoring rules into collation data that takes precedence over the root collation,
+/// where `root` is a collator without tailoring.
+pub(crate) fn compile(rules: &str, root: &Collator<'_>) -> Result<CollationDataV1<'static>, Error> {
+    let mut parser = Parser { rules, offset: 0 };
+    let mut chains: Vec<Chain> = Vec::new();
+    // The index of the current chain and the number of its nodes before the current position.
+    let mut position = None;
+    let mut alternate_shifted = false;
+    let mut backward_second_level = false;
+    let mut upper_first = false;
+
+    while let Some(c) = parser.skip_whitespace() {
+        let start = parser.offset;
+        match c {
+            '&' => {
+                parser.bump();
+                let text = root.decompose(&parser.parse_text()?);
+                position = Some(match find_node(&chains, &text) {
+                    Some((chain, index)) => (chain, index + 1),
+                    None => match chains.iter().position(|chain| chain.reset == text) {
+                        Some(chain) => (chain, 0),
+                        None => {
+                            chains.push(Chain {
+                                reset: text,
+                                nodes: Vec::new(),
+                            });
+                            (chains.len() - 1, 0)
+                        }
+                    },
+                });
+            }
+            '<' | '=' => {
+                let (relation, star) = parser.parse_relation()?;
+                let (chain, mut index) = position.ok_or(Error::InvalidRules(start))?;
+                let texts = if star {
+                    parser.parse_list()?
+                } else {
+                    vec![parser.parse_text()?]
+                };
+                for text in texts {
+                    let text = root.decompose(&text);
+                    // A string that is tailored again moves.
+                    if let Some((old_chain, old_index)) = find_node(&chains, &text) {
+                        chains[old_chain].nodes.remove(old_index);
+                        if old_chain == chain && old_index < index {
+                            index -= 1;
+                        }
+                    }
+                    // The string goes after the strings that follow the position with a weaker
+                    // relation, like in ICU.
+                    let nodes = &mut chains[chain].nodes;
+                    while nodes
+                        .get(index)
+                        .map_or(false, |node| node.relation > relation)
+                    {
+                        index += 1;
+                    }
+                    nodes.insert(
+                        index,
+                        Node {
+                            text,
+                            relation,
+                            offset: start,
+                        },
+                    );
+                    index += 1;
+                }
+                position = Some((chain, index));
+            }
+            '[' => {
+                let setting: Vec<&str> = parser.parse_setting()?.split_whitespace().collect();
+                match setting.as_slice() {
+                    ["alternate", "shifted"] => alternate_shifted = true,
+                    ["alternate", "non-ignorable"] => alternate_shifted = false,
+                    ["backwards", "2"] => backward_second_level = true,
+                    ["caseFirst", "upper"] => upper_first = true,
+                    ["caseFirst", "lower"] | ["caseFirst", "off"] => upper_first = false,
+                    _ => return Err(Error::InvalidRules(start)),
+                }
+            }
+            _ => return Err(Error::InvalidRules(start)),
+        }
+    }
+
+    let mut entries: BTreeMap<String, Vec<CollationElement>> = BTreeMap::new();
+    for chain in &chains {
+        let mut elements = root.collation_elements(&chain.reset);
+        for node in &chain.nodes {
+            match node.relation {
+                Relation::Primary => elements.push(CollationElement::new(0xFFFE, 0x0020, 0x0002)),
+                Relation::Secondary => elements.push(CollationElement::new(0, 0x03FF, 0x0002)),
+                Relation::Tertiary => elements.push(CollationElement::new(0, 0, 0x001F)),
+                Relation::Identical => {}
+            }
+            // The number of collation elements of a mapping is encoded in 8 bits.
+            if elements.len() > 0xFF {
+                return Err(Error::InvalidRules(node.offset));
+            }
+            entries.insert(node.text.clone(), elements.clone());
+        }
+    }
+
+    // All the prefixes of a contraction need mappings, see `CollationDataV1::contractions`.
+    let contractions: Vec<String> = entries
+        .keys()
+        .filter(|text| text.chars().nth(1).is_some())
+        .cloned()
+        .collect();
+    for text in contractions {
+        for (end, _) in text.char_indices().skip(2) {
+            let prefix = &text[..end];
+            if !entries.contains_key(prefix) && root.lookup(prefix).is_none() {
+                let elements = tailored_elements(prefix, &entries, root);
+                entries.insert(prefix.into(), elements);
+            }
+        }
+    }
+
+    let mut mappings = ZeroMap::new();
+    let mut contractions = ZeroMap::new();
+    let mut elements = Vec::new();
+    for (text, text_elements) in &entries {
+        let value = (elements.len() as u32) << 8 | text_elements.len() as u32;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                mappings.insert(&c, &value);
+            }
+            _ => {
+                contractions.insert(text.as_str(), &value);
+            }
+        }
+        elements.extend(text_elements.iter().map(|element| element.0));
+    }
+    Ok(CollationDataV1 {
+        mappings,
+        contractions,
+        elements: ZeroVec::clone_from_slice(&elements),
+        alternate_shifted,
+        backward_second_level,
+        upper_first,
+    })
+}
+
+/// Returns the chain and the index of the node of `text`, if it is tailored.
+fn find_node(chains: &[Chain], text: &str) -> Option<(usize, usize)> {
+    chains.iter().enumerate().find_map(|(i, chain)| {
+        chain
+            .nodes
+            .iter()
+            .position(|node| node.text == text)
+            .map(|j| (i, j))
+    })
+}
+
+/// Returns the collation elements of `text` with the tailored strings of `entries`, which take
+/// precedence over the root collation.
+fn tailored_elements(
+    text: &str,
+    entries: &BTreeMap<String, Vec<CollationElement>>,
+    root: &Collator<'_>,
+) -> Vec<CollationElement> {
+    let mut elements = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        // The longest tailored string at the start, or else the root collation elements up to
+        // the next tailored string.
+        let tailored = rest
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .rev()
+            .find(|&end| entries.contains_key(&rest[..end]));
+        let end = match tailored {
+            Some(end) => {
+                elements.extend_from_slice(&entries[&rest[..end]]);
+                end
+            }
+            None => {
+                let end = rest
+                    .char_indices()
+                    .skip(1)
+                    .map(|(i, _)| i)
+                    .find(|&i| {
+                        entries
+                            .keys()
+                            .any(|key| rest[i..].starts_with(key.as_str()))
+                    })
+                    .unwrap_or(rest.len());
+                elements.extend(root.collation_elements(&rest[..end]));
+                end
+            }
+        };
+        rest = &rest[end..];
+    }
+    elements
+}
+
+/// Returns whether `c` must be quoted or escaped in a string of the rules.
+fn is_syntax_character(c: char) -> bool {
+    c.is_ascii_punctuation()
+}
+
+struct Parser<'a> {
+    rules: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.rules[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// Skips whitespace and comments, which start with `#` and end with the line, and returns the
+    /// next character.
+    fn skip_whitespace(&mut self) -> Option<char> {
+        loop {
+            match self.peek()? {
+                '#' => while !matches!(self.bump(), None | Some('\n')) {},
+                c if c.is_whitespace() => {
+                    self.bump();
+                }
+                c => return Some(c),
+            }
+        }
+    }
+
+    /// Parses `<`, `<<`, `<<<`, or `=`, and returns whether it is followed by `*`.
+    fn parse_relation(&mut self) -> Result<(Relation, bool), Error> {
+        let start = self.offset;
+        let relation = if self.bump() == Some('=') {
+            Relation::Identical
+        } else {
+            let mut count = 1;
+            while self.peek() == Some('<') {
+                self.bump();
+                count += 1;
+            }
+            match count {
+                1 => Relation::Primary,
+                2 => Relation::Secondary,
+                3 => Relation::Tertiary,
+                // The quaternary relation `<<<<` is not supported.
+                _ => return Err(Error::InvalidRules(start)),
+            }
+        };
+        let star = self.peek() == Some('*');
+        if star {
+            self.bump();
+        }
+        Ok((relation, star))
+    }
+
+    /// Parses a non-empty string, in which unquoted whitespace is ignored.
+    fn parse_text(&mut self) -> Result<String, Error> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' => {
+                    self.bump();
+                    // Two apostrophes are an apostrophe, both inside and outside of quotes.
+                    if self.peek() == Some('\'') {
+                        self.bump();
+                        text.push('\'');
+                        continue;
+                    }
+                    loop {
+                        match self.bump() {
+                            Some('\'') if self.peek() == Some('\'') => {
+                                self.bump();
+                                text.push('\'');
+                            }
+                            Some('\'') => break,
+                            Some(c) => text.push(c),
+                            None => return Err(Error::InvalidRules(self.offset)),
+                        }
+                    }
+                }
+                '\\' => {
+                    self.bump();
+                    text.push(self.parse_escape()?);
+                }
+                c if c.is_whitespace() => {
+                    self.bump();
+                }
+                c if is_syntax_character(c) => break,
+                c => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if text.is_empty() {
+            Err(Error::InvalidRules(start))
+        } else {
+            Ok(text)
+        }
+    }
+
+    /// Parses the character after a backslash, which is either `uXXXX` or `UXXXXXXXX` for a
+    /// code point, or else the character itself.
+    fn parse_escape(&mut self) -> Result<char, Error> {
+        let start = self.offset;
+        let len = match self.bump() {
+            Some('u') => 4,
+            Some('U') => 8,
+            Some(c) => return Ok(c),
+            None => return Err(Error::InvalidRules(start)),
+        };
+        let c = self
+            .rules
+            .get(self.offset..self.offset + len)
+            .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or(Error::InvalidRules(start))?;
+        self.offset += len;
+        Ok(c)
+    }
+
+    /// Parses the characters after a relation with `*`, in which `-` forms ranges such as
+    /// `a-d`.
+    fn parse_list(&mut self) -> Result<Vec<String>, Error> {
+        let mut chars: Vec<char> = self.parse_text()?.chars().collect();
+        while self.peek() == Some('-') {
+            let start = self.offset;
+            self.bump();
+            let text = self.parse_text()?;
+            let mut rest = text.chars();
+            match (chars.pop(), rest.next()) {
+                (Some(first), Some(last)) if first <= last => chars.extend(first..=last),
+                _ => return Err(Error::InvalidRules(start)),
+            }
+            chars.extend(rest);
+        }
+        Ok(chars.into_iter().map(String::from).collect())
+    }
+
+    /// Parses a setting in brackets, such as `[caseFirst upper]`, and returns its contents.
+    fn parse_setting(&mut self) -> Result<&'a str, Error> {
+        let start = self.offset;
+        let end = start
+            + self.rules[start..]
+                .find(']')
+                .ok_or(Error::InvalidRules(start))?;
+        self.offset = end + 1;
+        Ok(&self.rules[start + 1..end])
+    }
+}
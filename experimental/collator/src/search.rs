@@ -0,0 +1,164 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::Collator;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+
+/// A search for the substrings of a text that are equal to a pattern according to a
+/// [`Collator`].
+///
+/// Which differences are ignored is determined by the options of the collator: with
+/// [`Strength::Primary`], a search for "cote" finds "côte" and "Côté", and with
+/// [`AlternateHandling::Shifted`], a search for "email" finds "e-mail".
+///
+/// Matches start and end on character boundaries that are not followed by a combining mark, so
+/// that a search for "e" does not find the "e" of "e\u{0301}" with [`Strength::Secondary`].
+/// The matched substrings are compared to the pattern on their own, so a search may find a part
+/// of a contraction, such as the "c" of the Spanish traditional "ch".
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::options::{CollatorOptions, Strength};
+/// use icu_collator::{Collator, StringSearch};
+/// use icu_locid_macros::langid;
+/// use icu_provider::fork::MultiForkByKeyProvider;
+/// use icu_provider_fs::FsDataProvider;
+///
+/// let provider = MultiForkByKeyProvider {
+///     providers: vec![
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///             .expect("Data directory should exist"),
+///         FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+///             .expect("Data directory should exist"),
+///     ],
+/// };
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Primary);
+/// let collator = Collator::try_new(langid!("fr"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// let text = "Le côté de la Côte";
+/// let search = StringSearch::new(&collator, "cote");
+/// let matches: Vec<&str> = search.find_iter(text).map(|range| &text[range]).collect();
+/// assert_eq!(matches, ["côté", "Côte"]);
+/// ```
+///
+/// [`Strength::Primary`]: crate::options::Strength::Primary
+/// [`Strength::Secondary`]: crate::options::Strength::Secondary
+/// [`AlternateHandling::Shifted`]: crate::options::AlternateHandling::Shifted
+pub struct StringSearch<'c, 'data> {
+    collator: &'c Collator<'data>,
+    pattern: String,
+    pattern_weights: Vec<[u16; 5]>,
+    /// The number of non-ignorable primary weights of the pattern; longer substrings of the text
+    /// cannot match.
+    primary_count: usize,
+}
+
+impl<'c, 'data> StringSearch<'c, 'data> {
+    /// Creates a search for `pattern` with the given collator.
+    ///
+    /// A pattern that only consists of characters that the collator ignores, such as the empty
+    /// string, matches nothing.
+    pub fn new(collator: &'c Collator<'data>, pattern: &str) -> Self {
+        let pattern_weights = collator.text_weights(pattern);
+        let primary_count = primary_count(&pattern_weights);
+        Self {
+            collator,
+            pattern: String::from(pattern),
+            pattern_weights,
+            primary_count,
+        }
+    }
+
+    /// Returns the byte range of the first match in `text`.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        self.find_at(text, 0)
+    }
+
+    /// Returns an iterator over the byte ranges of the non-overlapping matches in `text`.
+    pub fn find_iter<'s, 't>(&'s self, text: &'t str) -> Matches<'s, 't, 'c, 'data> {
+        Matches {
+            search: self,
+            text,
+            position: 0,
+        }
+    }
+
+    /// Returns the first match in `text` that starts at or after `start`.
+    fn find_at(&self, text: &str, start: usize) -> Option<Range<usize>> {
+        if self.is_ignorable(&self.pattern_weights) {
+            return None;
+        }
+        let boundaries = self.boundaries(text);
+        for (i, &match_start) in boundaries.iter().enumerate() {
+            if match_start < start {
+                continue;
+            }
+            for &match_end in &boundaries[i + 1..] {
+                let candidate = &text[match_start..match_end];
+                let weights = self.collator.text_weights(candidate);
+                if match_end == boundaries[i + 1] && self.is_ignorable(&weights) {
+                    // Matches do not start with ignorable characters.
+                    break;
+                }
+                if primary_count(&weights) > self.primary_count {
+                    break;
+                }
+                if self
+                    .collator
+                    .compare_weights(&weights, &self.pattern_weights)
+                    .then_with(|| self.collator.compare_identical(candidate, &self.pattern))
+                    == Ordering::Equal
+                {
+                    return Some(match_start..match_end);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns whether weights are ignorable on all the levels that are compared.
+    fn is_ignorable(&self, weights: &[[u16; 5]]) -> bool {
+        self.collator.compare_weights(weights, &[]) == Ordering::Equal
+    }
+
+    /// Returns the byte offsets in `text` at which a match can start or end, in ascending order.
+    fn boundaries(&self, text: &str) -> Vec<usize> {
+        text.char_indices()
+            .filter(|&(_, c)| self.collator.canonical_combining_class(c) == 0)
+            .map(|(index, _)| index)
+            .chain(core::iter::once(text.len()))
+            .collect()
+    }
+}
+
+/// Returns the number of non-ignorable primary weights.
+fn primary_count(weights: &[[u16; 5]]) -> usize {
+    weights.iter().filter(|weights| weights[0] != 0).count()
+}
+
+/// An iterator over the matches of a [`StringSearch`] in a text.
+///
+/// This struct is created by [`StringSearch::find_iter()`].
+pub struct Matches<'s, 't, 'c, 'data> {
+    search: &'s StringSearch<'c, 'data>,
+    text: &'t str,
+    position: usize,
+}
+
+impl Iterator for Matches<'_, '_, '_, '_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let range = self.search.find_at(self.text, self.position)?;
+        self.position = range.end;
+        Some(range)
+    }
+}
@@ -0,0 +1,592 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use icu_collator::options::{
+    AlternateHandling, CaseFirst, CaseLevel, CollatorOptions, Numeric, Strength,
+};
+use icu_collator::{AlphabeticIndex, Bucket, Collator, CollatorError, StringSearch};
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_locid_macros::langid;
+use icu_provider::fork::MultiForkByKeyProvider;
+use icu_provider_fs::FsDataProvider;
+
+fn get_provider() -> MultiForkByKeyProvider<FsDataProvider> {
+    MultiForkByKeyProvider {
+        providers: vec![
+            FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+                .expect("Data directory should exist"),
+            FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+                .expect("Data directory should exist"),
+        ],
+    }
+}
+
+fn sorted(langid: LanguageIdentifier, words: &[&'static str]) -> Vec<&'static str> {
+    sorted_with_options(langid, CollatorOptions::default(), words)
+}
+
+fn sorted_with_options<T: Into<Locale>>(
+    locale: T,
+    options: CollatorOptions,
+    words: &[&'static str],
+) -> Vec<&'static str> {
+    let provider = get_provider();
+    let collator = Collator::try_new(locale, &provider, options).expect("Data should load");
+    let mut words = words.to_vec();
+    words.sort_by(|a, b| collator.compare(a, b));
+    words
+}
+
+fn locale(s: &str) -> Locale {
+    s.parse().expect("Valid locale")
+}
+
+#[test]
+fn test_root() {
+    // Accents and case only matter if the base letters are equal, and punctuation sorts before
+    // digits and letters.
+    assert_eq!(
+        sorted(
+            langid!("und"),
+            &["b", "A", "a", "\u{E1}", "\u{C1}", "ab", "B", "Ab", "a-b", "a b", "a1", "\u{E4}"]
+        ),
+        ["a", "A", "\u{E1}", "\u{C1}", "\u{E4}", "a b", "a-b", "a1", "ab", "Ab", "b", "B"]
+    );
+}
+
+#[test]
+fn test_equivalence() {
+    let provider = get_provider();
+    let collator = Collator::try_new(langid!("und"), &provider, CollatorOptions::default())
+        .expect("Data should load");
+
+    // Canonically equivalent strings
+    assert_eq!(collator.compare("\u{E9}", "e\u{301}"), Ordering::Equal);
+    assert_eq!(collator.compare("\u{C5}", "\u{212B}"), Ordering::Equal);
+    assert_eq!(
+        collator.compare("a\u{323}\u{302}", "a\u{302}\u{323}"),
+        Ordering::Equal
+    );
+    // Hangul syllables and their jamo
+    assert_eq!(
+        collator.compare("\u{D55C}", "\u{1112}\u{1161}\u{11AB}"),
+        Ordering::Equal
+    );
+    // Ignorable characters: U+00AD SOFT HYPHEN
+    assert_eq!(collator.compare("ab", "a\u{AD}b"), Ordering::Equal);
+    assert_eq!(collator.compare("a", "b"), Ordering::Less);
+    assert_eq!(collator.compare("b", "a"), Ordering::Greater);
+}
+
+#[test]
+fn test_implicit_weights() {
+    // Tangut sorts before Han, core Han ideographs before the other ones, and unassigned code
+    // points last.
+    assert_eq!(
+        sorted(
+            langid!("und"),
+            &[
+                "\u{4E00}",
+                "\u{9FA5}",
+                "\u{20000}",
+                "\u{17000}",
+                "\u{E000}",
+                "a",
+                "\u{3400}",
+                "\u{FA0E}"
+            ]
+        ),
+        [
+            "a",
+            "\u{17000}",
+            "\u{4E00}",
+            "\u{9FA5}",
+            "\u{FA0E}",
+            "\u{3400}",
+            "\u{20000}",
+            "\u{E000}"
+        ]
+    );
+}
+
+#[test]
+fn test_tailorings() {
+    assert_eq!(
+        sorted(
+            langid!("sv"),
+            &["z", "\u{F6}", "\u{E4}", "\u{E5}", "o", "a", "\u{C4}", "\u{FC}", "y"]
+        ),
+        ["a", "o", "y", "\u{FC}", "z", "\u{E5}", "\u{E4}", "\u{C4}", "\u{F6}"]
+    );
+    assert_eq!(
+        sorted(langid!("es"), &["o", "nz", "\u{F1}", "n"]),
+        ["n", "nz", "\u{F1}", "o"]
+    );
+    assert_eq!(
+        sorted(langid!("tr"), &["\u{131}", "i", "I", "\u{130}", "h", "j"]),
+        ["h", "\u{131}", "I", "i", "\u{130}", "j"]
+    );
+    assert_eq!(
+        sorted(langid!("lt"), &["y", "i", "j", "k", "Y"]),
+        ["i", "y", "Y", "j", "k"]
+    );
+}
+
+#[test]
+fn test_discontiguous_contraction() {
+    // The Spanish "ñ" is matched across the dot below, which has a lower combining class than
+    // the tilde.
+    assert_eq!(
+        sorted(langid!("es"), &["o", "n\u{323}\u{303}", "nz", "\u{F1}"]),
+        ["nz", "\u{F1}", "n\u{323}\u{303}", "o"]
+    );
+}
+
+#[test]
+fn test_locale_fallback() {
+    // sv-SE uses the Swedish tailoring, de-AT and unsupported languages the root collation.
+    assert_eq!(sorted(langid!("sv-SE"), &["\u{E4}", "z"]), ["z", "\u{E4}"]);
+    assert_eq!(sorted(langid!("de-AT"), &["\u{E4}", "z"]), ["\u{E4}", "z"]);
+    assert_eq!(sorted(langid!("xx"), &["\u{E4}", "z"]), ["\u{E4}", "z"]);
+}
+
+#[test]
+fn test_strength() {
+    let provider = get_provider();
+    let compare = |strength, left, right| {
+        let options = CollatorOptions {
+            strength: Some(strength),
+            ..Default::default()
+        };
+        Collator::try_new(langid!("und"), &provider, options)
+            .expect("Data should load")
+            .compare(left, right)
+    };
+
+    assert_eq!(compare(Strength::Primary, "a", "\u{C1}"), Ordering::Equal);
+    assert_eq!(compare(Strength::Primary, "a", "b"), Ordering::Less);
+    assert_eq!(compare(Strength::Secondary, "a", "A"), Ordering::Equal);
+    assert_eq!(
+        compare(Strength::Secondary, "\u{E1}", "A"),
+        Ordering::Greater
+    );
+    assert_eq!(compare(Strength::Tertiary, "a", "A"), Ordering::Less);
+    // U+00AD SOFT HYPHEN is only compared on the identical level.
+    assert_eq!(
+        compare(Strength::Quaternary, "ab", "a\u{AD}b"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare(Strength::Identical, "ab", "a\u{AD}b"),
+        Ordering::Less
+    );
+    // Canonically equivalent strings are equal even on the identical level.
+    assert_eq!(
+        compare(Strength::Identical, "\u{C5}", "\u{212B}"),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_presets() {
+    let provider = get_provider();
+    let base_letters = Collator::try_new_ignoring_accents_and_case(langid!("en"), &provider)
+        .expect("Data should load");
+    assert_eq!(base_letters.compare("\u{C1}b", "ab"), Ordering::Equal);
+    assert_eq!(base_letters.compare("ab", "Ac"), Ordering::Less);
+
+    let accents =
+        Collator::try_new_ignoring_case(langid!("en"), &provider).expect("Data should load");
+    assert_eq!(accents.compare("\u{C1}b", "\u{E1}B"), Ordering::Equal);
+    assert_eq!(accents.compare("ab", "\u{C1}b"), Ordering::Less);
+
+    // The presets take precedence over the keywords of the locale.
+    let accents = Collator::try_new_ignoring_case(locale("en-u-ks-level3-kc"), &provider)
+        .expect("Data should load");
+    assert_eq!(accents.compare("ab", "AB"), Ordering::Equal);
+}
+
+#[test]
+fn test_alternate_handling() {
+    let words = ["email", "e-mail", "e mail", "Email"];
+    let mut options = CollatorOptions::default();
+    assert_eq!(
+        sorted_with_options(langid!("und"), options, &words),
+        ["e mail", "e-mail", "email", "Email"]
+    );
+    options.alternate_handling = Some(AlternateHandling::Shifted);
+    assert_eq!(
+        sorted_with_options(langid!("und"), options, &words),
+        ["email", "e-mail", "e mail", "Email"]
+    );
+    assert_eq!(
+        sorted_with_options(
+            locale("und-u-ka-shifted-ks-level4"),
+            Default::default(),
+            &words
+        ),
+        ["e mail", "e-mail", "email", "Email"]
+    );
+    // Punctuation is ignored by default in Thai.
+    assert_eq!(
+        sorted(
+            langid!("th"),
+            &["\u{E01} \u{E04}", "\u{E01}\u{E02}", "\u{E01}-\u{E02}"]
+        ),
+        ["\u{E01}\u{E02}", "\u{E01}-\u{E02}", "\u{E01} \u{E04}"]
+    );
+}
+
+#[test]
+fn test_case_first() {
+    let words = ["b", "B", "a", "A"];
+    assert_eq!(sorted(langid!("und"), &words), ["a", "A", "b", "B"]);
+    assert_eq!(
+        sorted_with_options(locale("und-u-kf-upper"), Default::default(), &words),
+        ["A", "a", "B", "b"]
+    );
+    // Uppercase letters sort first by default in Danish, unless the options say otherwise.
+    assert_eq!(sorted(langid!("da"), &words), ["A", "a", "B", "b"]);
+    let options = CollatorOptions {
+        case_first: Some(CaseFirst::Off),
+        ..Default::default()
+    };
+    assert_eq!(
+        sorted_with_options(locale("da-u-kf-upper"), options, &words),
+        ["a", "A", "b", "B"]
+    );
+}
+
+#[test]
+fn test_case_level() {
+    let provider = get_provider();
+    let options = CollatorOptions {
+        strength: Some(Strength::Primary),
+        case_level: Some(CaseLevel::On),
+        ..Default::default()
+    };
+    let collator = Collator::try_new(langid!("und"), &provider, options).expect("Data should load");
+
+    // Accents are ignored, but case is not.
+    assert_eq!(collator.compare("a", "\u{E1}"), Ordering::Equal);
+    assert_eq!(collator.compare("a", "A"), Ordering::Less);
+    assert_eq!(collator.compare("\u{E1}", "A"), Ordering::Less);
+    assert_eq!(collator.compare("A", "b"), Ordering::Less);
+
+    let collator = Collator::try_new(
+        locale("und-u-kc-ks-level1-kf-upper"),
+        &provider,
+        Default::default(),
+    )
+    .expect("Data should load");
+    assert_eq!(collator.compare("a", "\u{E1}"), Ordering::Equal);
+    assert_eq!(collator.compare("a", "A"), Ordering::Greater);
+}
+
+#[test]
+fn test_backward_second_level() {
+    let words = ["c\u{F4}t\u{E9}", "cot\u{E9}", "c\u{F4}te", "cote"];
+    assert_eq!(
+        sorted(langid!("fr"), &words),
+        ["cote", "cot\u{E9}", "c\u{F4}te", "c\u{F4}t\u{E9}"]
+    );
+    assert_eq!(
+        sorted(langid!("fr-CA"), &words),
+        ["cote", "c\u{F4}te", "cot\u{E9}", "c\u{F4}t\u{E9}"]
+    );
+}
+
+#[test]
+fn test_collation_type() {
+    let words = ["d", "cz", "ch", "ca"];
+    assert_eq!(sorted(langid!("es"), &words), ["ca", "ch", "cz", "d"]);
+    assert_eq!(
+        sorted_with_options(locale("es-u-co-trad"), Default::default(), &words),
+        ["ca", "cz", "ch", "d"]
+    );
+    // Unsupported collation types are ignored.
+    assert_eq!(
+        sorted_with_options(locale("es-u-co-standard"), Default::default(), &words),
+        ["ca", "ch", "cz", "d"]
+    );
+
+    let words = ["\u{E4}z", "af", "ae"];
+    assert_eq!(sorted(langid!("de"), &words), ["ae", "af", "\u{E4}z"]);
+    assert_eq!(
+        sorted_with_options(locale("de-CH-u-co-phonebk"), Default::default(), &words),
+        ["ae", "\u{E4}z", "af"]
+    );
+}
+
+#[test]
+fn test_numeric() {
+    let numeric = CollatorOptions {
+        numeric: Some(Numeric::On),
+        ..Default::default()
+    };
+    let words = ["item10", "item9", "item", "item09a", "item100", "itema"];
+    assert_eq!(
+        sorted(langid!("en"), &words),
+        ["item", "item09a", "item10", "item100", "item9", "itema"]
+    );
+    assert_eq!(
+        sorted_with_options(langid!("en"), numeric, &words),
+        ["item", "item9", "item09a", "item10", "item100", "itema"]
+    );
+    assert_eq!(
+        sorted_with_options(locale("en-u-kn"), Default::default(), &words),
+        ["item", "item9", "item09a", "item10", "item100", "itema"]
+    );
+    // Options take precedence over keywords.
+    let options = CollatorOptions {
+        numeric: Some(Numeric::Off),
+        ..Default::default()
+    };
+    assert_eq!(
+        sorted_with_options(locale("en-u-kn-true"), options, &words),
+        ["item", "item09a", "item10", "item100", "item9", "itema"]
+    );
+
+    let collator = Collator::try_new(langid!("en"), &get_provider(), numeric)
+        .expect("Data should load successfully");
+    // Leading zeros are ignored.
+    assert_eq!(collator.compare("007", "7"), Ordering::Equal);
+    assert_eq!(collator.compare("0", "000"), Ordering::Equal);
+    // Digits of other scripts, here Arabic-Indic and Devanagari, have the same values.
+    assert_eq!(collator.compare("\u{661}\u{662}", "12"), Ordering::Equal);
+    assert_eq!(collator.compare("\u{969}", "12"), Ordering::Less);
+    // Very long numbers.
+    let long = "9".repeat(100);
+    assert_eq!(
+        collator.compare(&long, &format!("1{}", long)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_string_search() {
+    let provider = get_provider();
+    let primary = CollatorOptions {
+        strength: Some(Strength::Primary),
+        ..Default::default()
+    };
+    let collator = Collator::try_new(langid!("fr"), &provider, primary)
+        .expect("Data should load successfully");
+    let text = "Le côté de la Côte, cote 12";
+    let search = StringSearch::new(&collator, "cote");
+    let matches: Vec<&str> = search.find_iter(text).map(|range| &text[range]).collect();
+    assert_eq!(matches, ["côté", "Côte", "cote"]);
+    assert_eq!(search.find(text), Some(3..9));
+    assert_eq!(search.find("coteau"), Some(0..4));
+    assert_eq!(search.find("coat"), None);
+
+    let collator = Collator::try_new(langid!("fr"), &provider, CollatorOptions::default())
+        .expect("Data should load successfully");
+    let search = StringSearch::new(&collator, "côte");
+    let matches: Vec<&str> = search.find_iter(text).map(|range| &text[range]).collect();
+    assert_eq!(matches, Vec::<&str>::new());
+    let search = StringSearch::new(&collator, "Côte");
+    assert_eq!(search.find(text), Some(16..21));
+    // Matches are canonically equivalent, and do not end before a combining mark.
+    assert_eq!(search.find("Co\u{302}te"), Some(0..6));
+    let search = StringSearch::new(&collator, "co");
+    assert_eq!(search.find("co\u{302}te"), None);
+
+    // Ignored characters are skipped within matches, but not at their start and end.
+    let shifted = CollatorOptions {
+        alternate_handling: Some(AlternateHandling::Shifted),
+        ..Default::default()
+    };
+    let collator = Collator::try_new(langid!("en"), &provider, shifted)
+        .expect("Data should load successfully");
+    let search = StringSearch::new(&collator, "email");
+    assert_eq!(search.find("(e-mail)"), Some(1..7));
+    assert_eq!(search.find("email, "), Some(0..5));
+
+    // Patterns that are ignored entirely match nothing.
+    assert_eq!(StringSearch::new(&collator, "").find("email"), None);
+    assert_eq!(StringSearch::new(&collator, "-").find("e-mail"), None);
+}
+
+#[test]
+fn test_script_reordering() {
+    let words = ["\u{6F22}", "\u{3042}", "\u{3B1}", "a", "1", "$", "!"];
+    assert_eq!(
+        sorted(langid!("en"), &words),
+        ["!", "$", "1", "a", "\u{3B1}", "\u{3042}", "\u{6F22}"]
+    );
+    let reordered = |s| sorted_with_options(locale(s), Default::default(), &words);
+    assert_eq!(
+        reordered("en-u-kr-grek"),
+        ["!", "$", "1", "\u{3B1}", "a", "\u{3042}", "\u{6F22}"]
+    );
+    // Special groups that are not listed stay before the scripts.
+    assert_eq!(
+        reordered("en-u-kr-hani-digit"),
+        ["!", "$", "\u{6F22}", "1", "a", "\u{3B1}", "\u{3042}"]
+    );
+    assert_eq!(
+        reordered("en-u-kr-kana-others-latn-currency"),
+        ["!", "1", "\u{3042}", "\u{3B1}", "\u{6F22}", "a", "$"]
+    );
+    // Unsupported codes disable the reordering.
+    assert_eq!(
+        reordered("en-u-kr-grek-qaaa"),
+        ["!", "$", "1", "a", "\u{3B1}", "\u{3042}", "\u{6F22}"]
+    );
+
+    // Digits are reordered in the numeric collation as well.
+    let words = ["a", "10", "9"];
+    assert_eq!(
+        sorted_with_options(locale("en-u-kn-kr-latn-digit"), Default::default(), &words),
+        ["a", "9", "10"]
+    );
+}
+
+#[test]
+fn test_rules() {
+    let provider = get_provider();
+    let sorted_with_rules = |rules: &str, words: &[&'static str]| {
+        let collator = Collator::try_new_with_rules(rules, &provider, CollatorOptions::default())
+            .expect("Rules should be valid");
+        let mut words = words.to_vec();
+        words.sort_by(|a, b| collator.compare(a, b));
+        words
+    };
+
+    // Strings are tailored after all the strings that start with their reset.
+    assert_eq!(
+        sorted_with_rules(
+            "&c < \u{10D} <<< \u{10C} &h < ch <<< cH <<< Ch <<< CH",
+            &["CH", "ch", "Ch", "h", "hz", "i", "\u{10D}", "c", "\u{10C}", "cz"]
+        ),
+        ["c", "cz", "\u{10D}", "\u{10C}", "h", "hz", "ch", "Ch", "CH", "i"]
+    );
+    assert_eq!(
+        sorted_with_rules("&b < a", &["c", "a", "b", "ba"]),
+        ["b", "ba", "a", "c"]
+    );
+    // A relation to a reset goes before the strings that are tailored after it on a stronger or
+    // the same level.
+    assert_eq!(
+        sorted_with_rules("&a < x &a < y &a <<< A", &["x", "y", "A", "a", "ab"]),
+        ["a", "A", "ab", "y", "x"]
+    );
+    // Lists, ranges, quoting, and escapes
+    assert_eq!(
+        sorted_with_rules("&z <* x-y '&' \\u0021", &["!", "&", "x", "y", "z"]),
+        ["z", "x", "y", "&", "!"]
+    );
+    // Settings
+    assert_eq!(
+        sorted_with_rules("[caseFirst upper]", &["a", "A"]),
+        ["A", "a"]
+    );
+
+    let collator = Collator::try_new_with_rules("&a = b", &provider, CollatorOptions::default())
+        .expect("Rules should be valid");
+    assert_eq!(collator.compare("a", "b"), Ordering::Equal);
+
+    for (rules, offset) in [
+        ("a < b", 0),
+        ("&a <<<< b", 3),
+        ("&[before 1]a < b", 1),
+        ("&a < b / c", 7),
+        ("&a < 'b", 7),
+        ("[strength 1]", 0),
+    ] {
+        assert!(
+            matches!(
+                Collator::try_new_with_rules(rules, &provider, CollatorOptions::default()),
+                Err(CollatorError::InvalidRules(o)) if o == offset
+            ),
+            "{}",
+            rules
+        );
+    }
+}
+
+#[test]
+fn test_alphabetic_index() {
+    let provider = get_provider();
+    let index = |langid| {
+        AlphabeticIndex::try_new(langid, &provider, CollatorOptions::default())
+            .expect("Data should load")
+    };
+
+    // German falls back to the labels of the root locale.
+    let german = index(langid!("de"));
+    assert_eq!(german.buckets().count(), 28);
+    assert_eq!(german.buckets().next(), Some(Bucket::Underflow));
+    assert_eq!(german.buckets().last(), Some(Bucket::Overflow));
+    assert_eq!(german.bucket("\u{C4}rger"), Bucket::Label("A"));
+    assert_eq!(german.bucket("zzz"), Bucket::Label("Z"));
+    assert_eq!(german.bucket("123"), Bucket::Underflow);
+    assert_eq!(german.bucket("\u{6F22}\u{5B57}"), Bucket::Overflow);
+    assert_eq!(
+        index(langid!("sv")).bucket("\u{C4}rger"),
+        Bucket::Label("\u{C4}")
+    );
+
+    // Labels of more than one letter
+    let czech = index(langid!("cs"));
+    assert_eq!(
+        czech.group(["hrad", "chata", "cukr", "\u{10D}aj", "ihned"]),
+        [
+            (Bucket::Label("C"), vec!["cukr"]),
+            (Bucket::Label("\u{10C}"), vec!["\u{10D}aj"]),
+            (Bucket::Label("H"), vec!["hrad"]),
+            (Bucket::Label("CH"), vec!["chata"]),
+            (Bucket::Label("I"), vec!["ihned"]),
+        ]
+    );
+
+    // Other scripts sort before or after the labels.
+    let russian = index(langid!("ru"));
+    assert_eq!(
+        russian.bucket("\u{401}\u{43B}\u{43A}\u{430}"),
+        Bucket::Label("\u{415}")
+    );
+    assert_eq!(russian.bucket("apple"), Bucket::Underflow);
+    assert_eq!(russian.bucket("\u{5D0}"), Bucket::Overflow);
+
+    let mut english = index(langid!("en"));
+    english.add_labels(["\u{391}", "\u{3A9}", "a"]);
+    assert_eq!(english.buckets().count(), 30);
+    assert_eq!(
+        english.bucket("\u{3B2}\u{3AE}\u{3C4}\u{3B1}"),
+        Bucket::Label("\u{391}")
+    );
+    assert_eq!(
+        english.bucket("\u{3A9}\u{3BC}\u{3AD}\u{3B3}\u{3B1}"),
+        Bucket::Label("\u{3A9}")
+    );
+    assert_eq!(english.bucket("\u{416}\u{443}\u{43A}"), Bucket::Overflow);
+
+    // Chinese characters go to the buckets of their pinyin initials in Simplified Chinese.
+    let chinese = index(langid!("zh-CN"));
+    assert_eq!(
+        chinese.group([
+            "\u{9648}",
+            "\u{5317}\u{4EAC}",
+            "Beta",
+            "\u{674E}",
+            "\u{957F}\u{57CE}"
+        ]),
+        [
+            (Bucket::Label("B"), vec!["Beta", "\u{5317}\u{4EAC}"]),
+            (Bucket::Label("C"), vec!["\u{9648}"]),
+            (Bucket::Label("L"), vec!["\u{674E}"]),
+            (Bucket::Label("Z"), vec!["\u{957F}\u{57CE}"]),
+        ]
+    );
+    assert_eq!(chinese.bucket("\u{3007}"), Bucket::Label("L"));
+    // Characters without a pinyin reading, and Traditional Chinese, are not supported yet.
+    assert_eq!(chinese.bucket("\u{3400}"), Bucket::Overflow);
+    assert_eq!(
+        index(langid!("zh-TW")).bucket("\u{5317}\u{4EAC}"),
+        Bucket::Overflow
+    );
+}
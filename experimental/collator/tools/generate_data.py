@@ -0,0 +1,384 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the collation data in ../data.
+#
+# Usage: generate_data.py <allkeys.txt> <tailoring directory> <unicore directory> <cldr-misc directory>
+#
+# The root collation elements are read from allkeys.txt, the Default Unicode
+# Collation Element Table (DUCET) of the Unicode Collation Algorithm:
+# https://www.unicode.org/Public/UCA/latest/allkeys.txt
+#
+# The locale tailorings are read from the CLDR-based tailorings of the
+# Unicode::Collate::Locale Perl module, which are expressed as collation
+# elements in the same format as allkeys.txt, along with parameters such as the
+# ordering of uppercase before lowercase, and are found in its
+# Unicode/Collate/Locale directory. Their DUCET version must match the version
+# of allkeys.txt.
+#
+# The ranges of Unified_Ideograph characters, which get implicit weights, and
+# the decimal digits, which are collated by their numeric value in the numeric
+# mode, are read from the uprops TOML files that are also used for the
+# icu_properties data.
+#
+# The Script property, which determines the reordering groups of the primary
+# weights, is read from the unicore directory of the same Perl installation,
+# whose Unicode version must match the version of the uprops data.
+#
+# The index characters, which are the labels of the buckets of an
+# AlphabeticIndex, are read from the characters.json files of the cldr-misc-full
+# package of the CLDR JSON data.
+#
+# The pinyin initials, which are the labels of the buckets of Chinese characters
+# in the index of Chinese, are read from the CJK/Pinyin.pm file next to the
+# tailoring directory, which lists the characters in the order of the CLDR
+# pinyin collation, starting each letter with a noncharacter marker.
+
+import json
+import os
+import re
+import sys
+import unicodedata
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..")
+OUT_DIR = os.path.join(ROOT_DIR, "experimental", "collator", "data")
+UPROPS_DIR = os.path.join(ROOT_DIR, "provider", "testdata", "data", "uprops")
+
+# Tailorings that need more than collation elements, such as suppressed
+# contractions or CJK orderings, are not supported yet.
+UNSUPPORTED_TAILORINGS = {"cu", "ja", "ko", "mk", "sr", "zh"}
+
+# Tailorings for alternative collation types, such as the phonebook ordering,
+# with their langid and the value of their -u-co keyword.
+COLLATION_TYPES = {
+    "de_at_ph": ("de-AT", "phonebk"),
+    "de_phone": ("de", "phonebk"),
+    "es_trad": ("es", "trad"),
+    "fi_phone": ("fi", "phonebk"),
+    "si_dict": ("si", "dict"),
+    "sv_refo": ("sv", "reformed"),
+}
+
+# The collation parameters that the tailorings may set, along with the
+# corresponding fields of CollationDataV1.
+PARAMETERS = {
+    "alternate => 'shifted'": "alternate_shifted",
+    "variable => 'shifted'": "alternate_shifted",
+    "backwards => 2": "backward_second_level",
+    "upper_before_lower => 1": "upper_first",
+}
+
+# The special reordering groups, in the order of the DUCET, see
+# https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering
+SPECIAL_GROUPS = ["space", "punct", "symbol", "currency", "digit"]
+
+# Scripts that are sorted together, and that form a single reordering group
+# with all of their codes.
+SCRIPT_ALIASES = {"hira": "hrkt", "kana": "hrkt"}
+
+# The langids whose index characters are generated, with their CLDR locale. The
+# root locale has no index characters, so it gets those of English.
+INDEX_LOCALES = {
+    "cs": "cs",
+    "da": "da",
+    "el": "el",
+    "es": "es",
+    "ru": "ru",
+    "sv": "sv",
+    "und": "en",
+    "zh": "zh",
+}
+
+ELEMENT_PATTERN = re.compile(r"\[([.*])([0-9A-F]{4})\.([0-9A-F]{4})\.([0-9A-F]{4})\]")
+
+
+def encode_element(variable, primary, secondary, tertiary):
+    """Encodes a collation element as documented on CollationDataV1."""
+    assert primary <= 0xFFFF and secondary <= 0x3FF and tertiary <= 0x1F
+    return (primary << 16) | (secondary << 6) | (tertiary << 1) | int(variable == "*")
+
+
+def parse_entries(lines):
+    """Returns a map from a string to its list of encoded collation elements."""
+    entries = {}
+    for line in lines:
+        line = line.split("#", 1)[0].strip()
+        if not line or line.startswith("@"):
+            continue
+        chars, elements = line.split(";")
+        key = "".join(chr(int(cp, 16)) for cp in chars.split())
+        entries[key] = [
+            encode_element(v, int(p, 16), int(s, 16), int(t, 16))
+            for v, p, s, t in ELEMENT_PATTERN.findall(elements)
+        ]
+    return entries
+
+
+def parse_parameters(source):
+    """Returns the CollationDataV1 fields for the parameters of a tailoring,
+    whose entry has been removed from its source."""
+    parameters = {field: False for field in PARAMETERS.values()}
+    for name, value in re.findall(r"^\s*(\w+) => (.*?),?$", source, re.M):
+        if name not in ("entry", "locale_version"):
+            parameters[PARAMETERS[name + " => " + value]] = True
+    return parameters
+
+
+def to_data_struct(entries, parameters):
+    mappings = {}
+    contractions = {}
+    elements = []
+    for key, key_elements in sorted(entries.items()):
+        assert len(key_elements) < 0x100
+        value = (len(elements) << 8) | len(key_elements)
+        if len(key) == 1:
+            mappings[key] = value
+        else:
+            contractions[key] = value
+        elements.extend(key_elements)
+    return dict(
+        mappings=mappings, contractions=contractions, elements=elements, **parameters
+    )
+
+
+def read_unified_ideographs():
+    with open(os.path.join(UPROPS_DIR, "UIdeo.toml")) as f:
+        ranges = re.findall(r"\[(0x[0-9a-f]+), (0x[0-9a-f]+)\]", f.read())
+    return [(int(start, 16), int(end, 16)) for start, end in ranges]
+
+
+def decimal_digit_zeros():
+    """Returns the digits zero of the decimal digits (General_Category=Nd),
+    which always come in contiguous runs from zero to nine."""
+    with open(os.path.join(UPROPS_DIR, "gc.toml")) as f:
+        ranges = re.findall(
+            r'\{a=(0x[0-9a-f]+), b=(0x[0-9a-f]+), v=\d+, name="Nd"\}', f.read()
+        )
+    zeros = []
+    for start, end in ranges:
+        start, end = int(start, 16), int(end, 16)
+        assert (end - start + 1) % 10 == 0
+        zeros.extend(range(start, end + 1, 10))
+    return zeros
+
+
+def han_implicit_weights():
+    """Returns the (first, last, base) triples of the Unified_Ideograph ranges,
+    see https://www.unicode.org/reports/tr10/#Implicit_Weights"""
+    result = []
+    for first, last in read_unified_ideographs():
+        # The CJK Unified Ideographs and CJK Compatibility Ideographs blocks
+        # get the base weight FB40, the other ideographs FB80.
+        for block_first, block_last in [(0x4E00, 0x9FFF), (0xF900, 0xFAFF)]:
+            if first >= block_first and last <= block_last:
+                result.append((first, last, 0xFB40))
+                break
+        else:
+            result.append((first, last, 0xFB80))
+    return result
+
+
+def read_scripts(unicore_dir):
+    """Returns the script codes of the code points, and the additional codes of
+    the scripts that are sorted with them."""
+    with open(os.path.join(unicore_dir, "UCD.pl")) as f:
+        # 'sc=latin' => 'latn',
+        codes = dict(re.findall(r"^'sc=(\w+)' => '(\w+)',$", f.read(), re.M))
+    with open(os.path.join(unicore_dir, "To", "Sc.pl")) as f:
+        table = f.read().split("return <<'END';\n")[1].split("\nEND")[0]
+    scripts = {}
+    for line in table.splitlines():
+        # 41\t5A\tLatin, with an empty end for single code points
+        start, end, name = line.split("\t")
+        code = codes[name.lower().replace("_", "")]
+        for cp in range(int(start, 16), int(end or start, 16) + 1):
+            scripts[cp] = SCRIPT_ALIASES.get(code, code)
+    return scripts
+
+
+def reordering_groups(root_entries, unicore_dir, implicit_bases):
+    """Returns the ReorderingGroupsV1 struct of the root collation.
+
+    The groups are contiguous ranges of primary weights, so each group starts
+    at the lowest primary weight of the characters that belong to it, and
+    ends where the next group starts. The characters that determine the start
+    of a script are its letters, except for the modifier letters, since some
+    of them are sorted with the symbols.
+    """
+    scripts = read_scripts(unicore_dir)
+    starts = {}
+
+    def update(group, primary):
+        starts[group] = min(starts.get(group, primary), primary)
+
+    for key, elements in root_entries.items():
+        if len(key) != 1 or len(elements) != 1 or elements[0] >> 16 == 0:
+            continue
+        primary = elements[0] >> 16
+        category = unicodedata.category(key)
+        script = scripts.get(ord(key), "zzzz")
+        if elements[0] & 1:
+            # Variable collation elements are whitespace, punctuation, and
+            # symbols.
+            if category[0] == "Z" or category == "Cc":
+                update("space", primary)
+            elif category[0] == "P":
+                update("punct", primary)
+            else:
+                update("symbol", primary)
+        elif category == "Sc":
+            update("currency", primary)
+        elif category == "Nd":
+            update("digit", primary)
+        elif category in ("Lu", "Ll", "Lt", "Lo") and script not in ("zyyy", "zinh"):
+            update(script, primary)
+    # The scripts whose characters get implicit weights start at their base
+    # weights. Han is the last one, and ends at the base weight of unassigned
+    # code points.
+    for script, base in implicit_bases.items():
+        starts[script] = base
+    end = 0xFBC0
+
+    groups = sorted(starts, key=lambda group: starts[group])
+    assert groups[: len(SPECIAL_GROUPS)] == SPECIAL_GROUPS
+    codes = {group: index for index, group in enumerate(groups)}
+    for alias, code in SCRIPT_ALIASES.items():
+        codes[alias] = codes[code]
+    return {
+        "starts": [starts[group] for group in groups] + [end],
+        "codes": dict(sorted(codes.items())),
+    }
+
+
+def index_characters(cldr_misc_dir, locale):
+    """Returns the index characters of a locale, which are listed in the order
+    of its collation, such as "[A B C Č D E F G H {CH} I …]", where strings of
+    more than one character are in braces."""
+    with open(os.path.join(cldr_misc_dir, "main", locale, "characters.json")) as f:
+        index = json.load(f)["main"][locale]["characters"]["index"]
+    return [
+        string or char
+        for string, char in re.findall(r"\{([^}]*)\}|(\S)", index[1:-1])
+    ]
+
+
+def pinyin_initials(tailorings_dir):
+    """Returns the PinyinInitialsV1 struct of the characters in CJK/Pinyin.pm,
+    whose data lists the characters of each letter after the marker FDD0-00XX,
+    where XX is the letter in hexadecimal."""
+    with open(os.path.join(tailorings_dir, "..", "CJK", "Pinyin.pm")) as f:
+        source = f.read()
+    data = source.split("__DATA__\n", 1)[1].split("__END__", 1)[0]
+    initials = {}
+    letter = None
+    for token in data.split():
+        if token.startswith("FDD0-"):
+            letter = chr(int(token[len("FDD0-") :], 16))
+        else:
+            assert letter is not None, token
+            initials.setdefault(int(token, 16), letter)
+    first = min(initials)
+    last = max(initials)
+    return {
+        "first": first,
+        "initials": "".join(initials.get(c, " ") for c in range(first, last + 1)),
+    }
+
+
+def write_json(path, data):
+    path = os.path.join(OUT_DIR, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        json.dump(data, f, indent=2)
+        f.write("\n")
+
+
+def main(allkeys_path, tailorings_dir, unicore_dir, cldr_misc_dir):
+    with open(allkeys_path) as f:
+        lines = f.readlines()
+
+    version = next(line.split()[1] for line in lines if line.startswith("@version"))
+    range_implicit_weights = []
+    for line in lines:
+        # @implicitweights 17000..18AFF; FB00 # Tangut and Tangut Components
+        m = re.match(r"@implicitweights ([0-9A-F]+)\.\.([0-9A-F]+); ([0-9A-F]+)", line)
+        if m:
+            range_implicit_weights.append(tuple(int(g, 16) for g in m.groups()))
+
+    implicit_weights = {
+        "han": [x for r in han_implicit_weights() for x in r],
+        "ranges": [x for r in sorted(range_implicit_weights) for x in r],
+    }
+
+    os.makedirs(OUT_DIR, exist_ok=True)
+    with open(os.path.join(OUT_DIR, "manifest.json"), "w") as f:
+        json.dump(
+            {"aliasing": "NoAliases", "syntax": "Json", "schema_version": "0.3"},
+            f,
+            indent=2,
+        )
+        f.write("\n")
+    root_parameters = {field: False for field in PARAMETERS.values()}
+    root_entries = parse_entries(lines)
+    write_json(
+        os.path.join("collator", "root@1.json"),
+        to_data_struct(root_entries, root_parameters),
+    )
+    write_json(os.path.join("collator", "implicit@1.json"), implicit_weights)
+    write_json(
+        os.path.join("collator", "digits@1.json"), {"zeros": decimal_digit_zeros()}
+    )
+    # Tangut, Nushu, and Khitan Small Script, then Han.
+    implicit_bases = {"tang": 0xFB00, "nshu": 0xFB01, "kits": 0xFB02, "hani": 0xFB40}
+    write_json(
+        os.path.join("collator", "reordering@1.json"),
+        reordering_groups(root_entries, unicore_dir, implicit_bases),
+    )
+
+    for file_name in sorted(os.listdir(tailorings_dir)):
+        name = file_name[: -len(".pl")]
+        if name.split("_")[0] in UNSUPPORTED_TAILORINGS:
+            continue
+        with open(os.path.join(tailorings_dir, file_name)) as f:
+            source = f.read()
+        entries = {}
+        m = re.search(r"entry => <<'ENTRY', # for DUCET v([0-9.]+)\n(.*?)\nENTRY", source, re.S)
+        # Some tailorings only set parameters.
+        if m:
+            assert m.group(1) == version, file_name
+            entries = parse_entries(m.group(2).splitlines())
+            # Remove the entry so that only the parameters are left.
+            source = source.replace(m.group(0), "entry => ''")
+
+        if name in COLLATION_TYPES:
+            langid, collation_type = COLLATION_TYPES[name]
+            path = os.path.join(collation_type, langid)
+        else:
+            # fr_ca -> fr-CA, ug_cyrl -> ug-Cyrl
+            subtags = name.split("_")
+            path = "-".join(
+                [subtags[0]]
+                + [s.upper() if len(s) == 2 else s.title() for s in subtags[1:]]
+            )
+        write_json(
+            os.path.join("collator", "tailoring@1", path + ".json"),
+            to_data_struct(entries, parse_parameters(source)),
+        )
+
+    for langid, locale in INDEX_LOCALES.items():
+        write_json(
+            os.path.join("collator", "index@1", langid + ".json"),
+            {"labels": index_characters(cldr_misc_dir, locale)},
+        )
+    write_json(
+        os.path.join("collator", "pinyin@1", "zh.json"), pinyin_initials(tailorings_dir)
+    )
+
+    print("Generated collation data for DUCET", version)
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
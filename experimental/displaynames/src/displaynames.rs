@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Types for looking up display names of individual subtags.
+
+use crate::error::Error;
+use crate::options::*;
+use crate::provider::*;
+use icu_locid::subtags::{Region, Script};
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+
+fn load_display_names<'data, D>(
+    key: ResourceKey,
+    locale: Locale,
+    data_provider: &D,
+) -> Result<DataPayload<'data, DisplayNamesV1Marker>, Error>
+where
+    D: DataProvider<'data, DisplayNamesV1Marker> + ?Sized,
+{
+    Ok(data_provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(locale.into()),
+                },
+            },
+        })?
+        .take_payload()?)
+}
+
+/// Lookup of localized display names for regions, such as "Germany" for `DE`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::provider::DisplayNamesV1;
+/// use icu_displaynames::RegionDisplayNames;
+/// use icu_locid_macros::{langid, region};
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+///
+/// let mut data = DisplayNamesV1::default();
+/// data.names.insert("DE".into(), "Germany".into());
+/// data.names.insert("419".into(), "Latin America".into());
+/// let provider = StructProvider {
+///     key: icu_displaynames::provider::key::TERRITORIES_V1,
+///     data: DataPayload::from_owned(data),
+/// };
+///
+/// let display_names = RegionDisplayNames::try_new(langid!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(display_names.of(region!("DE")), Some("Germany"));
+/// assert_eq!(display_names.of(region!("419")), Some("Latin America"));
+/// assert_eq!(display_names.of(region!("FR")), None);
+/// ```
+pub struct RegionDisplayNames<'data> {
+    options: DisplayNamesOptions,
+    region_data: DataPayload<'data, DisplayNamesV1Marker>,
+}
+
+impl<'data> RegionDisplayNames<'data> {
+    /// Creates a new [`RegionDisplayNames`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D: DataProvider<'data, DisplayNamesV1Marker> + ?Sized>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, Error> {
+        let region_data = load_display_names(key::TERRITORIES_V1, locale.into(), data_provider)?;
+        Ok(Self {
+            options,
+            region_data,
+        })
+    }
+
+    /// Returns the display name of a region, or [`None`] if the data has no name for it.
+    pub fn of(&self, region: Region) -> Option<&str> {
+        let data = self.region_data.get();
+        match self.options.style {
+            Style::Long => data.get_name(region.as_str()),
+            Style::Short => data.get_short_name(region.as_str()),
+        }
+    }
+}
+
+/// Lookup of localized display names for scripts, such as "Traditional Han" for `Hant`.
+///
+/// The names returned by this type are the stand-alone forms. Within a locale display name,
+/// such as "Chinese (Traditional)", a shorter form may be used instead.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::provider::DisplayNamesV1;
+/// use icu_displaynames::ScriptDisplayNames;
+/// use icu_locid_macros::{langid, script};
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+///
+/// let mut data = DisplayNamesV1::default();
+/// data.names.insert("Hant".into(), "Traditional".into());
+/// data.stand_alone_names.insert("Hant".into(), "Traditional Han".into());
+/// data.names.insert("Latn".into(), "Latin".into());
+/// let provider = StructProvider {
+///     key: icu_displaynames::provider::key::SCRIPTS_V1,
+///     data: DataPayload::from_owned(data),
+/// };
+///
+/// let display_names = ScriptDisplayNames::try_new(langid!("en"), &provider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(display_names.of(script!("Hant")), Some("Traditional Han"));
+/// assert_eq!(display_names.of(script!("Latn")), Some("Latin"));
+/// ```
+pub struct ScriptDisplayNames<'data> {
+    options: DisplayNamesOptions,
+    script_data: DataPayload<'data, DisplayNamesV1Marker>,
+}
+
+impl<'data> ScriptDisplayNames<'data> {
+    /// Creates a new [`ScriptDisplayNames`] from locale data and an options bag.
+    pub fn try_new<T: Into<Locale>, D: DataProvider<'data, DisplayNamesV1Marker> + ?Sized>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, Error> {
+        let script_data = load_display_names(key::SCRIPTS_V1, locale.into(), data_provider)?;
+        Ok(Self {
+            options,
+            script_data,
+        })
+    }
+
+    /// Returns the display name of a script, or [`None`] if the data has no name for it.
+    pub fn of(&self, script: Script) -> Option<&str> {
+        let data = self.script_data.get();
+        match self.options.style {
+            Style::Long => data.get_stand_alone_name(script.as_str()),
+            Style::Short => data.get_short_name(script.as_str()),
+        }
+    }
+}
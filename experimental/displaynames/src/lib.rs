@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_displaynames`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API provides localized display names for the subtags of a locale identifier, such as
+//! "Germany" for the region `DE` and "Traditional Han" for the script `Hant`, based on the
+//! `localeDisplayNames` data in [`CLDR`].
+//!
+//! Region and script names share a single data struct,
+//! [`DisplayNamesV1`](provider::DisplayNamesV1), which is served under a different
+//! [`ResourceKey`](icu_provider::ResourceKey) for each kind of subtag.
+//!
+//! Display names for full locales, such as "Chinese (Traditional, Taiwan, Buddhist Calendar)"
+//! for `zh-Hant-TW-u-ca-buddhist`, are composed by [`LocaleDisplayNamesFormatter`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_displaynames::provider::DisplayNamesV1;
+//! use icu_displaynames::RegionDisplayNames;
+//! use icu_locid_macros::{langid, region};
+//! use icu_provider::prelude::*;
+//! use icu_provider::struct_provider::StructProvider;
+//!
+//! let mut data = DisplayNamesV1::default();
+//! data.names.insert("DE".into(), "Deutschland".into());
+//! let provider = StructProvider {
+//!     key: icu_displaynames::provider::key::TERRITORIES_V1,
+//!     data: DataPayload::from_owned(data),
+//! };
+//!
+//! let display_names = RegionDisplayNames::try_new(langid!("de"), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(display_names.of(region!("DE")), Some("Deutschland"));
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`CLDR`]: http://cldr.unicode.org/
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod displaynames;
+pub mod error;
+mod locale_display_names;
+pub mod options;
+pub mod provider;
+
+pub use displaynames::{RegionDisplayNames, ScriptDisplayNames};
+pub use error::Error as DisplayNamesError;
+pub use locale_display_names::LocaleDisplayNamesFormatter;
@@ -0,0 +1,321 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Composition of display names for full locales.
+
+use crate::error::Error;
+use crate::options::*;
+use crate::provider::*;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+
+/// Substitutes `{0}` and `{1}` in a CLDR display pattern.
+fn interpolate(pattern: &str, first: &str, second: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + first.len() + second.len());
+    let mut rest = pattern;
+    while let Some(idx) = rest.find('{') {
+        result.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        if let Some(tail) = rest.strip_prefix("{0}") {
+            result.push_str(first);
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("{1}") {
+            result.push_str(second);
+            rest = tail;
+        } else {
+            result.push('{');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// A formatter for the display name of a full locale, such as
+/// "Chinese (Traditional, Taiwan, Buddhist Calendar)" for `zh-Hant-TW-u-ca-buddhist`.
+///
+/// The display name is composed according to the
+/// [Locale Display Name Algorithm](https://unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm)
+/// of UTS #35:
+///
+/// 1. The longest available language name is selected among `language-script-region`,
+///    `language-script`, `language-region`, and `language`. For example, `en-GB` may be
+///    displayed as "British English".
+/// 2. The subtags not covered by the language name, followed by the variants and the Unicode
+///    extension keywords, are displayed as qualifiers in parentheses.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::provider::*;
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::Locale;
+/// use icu_locid_macros::langid;
+/// use icu_provider::prelude::*;
+///
+/// struct DemoProvider;
+///
+/// impl DataProvider<'static, DisplayNamesV1Marker> for DemoProvider {
+///     fn load_payload(&self, req: &DataRequest)
+///             -> Result<DataResponse<'static, DisplayNamesV1Marker>, DataError> {
+///         let mut data = DisplayNamesV1::default();
+///         match req.resource_path.key {
+///             key::LANGUAGES_V1 => {
+///                 data.names.insert("zh".into(), "Chinese".into());
+///             }
+///             key::SCRIPTS_V1 => {
+///                 data.names.insert("Hant".into(), "Traditional".into());
+///                 data.stand_alone_names.insert("Hant".into(), "Traditional Han".into());
+///             }
+///             key::TERRITORIES_V1 => {
+///                 data.names.insert("TW".into(), "Taiwan".into());
+///             }
+///             key::VARIANTS_V1 => (),
+///             _ => return Err(DataError::MissingResourceKey(req.resource_path.key)),
+///         }
+///         Ok(DataResponse {
+///             metadata: Default::default(),
+///             payload: Some(DataPayload::from_owned(data)),
+///         })
+///     }
+/// }
+///
+/// impl DataProvider<'static, LocaleDisplayPatternsV1Marker> for DemoProvider {
+///     fn load_payload(&self, req: &DataRequest)
+///             -> Result<DataResponse<'static, LocaleDisplayPatternsV1Marker>, DataError> {
+///         let mut data = LocaleDisplayPatternsV1 {
+///             locale_pattern: "{0} ({1})".into(),
+///             locale_separator: "{0}, {1}".into(),
+///             locale_key_type_pattern: "{0}: {1}".into(),
+///             ..Default::default()
+///         };
+///         data.type_names.insert("ca-buddhist".into(), "Buddhist Calendar".into());
+///         Ok(DataResponse {
+///             metadata: Default::default(),
+///             payload: Some(DataPayload::from_owned(data)),
+///         })
+///     }
+/// }
+///
+/// let formatter = LocaleDisplayNamesFormatter::try_new(langid!("en"), &DemoProvider, Default::default())
+///     .expect("Data should load successfully");
+///
+/// let locale: Locale = "zh-Hant-TW-u-ca-buddhist".parse().unwrap();
+/// assert_eq!(formatter.of(&locale), "Chinese (Traditional, Taiwan, Buddhist Calendar)");
+///
+/// let locale: Locale = "zh".parse().unwrap();
+/// assert_eq!(formatter.of(&locale), "Chinese");
+/// ```
+pub struct LocaleDisplayNamesFormatter<'data> {
+    options: DisplayNamesOptions,
+    language_data: DataPayload<'data, DisplayNamesV1Marker>,
+    script_data: DataPayload<'data, DisplayNamesV1Marker>,
+    region_data: DataPayload<'data, DisplayNamesV1Marker>,
+    variant_data: DataPayload<'data, DisplayNamesV1Marker>,
+    patterns: DataPayload<'data, LocaleDisplayPatternsV1Marker>,
+}
+
+impl<'data> LocaleDisplayNamesFormatter<'data> {
+    /// Creates a new [`LocaleDisplayNamesFormatter`] from locale data and an options bag.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DisplayNamesV1Marker>
+            + DataProvider<'data, LocaleDisplayPatternsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let request = |key| DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(locale.id.clone()),
+                },
+            },
+        };
+        let load = |key| -> Result<DataPayload<'data, DisplayNamesV1Marker>, Error> {
+            Ok(data_provider.load_payload(&request(key))?.take_payload()?)
+        };
+        let language_data = load(key::LANGUAGES_V1)?;
+        let script_data = load(key::SCRIPTS_V1)?;
+        let region_data = load(key::TERRITORIES_V1)?;
+        let variant_data = load(key::VARIANTS_V1)?;
+        let patterns: DataPayload<LocaleDisplayPatternsV1Marker> = data_provider
+            .load_payload(&request(key::LOCALE_PATTERNS_V1))?
+            .take_payload()?;
+        Ok(Self {
+            options,
+            language_data,
+            script_data,
+            region_data,
+            variant_data,
+            patterns,
+        })
+    }
+
+    fn get_name<'a>(&self, data: &'a DisplayNamesV1, code: &str) -> Option<&'a str> {
+        match self.options.style {
+            Style::Long => data.get_name(code),
+            Style::Short => data.get_short_name(code),
+        }
+    }
+
+    /// Returns the display name of a locale.
+    ///
+    /// Subtags without a display name in the data are displayed as their code.
+    pub fn of<'a>(&'a self, locale: &'a Locale) -> Cow<'a, str> {
+        let langid = &locale.id;
+        let language_code = langid.language.as_str();
+        let script_code = langid.script.as_ref().map(|s| s.as_str());
+        let region_code = langid.region.as_ref().map(|r| r.as_str());
+
+        // Step 1: Find the longest matching language name.
+        let language_data = self.language_data.get();
+        let mut candidates: Vec<(String, bool, bool)> = Vec::with_capacity(3);
+        if let (Some(script), Some(region)) = (script_code, region_code) {
+            candidates.push((
+                format!("{}-{}-{}", language_code, script, region),
+                true,
+                true,
+            ));
+        }
+        if let Some(script) = script_code {
+            candidates.push((format!("{}-{}", language_code, script), true, false));
+        }
+        if let Some(region) = region_code {
+            candidates.push((format!("{}-{}", language_code, region), false, true));
+        }
+        let (language_name, script_used, region_used) = candidates
+            .iter()
+            .find_map(|(code, script_used, region_used)| {
+                self.get_name(language_data, code)
+                    .map(|name| (name, *script_used, *region_used))
+            })
+            .unwrap_or_else(|| {
+                (
+                    self.get_name(language_data, language_code)
+                        .unwrap_or(language_code),
+                    false,
+                    false,
+                )
+            });
+
+        // Step 2: Collect the qualifiers.
+        let mut qualifiers: Vec<Cow<str>> = Vec::new();
+        if let (Some(script), false) = (script_code, script_used) {
+            let name = self.get_name(self.script_data.get(), script);
+            qualifiers.push(Cow::Borrowed(name.unwrap_or(script)));
+        }
+        if let (Some(region), false) = (region_code, region_used) {
+            let name = self.get_name(self.region_data.get(), region);
+            qualifiers.push(Cow::Borrowed(name.unwrap_or(region)));
+        }
+        for variant in langid.variants.iter() {
+            let code = variant.as_str();
+            match self.get_name(self.variant_data.get(), code) {
+                Some(name) => qualifiers.push(Cow::Borrowed(name)),
+                None => qualifiers.push(Cow::Owned(code.to_ascii_uppercase())),
+            }
+        }
+        let patterns = self.patterns.get();
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            let key = key.as_str();
+            let mut value = value.to_string();
+            // A keyword without a value, such as `-u-kn`, has the value "true" (UTS #35).
+            if value.is_empty() {
+                value.push_str("true");
+            }
+            let type_code = format!("{}-{}", key, value);
+            let name = match patterns.type_names.get(type_code.as_str()) {
+                Some(name) => name.to_string(),
+                None => {
+                    let key_name = patterns.key_names.get(key).map(|s| &**s).unwrap_or(key);
+                    interpolate(&patterns.locale_key_type_pattern, key_name, &value)
+                }
+            };
+            qualifiers.push(Cow::Owned(name));
+        }
+
+        // Step 3: Compose the final display name.
+        let mut iter = qualifiers.into_iter();
+        let first = match iter.next() {
+            Some(first) => first,
+            None => return Cow::Borrowed(language_name),
+        };
+        let joined = iter.fold(first, |acc, qualifier| {
+            Cow::Owned(interpolate(&patterns.locale_separator, &acc, &qualifier))
+        });
+        Cow::Owned(interpolate(
+            &patterns.locale_pattern,
+            language_name,
+            &joined,
+        ))
+    }
+}
+
+#[test]
+fn test_interpolate() {
+    assert_eq!(interpolate("{0} ({1})", "a", "b"), "a (b)");
+    assert_eq!(interpolate("{1}、{0}", "a", "b"), "b、a");
+    assert_eq!(interpolate("{0} {x}", "{1}", "b"), "{1} {x}");
+}
+
+#[test]
+fn test_keyword_without_value() {
+    use icu_locid_macros::langid;
+
+    struct TestProvider;
+
+    impl DataProvider<'static, DisplayNamesV1Marker> for TestProvider {
+        fn load_payload(
+            &self,
+            _req: &DataRequest,
+        ) -> Result<DataResponse<'static, DisplayNamesV1Marker>, DataError> {
+            let mut data = DisplayNamesV1::default();
+            data.names.insert("de".into(), "German".into());
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(DataPayload::from_owned(data)),
+            })
+        }
+    }
+
+    impl DataProvider<'static, LocaleDisplayPatternsV1Marker> for TestProvider {
+        fn load_payload(
+            &self,
+            _req: &DataRequest,
+        ) -> Result<DataResponse<'static, LocaleDisplayPatternsV1Marker>, DataError> {
+            let mut data = LocaleDisplayPatternsV1 {
+                locale_pattern: "{0} ({1})".into(),
+                locale_separator: "{0}, {1}".into(),
+                locale_key_type_pattern: "{0}: {1}".into(),
+                ..Default::default()
+            };
+            data.key_names.insert("kn".into(), "Numeric Sorting".into());
+            data.type_names
+                .insert("kn-true".into(), "Numeric Sorting".into());
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(DataPayload::from_owned(data)),
+            })
+        }
+    }
+
+    let formatter =
+        LocaleDisplayNamesFormatter::try_new(langid!("en"), &TestProvider, Default::default())
+            .expect("Data should load successfully");
+    let of = |locale: &str| formatter.of(&locale.parse().unwrap()).into_owned();
+    assert_eq!(of("de-u-kn"), "German (Numeric Sorting)");
+    assert_eq!(of("de-u-kn-true"), "German (Numeric Sorting)");
+    assert_eq!(of("de-u-kn-false"), "German (Numeric Sorting: false)");
+}
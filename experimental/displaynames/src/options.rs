@@ -0,0 +1,62 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for the display names types.
+
+/// A bag of options defining how display names will be selected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct DisplayNamesOptions {
+    /// The length of the display name.
+    pub style: Style,
+}
+
+/// The length of a display name.
+///
+/// Not every code has a short display name. When a short name is requested but not available,
+/// the long name is returned instead.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, Style};
+/// use icu_displaynames::provider::DisplayNamesV1;
+/// use icu_displaynames::RegionDisplayNames;
+/// use icu_locid_macros::region;
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+///
+/// let mut data = DisplayNamesV1::default();
+/// data.names.insert("GB".into(), "United Kingdom".into());
+/// data.short_names.insert("GB".into(), "UK".into());
+/// data.names.insert("DE".into(), "Germany".into());
+/// let provider = StructProvider {
+///     key: icu_displaynames::provider::key::TERRITORIES_V1,
+///     data: DataPayload::from_owned(data),
+/// };
+///
+/// let mut options = DisplayNamesOptions::default();
+/// options.style = Style::Short;
+/// let display_names = RegionDisplayNames::try_new(icu_locid_macros::langid!("en"), &provider, options)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(display_names.of(region!("GB")), Some("UK"));
+/// // No short name is available: fall back to the long name.
+/// assert_eq!(display_names.of(region!("DE")), Some("Germany"));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// The full display name, for example "United Kingdom".
+    Long,
+
+    /// An abbreviated display name, for example "UK". Corresponds to the CLDR `alt="short"`
+    /// variant.
+    Short,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Long
+    }
+}
@@ -0,0 +1,129 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+
+pub mod key {
+    //! Resource keys for [`icu_displaynames`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: display names for regions (territories).
+    pub const TERRITORIES_V1: ResourceKey = resource_key!(DisplayNames, "territories", 1);
+
+    /// Resource key: display names for scripts.
+    pub const SCRIPTS_V1: ResourceKey = resource_key!(DisplayNames, "scripts", 1);
+
+    /// Resource key: display names for languages, including language-script and
+    /// language-region combinations such as "Traditional Chinese".
+    pub const LANGUAGES_V1: ResourceKey = resource_key!(DisplayNames, "languages", 1);
+
+    /// Resource key: display names for variants.
+    pub const VARIANTS_V1: ResourceKey = resource_key!(DisplayNames, "variants", 1);
+
+    /// Resource key: patterns for composing the display name of a full locale.
+    pub const LOCALE_PATTERNS_V1: ResourceKey = resource_key!(DisplayNames, "locale_patterns", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_displaynames`](crate),
+/// grouped by data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(
+        DisplayNamesV1Marker,
+        [
+            key::TERRITORIES_V1,
+            key::SCRIPTS_V1,
+            key::LANGUAGES_V1,
+            key::VARIANTS_V1
+        ]
+    ),
+    icu_provider::registered_keys!(LocaleDisplayPatternsV1Marker, [key::LOCALE_PATTERNS_V1]),
+];
+
+/// Localized display names for one kind of code, such as region or script codes.
+///
+/// The same data struct is used for every kind of code; the [`ResourceKey`] determines which
+/// kind of code is stored. Codes are stored in their canonical string form, e.g. `"DE"`,
+/// `"419"`, `"Hant"`, or `"zh-Hant"`.
+///
+/// [`ResourceKey`]: icu_provider::ResourceKey
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DisplayNamesV1<'data> {
+    /// Mapping from a code to its default (long) display name.
+    pub names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+
+    /// Mapping from a code to its short display name, for codes that have one
+    /// (CLDR `alt="short"`).
+    pub short_names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+
+    /// Mapping from a code to the display name used when the name stands alone rather than
+    /// inside of a locale display name (CLDR `alt="stand-alone"`). For example, the script
+    /// `Hant` is "Traditional" inside of "Chinese (Traditional)" but "Traditional Han" alone.
+    pub stand_alone_names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
+
+impl<'data> DisplayNamesV1<'data> {
+    /// Gets the default display name for a code.
+    pub fn get_name(&self, code: &str) -> Option<&str> {
+        self.names.get(code).map(|s| &**s)
+    }
+
+    /// Gets the short display name for a code, falling back to the default display name.
+    pub fn get_short_name(&self, code: &str) -> Option<&str> {
+        self.short_names
+            .get(code)
+            .map(|s| &**s)
+            .or_else(|| self.get_name(code))
+    }
+
+    /// Gets the stand-alone display name for a code, falling back to the default display name.
+    pub fn get_stand_alone_name(&self, code: &str) -> Option<&str> {
+        self.stand_alone_names
+            .get(code)
+            .map(|s| &**s)
+            .or_else(|| self.get_name(code))
+    }
+}
+
+/// Patterns and Unicode extension names used to compose the display name of a full locale,
+/// such as "Chinese (Traditional, Taiwan, Buddhist Calendar)".
+///
+/// See the CLDR `localeDisplayPattern`, `keys`, and `types` elements.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct LocaleDisplayPatternsV1<'data> {
+    /// Pattern combining a language name with its qualifiers, e.g. `"{0} ({1})"`.
+    pub locale_pattern: Cow<'data, str>,
+
+    /// Pattern joining two qualifiers, e.g. `"{0}, {1}"`.
+    pub locale_separator: Cow<'data, str>,
+
+    /// Pattern combining the name of a Unicode extension key with its value when no name is
+    /// available for the combination, e.g. `"{0}: {1}"`.
+    pub locale_key_type_pattern: Cow<'data, str>,
+
+    /// Mapping from a BCP-47 Unicode extension key, such as `"ca"`, to its display name.
+    pub key_names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+
+    /// Mapping from a BCP-47 Unicode extension key and value, joined by `"-"` (for example
+    /// `"ca-buddhist"`), to its display name.
+    pub type_names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
@@ -0,0 +1,64 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+/// A duration, as an amount of each unit of time, such as 1 hour, 23 minutes, and 4 seconds.
+///
+/// The units are not balanced against each other: 90 minutes are formatted as "90 min", not as
+/// "1 hr, 30 min".
+///
+/// # Examples
+///
+/// ```
+/// use icu_durationformat::Duration;
+///
+/// let duration = Duration {
+///     hours: 1,
+///     minutes: 23,
+///     seconds: 4,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+pub struct Duration {
+    /// Whether the duration is negative, such as the time remaining after a deadline.
+    pub negative: bool,
+    /// The number of years.
+    pub years: u64,
+    /// The number of months.
+    pub months: u64,
+    /// The number of weeks.
+    pub weeks: u64,
+    /// The number of days.
+    pub days: u64,
+    /// The number of hours.
+    pub hours: u64,
+    /// The number of minutes.
+    pub minutes: u64,
+    /// The number of seconds.
+    pub seconds: u64,
+    /// The number of milliseconds.
+    pub milliseconds: u64,
+    /// The number of microseconds.
+    pub microseconds: u64,
+    /// The number of nanoseconds.
+    pub nanoseconds: u64,
+}
+
+impl Duration {
+    /// Returns the amounts of the units, from years to nanoseconds.
+    pub(crate) fn fields(&self) -> [u64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+}
@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::duration::Duration;
+use crate::error::Error;
+use crate::options::*;
+use crate::provider::*;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::options::FixedDecimalFormatOptions;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_list::provider::ListFormatterPatternsV1Marker;
+use icu_list::{ListFormatter, Type, Width};
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_plurals::{PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+// The indices of the units in `Duration::fields`.
+const HOURS: usize = 4;
+const MINUTES: usize = 5;
+const SECONDS: usize = 6;
+const MILLISECONDS: usize = 7;
+const MICROSECONDS: usize = 8;
+const NANOSECONDS: usize = 9;
+
+/// The style of a unit after applying the defaults.
+#[derive(Debug, Clone, Copy)]
+enum ResolvedStyle {
+    Unit(Width),
+    /// A number on a clock with at least the given number of digits.
+    Numeric(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ResolvedField {
+    style: ResolvedStyle,
+    always: bool,
+}
+
+/// A formatter for [`Duration`]s, such as "1 hr, 23 min, 4 sec" or "1:23:04", according to the
+/// unit patterns, clock patterns, and list patterns of a locale.
+///
+/// Units with a unit style are formatted with the patterns of their width, and units with a
+/// numeric style form a clock, such as "1:23:04.5" for 1 hour, 23 minutes, 4 seconds, and 500
+/// milliseconds. The formatted units are joined as a list of measurements with
+/// [`ListFormatter`].
+pub struct DurationFormat<'data> {
+    fields: [ResolvedField; 10],
+    units: DataPayload<'data, DurationUnitsV1Marker>,
+    list: ListFormatter<'data>,
+    plurals: PluralRules,
+    decimal: FixedDecimalFormat<'data>,
+}
+
+impl<'data> DurationFormat<'data> {
+    /// Creates a new [`DurationFormat`] from the data of the units of time, the list patterns
+    /// for measurements, the cardinal plural rules, and the decimal symbols of a locale.
+    pub fn try_new<T, DP, LP, PP, NP>(
+        locale: T,
+        duration_provider: &DP,
+        list_provider: &LP,
+        plural_provider: &PP,
+        decimal_provider: &NP,
+        options: &DurationFormatOptions,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        DP: DataProvider<'data, DurationUnitsV1Marker> + ?Sized,
+        LP: DataProvider<'data, ListFormatterPatternsV1Marker> + ?Sized,
+        PP: DataProvider<'data, PluralRuleStringsV1Marker> + ?Sized,
+        NP: DataProvider<'data, DecimalSymbolsV1Marker> + ?Sized,
+    {
+        let locale = locale.into();
+        let fields = resolve(options)?;
+        let units = duration_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::UNITS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.id.clone()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        let width = match options.style {
+            Style::Long => Width::Wide,
+            Style::Short | Style::Digital => Width::Short,
+            Style::Narrow => Width::Narrow,
+        };
+        let list = ListFormatter::try_new(locale.clone(), list_provider, Type::Unit, width)?;
+        let plurals =
+            PluralRules::try_new(locale.id.clone(), plural_provider, PluralRuleType::Cardinal)?;
+        let decimal = FixedDecimalFormat::try_new(
+            locale,
+            decimal_provider,
+            FixedDecimalFormatOptions::default(),
+        )?;
+        Ok(Self {
+            fields,
+            units,
+            list,
+            plurals,
+            decimal,
+        })
+    }
+
+    /// Formats a [`Duration`].
+    ///
+    /// Units whose amount is zero are omitted, unless their [`FieldDisplay`] is
+    /// [`FieldDisplay::Always`], which is the default for the hours, minutes, and seconds of a
+    /// clock. If all units are omitted, zero seconds are displayed.
+    pub fn format(&self, duration: &Duration) -> String {
+        let values = duration.fields();
+        // The sign is displayed on the first displayed unit
+        let mut negative = duration.negative && values.iter().any(|&value| value != 0);
+        let clock_start = self
+            .fields
+            .iter()
+            .position(|field| matches!(field.style, ResolvedStyle::Numeric(_)));
+
+        let mut elements = Vec::new();
+        for (index, (field, &value)) in self
+            .fields
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .take(clock_start.unwrap_or(values.len()))
+        {
+            if let ResolvedStyle::Unit(width) = field.style {
+                if value != 0 || field.always {
+                    elements.push(self.format_unit(index, width, value, &mut negative));
+                }
+            }
+        }
+        if let Some(clock_start) = clock_start {
+            if let Some(clock) = self.format_clock(clock_start, &values, &mut negative) {
+                elements.push(clock);
+            }
+        }
+        if elements.is_empty() {
+            elements.push(match self.fields[SECONDS].style {
+                ResolvedStyle::Unit(width) => self.format_unit(SECONDS, width, 0, &mut negative),
+                ResolvedStyle::Numeric(digits) => self.format_number(0, digits, 0, &mut negative),
+            });
+        }
+        self.list
+            .format(&elements.iter().map(String::as_str).collect::<Vec<_>>())
+            .write_to_string()
+    }
+
+    /// Formats the amount of a unit with the pattern of its plural category.
+    fn format_unit(&self, index: usize, width: Width, value: u64, negative: &mut bool) -> String {
+        let mut decimal = FixedDecimal::from(value);
+        if core::mem::take(negative) {
+            decimal.negate();
+        }
+        let units = self.units.get();
+        let patterns = match width {
+            Width::Wide => &units.long,
+            Width::Short => &units.short,
+            Width::Narrow => &units.narrow,
+        };
+        let pattern = patterns.fields()[index].get(self.plurals.select(&decimal));
+        pattern.replace("{0}", &self.decimal.format(&decimal).write_to_string())
+    }
+
+    /// Formats the numeric units, which start at hours, minutes, or seconds, as a clock, or
+    /// returns `None` if none of them are displayed.
+    fn format_clock(
+        &self,
+        start: usize,
+        values: &[u64; 10],
+        negative: &mut bool,
+    ) -> Option<String> {
+        // Fractions of a second are always numeric if the seconds are, so they are carried over
+        // into the seconds
+        let nanoseconds = u128::from(values[MILLISECONDS]) * 1_000_000
+            + u128::from(values[MICROSECONDS]) * 1_000
+            + u128::from(values[NANOSECONDS]);
+        let seconds = u128::from(values[SECONDS]) + nanoseconds / 1_000_000_000;
+        let fraction = (nanoseconds % 1_000_000_000) as u32;
+
+        let show_seconds = seconds != 0 || fraction != 0 || self.fields[SECONDS].always;
+        let show_hours = start <= HOURS && (values[HOURS] != 0 || self.fields[HOURS].always);
+        let show_minutes = start <= MINUTES
+            && (values[MINUTES] != 0 || self.fields[MINUTES].always || show_hours && show_seconds);
+
+        let units = self.units.get();
+        let pattern = match (show_hours, show_minutes, show_seconds) {
+            (true, true, true) => &*units.hms,
+            (true, true, false) => &*units.hm,
+            (false, true, true) => &*units.ms,
+            (true, false, false) => "h",
+            (false, true, false) => "m",
+            (false, false, true) => "s",
+            _ => return None,
+        };
+
+        let mut clock = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            let (index, value, fraction) = match c {
+                'h' => (HOURS, u128::from(values[HOURS]), 0),
+                'm' => (MINUTES, u128::from(values[MINUTES]), 0),
+                's' => (SECONDS, seconds, fraction),
+                _ => {
+                    clock.push(c);
+                    continue;
+                }
+            };
+            let mut digits = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                digits += 1;
+            }
+            if let ResolvedStyle::Numeric(min_digits) = self.fields[index].style {
+                digits = digits.max(min_digits);
+            }
+            clock.push_str(&self.format_number(value, digits, fraction, negative));
+        }
+        Some(clock)
+    }
+
+    /// Formats a number on a clock with at least the given number of integer digits and the
+    /// given nanoseconds as decimal digits.
+    fn format_number(
+        &self,
+        value: u128,
+        digits: usize,
+        nanoseconds: u32,
+        negative: &mut bool,
+    ) -> String {
+        let mut number = format!("{:01$}", value, digits);
+        if nanoseconds != 0 {
+            number.push('.');
+            number.push_str(format!("{:09}", nanoseconds).trim_end_matches('0'));
+        }
+        let mut decimal: FixedDecimal = number
+            .parse()
+            .expect("Formatted integers are valid decimals");
+        if core::mem::take(negative) {
+            decimal.negate();
+        }
+        self.decimal.format(&decimal).write_to_string()
+    }
+}
+
+/// Applies the defaults of the options to each unit, from years to nanoseconds.
+fn resolve(options: &DurationFormatOptions) -> Result<[ResolvedField; 10], Error> {
+    let field_options = [
+        options.years,
+        options.months,
+        options.weeks,
+        options.days,
+        options.hours,
+        options.minutes,
+        options.seconds,
+        options.milliseconds,
+        options.microseconds,
+        options.nanoseconds,
+    ];
+    let mut fields = [ResolvedField {
+        style: ResolvedStyle::Numeric(1),
+        always: false,
+    }; 10];
+    let mut previous_numeric = false;
+    for (index, (field, field_options)) in fields.iter_mut().zip(field_options.iter()).enumerate() {
+        let style = match field_options.style {
+            Some(FieldStyle::Long) => ResolvedStyle::Unit(Width::Wide),
+            Some(FieldStyle::Short) => ResolvedStyle::Unit(Width::Short),
+            Some(FieldStyle::Narrow) => ResolvedStyle::Unit(Width::Narrow),
+            Some(FieldStyle::Numeric) => ResolvedStyle::Numeric(1),
+            Some(FieldStyle::TwoDigit) => ResolvedStyle::Numeric(2),
+            None if previous_numeric => ResolvedStyle::Numeric(1),
+            None => match options.style {
+                Style::Long => ResolvedStyle::Unit(Width::Wide),
+                Style::Short => ResolvedStyle::Unit(Width::Short),
+                Style::Narrow => ResolvedStyle::Unit(Width::Narrow),
+                Style::Digital if index < HOURS => ResolvedStyle::Unit(Width::Short),
+                Style::Digital => ResolvedStyle::Numeric(1),
+            },
+        };
+        let numeric = matches!(style, ResolvedStyle::Numeric(_));
+        if numeric && index < HOURS {
+            return Err(Error::InvalidOptions(
+                "only hours, minutes, seconds, and fractions of a second can be numeric",
+            ));
+        }
+        if previous_numeric && !numeric {
+            return Err(Error::InvalidOptions(
+                "the units after a numeric unit must be numeric",
+            ));
+        }
+        if numeric && index > SECONDS && !previous_numeric {
+            return Err(Error::InvalidOptions(
+                "fractions of a second can only be numeric if the seconds are",
+            ));
+        }
+        *field = ResolvedField {
+            style,
+            always: match field_options.display {
+                Some(FieldDisplay::Always) => true,
+                Some(FieldDisplay::Auto) => false,
+                None => numeric && index <= SECONDS,
+            },
+        };
+        previous_numeric = numeric;
+    }
+    Ok(fields)
+}
@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for duration formatting.
+
+use displaydoc::Display;
+use icu_decimal::FixedDecimalFormatError;
+use icu_list::ListError;
+use icu_plurals::PluralRulesError;
+
+/// A list of possible error outcomes for the [`DurationFormat`](crate::DurationFormat).
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("{0}")]
+    List(ListError),
+
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(PluralRulesError),
+
+    /// An error originating from [`FixedDecimalFormat`](icu_decimal::FixedDecimalFormat).
+    #[displaydoc("{0}")]
+    FixedDecimalFormat(FixedDecimalFormatError),
+
+    /// A combination of options that cannot be formatted, such as a numeric style for days.
+    #[displaydoc("invalid options: {0}")]
+    InvalidOptions(&'static str),
+}
+
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<ListError> for Error {
+    fn from(e: ListError) -> Self {
+        Error::List(e)
+    }
+}
+
+impl From<PluralRulesError> for Error {
+    fn from(e: PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatError> for Error {
+    fn from(e: FixedDecimalFormatError) -> Self {
+        Error::FixedDecimalFormat(e)
+    }
+}
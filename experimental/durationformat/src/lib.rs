@@ -0,0 +1,30 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_durationformat`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API formats durations, such as elapsed times, according to the conventions of a locale,
+//! using the unit patterns and the `durationUnit` clock patterns of [`CLDR`]: "1 hr, 23 min,
+//! 4 sec" or "1:23:04" in English.
+//!
+//! A [`Duration`] is a signed amount of years, months, weeks, days, hours, minutes, seconds,
+//! and fractions of a second, which are not balanced against each other. A [`DurationFormat`]
+//! displays each unit either with a unit pattern in one of three widths, or as a number on a
+//! clock, as chosen by the [`options`]; the displayed units are joined as a list of
+//! measurements with [`icu_list`].
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`CLDR`]: http://cldr.unicode.org/
+
+#![warn(missing_docs)]
+
+mod duration;
+mod duration_format;
+pub mod error;
+pub mod options;
+pub mod provider;
+
+pub use duration::Duration;
+pub use duration_format::DurationFormat;
+pub use error::Error as DurationFormatError;
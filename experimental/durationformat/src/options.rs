@@ -0,0 +1,99 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`DurationFormat`](crate::DurationFormat).
+
+/// A bag of options defining how durations will be formatted by
+/// [`DurationFormat`](crate::DurationFormat).
+///
+/// The [`style`](Self::style) applies to all units, unless the options of a unit override it.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct DurationFormatOptions {
+    /// The style of the units whose options do not set one.
+    pub style: Style,
+    /// The options for years.
+    pub years: FieldOptions,
+    /// The options for months.
+    pub months: FieldOptions,
+    /// The options for weeks.
+    pub weeks: FieldOptions,
+    /// The options for days.
+    pub days: FieldOptions,
+    /// The options for hours.
+    pub hours: FieldOptions,
+    /// The options for minutes.
+    pub minutes: FieldOptions,
+    /// The options for seconds.
+    pub seconds: FieldOptions,
+    /// The options for milliseconds.
+    pub milliseconds: FieldOptions,
+    /// The options for microseconds.
+    pub microseconds: FieldOptions,
+    /// The options for nanoseconds.
+    pub nanoseconds: FieldOptions,
+}
+
+/// The overall style of a formatted duration.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// Spelled-out units, such as "1 hour, 23 minutes, 4 seconds".
+    Long,
+    /// Abbreviated units, such as "1 hr, 23 min, 4 sec".
+    Short,
+    /// The shortest units, such as "1h 23m 4s".
+    Narrow,
+    /// A clock, such as "1:23:04", for hours, minutes, and seconds, with fractions of a second
+    /// as decimal digits, and abbreviated units for years, months, weeks, and days.
+    Digital,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+/// The options for a single unit of a duration.
+///
+/// Options that are `None` are derived from the [`Style`] and the preceding units.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct FieldOptions {
+    /// The style of the unit.
+    pub style: Option<FieldStyle>,
+    /// When to display the unit.
+    pub display: Option<FieldDisplay>,
+}
+
+/// The style of a single unit of a duration.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FieldStyle {
+    /// A spelled-out unit, such as "4 seconds".
+    Long,
+    /// An abbreviated unit, such as "4 sec".
+    Short,
+    /// The shortest unit, such as "4s".
+    Narrow,
+    /// A number on a clock, such as the "4" in "0:04".
+    ///
+    /// Only hours, minutes, seconds, and the units of fractions of a second can be numeric,
+    /// and all units after a numeric unit must be numeric too. Fractions of a second are
+    /// displayed as decimal digits of the seconds.
+    Numeric,
+    /// A number on a clock with at least two digits, such as the "04" in "1:23:04".
+    ///
+    /// The same restrictions as for [`FieldStyle::Numeric`] apply.
+    TwoDigit,
+}
+
+/// When to display a single unit of a duration.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FieldDisplay {
+    /// Only display the unit if its amount is not zero.
+    Auto,
+    /// Always display the unit.
+    Always,
+}
@@ -0,0 +1,169 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_plurals::PluralCategory;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use std::borrow::Cow;
+
+pub mod key {
+    //! Resource keys for [`icu_durationformat`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the patterns of the units of time and of clocks.
+    pub const UNITS_V1: ResourceKey = resource_key!(Duration, "units", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_durationformat`](crate),
+/// grouped by data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[icu_provider::registered_keys!(
+    DurationUnitsV1Marker,
+    [key::UNITS_V1]
+)];
+
+/// The patterns of the units of time in the three widths, from the CLDR `duration` units, and
+/// the patterns of clocks, from the CLDR `durationUnit` patterns.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DurationUnitsV1<'data> {
+    /// The spelled-out units, such as "{0} hours".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub long: UnitPatterns<'data>,
+
+    /// The abbreviated units, such as "{0} hr".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub short: UnitPatterns<'data>,
+
+    /// The shortest units, such as "{0}h".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub narrow: UnitPatterns<'data>,
+
+    /// The pattern of a clock with hours, minutes, and seconds, such as "h:mm:ss".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub hms: Cow<'data, str>,
+
+    /// The pattern of a clock with hours and minutes, such as "h:mm".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub hm: Cow<'data, str>,
+
+    /// The pattern of a clock with minutes and seconds, such as "m:ss".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ms: Cow<'data, str>,
+}
+
+/// The patterns of each unit of time in one width.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UnitPatterns<'data> {
+    /// The patterns for years.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub years: PluralPatterns<'data>,
+
+    /// The patterns for months.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub months: PluralPatterns<'data>,
+
+    /// The patterns for weeks.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub weeks: PluralPatterns<'data>,
+
+    /// The patterns for days.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub days: PluralPatterns<'data>,
+
+    /// The patterns for hours.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub hours: PluralPatterns<'data>,
+
+    /// The patterns for minutes.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub minutes: PluralPatterns<'data>,
+
+    /// The patterns for seconds.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub seconds: PluralPatterns<'data>,
+
+    /// The patterns for milliseconds.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub milliseconds: PluralPatterns<'data>,
+
+    /// The patterns for microseconds.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub microseconds: PluralPatterns<'data>,
+
+    /// The patterns for nanoseconds.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub nanoseconds: PluralPatterns<'data>,
+}
+
+/// The patterns of a unit for each plural category, in which `{0}` is replaced by the
+/// amount.
+///
+/// Patterns may lack the placeholder, such as for the singular of some languages.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[allow(missing_docs)] // The plural categories
+pub struct PluralPatterns<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub zero: Option<Cow<'data, str>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub one: Option<Cow<'data, str>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub two: Option<Cow<'data, str>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub few: Option<Cow<'data, str>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub many: Option<Cow<'data, str>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub other: Cow<'data, str>,
+}
+
+impl<'data> UnitPatterns<'data> {
+    /// Returns the patterns of the units, from years to nanoseconds.
+    pub(crate) fn fields(&self) -> [&PluralPatterns<'data>; 10] {
+        [
+            &self.years,
+            &self.months,
+            &self.weeks,
+            &self.days,
+            &self.hours,
+            &self.minutes,
+            &self.seconds,
+            &self.milliseconds,
+            &self.microseconds,
+            &self.nanoseconds,
+        ]
+    }
+}
+
+impl<'data> PluralPatterns<'data> {
+    /// Returns the pattern for the plural category, or the pattern for
+    /// [`PluralCategory::Other`] if the category has none.
+    pub fn get(&self, category: PluralCategory) -> &str {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
@@ -0,0 +1,37 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for list formatting.
+
+use displaydoc::Display;
+
+/// A list of possible error outcomes for the [`ListFormatter`](crate::ListFormatter).
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+
+    /// A list pattern that does not contain `{0}` followed by `{1}`.
+    #[displaydoc("invalid list pattern: {0}")]
+    InvalidPattern(String),
+
+    /// A condition of a special case that is not a valid regular expression.
+    #[displaydoc("invalid condition: {0}")]
+    InvalidCondition(regex::Error),
+}
+
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<regex::Error> for Error {
+    fn from(e: regex::Error) -> Self {
+        Error::InvalidCondition(e)
+    }
+}
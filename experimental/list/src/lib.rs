@@ -0,0 +1,30 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_list`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API formats lists of items according to the conventions of a locale, as specified by
+//! the list patterns of [`CLDR`]: "apples, pears, and plums" in English, or "manzanas, peras y
+//! ciruelas" in Spanish.
+//!
+//! A [`ListFormatter`] is created for a [`Type`] of list, which is a list of items joined by
+//! "and", a list of alternatives joined by "or", or a list of measurements such as "5 pounds, 12
+//! ounces", and for a [`Width`], which determines how much the joining words are abbreviated.
+//!
+//! Some languages change the joining words depending on the following item; in Spanish, "y"
+//! becomes "e" before words starting with the sound "i", as in "Mallorca e Ibiza", and "o"
+//! becomes "u" before words starting with the sound "o". These special cases are part of the
+//! data in [`provider`].
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`CLDR`]: http://cldr.unicode.org/
+
+#![warn(missing_docs)]
+
+pub mod error;
+mod list_formatter;
+pub mod provider;
+
+pub use error::Error as ListError;
+pub use list_formatter::{FieldType, FormattedList, ListFormatter, Type, Width};
@@ -0,0 +1,452 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::provider::*;
+use core::fmt;
+use formatted_string_builder::FormattedStringBuilder;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_provider::prelude::*;
+use regex::Regex;
+use writeable::{LengthHint, Writeable};
+
+// The indices of the patterns in `ListFormatter::conditions`.
+const START: usize = 0;
+const MIDDLE: usize = 1;
+const END: usize = 2;
+const PAIR: usize = 3;
+
+/// The fields of the output of [`ListFormatter::format_to_parts()`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FieldType {
+    /// An element of the list.
+    Element,
+    /// The text between the elements, such as ", and ".
+    Literal,
+}
+
+/// The type of a list, which determines the words that join its elements.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Type {
+    /// A list of items that all apply, such as "a, b, and c".
+    And,
+    /// A list of alternatives, such as "a, b, or c".
+    Or,
+    /// A list of measurements that form a single quantity, such as "5 pounds, 12 ounces".
+    Unit,
+}
+
+/// The width of a list, which determines how much the words that join its elements are
+/// abbreviated.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Width {
+    /// The full words, such as "a, b, and c".
+    Wide,
+    /// Abbreviated words, such as "a, b, & c".
+    Short,
+    /// The shortest form, such as "a, b, c".
+    Narrow,
+}
+
+/// A formatter that joins the elements of a list according to the list patterns of a locale.
+///
+/// # Examples
+///
+/// ```
+/// use icu_list::provider::*;
+/// use icu_list::{ListFormatter, Type, Width};
+/// use icu_locid_macros::langid;
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+/// use writeable::assert_writeable_eq;
+///
+/// let pattern = |pattern: &str| -> ConditionalListJoinerPattern {
+///     pattern
+///         .parse::<ListJoinerPattern>()
+///         .expect("Valid pattern")
+///         .into()
+/// };
+/// let patterns = |end: &str| ListJoinerPatterns {
+///     start: pattern("{0}, {1}"),
+///     middle: pattern("{0}, {1}"),
+///     end: pattern(end),
+///     pair: pattern("{0} and {1}"),
+/// };
+/// let provider = StructProvider {
+///     key: icu_list::provider::key::AND_V1,
+///     data: DataPayload::from_owned(ListFormatterPatternsV1 {
+///         wide: patterns("{0}, and {1}"),
+///         short: patterns("{0}, & {1}"),
+///         narrow: patterns("{0}, {1}"),
+///     }),
+/// };
+///
+/// let formatter = ListFormatter::try_new(langid!("en"), &provider, Type::And, Width::Wide)
+///     .expect("Data should load successfully");
+/// assert_writeable_eq!("apples", formatter.format(&["apples"]));
+/// assert_writeable_eq!("apples and pears", formatter.format(&["apples", "pears"]));
+/// assert_writeable_eq!(
+///     "apples, pears, and plums",
+///     formatter.format(&["apples", "pears", "plums"])
+/// );
+/// ```
+pub struct ListFormatter<'data> {
+    data: DataPayload<'data, ListFormatterPatternsV1Marker>,
+    width: Width,
+    /// The compiled conditions of the special cases of the `start`, `middle`, `end`, and `pair`
+    /// patterns.
+    conditions: [Option<Regex>; 4],
+    resolved_locale: Option<LanguageIdentifier>,
+}
+
+impl<'data> ListFormatter<'data> {
+    /// Creates a formatter for lists of the given type and width in the given locale.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        type_: Type,
+        width: Width,
+    ) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, ListFormatterPatternsV1Marker> + ?Sized,
+    {
+        let response = data_provider.load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: match type_ {
+                    Type::And => key::AND_V1,
+                    Type::Or => key::OR_V1,
+                    Type::Unit => key::UNIT_V1,
+                },
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(locale.into().into()),
+                },
+            },
+        })?;
+        let resolved_locale = response.metadata.data_langid.clone();
+        let data: DataPayload<ListFormatterPatternsV1Marker> = response.take_payload()?;
+        let compile = |pattern: &ConditionalListJoinerPattern| -> Result<Option<Regex>, Error> {
+            match pattern.special_case {
+                Some(ref special_case) => Ok(Some(Regex::new(&special_case.condition)?)),
+                None => Ok(None),
+            }
+        };
+        let conditions = {
+            let patterns = patterns_of(data.get(), width);
+            [
+                compile(&patterns.start)?,
+                compile(&patterns.middle)?,
+                compile(&patterns.end)?,
+                compile(&patterns.pair)?,
+            ]
+        };
+        Ok(Self {
+            data,
+            width,
+            conditions,
+            resolved_locale,
+        })
+    }
+
+    /// Returns the locale of the patterns loaded by [`ListFormatter::try_new`], which differs
+    /// from the requested locale if the data provider fell back to a more general locale, such as
+    /// `en-001` for `en-IN`.
+    ///
+    /// Returns `None` if the data provider did not report the locale of its data.
+    pub fn resolved_locale(&self) -> Option<&LanguageIdentifier> {
+        self.resolved_locale.as_ref()
+    }
+
+    /// Returns the parts of the pattern at `index` (`start`, `middle`, `end`, or `pair`) that
+    /// joins an element with `following_value`.
+    fn parts(&self, index: usize, following_value: &str) -> (&str, &str, &str) {
+        let patterns = patterns_of(self.data.get(), self.width);
+        let pattern = [
+            &patterns.start,
+            &patterns.middle,
+            &patterns.end,
+            &patterns.pair,
+        ][index];
+        let joiner = match (&pattern.special_case, &self.conditions[index]) {
+            (Some(special_case), Some(condition)) if condition.is_match(following_value) => {
+                &special_case.pattern
+            }
+            _ => &pattern.default,
+        };
+        (&joiner.before, &joiner.between, &joiner.after)
+    }
+
+    /// Returns the parts of the pattern that joins `values[i - 1]` with `values[i]`.
+    fn join(&self, values: &[&str], i: usize) -> (&str, &str, &str) {
+        let n = values.len();
+        let index = if n == 2 {
+            PAIR
+        } else if i == 1 {
+            START
+        } else if i == n - 1 {
+            END
+        } else {
+            MIDDLE
+        };
+        self.parts(index, values[i])
+    }
+
+    /// Joins the elements of a list, returning a [`FormattedList`].
+    pub fn format<'l>(&'l self, values: &'l [&'l str]) -> FormattedList<'l, 'data> {
+        FormattedList {
+            formatter: self,
+            values,
+        }
+    }
+
+    /// Joins the elements of a list, marking which parts of the result are elements and which
+    /// are literal text.
+    pub fn format_to_parts(&self, values: &[&str]) -> FormattedStringBuilder<FieldType> {
+        let mut builder = FormattedStringBuilder::<FieldType>::new();
+        for i in 1..values.len() {
+            let (before, between, _) = self.join(values, i);
+            builder.append(before, FieldType::Literal);
+            builder.append(values[i - 1], FieldType::Element);
+            builder.append(between, FieldType::Literal);
+        }
+        if let Some(last) = values.last() {
+            builder.append(last, FieldType::Element);
+        }
+        for i in (1..values.len()).rev() {
+            let (_, _, after) = self.join(values, i);
+            builder.append(after, FieldType::Literal);
+        }
+        builder
+    }
+}
+
+/// An intermediate structure returned by [`ListFormatter::format()`].
+/// Use [`Writeable`][Writeable] to render the formatted list to a string or buffer.
+pub struct FormattedList<'l, 'data> {
+    formatter: &'l ListFormatter<'data>,
+    values: &'l [&'l str],
+}
+
+impl<'l, 'data> Writeable for FormattedList<'l, 'data> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        // The pattern joining an element with the rest of the list encloses the rest of the
+        // list, so the text after the elements is written in reverse order.
+        for i in 1..self.values.len() {
+            let (before, between, _) = self.formatter.join(self.values, i);
+            sink.write_str(before)?;
+            sink.write_str(self.values[i - 1])?;
+            sink.write_str(between)?;
+        }
+        if let Some(last) = self.values.last() {
+            sink.write_str(last)?;
+        }
+        for i in (1..self.values.len()).rev() {
+            let (_, _, after) = self.formatter.join(self.values, i);
+            sink.write_str(after)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let values_len: usize = self.values.iter().map(|value| value.len()).sum();
+        let joins_len: usize = (1..self.values.len())
+            .map(|i| {
+                let (before, between, after) = self.formatter.join(self.values, i);
+                before.len() + between.len() + after.len()
+            })
+            .sum();
+        LengthHint::Exact(values_len + joins_len)
+    }
+}
+
+impl<'l, 'data> fmt::Display for FormattedList<'l, 'data> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn patterns_of<'a>(
+    data: &'a ListFormatterPatternsV1<'a>,
+    width: Width,
+) -> &'a ListJoinerPatterns<'a> {
+    match width {
+        Width::Wide => &data.wide,
+        Width::Short => &data.short,
+        Width::Narrow => &data.narrow,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid_macros::langid;
+    use icu_provider::struct_provider::StructProvider;
+    use writeable::assert_writeable_eq;
+
+    const VALUES: &[&str] = &["one", "two", "three", "four", "five"];
+
+    fn pattern(pattern: &str) -> ConditionalListJoinerPattern<'static> {
+        pattern
+            .parse::<ListJoinerPattern>()
+            .expect("Valid pattern")
+            .into()
+    }
+
+    fn conditional(
+        condition: &str,
+        then_pattern: &str,
+        else_pattern: &str,
+    ) -> ConditionalListJoinerPattern<'static> {
+        ConditionalListJoinerPattern {
+            default: else_pattern.parse().expect("Valid pattern"),
+            special_case: Some(SpecialCasePattern {
+                condition: condition.to_string().into(),
+                pattern: then_pattern.parse().expect("Valid pattern"),
+            }),
+        }
+    }
+
+    fn formatter(patterns: ListJoinerPatterns<'static>) -> ListFormatter<'static> {
+        let provider = StructProvider {
+            key: key::AND_V1,
+            data: DataPayload::from_owned(ListFormatterPatternsV1 {
+                wide: patterns.clone(),
+                short: patterns.clone(),
+                narrow: patterns,
+            }),
+        };
+        ListFormatter::try_new(langid!("und"), &provider, Type::And, Width::Wide)
+            .expect("Data should load successfully")
+    }
+
+    fn test_formatter() -> ListFormatter<'static> {
+        formatter(ListJoinerPatterns {
+            pair: pattern("{0}; {1}"),
+            start: pattern("{0}: {1}"),
+            middle: pattern("{0}, {1}"),
+            end: pattern("{0}. {1}!"),
+        })
+    }
+
+    #[test]
+    fn test_format() {
+        let formatter = test_formatter();
+        assert_writeable_eq!("", formatter.format(&VALUES[0..0]));
+        assert_writeable_eq!("one", formatter.format(&VALUES[0..1]));
+        assert_writeable_eq!("one; two", formatter.format(&VALUES[0..2]));
+        assert_writeable_eq!("one: two. three!", formatter.format(&VALUES[0..3]));
+        assert_writeable_eq!("one: two, three. four!", formatter.format(&VALUES[0..4]));
+        assert_writeable_eq!("one: two, three, four. five!", formatter.format(VALUES));
+    }
+
+    #[test]
+    fn test_format_to_parts() {
+        assert_eq!(test_formatter().format_to_parts(&VALUES[0..0]).as_str(), "");
+        assert_eq!(
+            test_formatter().format_to_parts(&VALUES[0..1]).as_str(),
+            "one"
+        );
+        assert_eq!(
+            test_formatter().format_to_parts(&VALUES[0..2]).as_str(),
+            "one; two"
+        );
+        assert_eq!(
+            test_formatter().format_to_parts(&VALUES[0..3]).as_str(),
+            "one: two. three!"
+        );
+        assert_eq!(
+            test_formatter().format_to_parts(&VALUES[0..4]).as_str(),
+            "one: two, three. four!"
+        );
+        let parts = test_formatter().format_to_parts(VALUES);
+        assert_eq!(parts.as_str(), "one: two, three, four. five!");
+
+        assert_eq!(parts.field_at(0), FieldType::Element);
+        assert!(parts.is_field_start(0, 0));
+        assert_eq!(parts.field_at(2), FieldType::Element);
+        assert!(!parts.is_field_start(2, 0));
+        assert_eq!(parts.field_at(3), FieldType::Literal);
+        assert!(parts.is_field_start(3, 0));
+        assert_eq!(parts.field_at(4), FieldType::Literal);
+        assert!(!parts.is_field_start(4, 0));
+        assert_eq!(parts.field_at(5), FieldType::Element);
+        assert!(parts.is_field_start(5, 0));
+    }
+
+    #[test]
+    fn test_conditional() {
+        let formatter = formatter(ListJoinerPatterns {
+            pair: conditional("^(?i)i", "{0} e {1}", "{0} y {1}"),
+            start: pattern("{0}, {1}"),
+            middle: pattern("{0}, {1}"),
+            end: conditional("^(?i)i", "{0} e {1}", "{0} y {1}"),
+        });
+        assert_writeable_eq!("Mallorca e Ibiza", formatter.format(&["Mallorca", "Ibiza"]));
+        assert_writeable_eq!("Ibiza y Mallorca", formatter.format(&["Ibiza", "Mallorca"]));
+        assert_writeable_eq!(
+            "Mallorca, Ibiza y Menorca",
+            formatter.format(&["Mallorca", "Ibiza", "Menorca"])
+        );
+        assert_writeable_eq!(
+            "Mallorca, Menorca e Ibiza",
+            formatter.format(&["Mallorca", "Menorca", "Ibiza"])
+        );
+    }
+
+    #[test]
+    fn test_invalid_condition() {
+        let provider = StructProvider {
+            key: key::AND_V1,
+            data: DataPayload::from_owned(ListFormatterPatternsV1 {
+                wide: ListJoinerPatterns {
+                    pair: conditional("(", "{0} e {1}", "{0} y {1}"),
+                    start: pattern("{0}, {1}"),
+                    middle: pattern("{0}, {1}"),
+                    end: pattern("{0} y {1}"),
+                },
+                short: ListJoinerPatterns {
+                    pair: pattern("{0} y {1}"),
+                    start: pattern("{0}, {1}"),
+                    middle: pattern("{0}, {1}"),
+                    end: pattern("{0} y {1}"),
+                },
+                narrow: ListJoinerPatterns {
+                    pair: pattern("{0} y {1}"),
+                    start: pattern("{0}, {1}"),
+                    middle: pattern("{0}, {1}"),
+                    end: pattern("{0} y {1}"),
+                },
+            }),
+        };
+        assert!(matches!(
+            ListFormatter::try_new(langid!("es"), &provider, Type::And, Width::Wide),
+            Err(Error::InvalidCondition(_))
+        ));
+        // Only the conditions of the requested width are compiled.
+        assert!(ListFormatter::try_new(langid!("es"), &provider, Type::And, Width::Short).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(matches!(
+            "{1} and {0}".parse::<ListJoinerPattern>(),
+            Err(Error::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            "{0}{0}".parse::<ListJoinerPattern>(),
+            Err(Error::InvalidPattern(_))
+        ));
+        assert_eq!(
+            "before {0} between {1} after"
+                .parse::<ListJoinerPattern>()
+                .ok(),
+            Some(ListJoinerPattern {
+                before: "before ".into(),
+                between: " between ".into(),
+                after: " after".into(),
+            })
+        );
+    }
+}
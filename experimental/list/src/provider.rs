@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::error::Error;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+pub mod key {
+    //! Resource keys for [`icu_list`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: patterns for lists joined by "and" (the CLDR `standard` lists).
+    pub const AND_V1: ResourceKey = resource_key!(List, "and", 1);
+
+    /// Resource key: patterns for lists of alternatives joined by "or".
+    pub const OR_V1: ResourceKey = resource_key!(List, "or", 1);
+
+    /// Resource key: patterns for lists of measurements, such as "5 ft, 2 in".
+    pub const UNIT_V1: ResourceKey = resource_key!(List, "unit", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_list`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[icu_provider::registered_keys!(
+    ListFormatterPatternsV1Marker,
+    [key::AND_V1, key::OR_V1, key::UNIT_V1]
+)];
+
+/// The list patterns of one type of list, such as "and" lists, in the three widths.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct ListFormatterPatternsV1<'data> {
+    /// The patterns of the default width, such as "a, b, and c".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub wide: ListJoinerPatterns<'data>,
+
+    /// The patterns of the short width, such as "a, b, & c".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub short: ListJoinerPatterns<'data>,
+
+    /// The patterns of the narrow width, such as "a, b, c".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub narrow: ListJoinerPatterns<'data>,
+}
+
+/// The four patterns that join the elements of a list, corresponding to the CLDR `start`,
+/// `middle`, `end`, and `2` patterns.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ListJoinerPatterns<'data> {
+    /// The pattern joining the first two elements of a list of three or more elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub start: ConditionalListJoinerPattern<'data>,
+
+    /// The pattern joining the middle elements of a list of four or more elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub middle: ConditionalListJoinerPattern<'data>,
+
+    /// The pattern joining the last two elements of a list of three or more elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub end: ConditionalListJoinerPattern<'data>,
+
+    /// The pattern joining the elements of a list of two elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pair: ConditionalListJoinerPattern<'data>,
+}
+
+/// A pattern that joins two elements, with an alternative pattern that is used if the second
+/// element matches a condition, such as the Spanish "{0} e {1}" instead of "{0} y {1}" before
+/// words that start with the sound "i".
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ConditionalListJoinerPattern<'data> {
+    /// The pattern that is used unless the special case applies.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub default: ListJoinerPattern<'data>,
+
+    /// The special case, if any.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub special_case: Option<SpecialCasePattern<'data>>,
+}
+
+/// The special case of a [`ConditionalListJoinerPattern`].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SpecialCasePattern<'data> {
+    /// A regular expression in the syntax of the [`regex`] crate; the special case applies if it
+    /// matches the second element.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub condition: Cow<'data, str>,
+
+    /// The pattern that is used if the condition matches.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pattern: ListJoinerPattern<'data>,
+}
+
+/// A pattern such as "{0}, and {1}", split into the literal text around the two elements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ListJoinerPattern<'data> {
+    /// The text before the first element.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub before: Cow<'data, str>,
+
+    /// The text between the two elements.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub between: Cow<'data, str>,
+
+    /// The text after the second element.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub after: Cow<'data, str>,
+}
+
+impl FromStr for ListJoinerPattern<'static> {
+    type Err = Error;
+
+    /// Parses a CLDR list pattern, in which `{0}` has to come before `{1}`.
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        match (pattern.find("{0}"), pattern.find("{1}")) {
+            (Some(index_0), Some(index_1)) if index_0 + 3 <= index_1 => Ok(Self {
+                before: Cow::Owned(pattern[..index_0].to_string()),
+                between: Cow::Owned(pattern[index_0 + 3..index_1].to_string()),
+                after: Cow::Owned(pattern[index_1 + 3..].to_string()),
+            }),
+            _ => Err(Error::InvalidPattern(pattern.to_string())),
+        }
+    }
+}
+
+impl<'data> From<ListJoinerPattern<'data>> for ConditionalListJoinerPattern<'data> {
+    fn from(default: ListJoinerPattern<'data>) -> Self {
+        Self {
+            default,
+            special_case: None,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for message formatting.
+
+use alloc::string::String;
+use displaydoc::Display;
+
+/// A list of possible error outcomes for the [`MessageFormat`](crate::MessageFormat) type.
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// An error loading the plural rules of a `plural` or `selectordinal` argument.
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(icu_plurals::PluralRulesError),
+    /// An error loading the decimal formatter for numeric arguments.
+    #[displaydoc("error loading decimal symbols: {0}")]
+    Decimal(icu_decimal::FixedDecimalFormatError),
+    /// The message pattern is not well-formed; the offset is the byte index of the error.
+    #[displaydoc("invalid message pattern at byte {offset}: {reason}")]
+    Syntax {
+        /// The byte index in the pattern at which the error was found.
+        offset: usize,
+        /// A description of the error.
+        reason: &'static str,
+    },
+    /// The pattern refers to an argument that was not given.
+    #[displaydoc("missing argument: {0}")]
+    MissingArgument(String),
+    /// An argument has a value that the pattern does not accept, such as a string for a
+    /// `plural` argument.
+    #[displaydoc("invalid value for argument: {0}")]
+    InvalidArgument(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_plurals::PluralRulesError> for Error {
+    fn from(e: icu_plurals::PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<icu_decimal::FixedDecimalFormatError> for Error {
+    fn from(e: icu_decimal::FixedDecimalFormatError) -> Self {
+        Error::Decimal(e)
+    }
+}
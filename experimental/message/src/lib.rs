@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_message`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API formats messages written in the ICU `MessageFormat` syntax, such as
+//! `"{count, plural, one {# file} other {# files}}"`, so that applications can localize
+//! sentences that contain variable text and numbers.
+//!
+//! A [`MessageFormat`] supports simple placeholders, number arguments, `plural` and
+//! `selectordinal` arguments, whose branches are selected by the plural rules of the locale
+//! from [`icu_plurals`], and `select` arguments, whose branches are selected by a string.
+//! Numbers are formatted for the locale with [`icu_decimal`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_message::MessageFormat;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let message = MessageFormat::try_new(
+//!     langid!("ru"),
+//!     &provider,
+//!     "{count, plural, one {# файл} few {# файла} other {# файлов}}",
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_eq!(message.format(&[("count", 1.into())]).unwrap(), "1 файл");
+//! assert_eq!(message.format(&[("count", 3.into())]).unwrap(), "3 файла");
+//! assert_eq!(message.format(&[("count", 25.into())]).unwrap(), "25 файлов");
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+pub mod error;
+mod message_format;
+mod parser;
+
+pub use error::Error as MessageFormatError;
+pub use message_format::{ArgumentValue, MessageFormat};
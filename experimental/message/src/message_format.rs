@@ -0,0 +1,325 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::parser::{self, Argument, ArgumentKind, Part, PluralSelector};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// The value of an argument of a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentValue<'a> {
+    /// A string, which can be used in simple placeholders and `select` arguments.
+    String(&'a str),
+    /// A number, which can be used in simple placeholders, `number` arguments, and `plural`
+    /// and `selectordinal` arguments.
+    Number(FixedDecimal),
+}
+
+impl<'a> From<&'a str> for ArgumentValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<FixedDecimal> for ArgumentValue<'_> {
+    fn from(value: FixedDecimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+macro_rules! impl_from_integer_type {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for ArgumentValue<'_> {
+                fn from(value: $ty) -> Self {
+                    Self::Number(FixedDecimal::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer_type!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A formatter for messages with placeholders, plural selection, and select branches, written
+/// in the ICU `MessageFormat` syntax.
+///
+/// A pattern consists of literal text and arguments in braces:
+///
+/// * `{name}` is replaced by the value of the argument `name`; numbers are formatted for the
+///   locale.
+/// * `{name, number}` is replaced by the number `name`, formatted for the locale.
+/// * `{name, plural, one {...} other {...}}` selects a message by the plural category of the
+///   number `name`. A branch `=N` matches the number N exactly and takes precedence over the
+///   categories. `offset:N` before the branches subtracts N from the number before the
+///   category is selected, and within the branches, `#` is replaced by the resulting number.
+/// * `{name, selectordinal, ...}` is like `plural`, with the ordinal plural rules.
+/// * `{name, select, female {...} other {...}}` selects a message by the string `name`.
+///
+/// `plural`, `selectordinal` and `select` arguments require an `other` branch, which is used
+/// if no other branch matches. The messages of branches can contain further arguments.
+///
+/// As in ICU, `''` is a literal apostrophe, and an apostrophe before `{`, `}`, or `#` in a
+/// plural branch starts literal text, which ends with the next single apostrophe.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_message::MessageFormat;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let message = MessageFormat::try_new(
+///     langid!("en"),
+///     &provider,
+///     "{user} uploaded {count, plural, =0 {no files} one {a file} other {# files}} to \
+///      {gender, select, female {her} male {his} other {their}} drive.",
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     message
+///         .format(&[
+///             ("user", "Ana".into()),
+///             ("count", 1500.into()),
+///             ("gender", "female".into()),
+///         ])
+///         .expect("The arguments should be valid"),
+///     "Ana uploaded 1,500 files to her drive."
+/// );
+/// ```
+pub struct MessageFormat<'data> {
+    message: Vec<Part>,
+    cardinal_rules: Option<PluralRules>,
+    ordinal_rules: Option<PluralRules>,
+    decimal_format: FixedDecimalFormat<'data>,
+}
+
+impl<'data> MessageFormat<'data> {
+    /// Parses a message pattern, and loads the data that is needed to format it in a locale.
+    pub fn try_new<T, D>(locale: T, data_provider: &D, pattern: &str) -> Result<Self, Error>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, PluralRuleStringsV1Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let message = parser::parse(pattern)?;
+
+        let mut uses_cardinal = false;
+        let mut uses_ordinal = false;
+        visit_plural_arguments(&message, &mut |rule_type| match rule_type {
+            PluralRuleType::Cardinal => uses_cardinal = true,
+            PluralRuleType::Ordinal => uses_ordinal = true,
+        });
+        let load_rules = |used: bool, rule_type| -> Result<Option<PluralRules>, Error> {
+            if used {
+                Ok(Some(PluralRules::try_new(
+                    locale.id.clone(),
+                    data_provider,
+                    rule_type,
+                )?))
+            } else {
+                Ok(None)
+            }
+        };
+        let cardinal_rules = load_rules(uses_cardinal, PluralRuleType::Cardinal)?;
+        let ordinal_rules = load_rules(uses_ordinal, PluralRuleType::Ordinal)?;
+
+        let decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, Default::default())?;
+
+        Ok(Self {
+            message,
+            cardinal_rules,
+            ordinal_rules,
+            decimal_format,
+        })
+    }
+
+    /// Formats the message with the given arguments, which are pairs of names and values.
+    ///
+    /// Returns an error if an argument that the message uses is missing, or has a value of the
+    /// wrong type. Arguments that the message does not use are ignored.
+    pub fn format(&self, arguments: &[(&str, ArgumentValue)]) -> Result<String, Error> {
+        let mut result = String::new();
+        self.format_message(&self.message, arguments, None, &mut result)?;
+        Ok(result)
+    }
+
+    fn format_message(
+        &self,
+        message: &[Part],
+        arguments: &[(&str, ArgumentValue)],
+        pound: Option<&FixedDecimal>,
+        result: &mut String,
+    ) -> Result<(), Error> {
+        for part in message {
+            match part {
+                Part::Literal(text) => result.push_str(text),
+                Part::Pound => {
+                    if let Some(value) = pound {
+                        self.format_number(value, result);
+                    }
+                }
+                Part::Argument(argument) => {
+                    self.format_argument(argument, arguments, pound, result)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn format_argument(
+        &self,
+        argument: &Argument,
+        arguments: &[(&str, ArgumentValue)],
+        pound: Option<&FixedDecimal>,
+        result: &mut String,
+    ) -> Result<(), Error> {
+        let value = arguments
+            .iter()
+            .find(|(name, _)| *name == argument.name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| Error::MissingArgument(argument.name.clone()))?;
+        let invalid = || Error::InvalidArgument(argument.name.clone());
+        match (&argument.kind, value) {
+            (ArgumentKind::Simple, ArgumentValue::String(value)) => result.push_str(value),
+            (ArgumentKind::Simple, ArgumentValue::Number(value))
+            | (ArgumentKind::Number, ArgumentValue::Number(value)) => {
+                self.format_number(value, result)
+            }
+            (
+                ArgumentKind::Plural {
+                    rule_type,
+                    offset,
+                    branches,
+                },
+                ArgumentValue::Number(value),
+            ) => {
+                let adjusted = subtract_offset(value, *offset).ok_or_else(invalid)?;
+                let exact = PluralSelector::Exact(parser::normalized(value));
+                let branch = match branches.iter().find(|(selector, _)| *selector == exact) {
+                    Some((_, branch)) => branch,
+                    None => {
+                        let rules = match rule_type {
+                            PluralRuleType::Cardinal => self.cardinal_rules.as_ref(),
+                            PluralRuleType::Ordinal => self.ordinal_rules.as_ref(),
+                        }
+                        .expect("Plural rules are loaded for every plural argument");
+                        let category = PluralSelector::Category(rules.select(&adjusted));
+                        let other = PluralSelector::Category(PluralCategory::Other);
+                        find_branch(branches, |selector| *selector == category)
+                            .or_else(|| find_branch(branches, |selector| *selector == other))
+                            .expect("The parser requires an 'other' branch")
+                    }
+                };
+                self.format_message(branch, arguments, Some(&adjusted), result)?
+            }
+            (ArgumentKind::Select { branches }, ArgumentValue::String(value)) => {
+                let branch = find_branch(branches, |keyword| keyword == value)
+                    .or_else(|| find_branch(branches, |keyword| keyword == "other"))
+                    .expect("The parser requires an 'other' branch");
+                self.format_message(branch, arguments, pound, result)?
+            }
+            _ => return Err(invalid()),
+        }
+        Ok(())
+    }
+
+    fn format_number(&self, value: &FixedDecimal, result: &mut String) {
+        result.push_str(&self.decimal_format.format(value).write_to_string());
+    }
+}
+
+/// Returns the message of the first branch whose selector matches `predicate`.
+fn find_branch<S>(branches: &[(S, Vec<Part>)], predicate: impl Fn(&S) -> bool) -> Option<&[Part]> {
+    branches
+        .iter()
+        .find(|(selector, _)| predicate(selector))
+        .map(|(_, message)| message.as_slice())
+}
+
+/// Calls `f` with the rule type of every `plural` and `selectordinal` argument of a message,
+/// including nested ones.
+fn visit_plural_arguments(message: &[Part], f: &mut impl FnMut(PluralRuleType)) {
+    for part in message {
+        if let Part::Argument(argument) = part {
+            match &argument.kind {
+                ArgumentKind::Plural {
+                    rule_type,
+                    branches,
+                    ..
+                } => {
+                    f(*rule_type);
+                    for (_, branch) in branches {
+                        visit_plural_arguments(branch, f);
+                    }
+                }
+                ArgumentKind::Select { branches } => {
+                    for (_, branch) in branches {
+                        visit_plural_arguments(branch, f);
+                    }
+                }
+                ArgumentKind::Simple | ArgumentKind::Number => {}
+            }
+        }
+    }
+}
+
+/// Subtracts the offset of a plural argument from a number, keeping its fraction digits.
+///
+/// Returns `None` if the number has too many digits to be computed with.
+fn subtract_offset(value: &FixedDecimal, offset: u32) -> Option<FixedDecimal> {
+    if offset == 0 {
+        return Some(value.clone());
+    }
+    let string = value.to_string();
+    let (integer, fraction) = match string.split_once('.') {
+        Some(parts) => parts,
+        None => (string.as_str(), ""),
+    };
+    let fraction_digits = u32::try_from(fraction.len()).ok()?;
+    let scaled: i128 = [integer, fraction].concat().parse().ok()?;
+    let scaled_offset = i128::from(offset).checked_mul(10i128.checked_pow(fraction_digits)?)?;
+    let mut result = FixedDecimal::from(scaled.checked_sub(scaled_offset)?);
+    result.multiply_pow10(-(fraction_digits as i16)).ok()?;
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtract_offset() {
+        for (value, offset, expected) in [
+            ("5", 0, "5"),
+            ("5", 1, "4"),
+            ("1", 1, "0"),
+            ("0", 2, "-2"),
+            ("2.50", 1, "1.50"),
+            ("0.5", 1, "-0.5"),
+            ("-3.0", 2, "-5.0"),
+        ] {
+            let value: FixedDecimal = value.parse().unwrap();
+            assert_eq!(
+                subtract_offset(&value, offset).unwrap().to_string(),
+                expected
+            );
+        }
+    }
+}
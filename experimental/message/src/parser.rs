@@ -0,0 +1,529 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for message patterns in the ICU `MessageFormat` syntax.
+
+use crate::error::Error;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_plurals::{PluralCategory, PluralRuleType};
+
+/// A part of a message.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Part {
+    /// Literal text, with the quoting already resolved.
+    Literal(String),
+    /// A placeholder for an argument.
+    Argument(Argument),
+    /// The `#` in the message of a plural branch, which stands for the number of the
+    /// innermost `plural` or `selectordinal` argument, minus its offset.
+    Pound,
+}
+
+/// A placeholder such as `{name}` or `{count, plural, ...}`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Argument {
+    pub name: String,
+    pub kind: ArgumentKind,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ArgumentKind {
+    /// `{name}`: the argument as a string, or as a formatted number.
+    Simple,
+    /// `{name, number}`: the argument as a formatted number.
+    Number,
+    /// `{name, plural, ...}` or `{name, selectordinal, ...}`.
+    Plural {
+        rule_type: PluralRuleType,
+        offset: u32,
+        branches: Vec<(PluralSelector, Vec<Part>)>,
+    },
+    /// `{name, select, ...}`.
+    Select { branches: Vec<(String, Vec<Part>)> },
+}
+
+/// The selector of a branch of a `plural` or `selectordinal` argument.
+#[derive(Debug, PartialEq)]
+pub(crate) enum PluralSelector {
+    /// `=N`, which matches the number N before the offset is subtracted. The number is stored
+    /// in the form returned by [`normalized()`].
+    Exact(String),
+    /// A plural category keyword, such as `one`.
+    Category(PluralCategory),
+}
+
+/// Returns the decimal representation of a number without leading and trailing zeros, so
+/// that the same number always has the same representation.
+pub(crate) fn normalized(value: &FixedDecimal) -> String {
+    let string = value.to_string();
+    let (sign, digits) = match string.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", string.as_str()),
+    };
+    let digits = digits.trim_start_matches('0');
+    let digits = if digits.contains('.') {
+        digits.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        digits
+    };
+    if digits.is_empty() {
+        String::from("0")
+    } else {
+        [sign, digits].concat()
+    }
+}
+
+/// Parses a message pattern.
+pub(crate) fn parse(pattern: &str) -> Result<Vec<Part>, Error> {
+    let mut parser = Parser { pattern, offset: 0 };
+    parser.parse_message(false, false)
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, reason: &'static str) -> Error {
+        Error::Syntax {
+            offset: self.offset,
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char, reason: &'static str) -> Result<(), Error> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Parses a name, type, or keyword, returning an error if there is none.
+    fn parse_identifier(&mut self, reason: &'static str) -> Result<&'a str, Error> {
+        let start = self.offset;
+        while matches!(self.peek(), Some(c) if c.is_alphanumeric() || c == '_' || c == '-') {
+            self.bump();
+        }
+        if self.offset == start {
+            Err(self.error(reason))
+        } else {
+            Ok(&self.pattern[start..self.offset])
+        }
+    }
+
+    /// Parses a message up to the end of the pattern, or up to the `}` that closes it if it is
+    /// `nested` in an argument.
+    ///
+    /// Apostrophes quote literal text as in ICU: `''` is a literal apostrophe, and an
+    /// apostrophe before a syntax character starts quoted text up to the next single
+    /// apostrophe. Other apostrophes are literal.
+    fn parse_message(&mut self, in_plural: bool, nested: bool) -> Result<Vec<Part>, Error> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        loop {
+            let c = match self.peek() {
+                Some(c) => c,
+                None if nested => return Err(self.error("unclosed argument")),
+                None => break,
+            };
+            match c {
+                '}' if nested => break,
+                '}' => return Err(self.error("unmatched '}'")),
+                '{' => {
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(core::mem::take(&mut literal)));
+                    }
+                    self.bump();
+                    parts.push(Part::Argument(self.parse_argument(in_plural)?));
+                }
+                '#' if in_plural => {
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(core::mem::take(&mut literal)));
+                    }
+                    self.bump();
+                    parts.push(Part::Pound);
+                }
+                '\'' => {
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            literal.push('\'');
+                        }
+                        Some(next)
+                            if matches!(next, '{' | '}' | '|') || (next == '#' && in_plural) =>
+                        {
+                            self.parse_quoted(&mut literal)?
+                        }
+                        _ => literal.push('\''),
+                    }
+                }
+                _ => {
+                    self.bump();
+                    literal.push(c);
+                }
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Ok(parts)
+    }
+
+    /// Parses quoted text after its opening apostrophe, up to and including the closing one.
+    fn parse_quoted(&mut self, literal: &mut String) -> Result<(), Error> {
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated quoted text")),
+                Some('\'') if self.peek() == Some('\'') => {
+                    self.bump();
+                    literal.push('\'');
+                }
+                Some('\'') => return Ok(()),
+                Some(c) => literal.push(c),
+            }
+        }
+    }
+
+    /// Parses an argument after its opening `{`, up to and including the closing `}`.
+    fn parse_argument(&mut self, in_plural: bool) -> Result<Argument, Error> {
+        self.skip_whitespace();
+        let name = self.parse_identifier("expected an argument name")?.into();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Argument {
+                name,
+                kind: ArgumentKind::Simple,
+            });
+        }
+        self.expect(',', "expected ',' or '}'")?;
+        self.skip_whitespace();
+        let start = self.offset;
+        let kind = match self.parse_identifier("expected an argument type")? {
+            "number" => {
+                self.skip_whitespace();
+                self.expect('}', "number styles are not supported")?;
+                return Ok(Argument {
+                    name,
+                    kind: ArgumentKind::Number,
+                });
+            }
+            "plural" => {
+                self.skip_whitespace();
+                self.expect(',', "expected ','")?;
+                self.parse_plural(PluralRuleType::Cardinal)?
+            }
+            "selectordinal" => {
+                self.skip_whitespace();
+                self.expect(',', "expected ','")?;
+                self.parse_plural(PluralRuleType::Ordinal)?
+            }
+            "select" => {
+                self.skip_whitespace();
+                self.expect(',', "expected ','")?;
+                self.parse_select(in_plural)?
+            }
+            _ => {
+                return Err(Error::Syntax {
+                    offset: start,
+                    reason: "unknown argument type",
+                })
+            }
+        };
+        Ok(Argument { name, kind })
+    }
+
+    /// Parses the branches of a `plural` or `selectordinal` argument, up to and including the
+    /// closing `}`.
+    fn parse_plural(&mut self, rule_type: PluralRuleType) -> Result<ArgumentKind, Error> {
+        self.skip_whitespace();
+        let mut offset = 0;
+        if self.pattern[self.offset..].starts_with("offset:") {
+            self.offset += "offset:".len();
+            self.skip_whitespace();
+            let start = self.offset;
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.bump();
+            }
+            offset = self.pattern[start..self.offset]
+                .parse()
+                .map_err(|_| self.error("expected an offset"))?;
+        }
+        let mut branches: Vec<(PluralSelector, Vec<Part>)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                None => return Err(self.error("unclosed argument")),
+                _ => {}
+            }
+            let start = self.offset;
+            let selector = if self.peek() == Some('=') {
+                self.bump();
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.' || c == '-') {
+                    self.bump();
+                }
+                let value: FixedDecimal = self.pattern[start + 1..self.offset]
+                    .parse()
+                    .map_err(|_| self.error("invalid explicit value"))?;
+                PluralSelector::Exact(normalized(&value))
+            } else {
+                let keyword = self.parse_identifier("expected a selector")?;
+                PluralSelector::Category(PluralCategory::from_tr35_string(keyword).ok_or(
+                    Error::Syntax {
+                        offset: start,
+                        reason: "unknown plural category",
+                    },
+                )?)
+            };
+            if branches.iter().any(|(existing, _)| *existing == selector) {
+                return Err(Error::Syntax {
+                    offset: start,
+                    reason: "duplicate selector",
+                });
+            }
+            self.skip_whitespace();
+            self.expect('{', "expected '{'")?;
+            let message = self.parse_message(true, true)?;
+            self.expect('}', "expected '}'")?;
+            branches.push((selector, message));
+        }
+        if !branches
+            .iter()
+            .any(|(selector, _)| *selector == PluralSelector::Category(PluralCategory::Other))
+        {
+            return Err(self.error("missing 'other' branch"));
+        }
+        Ok(ArgumentKind::Plural {
+            rule_type,
+            offset,
+            branches,
+        })
+    }
+
+    /// Parses the branches of a `select` argument, up to and including the closing `}`.
+    fn parse_select(&mut self, in_plural: bool) -> Result<ArgumentKind, Error> {
+        let mut branches: Vec<(String, Vec<Part>)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                None => return Err(self.error("unclosed argument")),
+                _ => {}
+            }
+            let start = self.offset;
+            let keyword = self.parse_identifier("expected a selector")?;
+            if branches.iter().any(|(existing, _)| existing == keyword) {
+                return Err(Error::Syntax {
+                    offset: start,
+                    reason: "duplicate selector",
+                });
+            }
+            self.skip_whitespace();
+            self.expect('{', "expected '{'")?;
+            let message = self.parse_message(in_plural, true)?;
+            self.expect('}', "expected '}'")?;
+            branches.push((keyword.into(), message));
+        }
+        if !branches.iter().any(|(keyword, _)| keyword == "other") {
+            return Err(self.error("missing 'other' branch"));
+        }
+        Ok(ArgumentKind::Select { branches })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literal(text: &str) -> Part {
+        Part::Literal(text.into())
+    }
+
+    fn simple(name: &str) -> Part {
+        Part::Argument(Argument {
+            name: name.into(),
+            kind: ArgumentKind::Simple,
+        })
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(
+            parse("Hello, {name}! You have { count , number } messages.").unwrap(),
+            vec![
+                literal("Hello, "),
+                simple("name"),
+                literal("! You have "),
+                Part::Argument(Argument {
+                    name: "count".into(),
+                    kind: ArgumentKind::Number,
+                }),
+                literal(" messages."),
+            ]
+        );
+        assert_eq!(parse("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(
+            parse("don't '{name}' ''{name}'' '#'").unwrap(),
+            vec![literal("don't {name} '"), simple("name"), literal("' '#'"),]
+        );
+        assert_eq!(
+            parse("{n, plural, other {'#' is #, it''s '{''}'}}").unwrap(),
+            vec![Part::Argument(Argument {
+                name: "n".into(),
+                kind: ArgumentKind::Plural {
+                    rule_type: PluralRuleType::Cardinal,
+                    offset: 0,
+                    branches: vec![(
+                        PluralSelector::Category(PluralCategory::Other),
+                        vec![literal("# is "), Part::Pound, literal(", it's {'}")],
+                    )],
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn test_plural_and_select() {
+        assert_eq!(
+            parse("{g, select, female {{n, selectordinal, offset:1 =0 {none} one {#st} other {#}}} other {}}")
+                .unwrap(),
+            vec![Part::Argument(Argument {
+                name: "g".into(),
+                kind: ArgumentKind::Select {
+                    branches: vec![
+                        (
+                            "female".into(),
+                            vec![Part::Argument(Argument {
+                                name: "n".into(),
+                                kind: ArgumentKind::Plural {
+                                    rule_type: PluralRuleType::Ordinal,
+                                    offset: 1,
+                                    branches: vec![
+                                        (PluralSelector::Exact("0".into()), vec![literal("none")]),
+                                        (
+                                            PluralSelector::Category(PluralCategory::One),
+                                            vec![Part::Pound, literal("st")],
+                                        ),
+                                        (
+                                            PluralSelector::Category(PluralCategory::Other),
+                                            vec![Part::Pound],
+                                        ),
+                                    ],
+                                },
+                            })],
+                        ),
+                        ("other".into(), vec![]),
+                    ],
+                },
+            })]
+        );
+        // '#' is only special in plural branches
+        assert_eq!(
+            parse("{g, select, other {#}}").unwrap(),
+            vec![Part::Argument(Argument {
+                name: "g".into(),
+                kind: ArgumentKind::Select {
+                    branches: vec![("other".into(), vec![literal("#")])],
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn test_normalized() {
+        for (input, expected) in [
+            ("1", "1"),
+            ("1.50", "1.5"),
+            ("1.0", "1"),
+            ("0.0", "0"),
+            ("-0", "0"),
+            ("-2.10", "-2.1"),
+            ("100", "100"),
+        ] {
+            let value: FixedDecimal = input.parse().unwrap();
+            assert_eq!(normalized(&value), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for (pattern, offset, reason) in [
+            ("{", 1, "expected an argument name"),
+            ("{name", 5, "expected ',' or '}'"),
+            ("a}", 1, "unmatched '}'"),
+            ("{n, date}", 4, "unknown argument type"),
+            (
+                "{n, number, integer}",
+                10,
+                "number styles are not supported",
+            ),
+            ("{n, plural, one {#}}", 20, "missing 'other' branch"),
+            (
+                "{n, plural, some {#} other {#}}",
+                12,
+                "unknown plural category",
+            ),
+            (
+                "{n, plural, =x {#} other {#}}",
+                13,
+                "invalid explicit value",
+            ),
+            ("{n, plural, offset:x other {#}}", 19, "expected an offset"),
+            ("{n, plural, other {#} other {#}}", 22, "duplicate selector"),
+            (
+                "{n, select, a {x} a {y} other {}}",
+                18,
+                "duplicate selector",
+            ),
+            ("{n, select, other {x}", 21, "unclosed argument"),
+            ("{n, select, other {'{x}}}", 25, "unterminated quoted text"),
+        ] {
+            match parse(pattern) {
+                Err(Error::Syntax {
+                    offset: actual_offset,
+                    reason: actual_reason,
+                }) => assert_eq!(
+                    (actual_offset, actual_reason),
+                    (offset, reason),
+                    "{}",
+                    pattern
+                ),
+                result => panic!("{}: {:?}", pattern, result),
+            }
+        }
+    }
+}
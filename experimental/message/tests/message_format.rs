@@ -0,0 +1,197 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::LanguageIdentifier;
+use icu_locid_macros::langid;
+use icu_message::{ArgumentValue, MessageFormat, MessageFormatError};
+
+fn format(
+    langid: LanguageIdentifier,
+    pattern: &str,
+    arguments: &[(&str, ArgumentValue)],
+) -> String {
+    let provider = icu_testdata::get_provider();
+    MessageFormat::try_new(langid, &provider, pattern)
+        .expect("Data should load successfully")
+        .format(arguments)
+        .expect("The arguments should be valid")
+}
+
+fn decimal(value: &str) -> ArgumentValue<'static> {
+    value
+        .parse::<FixedDecimal>()
+        .expect("The number should be valid")
+        .into()
+}
+
+#[test]
+fn test_placeholders() {
+    assert_eq!(
+        format(
+            langid!("en"),
+            "{name} has {count} points, or {count, number} in total.",
+            &[("name", "Ana".into()), ("count", 12345.into())]
+        ),
+        "Ana has 12,345 points, or 12,345 in total."
+    );
+    assert_eq!(
+        format(langid!("ar-EG"), "{count}", &[("count", 123.into())]),
+        "١٢٣"
+    );
+    assert_eq!(format(langid!("en"), "It''s '{name}'", &[]), "It's {name}");
+}
+
+#[test]
+fn test_plural() {
+    let pattern = "{count, plural, one {# file} other {# files}}";
+    assert_eq!(
+        format(langid!("en"), pattern, &[("count", 1.into())]),
+        "1 file"
+    );
+    assert_eq!(
+        format(langid!("en"), pattern, &[("count", 0.into())]),
+        "0 files"
+    );
+    // Visible fraction digits change the plural category
+    assert_eq!(
+        format(langid!("en"), pattern, &[("count", decimal("1.0"))]),
+        "1.0 files"
+    );
+    assert_eq!(
+        format(langid!("en"), pattern, &[("count", decimal("-1"))]),
+        "-1 file"
+    );
+
+    let pattern = "{count, plural, one {# fichier} other {# fichiers}}";
+    assert_eq!(
+        format(langid!("fr"), pattern, &[("count", decimal("1.5"))]),
+        "1,5 fichier"
+    );
+    // French selects "many" for 1000000, which falls back to "other"
+    assert_eq!(
+        format(langid!("fr"), pattern, &[("count", 1000000.into())]),
+        "1\u{202F}000\u{202F}000 fichiers"
+    );
+}
+
+#[test]
+fn test_plural_offset() {
+    let pattern = "{count, plural, offset:1 \
+        =0 {Nobody is here.} \
+        =1 {{host} is here.} \
+        one {{host} and # other are here.} \
+        other {{host} and # others are here.}}";
+    let expected = [
+        (0, "Nobody is here."),
+        (1, "Ana is here."),
+        (2, "Ana and 1 other are here."),
+        (5, "Ana and 4 others are here."),
+    ];
+    for (count, expected) in expected {
+        assert_eq!(
+            format(
+                langid!("en"),
+                pattern,
+                &[("count", count.into()), ("host", "Ana".into())]
+            ),
+            expected
+        );
+    }
+    // Exact matches use the number before the offset is subtracted
+    assert_eq!(
+        format(
+            langid!("en"),
+            pattern,
+            &[("count", decimal("1.00")), ("host", "Ana".into())]
+        ),
+        "Ana is here."
+    );
+}
+
+#[test]
+fn test_selectordinal() {
+    let pattern = "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+    let expected = [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (21, "21st"),
+        (112, "112th"),
+    ];
+    for (n, expected) in expected {
+        assert_eq!(format(langid!("en"), pattern, &[("n", n.into())]), expected);
+    }
+}
+
+#[test]
+fn test_select() {
+    let pattern = "{gender, select, \
+        female {{count, plural, one {She has # cat.} other {She has # cats.}}} \
+        male {{count, plural, one {He has # cat.} other {He has # cats.}}} \
+        other {{count, plural, one {They have # cat.} other {They have # cats.}}}}";
+    assert_eq!(
+        format(
+            langid!("en"),
+            pattern,
+            &[("gender", "female".into()), ("count", 1.into())]
+        ),
+        "She has 1 cat."
+    );
+    assert_eq!(
+        format(
+            langid!("en"),
+            pattern,
+            &[("gender", "unknown".into()), ("count", 2.into())]
+        ),
+        "They have 2 cats."
+    );
+
+    // '#' in a select branch refers to the enclosing plural argument
+    assert_eq!(
+        format(
+            langid!("en"),
+            "{count, plural, other {{unit, select, km {# km} other {# {unit}}}}}",
+            &[("count", 3.into()), ("unit", "mi".into())]
+        ),
+        "3 mi"
+    );
+}
+
+#[test]
+fn test_errors() {
+    let provider = icu_testdata::get_provider();
+
+    assert!(matches!(
+        MessageFormat::try_new(langid!("en"), &provider, "{count, plural, one {#}}"),
+        Err(MessageFormatError::Syntax { offset: 24, .. })
+    ));
+
+    let message = MessageFormat::try_new(
+        langid!("en"),
+        &provider,
+        "{name}: {count, plural, other {#}}",
+    )
+    .expect("Data should load successfully");
+    assert!(matches!(
+        message.format(&[("count", 1.into())]),
+        Err(MessageFormatError::MissingArgument(name)) if name == "name"
+    ));
+    assert!(matches!(
+        message.format(&[("name", "Ana".into()), ("count", "one".into())]),
+        Err(MessageFormatError::InvalidArgument(name)) if name == "count"
+    ));
+    assert_eq!(
+        message
+            .format(&[
+                ("name", "Ana".into()),
+                ("count", 1.into()),
+                ("extra", 2.into())
+            ])
+            .expect("Unused arguments are ignored"),
+        "Ana: 1"
+    );
+}
@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_provider_fs::FsDataProvider;
+
+// Mostly-Latin text: English, and French with a few accented letters.
+const EN: &str = "The quick brown fox jumps over the lazy dog. Pack my box with five dozen liquor jugs. How vexingly quick daft zebras jump! Sphinx of black quartz, judge my vow.";
+const FR: &str = "Le c\u{0153}ur d\u{00E9}\u{00E7}u mais l'\u{00E2}me plut\u{00F4}t na\u{00EF}ve, Lou\u{00FF}s r\u{00EA}va de crapa\u{00FC}ter en cano\u{00EB} au del\u{00E0} des \u{00EE}les, pr\u{00E8}s du m\u{00E4}lstr\u{00F6}m o\u{00F9} br\u{00FB}lent les nov\u{00E6}.";
+
+fn get_provider() -> FsDataProvider {
+    FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist")
+}
+
+fn nfc_bench(c: &mut Criterion) {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+    let fr_nfd = nfd.normalize(FR);
+
+    let mut group = c.benchmark_group("normalizer/nfc");
+    group.bench_function("normalize/en", |b| b.iter(|| nfc.normalize(black_box(EN))));
+    group.bench_function("normalize/fr", |b| b.iter(|| nfc.normalize(black_box(FR))));
+    group.bench_function("normalize/fr_nfd", |b| {
+        b.iter(|| nfc.normalize(black_box(&fr_nfd)))
+    });
+    group.bench_function("is_normalized/en", |b| {
+        b.iter(|| nfc.is_normalized(black_box(EN)))
+    });
+    group.bench_function("is_normalized/fr", |b| {
+        b.iter(|| nfc.is_normalized(black_box(FR)))
+    });
+    group.finish();
+}
+
+fn fcd_bench(c: &mut Criterion) {
+    let provider = get_provider();
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+    let fr_nfd = nfd.normalize(FR);
+
+    let mut group = c.benchmark_group("normalizer/fcd");
+    group.bench_function("is_fcd/en", |b| b.iter(|| nfd.is_fcd(black_box(EN))));
+    group.bench_function("is_fcd/fr", |b| b.iter(|| nfd.is_fcd(black_box(FR))));
+    group.bench_function("is_fcd/fr_nfd", |b| {
+        b.iter(|| nfd.is_fcd(black_box(&fr_nfd)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, nfc_bench, fcd_bench);
+criterion_main!(benches);
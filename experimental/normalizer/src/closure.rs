@@ -0,0 +1,187 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::decomposing::DecomposingNormalizer;
+use crate::error::Error;
+use crate::hangul;
+use crate::provider::*;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+
+/// An enumerator of the strings that are canonically equivalent to a string, like the
+/// `CanonicalIterator` of ICU4C.
+///
+/// This is needed to search text or to match regular expressions regardless of normalization,
+/// for example to find all the ways that the characters of a pattern can be written.
+///
+/// The number of equivalent strings grows quickly with the number of combining marks, so this
+/// is meant for short strings, such as single characters or grapheme clusters.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::CanonicalClosure;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let closure = CanonicalClosure::try_new(&provider).expect("Data should load successfully");
+///
+/// // "Å" as the letter A with ring above, as the Angstrom sign, and decomposed
+/// assert_eq!(
+///     closure.equivalents("\u{00C5}"),
+///     ["A\u{030A}", "\u{00C5}", "\u{212B}"]
+/// );
+/// // U+1EAD LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+/// assert_eq!(
+///     closure.equivalents("\u{1EAD}"),
+///     [
+///         "a\u{0302}\u{0323}",
+///         "a\u{0323}\u{0302}",
+///         "\u{00E2}\u{0323}",
+///         "\u{1EA1}\u{0302}",
+///         "\u{1EAD}",
+///     ]
+/// );
+/// ```
+pub struct CanonicalClosure<'data> {
+    nfd: DecomposingNormalizer<'data>,
+}
+
+impl<'data> CanonicalClosure<'data> {
+    /// Creates an enumerator of canonically equivalent strings from the canonical decomposition
+    /// data.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            nfd: DecomposingNormalizer::try_new_nfd(data_provider)?,
+        })
+    }
+
+    /// Returns all the strings that are canonically equivalent to `text`, including `text`
+    /// itself, sorted by code point.
+    pub fn equivalents(&self, text: &str) -> Vec<String> {
+        let target: Vec<char> = self.nfd.normalize_iter(text.chars()).collect();
+        let mut remaining = BTreeMap::new();
+        for &c in &target {
+            *remaining.entry(c).or_insert(0) += 1;
+        }
+        let candidates = self.candidates(&remaining);
+        let mut equivalents = Vec::new();
+        self.search(
+            &target,
+            &candidates,
+            &mut remaining,
+            &mut String::new(),
+            &mut equivalents,
+        );
+        equivalents.sort();
+        equivalents
+    }
+
+    /// Returns the characters whose full canonical decomposition only contains the characters
+    /// of `counts`, each at most as many times as it is counted, along with that decomposition.
+    fn candidates(&self, counts: &BTreeMap<char, usize>) -> Vec<(char, Vec<char>)> {
+        let mut candidates: BTreeMap<char, Vec<char>> =
+            counts.keys().map(|&c| (c, vec![c])).collect();
+        for (c, decomposition) in self.nfd.canonical_decompositions() {
+            let decomposition: Vec<char> = decomposition.chars().collect();
+            if fits(&decomposition, counts) {
+                candidates.insert(c, decomposition);
+            }
+        }
+        // Hangul syllables are composed algorithmically from the jamo.
+        for &l in counts.keys() {
+            for &v in counts.keys() {
+                if let Some(lv) = hangul::compose(l, v) {
+                    for &t in counts.keys() {
+                        if let Some(lvt) = hangul::compose(lv, t) {
+                            candidates.insert(lvt, vec![l, v, t]);
+                        }
+                    }
+                    candidates.insert(lv, vec![l, v]);
+                }
+            }
+        }
+        candidates
+            .into_iter()
+            .filter(|(_, decomposition)| fits(decomposition, counts))
+            .collect()
+    }
+
+    /// Appends to `equivalents` the strings that start with `prefix`, continue with
+    /// `candidates`, and whose NFD form is `target`, where `remaining` counts the characters of
+    /// `target` that are not in the decomposition of `prefix`.
+    fn search(
+        &self,
+        target: &[char],
+        candidates: &[(char, Vec<char>)],
+        remaining: &mut BTreeMap<char, usize>,
+        prefix: &mut String,
+        equivalents: &mut Vec<String>,
+    ) {
+        if remaining.values().all(|&count| count == 0) {
+            if self
+                .nfd
+                .normalize_iter(prefix.chars())
+                .eq(target.iter().copied())
+            {
+                equivalents.push(prefix.clone());
+            }
+            return;
+        }
+        for (c, decomposition) in candidates {
+            if !fits(decomposition, remaining) {
+                continue;
+            }
+            for d in decomposition {
+                if let Some(count) = remaining.get_mut(d) {
+                    *count -= 1;
+                }
+            }
+            prefix.push(*c);
+            if self.is_viable(prefix, target) {
+                self.search(target, candidates, remaining, prefix, equivalents);
+            }
+            prefix.pop();
+            for d in decomposition {
+                if let Some(count) = remaining.get_mut(d) {
+                    *count += 1;
+                }
+            }
+        }
+    }
+
+    /// Returns whether `prefix` can start a string whose NFD form is `target`.
+    ///
+    /// The characters of the NFD form of `prefix` up to its last starter are not reordered by
+    /// the characters that follow, so they must already be the ones of `target`.
+    fn is_viable(&self, prefix: &str, target: &[char]) -> bool {
+        let decomposed: Vec<char> = self.nfd.normalize_iter(prefix.chars()).collect();
+        let stable = decomposed
+            .iter()
+            .rposition(|&c| self.nfd.canonical_combining_class(c) == 0)
+            .map_or(0, |i| i + 1);
+        target.get(..stable) == Some(&decomposed[..stable])
+    }
+}
+
+/// Returns whether every character of `chars` is counted at least as many times in `counts` as
+/// it appears in `chars`.
+fn fits(chars: &[char], counts: &BTreeMap<char, usize>) -> bool {
+    chars.iter().all(|c| {
+        let needed = chars.iter().filter(|&d| d == c).count();
+        counts.get(c).map_or(false, |&count| count >= needed)
+    })
+}
@@ -0,0 +1,105 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::decomposing::DecomposingNormalizer;
+use crate::error::Error;
+use crate::provider::*;
+use core::cmp::Ordering;
+use icu_provider::prelude::*;
+
+/// A comparator for strings up to canonical equivalence, and optionally up to case, such as
+/// identifiers and file names.
+///
+/// The strings are compared by their NFD forms, which are computed incrementally and only as
+/// far as the first difference, so that strings that differ early are compared without
+/// normalizing them entirely.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use icu_normalizer::CanonicalComparator;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let comparator = CanonicalComparator::try_new(&provider).expect("Data should load successfully");
+///
+/// assert_eq!(
+///     comparator.canonical_compare("\u{00E9}t\u{00E9}", "e\u{0301}te\u{0301}"),
+///     Ordering::Equal
+/// );
+/// assert_eq!(
+///     comparator.canonical_compare("\u{00E9}t\u{00E9}", "\u{00C9}t\u{00E9}"),
+///     Ordering::Greater
+/// );
+///
+/// assert!(comparator.canonical_caseless_eq("\u{00E9}t\u{00E9}", "E\u{0301}T\u{00C9}"));
+/// assert!(comparator.canonical_caseless_eq("Stra\u{00DF}e", "STRASSE"));
+/// assert!(!comparator.canonical_caseless_eq("\u{00E9}t\u{00E9}", "ete"));
+/// ```
+pub struct CanonicalComparator<'data> {
+    nfd: DecomposingNormalizer<'data>,
+    casefold: DecomposingNormalizer<'data>,
+}
+
+impl<'data> CanonicalComparator<'data> {
+    /// Creates a comparator from the canonical decomposition and case folding data.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, DecompositionSupplementV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            nfd: DecomposingNormalizer::try_new_nfd(data_provider)?,
+            casefold: DecomposingNormalizer::try_new_with_supplement(
+                data_provider,
+                key::CANONICAL_CASEFOLD_V1,
+            )?,
+        })
+    }
+
+    /// Compares the NFD forms of `a` and `b` by code point, so that canonically equivalent
+    /// strings compare equal.
+    pub fn canonical_compare(&self, a: &str, b: &str) -> Ordering {
+        if a == b {
+            return Ordering::Equal;
+        }
+        self.nfd
+            .normalize_iter(a.chars())
+            .cmp(self.nfd.normalize_iter(b.chars()))
+    }
+
+    /// Compares the canonical caseless forms of `a` and `b` by code point, so that strings that
+    /// only differ by case and canonical equivalence compare equal.
+    ///
+    /// The canonical caseless form of a string is the NFD form of the full case folding of its
+    /// NFD form, see definition D145 of the [Unicode Standard].
+    ///
+    /// [Unicode Standard]: https://www.unicode.org/versions/Unicode14.0.0/ch03.pdf#G34145
+    pub fn canonical_caseless_compare(&self, a: &str, b: &str) -> Ordering {
+        if a == b {
+            return Ordering::Equal;
+        }
+        self.casefold
+            .normalize_iter(self.nfd.normalize_iter(a.chars()))
+            .cmp(
+                self.casefold
+                    .normalize_iter(self.nfd.normalize_iter(b.chars())),
+            )
+    }
+
+    /// Returns whether `a` and `b` are canonical caseless matches, i.e. whether they only differ
+    /// by case and canonical equivalence.
+    ///
+    /// See [`Self::canonical_caseless_compare()`].
+    pub fn canonical_caseless_eq(&self, a: &str, b: &str) -> bool {
+        self.canonical_caseless_compare(a, b) == Ordering::Equal
+    }
+}
@@ -0,0 +1,396 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::decomposing::{DecomposingNormalizer, DecompositionState};
+use crate::error::Error;
+use crate::mapping::CanonicalComposition;
+use crate::provider::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_provider::prelude::*;
+
+/// A normalizer for transforming text into a composed Unicode Normalization Form, either NFC or
+/// NFKC, or into its NFKC_Casefold form.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::ComposingNormalizer;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+///
+/// assert_eq!(nfc.normalize("e\u{0301}te\u{0301}"), "\u{00E9}t\u{00E9}");
+/// assert!(nfc.is_normalized("\u{00E9}t\u{00E9}"));
+/// assert!(!nfc.is_normalized("e\u{0301}te\u{0301}"));
+/// ```
+pub struct ComposingNormalizer<'data> {
+    decomposing: DecomposingNormalizer<'data>,
+    composition: CanonicalComposition<'data>,
+}
+
+impl<'data> ComposingNormalizer<'data> {
+    /// Creates a normalizer for Normalization Form C (canonical decomposition followed by
+    /// canonical composition).
+    pub fn try_new_nfc<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, CanonicalCompositionV1Marker>
+            + ?Sized,
+    {
+        let decomposing = DecomposingNormalizer::try_new_nfd(data_provider)?;
+        Self::try_new_with_decomposing(data_provider, decomposing)
+    }
+
+    /// Creates a normalizer for Normalization Form KC (compatibility decomposition followed by
+    /// canonical composition).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfkc = ComposingNormalizer::try_new_nfkc(&provider).expect("Data should load successfully");
+    ///
+    /// // U+1E9B LATIN SMALL LETTER LONG S WITH DOT ABOVE, U+0323 COMBINING DOT BELOW
+    /// assert_eq!(nfkc.normalize("\u{1E9B}\u{0323}"), "\u{1E69}");
+    /// ```
+    pub fn try_new_nfkc<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, DecompositionSupplementV1Marker>
+            + DataProvider<'data, CanonicalCompositionV1Marker>
+            + ?Sized,
+    {
+        let decomposing = DecomposingNormalizer::try_new_nfkd(data_provider)?;
+        Self::try_new_with_decomposing(data_provider, decomposing)
+    }
+
+    /// Creates a normalizer that applies the NFKC_Casefold mapping, which combines NFKC, case
+    /// folding, and the removal of default ignorable code points.
+    ///
+    /// This is the mapping used for caseless identifier matching, see [`UTS #39`] and
+    /// [`UTS #46`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfkc_cf =
+    ///     ComposingNormalizer::try_new_nfkc_casefold(&provider).expect("Data should load successfully");
+    ///
+    /// // Fullwidth letters, sharp s, and a soft hyphen
+    /// assert_eq!(nfkc_cf.normalize("\u{FF33}tra\u{00AD}\u{00DF}e"), "strasse");
+    /// ```
+    ///
+    /// [`UTS #39`]: https://www.unicode.org/reports/tr39/
+    /// [`UTS #46`]: https://www.unicode.org/reports/tr46/
+    pub fn try_new_nfkc_casefold<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, DecompositionSupplementV1Marker>
+            + DataProvider<'data, CanonicalCompositionV1Marker>
+            + ?Sized,
+    {
+        let decomposing =
+            DecomposingNormalizer::try_new_with_supplement(data_provider, key::NFKC_CASEFOLD_V1)?;
+        Self::try_new_with_decomposing(data_provider, decomposing)
+    }
+
+    fn try_new_with_decomposing<D>(
+        data_provider: &D,
+        decomposing: DecomposingNormalizer<'data>,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCompositionV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            decomposing,
+            composition: CanonicalComposition::try_new(data_provider)?,
+        })
+    }
+
+    /// Returns the normalized form of `text`.
+    pub fn normalize(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        self.normalize_to(text, &mut output)
+            .expect("impl Write for String is infallible");
+        output
+    }
+
+    /// Writes the normalized form of `text` to `sink`.
+    pub fn normalize_to<W: fmt::Write + ?Sized>(&self, text: &str, sink: &mut W) -> fmt::Result {
+        let (stable, rest) = text.split_at(self.stable_prefix_len(text));
+        sink.write_str(stable)?;
+        let mut writer = self.writer(sink);
+        fmt::Write::write_str(&mut writer, rest)?;
+        writer.finish().map(|_| ())
+    }
+
+    /// Returns an iterator over the normalized form of `chars`.
+    ///
+    /// The input is consumed lazily: the characters are returned as soon as they can no longer
+    /// be affected by the characters that follow them, which is the case once a starter that
+    /// does not compose with the preceding characters has been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+    ///
+    /// let mut composition = nfc.normalize_iter("e\u{0301}te\u{0301}".chars());
+    /// assert_eq!(composition.next(), Some('\u{00E9}'));
+    /// assert_eq!(composition.collect::<String>(), "t\u{00E9}");
+    /// ```
+    pub fn normalize_iter<I: Iterator<Item = char>>(&self, chars: I) -> Composition<'_, 'data, I> {
+        Composition {
+            normalizer: self,
+            chars,
+            state: CompositionState::default(),
+            output: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Returns an adapter that writes the normalized form of the text written to it to `sink`.
+    ///
+    /// Since the end of the text can affect the normalized form of the characters before it,
+    /// some of the text is buffered by the adapter; call [`ComposingWriter::finish()`] once all
+    /// of the text has been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+    ///
+    /// let mut output = String::new();
+    /// let mut writer = nfc.writer(&mut output);
+    /// // The accent is composed with the last letter of the first chunk.
+    /// writer.write_str("Cafe").expect("Writing to a String should succeed");
+    /// writer.write_str("\u{0301}").expect("Writing to a String should succeed");
+    /// writer.finish().expect("Writing to a String should succeed");
+    ///
+    /// assert_eq!(output, "Caf\u{00E9}");
+    /// ```
+    pub fn writer<W: fmt::Write>(&self, sink: W) -> ComposingWriter<'_, 'data, W> {
+        ComposingWriter {
+            normalizer: self,
+            sink,
+            state: CompositionState::default(),
+        }
+    }
+
+    /// Returns whether `text` is already in the normalized form.
+    pub fn is_normalized(&self, text: &str) -> bool {
+        let rest = &text[self.stable_prefix_len(text)..];
+        // Text in NFC is always FCD, so only text that is FCD needs to be recomposed.
+        self.decomposing.is_fcd(rest) && self.normalize_iter(rest.chars()).eq(rest.chars())
+    }
+
+    /// Returns whether `text` satisfies the FCD ("Fast C or D") condition, see
+    /// [`DecomposingNormalizer::is_fcd()`].
+    pub fn is_fcd(&self, text: &str) -> bool {
+        self.decomposing.is_fcd(text)
+    }
+
+    /// Returns the length of the longest prefix of `text` that is normalized and that cannot be
+    /// changed by the text that follows it.
+    ///
+    /// The characters below U+0300 are starters that do not compose with the preceding
+    /// characters, so a run of them that are not decomposed is normalized, except for its last
+    /// character, which may compose with the combining marks that follow the run. This makes
+    /// mostly-Latin text fast to normalize.
+    fn stable_prefix_len(&self, text: &str) -> usize {
+        let mut last_start = 0;
+        for (start, c) in text.char_indices() {
+            if c >= '\u{0300}' || self.decomposing.is_decomposed(c) {
+                return last_start;
+            }
+            last_start = start;
+        }
+        text.len()
+    }
+
+    fn compose_pair(&self, first: char, second: char) -> Option<char> {
+        self.composition.compose(first, second)
+    }
+}
+
+/// An iterator over the composed form of a sequence of characters.
+///
+/// This struct is created by [`ComposingNormalizer::normalize_iter()`].
+pub struct Composition<'n, 'data, I> {
+    normalizer: &'n ComposingNormalizer<'data>,
+    chars: I,
+    state: CompositionState,
+    output: Vec<char>,
+    position: usize,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Composition<'_, '_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.position == self.output.len() {
+            self.output.clear();
+            self.position = 0;
+            let output = &mut self.output;
+            match self.chars.next() {
+                Some(c) => self.state.push(self.normalizer, c, |c| output.push(c)),
+                None => {
+                    self.state.finish(self.normalizer, |c| output.push(c));
+                    if output.is_empty() {
+                        return None;
+                    }
+                }
+            }
+        }
+        self.position += 1;
+        Some(self.output[self.position - 1])
+    }
+}
+
+/// An adapter that composes the text written to it and writes the result to a sink.
+///
+/// This struct is created by [`ComposingNormalizer::writer()`].
+pub struct ComposingWriter<'n, 'data, W> {
+    normalizer: &'n ComposingNormalizer<'data>,
+    sink: W,
+    state: CompositionState,
+}
+
+impl<W: fmt::Write> ComposingWriter<'_, '_, W> {
+    /// Writes the buffered text to the sink, and returns the sink.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        let sink = &mut self.sink;
+        let mut result = Ok(());
+        self.state.finish(self.normalizer, |c| {
+            if result.is_ok() {
+                result = sink.write_char(c);
+            }
+        });
+        result.map(|_| self.sink)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for ComposingWriter<'_, '_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let sink = &mut self.sink;
+        let mut result = Ok(());
+        for c in s.chars() {
+            self.state.push(self.normalizer, c, |c| {
+                if result.is_ok() {
+                    result = sink.write_char(c);
+                }
+            });
+        }
+        result
+    }
+}
+
+/// The state of an incremental composition.
+#[derive(Default)]
+struct CompositionState {
+    decomposition: DecompositionState,
+    composition: PendingComposition,
+}
+
+impl CompositionState {
+    /// Normalizes `c`, and calls `f` with each character of the output that can no longer be
+    /// affected by the characters that follow.
+    fn push(&mut self, normalizer: &ComposingNormalizer, c: char, mut f: impl FnMut(char)) {
+        let composition = &mut self.composition;
+        self.decomposition
+            .push(&normalizer.decomposing, c, |c, ccc| {
+                composition.push(normalizer, c, ccc, &mut f)
+            });
+    }
+
+    /// Calls `f` with each of the remaining characters of the output at the end of the text.
+    fn finish(&mut self, normalizer: &ComposingNormalizer, mut f: impl FnMut(char)) {
+        let composition = &mut self.composition;
+        self.decomposition
+            .finish(|c, ccc| composition.push(normalizer, c, ccc, &mut f));
+        composition.finish(f);
+    }
+}
+
+/// The characters since the last starter, which can still be composed with the characters that
+/// follow.
+#[derive(Default)]
+struct PendingComposition {
+    chars: Vec<char>,
+    /// Whether the first character is a starter.
+    has_starter: bool,
+    /// The canonical combining class of the last character, if it is not the starter.
+    last_ccc: Option<u8>,
+}
+
+impl PendingComposition {
+    /// Applies the Canonical Composition Algorithm to the next character of the canonically
+    /// ordered decomposition, and calls `f` with the characters that can no longer change.
+    fn push(&mut self, normalizer: &ComposingNormalizer, c: char, ccc: u8, f: impl FnMut(char)) {
+        // `c` is composed with the last starter unless a character with the same or a higher
+        // combining class comes between them.
+        if self.has_starter && !matches!(self.last_ccc, Some(last_ccc) if last_ccc >= ccc) {
+            if let Some(composed) = normalizer.compose_pair(self.chars[0], c) {
+                self.chars[0] = composed;
+                return;
+            }
+        }
+        if ccc == 0 {
+            self.chars.drain(..).for_each(f);
+            self.has_starter = true;
+            self.last_ccc = None;
+        } else {
+            self.last_ccc = Some(ccc);
+        }
+        self.chars.push(c);
+    }
+
+    fn finish(&mut self, f: impl FnMut(char)) {
+        self.chars.drain(..).for_each(f);
+        self.has_starter = false;
+        self.last_ccc = None;
+    }
+}
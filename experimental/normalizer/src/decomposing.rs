@@ -0,0 +1,448 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::hangul;
+use crate::provider::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+
+/// A normalizer for transforming text into a decomposed Unicode Normalization Form, either NFD
+/// or NFKD.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::DecomposingNormalizer;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+///
+/// assert_eq!(nfd.normalize("\u{00E9}t\u{00E9}"), "e\u{0301}te\u{0301}");
+/// assert!(nfd.is_normalized("e\u{0301}te\u{0301}"));
+/// assert!(!nfd.is_normalized("\u{00E9}t\u{00E9}"));
+/// ```
+pub struct DecomposingNormalizer<'data> {
+    ccc: DataPayload<'data, CanonicalCombiningClassV1Marker>,
+    decompositions: DataPayload<'data, CanonicalDecompositionV1Marker>,
+    supplement: Option<DataPayload<'data, DecompositionSupplementV1Marker>>,
+}
+
+impl<'data> DecomposingNormalizer<'data> {
+    /// Creates a normalizer for Normalization Form D (canonical decomposition).
+    pub fn try_new_nfd<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let ccc: DataPayload<CanonicalCombiningClassV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::CANONICAL_COMBINING_CLASS_V1))?
+            .take_payload()?;
+        let decompositions: DataPayload<CanonicalDecompositionV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::CANONICAL_DECOMPOSITION_V1))?
+            .take_payload()?;
+        Ok(Self {
+            ccc,
+            decompositions,
+            supplement: None,
+        })
+    }
+
+    /// Creates a normalizer for Normalization Form KD (compatibility decomposition).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfkd = DecomposingNormalizer::try_new_nfkd(&provider).expect("Data should load successfully");
+    ///
+    /// assert_eq!(nfkd.normalize("\u{FB01}\u{00B2}"), "fi2");
+    /// ```
+    pub fn try_new_nfkd<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, DecompositionSupplementV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_supplement(data_provider, key::COMPATIBILITY_DECOMPOSITION_V1)
+    }
+
+    /// Creates a normalizer that decomposes characters to their canonical decomposition, except
+    /// for the characters in the [`DecompositionSupplementV1`] loaded for `supplement_key`.
+    pub(crate) fn try_new_with_supplement<D>(
+        data_provider: &D,
+        supplement_key: ResourceKey,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, DecompositionSupplementV1Marker>
+            + ?Sized,
+    {
+        let mut normalizer = Self::try_new_nfd(data_provider)?;
+        let supplement: DataPayload<DecompositionSupplementV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(supplement_key))?
+            .take_payload()?;
+        normalizer.supplement = Some(supplement);
+        Ok(normalizer)
+    }
+
+    /// Returns the normalized form of `text`.
+    pub fn normalize(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        self.normalize_to(text, &mut output)
+            .expect("impl Write for String is infallible");
+        output
+    }
+
+    /// Writes the normalized form of `text` to `sink`.
+    pub fn normalize_to<W: fmt::Write + ?Sized>(&self, text: &str, sink: &mut W) -> fmt::Result {
+        let mut writer = self.writer(sink);
+        fmt::Write::write_str(&mut writer, text)?;
+        writer.finish().map(|_| ())
+    }
+
+    /// Returns an iterator over the normalized form of `chars`.
+    ///
+    /// The input is consumed lazily: each character is returned as soon as it can no longer be
+    /// affected by the canonical reordering of the characters that follow it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+    ///
+    /// let mut decomposition = nfd.normalize_iter("\u{00E9}t\u{00E9}".chars());
+    /// assert_eq!(decomposition.next(), Some('e'));
+    /// assert_eq!(decomposition.next(), Some('\u{0301}'));
+    /// assert_eq!(decomposition.collect::<String>(), "te\u{0301}");
+    /// ```
+    pub fn normalize_iter<I: Iterator<Item = char>>(
+        &self,
+        chars: I,
+    ) -> Decomposition<'_, 'data, I> {
+        Decomposition {
+            normalizer: self,
+            chars,
+            state: DecompositionState::default(),
+            output: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Returns an adapter that writes the normalized form of the text written to it to `sink`.
+    ///
+    /// Since the end of the text can affect the normalized form of the characters before it,
+    /// some of the text is buffered by the adapter; call [`DecomposingWriter::finish()`] once all
+    /// of the text has been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+    ///
+    /// let mut output = String::new();
+    /// let mut writer = nfd.writer(&mut output);
+    /// // The dot below is moved before the circumflex of the first chunk.
+    /// writer.write_str("\u{00E2}").expect("Writing to a String should succeed");
+    /// writer.write_str("\u{0323}").expect("Writing to a String should succeed");
+    /// writer.finish().expect("Writing to a String should succeed");
+    ///
+    /// assert_eq!(output, "a\u{0323}\u{0302}");
+    /// ```
+    pub fn writer<W: fmt::Write>(&self, sink: W) -> DecomposingWriter<'_, 'data, W> {
+        DecomposingWriter {
+            normalizer: self,
+            sink,
+            state: DecompositionState::default(),
+        }
+    }
+
+    /// Returns whether `text` is already in the normalized form.
+    ///
+    /// This is faster than comparing `text` to the output of [`Self::normalize()`].
+    pub fn is_normalized(&self, text: &str) -> bool {
+        let mut last_ccc = 0;
+        for c in text.chars() {
+            if self.is_decomposed(c) {
+                return false;
+            }
+            let ccc = self.ccc(c);
+            if ccc != 0 && last_ccc > ccc {
+                return false;
+            }
+            last_ccc = ccc;
+        }
+        true
+    }
+
+    /// Returns whether `text` satisfies the FCD ("Fast C or D") condition: the canonical
+    /// decomposition of each character of `text` can be concatenated to the ones of the other
+    /// characters without reordering the combining marks.
+    ///
+    /// All the strings in NFC or NFD satisfy this condition, but many other strings do too, such
+    /// as "a\u{0301}\u{00E0}"; see [`UTN #5`]. Text that satisfies the condition does not need to
+    /// be normalized for some processes, such as collation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/data"
+    /// ))
+    /// .expect("Data directory should exist");
+    ///
+    /// let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+    ///
+    /// // U+0323 COMBINING DOT BELOW is ordered before the grave accent of U+00E0.
+    /// assert!(nfd.is_fcd("a\u{0323}\u{0300}"));
+    /// assert!(nfd.is_fcd("\u{1EA1}\u{0300}"));
+    /// assert!(!nfd.is_fcd("\u{00E0}\u{0323}"));
+    /// ```
+    ///
+    /// [`UTN #5`]: https://www.unicode.org/notes/tn5/
+    pub fn is_fcd(&self, text: &str) -> bool {
+        // The canonical combining class of the last character of the previous decomposition.
+        let mut last_ccc = 0;
+        for c in text.chars() {
+            let mut first_ccc = None;
+            let mut trailing_ccc = 0;
+            self.decompose_canonical(c, |_, ccc| {
+                first_ccc.get_or_insert(ccc);
+                trailing_ccc = ccc;
+            });
+            // Canonical decompositions are never empty.
+            let first_ccc = first_ccc.unwrap_or(0);
+            if first_ccc != 0 && first_ccc < last_ccc {
+                return false;
+            }
+            last_ccc = trailing_ccc;
+        }
+        true
+    }
+
+    /// Returns whether `c` is changed by the decomposition.
+    pub(crate) fn is_decomposed(&self, c: char) -> bool {
+        hangul::is_syllable(c)
+            || self.decompositions.get().decompositions.contains_key(&c)
+            || self.supplement_decomposition(c).is_some()
+    }
+
+    /// Returns the Canonical_Combining_Class property of `c`, which is zero for starters.
+    ///
+    /// The normalized forms keep the non-starters between two starters sorted by their
+    /// canonical combining class.
+    pub fn canonical_combining_class(&self, c: char) -> u8 {
+        self.ccc(c)
+    }
+
+    /// Returns an iterator over the characters that have a canonical decomposition, along with
+    /// their full canonical decomposition. Hangul syllables are not included.
+    pub(crate) fn canonical_decompositions(&self) -> impl Iterator<Item = (char, &str)> + '_ {
+        self.decompositions
+            .get()
+            .decompositions
+            .iter()
+            .map(|(c, decomposition)| (char::from_unaligned(c), decomposition))
+    }
+
+    /// Returns the canonical combining class of `c`.
+    fn ccc(&self, c: char) -> u8 {
+        self.ccc.get().ccc.get_copied(&c).unwrap_or(0)
+    }
+
+    /// Calls `f` with each character of the full decomposition of `c`, along with its canonical
+    /// combining class.
+    fn decompose(&self, c: char, mut f: impl FnMut(char, u8)) {
+        if let Some(decomposition) = self.supplement_decomposition(c) {
+            for c in decomposition.chars() {
+                f(c, self.ccc(c));
+            }
+        } else {
+            self.decompose_canonical(c, f);
+        }
+    }
+
+    /// Calls `f` with each character of the full canonical decomposition of `c`, along with its
+    /// canonical combining class, ignoring the supplement.
+    fn decompose_canonical(&self, c: char, mut f: impl FnMut(char, u8)) {
+        if hangul::is_syllable(c) {
+            // Jamo are all starters.
+            hangul::decompose(c, |c| f(c, 0));
+        } else if let Some(decomposition) = self.decompositions.get().decompositions.get(&c) {
+            for c in decomposition.chars() {
+                f(c, self.ccc(c));
+            }
+        } else {
+            f(c, self.ccc(c));
+        }
+    }
+
+    fn supplement_decomposition(&self, c: char) -> Option<&str> {
+        self.supplement
+            .as_ref()
+            .and_then(|supplement| supplement.get().decompositions.get(&c))
+    }
+}
+
+/// An iterator over the decomposed form of a sequence of characters.
+///
+/// This struct is created by [`DecomposingNormalizer::normalize_iter()`].
+pub struct Decomposition<'n, 'data, I> {
+    normalizer: &'n DecomposingNormalizer<'data>,
+    chars: I,
+    state: DecompositionState,
+    output: Vec<char>,
+    position: usize,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Decomposition<'_, '_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.position == self.output.len() {
+            self.output.clear();
+            self.position = 0;
+            let output = &mut self.output;
+            match self.chars.next() {
+                Some(c) => self.state.push(self.normalizer, c, |c, _| output.push(c)),
+                None => {
+                    self.state.finish(|c, _| output.push(c));
+                    if output.is_empty() {
+                        return None;
+                    }
+                }
+            }
+        }
+        self.position += 1;
+        Some(self.output[self.position - 1])
+    }
+}
+
+/// An adapter that decomposes the text written to it and writes the result to a sink.
+///
+/// This struct is created by [`DecomposingNormalizer::writer()`].
+pub struct DecomposingWriter<'n, 'data, W> {
+    normalizer: &'n DecomposingNormalizer<'data>,
+    sink: W,
+    state: DecompositionState,
+}
+
+impl<W: fmt::Write> DecomposingWriter<'_, '_, W> {
+    /// Writes the buffered text to the sink, and returns the sink.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        let sink = &mut self.sink;
+        let mut result = Ok(());
+        self.state.finish(|c, _| {
+            if result.is_ok() {
+                result = sink.write_char(c);
+            }
+        });
+        result.map(|_| self.sink)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for DecomposingWriter<'_, '_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let sink = &mut self.sink;
+        let mut result = Ok(());
+        for c in s.chars() {
+            self.state.push(self.normalizer, c, |c, _| {
+                if result.is_ok() {
+                    result = sink.write_char(c);
+                }
+            });
+        }
+        result
+    }
+}
+
+/// The state of an incremental decomposition.
+#[derive(Default)]
+pub(crate) struct DecompositionState {
+    /// The non-starters since the last starter, which can still be reordered by the characters
+    /// that follow, along with their canonical combining class.
+    non_starters: Vec<(char, u8)>,
+}
+
+impl DecompositionState {
+    /// Decomposes `c`, and calls `f` with each character of the output that can no longer be
+    /// affected by the characters that follow, along with its canonical combining class.
+    pub(crate) fn push(
+        &mut self,
+        normalizer: &DecomposingNormalizer,
+        c: char,
+        mut f: impl FnMut(char, u8),
+    ) {
+        let non_starters = &mut self.non_starters;
+        normalizer.decompose(c, |c, ccc| {
+            if ccc == 0 {
+                // Characters are never reordered across a starter.
+                for (c, ccc) in non_starters.drain(..) {
+                    f(c, ccc);
+                }
+                f(c, ccc);
+            } else {
+                insert_ordered(non_starters, c, ccc);
+            }
+        });
+    }
+
+    /// Calls `f` with each of the remaining characters of the output at the end of the text.
+    pub(crate) fn finish(&mut self, mut f: impl FnMut(char, u8)) {
+        for (c, ccc) in self.non_starters.drain(..) {
+            f(c, ccc);
+        }
+    }
+}
+
+/// Appends the non-starter `c` to `non_starters`, moving it before the preceding characters with
+/// a higher canonical combining class.
+///
+/// This is an insertion sort, which keeps characters with the same combining class in their
+/// original order as required by the Canonical Ordering Algorithm.
+fn insert_ordered(non_starters: &mut Vec<(char, u8)>, c: char, ccc: u8) {
+    let mut index = non_starters.len();
+    while index > 0 && non_starters[index - 1].1 > ccc {
+        index -= 1;
+    }
+    non_starters.insert(index, (c, ccc));
+}
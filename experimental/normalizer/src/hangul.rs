@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Algorithmic decomposition and composition of Hangul syllables.
+//!
+//! See section 3.12, "Conjoining Jamo Behavior", of the [Unicode Standard].
+//!
+//! [Unicode Standard]: https://www.unicode.org/versions/Unicode14.0.0/ch03.pdf
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Returns whether `c` is a precomposed Hangul syllable.
+pub(crate) fn is_syllable(c: char) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&(c as u32))
+}
+
+/// Calls `f` with each of the jamo that the Hangul syllable `c` decomposes to.
+///
+/// `c` must be a precomposed Hangul syllable.
+pub(crate) fn decompose(c: char, mut f: impl FnMut(char)) {
+    debug_assert!(is_syllable(c));
+    let s_index = c as u32 - S_BASE;
+    let lead = L_BASE + s_index / N_COUNT;
+    let vowel = V_BASE + (s_index % N_COUNT) / T_COUNT;
+    let trail = T_BASE + s_index % T_COUNT;
+    // The computed values are always valid code points.
+    f(char::from_u32(lead).unwrap_or(c));
+    f(char::from_u32(vowel).unwrap_or(c));
+    if trail != T_BASE {
+        f(char::from_u32(trail).unwrap_or(c));
+    }
+}
+
+/// Composes a leading jamo with a vowel jamo, or an LV syllable with a trailing jamo.
+pub(crate) fn compose(first: char, second: char) -> Option<char> {
+    let first = first as u32;
+    let second = second as u32;
+    if (L_BASE..L_BASE + L_COUNT).contains(&first) && (V_BASE..V_BASE + V_COUNT).contains(&second) {
+        let l_index = first - L_BASE;
+        let v_index = second - V_BASE;
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&first)
+        && (first - S_BASE) % T_COUNT == 0
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&second)
+    {
+        return char::from_u32(first + (second - T_BASE));
+    }
+    None
+}
+
+/// Returns the canonical decomposition mapping of the Hangul syllable `c`: a leading jamo and a
+/// vowel jamo for an LV syllable, or an LV syllable and a trailing jamo for an LVT syllable.
+///
+/// `c` must be a precomposed Hangul syllable.
+pub(crate) fn decompose_pair(c: char) -> (char, char) {
+    debug_assert!(is_syllable(c));
+    let s_index = c as u32 - S_BASE;
+    let t_index = s_index % T_COUNT;
+    // The computed values are always valid code points.
+    if t_index == 0 {
+        let lead = L_BASE + s_index / N_COUNT;
+        let vowel = V_BASE + (s_index % N_COUNT) / T_COUNT;
+        (
+            char::from_u32(lead).unwrap_or(c),
+            char::from_u32(vowel).unwrap_or(c),
+        )
+    } else {
+        let lead_vowel = c as u32 - t_index;
+        let trail = T_BASE + t_index;
+        (
+            char::from_u32(lead_vowel).unwrap_or(c),
+            char::from_u32(trail).unwrap_or(c),
+        )
+    }
+}
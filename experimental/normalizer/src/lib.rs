@@ -0,0 +1,81 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_normalizer`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API transforms text into the Unicode Normalization Forms defined in [`UAX #15`], so that
+//! canonically equivalent strings, such as "é" written as one character or as "e" followed by a
+//! combining acute accent, have the same representation.
+//!
+//! [`DecomposingNormalizer`] produces Normalization Form D (NFD), in which all characters are
+//! decomposed, and [`ComposingNormalizer`] produces Normalization Form C (NFC), in which the
+//! decomposed characters are composed again wherever possible. Both also support the
+//! compatibility forms NFKD and NFKC, which additionally replace characters such as ligatures
+//! and fullwidth letters with their compatibility equivalents, and [`ComposingNormalizer`]
+//! supports the NFKC_Casefold mapping used for comparing identifiers.
+//!
+//! Besides normalizing strings, the normalizers can normalize text incrementally, without
+//! buffering all of it: `normalize_iter()` adapts an iterator of characters, and `writer()`
+//! adapts a [`core::fmt::Write`] sink.
+//!
+//! [`CanonicalComparator`] compares strings up to canonical equivalence, and optionally up to
+//! case, without normalizing them entirely.
+//!
+//! [`CanonicalDecomposition`] and [`CanonicalComposition`] give access to the underlying data,
+//! for the algorithms that decompose and compose characters one at a time.
+//!
+//! [`CanonicalClosure`] enumerates the strings that are canonically equivalent to a short
+//! string, for searching text regardless of its normalization.
+//!
+//! The normalizers are driven by the decomposition and composition data in [`provider`] rather
+//! than by compiled-in tables. All normalizers share the canonical data; the compatibility and
+//! NFKC_Casefold data only contain the characters that are mapped differently.
+//!
+//! The `data` directory of this crate contains that data for the `FsDataProvider`; it is
+//! generated by `tools/generate_data.py`.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+//!
+//! let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+//!     env!("CARGO_MANIFEST_DIR"),
+//!     "/data"
+//! ))
+//! .expect("Data directory should exist");
+//!
+//! let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+//! let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+//!
+//! // "Å" as the letter A with ring above, and as the Angstrom sign
+//! assert_eq!(nfc.normalize("\u{00C5}"), "\u{00C5}");
+//! assert_eq!(nfc.normalize("\u{212B}"), "\u{00C5}");
+//! assert_eq!(nfd.normalize("\u{00C5}"), "A\u{030A}");
+//! assert_eq!(nfd.normalize("\u{212B}"), "A\u{030A}");
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`UAX #15`]: https://www.unicode.org/reports/tr15/
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod closure;
+mod comparison;
+mod composing;
+mod decomposing;
+pub mod error;
+mod hangul;
+mod mapping;
+pub mod provider;
+
+pub use closure::CanonicalClosure;
+pub use comparison::CanonicalComparator;
+pub use composing::{ComposingNormalizer, ComposingWriter, Composition};
+pub use decomposing::{DecomposingNormalizer, DecomposingWriter, Decomposition};
+pub use error::Error as NormalizerError;
+pub use mapping::{CanonicalComposition, CanonicalDecomposition};
@@ -0,0 +1,133 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::hangul;
+use crate::provider::*;
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+
+/// Access to the canonical decomposition mappings of the characters, one level at a time.
+///
+/// This is the raw data behind the normalizers, for the algorithms that need to decompose
+/// characters themselves, such as collation or canonical closure.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::CanonicalDecomposition;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let decomposition =
+///     CanonicalDecomposition::try_new(&provider).expect("Data should load successfully");
+///
+/// // U+1E69 LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE decomposes to U+1E63 LATIN SMALL
+/// // LETTER S WITH DOT BELOW, which decomposes further, and the dot above.
+/// assert_eq!(decomposition.decompose('\u{1E69}'), ('\u{1E63}', Some('\u{0307}')));
+/// assert_eq!(decomposition.decompose('\u{1E63}'), ('s', Some('\u{0323}')));
+/// // U+212B ANGSTROM SIGN
+/// assert_eq!(decomposition.decompose('\u{212B}'), ('\u{00C5}', None));
+/// assert_eq!(decomposition.decompose('s'), ('s', None));
+/// ```
+pub struct CanonicalDecomposition<'data> {
+    mappings: DataPayload<'data, CanonicalDecompositionMappingV1Marker>,
+}
+
+impl<'data> CanonicalDecomposition<'data> {
+    /// Creates an accessor for the canonical decomposition mappings.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalDecompositionMappingV1Marker> + ?Sized,
+    {
+        let mappings: DataPayload<CanonicalDecompositionMappingV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::CANONICAL_DECOMPOSITION_MAPPING_V1))?
+            .take_payload()?;
+        Ok(Self { mappings })
+    }
+
+    /// Returns the canonical decomposition mapping of `c`, without decomposing it recursively.
+    ///
+    /// The mapping is a pair of characters, or a single character for the characters that
+    /// decompose to a singleton. Characters without a decomposition are returned unchanged.
+    /// Hangul syllables decompose to a leading and a vowel jamo, or to an LV syllable and a
+    /// trailing jamo.
+    pub fn decompose(&self, c: char) -> (char, Option<char>) {
+        if hangul::is_syllable(c) {
+            let (first, second) = hangul::decompose_pair(c);
+            return (first, Some(second));
+        }
+        let mut mapping = match self.mappings.get().mappings.get(&c) {
+            Some(mapping) => mapping.chars(),
+            None => return (c, None),
+        };
+        match mapping.next() {
+            Some(first) => (first, mapping.next()),
+            None => (c, None),
+        }
+    }
+}
+
+/// Access to the canonical compositions of pairs of characters.
+///
+/// This is the raw data behind the composing normalizers: [`Self::compose()`] returns the
+/// primary composite of a pair of characters, regardless of the characters around them.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::CanonicalComposition;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let composition =
+///     CanonicalComposition::try_new(&provider).expect("Data should load successfully");
+///
+/// assert_eq!(composition.compose('a', '\u{0308}'), Some('\u{00E4}'));
+/// assert_eq!(composition.compose('\u{1100}', '\u{1161}'), Some('\u{AC00}'));
+/// // U+0958 DEVANAGARI LETTER QA is excluded from composition.
+/// assert_eq!(composition.compose('\u{0915}', '\u{093C}'), None);
+/// ```
+pub struct CanonicalComposition<'data> {
+    compositions: DataPayload<'data, CanonicalCompositionV1Marker>,
+}
+
+impl<'data> CanonicalComposition<'data> {
+    /// Creates an accessor for the canonical compositions.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCompositionV1Marker> + ?Sized,
+    {
+        let compositions: DataPayload<CanonicalCompositionV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::CANONICAL_COMPOSITION_V1))?
+            .take_payload()?;
+        Ok(Self { compositions })
+    }
+
+    /// Returns the primary composite of `first` followed by `second`, if there is one.
+    ///
+    /// The characters that are excluded from composition are never returned.
+    pub fn compose(&self, first: char, second: char) -> Option<char> {
+        if let Some(composed) = hangul::compose(first, second) {
+            return Some(composed);
+        }
+        let mut pair = [0; 8];
+        let len = first.encode_utf8(&mut pair).len();
+        let len = len + second.encode_utf8(&mut pair[len..]).len();
+        let pair = core::str::from_utf8(&pair[..len]).ok()?;
+        self.compositions
+            .get()
+            .compositions
+            .get(pair)
+            .map(char::from_unaligned)
+    }
+}
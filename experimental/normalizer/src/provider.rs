@@ -0,0 +1,160 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use zerovec::ZeroMap;
+
+pub mod key {
+    //! Resource keys for [`icu_normalizer`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: canonical combining classes.
+    pub const CANONICAL_COMBINING_CLASS_V1: ResourceKey = resource_key!(Normalizer, "ccc", 1);
+
+    /// Resource key: full canonical decompositions.
+    pub const CANONICAL_DECOMPOSITION_V1: ResourceKey = resource_key!(Normalizer, "nfd", 1);
+
+    /// Resource key: canonical decomposition mappings, not recursively applied.
+    pub const CANONICAL_DECOMPOSITION_MAPPING_V1: ResourceKey =
+        resource_key!(Normalizer, "nfd_raw", 1);
+
+    /// Resource key: canonical compositions of pairs of characters.
+    pub const CANONICAL_COMPOSITION_V1: ResourceKey = resource_key!(Normalizer, "comp", 1);
+
+    /// Resource key: full compatibility decompositions that differ from the canonical ones.
+    pub const COMPATIBILITY_DECOMPOSITION_V1: ResourceKey = resource_key!(Normalizer, "nfkd", 1);
+
+    /// Resource key: full case foldings, in decomposed form.
+    pub const CANONICAL_CASEFOLD_V1: ResourceKey = resource_key!(Normalizer, "nfd_cf", 1);
+
+    /// Resource key: NFKC_Casefold mappings, in decomposed form.
+    pub const NFKC_CASEFOLD_V1: ResourceKey = resource_key!(Normalizer, "nfkc_cf", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_normalizer`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(
+        CanonicalCombiningClassV1Marker,
+        [key::CANONICAL_COMBINING_CLASS_V1]
+    ),
+    icu_provider::registered_keys!(
+        CanonicalDecompositionV1Marker,
+        [key::CANONICAL_DECOMPOSITION_V1]
+    ),
+    icu_provider::registered_keys!(
+        CanonicalDecompositionMappingV1Marker,
+        [key::CANONICAL_DECOMPOSITION_MAPPING_V1]
+    ),
+    icu_provider::registered_keys!(
+        CanonicalCompositionV1Marker,
+        [key::CANONICAL_COMPOSITION_V1]
+    ),
+    icu_provider::registered_keys!(
+        DecompositionSupplementV1Marker,
+        [
+            key::COMPATIBILITY_DECOMPOSITION_V1,
+            key::CANONICAL_CASEFOLD_V1,
+            key::NFKC_CASEFOLD_V1
+        ]
+    ),
+];
+
+/// The Canonical_Combining_Class property of the characters for which it is not zero.
+///
+/// Characters missing from the map are starters, i.e. their combining class is zero.
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CanonicalCombiningClassV1<'data> {
+    /// Map from a character to its canonical combining class.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ccc: ZeroMap<'data, char, u8>,
+}
+
+/// The full canonical decompositions of the characters that have one.
+///
+/// The decompositions are fully expanded, i.e. each character of a decomposition decomposes to
+/// itself. Hangul syllables are not included since they are decomposed algorithmically.
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CanonicalDecompositionV1<'data> {
+    /// Map from a character to its full canonical decomposition.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub decompositions: ZeroMap<'data, char, str>,
+}
+
+/// The canonical decomposition mappings of the characters that have one, as in the
+/// `UnicodeData.txt` file of the Unicode Character Database.
+///
+/// Unlike [`CanonicalDecompositionV1`], the mappings are not recursively applied: each one is
+/// either a single character or a pair of characters, whose first character may decompose
+/// further. Hangul syllables are not included since they are decomposed algorithmically.
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CanonicalDecompositionMappingV1<'data> {
+    /// Map from a character to its canonical decomposition mapping.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub mappings: ZeroMap<'data, char, str>,
+}
+
+/// The primary composites, keyed by the pair of characters they compose from.
+///
+/// Only characters whose canonical decomposition mapping is a pair and that are not excluded
+/// from composition are included. Hangul syllables are composed algorithmically.
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CanonicalCompositionV1<'data> {
+    /// Map from a string of two characters to the character they compose to.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub compositions: ZeroMap<'data, str, char>,
+}
+
+/// Decompositions that replace the canonical decompositions of some characters.
+///
+/// The same data struct is used for the compatibility decompositions, for the full case foldings,
+/// and for the NFKC_Casefold mappings; the [`ResourceKey`] determines which are stored. Only the characters whose mapping
+/// differs from their full canonical decomposition are included, so that this data is used on
+/// top of [`CanonicalDecompositionV1`].
+///
+/// The mappings are fully decomposed and in canonical order, and may be empty: the
+/// NFKC_Casefold mapping removes the default ignorable code points.
+///
+/// [`ResourceKey`]: icu_provider::ResourceKey
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct DecompositionSupplementV1<'data> {
+    /// Map from a character to its full decomposition.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub decompositions: ZeroMap<'data, char, str>,
+}
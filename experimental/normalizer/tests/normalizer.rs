@@ -0,0 +1,393 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use core::fmt::Write;
+use icu_normalizer::{
+    CanonicalClosure, CanonicalComparator, CanonicalComposition, CanonicalDecomposition,
+    ComposingNormalizer, DecomposingNormalizer,
+};
+use icu_provider_fs::FsDataProvider;
+
+fn get_provider() -> FsDataProvider {
+    FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist")
+}
+
+#[test]
+fn test_nfd() {
+    let provider = get_provider();
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    // Recursive decomposition: U+1E69 LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE
+    assert_eq!(nfd.normalize("\u{1E69}"), "s\u{0323}\u{0307}");
+    // Singleton decomposition: U+2126 OHM SIGN
+    assert_eq!(nfd.normalize("\u{2126}"), "\u{03A9}");
+    // Canonical ordering of the combining marks
+    assert_eq!(nfd.normalize("a\u{0302}\u{0323}"), "a\u{0323}\u{0302}");
+    assert_eq!(
+        nfd.normalize("\u{1EA5}\u{0323}"),
+        "a\u{0323}\u{0302}\u{0301}"
+    );
+    // Marks with the same combining class keep their order
+    assert_eq!(nfd.normalize("a\u{0301}\u{0300}"), "a\u{0301}\u{0300}");
+    // Hangul syllables, with and without a trailing consonant
+    assert_eq!(
+        nfd.normalize("\u{D55C}\u{AE00}"),
+        "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+    );
+    assert_eq!(nfd.normalize("\u{AC00}"), "\u{1100}\u{1161}");
+    // Characters outside of the BMP
+    assert_eq!(nfd.normalize("\u{1D15E}"), "\u{1D157}\u{1D165}");
+    // Text that is already decomposed
+    assert_eq!(nfd.normalize("ICU4X"), "ICU4X");
+    assert_eq!(nfd.normalize(""), "");
+}
+
+#[test]
+fn test_nfc() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+
+    assert_eq!(nfc.normalize("s\u{0307}\u{0323}"), "\u{1E69}");
+    assert_eq!(nfc.normalize("\u{2126}"), "\u{03A9}");
+    assert_eq!(nfc.normalize("a\u{0302}\u{0323}"), "\u{1EAD}");
+    // The second acute accent is blocked by the first one
+    assert_eq!(nfc.normalize("a\u{0301}\u{0301}"), "\u{00E1}\u{0301}");
+    // A mark with a lower combining class is not blocked
+    assert_eq!(nfc.normalize("a\u{0301}\u{0328}"), "\u{0105}\u{0301}");
+    // Composition exclusion: U+0958 DEVANAGARI LETTER QA
+    assert_eq!(nfc.normalize("\u{0958}"), "\u{0915}\u{093C}");
+    // Hangul syllables, composed from jamo and from an LV syllable
+    assert_eq!(nfc.normalize("\u{1112}\u{1161}\u{11AB}"), "\u{D55C}");
+    assert_eq!(nfc.normalize("\u{AC00}\u{11A8}"), "\u{AC01}");
+    // Leading combining mark
+    assert_eq!(nfc.normalize("\u{0301}a"), "\u{0301}a");
+    // Characters outside of the BMP
+    assert_eq!(nfc.normalize("\u{11099}\u{110BA}"), "\u{1109A}");
+    assert_eq!(nfc.normalize("\u{1D15E}"), "\u{1D157}\u{1D165}");
+    assert_eq!(nfc.normalize(""), "");
+}
+
+#[test]
+fn test_is_normalized() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    for (text, is_nfc, is_nfd) in [
+        ("ICU4X", true, true),
+        ("\u{00E9}", true, false),
+        ("e\u{0301}", false, true),
+        ("a\u{0323}\u{0302}", false, true),
+        ("a\u{0302}\u{0323}", false, false),
+        ("\u{D55C}", true, false),
+        ("\u{1112}\u{1161}", false, true),
+        ("\u{0915}\u{093C}", true, true),
+    ] {
+        assert_eq!(nfc.is_normalized(text), is_nfc, "{:?}", text);
+        assert_eq!(nfd.is_normalized(text), is_nfd, "{:?}", text);
+    }
+}
+
+#[test]
+fn test_is_fcd() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    for (text, is_fcd) in [
+        ("ICU4X", true),
+        ("\u{00E9}", true),
+        ("e\u{0301}", true),
+        ("a\u{0301}\u{00E0}", true),
+        ("a\u{0302}\u{0323}", false),
+        // U+1EA1 LATIN SMALL LETTER A WITH DOT BELOW, followed by a mark with a higher and with a
+        // lower combining class than the dot below
+        ("\u{1EA1}\u{0302}", true),
+        ("\u{1EA1}\u{031B}", false),
+        // A combining mark after a character whose decomposition ends with a starter
+        ("\u{D55C}\u{0301}", true),
+        ("", true),
+    ] {
+        assert_eq!(nfd.is_fcd(text), is_fcd, "{:?}", text);
+        assert_eq!(nfc.is_fcd(text), is_fcd, "{:?}", text);
+    }
+}
+
+#[test]
+fn test_stable_prefix() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfkc_cf = ComposingNormalizer::try_new_nfkc_casefold(&provider).expect("Data should load");
+
+    // The last character of a run of Latin characters composes with the marks after the run.
+    assert_eq!(nfc.normalize("Cafe\u{0301} au lait"), "Caf\u{00E9} au lait");
+    assert_eq!(nfc.normalize("na\u{0308}ive"), "n\u{00E4}ive");
+    assert!(nfc.is_normalized("Caf\u{00E9} au lait"));
+    assert!(!nfc.is_normalized("Cafe\u{0301}"));
+    assert!(!nfc.is_normalized("Cafe\u{0323}\u{0301}"));
+    // Latin characters that are mapped by the supplement are not part of the prefix.
+    assert_eq!(nfkc_cf.normalize("Caf\u{00C9}\u{00AD}s"), "caf\u{00E9}s");
+    assert!(!nfkc_cf.is_normalized("caf\u{00E9}S"));
+}
+
+#[test]
+fn test_normalize_to() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    let mut output = String::from("NFD: ");
+    nfd.normalize_to("Cr\u{00E8}me br\u{00FB}l\u{00E9}e", &mut output)
+        .expect("Writing to a String should succeed");
+    assert_eq!(output, "NFD: Cre\u{0300}me bru\u{0302}le\u{0301}e");
+
+    let mut output = String::from("NFC: ");
+    nfc.normalize_to("Cre\u{0300}me bru\u{0302}le\u{0301}e", &mut output)
+        .expect("Writing to a String should succeed");
+    assert_eq!(output, "NFC: Cr\u{00E8}me br\u{00FB}l\u{00E9}e");
+}
+
+#[test]
+fn test_nfkd() {
+    let provider = get_provider();
+    let nfkd = DecomposingNormalizer::try_new_nfkd(&provider).expect("Data should load");
+
+    assert_eq!(nfkd.normalize("\u{FB01}"), "fi");
+    assert_eq!(nfkd.normalize("\u{2460}"), "1");
+    assert_eq!(nfkd.normalize("\u{FF21}\u{FF22}"), "AB");
+    // Compatibility decomposition followed by canonical ordering
+    assert_eq!(nfkd.normalize("\u{1E9B}\u{0323}"), "s\u{0323}\u{0307}");
+    // Halfwidth Hangul jamo and a syllable
+    assert_eq!(
+        nfkd.normalize("\u{FFA1}\u{D55C}"),
+        "\u{1100}\u{1112}\u{1161}\u{11AB}"
+    );
+    // Canonical decompositions are unchanged
+    assert_eq!(nfkd.normalize("\u{00C5}"), "A\u{030A}");
+
+    assert!(nfkd.is_normalized("fi"));
+    assert!(!nfkd.is_normalized("\u{FB01}"));
+    assert!(!nfkd.is_normalized("\u{00C5}"));
+}
+
+#[test]
+fn test_nfkc() {
+    let provider = get_provider();
+    let nfkc = ComposingNormalizer::try_new_nfkc(&provider).expect("Data should load");
+
+    assert_eq!(nfkc.normalize("\u{FB01}"), "fi");
+    assert_eq!(nfkc.normalize("\u{1E9B}\u{0323}"), "\u{1E69}");
+    assert_eq!(nfkc.normalize("\u{FFA1}\u{FFC2}"), "\u{AC00}");
+    assert_eq!(nfkc.normalize("\u{2126}"), "\u{03A9}");
+    // Case is preserved
+    assert_eq!(nfkc.normalize("\u{FF21}b"), "Ab");
+
+    assert!(nfkc.is_normalized("\u{1E69}"));
+    assert!(!nfkc.is_normalized("\u{FB01}"));
+}
+
+#[test]
+fn test_nfkc_casefold() {
+    let provider = get_provider();
+    let nfkc_cf = ComposingNormalizer::try_new_nfkc_casefold(&provider).expect("Data should load");
+
+    assert_eq!(nfkc_cf.normalize("ICU4X"), "icu4x");
+    assert_eq!(nfkc_cf.normalize("Stra\u{00DF}e"), "strasse");
+    assert_eq!(nfkc_cf.normalize("\u{FF21}\u{FB01}"), "afi");
+    // U+212A KELVIN SIGN, U+216B ROMAN NUMERAL TWELVE
+    assert_eq!(nfkc_cf.normalize("\u{212A}\u{216B}"), "kxii");
+    // Default ignorable code points are removed
+    assert_eq!(nfkc_cf.normalize("a\u{00AD}b\u{200B}c"), "abc");
+    // Characters are composed again after case folding
+    assert_eq!(nfkc_cf.normalize("E\u{0301}"), "\u{00E9}");
+    assert_eq!(nfkc_cf.normalize("\u{1F88}"), "\u{1F00}\u{03B9}");
+
+    assert!(nfkc_cf.is_normalized("strasse"));
+    assert!(!nfkc_cf.is_normalized("Strasse"));
+}
+
+#[test]
+fn test_canonical_comparator() {
+    let provider = get_provider();
+    let comparator = CanonicalComparator::try_new(&provider).expect("Data should load");
+
+    assert_eq!(
+        comparator.canonical_compare("\u{212B}", "A\u{030A}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        comparator.canonical_compare("a\u{0302}\u{0323}", "\u{1EAD}"),
+        Ordering::Equal
+    );
+    // The NFD forms are compared by code point: "e\u{0301}" < "e\u{0302}" < "f"
+    assert_eq!(
+        comparator.canonical_compare("\u{00E9}", "\u{00EA}"),
+        Ordering::Less
+    );
+    assert_eq!(
+        comparator.canonical_compare("\u{00EA}", "f"),
+        Ordering::Less
+    );
+    assert_eq!(comparator.canonical_compare("a", "A"), Ordering::Greater);
+    assert_eq!(comparator.canonical_compare("", "a"), Ordering::Less);
+
+    assert!(comparator.canonical_caseless_eq("ICU4X", "icu4x"));
+    assert!(comparator.canonical_caseless_eq("\u{00C5}ngstr\u{00F6}m", "A\u{030A}NGSTRO\u{0308}M"));
+    // U+212A KELVIN SIGN folds to "k", U+1E9E LATIN CAPITAL LETTER SHARP S to "ss"
+    assert!(comparator.canonical_caseless_eq("\u{212A}", "K"));
+    assert!(comparator.canonical_caseless_eq("\u{1E9E}", "SS"));
+    // The combining ypogegrammeni is reordered before it is folded to a starter
+    assert!(
+        comparator.canonical_caseless_eq("\u{03B1}\u{0345}\u{0301}", "\u{03B1}\u{0301}\u{03B9}")
+    );
+    // Compatibility characters are not folded to their compatibility decompositions, but case
+    // folding may expand them, as U+FB01 LATIN SMALL LIGATURE FI to "fi"
+    assert!(!comparator.canonical_caseless_eq("\u{FF21}", "a"));
+    assert!(!comparator.canonical_caseless_eq("\u{2460}", "1"));
+    assert!(comparator.canonical_caseless_eq("\u{FB01}", "FI"));
+    assert_eq!(
+        comparator.canonical_caseless_compare("a", "B"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_canonical_mappings() {
+    let provider = get_provider();
+    let decomposition = CanonicalDecomposition::try_new(&provider).expect("Data should load");
+    let composition = CanonicalComposition::try_new(&provider).expect("Data should load");
+
+    // Hangul LV and LVT syllables
+    assert_eq!(
+        decomposition.decompose('\u{AC00}'),
+        ('\u{1100}', Some('\u{1161}'))
+    );
+    assert_eq!(
+        decomposition.decompose('\u{D55C}'),
+        ('\u{D558}', Some('\u{11AB}'))
+    );
+    assert_eq!(
+        composition.compose('\u{D558}', '\u{11AB}'),
+        Some('\u{D55C}')
+    );
+    // Composition exclusion: U+0958 DEVANAGARI LETTER QA
+    assert_eq!(
+        decomposition.decompose('\u{0958}'),
+        ('\u{0915}', Some('\u{093C}'))
+    );
+    // Singleton decomposition: U+2126 OHM SIGN
+    assert_eq!(decomposition.decompose('\u{2126}'), ('\u{03A9}', None));
+    assert_eq!(
+        composition.compose('\u{03A9}', '\u{0301}'),
+        Some('\u{038F}')
+    );
+    // Characters outside of the BMP
+    assert_eq!(
+        decomposition.decompose('\u{1D15E}'),
+        ('\u{1D157}', Some('\u{1D165}'))
+    );
+    assert_eq!(
+        composition.compose('\u{11099}', '\u{110BA}'),
+        Some('\u{1109A}')
+    );
+    // Compatibility decompositions are not included
+    assert_eq!(decomposition.decompose('\u{FB01}'), ('\u{FB01}', None));
+    assert_eq!(composition.compose('a', 'b'), None);
+
+    // The primary composites are composed from their decomposition mapping.
+    for c in ['\u{00C5}', '\u{1E69}', '\u{1EAD}', '\u{AC01}'] {
+        match decomposition.decompose(c) {
+            (first, Some(second)) => assert_eq!(composition.compose(first, second), Some(c)),
+            _ => panic!("{:?} should decompose to a pair", c),
+        }
+    }
+}
+
+#[test]
+fn test_canonical_closure() {
+    let provider = get_provider();
+    let closure = CanonicalClosure::try_new(&provider).expect("Data should load");
+
+    assert_eq!(closure.equivalents("ICU4X"), ["ICU4X"]);
+    assert_eq!(closure.equivalents(""), [""]);
+    // U+0341 COMBINING ACUTE TONE MARK is a singleton decomposition of the acute accent
+    assert_eq!(
+        closure.equivalents("\u{00E1}b"),
+        ["a\u{0301}b", "a\u{0341}b", "\u{00E1}b"]
+    );
+    // Hangul syllables, composed from jamo and from an LV syllable
+    assert_eq!(
+        closure.equivalents("\u{D55C}"),
+        ["\u{1112}\u{1161}\u{11AB}", "\u{D558}\u{11AB}", "\u{D55C}"]
+    );
+
+    // Each equivalent has the same NFD form.
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+    let equivalents = closure.equivalents("\u{1E69}\u{0301}");
+    assert!(equivalents.len() > 5);
+    for equivalent in &equivalents {
+        assert_eq!(nfd.normalize(equivalent), "s\u{0323}\u{0307}\u{0301}");
+    }
+}
+
+#[test]
+fn test_normalize_iter() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    // The input is only read as far as needed to produce the next character.
+    let mut chars = "a\u{0302}\u{0323}bc".chars();
+    let mut composition = nfc.normalize_iter(chars.by_ref());
+    assert_eq!(composition.next(), Some('\u{1EAD}'));
+    assert_eq!(chars.as_str(), "c");
+
+    let mut chars = "a\u{0302}\u{0323}bc".chars();
+    let mut decomposition = nfd.normalize_iter(chars.by_ref());
+    assert_eq!(decomposition.next(), Some('a'));
+    assert_eq!(decomposition.next(), Some('\u{0323}'));
+    assert_eq!(decomposition.next(), Some('\u{0302}'));
+    assert_eq!(chars.as_str(), "c");
+
+    assert_eq!(nfc.normalize_iter("".chars()).next(), None);
+    assert_eq!(
+        nfd.normalize_iter("\u{1E69}\u{D55C}".chars())
+            .collect::<String>(),
+        "s\u{0323}\u{0307}\u{1112}\u{1161}\u{11AB}"
+    );
+}
+
+#[test]
+fn test_writer() {
+    let provider = get_provider();
+    let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load");
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load");
+
+    // Writing the text one character at a time gives the same result as normalizing it at once.
+    let text = "\u{1E9B}\u{0323}a\u{0302}\u{0323}\u{1112}\u{1161}\u{11AB}\u{0301}e";
+    let mut nfc_writer = nfc.writer(String::new());
+    let mut nfd_writer = nfd.writer(String::new());
+    for c in text.chars() {
+        nfc_writer
+            .write_char(c)
+            .expect("Writing to a String should succeed");
+        nfd_writer
+            .write_char(c)
+            .expect("Writing to a String should succeed");
+    }
+    assert_eq!(
+        nfc_writer
+            .finish()
+            .expect("Writing to a String should succeed"),
+        nfc.normalize(text)
+    );
+    assert_eq!(
+        nfd_writer
+            .finish()
+            .expect("Writing to a String should succeed"),
+        nfd.normalize(text)
+    );
+}
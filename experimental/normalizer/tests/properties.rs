@@ -0,0 +1,30 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_normalizer::ComposingNormalizer;
+use icu_provider_fs::FsDataProvider;
+
+#[test]
+fn test_nfkc_casefold_matches_changes_when_nfkc_casefolded() {
+    let provider = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist");
+    let nfkc_cf = ComposingNormalizer::try_new_nfkc_casefold(&provider).expect("Data should load");
+
+    let uprops_provider = icu_testdata::get_static_properties_provider();
+    let payload = icu_properties::sets::get_changes_when_nfkc_casefolded(&uprops_provider)
+        .expect("Data should load");
+    let changes_when_nfkc_casefolded = &payload.get().inv_list;
+
+    // Changes_When_NFKC_Casefolded is derived from the NFKC_Casefold mapping.
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let mut buffer = [0; 4];
+        let text = c.encode_utf8(&mut buffer);
+        assert_eq!(
+            nfkc_cf.normalize(text) != *text,
+            changes_when_nfkc_casefolded.contains(c),
+            "{:?}",
+            c
+        );
+    }
+}
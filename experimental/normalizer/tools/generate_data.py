@@ -0,0 +1,151 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the normalization data in ../data from the Unicode Character
+# Database bundled with Python's unicodedata module, and from the
+# Default_Ignorable_Code_Point property in the uprops TOML files that are also
+# used for the icu_properties data.
+#
+# The Unicode version of the generated data is the one reported by
+# unicodedata.unidata_version; make sure it matches the version used by the
+# rest of the ICU4X data before checking in the output.
+
+import json
+import os
+import re
+import unicodedata
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..")
+OUT_DIR = os.path.join(ROOT_DIR, "experimental", "normalizer", "data")
+UPROPS_DIR = os.path.join(ROOT_DIR, "provider", "testdata", "data", "uprops")
+
+# Hangul syllables are decomposed and composed algorithmically, see
+# https://www.unicode.org/versions/Unicode14.0.0/ch03.pdf#G56669
+HANGUL_S_BASE = 0xAC00
+HANGUL_S_COUNT = 11172
+
+
+def code_points():
+    for cp in range(0x110000):
+        # Skip surrogates, they cannot be represented in a Rust char.
+        if 0xD800 <= cp <= 0xDFFF:
+            continue
+        if HANGUL_S_BASE <= cp < HANGUL_S_BASE + HANGUL_S_COUNT:
+            continue
+        yield chr(cp)
+
+
+def canonical_mapping(c):
+    """Returns the canonical decomposition mapping of c from UnicodeData.txt, or
+    None if c has no canonical decomposition mapping."""
+    fields = unicodedata.decomposition(c).split()
+    if not fields or fields[0].startswith("<"):
+        return None
+    return "".join(chr(int(f, 16)) for f in fields)
+
+
+def read_binary_property(short_name):
+    """Returns the set of code points of a binary property from the uprops TOML."""
+    with open(os.path.join(UPROPS_DIR, short_name + ".toml")) as f:
+        ranges = re.findall(r"\[(0x[0-9a-f]+), (0x[0-9a-f]+)\]", f.read())
+    return {
+        cp for start, end in ranges for cp in range(int(start, 16), int(end, 16) + 1)
+    }
+
+
+DEFAULT_IGNORABLE = read_binary_property("DI")
+
+
+def nfkc_casefold(c):
+    """Returns the NFKC_Casefold mapping of c, see the definition of NFKC_CF in
+    https://www.unicode.org/reports/tr44/#NFKC_Casefold"""
+    s = c
+    while True:
+        mapped = unicodedata.normalize(
+            "NFKC", unicodedata.normalize("NFKC", s).casefold()
+        )
+        mapped = "".join(ch for ch in mapped if ord(ch) not in DEFAULT_IGNORABLE)
+        if mapped == s:
+            return mapped
+        s = mapped
+
+
+def canonical_casefold(c):
+    """Returns the canonical caseless form of c, see definition D145 in
+    https://www.unicode.org/versions/Unicode14.0.0/ch03.pdf#G34145"""
+    return unicodedata.normalize("NFD", unicodedata.normalize("NFD", c).casefold())
+
+
+def write_json(name, data):
+    path = os.path.join(OUT_DIR, "normalizer", name + "@1.json")
+    with open(path, "w") as f:
+        json.dump(data, f, indent=2)
+        f.write("\n")
+
+
+ccc = {}
+decompositions = {}
+decomposition_mappings = {}
+compositions = {}
+compatibility_decompositions = {}
+canonical_casefold_decompositions = {}
+nfkc_casefold_decompositions = {}
+
+for c in code_points():
+    combining_class = unicodedata.combining(c)
+    if combining_class != 0:
+        ccc[c] = combining_class
+
+    # The compatibility and case folding data only contain the characters
+    # that are not decomposed to their canonical decomposition.
+    nfd = unicodedata.normalize("NFD", c)
+    nfkd = unicodedata.normalize("NFKD", c)
+    if nfkd != nfd:
+        compatibility_decompositions[c] = nfkd
+    casefolded = canonical_casefold(c)
+    if casefolded != nfd:
+        canonical_casefold_decompositions[c] = casefolded
+    casefolded = nfkc_casefold(c)
+    if unicodedata.normalize("NFD", casefolded) != nfd:
+        nfkc_casefold_decompositions[c] = unicodedata.normalize("NFD", casefolded)
+
+    mapping = canonical_mapping(c)
+    if mapping is None:
+        continue
+    decompositions[c] = nfd
+    decomposition_mappings[c] = mapping
+    # Primary composites are the characters whose canonical decomposition
+    # mapping is a pair and that are not excluded from composition, i.e. that
+    # are left unchanged by NFC.
+    if len(mapping) == 2 and unicodedata.normalize("NFC", c) == c:
+        compositions[mapping] = c
+
+os.makedirs(os.path.join(OUT_DIR, "normalizer"), exist_ok=True)
+
+with open(os.path.join(OUT_DIR, "manifest.json"), "w") as f:
+    json.dump(
+        {"aliasing": "NoAliases", "syntax": "Json", "schema_version": "0.3"},
+        f,
+        indent=2,
+    )
+    f.write("\n")
+
+write_json("ccc", {"ccc": dict(sorted(ccc.items()))})
+write_json("nfd", {"decompositions": dict(sorted(decompositions.items()))})
+write_json("nfd_raw", {"mappings": dict(sorted(decomposition_mappings.items()))})
+write_json("comp", {"compositions": dict(sorted(compositions.items()))})
+write_json(
+    "nfkd", {"decompositions": dict(sorted(compatibility_decompositions.items()))}
+)
+write_json(
+    "nfd_cf",
+    {"decompositions": dict(sorted(canonical_casefold_decompositions.items()))},
+)
+write_json(
+    "nfkc_cf", {"decompositions": dict(sorted(nfkc_casefold_decompositions.items()))}
+)
+
+print("Generated normalization data for Unicode", unicodedata.unidata_version)
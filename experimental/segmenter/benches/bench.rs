@@ -8,6 +8,7 @@ use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorLatin1;
 use icu_segmenter::LineBreakIteratorUtf16;
 use icu_segmenter::LineBreakRule;
+use icu_segmenter::WordBreakIterator;
 use icu_segmenter::WordBreakRule;
 
 // Example is MIT license.
@@ -80,10 +81,19 @@ fn line_break_iter_utf16(c: &mut Criterion) {
     });
 }
 
+fn word_break_iter_utf8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Word Break/UTF8");
+
+    group.bench_function("En", |b| {
+        b.iter(|| WordBreakIterator::new(TEST_STR).count())
+    });
+}
+
 criterion_group!(
     benches,
     line_break_iter_latin1,
     line_break_iter_utf8,
-    line_break_iter_utf16
+    line_break_iter_utf16,
+    word_break_iter_utf8
 );
 criterion_main!(benches);
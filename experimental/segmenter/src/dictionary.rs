@@ -0,0 +1,441 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::language::*;
+#[cfg(feature = "lstm")]
+use crate::lstm::*;
+use crate::wb_define::*;
+use crate::word_breaker::get_word_break_property_utf32;
+#[cfg(feature = "lstm")]
+use crate::SegmenterError;
+
+#[cfg(feature = "lstm")]
+use icu_segmenter_lstm::{lstm::Lstm, structs::LstmData};
+use serde::{Deserialize, Serialize};
+use std::char::decode_utf16;
+
+/// A node of the trie of a [`Dictionary`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct DictionaryNode {
+    /// Whether the code points from the root to this node spell a word.
+    is_word: bool,
+
+    /// The code points following this node, and the indices of their nodes,
+    /// sorted by code point.
+    children: Vec<(u32, u32)>,
+}
+
+/// The best segmentation of the input up to a boundary.
+#[derive(Copy, Clone)]
+struct Segmentation {
+    /// The number of code points not in a word, then the number of words.
+    cost: (usize, usize),
+
+    /// The previous boundary.
+    start: usize,
+
+    /// Whether the text from the previous boundary is a word.
+    is_word: bool,
+}
+
+/// A list of words, used to find the word boundaries of the languages that
+/// are written without spaces between words, such as Thai, Lao, and Khmer.
+///
+/// The words are stored in a trie of code points, which is the serialized
+/// form of the dictionary; it can be deserialized with any serde format, such
+/// as JSON with `serde_json`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_segmenter::Dictionary;
+///
+/// let dictionary = Dictionary::from_words(["ພາສາ", "ລາວ"]);
+/// assert!(dictionary.contains("ລາວ"));
+/// assert!(!dictionary.contains("ລາ"));
+///
+/// // Break after "ພາສາ" (language).
+/// assert_eq!(dictionary.segment_str("ພາສາລາວ"), [12]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dictionary {
+    /// The nodes of the trie. The first node is the root.
+    nodes: Vec<DictionaryNode>,
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Self {
+            nodes: vec![DictionaryNode::default()],
+        }
+    }
+}
+
+impl Dictionary {
+    /// Create a dictionary from a list of words.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = Self::default();
+        for word in words {
+            dictionary.insert(word.as_ref());
+        }
+        dictionary
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = 0;
+        for ch in word.chars() {
+            let ch = ch as u32;
+            let children = &self.nodes[node].children;
+            node = match children.binary_search_by_key(&ch, |&(c, _)| c) {
+                Ok(i) => children[i].1 as usize,
+                Err(i) => {
+                    let child = self.nodes.len();
+                    self.nodes[node].children.insert(i, (ch, child as u32));
+                    self.nodes.push(DictionaryNode::default());
+                    child
+                }
+            };
+        }
+        self.nodes[node].is_word = true;
+    }
+
+    fn get_child(&self, node: usize, ch: u32) -> Option<usize> {
+        let children = &self.nodes.get(node)?.children;
+        let i = children.binary_search_by_key(&ch, |&(c, _)| c).ok()?;
+        Some(children[i].1 as usize)
+    }
+
+    /// Returns whether the dictionary contains `word`.
+    pub fn contains(&self, word: &str) -> bool {
+        let mut node = 0;
+        for ch in word.chars() {
+            node = match self.get_child(node, ch as u32) {
+                Some(child) => child,
+                None => return false,
+            };
+        }
+        self.nodes.get(node).map_or(false, |node| node.is_word)
+    }
+
+    /// Returns the lengths of the words of the dictionary that `input` starts
+    /// with, in increasing order.
+    fn prefix_lengths<'a>(&'a self, input: &'a [u32]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(0);
+        input.iter().enumerate().filter_map(move |(i, &ch)| {
+            node = self.get_child(node?, ch);
+            match self.nodes.get(node?) {
+                Some(n) if n.is_word => Some(i + 1),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the word boundaries of a string of code points, as indices into
+    /// `input`. The start and the end of the string are not returned.
+    ///
+    /// The string is split into the dictionary words so that as few code points
+    /// as possible are not part of a word, and then into as few words as
+    /// possible. Consecutive code points that are not part of a word are kept
+    /// together, and combining marks are never split from their base.
+    pub fn segment_utf32(&self, input: &[u32]) -> Vec<usize> {
+        let len = input.len();
+        let is_boundary = |i: usize| {
+            i == len
+                || !matches!(
+                    get_word_break_property_utf32(input[i]),
+                    EXTEND | FORMAT | ZWJ
+                )
+        };
+
+        // The best segmentation of input[..i], for each boundary i.
+        let mut best: Vec<Option<Segmentation>> = vec![None; len + 1];
+        best[0] = Some(Segmentation {
+            cost: (0, 0),
+            start: 0,
+            is_word: true,
+        });
+        for start in 0..len {
+            let (unknown, words) = match best[start] {
+                Some(segmentation) => segmentation.cost,
+                None => continue,
+            };
+            let mut relax = |end: usize, cost: (usize, usize), is_word: bool| {
+                if best[end].map_or(true, |segmentation| cost < segmentation.cost) {
+                    best[end] = Some(Segmentation {
+                        cost,
+                        start,
+                        is_word,
+                    });
+                }
+            };
+            for end in self.prefix_lengths(&input[start..]).map(|n| start + n) {
+                if is_boundary(end) {
+                    relax(end, (unknown, words + 1), true);
+                }
+            }
+            // Skip a single character, with its combining marks.
+            let mut end = start + 1;
+            while !is_boundary(end) {
+                end += 1;
+            }
+            relax(end, (unknown + end - start, words + 1), false);
+        }
+
+        let mut result = Vec::new();
+        let mut end = len;
+        while end > 0 {
+            let Segmentation { start, is_word, .. } = best[end].unwrap();
+            // Keep consecutive code points that are not in a word together.
+            if start > 0 && (is_word || best[start].unwrap().is_word) {
+                result.push(start);
+            }
+            end = start;
+        }
+        result.reverse();
+        result
+    }
+
+    /// Returns the word boundaries of a string, as byte indices into `input`.
+    /// The start and the end of the string are not returned.
+    ///
+    /// See [`Dictionary::segment_utf32()`] for details.
+    pub fn segment_str(&self, input: &str) -> Vec<usize> {
+        let indices: Vec<usize> = input.char_indices().map(|(i, _)| i).collect();
+        let codepoints: Vec<u32> = input.chars().map(|ch| ch as u32).collect();
+        self.segment_utf32(&codepoints)
+            .into_iter()
+            .map(|i| indices[i])
+            .collect()
+    }
+}
+
+/// The dictionaries used by a segmenter, one per [`Language`].
+///
+/// The text of a language without a dictionary is segmented with an LSTM
+/// model if there is one for the language, or else is not segmented. LSTM
+/// models require the `lstm` feature, which is enabled by default: models for
+/// Thai and Burmese are built in, and models for other languages can be added
+/// with [`Dictionaries::insert_lstm()`] or loaded from a data provider with
+/// [`Dictionaries::load_lstm()`].
+#[derive(Debug, Clone, Default)]
+pub struct Dictionaries {
+    dictionaries: Vec<(Language, Dictionary)>,
+    #[cfg(feature = "lstm")]
+    lstm_models: Vec<(Language, Lstm)>,
+}
+
+impl Dictionaries {
+    /// Create an empty set of dictionaries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the dictionary used to segment the text of `language`.
+    pub fn insert(&mut self, language: Language, dictionary: Dictionary) {
+        match self.dictionaries.iter_mut().find(|(l, _)| *l == language) {
+            Some(entry) => entry.1 = dictionary,
+            None => self.dictionaries.push((language, dictionary)),
+        }
+    }
+
+    /// Returns the dictionary used to segment the text of `language`.
+    pub fn get(&self, language: Language) -> Option<&Dictionary> {
+        self.dictionaries
+            .iter()
+            .find(|(l, _)| *l == language)
+            .map(|(_, dictionary)| dictionary)
+    }
+
+    /// Set the LSTM model used to segment the text of `language` when there is
+    /// no dictionary for it, replacing the built-in model if there is one.
+    #[cfg(feature = "lstm")]
+    pub fn insert_lstm(
+        &mut self,
+        language: Language,
+        data: LstmData,
+    ) -> Result<(), SegmenterError> {
+        let lstm = Lstm::try_new(data).map_err(SegmenterError::Lstm)?;
+        match self.lstm_models.iter_mut().find(|(l, _)| *l == language) {
+            Some(entry) => entry.1 = lstm,
+            None => self.lstm_models.push((language, lstm)),
+        }
+        Ok(())
+    }
+
+    /// Returns how to segment the text of `language`: with its dictionary,
+    /// or else with its LSTM model.
+    fn get_model(&self, language: Language) -> Option<ComplexModel<'_>> {
+        if let Some(dictionary) = self.get(language) {
+            return Some(ComplexModel::Dictionary(dictionary));
+        }
+        #[cfg(feature = "lstm")]
+        if let Some((_, lstm)) = self.lstm_models.iter().find(|(l, _)| *l == language) {
+            return Some(ComplexModel::Lstm(lstm));
+        }
+        None
+    }
+}
+
+/// A way to find the word boundaries of a language written without spaces
+/// between words.
+#[derive(Copy, Clone)]
+pub enum ComplexModel<'a> {
+    Dictionary(&'a Dictionary),
+    #[cfg(feature = "lstm")]
+    Lstm(&'a Lstm),
+}
+
+impl<'a> ComplexModel<'a> {
+    /// Returns the word boundaries of a string of code points, as indices into
+    /// `input`. The start and the end of the string are not returned.
+    pub fn segment_utf32(&self, input: &[u32]) -> Vec<usize> {
+        match self {
+            ComplexModel::Dictionary(dictionary) => dictionary.segment_utf32(input),
+            #[cfg(feature = "lstm")]
+            ComplexModel::Lstm(lstm) => {
+                let s: String = input
+                    .iter()
+                    .map(|&ch| char::from_u32(ch).unwrap_or(char::REPLACEMENT_CHARACTER))
+                    .collect();
+                lstm.word_breaks(&s)
+            }
+        }
+    }
+}
+
+/// Returns how to segment the text of `language`, using `dictionaries` if
+/// given, or else the built-in LSTM models.
+pub fn get_complex_model(
+    dictionaries: Option<&Dictionaries>,
+    language: Language,
+) -> Option<ComplexModel<'_>> {
+    if let Some(model) = dictionaries.and_then(|d| d.get_model(language)) {
+        return Some(model);
+    }
+    #[cfg(feature = "lstm")]
+    if let Some(lstm) = get_builtin_lstm_model(language) {
+        return Some(ComplexModel::Lstm(lstm));
+    }
+    None
+}
+
+/// Returns the line break opportunities of a string of complex languages, as
+/// UTF-16 indices, including the end of the string. Each language is
+/// segmented with its dictionary if there is one, or else its LSTM model.
+pub fn get_complex_line_break_utf16(
+    dictionaries: Option<&Dictionaries>,
+    input: &[u16],
+) -> Vec<usize> {
+    let s: String = decode_utf16(input.iter().cloned())
+        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    let mut result: Vec<usize> = Vec::new();
+    let mut offset = 0;
+    for str_per_lang in LanguageIterator::new(&s) {
+        if offset != 0 {
+            // language break
+            result.push(offset);
+        }
+
+        let language = get_language(str_per_lang.chars().next().unwrap() as u32);
+        if let Some(model) = get_complex_model(dictionaries, language) {
+            let codepoints: Vec<u32> = str_per_lang.chars().map(|ch| ch as u32).collect();
+            // Complex languages are in the BMP, so code point indices are UTF-16 indices.
+            result.extend(model.segment_utf32(&codepoints).iter().map(|n| offset + n));
+        }
+        offset += str_per_lang.chars().fold(0, |n, c| n + c.len_utf16());
+    }
+    result.push(input.len());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dictionary::*;
+
+    #[test]
+    fn dictionary_trie() {
+        let dictionary = Dictionary::from_words(["ab", "abc", "b"]);
+        assert!(dictionary.contains("ab"));
+        assert!(dictionary.contains("abc"));
+        assert!(dictionary.contains("b"));
+        assert!(!dictionary.contains(""));
+        assert!(!dictionary.contains("a"));
+        assert!(!dictionary.contains("abcd"));
+
+        let input: Vec<u32> = "abcd".chars().map(|ch| ch as u32).collect();
+        let lengths: Vec<usize> = dictionary.prefix_lengths(&input).collect();
+        assert_eq!(lengths, [2, 3]);
+
+        let json = serde_json::to_string(&dictionary).unwrap();
+        let deserialized: Dictionary = serde_json::from_str(&json).unwrap();
+        assert_eq!(dictionary, deserialized);
+    }
+
+    #[test]
+    fn dictionary_segment() {
+        let dictionary = Dictionary::from_words(["ab", "abc", "cd", "b", "d"]);
+        let segment = |s: &str| {
+            let input: Vec<u32> = s.chars().map(|ch| ch as u32).collect();
+            dictionary.segment_utf32(&input)
+        };
+        // Prefer the segmentation without unknown characters.
+        assert_eq!(segment("abcd"), [2]);
+        assert_eq!(segment("abcb"), [3]);
+        // Prefer fewer words.
+        assert_eq!(segment("abd"), [2]);
+        // Unknown characters are kept together.
+        assert_eq!(segment("xyab"), [2]);
+        assert_eq!(segment("abxyzb"), [2, 5]);
+        assert!(segment("").is_empty());
+        // Combining marks are not split from their base.
+        assert_eq!(segment("ab\u{0301}d"), [3]);
+    }
+
+    #[test]
+    fn dictionary_thai() {
+        let dictionary = Dictionary::from_words(["ภาษา", "ไทย"]);
+        assert_eq!(dictionary.segment_str("ภาษาไทยภาษาไทย"), [12, 21, 33]);
+    }
+
+    #[test]
+    fn complex_line_break() {
+        let mut dictionaries = Dictionaries::new();
+        dictionaries.insert(Language::Lao, Dictionary::from_words(["ພາສາ", "ລາວ"]));
+        dictionaries.insert(Language::Thai, Dictionary::from_words(["ภาษา", "ไทย"]));
+
+        // Lao followed by Thai, each with its dictionary.
+        let input: Vec<u16> = "ພາສາລາວภาษาไทยภาษาไทย".encode_utf16().collect();
+        let breaks = get_complex_line_break_utf16(Some(&dictionaries), &input);
+        assert_eq!(breaks, [4, 7, 11, 14, 18, 21]);
+
+        // Lao without a dictionary is not segmented.
+        let input: Vec<u16> = "ພາສາລາວ".encode_utf16().collect();
+        let breaks = get_complex_line_break_utf16(None, &input);
+        assert_eq!(breaks, [7]);
+    }
+
+    #[cfg(feature = "lstm")]
+    #[test]
+    fn complex_line_break_lstm() {
+        let data = serde_json::from_slice(include_bytes!(
+            "../../segmenter_lstm/tests/testdata/Thai_graphclust_exclusive_model4_heavy/weights.json"
+        ))
+        .unwrap();
+        let mut dictionaries = Dictionaries::new();
+        dictionaries.insert_lstm(Language::Thai, data).unwrap();
+
+        // Thai is segmented with the given model, which works on grapheme clusters, instead of
+        // the built-in one.
+        let input: Vec<u16> = "ภาษาไทยภาษาไทย".encode_utf16().collect();
+        let breaks = get_complex_line_break_utf16(Some(&dictionaries), &input);
+        assert_eq!(breaks, [2, 4, 7, 9, 11, 14]);
+        let breaks = get_complex_line_break_utf16(None, &input);
+        assert_eq!(breaks, [4, 7, 11, 14]);
+    }
+}
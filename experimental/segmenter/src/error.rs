@@ -0,0 +1,28 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for segmentation.
+
+use displaydoc::Display;
+
+/// An error creating a segmenter.
+#[derive(Display, Debug)]
+pub enum Error {
+    /// The data could not be loaded from the data provider.
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+
+    /// The weights of an LSTM model are invalid.
+    #[cfg(feature = "lstm")]
+    #[displaydoc("invalid LSTM model: {0:?}")]
+    Lstm(icu_segmenter_lstm::LstmError),
+}
+
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
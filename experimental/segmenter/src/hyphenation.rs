@@ -0,0 +1,284 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::line_breaker::LineBreakIterator;
+use crate::word_breaker::{WordBreakIterator, WordType};
+
+use std::collections::BTreeMap;
+use std::iter::once;
+
+/// A hyphenator finding the positions in a word where it can be broken with a
+/// hyphen, from TeX hyphenation patterns, following the algorithm of Frank
+/// Liang's "Word Hy-phen-a-tion by Com-put-er".
+///
+/// A pattern is a fragment of a word with a digit between some of its letters,
+/// such as `"hy3ph"`, where `"."` matches the start or the end of the word. An
+/// odd digit allows a hyphen between the letters around it, and an even digit
+/// forbids it; the greatest digit of all the patterns matching a position
+/// wins.
+///
+/// # Examples
+///
+/// ```
+/// use icu_segmenter::Hyphenator;
+///
+/// let mut hyphenator = Hyphenator::from_patterns([
+///     "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+/// ]);
+/// assert_eq!(hyphenator.hyphenate("hyphenation"), [2, 6]);
+/// assert_eq!(hyphenator.hyphenate("Hyphenation"), [2, 6]);
+///
+/// // Words that the patterns hyphenate wrongly can be listed as exceptions.
+/// hyphenator.insert_exception("ta-ble");
+/// assert_eq!(hyphenator.hyphenate("table"), [2]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hyphenator {
+    /// The letters of each pattern, and the digits before, between, and after
+    /// them.
+    patterns: BTreeMap<String, Vec<u8>>,
+
+    /// The number of letters of the longest pattern.
+    max_pattern_length: usize,
+
+    /// The lowercase words hyphenated without patterns, and the positions of
+    /// their hyphens in code points.
+    exceptions: BTreeMap<String, Vec<usize>>,
+
+    /// The minimum numbers of code points before and after a hyphen.
+    left_min: usize,
+    right_min: usize,
+}
+
+impl Default for Hyphenator {
+    fn default() -> Self {
+        Self {
+            patterns: BTreeMap::new(),
+            max_pattern_length: 0,
+            exceptions: BTreeMap::new(),
+            left_min: 2,
+            right_min: 3,
+        }
+    }
+}
+
+impl Hyphenator {
+    /// Create a hyphenator from a list of TeX hyphenation patterns, such as
+    /// `"hy3ph"`.
+    ///
+    /// Following TeX for English, at least 2 code points are kept before a
+    /// hyphen and 3 after it; see [`set_min_lengths()`](Self::set_min_lengths).
+    pub fn from_patterns<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut hyphenator = Self::default();
+        for pattern in patterns {
+            hyphenator.insert_pattern(pattern.as_ref());
+        }
+        hyphenator
+    }
+
+    fn insert_pattern(&mut self, pattern: &str) {
+        let mut letters = String::new();
+        let mut values = vec![0];
+        for ch in pattern.chars() {
+            match ch.to_digit(10) {
+                Some(digit) => *values.last_mut().unwrap() = digit as u8,
+                None => {
+                    letters.push(ch);
+                    values.push(0);
+                }
+            }
+        }
+        self.max_pattern_length = self.max_pattern_length.max(values.len() - 1);
+        self.patterns.insert(letters, values);
+    }
+
+    /// Hyphenate `word` where its hyphens are, such as `"ta-ble"`, instead of
+    /// with the patterns.
+    pub fn insert_exception(&mut self, word: &str) {
+        let mut letters = String::new();
+        let mut positions = Vec::new();
+        let mut length = 0;
+        for ch in word.chars() {
+            if ch == '-' {
+                positions.push(length);
+            } else {
+                letters.push(to_lowercase(ch));
+                length += 1;
+            }
+        }
+        self.exceptions.insert(letters, positions);
+    }
+
+    /// Set the minimum numbers of code points of a word before and after a
+    /// hyphen.
+    pub fn set_min_lengths(&mut self, left_min: usize, right_min: usize) {
+        self.left_min = left_min;
+        self.right_min = right_min;
+    }
+
+    /// Returns the positions in `word` where a hyphen can be inserted, as byte
+    /// indices in increasing order. The letters of `word` are compared to the
+    /// patterns in lowercase.
+    pub fn hyphenate(&self, word: &str) -> Vec<usize> {
+        let indices: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+        let length = indices.len();
+        if length < self.left_min + self.right_min {
+            return Vec::new();
+        }
+        let lowercase: String = word.chars().map(to_lowercase).collect();
+        let positions = match self.exceptions.get(&lowercase) {
+            Some(positions) => positions.clone(),
+            None => self.hyphenate_by_patterns(&lowercase, length),
+        };
+        positions
+            .into_iter()
+            .filter(|&i| i >= self.left_min && i + self.right_min <= length)
+            .map(|i| indices[i])
+            .collect()
+    }
+
+    /// Returns the positions of the hyphens of a lowercase word of `length`
+    /// code points found by the patterns, in code points.
+    fn hyphenate_by_patterns(&self, word: &str, length: usize) -> Vec<usize> {
+        let dotted: Vec<char> = once('.').chain(word.chars()).chain(once('.')).collect();
+        // The value before each code point of the dotted word, and after the
+        // last one.
+        let mut values = vec![0; dotted.len() + 1];
+        for start in 0..dotted.len() {
+            let mut key = String::new();
+            for &ch in dotted[start..].iter().take(self.max_pattern_length) {
+                key.push(ch);
+                if let Some(pattern) = self.patterns.get(&key) {
+                    for (value, &v) in values[start..].iter_mut().zip(pattern) {
+                        *value = (*value).max(v);
+                    }
+                }
+            }
+        }
+        // The position before the code point `i` of the word is the one before
+        // the code point `i + 1` of the dotted word.
+        (1..length).filter(|&i| values[i + 1] % 2 == 1).collect()
+    }
+}
+
+/// Returns the lowercase of `ch`, keeping a single code point so that the
+/// positions in a word don't change.
+fn to_lowercase(ch: char) -> char {
+    let mut lowercase = ch.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(lower), None) => lower,
+        _ => ch,
+    }
+}
+
+/// The kind of a line break opportunity returned by a
+/// [`HyphenatedLineBreakIterator`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineBreakType {
+    /// A line break opportunity of Unicode Standard Annex #14, such as after a
+    /// space.
+    Break,
+
+    /// A line break opportunity inside a word, where a hyphen is inserted at
+    /// the end of the line if the line is broken there.
+    Hyphen,
+}
+
+/// The struct implementing the [`Iterator`] trait over the line break
+/// opportunities of the given string, including the hyphenation points of its
+/// words. Each item is the byte index of an opportunity, along with its
+/// [`LineBreakType`]. Please see
+/// [`LineBreakSegmenter::segment_str_with_hyphens()`](crate::LineBreakSegmenter::segment_str_with_hyphens)
+/// for its usages.
+///
+/// [`Iterator`]: core::iter::Iterator
+pub struct HyphenatedLineBreakIterator<'a> {
+    input: &'a str,
+    iter: LineBreakIterator<'a>,
+    hyphenator: Option<&'a Hyphenator>,
+    // The start of the text after the last line break opportunity of `iter`.
+    start: usize,
+    // The remaining opportunities before the last one of `iter`, in reverse
+    // order.
+    result_cache: Vec<(usize, LineBreakType)>,
+}
+
+impl<'a> HyphenatedLineBreakIterator<'a> {
+    pub(crate) fn new(
+        input: &'a str,
+        iter: LineBreakIterator<'a>,
+        hyphenator: Option<&'a Hyphenator>,
+    ) -> Self {
+        Self {
+            input,
+            iter,
+            hyphenator,
+            start: 0,
+            result_cache: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Iterator for HyphenatedLineBreakIterator<'a> {
+    type Item = (usize, LineBreakType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(result) = self.result_cache.pop() {
+            return Some(result);
+        }
+
+        let end = self.iter.next()?;
+        let start = core::mem::replace(&mut self.start, end);
+        self.result_cache.push((end, LineBreakType::Break));
+        let hyphenator = match self.hyphenator {
+            Some(hyphenator) => hyphenator,
+            None => return self.result_cache.pop(),
+        };
+
+        // Hyphenate the words between the two line break opportunities.
+        let segment = &self.input[start..end];
+        let mut hyphens = Vec::new();
+        let mut word_start = 0;
+        for (word_end, word_type) in WordBreakIterator::new(segment) {
+            if word_type == WordType::Letter {
+                hyphens.extend(
+                    hyphenator
+                        .hyphenate(&segment[word_start..word_end])
+                        .into_iter()
+                        .map(|i| (start + word_start + i, LineBreakType::Hyphen)),
+                );
+            }
+            word_start = word_end;
+        }
+        self.result_cache.extend(hyphens.into_iter().rev());
+        self.result_cache.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hyphenation::*;
+
+    #[test]
+    fn hyphenate() {
+        let mut hyphenator = Hyphenator::from_patterns(["1ta", "a1b", "b1l", ".no4"]);
+        assert_eq!(hyphenator.hyphenate("table"), [2]);
+        // ".no4" forbids "no-table", and "b1l" is too close to the end.
+        assert_eq!(hyphenator.hyphenate("notable"), [4]);
+
+        hyphenator.set_min_lengths(1, 1);
+        assert_eq!(hyphenator.hyphenate("notable"), [4, 5]);
+        assert!(hyphenator.hyphenate("").is_empty());
+
+        // Positions are byte indices.
+        assert_eq!(hyphenator.hyphenate("étable"), [2, 4, 5]);
+
+        hyphenator.insert_exception("Ta-ble");
+        assert_eq!(hyphenator.hyphenate("TABLE"), [2]);
+    }
+}
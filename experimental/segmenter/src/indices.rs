@@ -73,6 +73,34 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
+/// Similar to CharIndices for UTF-32 code points
+#[derive(Clone)]
+pub struct Utf32Indices<'a> {
+    front_offset: usize,
+    iter: &'a [u32],
+}
+
+impl<'a> Utf32Indices<'a> {
+    pub fn new(input: &'a [u32]) -> Self {
+        Self {
+            front_offset: 0,
+            iter: input,
+        }
+    }
+}
+
+impl<'a> Iterator for Utf32Indices<'a> {
+    type Item = (usize, u32);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, u32)> {
+        self.iter.get(self.front_offset).map(|ch| {
+            self.front_offset += 1;
+            (self.front_offset - 1, *ch)
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::indices::*;
@@ -117,4 +145,21 @@ mod tests {
         let n = indices.next();
         assert_eq!(n, None);
     }
+
+    #[test]
+    fn utf32_indices() {
+        let utf32 = [0x1f603, 0x0020, 0x1f300];
+        let mut indices = Utf32Indices::new(&utf32);
+        let n = indices.next().unwrap();
+        assert_eq!(n.0, 0);
+        assert_eq!(n.1, 0x1f603);
+        let n = indices.next().unwrap();
+        assert_eq!(n.0, 1);
+        assert_eq!(n.1, 0x20);
+        let n = indices.next().unwrap();
+        assert_eq!(n.0, 2);
+        assert_eq!(n.1, 0x1f300);
+        let n = indices.next();
+        assert_eq!(n, None);
+    }
 }
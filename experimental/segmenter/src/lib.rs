@@ -4,29 +4,30 @@
 
 #![warn(missing_docs)]
 
-//! A line breaker that is compatible with [Unicode Standard Annex #14][UAX14] and CSS properties.
+//! A line breaker that is compatible with [Unicode Standard Annex #14][UAX14] and CSS properties,
+//! and a word breaker that is compatible with [Unicode Standard Annex #29][UAX29].
 //!
 //! [UAX14]: http://www.unicode.org/reports/tr14/
+//! [UAX29]: http://www.unicode.org/reports/tr29/
 //!
-//!```rust
-//! use icu_segmenter::LineBreakIterator;
+//! ```rust
+//! use icu_segmenter::LineBreakSegmenter;
 //!
-//! let mut iter = LineBreakIterator::new("Hello World");
-//! let result: Vec<usize> = iter.collect();
+//! let segmenter = LineBreakSegmenter::new();
+//! let result: Vec<usize> = segmenter.segment_str("Hello World").collect();
 //! println!("{:?}", result);
 //! ```
 //!
 //! With CSS property.
 //! ```rust
-//! use icu_segmenter::{LineBreakIterator, LineBreakRule, WordBreakRule};
-//!
-//! let iter = LineBreakIterator::new_with_break_rule(
-//!     "Hello World",
-//!     LineBreakRule::Strict,
-//!     WordBreakRule::BreakAll,
-//!     false,
-//! );
-//! let result: Vec<usize> = iter.collect();
+//! use icu_segmenter::{LineBreakOptions, LineBreakRule, LineBreakSegmenter, WordBreakRule};
+//!
+//! let segmenter = LineBreakSegmenter::new_with_options(LineBreakOptions {
+//!     line_break_rule: LineBreakRule::Strict,
+//!     word_break_rule: WordBreakRule::BreakAll,
+//!     ja_zh: false,
+//! });
+//! let result: Vec<usize> = segmenter.segment_str("Hello World").collect();
 //! println!("{:?}", result);
 //! ```
 //!
@@ -41,23 +42,69 @@
 //! println!("{:?}", result);
 //! ```
 //!
+//! Find word boundaries, along with the type of each word. The word break iterators also return
+//! the [`RuleStatus`] of the last boundary, as in ICU4C, which tells numbers, letters, kana, and
+//! ideographs apart from the segments that are not words.
+//!
+//! ```rust
+//! use icu_segmenter::WordBreakSegmenter;
+//!
+//! let segmenter = WordBreakSegmenter::new();
+//! let result: Vec<_> = segmenter.segment_str("Hello World").collect();
+//! println!("{:?}", result);
+//! ```
+//!
+//! Languages written without spaces between words, such as Thai, Lao, and Khmer, are segmented
+//! with a [`Dictionary`] of words when the segmenter is created with one for the language; see
+//! [`Dictionaries`]. Otherwise they are segmented with an LSTM model if there is one for the
+//! language: models for Thai and Burmese are built in, and models for other languages, such as
+//! Khmer, can be loaded from a data provider. LSTM models require the `lstm` feature, which is
+//! enabled by default.
+//!
+//! The exceptions of a locale to the default rules, such as the abbreviations after which a
+//! sentence does not end, or whether a colon between letters is part of a word, are loaded from
+//! a data provider with the `provider` feature; see [`provider::SegmenterExceptionsV1`].
+//!
+//! The line segmenter also finds the points where words can be hyphenated with a [`Hyphenator`],
+//! created from TeX hyphenation patterns or loaded from a data provider; see
+//! [`LineBreakSegmenter::segment_str_with_hyphens()`].
+//!
 //! # Generating property table
 //!
 //! Copy the following files to `tools` directory. Then run `./generate_properties.py` in `tools` directory (requires Python 3.8+). Machine generated files are moved to `src` directory.
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/LineBreak.txt>
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/EastAsianWidth.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/auxiliary/WordBreakProperty.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/emoji/emoji-data.txt>
 
+mod dictionary;
+#[cfg(feature = "provider")]
+pub mod error;
+mod hyphenation;
 mod indices;
 mod language;
 mod lb_define;
 mod line_breaker;
+#[cfg(feature = "lstm")]
 mod lstm;
 mod properties_defines;
 mod properties_other;
 mod property_table;
+#[cfg(feature = "provider")]
+pub mod provider;
 mod rule_table;
+mod wb_define;
+mod word_break_property_table;
+mod word_breaker;
 
+#[cfg(feature = "lstm")]
 #[macro_use]
 extern crate lazy_static;
 
+pub use crate::dictionary::{Dictionaries, Dictionary};
+#[cfg(feature = "provider")]
+pub use crate::error::Error as SegmenterError;
+pub use crate::hyphenation::*;
+pub use crate::language::Language;
 pub use crate::line_breaker::*;
+pub use crate::word_breaker::*;
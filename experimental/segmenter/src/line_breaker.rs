@@ -4,10 +4,11 @@
 
 extern crate unicode_width;
 
+use crate::dictionary::*;
+use crate::hyphenation::*;
 use crate::indices::*;
 use crate::language::*;
 use crate::lb_define::*;
-use crate::lstm::*;
 use crate::property_table::*;
 use crate::rule_table::*;
 
@@ -21,7 +22,7 @@ use unicode_width::UnicodeWidthChar;
 /// Each enum value has the same meaning with respect to the `line-break`
 /// property values in the CSS Text spec. See the details in
 /// <https://drafts.csswg.org/css-text-3/#line-break-property>.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LineBreakRule {
     /// Breaks text using the most common set of line-breaking rules.
     /// <https://drafts.csswg.org/css-text-3/#valdef-line-break-normal>
@@ -49,7 +50,7 @@ pub enum LineBreakRule {
 /// Each enum value has the same meaning with respect to the `word-break`
 /// property values in the CSS Text spec. See the details in
 /// <https://drafts.csswg.org/css-text-3/#word-break-property>
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum WordBreakRule {
     /// Words break according to their customary rules. See the details in
     /// <https://drafts.csswg.org/css-text-3/#valdef-word-break-normal>.
@@ -245,15 +246,14 @@ fn get_break_state(left: u8, right: u8) -> i8 {
 }
 
 #[inline]
-fn use_complex_breaking_utf32(codepoint: u32) -> bool {
+fn use_complex_breaking_utf32(codepoint: u32, dictionaries: Option<&Dictionaries>) -> bool {
     let line_break_property = get_linebreak_property_utf32_with_rule(
         codepoint,
         LineBreakRule::Strict,
         WordBreakRule::Normal,
     );
 
-    line_break_property == SA
-        && matches!(get_language(codepoint), Language::Thai | Language::Burmese)
+    line_break_property == SA && get_complex_model(dictionaries, get_language(codepoint)).is_some()
 }
 
 /*
@@ -281,6 +281,7 @@ macro_rules! break_iterator_impl {
             line_break_rule: LineBreakRule,
             word_break_rule: WordBreakRule,
             ja_zh: bool,
+            dictionaries: Option<&'a Dictionaries>,
         }
 
         impl<'a> Iterator for $name<'a> {
@@ -367,8 +368,8 @@ macro_rules! break_iterator_impl {
 
                     // UAX14 doesn't have Thai etc, so use another way.
                     if self.word_break_rule != WordBreakRule::BreakAll
-                        && $name::use_complex_breaking(left_codepoint.unwrap().1)
-                        && $name::use_complex_breaking(self.current_pos_data.unwrap().1)
+                        && self.use_complex_breaking(left_codepoint.unwrap().1)
+                        && self.use_complex_breaking(self.current_pos_data.unwrap().1)
                     {
                         let result = self.handle_complex_language(left_codepoint.unwrap().1);
                         if result.is_some() {
@@ -448,7 +449,7 @@ macro_rules! break_iterator_impl {
                     if self.current_pos_data.is_none() {
                         break;
                     }
-                    if !$name::use_complex_breaking(self.current_pos_data.unwrap().1) {
+                    if !self.use_complex_breaking(self.current_pos_data.unwrap().1) {
                         break;
                     }
                 }
@@ -490,6 +491,7 @@ impl<'a> LineBreakIterator<'a> {
             line_break_rule: LineBreakRule::Strict,
             word_break_rule: WordBreakRule::Normal,
             ja_zh: false,
+            dictionaries: None,
         }
     }
 
@@ -513,6 +515,7 @@ impl<'a> LineBreakIterator<'a> {
             line_break_rule,
             word_break_rule,
             ja_zh,
+            dictionaries: None,
         }
     }
 
@@ -529,16 +532,12 @@ impl<'a> LineBreakIterator<'a> {
     }
 
     #[inline]
-    fn use_complex_breaking(c: char) -> bool {
-        use_complex_breaking_utf32(c as u32)
+    fn use_complex_breaking(&self, c: char) -> bool {
+        use_complex_breaking_utf32(c as u32, self.dictionaries)
     }
 
     fn get_line_break_by_platform_fallback(&mut self, input: &[u16]) -> Vec<usize> {
-        if let Some(mut ret) = get_line_break_utf16(input) {
-            ret.push(input.len());
-            return ret;
-        }
-        [input.len()].to_vec()
+        get_complex_line_break_utf16(self.dictionaries, input)
     }
 
     /*
@@ -577,6 +576,7 @@ impl<'a> LineBreakIteratorLatin1<'a> {
             line_break_rule: LineBreakRule::Strict,
             word_break_rule: WordBreakRule::Normal,
             ja_zh: false,
+            dictionaries: None,
         }
     }
 
@@ -595,6 +595,7 @@ impl<'a> LineBreakIteratorLatin1<'a> {
             line_break_rule,
             word_break_rule,
             ja_zh: false,
+            dictionaries: None,
         }
     }
 
@@ -613,7 +614,7 @@ impl<'a> LineBreakIteratorLatin1<'a> {
     }
 
     #[inline]
-    fn use_complex_breaking(_c: u8) -> bool {
+    fn use_complex_breaking(&self, _c: u8) -> bool {
         false
     }
 
@@ -636,6 +637,7 @@ impl<'a> LineBreakIteratorUtf16<'a> {
             line_break_rule: LineBreakRule::Strict,
             word_break_rule: WordBreakRule::Normal,
             ja_zh: false,
+            dictionaries: None,
         }
     }
 
@@ -659,6 +661,7 @@ impl<'a> LineBreakIteratorUtf16<'a> {
             line_break_rule,
             word_break_rule,
             ja_zh,
+            dictionaries: None,
         }
     }
 
@@ -675,16 +678,288 @@ impl<'a> LineBreakIteratorUtf16<'a> {
     }
 
     #[inline]
-    fn use_complex_breaking(c: u32) -> bool {
-        use_complex_breaking_utf32(c)
+    fn use_complex_breaking(&self, c: u32) -> bool {
+        use_complex_breaking_utf32(c, self.dictionaries)
     }
 
     fn get_line_break_by_platform_fallback(&mut self, input: &[u16]) -> Vec<usize> {
-        if let Some(mut ret) = get_line_break_utf16(input) {
-            ret.push(input.len());
-            return ret;
+        get_complex_line_break_utf16(self.dictionaries, input)
+    }
+}
+
+break_iterator_impl!(LineBreakIteratorUtf32, Utf32Indices<'a>, u32);
+
+/// UTF-32 version of line break iterator, operating on a slice of code points.
+impl<'a> LineBreakIteratorUtf32<'a> {
+    /// Create a line break iterator for a UTF-32 string.
+    pub fn new(input: &[u32]) -> LineBreakIteratorUtf32 {
+        LineBreakIteratorUtf32 {
+            iter: Utf32Indices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            line_break_rule: LineBreakRule::Strict,
+            word_break_rule: WordBreakRule::Normal,
+            ja_zh: false,
+            dictionaries: None,
+        }
+    }
+
+    /// Create a line break iterator with CSS rules for a UTF-32 string.
+    ///
+    /// * `ja_zh` - Use `true` as a hint to the line breaker that the writing
+    /// system is Chinese or Japanese. This allows more break opportunities when
+    /// [`LineBreakRule`] is `Normal` or `Loose`. See
+    /// <https://drafts.csswg.org/css-text-3/#line-break-property> for details.
+    pub fn new_with_break_rule(
+        input: &[u32],
+        line_break_rule: LineBreakRule,
+        word_break_rule: WordBreakRule,
+        ja_zh: bool,
+    ) -> LineBreakIteratorUtf32 {
+        LineBreakIteratorUtf32 {
+            iter: Utf32Indices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            line_break_rule,
+            word_break_rule,
+            ja_zh,
+            dictionaries: None,
+        }
+    }
+
+    fn get_linebreak_property(&mut self) -> u8 {
+        self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
+    }
+
+    fn get_linebreak_property_with_rule(&mut self, c: u32) -> u8 {
+        get_linebreak_property_utf32_with_rule(c, self.line_break_rule, self.word_break_rule)
+    }
+
+    fn is_break_by_normal(&mut self) -> bool {
+        is_break_utf32_by_normal(self.current_pos_data.unwrap().1, self.ja_zh)
+    }
+
+    #[inline]
+    fn use_complex_breaking(&self, c: u32) -> bool {
+        use_complex_breaking_utf32(c, self.dictionaries)
+    }
+
+    fn get_line_break_by_platform_fallback(&mut self, input: &[u16]) -> Vec<usize> {
+        get_complex_line_break_utf16(self.dictionaries, input)
+    }
+}
+
+/// Options to tailor the line breaking of a [`LineBreakSegmenter`], following the CSS
+/// `line-break` and `word-break` properties.
+///
+/// The default options break lines with [`LineBreakRule::Strict`] and
+/// [`WordBreakRule::Normal`], without assuming Chinese or Japanese text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineBreakOptions {
+    /// The strictness of the line breaking rules.
+    pub line_break_rule: LineBreakRule,
+
+    /// The line break opportunities between letters, including between CJK characters.
+    pub word_break_rule: WordBreakRule,
+
+    /// Use `true` as a hint that the writing system is Chinese or Japanese. This allows more
+    /// break opportunities when `line_break_rule` is [`LineBreakRule::Normal`] or
+    /// [`LineBreakRule::Loose`].
+    pub ja_zh: bool,
+}
+
+impl Default for LineBreakOptions {
+    fn default() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Strict,
+            word_break_rule: WordBreakRule::Normal,
+            ja_zh: false,
         }
-        [input.len()].to_vec()
+    }
+}
+
+/// A line segmenter finding the line break opportunities of a text, according to the
+/// [`LineBreakOptions`] it was created with.
+///
+/// The break opportunities are determined from the `Line_Break` property of each code point
+/// and the rules of [Unicode Standard Annex #14][UAX14].
+///
+/// [UAX14]: http://www.unicode.org/reports/tr14/
+///
+/// # Examples
+///
+/// ```
+/// use icu_segmenter::{LineBreakOptions, LineBreakRule, LineBreakSegmenter, WordBreakRule};
+///
+/// let segmenter = LineBreakSegmenter::new();
+/// let breakpoints: Vec<usize> = segmenter.segment_str("Hello World").collect();
+/// assert_eq!(&breakpoints, &[6, 11]);
+///
+/// let segmenter = LineBreakSegmenter::new_with_options(LineBreakOptions {
+///     line_break_rule: LineBreakRule::Strict,
+///     word_break_rule: WordBreakRule::BreakAll,
+///     ja_zh: false,
+/// });
+/// let breakpoints: Vec<usize> = segmenter.segment_str("Hello").collect();
+/// assert_eq!(&breakpoints, &[1, 2, 3, 4, 5]);
+/// ```
+///
+/// Segment a string of code points:
+///
+/// ```
+/// use icu_segmenter::LineBreakSegmenter;
+///
+/// let segmenter = LineBreakSegmenter::new();
+/// let code_points: Vec<u32> = "Hello World".chars().map(|ch| ch as u32).collect();
+/// let breakpoints: Vec<usize> = segmenter.segment_utf32(&code_points).collect();
+/// assert_eq!(&breakpoints, &[6, 11]);
+/// ```
+///
+/// Segment Lao text, which has no spaces between words, with a dictionary:
+///
+/// ```
+/// use icu_segmenter::{Dictionaries, Dictionary, Language, LineBreakSegmenter};
+///
+/// let mut dictionaries = Dictionaries::new();
+/// dictionaries.insert(Language::Lao, Dictionary::from_words(["ພາສາ", "ລາວ"]));
+/// let segmenter = LineBreakSegmenter::new_with_dictionaries(Default::default(), dictionaries);
+/// let breakpoints: Vec<usize> = segmenter.segment_str("ພາສາລາວ").collect();
+/// assert_eq!(&breakpoints, &[12, 21]);
+/// ```
+///
+/// Find the hyphenation points of the words along with the line break opportunities, with a
+/// [`Hyphenator`]:
+///
+/// ```
+/// use icu_segmenter::{Hyphenator, LineBreakSegmenter, LineBreakType};
+///
+/// let mut segmenter = LineBreakSegmenter::new();
+/// segmenter.set_hyphenator(Hyphenator::from_patterns([
+///     "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+/// ]));
+/// let breakpoints: Vec<(usize, LineBreakType)> =
+///     segmenter.segment_str_with_hyphens("Hyphenation rules").collect();
+/// assert_eq!(
+///     &breakpoints,
+///     &[
+///         (2, LineBreakType::Hyphen),
+///         (6, LineBreakType::Hyphen),
+///         (12, LineBreakType::Break),
+///         (17, LineBreakType::Break),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LineBreakSegmenter {
+    options: LineBreakOptions,
+    dictionaries: Dictionaries,
+    hyphenator: Option<Hyphenator>,
+}
+
+impl LineBreakSegmenter {
+    /// Create a line segmenter with the default [`LineBreakOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a line segmenter with the given [`LineBreakOptions`].
+    pub fn new_with_options(options: LineBreakOptions) -> Self {
+        Self {
+            options,
+            dictionaries: Dictionaries::default(),
+            hyphenator: None,
+        }
+    }
+
+    /// Create a line segmenter with the given [`LineBreakOptions`], using `dictionaries` to find
+    /// the line break opportunities in the languages written without spaces between words.
+    pub fn new_with_dictionaries(options: LineBreakOptions, dictionaries: Dictionaries) -> Self {
+        Self {
+            options,
+            dictionaries,
+            hyphenator: None,
+        }
+    }
+
+    /// Returns the options of this segmenter.
+    pub fn options(&self) -> &LineBreakOptions {
+        &self.options
+    }
+
+    /// Set the hyphenator used to find the hyphenation points of the words by
+    /// [`segment_str_with_hyphens()`](Self::segment_str_with_hyphens).
+    pub fn set_hyphenator(&mut self, hyphenator: Hyphenator) {
+        self.hyphenator = Some(hyphenator);
+    }
+
+    /// Create a line break iterator for an `str` (a UTF-8 string).
+    ///
+    /// The break opportunities are byte indices into `input`.
+    pub fn segment_str<'s>(&'s self, input: &'s str) -> LineBreakIterator<'s> {
+        let mut iter = LineBreakIterator::new_with_break_rule(
+            input,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+            self.options.ja_zh,
+        );
+        iter.dictionaries = Some(&self.dictionaries);
+        iter
+    }
+
+    /// Create a line break iterator for an `str` (a UTF-8 string), which also returns the
+    /// hyphenation points of the words found by the hyphenator of the segmenter, if any; see
+    /// [`set_hyphenator()`](Self::set_hyphenator).
+    ///
+    /// The break opportunities are byte indices into `input`, along with their
+    /// [`LineBreakType`].
+    pub fn segment_str_with_hyphens<'s>(
+        &'s self,
+        input: &'s str,
+    ) -> HyphenatedLineBreakIterator<'s> {
+        HyphenatedLineBreakIterator::new(input, self.segment_str(input), self.hyphenator.as_ref())
+    }
+
+    /// Create a line break iterator for a Latin-1 (8-bit) string.
+    ///
+    /// The break opportunities are indices into `input`.
+    pub fn segment_latin1<'s>(&'s self, input: &'s [u8]) -> LineBreakIteratorLatin1<'s> {
+        let mut iter = LineBreakIteratorLatin1::new_with_break_rule(
+            input,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+        );
+        iter.dictionaries = Some(&self.dictionaries);
+        iter
+    }
+
+    /// Create a line break iterator for a UTF-16 string.
+    ///
+    /// The break opportunities are indices of 16-bit code units into `input`.
+    pub fn segment_utf16<'s>(&'s self, input: &'s [u16]) -> LineBreakIteratorUtf16<'s> {
+        let mut iter = LineBreakIteratorUtf16::new_with_break_rule(
+            input,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+            self.options.ja_zh,
+        );
+        iter.dictionaries = Some(&self.dictionaries);
+        iter
+    }
+
+    /// Create a line break iterator for a UTF-32 string, a slice of code points.
+    ///
+    /// The break opportunities are indices into `input`.
+    pub fn segment_utf32<'s>(&'s self, input: &'s [u32]) -> LineBreakIteratorUtf32<'s> {
+        let mut iter = LineBreakIteratorUtf32::new_with_break_rule(
+            input,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+            self.options.ja_zh,
+        );
+        iter.dictionaries = Some(&self.dictionaries);
+        iter
     }
 }
 
@@ -696,7 +971,10 @@ mod tests {
     use crate::LineBreakIterator;
     use crate::LineBreakIteratorLatin1;
     use crate::LineBreakIteratorUtf16;
+    use crate::LineBreakIteratorUtf32;
+    use crate::LineBreakOptions;
     use crate::LineBreakRule;
+    use crate::LineBreakSegmenter;
     use crate::WordBreakRule;
 
     fn get_linebreak_property(codepoint: char) -> u8 {
@@ -895,4 +1173,53 @@ mod tests {
         iter = LineBreakIterator::new("\u{1F3FB} \u{1F3FB}");
         assert_eq!(Some(5), iter.next());
     }
+
+    #[test]
+    fn linebreak_utf32() {
+        let input: Vec<u32> = "\u{1F3FB} \u{1F3FB}".chars().map(|ch| ch as u32).collect();
+        let mut iter = LineBreakIteratorUtf32::new(&input);
+        assert_eq!(Some(2), iter.next());
+        assert_eq!(Some(3), iter.next());
+        assert_eq!(None, iter.next());
+
+        // LB14
+        let input: [u32; 10] = [0x5B, 0x20, 0x20, 0x61, 0x62, 0x63, 0x20, 0x64, 0x65, 0x66];
+        let mut iter = LineBreakIteratorUtf32::new(&input);
+        assert_eq!(Some(7), iter.next());
+        assert_eq!(Some(10), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn segmenter_options() {
+        let s = "\u{3042}\u{3041}";
+
+        // Small kana are CJ, which is treated as NS in strict line breaking.
+        let segmenter = LineBreakSegmenter::new();
+        let result: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(result, [6]);
+
+        let segmenter = LineBreakSegmenter::new_with_options(LineBreakOptions {
+            line_break_rule: LineBreakRule::Normal,
+            ..Default::default()
+        });
+        let result: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(result, [3, 6]);
+
+        let input: Vec<u32> = s.chars().map(|ch| ch as u32).collect();
+        let result: Vec<usize> = segmenter.segment_utf32(&input).collect();
+        assert_eq!(result, [1, 2]);
+
+        let input: Vec<u16> = s.encode_utf16().collect();
+        let result: Vec<usize> = segmenter.segment_utf16(&input).collect();
+        assert_eq!(result, [1, 2]);
+
+        let segmenter = LineBreakSegmenter::new_with_options(LineBreakOptions {
+            line_break_rule: LineBreakRule::Normal,
+            word_break_rule: WordBreakRule::KeepAll,
+            ja_zh: true,
+        });
+        let result: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(result, [6]);
+    }
 }
@@ -5,8 +5,6 @@
 use crate::language::*;
 
 use icu_segmenter_lstm::lstm::Lstm;
-use std::char::decode_utf16;
-use std::str::Chars;
 
 // TODO:
 // json file is big, So I should use anoher binary format like npy.
@@ -28,188 +26,28 @@ lazy_static! {
     };
 }
 
-// LSTM model depends on language, So we have to switch models per language.
-fn get_best_lstm_model(codepoint: u32) -> &'static Lstm {
-    let lang = get_language(codepoint);
-    match lang {
-        Language::Thai => &*THAI_LSTM,
-        Language::Burmese => &*BURMESE_LSTM,
-        _ => panic!("Unsupported"),
-    }
-}
-
-/// This struct is an iterator that returns the string per language from the
-/// given string.
-///
-/// Actually supported LSTM model is Thai and Burmese only. If using other
-/// code point, it causes panic.
-struct LanguageIterator<'a> {
-    input: Chars<'a>,
-    last: Option<char>,
-}
-
-impl<'a> LanguageIterator<'a> {
-    pub fn new(input: &'a str) -> Self {
-        let mut input = input.chars();
-        let last = input.next();
-        Self { input, last }
-    }
-}
-
-impl<'a> Iterator for LanguageIterator<'a> {
-    type Item = String;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut s = "".to_string();
-
-        let lang = get_language(self.last? as u32);
-        s.push(self.last.unwrap());
-        loop {
-            let c = self.input.next();
-            if c.is_none() {
-                self.last = None;
-                break;
-            }
-            self.last = c;
-            let new_lang = get_language(c.unwrap() as u32);
-            if lang != new_lang {
-                break;
-            }
-            s.push(c.unwrap());
-        }
-        Some(s)
-    }
-}
-
-// A word break iterator using LSTM model. Input string have to be same language.
-
-struct LstmSegmenterIterator {
-    input: String,
-    bies_str: String,
-    pos: usize,
-    pos_utf8: usize,
-}
-
-impl Iterator for LstmSegmenterIterator {
-    type Item = usize;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let ch = self.bies_str.chars().nth(self.pos)?;
-            self.pos_utf8 += self.input.chars().nth(self.pos).unwrap().len_utf8();
-            self.pos += 1;
-            if ch == 'e' && self.bies_str.len() > self.pos {
-                return Some(self.pos_utf8);
-            }
-        }
-    }
-}
-
-impl LstmSegmenterIterator {
-    #[cfg(test)]
-    pub fn new(lstm: &Lstm, input: &str) -> Self {
-        let lstm_output = lstm.word_segmenter(input);
-        Self {
-            input: input.to_string(),
-            bies_str: lstm_output,
-            pos: 0,
-            pos_utf8: 0,
-        }
-    }
-}
-
-struct LstmSegmenterIteratorUtf16 {
-    bies_str: String,
-    pos: usize,
-}
-
-impl Iterator for LstmSegmenterIteratorUtf16 {
-    type Item = usize;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let ch = self.bies_str.chars().nth(self.pos)?;
-            // This ch is always in bitmap.
-            self.pos += 1;
-            if ch == 'e' && self.bies_str.len() > self.pos {
-                return Some(self.pos);
-            }
-        }
-    }
-}
-
-impl LstmSegmenterIteratorUtf16 {
-    pub fn new(lstm: &Lstm, input: &str) -> Self {
-        let lstm_output = lstm.word_segmenter(input);
-        Self {
-            bies_str: lstm_output,
-            pos: 0,
-        }
+/// Returns the LSTM model built into the segmenter for the language, if
+/// there is one.
+pub fn get_builtin_lstm_model(language: Language) -> Option<&'static Lstm> {
+    match language {
+        Language::Thai => Some(&*THAI_LSTM),
+        Language::Burmese => Some(&*BURMESE_LSTM),
+        _ => None,
     }
 }
 
-#[cfg(test)]
-pub fn get_line_break_utf8(input: &str) -> Option<Vec<usize>> {
-    let mut result: Vec<usize> = Vec::new();
-    let mut lang_iter = LanguageIterator::new(input);
-    let mut offset = 0;
-    loop {
-        let str_per_lang = lang_iter.next();
-        if str_per_lang.is_none() {
-            break;
-        }
-        if offset != 0 {
-            result.push(offset);
-        }
-
-        let str_per_lang = str_per_lang.unwrap();
-        let lstm = get_best_lstm_model(str_per_lang.chars().next().unwrap() as u32);
-        let lstm_iter = LstmSegmenterIterator::new(lstm, &str_per_lang);
-        let mut r: Vec<usize> = lstm_iter.map(|n| offset + n).collect();
-        result.append(&mut r);
-        offset += str_per_lang.len();
-    }
-    if result.is_empty() {
-        return None;
-    }
-    Some(result)
-}
-
-pub fn get_line_break_utf16(input: &[u16]) -> Option<Vec<usize>> {
-    let s: String = decode_utf16(input.iter().cloned())
-        .map(|r| r.unwrap())
-        .collect();
-    let mut result: Vec<usize> = Vec::new();
-    let mut offset = 0;
-    for str_per_lang in LanguageIterator::new(&s) {
-        if offset != 0 {
-            // language break
-            result.push(offset);
-        }
-
-        let lstm = get_best_lstm_model(str_per_lang.chars().next().unwrap() as u32);
-        let lstm_iter = LstmSegmenterIteratorUtf16::new(lstm, &str_per_lang);
-        let mut r: Vec<usize> = lstm_iter.map(|n| offset + n).collect();
-        result.append(&mut r);
-        offset += str_per_lang.chars().fold(0, |n, c| n + c.len_utf16());
-    }
-    if result.is_empty() {
-        return None;
-    }
-    Some(result)
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::lstm::get_line_break_utf16;
-    use crate::lstm::get_line_break_utf8;
+    use crate::dictionary::get_complex_line_break_utf16;
+    use crate::language::Language;
+    use crate::lstm::get_builtin_lstm_model;
 
     #[test]
     fn thai_word_break() {
         const TEST_STR: &str = "ภาษาไทยภาษาไทย";
 
-        let breaks = get_line_break_utf8(TEST_STR);
-        assert_eq!(breaks.unwrap(), [12, 21, 33], "Thai test");
+        let lstm = get_builtin_lstm_model(Language::Thai).unwrap();
+        assert_eq!(lstm.word_breaks(TEST_STR), [4, 7, 11], "Thai test");
     }
 
     #[test]
@@ -218,12 +56,12 @@ mod tests {
             0x0e20, 0x0e32, 0x0e29, 0x0e32, 0x0e44, 0x0e17, 0x0e22, 0x0e20, 0x0e32, 0x0e29, 0x0e32,
             0x0e44, 0x0e17, 0x0e22,
         ];
-        let breaks = get_line_break_utf16(&text);
-        assert_eq!(breaks.unwrap(), [4, 7, 11], "Thai test");
+        let breaks = get_complex_line_break_utf16(None, &text);
+        assert_eq!(breaks, [4, 7, 11, 14], "Thai test");
 
         let text: [u16; 4] = [0x0e20, 0x0e32, 0x0e29, 0x0e32];
-        let breaks = get_line_break_utf16(&text);
-        assert_eq!(breaks, None, "Thai test");
+        let breaks = get_complex_line_break_utf16(None, &text);
+        assert_eq!(breaks, [4], "Thai test");
     }
 
     #[test]
@@ -231,9 +69,9 @@ mod tests {
         // "Burmese Language" in Burmese
         const TEST_STR: &str = "မြန်မာဘာသာစကား";
 
-        let breaks = get_line_break_utf8(TEST_STR);
-        // LSTM model breaks more characters, but it is better to return [30].
-        assert_eq!(breaks.unwrap(), [12, 18, 30], "Burmese test");
+        let lstm = get_builtin_lstm_model(Language::Burmese).unwrap();
+        // LSTM model breaks more characters, but it is better to return [10].
+        assert_eq!(lstm.word_breaks(TEST_STR), [4, 6, 10], "Burmese test");
     }
 
     #[test]
@@ -243,9 +81,9 @@ mod tests {
             0x1019, 0x103c, 0x1014, 0x103a, 0x1019, 0x102c, 0x1018, 0x102c, 0x101e, 0x102c, 0x1005,
             0x1000, 0x102c, 0x1038,
         ];
-        let breaks = get_line_break_utf16(&text);
-        // LSTM model breaks more characters, but it is better to return [10].
-        assert_eq!(breaks.unwrap(), [4, 6, 10], "Burmese utf-16 test");
+        let breaks = get_complex_line_break_utf16(None, &text);
+        // LSTM model breaks more characters, but it is better to return [10, 14].
+        assert_eq!(breaks, [4, 6, 10, 14], "Burmese utf-16 test");
     }
 
     #[test]
@@ -255,10 +93,11 @@ mod tests {
         let mut sample = String::from(TEST_STR_THAI);
         sample.push_str(TEST_STR_BURMESE);
 
-        let breaks = get_line_break_utf8(&sample);
+        let text: Vec<u16> = sample.encode_utf16().collect();
+        let breaks = get_complex_line_break_utf16(None, &text);
         assert_eq!(
-            breaks.unwrap(),
-            [12, 21, 33, 42, 51, 63, 75, 87],
+            breaks,
+            [4, 7, 11, 14, 17, 21, 25, 29, text.len()],
             "Combined test"
         );
     }
@@ -0,0 +1,347 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+#[cfg(feature = "lstm")]
+use crate::dictionary::Dictionaries;
+#[cfg(feature = "lstm")]
+use crate::language::Language;
+use crate::SegmenterError;
+use crate::{Hyphenator, LineBreakSegmenter, WordBreakSegmenter};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+#[cfg(feature = "lstm")]
+use icu_segmenter_lstm::structs::LstmData;
+use serde::{Deserialize, Serialize};
+
+pub mod key {
+    //! Resource keys for [`icu_segmenter`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the weights of an LSTM model that finds word boundaries.
+    ///
+    /// The language of the request selects the model, such as `"th"` for Thai.
+    #[cfg(feature = "lstm")]
+    pub const LSTM_V1: ResourceKey = resource_key!(Segmenter, "lstm", 1);
+
+    /// Resource key: the exceptions of a locale to the default segmentation
+    /// rules.
+    pub const EXCEPTIONS_V1: ResourceKey = resource_key!(Segmenter, "exceptions", 1);
+
+    /// Resource key: the TeX hyphenation patterns of a language.
+    pub const HYPHENATION_V1: ResourceKey = resource_key!(Segmenter, "hyphenation", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_segmenter`](crate), grouped by
+/// data struct.
+#[cfg(feature = "lstm")]
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(SegmenterExceptionsV1Marker, [key::EXCEPTIONS_V1]),
+    icu_provider::registered_keys!(HyphenationPatternsV1Marker, [key::HYPHENATION_V1]),
+    icu_provider::registered_keys!(LstmDataV1Marker, [key::LSTM_V1]),
+];
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_segmenter`](crate), grouped by
+/// data struct.
+#[cfg(not(feature = "lstm"))]
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(SegmenterExceptionsV1Marker, [key::EXCEPTIONS_V1]),
+    icu_provider::registered_keys!(HyphenationPatternsV1Marker, [key::HYPHENATION_V1]),
+];
+
+/// The exceptions of a locale to the default segmentation rules, following
+/// the segmentation tailorings and suppressions of CLDR.
+///
+/// Load them into a word segmenter with
+/// [`WordBreakSegmenter::load_exceptions()`](crate::WordBreakSegmenter::load_exceptions).
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[yoke(cloning_zcf)]
+pub struct SegmenterExceptionsV1 {
+    /// Abbreviations ending with a full stop, such as "Dr." or "etc." in
+    /// English, after which a sentence does not end.
+    pub sentence_suppressions: Vec<String>,
+
+    /// Whether a colon between letters is part of a word, as in the Swedish
+    /// "EU:s". Unicode Standard Annex #29 treats the colon this way in every
+    /// language, but CLDR does only in Finnish and Swedish.
+    pub colon_in_words: bool,
+}
+
+impl Default for SegmenterExceptionsV1 {
+    /// Returns the exceptions of the default rules of Unicode Standard Annex
+    /// #29, with no sentence suppressions and colons in words.
+    fn default() -> Self {
+        Self {
+            sentence_suppressions: Vec::new(),
+            colon_in_words: true,
+        }
+    }
+}
+
+impl SegmenterExceptionsV1 {
+    /// Returns whether a sentence does not end after `text`, because `text`
+    /// ends with one of the [`sentence_suppressions`](Self::sentence_suppressions),
+    /// not counting trailing white space.
+    ///
+    /// A suppression only matches a whole word: "p." matches "See p." but not
+    /// "A long trip.".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_segmenter::provider::SegmenterExceptionsV1;
+    ///
+    /// let exceptions = SegmenterExceptionsV1 {
+    ///     sentence_suppressions: vec!["Dr.".to_string(), "p.".to_string()],
+    ///     colon_in_words: false,
+    /// };
+    ///
+    /// assert!(exceptions.is_sentence_break_suppressed("I met Dr. "));
+    /// assert!(exceptions.is_sentence_break_suppressed("See p."));
+    /// assert!(!exceptions.is_sentence_break_suppressed("I met Dr. Smith. "));
+    /// assert!(!exceptions.is_sentence_break_suppressed("A long trip."));
+    /// ```
+    pub fn is_sentence_break_suppressed(&self, text: &str) -> bool {
+        let text = text.trim_end();
+        self.sentence_suppressions.iter().any(|suppression| {
+            text.strip_suffix(suppression.as_str())
+                .map_or(false, |before| {
+                    !before
+                        .chars()
+                        .next_back()
+                        .map_or(false, char::is_alphanumeric)
+                })
+        })
+    }
+}
+
+impl WordBreakSegmenter {
+    /// Load the exceptions of `langid` to the default word boundary rules
+    /// from a data provider, and use them to find the word boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::struct_provider::StructProvider;
+    /// use icu_segmenter::provider::{key, SegmenterExceptionsV1, SegmenterExceptionsV1Marker};
+    /// use icu_segmenter::WordBreakSegmenter;
+    ///
+    /// let provider = StructProvider {
+    ///     key: key::EXCEPTIONS_V1,
+    ///     data: DataPayload::<SegmenterExceptionsV1Marker>::from_owned(SegmenterExceptionsV1 {
+    ///         sentence_suppressions: vec!["Dr.".to_string()],
+    ///         colon_in_words: false,
+    ///     }),
+    /// };
+    ///
+    /// let mut segmenter = WordBreakSegmenter::new();
+    /// let boundaries: Vec<usize> = segmenter.segment_str("EU:s").map(|(i, _)| i).collect();
+    /// assert_eq!(&boundaries, &[4]);
+    ///
+    /// segmenter
+    ///     .load_exceptions(&provider, &"en".parse().unwrap())
+    ///     .expect("Data should load successfully");
+    /// let boundaries: Vec<usize> = segmenter.segment_str("EU:s").map(|(i, _)| i).collect();
+    /// assert_eq!(&boundaries, &[2, 3, 4]);
+    /// ```
+    pub fn load_exceptions<'data, D>(
+        &mut self,
+        provider: &D,
+        langid: &LanguageIdentifier,
+    ) -> Result<(), SegmenterError>
+    where
+        D: DataProvider<'data, SegmenterExceptionsV1Marker> + ?Sized,
+    {
+        let payload: DataPayload<SegmenterExceptionsV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::EXCEPTIONS_V1,
+                    options: langid.clone().into(),
+                },
+            })?
+            .take_payload()?;
+        self.colon_in_words = payload.get().colon_in_words;
+        Ok(())
+    }
+}
+
+/// The TeX hyphenation patterns of a language, such as the ones of the
+/// `hyph-utf8` package, used to find the hyphenation points of words.
+///
+/// Load them into a line segmenter with
+/// [`LineBreakSegmenter::load_hyphenation()`](crate::LineBreakSegmenter::load_hyphenation).
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[yoke(cloning_zcf)]
+pub struct HyphenationPatternsV1 {
+    /// The patterns, such as `"hy3ph"`; see [`Hyphenator`](crate::Hyphenator).
+    pub patterns: Vec<String>,
+
+    /// The words hyphenated without patterns, with their hyphens, such as
+    /// `"ta-ble"`.
+    pub exceptions: Vec<String>,
+
+    /// The minimum number of code points of a word before a hyphen.
+    pub left_min: u8,
+
+    /// The minimum number of code points of a word after a hyphen.
+    pub right_min: u8,
+}
+
+impl From<&HyphenationPatternsV1> for Hyphenator {
+    fn from(data: &HyphenationPatternsV1) -> Self {
+        let mut hyphenator = Hyphenator::from_patterns(&data.patterns);
+        for exception in &data.exceptions {
+            hyphenator.insert_exception(exception);
+        }
+        hyphenator.set_min_lengths(data.left_min as usize, data.right_min as usize);
+        hyphenator
+    }
+}
+
+impl LineBreakSegmenter {
+    /// Load the hyphenation patterns of `langid` from a data provider, and use
+    /// them to find the hyphenation points of the words with
+    /// [`segment_str_with_hyphens()`](Self::segment_str_with_hyphens).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::struct_provider::StructProvider;
+    /// use icu_segmenter::provider::{key, HyphenationPatternsV1, HyphenationPatternsV1Marker};
+    /// use icu_segmenter::{LineBreakSegmenter, LineBreakType};
+    ///
+    /// let provider = StructProvider {
+    ///     key: key::HYPHENATION_V1,
+    ///     data: DataPayload::<HyphenationPatternsV1Marker>::from_owned(HyphenationPatternsV1 {
+    ///         patterns: vec!["1ta".to_string(), "a1b".to_string()],
+    ///         exceptions: vec![],
+    ///         left_min: 2,
+    ///         right_min: 3,
+    ///     }),
+    /// };
+    ///
+    /// let mut segmenter = LineBreakSegmenter::new();
+    /// segmenter
+    ///     .load_hyphenation(&provider, &"en".parse().unwrap())
+    ///     .expect("Data should load successfully");
+    /// let breakpoints: Vec<(usize, LineBreakType)> =
+    ///     segmenter.segment_str_with_hyphens("notable").collect();
+    /// assert_eq!(
+    ///     &breakpoints,
+    ///     &[(2, LineBreakType::Hyphen), (4, LineBreakType::Hyphen), (7, LineBreakType::Break)]
+    /// );
+    /// ```
+    pub fn load_hyphenation<'data, D>(
+        &mut self,
+        provider: &D,
+        langid: &LanguageIdentifier,
+    ) -> Result<(), SegmenterError>
+    where
+        D: DataProvider<'data, HyphenationPatternsV1Marker> + ?Sized,
+    {
+        let payload: DataPayload<HyphenationPatternsV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::HYPHENATION_V1,
+                    options: langid.clone().into(),
+                },
+            })?
+            .take_payload()?;
+        self.set_hyphenator(payload.get().into());
+        Ok(())
+    }
+}
+
+/// The weights of an LSTM model that finds the word boundaries of a language
+/// written without spaces between words.
+#[cfg(feature = "lstm")]
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[yoke(cloning_zcf)]
+pub struct LstmDataV1 {
+    /// The name, the embedding dictionary, and the weight matrices of the
+    /// model, in the format of `icu_segmenter_lstm`.
+    pub model: LstmData,
+}
+
+/// Returns the language identifier used to request the data of a language.
+#[cfg(feature = "lstm")]
+fn get_language_identifier(language: Language) -> Option<LanguageIdentifier> {
+    let id = match language {
+        Language::Burmese => "my",
+        Language::Khmer => "km",
+        Language::Lao => "lo",
+        Language::Thai => "th",
+        Language::Unknown => return None,
+    };
+    id.parse().ok()
+}
+
+#[cfg(feature = "lstm")]
+impl Dictionaries {
+    /// Load the LSTM models of `languages` from a data provider, and use them
+    /// to segment the text of these languages when there is no dictionary for
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::struct_provider::StructProvider;
+    /// use icu_segmenter::provider::{key, LstmDataV1, LstmDataV1Marker};
+    /// use icu_segmenter::{Dictionaries, Language};
+    ///
+    /// # fn load_weights() -> icu_segmenter_lstm::structs::LstmData {
+    /// #     serde_json::from_slice(include_bytes!(
+    /// #         "../data/Burmese_codepoints_exclusive_model4_heavy/weights.json"
+    /// #     ))
+    /// #     .unwrap()
+    /// # }
+    /// let provider = StructProvider {
+    ///     key: key::LSTM_V1,
+    ///     data: DataPayload::<LstmDataV1Marker>::from_owned(LstmDataV1 {
+    ///         model: load_weights(),
+    ///     }),
+    /// };
+    ///
+    /// let mut dictionaries = Dictionaries::new();
+    /// dictionaries
+    ///     .load_lstm(&provider, &[Language::Burmese])
+    ///     .expect("Data should load successfully");
+    /// ```
+    pub fn load_lstm<'data, D>(
+        &mut self,
+        provider: &D,
+        languages: &[Language],
+    ) -> Result<(), SegmenterError>
+    where
+        D: DataProvider<'data, LstmDataV1Marker> + ?Sized,
+    {
+        for &language in languages {
+            let langid = match get_language_identifier(language) {
+                Some(langid) => langid,
+                None => continue,
+            };
+            let payload: DataPayload<LstmDataV1Marker> = provider
+                .load_payload(&DataRequest {
+                    resource_path: ResourcePath {
+                        key: key::LSTM_V1,
+                        options: langid.into(),
+                    },
+                })?
+                .take_payload()?;
+            self.insert_lstm(language, payload.get().model.clone())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_properties.py. DO NOT EDIT MANUALLY!
+
+pub const OTHER: u8 = 0;
+pub const ALETTER: u8 = 1;
+pub const CR: u8 = 2;
+pub const DOUBLE_QUOTE: u8 = 3;
+pub const EXTEND: u8 = 4;
+pub const EXTEND_NUM_LET: u8 = 5;
+pub const FORMAT: u8 = 6;
+pub const HEBREW_LETTER: u8 = 7;
+pub const KATAKANA: u8 = 8;
+pub const LF: u8 = 9;
+pub const MID_LETTER: u8 = 10;
+pub const MID_NUM: u8 = 11;
+pub const MID_NUM_LET: u8 = 12;
+pub const NEWLINE: u8 = 13;
+pub const NUMERIC: u8 = 14;
+pub const REGIONAL_INDICATOR: u8 = 15;
+pub const SINGLE_QUOTE: u8 = 16;
+pub const WSEG_SPACE: u8 = 17;
+pub const ZWJ: u8 = 18;
@@ -0,0 +1,1084 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_properties.py. DO NOT EDIT MANUALLY!
+
+use crate::wb_define::*;
+
+pub const UAX29_PROPERTY_TABLE: [(u32, u32, u8); 993] = [
+    (0xa, 0xa, LF),
+    (0xb, 0xc, NEWLINE),
+    (0xd, 0xd, CR),
+    (0x20, 0x20, WSEG_SPACE),
+    (0x22, 0x22, DOUBLE_QUOTE),
+    (0x27, 0x27, SINGLE_QUOTE),
+    (0x2c, 0x2c, MID_NUM),
+    (0x2e, 0x2e, MID_NUM_LET),
+    (0x30, 0x39, NUMERIC),
+    (0x3a, 0x3a, MID_LETTER),
+    (0x3b, 0x3b, MID_NUM),
+    (0x41, 0x5a, ALETTER),
+    (0x5f, 0x5f, EXTEND_NUM_LET),
+    (0x61, 0x7a, ALETTER),
+    (0x85, 0x85, NEWLINE),
+    (0xaa, 0xaa, ALETTER),
+    (0xad, 0xad, FORMAT),
+    (0xb5, 0xb5, ALETTER),
+    (0xb7, 0xb7, MID_LETTER),
+    (0xba, 0xba, ALETTER),
+    (0xc0, 0xd6, ALETTER),
+    (0xd8, 0xf6, ALETTER),
+    (0xf8, 0x2d7, ALETTER),
+    (0x2de, 0x2ff, ALETTER),
+    (0x300, 0x36f, EXTEND),
+    (0x370, 0x374, ALETTER),
+    (0x376, 0x377, ALETTER),
+    (0x37a, 0x37d, ALETTER),
+    (0x37e, 0x37e, MID_NUM),
+    (0x37f, 0x37f, ALETTER),
+    (0x386, 0x386, ALETTER),
+    (0x387, 0x387, MID_LETTER),
+    (0x388, 0x38a, ALETTER),
+    (0x38c, 0x38c, ALETTER),
+    (0x38e, 0x3a1, ALETTER),
+    (0x3a3, 0x3f5, ALETTER),
+    (0x3f7, 0x481, ALETTER),
+    (0x483, 0x489, EXTEND),
+    (0x48a, 0x52f, ALETTER),
+    (0x531, 0x556, ALETTER),
+    (0x559, 0x55c, ALETTER),
+    (0x55e, 0x55e, ALETTER),
+    (0x55f, 0x55f, MID_LETTER),
+    (0x560, 0x588, ALETTER),
+    (0x589, 0x589, MID_NUM),
+    (0x58a, 0x58a, ALETTER),
+    (0x591, 0x5bd, EXTEND),
+    (0x5bf, 0x5bf, EXTEND),
+    (0x5c1, 0x5c2, EXTEND),
+    (0x5c4, 0x5c5, EXTEND),
+    (0x5c7, 0x5c7, EXTEND),
+    (0x5d0, 0x5ea, HEBREW_LETTER),
+    (0x5ef, 0x5f2, HEBREW_LETTER),
+    (0x5f3, 0x5f3, ALETTER),
+    (0x5f4, 0x5f4, MID_LETTER),
+    (0x600, 0x605, FORMAT),
+    (0x60c, 0x60d, MID_NUM),
+    (0x610, 0x61a, EXTEND),
+    (0x61c, 0x61c, FORMAT),
+    (0x620, 0x64a, ALETTER),
+    (0x64b, 0x65f, EXTEND),
+    (0x660, 0x669, NUMERIC),
+    (0x66b, 0x66b, NUMERIC),
+    (0x66c, 0x66c, MID_NUM),
+    (0x66e, 0x66f, ALETTER),
+    (0x670, 0x670, EXTEND),
+    (0x671, 0x6d3, ALETTER),
+    (0x6d5, 0x6d5, ALETTER),
+    (0x6d6, 0x6dc, EXTEND),
+    (0x6dd, 0x6dd, FORMAT),
+    (0x6df, 0x6e4, EXTEND),
+    (0x6e5, 0x6e6, ALETTER),
+    (0x6e7, 0x6e8, EXTEND),
+    (0x6ea, 0x6ed, EXTEND),
+    (0x6ee, 0x6ef, ALETTER),
+    (0x6f0, 0x6f9, NUMERIC),
+    (0x6fa, 0x6fc, ALETTER),
+    (0x6ff, 0x6ff, ALETTER),
+    (0x70f, 0x70f, FORMAT),
+    (0x710, 0x710, ALETTER),
+    (0x711, 0x711, EXTEND),
+    (0x712, 0x72f, ALETTER),
+    (0x730, 0x74a, EXTEND),
+    (0x74d, 0x7a5, ALETTER),
+    (0x7a6, 0x7b0, EXTEND),
+    (0x7b1, 0x7b1, ALETTER),
+    (0x7c0, 0x7c9, NUMERIC),
+    (0x7ca, 0x7ea, ALETTER),
+    (0x7eb, 0x7f3, EXTEND),
+    (0x7f4, 0x7f5, ALETTER),
+    (0x7f8, 0x7f8, MID_NUM),
+    (0x7fa, 0x7fa, ALETTER),
+    (0x7fd, 0x7fd, EXTEND),
+    (0x800, 0x815, ALETTER),
+    (0x816, 0x819, EXTEND),
+    (0x81a, 0x81a, ALETTER),
+    (0x81b, 0x823, EXTEND),
+    (0x824, 0x824, ALETTER),
+    (0x825, 0x827, EXTEND),
+    (0x828, 0x828, ALETTER),
+    (0x829, 0x82d, EXTEND),
+    (0x840, 0x858, ALETTER),
+    (0x859, 0x85b, EXTEND),
+    (0x860, 0x86a, ALETTER),
+    (0x8a0, 0x8b4, ALETTER),
+    (0x8b6, 0x8c7, ALETTER),
+    (0x8d3, 0x8e1, EXTEND),
+    (0x8e2, 0x8e2, FORMAT),
+    (0x8e3, 0x903, EXTEND),
+    (0x904, 0x939, ALETTER),
+    (0x93a, 0x93c, EXTEND),
+    (0x93d, 0x93d, ALETTER),
+    (0x93e, 0x94f, EXTEND),
+    (0x950, 0x950, ALETTER),
+    (0x951, 0x957, EXTEND),
+    (0x958, 0x961, ALETTER),
+    (0x962, 0x963, EXTEND),
+    (0x966, 0x96f, NUMERIC),
+    (0x971, 0x980, ALETTER),
+    (0x981, 0x983, EXTEND),
+    (0x985, 0x98c, ALETTER),
+    (0x98f, 0x990, ALETTER),
+    (0x993, 0x9a8, ALETTER),
+    (0x9aa, 0x9b0, ALETTER),
+    (0x9b2, 0x9b2, ALETTER),
+    (0x9b6, 0x9b9, ALETTER),
+    (0x9bc, 0x9bc, EXTEND),
+    (0x9bd, 0x9bd, ALETTER),
+    (0x9be, 0x9c4, EXTEND),
+    (0x9c7, 0x9c8, EXTEND),
+    (0x9cb, 0x9cd, EXTEND),
+    (0x9ce, 0x9ce, ALETTER),
+    (0x9d7, 0x9d7, EXTEND),
+    (0x9dc, 0x9dd, ALETTER),
+    (0x9df, 0x9e1, ALETTER),
+    (0x9e2, 0x9e3, EXTEND),
+    (0x9e6, 0x9ef, NUMERIC),
+    (0x9f0, 0x9f1, ALETTER),
+    (0x9fc, 0x9fc, ALETTER),
+    (0x9fe, 0x9fe, EXTEND),
+    (0xa01, 0xa03, EXTEND),
+    (0xa05, 0xa0a, ALETTER),
+    (0xa0f, 0xa10, ALETTER),
+    (0xa13, 0xa28, ALETTER),
+    (0xa2a, 0xa30, ALETTER),
+    (0xa32, 0xa33, ALETTER),
+    (0xa35, 0xa36, ALETTER),
+    (0xa38, 0xa39, ALETTER),
+    (0xa3c, 0xa3c, EXTEND),
+    (0xa3e, 0xa42, EXTEND),
+    (0xa47, 0xa48, EXTEND),
+    (0xa4b, 0xa4d, EXTEND),
+    (0xa51, 0xa51, EXTEND),
+    (0xa59, 0xa5c, ALETTER),
+    (0xa5e, 0xa5e, ALETTER),
+    (0xa66, 0xa6f, NUMERIC),
+    (0xa70, 0xa71, EXTEND),
+    (0xa72, 0xa74, ALETTER),
+    (0xa75, 0xa75, EXTEND),
+    (0xa81, 0xa83, EXTEND),
+    (0xa85, 0xa8d, ALETTER),
+    (0xa8f, 0xa91, ALETTER),
+    (0xa93, 0xaa8, ALETTER),
+    (0xaaa, 0xab0, ALETTER),
+    (0xab2, 0xab3, ALETTER),
+    (0xab5, 0xab9, ALETTER),
+    (0xabc, 0xabc, EXTEND),
+    (0xabd, 0xabd, ALETTER),
+    (0xabe, 0xac5, EXTEND),
+    (0xac7, 0xac9, EXTEND),
+    (0xacb, 0xacd, EXTEND),
+    (0xad0, 0xad0, ALETTER),
+    (0xae0, 0xae1, ALETTER),
+    (0xae2, 0xae3, EXTEND),
+    (0xae6, 0xaef, NUMERIC),
+    (0xaf9, 0xaf9, ALETTER),
+    (0xafa, 0xaff, EXTEND),
+    (0xb01, 0xb03, EXTEND),
+    (0xb05, 0xb0c, ALETTER),
+    (0xb0f, 0xb10, ALETTER),
+    (0xb13, 0xb28, ALETTER),
+    (0xb2a, 0xb30, ALETTER),
+    (0xb32, 0xb33, ALETTER),
+    (0xb35, 0xb39, ALETTER),
+    (0xb3c, 0xb3c, EXTEND),
+    (0xb3d, 0xb3d, ALETTER),
+    (0xb3e, 0xb44, EXTEND),
+    (0xb47, 0xb48, EXTEND),
+    (0xb4b, 0xb4d, EXTEND),
+    (0xb55, 0xb57, EXTEND),
+    (0xb5c, 0xb5d, ALETTER),
+    (0xb5f, 0xb61, ALETTER),
+    (0xb62, 0xb63, EXTEND),
+    (0xb66, 0xb6f, NUMERIC),
+    (0xb71, 0xb71, ALETTER),
+    (0xb82, 0xb82, EXTEND),
+    (0xb83, 0xb83, ALETTER),
+    (0xb85, 0xb8a, ALETTER),
+    (0xb8e, 0xb90, ALETTER),
+    (0xb92, 0xb95, ALETTER),
+    (0xb99, 0xb9a, ALETTER),
+    (0xb9c, 0xb9c, ALETTER),
+    (0xb9e, 0xb9f, ALETTER),
+    (0xba3, 0xba4, ALETTER),
+    (0xba8, 0xbaa, ALETTER),
+    (0xbae, 0xbb9, ALETTER),
+    (0xbbe, 0xbc2, EXTEND),
+    (0xbc6, 0xbc8, EXTEND),
+    (0xbca, 0xbcd, EXTEND),
+    (0xbd0, 0xbd0, ALETTER),
+    (0xbd7, 0xbd7, EXTEND),
+    (0xbe6, 0xbef, NUMERIC),
+    (0xc00, 0xc04, EXTEND),
+    (0xc05, 0xc0c, ALETTER),
+    (0xc0e, 0xc10, ALETTER),
+    (0xc12, 0xc28, ALETTER),
+    (0xc2a, 0xc39, ALETTER),
+    (0xc3d, 0xc3d, ALETTER),
+    (0xc3e, 0xc44, EXTEND),
+    (0xc46, 0xc48, EXTEND),
+    (0xc4a, 0xc4d, EXTEND),
+    (0xc55, 0xc56, EXTEND),
+    (0xc58, 0xc5a, ALETTER),
+    (0xc60, 0xc61, ALETTER),
+    (0xc62, 0xc63, EXTEND),
+    (0xc66, 0xc6f, NUMERIC),
+    (0xc80, 0xc80, ALETTER),
+    (0xc81, 0xc83, EXTEND),
+    (0xc85, 0xc8c, ALETTER),
+    (0xc8e, 0xc90, ALETTER),
+    (0xc92, 0xca8, ALETTER),
+    (0xcaa, 0xcb3, ALETTER),
+    (0xcb5, 0xcb9, ALETTER),
+    (0xcbc, 0xcbc, EXTEND),
+    (0xcbd, 0xcbd, ALETTER),
+    (0xcbe, 0xcc4, EXTEND),
+    (0xcc6, 0xcc8, EXTEND),
+    (0xcca, 0xccd, EXTEND),
+    (0xcd5, 0xcd6, EXTEND),
+    (0xcde, 0xcde, ALETTER),
+    (0xce0, 0xce1, ALETTER),
+    (0xce2, 0xce3, EXTEND),
+    (0xce6, 0xcef, NUMERIC),
+    (0xcf1, 0xcf2, ALETTER),
+    (0xd00, 0xd03, EXTEND),
+    (0xd04, 0xd0c, ALETTER),
+    (0xd0e, 0xd10, ALETTER),
+    (0xd12, 0xd3a, ALETTER),
+    (0xd3b, 0xd3c, EXTEND),
+    (0xd3d, 0xd3d, ALETTER),
+    (0xd3e, 0xd44, EXTEND),
+    (0xd46, 0xd48, EXTEND),
+    (0xd4a, 0xd4d, EXTEND),
+    (0xd4e, 0xd4e, ALETTER),
+    (0xd54, 0xd56, ALETTER),
+    (0xd57, 0xd57, EXTEND),
+    (0xd5f, 0xd61, ALETTER),
+    (0xd62, 0xd63, EXTEND),
+    (0xd66, 0xd6f, NUMERIC),
+    (0xd7a, 0xd7f, ALETTER),
+    (0xd81, 0xd83, EXTEND),
+    (0xd85, 0xd96, ALETTER),
+    (0xd9a, 0xdb1, ALETTER),
+    (0xdb3, 0xdbb, ALETTER),
+    (0xdbd, 0xdbd, ALETTER),
+    (0xdc0, 0xdc6, ALETTER),
+    (0xdca, 0xdca, EXTEND),
+    (0xdcf, 0xdd4, EXTEND),
+    (0xdd6, 0xdd6, EXTEND),
+    (0xdd8, 0xddf, EXTEND),
+    (0xde6, 0xdef, NUMERIC),
+    (0xdf2, 0xdf3, EXTEND),
+    (0xe31, 0xe31, EXTEND),
+    (0xe34, 0xe3a, EXTEND),
+    (0xe47, 0xe4e, EXTEND),
+    (0xe50, 0xe59, NUMERIC),
+    (0xeb1, 0xeb1, EXTEND),
+    (0xeb4, 0xebc, EXTEND),
+    (0xec8, 0xecd, EXTEND),
+    (0xed0, 0xed9, NUMERIC),
+    (0xf00, 0xf00, ALETTER),
+    (0xf18, 0xf19, EXTEND),
+    (0xf20, 0xf29, NUMERIC),
+    (0xf35, 0xf35, EXTEND),
+    (0xf37, 0xf37, EXTEND),
+    (0xf39, 0xf39, EXTEND),
+    (0xf3e, 0xf3f, EXTEND),
+    (0xf40, 0xf47, ALETTER),
+    (0xf49, 0xf6c, ALETTER),
+    (0xf71, 0xf84, EXTEND),
+    (0xf86, 0xf87, EXTEND),
+    (0xf88, 0xf8c, ALETTER),
+    (0xf8d, 0xf97, EXTEND),
+    (0xf99, 0xfbc, EXTEND),
+    (0xfc6, 0xfc6, EXTEND),
+    (0x102b, 0x103e, EXTEND),
+    (0x1040, 0x1049, NUMERIC),
+    (0x1056, 0x1059, EXTEND),
+    (0x105e, 0x1060, EXTEND),
+    (0x1062, 0x1064, EXTEND),
+    (0x1067, 0x106d, EXTEND),
+    (0x1071, 0x1074, EXTEND),
+    (0x1082, 0x108d, EXTEND),
+    (0x108f, 0x108f, EXTEND),
+    (0x1090, 0x1099, NUMERIC),
+    (0x109a, 0x109d, EXTEND),
+    (0x10a0, 0x10c5, ALETTER),
+    (0x10c7, 0x10c7, ALETTER),
+    (0x10cd, 0x10cd, ALETTER),
+    (0x10d0, 0x10fa, ALETTER),
+    (0x10fc, 0x1248, ALETTER),
+    (0x124a, 0x124d, ALETTER),
+    (0x1250, 0x1256, ALETTER),
+    (0x1258, 0x1258, ALETTER),
+    (0x125a, 0x125d, ALETTER),
+    (0x1260, 0x1288, ALETTER),
+    (0x128a, 0x128d, ALETTER),
+    (0x1290, 0x12b0, ALETTER),
+    (0x12b2, 0x12b5, ALETTER),
+    (0x12b8, 0x12be, ALETTER),
+    (0x12c0, 0x12c0, ALETTER),
+    (0x12c2, 0x12c5, ALETTER),
+    (0x12c8, 0x12d6, ALETTER),
+    (0x12d8, 0x1310, ALETTER),
+    (0x1312, 0x1315, ALETTER),
+    (0x1318, 0x135a, ALETTER),
+    (0x135d, 0x135f, EXTEND),
+    (0x1380, 0x138f, ALETTER),
+    (0x13a0, 0x13f5, ALETTER),
+    (0x13f8, 0x13fd, ALETTER),
+    (0x1401, 0x166c, ALETTER),
+    (0x166f, 0x167f, ALETTER),
+    (0x1680, 0x1680, WSEG_SPACE),
+    (0x1681, 0x169a, ALETTER),
+    (0x16a0, 0x16ea, ALETTER),
+    (0x16ee, 0x16f8, ALETTER),
+    (0x1700, 0x170c, ALETTER),
+    (0x170e, 0x1711, ALETTER),
+    (0x1712, 0x1714, EXTEND),
+    (0x1720, 0x1731, ALETTER),
+    (0x1732, 0x1734, EXTEND),
+    (0x1740, 0x1751, ALETTER),
+    (0x1752, 0x1753, EXTEND),
+    (0x1760, 0x176c, ALETTER),
+    (0x176e, 0x1770, ALETTER),
+    (0x1772, 0x1773, EXTEND),
+    (0x17b4, 0x17d3, EXTEND),
+    (0x17dd, 0x17dd, EXTEND),
+    (0x17e0, 0x17e9, NUMERIC),
+    (0x180b, 0x180d, EXTEND),
+    (0x180e, 0x180e, FORMAT),
+    (0x1810, 0x1819, NUMERIC),
+    (0x1820, 0x1878, ALETTER),
+    (0x1880, 0x1884, ALETTER),
+    (0x1885, 0x1886, EXTEND),
+    (0x1887, 0x18a8, ALETTER),
+    (0x18a9, 0x18a9, EXTEND),
+    (0x18aa, 0x18aa, ALETTER),
+    (0x18b0, 0x18f5, ALETTER),
+    (0x1900, 0x191e, ALETTER),
+    (0x1920, 0x192b, EXTEND),
+    (0x1930, 0x193b, EXTEND),
+    (0x1946, 0x194f, NUMERIC),
+    (0x19d0, 0x19d9, NUMERIC),
+    (0x1a00, 0x1a16, ALETTER),
+    (0x1a17, 0x1a1b, EXTEND),
+    (0x1a55, 0x1a5e, EXTEND),
+    (0x1a60, 0x1a7c, EXTEND),
+    (0x1a7f, 0x1a7f, EXTEND),
+    (0x1a80, 0x1a89, NUMERIC),
+    (0x1a90, 0x1a99, NUMERIC),
+    (0x1ab0, 0x1ac0, EXTEND),
+    (0x1b00, 0x1b04, EXTEND),
+    (0x1b05, 0x1b33, ALETTER),
+    (0x1b34, 0x1b44, EXTEND),
+    (0x1b45, 0x1b4b, ALETTER),
+    (0x1b50, 0x1b59, NUMERIC),
+    (0x1b6b, 0x1b73, EXTEND),
+    (0x1b80, 0x1b82, EXTEND),
+    (0x1b83, 0x1ba0, ALETTER),
+    (0x1ba1, 0x1bad, EXTEND),
+    (0x1bae, 0x1baf, ALETTER),
+    (0x1bb0, 0x1bb9, NUMERIC),
+    (0x1bba, 0x1be5, ALETTER),
+    (0x1be6, 0x1bf3, EXTEND),
+    (0x1c00, 0x1c23, ALETTER),
+    (0x1c24, 0x1c37, EXTEND),
+    (0x1c40, 0x1c49, NUMERIC),
+    (0x1c4d, 0x1c4f, ALETTER),
+    (0x1c50, 0x1c59, NUMERIC),
+    (0x1c5a, 0x1c7d, ALETTER),
+    (0x1c80, 0x1c88, ALETTER),
+    (0x1c90, 0x1cba, ALETTER),
+    (0x1cbd, 0x1cbf, ALETTER),
+    (0x1cd0, 0x1cd2, EXTEND),
+    (0x1cd4, 0x1ce8, EXTEND),
+    (0x1ce9, 0x1cec, ALETTER),
+    (0x1ced, 0x1ced, EXTEND),
+    (0x1cee, 0x1cf3, ALETTER),
+    (0x1cf4, 0x1cf4, EXTEND),
+    (0x1cf5, 0x1cf6, ALETTER),
+    (0x1cf7, 0x1cf9, EXTEND),
+    (0x1cfa, 0x1cfa, ALETTER),
+    (0x1d00, 0x1dbf, ALETTER),
+    (0x1dc0, 0x1df9, EXTEND),
+    (0x1dfb, 0x1dff, EXTEND),
+    (0x1e00, 0x1f15, ALETTER),
+    (0x1f18, 0x1f1d, ALETTER),
+    (0x1f20, 0x1f45, ALETTER),
+    (0x1f48, 0x1f4d, ALETTER),
+    (0x1f50, 0x1f57, ALETTER),
+    (0x1f59, 0x1f59, ALETTER),
+    (0x1f5b, 0x1f5b, ALETTER),
+    (0x1f5d, 0x1f5d, ALETTER),
+    (0x1f5f, 0x1f7d, ALETTER),
+    (0x1f80, 0x1fb4, ALETTER),
+    (0x1fb6, 0x1fbc, ALETTER),
+    (0x1fbe, 0x1fbe, ALETTER),
+    (0x1fc2, 0x1fc4, ALETTER),
+    (0x1fc6, 0x1fcc, ALETTER),
+    (0x1fd0, 0x1fd3, ALETTER),
+    (0x1fd6, 0x1fdb, ALETTER),
+    (0x1fe0, 0x1fec, ALETTER),
+    (0x1ff2, 0x1ff4, ALETTER),
+    (0x1ff6, 0x1ffc, ALETTER),
+    (0x2000, 0x2006, WSEG_SPACE),
+    (0x2008, 0x200a, WSEG_SPACE),
+    (0x200c, 0x200c, EXTEND),
+    (0x200d, 0x200d, ZWJ),
+    (0x200e, 0x200f, FORMAT),
+    (0x2018, 0x2019, MID_NUM_LET),
+    (0x2024, 0x2024, MID_NUM_LET),
+    (0x2027, 0x2027, MID_LETTER),
+    (0x2028, 0x2029, NEWLINE),
+    (0x202a, 0x202e, FORMAT),
+    (0x202f, 0x202f, EXTEND_NUM_LET),
+    (0x203f, 0x2040, EXTEND_NUM_LET),
+    (0x2044, 0x2044, MID_NUM),
+    (0x2054, 0x2054, EXTEND_NUM_LET),
+    (0x205f, 0x205f, WSEG_SPACE),
+    (0x2060, 0x2064, FORMAT),
+    (0x2066, 0x206f, FORMAT),
+    (0x2071, 0x2071, ALETTER),
+    (0x207f, 0x207f, ALETTER),
+    (0x2090, 0x209c, ALETTER),
+    (0x20d0, 0x20f0, EXTEND),
+    (0x2102, 0x2102, ALETTER),
+    (0x2107, 0x2107, ALETTER),
+    (0x210a, 0x2113, ALETTER),
+    (0x2115, 0x2115, ALETTER),
+    (0x2119, 0x211d, ALETTER),
+    (0x2124, 0x2124, ALETTER),
+    (0x2126, 0x2126, ALETTER),
+    (0x2128, 0x2128, ALETTER),
+    (0x212a, 0x212d, ALETTER),
+    (0x212f, 0x2139, ALETTER),
+    (0x213c, 0x213f, ALETTER),
+    (0x2145, 0x2149, ALETTER),
+    (0x214e, 0x214e, ALETTER),
+    (0x2160, 0x2188, ALETTER),
+    (0x24b6, 0x24e9, ALETTER),
+    (0x2c00, 0x2c2e, ALETTER),
+    (0x2c30, 0x2c5e, ALETTER),
+    (0x2c60, 0x2ce4, ALETTER),
+    (0x2ceb, 0x2cee, ALETTER),
+    (0x2cef, 0x2cf1, EXTEND),
+    (0x2cf2, 0x2cf3, ALETTER),
+    (0x2d00, 0x2d25, ALETTER),
+    (0x2d27, 0x2d27, ALETTER),
+    (0x2d2d, 0x2d2d, ALETTER),
+    (0x2d30, 0x2d67, ALETTER),
+    (0x2d6f, 0x2d6f, ALETTER),
+    (0x2d7f, 0x2d7f, EXTEND),
+    (0x2d80, 0x2d96, ALETTER),
+    (0x2da0, 0x2da6, ALETTER),
+    (0x2da8, 0x2dae, ALETTER),
+    (0x2db0, 0x2db6, ALETTER),
+    (0x2db8, 0x2dbe, ALETTER),
+    (0x2dc0, 0x2dc6, ALETTER),
+    (0x2dc8, 0x2dce, ALETTER),
+    (0x2dd0, 0x2dd6, ALETTER),
+    (0x2dd8, 0x2dde, ALETTER),
+    (0x2de0, 0x2dff, EXTEND),
+    (0x2e2f, 0x2e2f, ALETTER),
+    (0x3000, 0x3000, WSEG_SPACE),
+    (0x3005, 0x3005, ALETTER),
+    (0x302a, 0x302f, EXTEND),
+    (0x3031, 0x3035, KATAKANA),
+    (0x303b, 0x303c, ALETTER),
+    (0x3099, 0x309a, EXTEND),
+    (0x309b, 0x309c, KATAKANA),
+    (0x30a0, 0x30fa, KATAKANA),
+    (0x30fc, 0x30ff, KATAKANA),
+    (0x3105, 0x312f, ALETTER),
+    (0x3131, 0x318e, ALETTER),
+    (0x31a0, 0x31bf, ALETTER),
+    (0x31f0, 0x31ff, KATAKANA),
+    (0x32d0, 0x32fe, KATAKANA),
+    (0x3300, 0x3357, KATAKANA),
+    (0xa000, 0xa48c, ALETTER),
+    (0xa4d0, 0xa4fd, ALETTER),
+    (0xa500, 0xa60c, ALETTER),
+    (0xa610, 0xa61f, ALETTER),
+    (0xa620, 0xa629, NUMERIC),
+    (0xa62a, 0xa62b, ALETTER),
+    (0xa640, 0xa66e, ALETTER),
+    (0xa66f, 0xa672, EXTEND),
+    (0xa674, 0xa67d, EXTEND),
+    (0xa67f, 0xa69d, ALETTER),
+    (0xa69e, 0xa69f, EXTEND),
+    (0xa6a0, 0xa6ef, ALETTER),
+    (0xa6f0, 0xa6f1, EXTEND),
+    (0xa708, 0xa7bf, ALETTER),
+    (0xa7c2, 0xa7ca, ALETTER),
+    (0xa7f5, 0xa801, ALETTER),
+    (0xa802, 0xa802, EXTEND),
+    (0xa803, 0xa805, ALETTER),
+    (0xa806, 0xa806, EXTEND),
+    (0xa807, 0xa80a, ALETTER),
+    (0xa80b, 0xa80b, EXTEND),
+    (0xa80c, 0xa822, ALETTER),
+    (0xa823, 0xa827, EXTEND),
+    (0xa82c, 0xa82c, EXTEND),
+    (0xa840, 0xa873, ALETTER),
+    (0xa880, 0xa881, EXTEND),
+    (0xa882, 0xa8b3, ALETTER),
+    (0xa8b4, 0xa8c5, EXTEND),
+    (0xa8d0, 0xa8d9, NUMERIC),
+    (0xa8e0, 0xa8f1, EXTEND),
+    (0xa8f2, 0xa8f7, ALETTER),
+    (0xa8fb, 0xa8fb, ALETTER),
+    (0xa8fd, 0xa8fe, ALETTER),
+    (0xa8ff, 0xa8ff, EXTEND),
+    (0xa900, 0xa909, NUMERIC),
+    (0xa90a, 0xa925, ALETTER),
+    (0xa926, 0xa92d, EXTEND),
+    (0xa930, 0xa946, ALETTER),
+    (0xa947, 0xa953, EXTEND),
+    (0xa960, 0xa97c, ALETTER),
+    (0xa980, 0xa983, EXTEND),
+    (0xa984, 0xa9b2, ALETTER),
+    (0xa9b3, 0xa9c0, EXTEND),
+    (0xa9cf, 0xa9cf, ALETTER),
+    (0xa9d0, 0xa9d9, NUMERIC),
+    (0xa9e5, 0xa9e5, EXTEND),
+    (0xa9f0, 0xa9f9, NUMERIC),
+    (0xaa00, 0xaa28, ALETTER),
+    (0xaa29, 0xaa36, EXTEND),
+    (0xaa40, 0xaa42, ALETTER),
+    (0xaa43, 0xaa43, EXTEND),
+    (0xaa44, 0xaa4b, ALETTER),
+    (0xaa4c, 0xaa4d, EXTEND),
+    (0xaa50, 0xaa59, NUMERIC),
+    (0xaa7b, 0xaa7d, EXTEND),
+    (0xaab0, 0xaab0, EXTEND),
+    (0xaab2, 0xaab4, EXTEND),
+    (0xaab7, 0xaab8, EXTEND),
+    (0xaabe, 0xaabf, EXTEND),
+    (0xaac1, 0xaac1, EXTEND),
+    (0xaae0, 0xaaea, ALETTER),
+    (0xaaeb, 0xaaef, EXTEND),
+    (0xaaf2, 0xaaf4, ALETTER),
+    (0xaaf5, 0xaaf6, EXTEND),
+    (0xab01, 0xab06, ALETTER),
+    (0xab09, 0xab0e, ALETTER),
+    (0xab11, 0xab16, ALETTER),
+    (0xab20, 0xab26, ALETTER),
+    (0xab28, 0xab2e, ALETTER),
+    (0xab30, 0xab69, ALETTER),
+    (0xab70, 0xabe2, ALETTER),
+    (0xabe3, 0xabea, EXTEND),
+    (0xabec, 0xabed, EXTEND),
+    (0xabf0, 0xabf9, NUMERIC),
+    (0xac00, 0xd7a3, ALETTER),
+    (0xd7b0, 0xd7c6, ALETTER),
+    (0xd7cb, 0xd7fb, ALETTER),
+    (0xfb00, 0xfb06, ALETTER),
+    (0xfb13, 0xfb17, ALETTER),
+    (0xfb1d, 0xfb1d, HEBREW_LETTER),
+    (0xfb1e, 0xfb1e, EXTEND),
+    (0xfb1f, 0xfb28, HEBREW_LETTER),
+    (0xfb2a, 0xfb36, HEBREW_LETTER),
+    (0xfb38, 0xfb3c, HEBREW_LETTER),
+    (0xfb3e, 0xfb3e, HEBREW_LETTER),
+    (0xfb40, 0xfb41, HEBREW_LETTER),
+    (0xfb43, 0xfb44, HEBREW_LETTER),
+    (0xfb46, 0xfb4f, HEBREW_LETTER),
+    (0xfb50, 0xfbb1, ALETTER),
+    (0xfbd3, 0xfd3d, ALETTER),
+    (0xfd50, 0xfd8f, ALETTER),
+    (0xfd92, 0xfdc7, ALETTER),
+    (0xfdf0, 0xfdfb, ALETTER),
+    (0xfe00, 0xfe0f, EXTEND),
+    (0xfe10, 0xfe10, MID_NUM),
+    (0xfe13, 0xfe13, MID_LETTER),
+    (0xfe14, 0xfe14, MID_NUM),
+    (0xfe20, 0xfe2f, EXTEND),
+    (0xfe33, 0xfe34, EXTEND_NUM_LET),
+    (0xfe4d, 0xfe4f, EXTEND_NUM_LET),
+    (0xfe50, 0xfe50, MID_NUM),
+    (0xfe52, 0xfe52, MID_NUM_LET),
+    (0xfe54, 0xfe54, MID_NUM),
+    (0xfe55, 0xfe55, MID_LETTER),
+    (0xfe70, 0xfe74, ALETTER),
+    (0xfe76, 0xfefc, ALETTER),
+    (0xfeff, 0xfeff, FORMAT),
+    (0xff07, 0xff07, MID_NUM_LET),
+    (0xff0c, 0xff0c, MID_NUM),
+    (0xff0e, 0xff0e, MID_NUM_LET),
+    (0xff10, 0xff19, NUMERIC),
+    (0xff1a, 0xff1a, MID_LETTER),
+    (0xff1b, 0xff1b, MID_NUM),
+    (0xff21, 0xff3a, ALETTER),
+    (0xff3f, 0xff3f, EXTEND_NUM_LET),
+    (0xff41, 0xff5a, ALETTER),
+    (0xff66, 0xff9d, KATAKANA),
+    (0xff9e, 0xff9f, EXTEND),
+    (0xffa0, 0xffbe, ALETTER),
+    (0xffc2, 0xffc7, ALETTER),
+    (0xffca, 0xffcf, ALETTER),
+    (0xffd2, 0xffd7, ALETTER),
+    (0xffda, 0xffdc, ALETTER),
+    (0xfff9, 0xfffb, FORMAT),
+    (0x10000, 0x1000b, ALETTER),
+    (0x1000d, 0x10026, ALETTER),
+    (0x10028, 0x1003a, ALETTER),
+    (0x1003c, 0x1003d, ALETTER),
+    (0x1003f, 0x1004d, ALETTER),
+    (0x10050, 0x1005d, ALETTER),
+    (0x10080, 0x100fa, ALETTER),
+    (0x10140, 0x10174, ALETTER),
+    (0x101fd, 0x101fd, EXTEND),
+    (0x10280, 0x1029c, ALETTER),
+    (0x102a0, 0x102d0, ALETTER),
+    (0x102e0, 0x102e0, EXTEND),
+    (0x10300, 0x1031f, ALETTER),
+    (0x1032d, 0x1034a, ALETTER),
+    (0x10350, 0x10375, ALETTER),
+    (0x10376, 0x1037a, EXTEND),
+    (0x10380, 0x1039d, ALETTER),
+    (0x103a0, 0x103c3, ALETTER),
+    (0x103c8, 0x103cf, ALETTER),
+    (0x103d1, 0x103d5, ALETTER),
+    (0x10400, 0x1049d, ALETTER),
+    (0x104a0, 0x104a9, NUMERIC),
+    (0x104b0, 0x104d3, ALETTER),
+    (0x104d8, 0x104fb, ALETTER),
+    (0x10500, 0x10527, ALETTER),
+    (0x10530, 0x10563, ALETTER),
+    (0x10600, 0x10736, ALETTER),
+    (0x10740, 0x10755, ALETTER),
+    (0x10760, 0x10767, ALETTER),
+    (0x10800, 0x10805, ALETTER),
+    (0x10808, 0x10808, ALETTER),
+    (0x1080a, 0x10835, ALETTER),
+    (0x10837, 0x10838, ALETTER),
+    (0x1083c, 0x1083c, ALETTER),
+    (0x1083f, 0x10855, ALETTER),
+    (0x10860, 0x10876, ALETTER),
+    (0x10880, 0x1089e, ALETTER),
+    (0x108e0, 0x108f2, ALETTER),
+    (0x108f4, 0x108f5, ALETTER),
+    (0x10900, 0x10915, ALETTER),
+    (0x10920, 0x10939, ALETTER),
+    (0x10980, 0x109b7, ALETTER),
+    (0x109be, 0x109bf, ALETTER),
+    (0x10a00, 0x10a00, ALETTER),
+    (0x10a01, 0x10a03, EXTEND),
+    (0x10a05, 0x10a06, EXTEND),
+    (0x10a0c, 0x10a0f, EXTEND),
+    (0x10a10, 0x10a13, ALETTER),
+    (0x10a15, 0x10a17, ALETTER),
+    (0x10a19, 0x10a35, ALETTER),
+    (0x10a38, 0x10a3a, EXTEND),
+    (0x10a3f, 0x10a3f, EXTEND),
+    (0x10a60, 0x10a7c, ALETTER),
+    (0x10a80, 0x10a9c, ALETTER),
+    (0x10ac0, 0x10ac7, ALETTER),
+    (0x10ac9, 0x10ae4, ALETTER),
+    (0x10ae5, 0x10ae6, EXTEND),
+    (0x10b00, 0x10b35, ALETTER),
+    (0x10b40, 0x10b55, ALETTER),
+    (0x10b60, 0x10b72, ALETTER),
+    (0x10b80, 0x10b91, ALETTER),
+    (0x10c00, 0x10c48, ALETTER),
+    (0x10c80, 0x10cb2, ALETTER),
+    (0x10cc0, 0x10cf2, ALETTER),
+    (0x10d00, 0x10d23, ALETTER),
+    (0x10d24, 0x10d27, EXTEND),
+    (0x10d30, 0x10d39, NUMERIC),
+    (0x10e80, 0x10ea9, ALETTER),
+    (0x10eab, 0x10eac, EXTEND),
+    (0x10eb0, 0x10eb1, ALETTER),
+    (0x10f00, 0x10f1c, ALETTER),
+    (0x10f27, 0x10f27, ALETTER),
+    (0x10f30, 0x10f45, ALETTER),
+    (0x10f46, 0x10f50, EXTEND),
+    (0x10fb0, 0x10fc4, ALETTER),
+    (0x10fe0, 0x10ff6, ALETTER),
+    (0x11000, 0x11002, EXTEND),
+    (0x11003, 0x11037, ALETTER),
+    (0x11038, 0x11046, EXTEND),
+    (0x11066, 0x1106f, NUMERIC),
+    (0x1107f, 0x11082, EXTEND),
+    (0x11083, 0x110af, ALETTER),
+    (0x110b0, 0x110ba, EXTEND),
+    (0x110bd, 0x110bd, FORMAT),
+    (0x110cd, 0x110cd, FORMAT),
+    (0x110d0, 0x110e8, ALETTER),
+    (0x110f0, 0x110f9, NUMERIC),
+    (0x11100, 0x11102, EXTEND),
+    (0x11103, 0x11126, ALETTER),
+    (0x11127, 0x11134, EXTEND),
+    (0x11136, 0x1113f, NUMERIC),
+    (0x11144, 0x11144, ALETTER),
+    (0x11145, 0x11146, EXTEND),
+    (0x11147, 0x11147, ALETTER),
+    (0x11150, 0x11172, ALETTER),
+    (0x11173, 0x11173, EXTEND),
+    (0x11176, 0x11176, ALETTER),
+    (0x11180, 0x11182, EXTEND),
+    (0x11183, 0x111b2, ALETTER),
+    (0x111b3, 0x111c0, EXTEND),
+    (0x111c1, 0x111c4, ALETTER),
+    (0x111c9, 0x111cc, EXTEND),
+    (0x111ce, 0x111cf, EXTEND),
+    (0x111d0, 0x111d9, NUMERIC),
+    (0x111da, 0x111da, ALETTER),
+    (0x111dc, 0x111dc, ALETTER),
+    (0x11200, 0x11211, ALETTER),
+    (0x11213, 0x1122b, ALETTER),
+    (0x1122c, 0x11237, EXTEND),
+    (0x1123e, 0x1123e, EXTEND),
+    (0x11280, 0x11286, ALETTER),
+    (0x11288, 0x11288, ALETTER),
+    (0x1128a, 0x1128d, ALETTER),
+    (0x1128f, 0x1129d, ALETTER),
+    (0x1129f, 0x112a8, ALETTER),
+    (0x112b0, 0x112de, ALETTER),
+    (0x112df, 0x112ea, EXTEND),
+    (0x112f0, 0x112f9, NUMERIC),
+    (0x11300, 0x11303, EXTEND),
+    (0x11305, 0x1130c, ALETTER),
+    (0x1130f, 0x11310, ALETTER),
+    (0x11313, 0x11328, ALETTER),
+    (0x1132a, 0x11330, ALETTER),
+    (0x11332, 0x11333, ALETTER),
+    (0x11335, 0x11339, ALETTER),
+    (0x1133b, 0x1133c, EXTEND),
+    (0x1133d, 0x1133d, ALETTER),
+    (0x1133e, 0x11344, EXTEND),
+    (0x11347, 0x11348, EXTEND),
+    (0x1134b, 0x1134d, EXTEND),
+    (0x11350, 0x11350, ALETTER),
+    (0x11357, 0x11357, EXTEND),
+    (0x1135d, 0x11361, ALETTER),
+    (0x11362, 0x11363, EXTEND),
+    (0x11366, 0x1136c, EXTEND),
+    (0x11370, 0x11374, EXTEND),
+    (0x11400, 0x11434, ALETTER),
+    (0x11435, 0x11446, EXTEND),
+    (0x11447, 0x1144a, ALETTER),
+    (0x11450, 0x11459, NUMERIC),
+    (0x1145e, 0x1145e, EXTEND),
+    (0x1145f, 0x11461, ALETTER),
+    (0x11480, 0x114af, ALETTER),
+    (0x114b0, 0x114c3, EXTEND),
+    (0x114c4, 0x114c5, ALETTER),
+    (0x114c7, 0x114c7, ALETTER),
+    (0x114d0, 0x114d9, NUMERIC),
+    (0x11580, 0x115ae, ALETTER),
+    (0x115af, 0x115b5, EXTEND),
+    (0x115b8, 0x115c0, EXTEND),
+    (0x115d8, 0x115db, ALETTER),
+    (0x115dc, 0x115dd, EXTEND),
+    (0x11600, 0x1162f, ALETTER),
+    (0x11630, 0x11640, EXTEND),
+    (0x11644, 0x11644, ALETTER),
+    (0x11650, 0x11659, NUMERIC),
+    (0x11680, 0x116aa, ALETTER),
+    (0x116ab, 0x116b7, EXTEND),
+    (0x116b8, 0x116b8, ALETTER),
+    (0x116c0, 0x116c9, NUMERIC),
+    (0x1171d, 0x1172b, EXTEND),
+    (0x11730, 0x11739, NUMERIC),
+    (0x11800, 0x1182b, ALETTER),
+    (0x1182c, 0x1183a, EXTEND),
+    (0x118a0, 0x118df, ALETTER),
+    (0x118e0, 0x118e9, NUMERIC),
+    (0x118ff, 0x11906, ALETTER),
+    (0x11909, 0x11909, ALETTER),
+    (0x1190c, 0x11913, ALETTER),
+    (0x11915, 0x11916, ALETTER),
+    (0x11918, 0x1192f, ALETTER),
+    (0x11930, 0x11935, EXTEND),
+    (0x11937, 0x11938, EXTEND),
+    (0x1193b, 0x1193e, EXTEND),
+    (0x1193f, 0x1193f, ALETTER),
+    (0x11940, 0x11940, EXTEND),
+    (0x11941, 0x11941, ALETTER),
+    (0x11942, 0x11943, EXTEND),
+    (0x11950, 0x11959, NUMERIC),
+    (0x119a0, 0x119a7, ALETTER),
+    (0x119aa, 0x119d0, ALETTER),
+    (0x119d1, 0x119d7, EXTEND),
+    (0x119da, 0x119e0, EXTEND),
+    (0x119e1, 0x119e1, ALETTER),
+    (0x119e3, 0x119e3, ALETTER),
+    (0x119e4, 0x119e4, EXTEND),
+    (0x11a00, 0x11a00, ALETTER),
+    (0x11a01, 0x11a0a, EXTEND),
+    (0x11a0b, 0x11a32, ALETTER),
+    (0x11a33, 0x11a39, EXTEND),
+    (0x11a3a, 0x11a3a, ALETTER),
+    (0x11a3b, 0x11a3e, EXTEND),
+    (0x11a47, 0x11a47, EXTEND),
+    (0x11a50, 0x11a50, ALETTER),
+    (0x11a51, 0x11a5b, EXTEND),
+    (0x11a5c, 0x11a89, ALETTER),
+    (0x11a8a, 0x11a99, EXTEND),
+    (0x11a9d, 0x11a9d, ALETTER),
+    (0x11ac0, 0x11af8, ALETTER),
+    (0x11c00, 0x11c08, ALETTER),
+    (0x11c0a, 0x11c2e, ALETTER),
+    (0x11c2f, 0x11c36, EXTEND),
+    (0x11c38, 0x11c3f, EXTEND),
+    (0x11c40, 0x11c40, ALETTER),
+    (0x11c50, 0x11c59, NUMERIC),
+    (0x11c72, 0x11c8f, ALETTER),
+    (0x11c92, 0x11ca7, EXTEND),
+    (0x11ca9, 0x11cb6, EXTEND),
+    (0x11d00, 0x11d06, ALETTER),
+    (0x11d08, 0x11d09, ALETTER),
+    (0x11d0b, 0x11d30, ALETTER),
+    (0x11d31, 0x11d36, EXTEND),
+    (0x11d3a, 0x11d3a, EXTEND),
+    (0x11d3c, 0x11d3d, EXTEND),
+    (0x11d3f, 0x11d45, EXTEND),
+    (0x11d46, 0x11d46, ALETTER),
+    (0x11d47, 0x11d47, EXTEND),
+    (0x11d50, 0x11d59, NUMERIC),
+    (0x11d60, 0x11d65, ALETTER),
+    (0x11d67, 0x11d68, ALETTER),
+    (0x11d6a, 0x11d89, ALETTER),
+    (0x11d8a, 0x11d8e, EXTEND),
+    (0x11d90, 0x11d91, EXTEND),
+    (0x11d93, 0x11d97, EXTEND),
+    (0x11d98, 0x11d98, ALETTER),
+    (0x11da0, 0x11da9, NUMERIC),
+    (0x11ee0, 0x11ef2, ALETTER),
+    (0x11ef3, 0x11ef6, EXTEND),
+    (0x11fb0, 0x11fb0, ALETTER),
+    (0x12000, 0x12399, ALETTER),
+    (0x12400, 0x1246e, ALETTER),
+    (0x12480, 0x12543, ALETTER),
+    (0x13000, 0x1342e, ALETTER),
+    (0x13430, 0x13438, FORMAT),
+    (0x14400, 0x14646, ALETTER),
+    (0x16800, 0x16a38, ALETTER),
+    (0x16a40, 0x16a5e, ALETTER),
+    (0x16a60, 0x16a69, NUMERIC),
+    (0x16ad0, 0x16aed, ALETTER),
+    (0x16af0, 0x16af4, EXTEND),
+    (0x16b00, 0x16b2f, ALETTER),
+    (0x16b30, 0x16b36, EXTEND),
+    (0x16b40, 0x16b43, ALETTER),
+    (0x16b50, 0x16b59, NUMERIC),
+    (0x16b63, 0x16b77, ALETTER),
+    (0x16b7d, 0x16b8f, ALETTER),
+    (0x16e40, 0x16e7f, ALETTER),
+    (0x16f00, 0x16f4a, ALETTER),
+    (0x16f4f, 0x16f4f, EXTEND),
+    (0x16f50, 0x16f50, ALETTER),
+    (0x16f51, 0x16f87, EXTEND),
+    (0x16f8f, 0x16f92, EXTEND),
+    (0x16f93, 0x16f9f, ALETTER),
+    (0x16fe0, 0x16fe1, ALETTER),
+    (0x16fe3, 0x16fe3, ALETTER),
+    (0x16fe4, 0x16fe4, EXTEND),
+    (0x16ff0, 0x16ff1, EXTEND),
+    (0x1b000, 0x1b000, KATAKANA),
+    (0x1b164, 0x1b167, KATAKANA),
+    (0x1bc00, 0x1bc6a, ALETTER),
+    (0x1bc70, 0x1bc7c, ALETTER),
+    (0x1bc80, 0x1bc88, ALETTER),
+    (0x1bc90, 0x1bc99, ALETTER),
+    (0x1bc9d, 0x1bc9e, EXTEND),
+    (0x1bca0, 0x1bca3, FORMAT),
+    (0x1d165, 0x1d169, EXTEND),
+    (0x1d16d, 0x1d172, EXTEND),
+    (0x1d173, 0x1d17a, FORMAT),
+    (0x1d17b, 0x1d182, EXTEND),
+    (0x1d185, 0x1d18b, EXTEND),
+    (0x1d1aa, 0x1d1ad, EXTEND),
+    (0x1d242, 0x1d244, EXTEND),
+    (0x1d400, 0x1d454, ALETTER),
+    (0x1d456, 0x1d49c, ALETTER),
+    (0x1d49e, 0x1d49f, ALETTER),
+    (0x1d4a2, 0x1d4a2, ALETTER),
+    (0x1d4a5, 0x1d4a6, ALETTER),
+    (0x1d4a9, 0x1d4ac, ALETTER),
+    (0x1d4ae, 0x1d4b9, ALETTER),
+    (0x1d4bb, 0x1d4bb, ALETTER),
+    (0x1d4bd, 0x1d4c3, ALETTER),
+    (0x1d4c5, 0x1d505, ALETTER),
+    (0x1d507, 0x1d50a, ALETTER),
+    (0x1d50d, 0x1d514, ALETTER),
+    (0x1d516, 0x1d51c, ALETTER),
+    (0x1d51e, 0x1d539, ALETTER),
+    (0x1d53b, 0x1d53e, ALETTER),
+    (0x1d540, 0x1d544, ALETTER),
+    (0x1d546, 0x1d546, ALETTER),
+    (0x1d54a, 0x1d550, ALETTER),
+    (0x1d552, 0x1d6a5, ALETTER),
+    (0x1d6a8, 0x1d6c0, ALETTER),
+    (0x1d6c2, 0x1d6da, ALETTER),
+    (0x1d6dc, 0x1d6fa, ALETTER),
+    (0x1d6fc, 0x1d714, ALETTER),
+    (0x1d716, 0x1d734, ALETTER),
+    (0x1d736, 0x1d74e, ALETTER),
+    (0x1d750, 0x1d76e, ALETTER),
+    (0x1d770, 0x1d788, ALETTER),
+    (0x1d78a, 0x1d7a8, ALETTER),
+    (0x1d7aa, 0x1d7c2, ALETTER),
+    (0x1d7c4, 0x1d7cb, ALETTER),
+    (0x1d7ce, 0x1d7ff, NUMERIC),
+    (0x1da00, 0x1da36, EXTEND),
+    (0x1da3b, 0x1da6c, EXTEND),
+    (0x1da75, 0x1da75, EXTEND),
+    (0x1da84, 0x1da84, EXTEND),
+    (0x1da9b, 0x1da9f, EXTEND),
+    (0x1daa1, 0x1daaf, EXTEND),
+    (0x1e000, 0x1e006, EXTEND),
+    (0x1e008, 0x1e018, EXTEND),
+    (0x1e01b, 0x1e021, EXTEND),
+    (0x1e023, 0x1e024, EXTEND),
+    (0x1e026, 0x1e02a, EXTEND),
+    (0x1e100, 0x1e12c, ALETTER),
+    (0x1e130, 0x1e136, EXTEND),
+    (0x1e137, 0x1e13d, ALETTER),
+    (0x1e140, 0x1e149, NUMERIC),
+    (0x1e14e, 0x1e14e, ALETTER),
+    (0x1e2c0, 0x1e2eb, ALETTER),
+    (0x1e2ec, 0x1e2ef, EXTEND),
+    (0x1e2f0, 0x1e2f9, NUMERIC),
+    (0x1e800, 0x1e8c4, ALETTER),
+    (0x1e8d0, 0x1e8d6, EXTEND),
+    (0x1e900, 0x1e943, ALETTER),
+    (0x1e944, 0x1e94a, EXTEND),
+    (0x1e94b, 0x1e94b, ALETTER),
+    (0x1e950, 0x1e959, NUMERIC),
+    (0x1ee00, 0x1ee03, ALETTER),
+    (0x1ee05, 0x1ee1f, ALETTER),
+    (0x1ee21, 0x1ee22, ALETTER),
+    (0x1ee24, 0x1ee24, ALETTER),
+    (0x1ee27, 0x1ee27, ALETTER),
+    (0x1ee29, 0x1ee32, ALETTER),
+    (0x1ee34, 0x1ee37, ALETTER),
+    (0x1ee39, 0x1ee39, ALETTER),
+    (0x1ee3b, 0x1ee3b, ALETTER),
+    (0x1ee42, 0x1ee42, ALETTER),
+    (0x1ee47, 0x1ee47, ALETTER),
+    (0x1ee49, 0x1ee49, ALETTER),
+    (0x1ee4b, 0x1ee4b, ALETTER),
+    (0x1ee4d, 0x1ee4f, ALETTER),
+    (0x1ee51, 0x1ee52, ALETTER),
+    (0x1ee54, 0x1ee54, ALETTER),
+    (0x1ee57, 0x1ee57, ALETTER),
+    (0x1ee59, 0x1ee59, ALETTER),
+    (0x1ee5b, 0x1ee5b, ALETTER),
+    (0x1ee5d, 0x1ee5d, ALETTER),
+    (0x1ee5f, 0x1ee5f, ALETTER),
+    (0x1ee61, 0x1ee62, ALETTER),
+    (0x1ee64, 0x1ee64, ALETTER),
+    (0x1ee67, 0x1ee6a, ALETTER),
+    (0x1ee6c, 0x1ee72, ALETTER),
+    (0x1ee74, 0x1ee77, ALETTER),
+    (0x1ee79, 0x1ee7c, ALETTER),
+    (0x1ee7e, 0x1ee7e, ALETTER),
+    (0x1ee80, 0x1ee89, ALETTER),
+    (0x1ee8b, 0x1ee9b, ALETTER),
+    (0x1eea1, 0x1eea3, ALETTER),
+    (0x1eea5, 0x1eea9, ALETTER),
+    (0x1eeab, 0x1eebb, ALETTER),
+    (0x1f130, 0x1f149, ALETTER),
+    (0x1f150, 0x1f169, ALETTER),
+    (0x1f170, 0x1f189, ALETTER),
+    (0x1f1e6, 0x1f1ff, REGIONAL_INDICATOR),
+    (0x1f3fb, 0x1f3ff, EXTEND),
+    (0x1fbf0, 0x1fbf9, NUMERIC),
+    (0xe0001, 0xe0001, FORMAT),
+    (0xe0020, 0xe007f, EXTEND),
+    (0xe0100, 0xe01ef, EXTEND),
+];
+
+pub const EXTENDED_PICTOGRAPHIC_TABLE: [(u32, u32); 78] = [
+    (0xa9, 0xa9),
+    (0xae, 0xae),
+    (0x203c, 0x203c),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21a9, 0x21aa),
+    (0x231a, 0x231b),
+    (0x2328, 0x2328),
+    (0x2388, 0x2388),
+    (0x23cf, 0x23cf),
+    (0x23e9, 0x23f3),
+    (0x23f8, 0x23fa),
+    (0x24c2, 0x24c2),
+    (0x25aa, 0x25ab),
+    (0x25b6, 0x25b6),
+    (0x25c0, 0x25c0),
+    (0x25fb, 0x25fe),
+    (0x2600, 0x2605),
+    (0x2607, 0x2612),
+    (0x2614, 0x2685),
+    (0x2690, 0x2705),
+    (0x2708, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271d, 0x271d),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2767),
+    (0x2795, 0x2797),
+    (0x27a1, 0x27a1),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2934, 0x2935),
+    (0x2b05, 0x2b07),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x3030, 0x3030),
+    (0x303d, 0x303d),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1f000, 0x1f0ff),
+    (0x1f10d, 0x1f10f),
+    (0x1f12f, 0x1f12f),
+    (0x1f16c, 0x1f171),
+    (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1ad, 0x1f1e5),
+    (0x1f201, 0x1f20f),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f23a),
+    (0x1f23c, 0x1f23f),
+    (0x1f249, 0x1f3fa),
+    (0x1f400, 0x1f53d),
+    (0x1f546, 0x1f64f),
+    (0x1f680, 0x1f6ff),
+    (0x1f774, 0x1f77f),
+    (0x1f7d5, 0x1f7ff),
+    (0x1f80c, 0x1f80f),
+    (0x1f848, 0x1f84f),
+    (0x1f85a, 0x1f85f),
+    (0x1f888, 0x1f88f),
+    (0x1f8ae, 0x1f8ff),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1faff),
+    (0x1fc00, 0x1fffd),
+];
@@ -0,0 +1,897 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::dictionary::*;
+use crate::indices::*;
+use crate::language::*;
+use crate::lb_define;
+use crate::property_table::*;
+use crate::wb_define::*;
+use crate::word_break_property_table::*;
+
+use core::cmp::Ordering;
+use core::str::CharIndices;
+
+/// An enum specifies the kind of text between two word boundaries. It is
+/// returned along with each boundary by a word break iterator, and describes
+/// the segment ending at that boundary.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WordType {
+    /// A word containing letters, such as "Hello", "can't", or "a1", or a run
+    /// of ideographs or kana.
+    Letter,
+
+    /// A number without letters, such as "3.14".
+    Number,
+
+    /// Punctuation, symbols, or other characters that are not part of a word.
+    Punctuation,
+
+    /// White space, including line breaks.
+    Space,
+}
+
+/// The status of the rule that determined a word boundary, compatible with the
+/// word break rule statuses of ICU4C, such as `UBRK_WORD_LETTER`.
+///
+/// Unlike [`WordType`], it tells kana and ideographs apart from other letters.
+/// It is returned by the `rule_status()` method of the word break iterators,
+/// and describes the segment ending at the last boundary they returned.
+///
+/// The values of the variants are the lower bounds of the ICU4C ranges of rule
+/// statuses, so that `RuleStatus::Letter as i32` equals `UBRK_WORD_LETTER`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RuleStatus {
+    /// Spaces, punctuation, and symbols, which are not words. This is also the
+    /// status of the start of the text.
+    None = 0,
+
+    /// A number without letters, such as "3.14".
+    Number = 100,
+
+    /// A word containing letters other than kana and ideographs, such as
+    /// "Hello" or "a1", or a word of a language written without spaces, such
+    /// as Thai.
+    Letter = 200,
+
+    /// A run of katakana, or a hiragana character.
+    Kana = 300,
+
+    /// An ideograph, such as "中".
+    Ideograph = 400,
+}
+
+impl RuleStatus {
+    /// Returns whether the segment is a word, that is, whether it contains
+    /// letters, kana, ideographs, or numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_segmenter::RuleStatus;
+    ///
+    /// assert!(RuleStatus::Number.is_word_like());
+    /// assert!(!RuleStatus::None.is_word_like());
+    /// ```
+    pub fn is_word_like(self) -> bool {
+        self != RuleStatus::None
+    }
+}
+
+fn search_range_table<T: Copy>(codepoint: u32, table: &[(u32, u32, T)]) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| {
+            if codepoint < start {
+                Ordering::Greater
+            } else if codepoint > end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| table[index].2)
+}
+
+pub(crate) fn get_word_break_property_utf32(codepoint: u32) -> u8 {
+    search_range_table(codepoint, &UAX29_PROPERTY_TABLE).unwrap_or(OTHER)
+}
+
+fn is_extended_pictographic_utf32(codepoint: u32) -> bool {
+    EXTENDED_PICTOGRAPHIC_TABLE
+        .binary_search_by(|&(start, end)| {
+            if codepoint < start {
+                Ordering::Greater
+            } else if codepoint > end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Ideographs and hiragana have the Word_Break property value Other, so use
+/// their Line_Break property to tell them apart from symbols.
+fn is_ideographic_utf32(codepoint: u32) -> bool {
+    if codepoint >= 0x20000 {
+        return matches!(codepoint, 0x20000..=0x2fffd | 0x30000..=0x3fffd);
+    }
+    let codepoint = codepoint as usize;
+    matches!(
+        UAX14_PROPERTY_TABLE[codepoint / 1024][codepoint & 0x3ff],
+        lb_define::ID
+            | lb_define::CJ
+            | lb_define::H2
+            | lb_define::H3
+            | lb_define::JL
+            | lb_define::JV
+            | lb_define::JT
+            | lb_define::SA
+    )
+}
+
+fn is_hiragana_utf32(codepoint: u32) -> bool {
+    matches!(codepoint, 0x3041..=0x309f | 0x1b001..=0x1b11f)
+}
+
+/// Returns whether the code point is a letter of a language written without
+/// spaces between words, which has the Line_Break property value SA.
+fn is_complex_utf32(codepoint: u32) -> bool {
+    codepoint < 0x20000
+        && UAX14_PROPERTY_TABLE[codepoint as usize / 1024][codepoint as usize & 0x3ff]
+            == lb_define::SA
+}
+
+/// Returns how to segment the text of the code point, if it is a letter of a
+/// language written without spaces between words.
+fn get_complex_model_utf32(
+    codepoint: u32,
+    dictionaries: Option<&Dictionaries>,
+) -> Option<ComplexModel<'_>> {
+    if !is_complex_utf32(codepoint) {
+        return None;
+    }
+    get_complex_model(dictionaries, get_language(codepoint))
+}
+
+#[inline]
+fn is_ignored_by_wb4(prop: u8) -> bool {
+    matches!(prop, EXTEND | FORMAT | ZWJ)
+}
+
+#[inline]
+fn is_ahletter(prop: u8) -> bool {
+    matches!(prop, ALETTER | HEBREW_LETTER)
+}
+
+#[inline]
+fn is_mid_letter_or_mid_num_let_q(prop: u8) -> bool {
+    matches!(prop, MID_LETTER | MID_NUM_LET | SINGLE_QUOTE)
+}
+
+/// Returns whether `codepoint` is a colon, which is part of a word between
+/// letters following UAX #29, but not in most locales of CLDR.
+#[inline]
+fn is_colon_utf32(codepoint: u32) -> bool {
+    matches!(codepoint, 0x3a | 0xfe55 | 0xff1a)
+}
+
+#[inline]
+fn is_mid_num_or_mid_num_let_q(prop: u8) -> bool {
+    matches!(prop, MID_NUM | MID_NUM_LET | SINGLE_QUOTE)
+}
+
+/// Accumulates the kinds of characters in a segment to find its [`WordType`]
+/// and [`RuleStatus`].
+#[derive(Default)]
+struct WordTypeBuilder {
+    has_letter: bool,
+    has_kana: bool,
+    has_ideograph: bool,
+    has_number: bool,
+    has_non_space: bool,
+}
+
+impl WordTypeBuilder {
+    fn push(&mut self, codepoint: u32, prop: u8) {
+        match prop {
+            ALETTER | HEBREW_LETTER => self.has_letter = true,
+            KATAKANA => self.has_kana = true,
+            NUMERIC => self.has_number = true,
+            WSEG_SPACE | CR | LF | NEWLINE => (),
+            _ if is_ignored_by_wb4(prop) => (),
+            _ => {
+                if is_hiragana_utf32(codepoint) {
+                    self.has_kana = true;
+                } else if !is_extended_pictographic_utf32(codepoint)
+                    && is_ideographic_utf32(codepoint)
+                {
+                    // Letters of languages written without spaces, which
+                    // have no dictionary or LSTM model, are also found here.
+                    if is_complex_utf32(codepoint) {
+                        self.has_letter = true;
+                    } else {
+                        self.has_ideograph = true;
+                    }
+                }
+                self.has_non_space = true;
+            }
+        }
+    }
+
+    fn rule_status(&self) -> RuleStatus {
+        if self.has_letter {
+            RuleStatus::Letter
+        } else if self.has_kana {
+            RuleStatus::Kana
+        } else if self.has_ideograph {
+            RuleStatus::Ideograph
+        } else if self.has_number {
+            RuleStatus::Number
+        } else {
+            RuleStatus::None
+        }
+    }
+
+    fn word_type(&self) -> WordType {
+        if self.has_letter || self.has_kana || self.has_ideograph {
+            WordType::Letter
+        } else if self.has_number {
+            WordType::Number
+        } else if self.has_non_space {
+            WordType::Punctuation
+        } else {
+            WordType::Space
+        }
+    }
+}
+
+macro_rules! word_break_iterator_impl {
+    ($name:ident, $iter_attr:ty, $char_type:ty) => {
+        /// The struct implementing the [`Iterator`] trait over the word
+        /// boundaries of the given string. Each item is the index of a
+        /// boundary, along with the [`WordType`] of the text between the
+        /// previous boundary and this one. Please see [`WordBreakSegmenter`]
+        /// for its usages.
+        ///
+        /// The [`RuleStatus`] of the last boundary is returned by
+        /// [`rule_status()`](Self::rule_status).
+        ///
+        /// [`Iterator`]: core::iter::Iterator
+        pub struct $name<'a> {
+            iter: $iter_attr,
+            len: usize,
+            current_pos_data: Option<(usize, $char_type)>,
+            dictionaries: Option<&'a Dictionaries>,
+            // The remaining boundaries found by a dictionary or an LSTM model, in
+            // reverse order.
+            result_cache: Vec<usize>,
+            rule_status: RuleStatus,
+            colon_in_words: bool,
+        }
+
+        impl<'a> Iterator for $name<'a> {
+            type Item = (usize, WordType);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if let Some(index) = self.result_cache.pop() {
+                    self.rule_status = RuleStatus::Letter;
+                    return Some((index, WordType::Letter));
+                }
+
+                let (index, first) = self.current_pos_data.or_else(|| self.iter.next())?;
+                if let Some(model) = get_complex_model_utf32(first as u32, self.dictionaries) {
+                    return self.handle_complex_language(model, index, first as u32);
+                }
+                let mut word_type = WordTypeBuilder::default();
+
+                // The property of the previous character.
+                let mut left = self.get_word_break_property(first as u32);
+                word_type.push(first as u32, left);
+                // The property of the previous character not ignored by WB4,
+                // and of the one before it.
+                let mut left_wb4 = left;
+                let mut left_wb4_previous = None;
+                // The number of consecutive regional indicators before the
+                // current position.
+                let mut ri_count = (left == REGIONAL_INDICATOR) as usize;
+
+                loop {
+                    self.current_pos_data = self.iter.next();
+                    let (_, right_codepoint) = match self.current_pos_data {
+                        Some(data) => data,
+                        // WB2
+                        None => return self.end_segment(self.len, word_type),
+                    };
+                    let right_codepoint = right_codepoint as u32;
+                    let right = self.get_word_break_property(right_codepoint);
+
+                    if !self.is_word_break(
+                        left,
+                        left_wb4,
+                        left_wb4_previous,
+                        right,
+                        right_codepoint,
+                        ri_count,
+                    ) {
+                        word_type.push(right_codepoint, right);
+                        // WB4: Ignore format and extend characters.
+                        if !is_ignored_by_wb4(right) {
+                            left_wb4_previous = Some(left_wb4);
+                            left_wb4 = right;
+                            if right == REGIONAL_INDICATOR {
+                                ri_count += 1;
+                            } else {
+                                ri_count = 0;
+                            }
+                        }
+                        left = right;
+                        continue;
+                    }
+
+                    return self.end_segment(self.current_pos_data.unwrap().0, word_type);
+                }
+            }
+        }
+
+        impl<'a> $name<'a> {
+            /// Returns the [`RuleStatus`] of the segment ending at the last
+            /// boundary returned by the iterator, or [`RuleStatus::None`] if
+            /// no boundary was returned yet.
+            pub fn rule_status(&self) -> RuleStatus {
+                self.rule_status
+            }
+
+            /// Returns whether the segment ending at the last boundary
+            /// returned by the iterator is a word, that is, whether it
+            /// contains letters, kana, ideographs, or numbers.
+            pub fn is_word_like(&self) -> bool {
+                self.rule_status.is_word_like()
+            }
+
+            fn end_segment(
+                &mut self,
+                index: usize,
+                word_type: WordTypeBuilder,
+            ) -> Option<(usize, WordType)> {
+                self.rule_status = word_type.rule_status();
+                Some((index, word_type.word_type()))
+            }
+
+            // UAX29 doesn't define word boundaries for languages such as Thai,
+            // so find them with a dictionary or an LSTM model.
+            fn handle_complex_language(
+                &mut self,
+                model: ComplexModel,
+                index: usize,
+                codepoint: u32,
+            ) -> Option<(usize, WordType)> {
+                let language = get_language(codepoint);
+                let mut indices = vec![index];
+                let mut codepoints = vec![codepoint];
+                loop {
+                    self.current_pos_data = self.iter.next();
+                    match self.current_pos_data {
+                        Some((index, ch))
+                            if get_language(ch as u32) == language
+                                && get_complex_model_utf32(ch as u32, self.dictionaries)
+                                    .is_some() =>
+                        {
+                            indices.push(index);
+                            codepoints.push(ch as u32);
+                        }
+                        _ => break,
+                    }
+                }
+                let end = self.current_pos_data.map_or(self.len, |(index, _)| index);
+
+                self.result_cache.push(end);
+                for i in model.segment_utf32(&codepoints).iter().rev() {
+                    self.result_cache.push(indices[*i]);
+                }
+                self.rule_status = RuleStatus::Letter;
+                self.result_cache
+                    .pop()
+                    .map(|index| (index, WordType::Letter))
+            }
+
+            /// Returns whether there is a word boundary before a character of
+            /// property `right`, following the rules of UAX #29.
+            fn is_word_break(
+                &self,
+                left: u8,
+                left_wb4: u8,
+                left_wb4_previous: Option<u8>,
+                right: u8,
+                right_codepoint: u32,
+                ri_count: usize,
+            ) -> bool {
+                // WB3
+                if left == CR && right == LF {
+                    return false;
+                }
+                // WB3a, WB3b
+                if matches!(left, CR | LF | NEWLINE) || matches!(right, CR | LF | NEWLINE) {
+                    return true;
+                }
+                // WB3c
+                if left == ZWJ && is_extended_pictographic_utf32(right_codepoint) {
+                    return false;
+                }
+                // WB3d
+                if left == WSEG_SPACE && right == WSEG_SPACE {
+                    return false;
+                }
+                // WB4
+                if is_ignored_by_wb4(right) {
+                    return false;
+                }
+
+                let left = left_wb4;
+                // WB5
+                if is_ahletter(left) && is_ahletter(right) {
+                    return false;
+                }
+                // WB6
+                if is_ahletter(left)
+                    && is_mid_letter_or_mid_num_let_q(right)
+                    && self.peek_property().map_or(false, is_ahletter)
+                {
+                    return false;
+                }
+                // WB7
+                if left_wb4_previous.map_or(false, is_ahletter)
+                    && is_mid_letter_or_mid_num_let_q(left)
+                    && is_ahletter(right)
+                {
+                    return false;
+                }
+                // WB7a
+                if left == HEBREW_LETTER && right == SINGLE_QUOTE {
+                    return false;
+                }
+                // WB7b
+                if left == HEBREW_LETTER
+                    && right == DOUBLE_QUOTE
+                    && self.peek_property() == Some(HEBREW_LETTER)
+                {
+                    return false;
+                }
+                // WB7c
+                if left_wb4_previous == Some(HEBREW_LETTER)
+                    && left == DOUBLE_QUOTE
+                    && right == HEBREW_LETTER
+                {
+                    return false;
+                }
+                // WB8, WB9, WB10
+                if (left == NUMERIC || is_ahletter(left))
+                    && (right == NUMERIC || is_ahletter(right))
+                {
+                    return false;
+                }
+                // WB11
+                if left_wb4_previous == Some(NUMERIC)
+                    && is_mid_num_or_mid_num_let_q(left)
+                    && right == NUMERIC
+                {
+                    return false;
+                }
+                // WB12
+                if left == NUMERIC
+                    && is_mid_num_or_mid_num_let_q(right)
+                    && self.peek_property() == Some(NUMERIC)
+                {
+                    return false;
+                }
+                // WB13
+                if left == KATAKANA && right == KATAKANA {
+                    return false;
+                }
+                // WB13a
+                if (is_ahletter(left) || matches!(left, NUMERIC | KATAKANA | EXTEND_NUM_LET))
+                    && right == EXTEND_NUM_LET
+                {
+                    return false;
+                }
+                // WB13b
+                if left == EXTEND_NUM_LET
+                    && (is_ahletter(right) || matches!(right, NUMERIC | KATAKANA))
+                {
+                    return false;
+                }
+                // WB15, WB16
+                if left == REGIONAL_INDICATOR && right == REGIONAL_INDICATOR && ri_count % 2 == 1 {
+                    return false;
+                }
+                // WB999
+                true
+            }
+
+            /// Returns the property of the character after the current one,
+            /// skipping the characters ignored by WB4.
+            fn peek_property(&self) -> Option<u8> {
+                self.iter
+                    .clone()
+                    .map(|(_, codepoint)| self.get_word_break_property(codepoint as u32))
+                    .find(|prop| !is_ignored_by_wb4(*prop))
+            }
+
+            /// Returns the word break property of `codepoint`, tailored by the
+            /// exceptions of the segmenter.
+            fn get_word_break_property(&self, codepoint: u32) -> u8 {
+                let prop = get_word_break_property_utf32(codepoint);
+                if prop == MID_LETTER && !self.colon_in_words && is_colon_utf32(codepoint) {
+                    OTHER
+                } else {
+                    prop
+                }
+            }
+        }
+    };
+}
+
+word_break_iterator_impl!(WordBreakIterator, CharIndices<'a>, char);
+
+impl<'a> WordBreakIterator<'a> {
+    /// Create a word break iterator for an `str` (a UTF-8 string).
+    pub fn new(input: &str) -> WordBreakIterator {
+        WordBreakIterator {
+            iter: input.char_indices(),
+            len: input.len(),
+            current_pos_data: None,
+            dictionaries: None,
+            result_cache: Vec::new(),
+            rule_status: RuleStatus::None,
+            colon_in_words: true,
+        }
+    }
+}
+
+word_break_iterator_impl!(WordBreakIteratorLatin1, Latin1Indices<'a>, u8);
+
+impl<'a> WordBreakIteratorLatin1<'a> {
+    /// Create a word break iterator for a Latin-1 (8-bit) string.
+    pub fn new(input: &[u8]) -> WordBreakIteratorLatin1 {
+        WordBreakIteratorLatin1 {
+            iter: Latin1Indices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            dictionaries: None,
+            result_cache: Vec::new(),
+            rule_status: RuleStatus::None,
+            colon_in_words: true,
+        }
+    }
+}
+
+word_break_iterator_impl!(WordBreakIteratorUtf16, Utf16Indices<'a>, u32);
+
+impl<'a> WordBreakIteratorUtf16<'a> {
+    /// Create a word break iterator for a UTF-16 string.
+    pub fn new(input: &[u16]) -> WordBreakIteratorUtf16 {
+        WordBreakIteratorUtf16 {
+            iter: Utf16Indices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            dictionaries: None,
+            result_cache: Vec::new(),
+            rule_status: RuleStatus::None,
+            colon_in_words: true,
+        }
+    }
+}
+
+/// A word segmenter finding the word boundaries of a text, following the
+/// default word boundary rules of [Unicode Standard Annex #29][UAX29].
+///
+/// The boundaries are returned along with the [`WordType`] of the text before
+/// them, so that the segments that are words can be told apart from spaces
+/// and punctuation, for example to index the words of a text for search, or
+/// to select a word on double-click.
+///
+/// Languages that are written without spaces between words, such as Thai, are
+/// segmented with the dictionaries the segmenter was created with, or else
+/// with an LSTM model if there is one for the language (see [`Dictionaries`]);
+/// otherwise each character of these languages is a separate segment.
+///
+/// [UAX29]: http://www.unicode.org/reports/tr29/
+///
+/// # Examples
+///
+/// ```
+/// use icu_segmenter::{WordBreakSegmenter, WordType};
+///
+/// let segmenter = WordBreakSegmenter::new();
+/// let boundaries: Vec<(usize, WordType)> =
+///     segmenter.segment_str("Hello, 3.14 worlds").collect();
+/// assert_eq!(
+///     &boundaries,
+///     &[
+///         (5, WordType::Letter),
+///         (6, WordType::Punctuation),
+///         (7, WordType::Space),
+///         (11, WordType::Number),
+///         (12, WordType::Space),
+///         (18, WordType::Letter),
+///     ]
+/// );
+///
+/// // Find the words of a text.
+/// let text = "Can't stop!";
+/// let mut start = 0;
+/// let words: Vec<&str> = segmenter
+///     .segment_str(text)
+///     .filter_map(|(end, word_type)| {
+///         let segment = &text[start..end];
+///         start = end;
+///         (word_type == WordType::Letter).then(|| segment)
+///     })
+///     .collect();
+/// assert_eq!(&words, &["Can't", "stop"]);
+/// ```
+///
+/// The iterators also return the ICU4C-compatible [`RuleStatus`] of the last
+/// boundary, which tells kana and ideographs apart from other letters:
+///
+/// ```
+/// use icu_segmenter::{RuleStatus, WordBreakSegmenter};
+///
+/// let segmenter = WordBreakSegmenter::new();
+/// let mut iter = segmenter.segment_str("\u{30ab}\u{30ca}!");
+/// iter.next();
+/// assert_eq!(iter.rule_status(), RuleStatus::Kana);
+/// assert!(iter.is_word_like());
+/// iter.next();
+/// assert_eq!(iter.rule_status(), RuleStatus::None);
+/// assert!(!iter.is_word_like());
+/// ```
+///
+/// Segment Thai text, which has no spaces between words, with a dictionary:
+///
+/// ```
+/// use icu_segmenter::{Dictionaries, Dictionary, Language, WordBreakSegmenter, WordType};
+///
+/// let mut dictionaries = Dictionaries::new();
+/// dictionaries.insert(Language::Thai, Dictionary::from_words(["ภาษา", "ไทย"]));
+/// let segmenter = WordBreakSegmenter::new_with_dictionaries(dictionaries);
+/// let boundaries: Vec<(usize, WordType)> = segmenter.segment_str("ภาษาไทย!").collect();
+/// assert_eq!(
+///     &boundaries,
+///     &[
+///         (12, WordType::Letter),
+///         (21, WordType::Letter),
+///         (22, WordType::Punctuation),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordBreakSegmenter {
+    dictionaries: Dictionaries,
+    pub(crate) colon_in_words: bool,
+}
+
+impl Default for WordBreakSegmenter {
+    fn default() -> Self {
+        Self::new_with_dictionaries(Dictionaries::default())
+    }
+}
+
+impl WordBreakSegmenter {
+    /// Create a word segmenter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a word segmenter using `dictionaries` to find the word
+    /// boundaries in the languages written without spaces between words.
+    pub fn new_with_dictionaries(dictionaries: Dictionaries) -> Self {
+        Self {
+            dictionaries,
+            colon_in_words: true,
+        }
+    }
+
+    /// Create a word break iterator for an `str` (a UTF-8 string).
+    ///
+    /// The boundaries are byte indices into `input`. The start of the string
+    /// is not returned as a boundary.
+    pub fn segment_str<'s>(&'s self, input: &'s str) -> WordBreakIterator<'s> {
+        let mut iter = WordBreakIterator::new(input);
+        iter.dictionaries = Some(&self.dictionaries);
+        iter.colon_in_words = self.colon_in_words;
+        iter
+    }
+
+    /// Create a word break iterator for a Latin-1 (8-bit) string.
+    ///
+    /// The boundaries are indices into `input`. The start of the string is
+    /// not returned as a boundary.
+    pub fn segment_latin1<'s>(&'s self, input: &'s [u8]) -> WordBreakIteratorLatin1<'s> {
+        let mut iter = WordBreakIteratorLatin1::new(input);
+        iter.dictionaries = Some(&self.dictionaries);
+        iter.colon_in_words = self.colon_in_words;
+        iter
+    }
+
+    /// Create a word break iterator for a UTF-16 string.
+    ///
+    /// The boundaries are indices of 16-bit code units into `input`. The start
+    /// of the string is not returned as a boundary.
+    pub fn segment_utf16<'s>(&'s self, input: &'s [u16]) -> WordBreakIteratorUtf16<'s> {
+        let mut iter = WordBreakIteratorUtf16::new(input);
+        iter.dictionaries = Some(&self.dictionaries);
+        iter.colon_in_words = self.colon_in_words;
+        iter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wb_define::*;
+    use crate::word_breaker::get_word_break_property_utf32;
+    use crate::RuleStatus;
+    use crate::WordBreakIterator;
+    use crate::WordBreakIteratorLatin1;
+    use crate::WordBreakIteratorUtf16;
+    use crate::WordBreakSegmenter;
+    use crate::WordType;
+
+    #[test]
+    fn word_break_property() {
+        assert_eq!(get_word_break_property_utf32(0x0020), WSEG_SPACE);
+        assert_eq!(get_word_break_property_utf32(0x0027), SINGLE_QUOTE);
+        assert_eq!(get_word_break_property_utf32(0x002e), MID_NUM_LET);
+        assert_eq!(get_word_break_property_utf32(0x0030), NUMERIC);
+        assert_eq!(get_word_break_property_utf32(0x0041), ALETTER);
+        assert_eq!(get_word_break_property_utf32(0x005f), EXTEND_NUM_LET);
+        assert_eq!(get_word_break_property_utf32(0x05d0), HEBREW_LETTER);
+        assert_eq!(get_word_break_property_utf32(0x30a2), KATAKANA);
+        assert_eq!(get_word_break_property_utf32(0x4e00), OTHER);
+        assert_eq!(get_word_break_property_utf32(0x1f1e6), REGIONAL_INDICATOR);
+        assert_eq!(get_word_break_property_utf32(0x200d), ZWJ);
+        assert_eq!(get_word_break_property_utf32(0x50005), OTHER);
+    }
+
+    #[test]
+    fn word_break() {
+        let mut iter = WordBreakIterator::new("");
+        assert_eq!(None, iter.next());
+
+        let mut iter = WordBreakIterator::new("can't stop");
+        assert_eq!(Some((5, WordType::Letter)), iter.next());
+        assert_eq!(Some((6, WordType::Space)), iter.next());
+        assert_eq!(Some((10, WordType::Letter)), iter.next());
+        assert_eq!(None, iter.next());
+
+        // WB3d, WB6, WB7
+        let mut iter = WordBreakIterator::new("e.g.  x");
+        assert_eq!(Some((3, WordType::Letter)), iter.next());
+        assert_eq!(Some((4, WordType::Punctuation)), iter.next());
+        assert_eq!(Some((6, WordType::Space)), iter.next());
+        assert_eq!(Some((7, WordType::Letter)), iter.next());
+        assert_eq!(None, iter.next());
+
+        // WB11, WB12
+        let mut iter = WordBreakIterator::new("1,000.5!");
+        assert_eq!(Some((7, WordType::Number)), iter.next());
+        assert_eq!(Some((8, WordType::Punctuation)), iter.next());
+
+        // WB13a, WB13b
+        let mut iter = WordBreakIterator::new("snake_case_1");
+        assert_eq!(Some((12, WordType::Letter)), iter.next());
+
+        // Ideographs are letters
+        let mut iter = WordBreakIterator::new("\u{4e2d}\u{6587}");
+        assert_eq!(Some((3, WordType::Letter)), iter.next());
+        assert_eq!(Some((6, WordType::Letter)), iter.next());
+
+        // WB3c
+        let mut iter = WordBreakIterator::new("\u{1f468}\u{200d}\u{1f469}!");
+        assert_eq!(Some((11, WordType::Punctuation)), iter.next());
+        assert_eq!(Some((12, WordType::Punctuation)), iter.next());
+
+        // WB15, WB16
+        let mut iter = WordBreakIterator::new("\u{1f1e8}\u{1f1ed}\u{1f1e9}\u{1f1ea}\u{1f1eb}");
+        assert_eq!(Some((8, WordType::Punctuation)), iter.next());
+        assert_eq!(Some((16, WordType::Punctuation)), iter.next());
+        assert_eq!(Some((20, WordType::Punctuation)), iter.next());
+        assert_eq!(None, iter.next());
+
+        // WB3a, WB3b
+        let mut iter = WordBreakIterator::new("a\r\nb");
+        assert_eq!(Some((1, WordType::Letter)), iter.next());
+        assert_eq!(Some((3, WordType::Space)), iter.next());
+        assert_eq!(Some((4, WordType::Letter)), iter.next());
+    }
+
+    #[test]
+    fn word_break_rule_status() {
+        let mut iter = WordBreakIterator::new("a1 3 \u{30ab}\u{30ca}\u{3072}\u{4e2d}\u{1f642}");
+        assert_eq!(RuleStatus::None, iter.rule_status());
+        let expected = [
+            (2, RuleStatus::Letter),
+            (3, RuleStatus::None),
+            (4, RuleStatus::Number),
+            (5, RuleStatus::None),
+            (11, RuleStatus::Kana),
+            (14, RuleStatus::Kana),
+            (17, RuleStatus::Ideograph),
+            (21, RuleStatus::None),
+        ];
+        for (index, rule_status) in expected {
+            assert_eq!(Some(index), iter.next().map(|(i, _)| i));
+            assert_eq!(rule_status, iter.rule_status());
+            assert_eq!(rule_status != RuleStatus::None, iter.is_word_like());
+        }
+        assert_eq!(None, iter.next());
+
+        // Letters of languages written without spaces are letters, even without
+        // a dictionary or an LSTM model.
+        let mut iter = WordBreakIteratorUtf16::new(&[0x1780]);
+        assert_eq!(Some((1, WordType::Letter)), iter.next());
+        assert_eq!(RuleStatus::Letter, iter.rule_status());
+
+        assert_eq!(RuleStatus::Letter as i32, 200);
+    }
+
+    #[test]
+    fn word_break_utf16_latin1() {
+        // "café 🙂!" in UTF-16, with a surrogate pair.
+        let input: Vec<u16> = "caf\u{e9} \u{1f642}!".encode_utf16().collect();
+        let mut iter = WordBreakIteratorUtf16::new(&input);
+        assert_eq!(Some((4, WordType::Letter)), iter.next());
+        assert_eq!(Some((5, WordType::Space)), iter.next());
+        assert_eq!(Some((7, WordType::Punctuation)), iter.next());
+        assert_eq!(Some((8, WordType::Punctuation)), iter.next());
+        assert_eq!(None, iter.next());
+
+        // "café 3,5" in Latin-1
+        let input = [0x63, 0x61, 0x66, 0xe9, 0x20, 0x33, 0x2c, 0x35];
+        let mut iter = WordBreakIteratorLatin1::new(&input);
+        assert_eq!(Some((4, WordType::Letter)), iter.next());
+        assert_eq!(Some((5, WordType::Space)), iter.next());
+        assert_eq!(Some((8, WordType::Number)), iter.next());
+        assert_eq!(None, iter.next());
+
+        let segmenter = WordBreakSegmenter::new();
+        let input: Vec<u16> = "Hello World".encode_utf16().collect();
+        let result: Vec<usize> = segmenter.segment_utf16(&input).map(|(i, _)| i).collect();
+        assert_eq!(result, [5, 6, 11]);
+        let result: Vec<usize> = segmenter
+            .segment_latin1(b"Hello World")
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(result, [5, 6, 11]);
+    }
+
+    #[test]
+    fn word_break_colon() {
+        let mut segmenter = WordBreakSegmenter::new();
+        let result: Vec<usize> = segmenter.segment_str("EU:s a:").map(|(i, _)| i).collect();
+        assert_eq!(result, [4, 5, 6, 7]);
+
+        // Most locales of CLDR break words at colons.
+        segmenter.colon_in_words = false;
+        let result: Vec<usize> = segmenter.segment_str("EU:s a:").map(|(i, _)| i).collect();
+        assert_eq!(result, [2, 3, 4, 5, 6, 7]);
+        let input: Vec<u16> = "EU\u{ff1a}s".encode_utf16().collect();
+        let result: Vec<usize> = segmenter.segment_utf16(&input).map(|(i, _)| i).collect();
+        assert_eq!(result, [2, 3, 4]);
+    }
+
+    #[cfg(feature = "lstm")]
+    #[test]
+    fn word_break_lstm() {
+        // Thai is segmented with the built-in LSTM model.
+        let mut iter = WordBreakIterator::new("ภาษาไทยภาษาไทย!");
+        assert_eq!(Some((12, WordType::Letter)), iter.next());
+        assert_eq!(Some((21, WordType::Letter)), iter.next());
+        assert_eq!(Some((33, WordType::Letter)), iter.next());
+        assert_eq!(Some((42, WordType::Letter)), iter.next());
+        assert_eq!(Some((43, WordType::Punctuation)), iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
@@ -5,6 +5,10 @@
 use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorLatin1;
 use icu_segmenter::LineBreakIteratorUtf16;
+use icu_segmenter::LineBreakIteratorUtf32;
+use icu_segmenter::WordBreakIterator;
+use icu_segmenter::WordBreakIteratorLatin1;
+use icu_segmenter::WordBreakIteratorUtf16;
 use std::char;
 use std::fs::File;
 use std::io::prelude::*;
@@ -22,6 +26,7 @@ struct TestData {
     latin1_vec: Vec<u8>,
     break_result_utf8: Vec<usize>,
     break_result_utf16: Vec<usize>,
+    break_result_utf32: Vec<usize>,
     break_result_latin1: Option<Vec<usize>>,
 }
 
@@ -56,6 +61,7 @@ impl Iterator for TestContentIterator {
             let mut char_break: Vec<_> = Vec::new();
             let mut u8_break: Vec<_> = Vec::new();
             let mut u16_break: Vec<_> = Vec::new();
+            let mut u32_break: Vec<_> = Vec::new();
             let mut char_vec: Vec<_> = Vec::new();
             let mut u8_vec: Vec<_> = Vec::new();
             let mut u16_vec: Vec<_> = Vec::new();
@@ -87,6 +93,7 @@ impl Iterator for TestContentIterator {
                     char_break.push(char_len);
                     u8_break.push(u8_len);
                     u16_break.push(u16_len);
+                    u32_break.push(char_vec.len());
                 }
             }
             return Some(Self::Item {
@@ -96,6 +103,7 @@ impl Iterator for TestContentIterator {
                 latin1_vec: u8_vec,
                 break_result_utf8: char_break,
                 break_result_utf16: u16_break,
+                break_result_utf32: u32_break,
                 break_result_latin1: if ascii_only { Some(u8_break) } else { None },
             });
         }
@@ -106,6 +114,7 @@ impl Iterator for TestContentIterator {
 fn run_line_break_test() {
     let test_iter = TestContentIterator::new("./tests/testdata/LineBreakTest.txt");
     for test in test_iter {
+        let utf32_vec: Vec<u32> = test.utf8_vec.iter().map(|ch| *ch as u32).collect();
         let s: String = test.utf8_vec.into_iter().collect();
         let iter = LineBreakIterator::new(&s);
         let result: Vec<usize> = iter.collect();
@@ -119,6 +128,14 @@ fn run_line_break_test() {
             test.original_line
         );
 
+        let iter = LineBreakIteratorUtf32::new(&utf32_vec);
+        let result: Vec<usize> = iter.collect();
+        assert_eq!(
+            result, test.break_result_utf32,
+            "UTF32: {}",
+            test.original_line
+        );
+
         // Test data is Latin-1 character only, it can run for Latin-1 segmenter test.
         if let Some(break_result_latin1) = test.break_result_latin1 {
             let iter = LineBreakIteratorLatin1::new(&test.latin1_vec);
@@ -131,3 +148,41 @@ fn run_line_break_test() {
         }
     }
 }
+
+#[test]
+fn run_word_break_test() {
+    let test_iter = TestContentIterator::new("./tests/testdata/WordBreakTest.txt");
+    for test in test_iter {
+        let s: String = test.utf8_vec.into_iter().collect();
+        let iter = WordBreakIterator::new(&s);
+        let result: Vec<usize> = iter.map(|(index, _)| index).collect();
+        // The start of text is not returned as a boundary.
+        assert_eq!(
+            result,
+            test.break_result_utf8[1..],
+            "{}",
+            test.original_line
+        );
+
+        let iter = WordBreakIteratorUtf16::new(&test.utf16_vec);
+        let result: Vec<usize> = iter.map(|(index, _)| index).collect();
+        assert_eq!(
+            result,
+            test.break_result_utf16[1..],
+            "UTF16: {}",
+            test.original_line
+        );
+
+        // Test data is Latin-1 character only, it can run for Latin-1 segmenter test.
+        if let Some(break_result_latin1) = test.break_result_latin1 {
+            let iter = WordBreakIteratorLatin1::new(&test.latin1_vec);
+            let result: Vec<usize> = iter.map(|(index, _)| index).collect();
+            assert_eq!(
+                result,
+                break_result_latin1[1..],
+                "Latin1: {}",
+                test.original_line
+            );
+        }
+    }
+}
@@ -533,6 +533,90 @@ with open('rule_table.rs', 'w') as table_file:
     table_file.write("];\n")
 
 
+# Codepoints not listed in WordBreakProperty.txt have the default word break
+# property value "Other"
+# https://www.unicode.org/reports/tr29/#Default_Word_Boundaries
+wb_props = {}
+ep_codepoints = set()
+
+# Word break property values, and the names of their constants
+wb_names = {
+    "Other": "OTHER",
+    "ALetter": "ALETTER",
+    "CR": "CR",
+    "Double_Quote": "DOUBLE_QUOTE",
+    "Extend": "EXTEND",
+    "ExtendNumLet": "EXTEND_NUM_LET",
+    "Format": "FORMAT",
+    "Hebrew_Letter": "HEBREW_LETTER",
+    "Katakana": "KATAKANA",
+    "LF": "LF",
+    "MidLetter": "MID_LETTER",
+    "MidNum": "MID_NUM",
+    "MidNumLet": "MID_NUM_LET",
+    "Newline": "NEWLINE",
+    "Numeric": "NUMERIC",
+    "Regional_Indicator": "REGIONAL_INDICATOR",
+    "Single_Quote": "SINGLE_QUOTE",
+    "WSegSpace": "WSEG_SPACE",
+    "ZWJ": "ZWJ",
+}
+
+ucd_codepoint_pattern = r"([0-9A-F]{4,6})(?:\.\.([0-9A-F]{4,6}))?\s*;\s*([A-Za-z_]+)"
+
+with open('WordBreakProperty.txt', 'r') as wb_file:
+    for line in wb_file.readlines():
+        if m := re.match(ucd_codepoint_pattern, line):
+            start = int(m[1], 16)
+            end = int(m[2], 16) if m[2] else start
+            for i in range(start, end + 1):
+                wb_props[i] = m[3]
+
+# Extended_Pictographic is used by WB3c
+with open('emoji-data.txt', 'r') as emoji_file:
+    for line in emoji_file.readlines():
+        if m := re.match(ucd_codepoint_pattern, line):
+            if m[3] == "Extended_Pictographic":
+                start = int(m[1], 16)
+                end = int(m[2], 16) if m[2] else start
+                ep_codepoints.update(range(start, end + 1))
+
+
+def to_ranges(codepoints):
+    """Merge a sorted list of (codepoint, value) into (start, end, value) ranges."""
+    ranges = []
+    for (cp, value) in codepoints:
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == value:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp, value])
+    return ranges
+
+with open('wb_define.rs', 'w') as define_file:
+    define_file.write(header)
+
+    count = 0
+    for name in wb_names.values():
+        define_file.write("pub const %s: u8 = %d;\n" % (name, count))
+        count = count + 1
+
+with open('word_break_property_table.rs', 'w') as table_file:
+    table_file.write(header)
+    table_file.write("use crate::wb_define::*;\n\n")
+
+    wb_ranges = to_ranges(sorted(wb_props.items()))
+    table_file.write("pub const UAX29_PROPERTY_TABLE: [(u32, u32, u8); %d] = [\n" % len(wb_ranges))
+    for (start, end, prop) in wb_ranges:
+        table_file.write("  (0x%x, 0x%x, %s),\n" % (start, end, wb_names[prop]))
+    table_file.write("];\n\n")
+
+    ep_ranges = to_ranges([(cp, True) for cp in sorted(ep_codepoints)])
+    table_file.write("pub const EXTENDED_PICTOGRAPHIC_TABLE: [(u32, u32); %d] = [\n" % len(ep_ranges))
+    for (start, end, _) in ep_ranges:
+        table_file.write("  (0x%x, 0x%x),\n" % (start, end))
+    table_file.write("];\n")
+
+
 # Format and copy generated rs files to src directory.
 
 # properties_defines.rs, properties_other.rs and property_table.rs are Line Break property table map for UAX14
@@ -548,3 +632,8 @@ os.rename("lb_define.rs", "../src/lb_define.rs")
 # rule_table.rs is state machine table by UAX14
 subprocess.call(["rustfmt", "rule_table.rs"])
 os.rename("rule_table.rs", "../src/rule_table.rs")
+# wb_define.rs and word_break_property_table.rs are Word Break property defines and table map for UAX29
+subprocess.call(["rustfmt", "wb_define.rs"])
+os.rename("wb_define.rs", "../src/wb_define.rs")
+subprocess.call(["rustfmt", "word_break_property_table.rs"])
+os.rename("word_break_property_table.rs", "../src/word_break_property_table.rs")
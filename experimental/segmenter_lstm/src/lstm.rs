@@ -9,6 +9,7 @@ use ndarray::{Array1, Array2, ArrayBase, Dim, ViewRepr};
 use std::str;
 use unicode_segmentation::UnicodeSegmentation;
 
+#[derive(Debug, Clone)]
 pub struct Lstm {
     data: structs::LstmData,
 }
@@ -163,4 +164,28 @@ impl Lstm {
         }
         bies
     }
+
+    /// `word_breaks` returns the word boundaries found by the model in a "clean" unsegmented string, as code point
+    /// indices. The start and the end of the string are not returned.
+    pub fn word_breaks(&self, input: &str) -> Vec<usize> {
+        let bies = self.word_segmenter(input);
+        // The number of code points of each element of the BIES sequence
+        let lengths: Vec<usize> = if self.data.model.contains("_codepoints_") {
+            input.chars().map(|_| 1).collect()
+        } else {
+            UnicodeSegmentation::graphemes(input, true)
+                .map(|s| s.chars().count())
+                .collect()
+        };
+        let len: usize = lengths.iter().sum();
+        let mut result = Vec::new();
+        let mut offset = 0;
+        for (ch, n) in bies.chars().zip(lengths) {
+            offset += n;
+            if ch == 'e' && offset < len {
+                result.push(offset);
+            }
+        }
+        result
+    }
 }
@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_spoof`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API implements the confusable detection and restriction level detection of
+//! [`UTS #39`], which protect against identifiers, such as domain names and user names, that
+//! are made to look like others, for example by replacing Latin letters with look-alike
+//! Cyrillic ones.
+//!
+//! [`SpoofChecker::skeleton()`] maps a string to a skeleton, which is the same for strings
+//! that look alike, and [`SpoofChecker::restriction_level()`] determines how an identifier
+//! mixes scripts, as a [`RestrictionLevel`].
+//!
+//! The spoof checker is driven by the confusables, Script_Extensions, and Identifier_Status
+//! data in [`provider`], together with the canonical decompositions of [`icu_normalizer`]. The
+//! `data` directory of this crate contains that data for the `FsDataProvider`; it is generated
+//! by `tools/generate_data.py`. Its confusables are generated from an excerpt of
+//! `confusables.txt` that only covers common Latin, Greek, and Cyrillic letters; generate the
+//! data from the complete file for production use.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::fork::ForkByKeyProvider;
+//! use icu_provider_fs::FsDataProvider;
+//! use icu_spoof::{RestrictionLevel, SpoofChecker};
+//!
+//! let spoof = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+//!     .expect("Data directory should exist");
+//! let normalizer = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+//!     .expect("Data directory should exist");
+//! let provider = ForkByKeyProvider(spoof, normalizer);
+//!
+//! let checker = SpoofChecker::try_new(&provider).expect("Data should load successfully");
+//!
+//! // "rn" looks like "m", and "0" like "O"
+//! assert!(checker.are_confusable("modern", "rnodern"));
+//! assert!(checker.are_confusable("BOOK", "B00K"));
+//!
+//! // Japanese mixes Han, Hiragana, and Katakana
+//! assert_eq!(checker.restriction_level("東京タワー"), RestrictionLevel::SingleScript);
+//! assert_eq!(checker.restriction_level("Tokyoタワー"), RestrictionLevel::HighlyRestrictive);
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`UTS #39`]: https://www.unicode.org/reports/tr39/
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+pub mod error;
+pub mod provider;
+mod spoof_checker;
+
+pub use error::Error as SpoofError;
+pub use spoof_checker::{RestrictionLevel, SpoofChecker};
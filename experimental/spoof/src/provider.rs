@@ -0,0 +1,104 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use icu_uniset::UnicodeSet;
+use zerovec::{ZeroMap, ZeroVec};
+
+pub mod key {
+    //! Resource keys for [`icu_spoof`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the prototypes of confusable characters.
+    pub const CONFUSABLES_V1: ResourceKey = resource_key!(Spoof, "confusables", 1);
+
+    /// Resource key: the Script_Extensions property.
+    pub const SCRIPT_EXTENSIONS_V1: ResourceKey = resource_key!(Spoof, "scx", 1);
+
+    /// Resource key: the characters allowed in identifiers.
+    pub const ALLOWED_IDENTIFIER_CHARS_V1: ResourceKey = resource_key!(Spoof, "allowed", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_spoof`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(ConfusablesV1Marker, [key::CONFUSABLES_V1]),
+    icu_provider::registered_keys!(ScriptExtensionsV1Marker, [key::SCRIPT_EXTENSIONS_V1]),
+    icu_provider::registered_keys!(
+        AllowedIdentifierCharsV1Marker,
+        [key::ALLOWED_IDENTIFIER_CHARS_V1]
+    ),
+];
+
+/// The confusable characters from `confusables.txt` of [`UTS #39`], mapped to their
+/// prototypes.
+///
+/// The prototypes are in NFD, so that the skeleton of a string is formed by replacing the
+/// characters of its NFD with their prototypes.
+///
+/// [`UTS #39`]: https://www.unicode.org/reports/tr39/#Confusable_Detection
+#[icu_provider::data_struct]
+#[derive(Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ConfusablesV1<'data> {
+    /// Map from a confusable character to its prototype.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub prototypes: ZeroMap<'data, char, str>,
+}
+
+/// The Script_Extensions property of all code points, as ranges of code points with the same
+/// set of scripts.
+///
+/// Scripts are identified by their `UScriptCode` values in ICU4C, which are also the values of
+/// `icu_properties::Script`. Code points that are not assigned to a script have the script
+/// Unknown (103).
+#[icu_provider::data_struct]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ScriptExtensionsV1<'data> {
+    /// The first code point of each range, in ascending order, starting with U+0000.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub starts: ZeroVec<'data, u32>,
+
+    /// The index of the script set of each range in `set_starts`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub sets: ZeroVec<'data, u16>,
+
+    /// The index in `scripts` at which each script set starts; a script set ends where the next
+    /// one starts.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub set_starts: ZeroVec<'data, u16>,
+
+    /// The scripts of all script sets, one set after another.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub scripts: ZeroVec<'data, u16>,
+}
+
+/// The characters that may be used in identifiers, i.e. those with the Identifier_Status
+/// Allowed from [`UTS #39`].
+///
+/// [`UTS #39`]: https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type
+#[icu_provider::data_struct]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AllowedIdentifierCharsV1<'data> {
+    /// The set of allowed characters, represented as an inversion list.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub allowed: UnicodeSet<'data>,
+}
@@ -0,0 +1,304 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::provider::{
+    key, AllowedIdentifierCharsV1Marker, ConfusablesV1Marker, ScriptExtensionsV1Marker,
+};
+use alloc::string::String;
+use icu_normalizer::provider::{CanonicalCombiningClassV1Marker, CanonicalDecompositionV1Marker};
+use icu_normalizer::DecomposingNormalizer;
+use icu_provider::prelude::*;
+
+// The UScriptCode values of ICU4C for the scripts that are treated specially.
+const COMMON: u16 = 0;
+const INHERITED: u16 = 1;
+const BOPOMOFO: u16 = 5;
+const CHEROKEE: u16 = 6;
+const CYRILLIC: u16 = 8;
+const GREEK: u16 = 14;
+const HAN: u16 = 17;
+const HANGUL: u16 = 18;
+const HIRAGANA: u16 = 20;
+const KATAKANA: u16 = 22;
+const LATIN: u16 = 25;
+const JAPANESE: u16 = 105;
+const KOREAN: u16 = 119;
+const HAN_WITH_BOPOMOFO: u16 = 172;
+
+/// The restriction levels of [`UTS #39`], which describe how strictly the characters of an
+/// identifier are limited to a single script.
+///
+/// The levels are ordered from the most to the least restrictive, so that an identifier can be
+/// checked against a maximum level with `<=`.
+///
+/// [`UTS #39`]: https://www.unicode.org/reports/tr39/#Restriction_Level_Detection
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RestrictionLevel {
+    /// All characters are ASCII.
+    AsciiOnly,
+    /// All characters are from a single script, such as Cyrillic, or from a combination of
+    /// scripts that is used as a single writing system, such as Han and Katakana for Japanese.
+    SingleScript,
+    /// All characters are from Latin and one of the combinations of scripts used for Chinese,
+    /// Japanese, or Korean.
+    HighlyRestrictive,
+    /// All characters are from Latin and one other script, which is not Cyrillic, Greek, or
+    /// Cherokee.
+    ModeratelyRestrictive,
+    /// The characters are from an arbitrary mix of scripts.
+    MinimallyRestrictive,
+    /// Some characters are not allowed in identifiers.
+    Unrestricted,
+}
+
+/// A set of scripts, as a bit set of their `UScriptCode` values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct ScriptSet([u64; 4]);
+
+impl ScriptSet {
+    const EMPTY: Self = Self([0; 4]);
+    const ALL: Self = Self([u64::MAX; 4]);
+
+    fn insert(&mut self, script: u16) {
+        debug_assert!(script < 256, "UScriptCode values are below 256");
+        if let Some(bits) = self.0.get_mut(usize::from(script / 64)) {
+            *bits |= 1 << (script % 64);
+        }
+    }
+
+    fn contains(&self, script: u16) -> bool {
+        self.0
+            .get(usize::from(script / 64))
+            .map_or(false, |bits| bits & (1 << (script % 64)) != 0)
+    }
+
+    fn intersect(&mut self, other: &Self) {
+        for (bits, other_bits) in self.0.iter_mut().zip(other.0.iter()) {
+            *bits &= other_bits;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::EMPTY
+    }
+}
+
+/// A spoof checker, which detects strings that look alike and identifiers that mix scripts, as
+/// described in [`UTS #39`].
+///
+/// Two strings are confusable if they have the same [skeleton](SpoofChecker::skeleton), which
+/// replaces each character with a prototype that it can be confused with, such as the Latin "a"
+/// for the Cyrillic "а". The [restriction level](SpoofChecker::restriction_level) of an
+/// identifier describes how its characters mix scripts; registries usually only accept
+/// identifiers up to [`RestrictionLevel::HighlyRestrictive`] or
+/// [`RestrictionLevel::ModeratelyRestrictive`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::fork::ForkByKeyProvider;
+/// use icu_provider_fs::FsDataProvider;
+/// use icu_spoof::{RestrictionLevel, SpoofChecker};
+///
+/// let spoof = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///     .expect("Data directory should exist");
+/// let normalizer = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+///     .expect("Data directory should exist");
+/// let provider = ForkByKeyProvider(spoof, normalizer);
+///
+/// let checker = SpoofChecker::try_new(&provider).expect("Data should load successfully");
+///
+/// // "paypal" with the Cyrillic letters "а" and "р"
+/// let spoofed = "\u{0440}\u{0430}ypal";
+/// assert!(checker.are_confusable("paypal", spoofed));
+/// assert_eq!(checker.restriction_level("paypal"), RestrictionLevel::AsciiOnly);
+/// assert_eq!(
+///     checker.restriction_level(spoofed),
+///     RestrictionLevel::MinimallyRestrictive
+/// );
+/// ```
+///
+/// [`UTS #39`]: https://www.unicode.org/reports/tr39/
+pub struct SpoofChecker<'data> {
+    nfd: DecomposingNormalizer<'data>,
+    confusables: DataPayload<'data, ConfusablesV1Marker>,
+    script_extensions: DataPayload<'data, ScriptExtensionsV1Marker>,
+    allowed_chars: DataPayload<'data, AllowedIdentifierCharsV1Marker>,
+}
+
+impl<'data> SpoofChecker<'data> {
+    /// Creates a spoof checker, loading the confusables, script, and identifier data, as well
+    /// as the canonical decomposition data of [`icu_normalizer`].
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, ConfusablesV1Marker>
+            + DataProvider<'data, ScriptExtensionsV1Marker>
+            + DataProvider<'data, AllowedIdentifierCharsV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + ?Sized,
+    {
+        let nfd = DecomposingNormalizer::try_new_nfd(data_provider)?;
+        let confusables: DataPayload<ConfusablesV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::CONFUSABLES_V1))?
+            .take_payload()?;
+        let script_extensions: DataPayload<ScriptExtensionsV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::SCRIPT_EXTENSIONS_V1))?
+            .take_payload()?;
+        let allowed_chars: DataPayload<AllowedIdentifierCharsV1Marker> = data_provider
+            .load_payload_with_context(&DataRequest::from(key::ALLOWED_IDENTIFIER_CHARS_V1))?
+            .take_payload()?;
+        Ok(Self {
+            nfd,
+            confusables,
+            script_extensions,
+            allowed_chars,
+        })
+    }
+
+    /// Returns the skeleton of a string, which is formed by replacing the characters of its NFD
+    /// with their prototypes, and normalizing the result to NFD again.
+    ///
+    /// Skeletons are only meant to be compared with each other; they are not suitable for
+    /// display, since the prototypes are chosen arbitrarily among the characters that look
+    /// alike, such as "rn" for "m".
+    pub fn skeleton(&self, text: &str) -> String {
+        let prototypes = &self.confusables.get().prototypes;
+        let mut skeleton = String::with_capacity(text.len());
+        for c in self.nfd.normalize_iter(text.chars()) {
+            match prototypes.get(&c) {
+                Some(prototype) => skeleton.push_str(prototype),
+                None => skeleton.push(c),
+            }
+        }
+        self.nfd.normalize(&skeleton)
+    }
+
+    /// Returns whether two strings are confusable, i.e. whether they have the same skeleton.
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.skeleton(a) == self.skeleton(b)
+    }
+
+    /// Returns whether a string is single-script, i.e. whether there is a script that all of its
+    /// characters are used with.
+    ///
+    /// Characters that are used with all scripts, such as digits and combining marks, and
+    /// combinations of scripts that are used together, such as Han and Hiragana, are taken
+    /// into account. Strings that are not single-script are mixed-script.
+    pub fn is_single_script(&self, text: &str) -> bool {
+        !self.resolved_script_set(text, None).is_empty()
+    }
+
+    /// Returns the most restrictive level that an identifier satisfies.
+    ///
+    /// Identifiers that contain characters whose Identifier_Status is not Allowed, such as
+    /// spaces, symbols, or characters of historic scripts, are
+    /// [`Unrestricted`](RestrictionLevel::Unrestricted).
+    pub fn restriction_level(&self, identifier: &str) -> RestrictionLevel {
+        let allowed_chars = &self.allowed_chars.get().allowed;
+        if !identifier.chars().all(|c| allowed_chars.contains(c)) {
+            return RestrictionLevel::Unrestricted;
+        }
+        if identifier.is_ascii() {
+            return RestrictionLevel::AsciiOnly;
+        }
+        if self.is_single_script(identifier) {
+            return RestrictionLevel::SingleScript;
+        }
+        // The scripts of the characters that are not Latin
+        let scripts = self.resolved_script_set(identifier, Some(LATIN));
+        if scripts.contains(JAPANESE)
+            || scripts.contains(KOREAN)
+            || scripts.contains(HAN_WITH_BOPOMOFO)
+        {
+            RestrictionLevel::HighlyRestrictive
+        } else if !scripts.is_empty()
+            && !scripts.contains(CYRILLIC)
+            && !scripts.contains(GREEK)
+            && !scripts.contains(CHEROKEE)
+        {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+
+    /// Returns the intersection of the augmented script sets of the characters of a string,
+    /// skipping the characters whose script set contains `without`.
+    fn resolved_script_set(&self, text: &str, without: Option<u16>) -> ScriptSet {
+        let mut result = ScriptSet::ALL;
+        for c in text.chars() {
+            let scripts = self.augmented_script_set(c);
+            if let Some(without) = without {
+                if scripts.contains(without) {
+                    continue;
+                }
+            }
+            result.intersect(&scripts);
+        }
+        result
+    }
+
+    /// Returns the Script_Extensions of a character, augmented with the combinations of scripts
+    /// that it is used in; the scripts Common and Inherited are replaced with all scripts.
+    fn augmented_script_set(&self, c: char) -> ScriptSet {
+        let data = self.script_extensions.get();
+        let range = match data.starts.binary_search(&u32::from(c)) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+        let set = usize::from(data.sets.get(range).unwrap_or(0));
+        let start = usize::from(data.set_starts.get(set).unwrap_or(0));
+        let end = data
+            .set_starts
+            .get(set + 1)
+            .map_or(data.scripts.len(), usize::from);
+
+        let mut result = ScriptSet::EMPTY;
+        for script in data.scripts.iter().skip(start).take(end - start) {
+            match script {
+                COMMON | INHERITED => return ScriptSet::ALL,
+                HAN => {
+                    result.insert(HAN_WITH_BOPOMOFO);
+                    result.insert(JAPANESE);
+                    result.insert(KOREAN);
+                }
+                HIRAGANA | KATAKANA => result.insert(JAPANESE),
+                HANGUL => result.insert(KOREAN),
+                BOPOMOFO => result.insert(HAN_WITH_BOPOMOFO),
+                _ => {}
+            }
+            result.insert(script);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_set() {
+        let mut set = ScriptSet::EMPTY;
+        set.insert(LATIN);
+        set.insert(HAN_WITH_BOPOMOFO);
+        assert!(set.contains(LATIN));
+        assert!(set.contains(HAN_WITH_BOPOMOFO));
+        assert!(!set.contains(GREEK));
+
+        let mut other = ScriptSet::EMPTY;
+        other.insert(HAN_WITH_BOPOMOFO);
+        other.insert(GREEK);
+        set.intersect(&other);
+        assert!(!set.contains(LATIN));
+        assert!(set.contains(HAN_WITH_BOPOMOFO));
+
+        set.intersect(&ScriptSet::ALL);
+        assert!(!set.is_empty());
+        other.intersect(&ScriptSet::EMPTY);
+        assert!(other.is_empty());
+    }
+}
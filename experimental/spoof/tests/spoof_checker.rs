@@ -0,0 +1,82 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::fork::ForkByKeyProvider;
+use icu_provider_fs::FsDataProvider;
+use icu_spoof::{RestrictionLevel, SpoofChecker};
+
+fn get_checker() -> SpoofChecker<'static> {
+    let spoof = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist");
+    let normalizer =
+        FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+            .expect("Data directory should exist");
+    SpoofChecker::try_new(&ForkByKeyProvider(spoof, normalizer))
+        .expect("Data should load successfully")
+}
+
+#[test]
+fn test_skeleton() {
+    let checker = get_checker();
+    assert_eq!(checker.skeleton("paypal"), "paypal");
+    // Cyrillic "р" and "а"
+    assert_eq!(checker.skeleton("\u{0440}\u{0430}ypal"), "paypal");
+    assert_eq!(checker.skeleton("Illinois"), "lllinois");
+    assert_eq!(checker.skeleton("m"), "rn");
+    // Characters are decomposed before and after they are mapped
+    assert_eq!(checker.skeleton("\u{00E9}"), "e\u{0301}");
+    assert_eq!(checker.skeleton("\u{0439}"), "\u{0438}\u{0306}");
+}
+
+#[test]
+fn test_are_confusable() {
+    let checker = get_checker();
+    assert!(checker.are_confusable("google", "\u{0261}oogle"));
+    assert!(checker.are_confusable("Cyrillic", "\u{0421}yrillic"));
+    assert!(checker.are_confusable("caf\u{00E9}", "\u{0441}afe\u{0301}"));
+    assert!(!checker.are_confusable("google", "goggle"));
+    assert!(!checker.are_confusable("caf\u{00E9}", "cafe"));
+}
+
+#[test]
+fn test_single_script() {
+    let checker = get_checker();
+    assert!(checker.is_single_script("Москва"));
+    // Digits and combining marks are used with all scripts
+    assert!(checker.is_single_script("Москва2021"));
+    assert!(checker.is_single_script("e\u{0301}"));
+    assert!(checker.is_single_script("한국어"));
+    // Latin "a" in a Cyrillic word
+    assert!(!checker.is_single_script("Москв\u{0061}"));
+}
+
+#[test]
+fn test_restriction_level() {
+    let checker = get_checker();
+    for (identifier, level) in [
+        ("paypal", RestrictionLevel::AsciiOnly),
+        ("caf\u{00E9}", RestrictionLevel::SingleScript),
+        ("Москва", RestrictionLevel::SingleScript),
+        ("東京タワー", RestrictionLevel::SingleScript),
+        ("中文abc", RestrictionLevel::HighlyRestrictive),
+        ("한국abc", RestrictionLevel::HighlyRestrictive),
+        ("नमस्तेabc", RestrictionLevel::ModeratelyRestrictive),
+        (
+            "\u{0440}\u{0430}ypal",
+            RestrictionLevel::MinimallyRestrictive,
+        ),
+        ("αβγabc", RestrictionLevel::MinimallyRestrictive),
+        ("Москва東京", RestrictionLevel::MinimallyRestrictive),
+        ("pay pal", RestrictionLevel::Unrestricted),
+        ("paypal\u{1F4B0}", RestrictionLevel::Unrestricted),
+    ] {
+        assert_eq!(
+            checker.restriction_level(identifier),
+            level,
+            "{}",
+            identifier
+        );
+    }
+    assert!(RestrictionLevel::SingleScript < RestrictionLevel::HighlyRestrictive);
+}
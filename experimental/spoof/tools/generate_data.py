@@ -0,0 +1,160 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the spoof checking data in ../data.
+#
+# Usage: generate_data.py <unicore directory> <confusables.txt>
+#
+# The Script_Extensions and Identifier_Status properties are read from the
+# unicore directory of a Perl installation, e.g. /usr/share/perl/5.36.0/unicore,
+# and the confusables from confusables.txt of UTS #39. The prototypes are
+# normalized to NFD with Python's unicodedata module.
+#
+# The checked-in data is generated from tools/confusables-excerpt.txt, which
+# only contains the confusables needed by the tests.
+#
+# The Unicode version of the unicore directory, confusables.txt, and Python
+# must match the version used by the rest of the ICU4X data.
+
+import json
+import os
+import re
+import sys
+import unicodedata
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..")
+OUT_DIR = os.path.join(ROOT_DIR, "experimental", "spoof", "data")
+SCRIPTS_RS = os.path.join(ROOT_DIR, "components", "properties", "src", "props.rs")
+
+
+def read_table(unicore_dir, name):
+    """Returns the (first, last, value) ranges of a unicore/To table, and the
+    value of the code points that are not listed."""
+    with open(os.path.join(unicore_dir, "To", name + ".pl")) as f:
+        source = f.read()
+    missing = source.split("{'missing'} = '")[1].split("'")[0]
+    table = source.split("return <<'END';\n")[1].split("\nEND")[0]
+    ranges = []
+    for line in table.splitlines():
+        # 60C\t\tArabic Nko Syriac, with an empty end for single code points
+        first, last, value = line.split("\t")
+        ranges.append((int(first, 16), int(last or first, 16), value))
+    return ranges, missing
+
+
+def script_codes():
+    """Returns the UScriptCode values of the scripts, keyed by their names
+    without underscores, from the Script constants of icu_properties."""
+    with open(SCRIPTS_RS) as f:
+        return {
+            name: int(code)
+            for name, code in re.findall(
+                r"pub const (\w+): Script = Script\((\d+)\);", f.read()
+            )
+        }
+
+
+def script_extensions(unicore_dir):
+    """Returns the ScriptExtensionsV1 struct."""
+    codes = script_codes()
+    ranges, missing = read_table(unicore_dir, "Scx")
+    starts = []
+    sets = []
+    script_sets = []
+
+    def add(start, value):
+        script_set = sorted(codes[name.replace("_", "")] for name in value.split())
+        if script_set not in script_sets:
+            script_sets.append(script_set)
+        index = script_sets.index(script_set)
+        if sets and sets[-1] == index:
+            return
+        starts.append(start)
+        sets.append(index)
+
+    next_cp = 0
+    for first, last, value in ranges:
+        if first > next_cp:
+            add(next_cp, missing)
+        add(first, value)
+        next_cp = last + 1
+    if next_cp <= 0x10FFFF:
+        add(next_cp, missing)
+
+    set_starts = []
+    scripts = []
+    for script_set in script_sets:
+        set_starts.append(len(scripts))
+        scripts.extend(script_set)
+    return {
+        "starts": starts,
+        "sets": sets,
+        "set_starts": set_starts,
+        "scripts": scripts,
+    }
+
+
+def allowed_identifier_chars(unicore_dir):
+    """Returns the AllowedIdentifierCharsV1 struct."""
+    ranges, missing = read_table(unicore_dir, "Identifi")
+    assert missing != "Allowed"
+    inversion_list = []
+    for first, last, value in ranges:
+        if value != "Allowed":
+            continue
+        if inversion_list and inversion_list[-1] == first:
+            inversion_list[-1] = last + 1
+        else:
+            inversion_list.extend([first, last + 1])
+    return {"allowed": inversion_list}
+
+
+def confusables(path):
+    """Returns the ConfusablesV1 struct."""
+    prototypes = {}
+    with open(path, encoding="utf-8-sig") as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            # 0441 ;\t0063 ;\tMA
+            source, target, _ = (field.strip() for field in line.split(";"))
+            prototype = "".join(chr(int(cp, 16)) for cp in target.split())
+            prototypes[chr(int(source, 16))] = unicodedata.normalize("NFD", prototype)
+    return {"prototypes": dict(sorted(prototypes.items()))}
+
+
+def write_json(path, data):
+    path = os.path.join(OUT_DIR, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        json.dump(data, f, indent=2)
+        f.write("\n")
+
+
+def main(unicore_dir, confusables_path):
+    with open(os.path.join(unicore_dir, "version")) as f:
+        version = f.read().strip()
+
+    os.makedirs(OUT_DIR, exist_ok=True)
+    with open(os.path.join(OUT_DIR, "manifest.json"), "w") as f:
+        json.dump(
+            {"aliasing": "NoAliases", "syntax": "Json", "schema_version": "0.3"},
+            f,
+            indent=2,
+        )
+        f.write("\n")
+    write_json(os.path.join("spoof", "scx@1.json"), script_extensions(unicore_dir))
+    write_json(
+        os.path.join("spoof", "allowed@1.json"), allowed_identifier_chars(unicore_dir)
+    )
+    write_json(os.path.join("spoof", "confusables@1.json"), confusables(confusables_path))
+
+    print("Generated spoof checking data for Unicode", version)
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
@@ -0,0 +1,275 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::civil::{civil_from_days, SECONDS_PER_DAY};
+use crate::error::Error;
+use crate::posix::PosixTz;
+use crate::provider::{key, ZoneRulesV1, ZoneRulesV1Marker};
+use crate::tzif;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use icu_calendar::types::*;
+use icu_calendar::{DateTime, DateTimeError, Gregorian};
+use icu_datetime::date::{DateInput, IsoTimeInput, TimeZoneInput};
+use icu_provider::prelude::*;
+use tinystr::{tinystr8, TinyStr8};
+
+/// The UTC offset of a time zone at an instant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ZoneOffset {
+    /// The offset from UTC, in seconds east of UTC.
+    pub offset_seconds: i32,
+    /// Whether the offset is that of daylight saving time.
+    pub is_dst: bool,
+}
+
+/// A time zone calculator, which resolves instants to the UTC offsets of an IANA time zone,
+/// such as `America/New_York`.
+///
+/// The offsets come from the history of transitions of the time zone in the TZif format of
+/// [`RFC 8536`], which is either preprocessed into provider data or parsed from a TZif file with
+/// [`TimeZoneCalculator::try_from_tzif`]. Instants after the last transition use the rules of
+/// the POSIX TZ string at the end of the TZif data.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider_fs::FsDataProvider;
+/// use icu_timezone::{TimeZoneCalculator, ZoneOffset};
+///
+/// let provider = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+///     .expect("Data directory should exist");
+/// let calculator = TimeZoneCalculator::try_new("Europe/Berlin", &provider)
+///     .expect("Data should load successfully");
+///
+/// // 2021-07-01T12:00:00Z
+/// assert_eq!(
+///     calculator.offset_at(1625140800),
+///     ZoneOffset {
+///         offset_seconds: 7200,
+///         is_dst: true,
+///     }
+/// );
+/// ```
+///
+/// [`RFC 8536`]: https://datatracker.ietf.org/doc/html/rfc8536
+pub struct TimeZoneCalculator<'data> {
+    time_zone_id: String,
+    rules: DataPayload<'data, ZoneRulesV1Marker>,
+    posix_tz: Option<PosixTz>,
+}
+
+impl<'data> TimeZoneCalculator<'data> {
+    /// Creates a calculator for the IANA time zone ID, loading its rules from the data provider.
+    pub fn try_new<D>(time_zone_id: &str, data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, ZoneRulesV1Marker> + ?Sized,
+    {
+        let request = DataRequest {
+            resource_path: ResourcePath {
+                key: key::ZONE_RULES_V1,
+                options: ResourceOptions {
+                    variant: Some(Cow::Owned(time_zone_id.to_string())),
+                    langid: None,
+                },
+            },
+        };
+        let rules = match data_provider.load_payload_with_context(&request) {
+            Ok(response) => response.take_payload()?,
+            Err(e) if matches!(e.without_context(), DataError::MissingResourceOptions(_)) => {
+                return Err(Error::UnknownTimeZone(time_zone_id.to_string()))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Self::try_from_payload(time_zone_id, rules)
+    }
+
+    /// Creates a calculator for the IANA time zone ID from the contents of its TZif file, such
+    /// as `/usr/share/zoneinfo/America/New_York`.
+    pub fn try_from_tzif(time_zone_id: &str, tzif: &[u8]) -> Result<Self, Error> {
+        Self::try_from_payload(time_zone_id, DataPayload::from_owned(tzif::parse(tzif)?))
+    }
+
+    fn try_from_payload(
+        time_zone_id: &str,
+        rules: DataPayload<'data, ZoneRulesV1Marker>,
+    ) -> Result<Self, Error> {
+        let data: &ZoneRulesV1 = rules.get();
+        if data.transitions.len() != data.transition_types.len()
+            || data.offsets.is_empty()
+            || data.offsets.len() != data.dst.len()
+            || data
+                .transition_types
+                .iter()
+                .any(|index| usize::from(index) >= data.offsets.len())
+        {
+            return Err(Error::InvalidData("inconsistent lengths"));
+        }
+        let posix_tz = if data.posix_tz.is_empty() {
+            None
+        } else {
+            Some(PosixTz::parse(&data.posix_tz)?)
+        };
+        Ok(Self {
+            time_zone_id: time_zone_id.to_string(),
+            rules,
+            posix_tz,
+        })
+    }
+
+    /// Returns the IANA time zone ID.
+    pub fn time_zone_id(&self) -> &str {
+        &self.time_zone_id
+    }
+
+    /// Returns the UTC offset at the instant that is the given number of seconds since
+    /// 1970-01-01T00:00:00Z, ignoring leap seconds.
+    pub fn offset_at(&self, seconds: i64) -> ZoneOffset {
+        let data = self.rules.get();
+        let transition = match data.transitions.binary_search(&seconds) {
+            Ok(index) => Some(index),
+            Err(0) => None,
+            Err(index) => Some(index - 1),
+        };
+        let is_after_last = match transition {
+            Some(index) => index + 1 == data.transitions.len(),
+            None => data.transitions.is_empty(),
+        };
+        if let (true, Some(posix_tz)) = (is_after_last, &self.posix_tz) {
+            let (offset_seconds, is_dst) = posix_tz.offset_at(seconds);
+            return ZoneOffset {
+                offset_seconds,
+                is_dst,
+            };
+        }
+        // Before the first transition, the first local time type is in effect
+        let local_time_type = transition
+            .and_then(|index| data.transition_types.get(index))
+            .map_or(0, usize::from);
+        ZoneOffset {
+            offset_seconds: data.offsets.get(local_time_type).unwrap_or(0),
+            is_dst: data.dst.get(local_time_type) == Some(1),
+        }
+    }
+
+    /// Returns the local date and time and the time zone at the instant that is the given
+    /// number of seconds since 1970-01-01T00:00:00Z, which can be formatted with
+    /// [`ZonedDateTimeFormat`](icu_datetime::ZonedDateTimeFormat).
+    pub fn zoned_datetime_at(&self, seconds: i64) -> Result<ZonedInstant<'_>, Error> {
+        let zone_offset = self.offset_at(seconds);
+        let local = seconds
+            .checked_add(i64::from(zone_offset.offset_seconds))
+            .ok_or(DateTimeError::OutOfRange)?;
+        let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+        let seconds_of_day = local.rem_euclid(SECONDS_PER_DAY);
+        let datetime = DateTime::new_gregorian_datetime_from_integers(
+            i32::try_from(year).map_err(|_| DateTimeError::OutOfRange)?,
+            month,
+            day,
+            (seconds_of_day / 3600) as u8,
+            (seconds_of_day / 60 % 60) as u8,
+            (seconds_of_day % 60) as u8,
+        )?;
+        Ok(ZonedInstant {
+            datetime,
+            zone_offset,
+            gmt_offset: GmtOffset::try_new(zone_offset.offset_seconds)?,
+            time_zone_id: &self.time_zone_id,
+            time_variant: if zone_offset.is_dst {
+                tinystr8!("daylight")
+            } else {
+                tinystr8!("standard")
+            },
+        })
+    }
+}
+
+/// An instant in a time zone, as returned by [`TimeZoneCalculator::zoned_datetime_at`].
+///
+/// It implements [`ZonedDateTimeInput`](icu_datetime::date::ZonedDateTimeInput), with the
+/// local date and time, the UTC offset, the IANA time zone ID, and whether daylight saving time
+/// is in effect.
+#[derive(Debug)]
+pub struct ZonedInstant<'a> {
+    datetime: DateTime<Gregorian>,
+    zone_offset: ZoneOffset,
+    gmt_offset: GmtOffset,
+    time_zone_id: &'a str,
+    time_variant: TinyStr8,
+}
+
+impl ZonedInstant<'_> {
+    /// Returns the local date and time.
+    pub fn datetime(&self) -> &DateTime<Gregorian> {
+        &self.datetime
+    }
+
+    /// Returns the UTC offset.
+    pub fn zone_offset(&self) -> ZoneOffset {
+        self.zone_offset
+    }
+}
+
+impl DateInput for ZonedInstant<'_> {
+    fn year(&self) -> Option<Year> {
+        self.datetime.year()
+    }
+
+    fn month(&self) -> Option<Month> {
+        self.datetime.month()
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        self.datetime.day_of_month()
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        self.datetime.iso_weekday()
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        self.datetime.day_of_year_info()
+    }
+
+    fn julian_day(&self) -> Option<i64> {
+        self.datetime.julian_day()
+    }
+}
+
+impl IsoTimeInput for ZonedInstant<'_> {
+    fn hour(&self) -> Option<IsoHour> {
+        self.datetime.hour()
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        self.datetime.minute()
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        self.datetime.second()
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        self.datetime.fraction()
+    }
+}
+
+impl TimeZoneInput for ZonedInstant<'_> {
+    fn gmt_offset(&self) -> GmtOffset {
+        self.gmt_offset
+    }
+
+    fn time_zone_id(&self) -> Option<&str> {
+        Some(self.time_zone_id)
+    }
+
+    fn metazone_id(&self) -> Option<&str> {
+        None
+    }
+
+    fn time_variant(&self) -> Option<&TinyStr8> {
+        Some(&self.time_variant)
+    }
+}
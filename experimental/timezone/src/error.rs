@@ -0,0 +1,47 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for time zone calculations.
+
+use alloc::string::String;
+use displaydoc::Display;
+use icu_calendar::DateTimeError;
+
+/// A list of possible error outcomes for the [`TimeZoneCalculator`](crate::TimeZoneCalculator)
+/// type.
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// The data provider has no data for the time zone ID.
+    #[displaydoc("unknown time zone: {0}")]
+    UnknownTimeZone(String),
+    /// The TZif data, or the provider data derived from it, is not well-formed.
+    #[displaydoc("invalid time zone data: {0}")]
+    InvalidData(&'static str),
+    /// The POSIX TZ string, which describes the rules for instants after the last transition,
+    /// is not well-formed.
+    #[displaydoc("invalid POSIX TZ string: {0}")]
+    PosixTz(String),
+    /// The local date and time of an instant cannot be represented, for example because the
+    /// instant is too far in the past or the future.
+    #[displaydoc("{0}")]
+    DateTime(DateTimeError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<DateTimeError> for Error {
+    fn from(e: DateTimeError) -> Self {
+        Error::DateTime(e)
+    }
+}
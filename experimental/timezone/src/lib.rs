@@ -0,0 +1,86 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_timezone`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API resolves an instant in an IANA time zone, such as `America/New_York`, to its UTC
+//! offset and whether daylight saving time is in effect, so that instants can be formatted with
+//! [`ZonedDateTimeFormat`] without computing the offset beforehand.
+//!
+//! The offsets of a time zone come from its history of transitions in the Time Zone
+//! Information Format (TZif) of [`RFC 8536`], as compiled from the [`IANA time zone database`].
+//! A [`TimeZoneCalculator`] either loads this history as provider data or parses a TZif file,
+//! such as those in the `zoneinfo` directories of most Unix systems.
+//!
+//! Windows hosts identify time zones by their own IDs, such as `Eastern Standard Time`, which
+//! [`WindowsZones`] maps to and from IANA time zone IDs with the data of CLDR.
+//!
+//! The `data` directory of this crate contains the rules of a few time zones for the
+//! `FsDataProvider`, generated from tzdata 2025b with `tools/generate_data.py`.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_datetime::{options::length, ZonedDateTimeFormat};
+//! use icu_locid_macros::langid;
+//! use icu_provider_fs::FsDataProvider;
+//! use icu_timezone::TimeZoneCalculator;
+//!
+//! let provider = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+//!     .expect("Data directory should exist");
+//! let calculator = TimeZoneCalculator::try_new("America/New_York", &provider)
+//!     .expect("Data should load successfully");
+//!
+//! // 2021-03-14T06:59:59Z and 2021-03-14T07:00:00Z, around the start of daylight saving time
+//! let before = calculator.offset_at(1615705199);
+//! let after = calculator.offset_at(1615705200);
+//! assert_eq!((before.offset_seconds, before.is_dst), (-5 * 3600, false));
+//! assert_eq!((after.offset_seconds, after.is_dst), (-4 * 3600, true));
+//!
+//! let testdata = icu_testdata::get_provider();
+//! let options = length::Bag {
+//!     date: Some(length::Date::Medium),
+//!     time: Some(length::Time::Full),
+//!     ..Default::default()
+//! };
+//! let zdtf = ZonedDateTimeFormat::try_new(
+//!     langid!("en"),
+//!     &testdata,
+//!     &testdata,
+//!     &testdata,
+//!     &options.into(),
+//! )
+//! .expect("Failed to create ZonedDateTimeFormat");
+//!
+//! let instant = calculator
+//!     .zoned_datetime_at(1615705200)
+//!     .expect("The instant should be in range");
+//! // Without metazone data, the time zone is formatted as a GMT offset
+//! assert_eq!(
+//!     zdtf.format_to_string(&instant),
+//!     "Mar 14, 2021, 3:00:00 AM GMT-04:00"
+//! );
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`ZonedDateTimeFormat`]: icu_datetime::ZonedDateTimeFormat
+//! [`RFC 8536`]: https://datatracker.ietf.org/doc/html/rfc8536
+//! [`IANA time zone database`]: https://www.iana.org/time-zones
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod calculator;
+mod civil;
+pub mod error;
+mod posix;
+pub mod provider;
+mod tzif;
+mod windows;
+
+pub use calculator::{TimeZoneCalculator, ZoneOffset, ZonedInstant};
+pub use error::Error as TimeZoneError;
+pub use windows::WindowsZones;
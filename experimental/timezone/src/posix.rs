@@ -0,0 +1,427 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing and evaluation of POSIX TZ strings, such as `EST5EDT,M3.2.0,M11.1.0`, as used in
+//! the footer of TZif data.
+//!
+//! The syntax is that of [`POSIX`] with the extensions of [`RFC 8536`]: the hours of the
+//! transition times range from -167 to 167.
+//!
+//! [`POSIX`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+//! [`RFC 8536`]: https://datatracker.ietf.org/doc/html/rfc8536#section-3.3
+
+use crate::civil::{
+    civil_from_days, days_from_civil, is_leap_year, weekday_from_days, SECONDS_PER_DAY,
+};
+use crate::error::Error;
+use alloc::string::ToString;
+
+/// The day of the year on which daylight saving time starts or ends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: the day of the year from 1 to 365, where February 29 is never counted.
+    Julian(u16),
+    /// `n`: the day of the year from 0 to 365, where February 29 is counted in leap years.
+    ZeroBased(u16),
+    /// `Mm.w.d`: the `d`th day of the week (0 for Sunday) of the `w`th week of month `m`, where
+    /// week 5 is the last week of the month.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+/// A date and a local time at which daylight saving time starts or ends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    date: RuleDate,
+    /// Seconds after midnight of the local time in effect before the transition.
+    time: i32,
+}
+
+/// The UTC offset of daylight saving time and the rules for when it is in effect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct DaylightTime {
+    offset: i32,
+    start: Rule,
+    end: Rule,
+}
+
+/// A parsed POSIX TZ string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PosixTz {
+    /// The UTC offset of standard time, in seconds east of UTC.
+    std_offset: i32,
+    daylight: Option<DaylightTime>,
+}
+
+/// The rules that apply if a TZ string has daylight saving time but no rules, which are those
+/// of the United States since 2007.
+const DEFAULT_RULES: (Rule, Rule) = (
+    Rule {
+        date: RuleDate::MonthWeekDay {
+            month: 3,
+            week: 2,
+            weekday: 0,
+        },
+        time: 7200,
+    },
+    Rule {
+        date: RuleDate::MonthWeekDay {
+            month: 11,
+            week: 1,
+            weekday: 0,
+        },
+        time: 7200,
+    },
+);
+
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> Error {
+        Error::PosixTz(self.source.to_string())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    /// Parses a time zone abbreviation, either alphabetic or quoted in `<>`.
+    fn name(&mut self) -> Result<(), Error> {
+        let start = self.position;
+        if self.eat(b'<') {
+            let is_quoted_name_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'+' || b == b'-';
+            while self.peek().map_or(false, is_quoted_name_byte) {
+                self.position += 1;
+            }
+            let len = self.position - start - 1;
+            self.expect(b'>')?;
+            if len < 3 {
+                return Err(self.error());
+            }
+        } else {
+            while self.peek().map_or(false, |b| b.is_ascii_alphabetic()) {
+                self.position += 1;
+            }
+            if self.position - start < 3 {
+                return Err(self.error());
+            }
+        }
+        Ok(())
+    }
+
+    fn number(&mut self, max: u32) -> Result<u32, Error> {
+        let start = self.position;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().filter(u8::is_ascii_digit) {
+            value = value * 10 + u32::from(digit - b'0');
+            if value > max {
+                return Err(self.error());
+            }
+            self.position += 1;
+        }
+        if self.position == start {
+            return Err(self.error());
+        }
+        Ok(value)
+    }
+
+    /// Parses `[+-]hh[:mm[:ss]]` as seconds, with the hours up to `max_hours`.
+    fn time(&mut self, max_hours: u32) -> Result<i32, Error> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number(max_hours)? * 3600;
+        if self.eat(b':') {
+            seconds += self.number(59)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(59)?;
+            }
+        }
+        Ok(sign * seconds as i32)
+    }
+
+    /// Parses a UTC offset, which POSIX writes as the seconds west of UTC, as seconds east of UTC.
+    fn offset(&mut self) -> Result<i32, Error> {
+        Ok(-self.time(24)?)
+    }
+
+    fn rule(&mut self) -> Result<Rule, Error> {
+        let date = if self.eat(b'J') {
+            match self.number(365)? {
+                0 => return Err(self.error()),
+                day => RuleDate::Julian(day as u16),
+            }
+        } else if self.eat(b'M') {
+            let month = self.number(12)? as u8;
+            self.expect(b'.')?;
+            let week = self.number(5)? as u8;
+            self.expect(b'.')?;
+            let weekday = self.number(6)? as u8;
+            if month == 0 || week == 0 {
+                return Err(self.error());
+            }
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            }
+        } else {
+            RuleDate::ZeroBased(self.number(365)? as u16)
+        };
+        let time = if self.eat(b'/') {
+            self.time(167)?
+        } else {
+            7200
+        };
+        Ok(Rule { date, time })
+    }
+}
+
+impl PosixTz {
+    /// Parses a POSIX TZ string.
+    pub(crate) fn parse(source: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            source,
+            bytes: source.as_bytes(),
+            position: 0,
+        };
+        parser.name()?;
+        let std_offset = parser.offset()?;
+        if parser.is_at_end() {
+            return Ok(Self {
+                std_offset,
+                daylight: None,
+            });
+        }
+        parser.name()?;
+        let dst_offset = if parser.is_at_end() || parser.peek() == Some(b',') {
+            std_offset + 3600
+        } else {
+            parser.offset()?
+        };
+        let (start, end) = if parser.is_at_end() {
+            DEFAULT_RULES
+        } else {
+            parser.expect(b',')?;
+            let start = parser.rule()?;
+            parser.expect(b',')?;
+            let end = parser.rule()?;
+            (start, end)
+        };
+        if !parser.is_at_end() {
+            return Err(parser.error());
+        }
+        Ok(Self {
+            std_offset,
+            daylight: Some(DaylightTime {
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Returns the UTC offset in seconds and whether daylight saving time is in effect at the
+    /// given number of seconds since 1970-01-01T00:00:00Z.
+    pub(crate) fn offset_at(&self, seconds: i64) -> (i32, bool) {
+        let daylight = match self.daylight {
+            Some(daylight) => daylight,
+            None => return (self.std_offset, false),
+        };
+        let local_days = (seconds + i64::from(self.std_offset)).div_euclid(SECONDS_PER_DAY);
+        let (year, _, _) = civil_from_days(local_days);
+        // The start is given in standard time and the end in daylight saving time
+        let start = daylight.start.seconds_in(year) - i64::from(self.std_offset);
+        let end = daylight.end.seconds_in(year) - i64::from(daylight.offset);
+        let is_dst = if start < end {
+            start <= seconds && seconds < end
+        } else {
+            // Daylight saving time spans the turn of the year, as in the southern hemisphere
+            !(end <= seconds && seconds < start)
+        };
+        if is_dst {
+            (daylight.offset, true)
+        } else {
+            (self.std_offset, false)
+        }
+    }
+}
+
+impl Rule {
+    /// Returns the local seconds since 1970-01-01T00:00:00 of the transition in the given year.
+    fn seconds_in(&self, year: i64) -> i64 {
+        let days = match self.date {
+            RuleDate::Julian(day) => {
+                let day = i64::from(day) - 1;
+                let leap_day = if is_leap_year(year) && day >= 59 {
+                    1
+                } else {
+                    0
+                };
+                days_from_civil(year, 1, 1) + day + leap_day
+            }
+            RuleDate::ZeroBased(day) => days_from_civil(year, 1, 1) + i64::from(day),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                let next_month = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                let mut day = first
+                    + i64::from((7 + weekday - weekday_from_days(first)) % 7)
+                    + 7 * i64::from(week - 1);
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        days * SECONDS_PER_DAY + i64::from(self.time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Option<PosixTz> {
+        PosixTz::parse(source).ok()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("JST-9"),
+            Some(PosixTz {
+                std_offset: 9 * 3600,
+                daylight: None,
+            })
+        );
+        assert_eq!(
+            parse("<-03>3"),
+            Some(PosixTz {
+                std_offset: -3 * 3600,
+                daylight: None,
+            })
+        );
+        assert_eq!(
+            parse("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0"),
+            Some(PosixTz {
+                std_offset: 37800,
+                daylight: Some(DaylightTime {
+                    offset: 39600,
+                    start: Rule {
+                        date: RuleDate::MonthWeekDay {
+                            month: 10,
+                            week: 1,
+                            weekday: 0
+                        },
+                        time: 7200,
+                    },
+                    end: Rule {
+                        date: RuleDate::MonthWeekDay {
+                            month: 4,
+                            week: 1,
+                            weekday: 0
+                        },
+                        time: 7200,
+                    },
+                }),
+            })
+        );
+        assert_eq!(
+            parse("EST5EDT,0/0,J365/25"),
+            Some(PosixTz {
+                std_offset: -5 * 3600,
+                daylight: Some(DaylightTime {
+                    offset: -4 * 3600,
+                    start: Rule {
+                        date: RuleDate::ZeroBased(0),
+                        time: 0,
+                    },
+                    end: Rule {
+                        date: RuleDate::Julian(365),
+                        time: 25 * 3600,
+                    },
+                }),
+            })
+        );
+        assert_eq!(
+            parse("EST5EDT").map(|tz| tz.daylight.map(|d| (d.start, d.end))),
+            Some(Some(DEFAULT_RULES))
+        );
+        for invalid in &[
+            "",
+            "EST",
+            "E5",
+            "EST5EDT,M3.2.0",
+            "EST5EDT,M13.2.0,M11.1.0",
+            "<+1>-1",
+            "EST5x",
+        ] {
+            assert!(
+                matches!(PosixTz::parse(invalid), Err(Error::PosixTz(s)) if s == *invalid),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_offset_at() {
+        let new_york = parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        // 2024-03-10T06:59:59Z and 2024-03-10T07:00:00Z
+        assert_eq!(new_york.offset_at(1710053999), (-5 * 3600, false));
+        assert_eq!(new_york.offset_at(1710054000), (-4 * 3600, true));
+        // 2024-11-03T05:59:59Z and 2024-11-03T06:00:00Z
+        assert_eq!(new_york.offset_at(1730613599), (-4 * 3600, true));
+        assert_eq!(new_york.offset_at(1730613600), (-5 * 3600, false));
+
+        let sydney = parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        // 2024-01-01T00:00:00Z
+        assert_eq!(sydney.offset_at(1704067200), (11 * 3600, true));
+        // 2024-04-06T15:59:59Z and 2024-04-06T16:00:00Z
+        assert_eq!(sydney.offset_at(1712419199), (11 * 3600, true));
+        assert_eq!(sydney.offset_at(1712419200), (10 * 3600, false));
+        // 2024-10-05T16:00:00Z
+        assert_eq!(sydney.offset_at(1728143999), (10 * 3600, false));
+        assert_eq!(sydney.offset_at(1728144000), (11 * 3600, true));
+
+        let permanent = parse("EST5EDT,0/0,J365/25").unwrap();
+        assert_eq!(permanent.offset_at(1704067200), (-4 * 3600, true));
+        assert_eq!(permanent.offset_at(1719792000), (-4 * 3600, true));
+    }
+}
@@ -0,0 +1,95 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+use zerovec::ZeroVec;
+
+pub mod key {
+    //! Resource keys for [`icu_timezone`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the UTC offsets of a time zone, requested with the IANA time zone ID,
+    /// such as `America/New_York`, as the variant.
+    pub const ZONE_RULES_V1: ResourceKey = resource_key!(TimeZone, "rules", 1);
+
+    /// Resource key: the mapping between Windows time zone IDs and IANA time zone IDs.
+    pub const WINDOWS_ZONES_V1: ResourceKey = resource_key!(TimeZone, "windows", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_timezone`](crate), grouped by
+/// data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[
+    icu_provider::registered_keys!(ZoneRulesV1Marker, [key::ZONE_RULES_V1]),
+    icu_provider::registered_keys!(WindowsZonesV1Marker, [key::WINDOWS_ZONES_V1]),
+];
+
+/// The history of the UTC offsets of a time zone, as in the TZif format of [`RFC 8536`].
+///
+/// Each transition switches to one of the local time types, which consist of a UTC offset and
+/// whether it is daylight saving time. Before the first transition, the first local time type
+/// is in effect, and after the last transition, the POSIX TZ string describes the offsets.
+///
+/// [`RFC 8536`]: https://datatracker.ietf.org/doc/html/rfc8536
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneRulesV1<'data> {
+    /// The instants of the transitions, in seconds since 1970-01-01T00:00:00Z, in ascending
+    /// order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub transitions: ZeroVec<'data, i64>,
+
+    /// The index of the local time type that each transition switches to.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub transition_types: ZeroVec<'data, u8>,
+
+    /// The UTC offset of each local time type, in seconds east of UTC.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub offsets: ZeroVec<'data, i32>,
+
+    /// Whether each local time type is daylight saving time, as 1, or standard time, as 0.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub dst: ZeroVec<'data, u8>,
+
+    /// The POSIX TZ string for the instants after the last transition, such as
+    /// `EST5EDT,M3.2.0,M11.1.0`, or an empty string if the last local time type stays in
+    /// effect.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub posix_tz: Cow<'data, str>,
+}
+
+/// The mapping between Windows time zone IDs, such as `Eastern Standard Time`, and IANA time
+/// zone IDs, from the `windowsZones` supplemental data of [`CLDR`].
+///
+/// A Windows time zone can correspond to different IANA time zones in different regions; the
+/// region `001` (the world) has the IANA time zone that is used by default.
+///
+/// [`CLDR`]: https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Names
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct WindowsZonesV1<'data> {
+    /// Map from a Windows time zone ID and a region, joined with a slash as in
+    /// `Eastern Standard Time/CA`, to the IANA time zone IDs of the region, separated by
+    /// spaces, with the preferred one first.
+    pub iana_ids: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+
+    /// Map from an IANA time zone ID to its Windows time zone ID.
+    pub windows_ids: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
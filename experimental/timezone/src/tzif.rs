@@ -0,0 +1,165 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of the Time Zone Information Format (TZif) of [`RFC 8536`], as found in the
+//! `zoneinfo` directories of most Unix systems.
+//!
+//! [`RFC 8536`]: https://datatracker.ietf.org/doc/html/rfc8536
+
+use crate::error::Error;
+use crate::provider::ZoneRulesV1;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use zerovec::ZeroVec;
+
+const HEADER_LEN: usize = 44;
+
+/// The counts of a TZif header.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != b"TZif" {
+            return Err(Error::InvalidData("missing header"));
+        }
+        let count = |index: usize| {
+            let start = 20 + 4 * index;
+            u32::from_be_bytes([
+                bytes[start],
+                bytes[start + 1],
+                bytes[start + 2],
+                bytes[start + 3],
+            ]) as usize
+        };
+        let header = Self {
+            version: bytes[4],
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        };
+        if header.typecnt == 0
+            || header.typecnt > 256
+            || header.charcnt == 0
+            || (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+            || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+        {
+            return Err(Error::InvalidData("invalid counts"));
+        }
+        Ok(header)
+    }
+
+    /// Returns the length of the data block, with transition times and leap second occurrences
+    /// of `time_size` bytes.
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Parses TZif data of any version into [`ZoneRulesV1`].
+///
+/// For version 2 and later, the 64-bit data and the POSIX TZ string of the footer are used.
+/// Leap seconds are ignored, as they are by the rest of ICU4X.
+pub(crate) fn parse(bytes: &[u8]) -> Result<ZoneRulesV1<'static>, Error> {
+    let mut header = Header::parse(bytes)?;
+    let mut data = &bytes[HEADER_LEN..];
+    let mut time_size = 4;
+    if header.version != 0 {
+        // Skip the 32-bit data of version 1
+        data = data
+            .get(header.data_len(4)..)
+            .ok_or(Error::InvalidData("truncated data"))?;
+        header = Header::parse(data)?;
+        data = &data[HEADER_LEN..];
+        time_size = 8;
+    }
+    if data.len() < header.data_len(time_size) {
+        return Err(Error::InvalidData("truncated data"));
+    }
+
+    let (times, data) = data.split_at(header.timecnt * time_size);
+    let transitions: Vec<i64> = times
+        .chunks_exact(time_size)
+        .map(|chunk| match chunk.try_into() {
+            Ok(bytes) => i64::from_be_bytes(bytes),
+            // Version 1 only has 32-bit transition times
+            Err(_) => i64::from(i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])),
+        })
+        .collect();
+    if transitions.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(Error::InvalidData("transitions are not in ascending order"));
+    }
+
+    let (transition_types, data) = data.split_at(header.timecnt);
+    if transition_types
+        .iter()
+        .any(|&index| usize::from(index) >= header.typecnt)
+    {
+        return Err(Error::InvalidData("invalid local time type"));
+    }
+
+    let (types, data) = data.split_at(header.typecnt * 6);
+    let mut offsets = Vec::with_capacity(header.typecnt);
+    let mut dst = Vec::with_capacity(header.typecnt);
+    for local_time_type in types.chunks_exact(6) {
+        let offset = i32::from_be_bytes([
+            local_time_type[0],
+            local_time_type[1],
+            local_time_type[2],
+            local_time_type[3],
+        ]);
+        if offset == i32::MIN || local_time_type[4] > 1 {
+            return Err(Error::InvalidData("invalid local time type"));
+        }
+        offsets.push(offset);
+        dst.push(local_time_type[4]);
+    }
+
+    let posix_tz = if header.version == 0 {
+        ""
+    } else {
+        // Skip the time zone designations, leap seconds, and standard/wall and UT/local
+        // indicators, which are not needed
+        let footer = &data[header.charcnt
+            + header.leapcnt * (time_size + 4)
+            + header.isstdcnt
+            + header.isutcnt..];
+        let footer = footer
+            .strip_prefix(b"\n")
+            .and_then(|footer| {
+                footer
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map(|end| &footer[..end])
+            })
+            .ok_or(Error::InvalidData("missing footer"))?;
+        core::str::from_utf8(footer).map_err(|_| Error::InvalidData("invalid footer"))?
+    };
+
+    Ok(ZoneRulesV1 {
+        transitions: ZeroVec::clone_from_slice(&transitions),
+        transition_types: ZeroVec::clone_from_slice(transition_types),
+        offsets: ZeroVec::clone_from_slice(&offsets),
+        dst: ZeroVec::clone_from_slice(&dst),
+        posix_tz: Cow::Owned(posix_tz.to_string()),
+    })
+}
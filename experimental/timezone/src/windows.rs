@@ -0,0 +1,67 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::provider::{key, WindowsZonesV1Marker};
+use alloc::format;
+use icu_locid::subtags::Region;
+use icu_provider::prelude::*;
+
+/// The region whose mappings are used if a region has none of its own.
+const WORLD: &str = "001";
+
+/// A mapping between Windows time zone IDs, such as `Eastern Standard Time`, and IANA time zone
+/// IDs, such as `America/New_York`, as used by Windows hosts and by everything else,
+/// respectively.
+///
+/// The mapping comes from the `windowsZones` supplemental data of CLDR, whose IANA time zone
+/// IDs are the canonical IDs of CLDR. These are sometimes older names than those of the IANA
+/// time zone database, such as `Asia/Calcutta` for `Asia/Kolkata`.
+pub struct WindowsZones<'data> {
+    data: DataPayload<'data, WindowsZonesV1Marker>,
+}
+
+impl<'data> WindowsZones<'data> {
+    /// Creates a mapping, loading its data from the data provider.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, WindowsZonesV1Marker> + ?Sized,
+    {
+        let data = data_provider
+            .load_payload(&DataRequest::from(key::WINDOWS_ZONES_V1))?
+            .take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns the preferred IANA time zone ID for the Windows time zone ID in the region, or
+    /// the default one if the region is `None` or has no IANA time zone of its own.
+    pub fn iana_id(&self, windows_id: &str, region: Option<Region>) -> Option<&str> {
+        self.iana_ids(windows_id, region).next()
+    }
+
+    /// Returns all IANA time zone IDs that the Windows time zone ID corresponds to in the
+    /// region, with the preferred one first, or the default one if the region is `None` or has
+    /// no IANA time zone of its own.
+    pub fn iana_ids(
+        &self,
+        windows_id: &str,
+        region: Option<Region>,
+    ) -> impl Iterator<Item = &str> + '_ {
+        let iana_ids = &self.data.get().iana_ids;
+        region
+            .and_then(|region| iana_ids.get(format!("{}/{}", windows_id, region).as_str()))
+            .or_else(|| iana_ids.get(format!("{}/{}", windows_id, WORLD).as_str()))
+            .map_or("", |iana_ids| &**iana_ids)
+            .split_ascii_whitespace()
+    }
+
+    /// Returns the Windows time zone ID for the IANA time zone ID.
+    pub fn windows_id(&self, iana_id: &str) -> Option<&str> {
+        self.data
+            .get()
+            .windows_ids
+            .get(iana_id)
+            .map(|windows_id| &**windows_id)
+    }
+}
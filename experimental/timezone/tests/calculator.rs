@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_datetime::date::TimeZoneInput;
+use icu_provider_fs::FsDataProvider;
+use icu_timezone::{TimeZoneCalculator, TimeZoneError, ZoneOffset};
+
+fn get_calculator(time_zone_id: &str) -> TimeZoneCalculator<'static> {
+    let provider = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist");
+    TimeZoneCalculator::try_new(time_zone_id, &provider).expect("Data should load successfully")
+}
+
+fn offset(hours: i32, minutes: i32, is_dst: bool) -> ZoneOffset {
+    ZoneOffset {
+        offset_seconds: hours * 3600 + minutes * 60,
+        is_dst,
+    }
+}
+
+#[test]
+fn test_transitions() {
+    let new_york = get_calculator("America/New_York");
+    // 1883-11-18T16:59:59Z, before the first transition to standard time: local mean time
+    assert_eq!(
+        new_york.offset_at(-2717650801),
+        ZoneOffset {
+            offset_seconds: -17762,
+            is_dst: false,
+        }
+    );
+    assert_eq!(new_york.offset_at(-2717650800), offset(-5, 0, false));
+    // 1945-08-14T23:00:00Z, "Peace Time"
+    assert_eq!(new_york.offset_at(-769395600), offset(-4, 0, true));
+    // 2000-04-02T06:59:59Z and 2000-04-02T07:00:00Z
+    assert_eq!(new_york.offset_at(954658799), offset(-5, 0, false));
+    assert_eq!(new_york.offset_at(954658800), offset(-4, 0, true));
+
+    let sao_paulo = get_calculator("America/Sao_Paulo");
+    // 2018-12-01T12:00:00Z, before daylight saving time was abolished
+    assert_eq!(sao_paulo.offset_at(1543665600), offset(-2, 0, true));
+    assert_eq!(sao_paulo.offset_at(1638360000), offset(-3, 0, false));
+
+    let kolkata = get_calculator("Asia/Kolkata");
+    assert_eq!(kolkata.offset_at(0), offset(5, 30, false));
+}
+
+#[test]
+fn test_posix_tz_after_last_transition() {
+    let new_york = get_calculator("America/New_York");
+    // 2100-03-14T06:59:59Z and 2100-03-14T07:00:00Z
+    assert_eq!(new_york.offset_at(4108690799), offset(-5, 0, false));
+    assert_eq!(new_york.offset_at(4108690800), offset(-4, 0, true));
+
+    let london = get_calculator("Europe/London");
+    // 2077-07-01T00:00:00Z and 2077-12-01T00:00:00Z
+    assert_eq!(london.offset_at(3392323200), offset(1, 0, true));
+    assert_eq!(london.offset_at(3405542400), offset(0, 0, false));
+
+    let lord_howe = get_calculator("Australia/Lord_Howe");
+    // 2077-01-01T00:00:00Z and 2077-07-01T00:00:00Z
+    assert_eq!(lord_howe.offset_at(3376684800), offset(11, 0, true));
+    assert_eq!(lord_howe.offset_at(3392323200), offset(10, 30, false));
+}
+
+#[test]
+fn test_zoned_datetime_at() {
+    let sydney = get_calculator("Australia/Sydney");
+    // 2021-12-31T13:00:00Z
+    let instant = sydney
+        .zoned_datetime_at(1640955600)
+        .expect("The instant should be in range");
+    assert_eq!(instant.zone_offset(), offset(11, 0, true));
+    assert_eq!(instant.gmt_offset().raw_offset_seconds(), 11 * 3600);
+    assert_eq!(instant.time_zone_id(), Some("Australia/Sydney"));
+    assert_eq!(
+        instant.time_variant().map(|variant| variant.as_str()),
+        Some("daylight")
+    );
+    let datetime = instant.datetime();
+    assert_eq!(
+        (
+            datetime.date.year().number,
+            u8::from(datetime.time.hour),
+            u8::from(datetime.time.minute)
+        ),
+        (2022, 0, 0)
+    );
+}
+
+#[test]
+fn test_tzif() {
+    // Version 2 TZif data for a zone with a single local time type and a POSIX TZ string
+    let mut tzif = Vec::new();
+    for _ in 0..2 {
+        tzif.extend_from_slice(b"TZif2");
+        tzif.extend_from_slice(&[0; 15]);
+        for count in &[0u32, 0, 0, 0, 1, 4] {
+            tzif.extend_from_slice(&count.to_be_bytes());
+        }
+        tzif.extend_from_slice(&(-10800i32).to_be_bytes());
+        tzif.extend_from_slice(&[0, 0]);
+        tzif.extend_from_slice(b"-03\0");
+    }
+    tzif.extend_from_slice(b"\n<-03>3\n");
+
+    let calculator =
+        TimeZoneCalculator::try_from_tzif("America/Sao_Paulo", &tzif).expect("Valid TZif data");
+    assert_eq!(calculator.offset_at(0), offset(-3, 0, false));
+
+    assert!(matches!(
+        TimeZoneCalculator::try_from_tzif("America/Sao_Paulo", &tzif[..60]),
+        Err(TimeZoneError::InvalidData(_))
+    ));
+    let invalid_footer = [&tzif[..tzif.len() - 4], b"<\n"].concat();
+    assert!(matches!(
+        TimeZoneCalculator::try_from_tzif("America/Sao_Paulo", &invalid_footer),
+        Err(TimeZoneError::PosixTz(_))
+    ));
+}
+
+#[test]
+fn test_unknown_time_zone() {
+    let provider = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist");
+    assert!(matches!(
+        TimeZoneCalculator::try_new("Mars/Olympus_Mons", &provider),
+        Err(TimeZoneError::UnknownTimeZone(id)) if id == "Mars/Olympus_Mons"
+    ));
+}
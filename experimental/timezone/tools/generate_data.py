@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the time zone data in ../data.
+#
+# Usage: generate_data.py <zoneinfo directory>
+#
+# The TZif files of the time zones are read from a zoneinfo directory compiled
+# from the IANA time zone database, e.g. /usr/share/zoneinfo, which must
+# contain version 2 or later TZif files and a tzdata.zi file with the version.
+
+import json
+import os
+import struct
+import sys
+
+OUT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "data")
+
+TIME_ZONES = [
+    "America/Los_Angeles",
+    "America/New_York",
+    "America/Sao_Paulo",
+    "Asia/Kolkata",
+    "Asia/Tokyo",
+    "Australia/Lord_Howe",
+    "Australia/Sydney",
+    "Europe/Berlin",
+    "Europe/London",
+]
+
+
+def zone_rules(path):
+    """Returns the ZoneRulesV1 struct of a TZif file."""
+    with open(path, "rb") as f:
+        data = f.read()
+
+    def header(offset):
+        magic, version, *counts = struct.unpack_from(">4sc15x6L", data, offset)
+        assert magic == b"TZif" and version >= b"2", path
+        return counts
+
+    # Skip the header and the 32-bit data of version 1
+    isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt = header(0)
+    offset = 44 + timecnt * 5 + typecnt * 6 + charcnt + leapcnt * 8 + isstdcnt + isutcnt
+    isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt = header(offset)
+    offset += 44
+
+    transitions = list(struct.unpack_from(">%dq" % timecnt, data, offset))
+    offset += timecnt * 8
+    transition_types = list(data[offset : offset + timecnt])
+    offset += timecnt
+    offsets = []
+    dst = []
+    for _ in range(typecnt):
+        utoff, isdst, _ = struct.unpack_from(">lBB", data, offset)
+        offsets.append(utoff)
+        dst.append(isdst)
+        offset += 6
+    offset += charcnt + leapcnt * 12 + isstdcnt + isutcnt
+    posix_tz = data[offset:].split(b"\n")[1].decode("ascii")
+
+    return {
+        "transitions": transitions,
+        "transition_types": transition_types,
+        "offsets": offsets,
+        "dst": dst,
+        "posix_tz": posix_tz,
+    }
+
+
+def write_json(path, data):
+    path = os.path.join(OUT_DIR, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w") as f:
+        json.dump(data, f, indent=2)
+        f.write("\n")
+
+
+def main(zoneinfo_dir):
+    with open(os.path.join(zoneinfo_dir, "tzdata.zi")) as f:
+        # # version 2025b
+        version = f.readline().split()[-1]
+
+    os.makedirs(OUT_DIR, exist_ok=True)
+    with open(os.path.join(OUT_DIR, "manifest.json"), "w") as f:
+        json.dump(
+            {"aliasing": "NoAliases", "syntax": "Json", "schema_version": "0.3"},
+            f,
+            indent=2,
+        )
+        f.write("\n")
+    for time_zone in TIME_ZONES:
+        write_json(
+            os.path.join("time_zone", "rules@1", time_zone + ".json"),
+            zone_rules(os.path.join(zoneinfo_dir, time_zone)),
+        )
+
+    print("Generated time zone data for tzdata", version)
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
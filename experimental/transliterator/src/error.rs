@@ -0,0 +1,51 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for transliteration.
+
+use alloc::string::String;
+use displaydoc::Display;
+
+/// A list of possible error outcomes for the [`Transliterator`](crate::Transliterator) type.
+#[derive(Display, Debug)]
+pub enum Error {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    /// An error loading a normalizer for a `::NFD` or `::NFC` rule.
+    #[displaydoc("error loading normalizer: {0}")]
+    Normalizer(icu_normalizer::NormalizerError),
+    /// Neither the transform nor its inverse is available from the data provider.
+    #[displaydoc("unknown transform: {0}")]
+    UnknownTransform(String),
+    /// The rules of a transform are not well-formed, or use syntax that is not supported.
+    #[displaydoc("invalid rules for {id} at byte {offset}: {reason}")]
+    Syntax {
+        /// The ID of the transform whose rules are invalid.
+        id: String,
+        /// The byte index in the rules at which the error was found.
+        offset: usize,
+        /// A description of the error.
+        reason: &'static str,
+    },
+    /// The transforms call each other too deeply, which usually means that they call each
+    /// other in a cycle.
+    #[displaydoc("transforms are nested too deeply: {0}")]
+    NestingLimit(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_normalizer::NormalizerError> for Error {
+    fn from(e: icu_normalizer::NormalizerError) -> Self {
+        Error::Normalizer(e)
+    }
+}
@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_transliterator`](crate) is one of the [`ICU4X`] components.
+//!
+//! This API converts text from one script or form to another, such as from Cyrillic to Latin or
+//! from Katakana to Hiragana, by running the rules of a transform written in the syntax of the
+//! [`CLDR transform rules`].
+//!
+//! A [`Transliterator`] is created from the ID of a transform, in the form `Source-Target`, and
+//! loads its rules from the data provider. Transforms whose rules are written as reversible
+//! can also be run backwards, as `Target-Source`, and transforms can call other transforms,
+//! including Unicode normalization through [`icu_normalizer`].
+//!
+//! The `data` directory of this crate contains the rules of a few transforms for the
+//! `FsDataProvider`: `Hiragana-Katakana`, `Cyrillic-Latin` (for Russian, following ISO 9), and
+//! `Cyrillic-ASCII`, together with their inverses.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::fork::ForkByKeyProvider;
+//! use icu_provider_fs::FsDataProvider;
+//! use icu_transliterator::Transliterator;
+//!
+//! let rules = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+//!     .expect("Data directory should exist");
+//! // Cyrillic-Latin normalizes its input and output
+//! let normalizer = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+//!     .expect("Data directory should exist");
+//! let provider = ForkByKeyProvider(rules, normalizer);
+//!
+//! let transliterator =
+//!     Transliterator::try_new("Cyrillic-Latin", &provider).expect("Data should load successfully");
+//! assert_eq!(transliterator.transliterate("Жёлтый чай"), "Žëltyj čaj");
+//!
+//! let transliterator =
+//!     Transliterator::try_new("Latin-Cyrillic", &provider).expect("Data should load successfully");
+//! assert_eq!(transliterator.transliterate("Žëltyj čaj"), "Жёлтый чай");
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`CLDR transform rules`]: https://unicode.org/reports/tr35/tr35-general.html#Transforms
+
+#![warn(missing_docs)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+pub mod error;
+mod parser;
+pub mod provider;
+mod transliterator;
+
+pub use error::Error as TransliteratorError;
+pub use transliterator::Transliterator;
@@ -0,0 +1,888 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for transform rules in the syntax of [UTS #35, Transforms].
+//!
+//! [UTS #35, Transforms]: https://unicode.org/reports/tr35/tr35-general.html#Transforms
+
+use crate::error::Error;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::char;
+use core::cmp::Ordering;
+
+/// The direction in which the rules of a transform are run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Direction {
+    /// The rules are run as written, for the transform `Source-Target`.
+    Forward,
+    /// The rules are run backwards, for the transform `Target-Source`.
+    Reverse,
+}
+
+/// A set of characters, as sorted and disjoint ranges of code points.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct CharSet {
+    /// Inclusive ranges, which neither overlap nor touch.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    fn from_ranges(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub(crate) fn contains(&self, c: char) -> bool {
+        let c = u32::from(c);
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < c {
+                    Ordering::Less
+                } else if start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied().collect())
+    }
+
+    fn complement(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+        for &(start, end) in &self.ranges {
+            if start > next {
+                ranges.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= char::MAX as u32 {
+            ranges.push((next, char::MAX as u32));
+        }
+        Self { ranges }
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self.complement().union(&other.complement()).complement()
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+}
+
+/// An element of a pattern, which matches one character.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Element {
+    Char(char),
+    Set(CharSet),
+}
+
+impl Element {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Char(expected) => *expected == c,
+            Self::Set(set) => set.contains(c),
+        }
+    }
+}
+
+fn matches_all(elements: &[Element], text: &[char]) -> bool {
+    elements.len() == text.len()
+        && elements
+            .iter()
+            .zip(text)
+            .all(|(element, &c)| element.matches(c))
+}
+
+/// A conversion rule, such as `a { b } c > d`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Rule {
+    /// The context before the key, which is matched against the text that the rules have
+    /// already been applied to.
+    pub before: Vec<Element>,
+    /// The text that is replaced.
+    pub key: Vec<Element>,
+    /// The context after the key.
+    pub after: Vec<Element>,
+    pub replacement: Vec<char>,
+    /// The number of characters of the replacement after which the rules continue; this is
+    /// the position of `|` in the replacement, or its end.
+    pub cursor: usize,
+}
+
+impl Rule {
+    /// Returns whether the rule matches the text at a position.
+    pub(crate) fn matches(&self, text: &[char], position: usize) -> bool {
+        let key_end = position + self.key.len();
+        let after_end = key_end + self.after.len();
+        position >= self.before.len()
+            && after_end <= text.len()
+            && matches_all(&self.key, &text[position..key_end])
+            && matches_all(&self.before, &text[position - self.before.len()..position])
+            && matches_all(&self.after, &text[key_end..after_end])
+    }
+}
+
+/// A step of a transform.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Phase {
+    /// Conversion rules, which are applied together in a single pass over the text.
+    Rules(Vec<Rule>),
+    /// A call of another transform by its ID, such as `NFD` or `Latin-ASCII`.
+    Transform(String),
+}
+
+/// The rules of a transform in one direction.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ParsedRules {
+    /// The global filter; if present, only the characters in the filter are transformed.
+    pub filter: Option<CharSet>,
+    pub phases: Vec<Phase>,
+}
+
+/// Returns the ID of the inverse of a transform, such as `Latin-Cyrillic` for
+/// `Cyrillic-Latin` and `NFC` for `NFD`.
+pub(crate) fn inverse_id(id: &str) -> String {
+    match id {
+        "NFD" => "NFC".into(),
+        "NFC" => "NFD".into(),
+        "Lower" => "Upper".into(),
+        "Upper" => "Lower".into(),
+        _ => match id.split_once('-') {
+            Some((source, target)) => [target, "-", source].concat(),
+            None => id.into(),
+        },
+    }
+}
+
+/// Parses the rules of the transform `id`, for running them in the given direction.
+pub(crate) fn parse(id: &str, rules: &str, direction: Direction) -> Result<ParsedRules, Error> {
+    let mut parser = Parser {
+        id,
+        rules,
+        offset: 0,
+        variables: BTreeMap::new(),
+    };
+    parser.parse_rules(direction)
+}
+
+/// One side of a conversion rule, with its contexts.
+struct Side {
+    before: Vec<Element>,
+    key: Vec<Element>,
+    after: Vec<Element>,
+    cursor: Option<usize>,
+}
+
+struct Parser<'a> {
+    id: &'a str,
+    rules: &'a str,
+    offset: usize,
+    variables: BTreeMap<&'a str, Vec<Element>>,
+}
+
+impl<'a> Parser<'a> {
+    fn error_at(&self, offset: usize, reason: &'static str) -> Error {
+        Error::Syntax {
+            id: self.id.into(),
+            offset,
+            reason,
+        }
+    }
+
+    fn error(&self, reason: &'static str) -> Error {
+        self.error_at(self.offset, reason)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rules[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char, reason: &'static str) -> Result<(), Error> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    /// Skips whitespace and comments, which start with `#` and end at the end of the line.
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('#') {
+                break;
+            }
+            while !matches!(self.peek(), None | Some('\n') | Some('\r')) {
+                self.bump();
+            }
+        }
+    }
+
+    /// Parses a sequence of characters that are accepted by `predicate`, which may be empty.
+    fn parse_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.offset;
+        while matches!(self.peek(), Some(c) if predicate(c)) {
+            self.bump();
+        }
+        &self.rules[start..self.offset]
+    }
+
+    fn parse_rules(&mut self, direction: Direction) -> Result<ParsedRules, Error> {
+        let mut filter = None;
+        let mut reverse_filter = None;
+        let mut phases = Vec::new();
+        let mut rules = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            let start = self.offset;
+            if self.peek().is_none() {
+                break;
+            }
+            if self.rules[self.offset..].starts_with("::") {
+                self.offset += 2;
+                self.skip_whitespace();
+                if self.peek() == Some('[') {
+                    if filter.is_some() || !phases.is_empty() || !rules.is_empty() {
+                        return Err(self.error_at(start, "a global filter must come first"));
+                    }
+                    filter = Some(self.parse_set()?);
+                } else if self.rules[self.offset..].starts_with("([") {
+                    self.bump();
+                    if reverse_filter.is_some() {
+                        return Err(self.error_at(start, "duplicate global filter"));
+                    }
+                    reverse_filter = Some(self.parse_set()?);
+                    self.skip_whitespace();
+                    self.expect(')', "expected ')'")?;
+                } else {
+                    let id = self.parse_transform_call(direction)?;
+                    if !id.is_empty() {
+                        if !rules.is_empty() {
+                            phases.push(Phase::Rules(core::mem::take(&mut rules)));
+                        }
+                        phases.push(Phase::Transform(id));
+                    }
+                }
+                self.skip_whitespace();
+                self.expect(';', "expected ';'")?;
+                continue;
+            }
+            if self.peek() == Some('$') {
+                self.bump();
+                let name = self.parse_while(is_name_char);
+                self.skip_whitespace();
+                if !name.is_empty() && self.peek() == Some('=') {
+                    self.bump();
+                    let value = self.parse_elements()?;
+                    self.expect(';', "expected ';'")?;
+                    self.variables.insert(name, value);
+                    continue;
+                }
+                // Not a definition, but a rule that starts with a variable
+                self.offset = start;
+            }
+            let left = self.parse_side()?;
+            let (forward, reverse) = self.parse_operator()?;
+            let right = self.parse_side()?;
+            self.expect(';', "expected ';'")?;
+            let rule = match direction {
+                Direction::Forward if forward => self.make_rule(start, left, right)?,
+                Direction::Reverse if reverse => self.make_rule(start, right, left)?,
+                _ => continue,
+            };
+            rules.push(rule);
+        }
+        if !rules.is_empty() {
+            phases.push(Phase::Rules(rules));
+        }
+        if direction == Direction::Reverse {
+            phases.reverse();
+            filter = reverse_filter;
+        }
+        Ok(ParsedRules { filter, phases })
+    }
+
+    /// Parses a transform call after its `::`, such as `NFD (NFC)`, and returns the ID of the
+    /// transform to run in the given direction, which is empty if there is none.
+    fn parse_transform_call(&mut self, direction: Direction) -> Result<String, Error> {
+        let start = self.offset;
+        let forward = self.parse_while(is_id_char);
+        self.skip_whitespace();
+        let reverse = if self.peek() == Some('(') {
+            self.bump();
+            self.skip_whitespace();
+            let reverse = self.parse_while(is_id_char);
+            self.skip_whitespace();
+            self.expect(')', "expected ')'")?;
+            Some(reverse)
+        } else {
+            None
+        };
+        if forward.is_empty() && reverse.is_none() {
+            return Err(self.error_at(start, "expected a transform ID"));
+        }
+        Ok(match direction {
+            Direction::Forward => forward.into(),
+            Direction::Reverse => match reverse {
+                Some(reverse) => reverse.into(),
+                None => inverse_id(forward),
+            },
+        })
+    }
+
+    /// Parses the operator of a conversion rule, returning whether the rule applies in the
+    /// forward and reverse directions.
+    fn parse_operator(&mut self) -> Result<(bool, bool), Error> {
+        if self.rules[self.offset..].starts_with("<>") {
+            self.offset += 2;
+            return Ok((true, true));
+        }
+        match self.peek() {
+            Some('↔') => {
+                self.bump();
+                Ok((true, true))
+            }
+            Some('>') | Some('→') => {
+                self.bump();
+                Ok((true, false))
+            }
+            Some('<') | Some('←') => {
+                self.bump();
+                Ok((false, true))
+            }
+            _ => Err(self.error("expected '>', '<', or '<>'")),
+        }
+    }
+
+    /// Builds a rule that matches the `pattern` side and outputs the text of the `output`
+    /// side.
+    fn make_rule(&self, start: usize, pattern: Side, output: Side) -> Result<Rule, Error> {
+        if pattern.key.is_empty() {
+            return Err(self.error_at(start, "a rule must match at least one character"));
+        }
+        let replacement = output
+            .key
+            .iter()
+            .map(|element| match element {
+                Element::Char(c) => Ok(*c),
+                Element::Set(_) => {
+                    Err(self.error_at(start, "sets are not allowed in replacements"))
+                }
+            })
+            .collect::<Result<Vec<char>, Error>>()?;
+        let cursor = output.cursor.unwrap_or(replacement.len());
+        if cursor == 0 && !replacement.is_empty() {
+            return Err(self.error_at(start, "the cursor must not precede the replacement"));
+        }
+        Ok(Rule {
+            before: pattern.before,
+            key: pattern.key,
+            after: pattern.after,
+            replacement,
+            cursor,
+        })
+    }
+
+    /// Parses one side of a conversion rule, up to the operator or the `;`.
+    fn parse_side(&mut self) -> Result<Side, Error> {
+        let mut side = Side {
+            before: Vec::new(),
+            key: Vec::new(),
+            after: Vec::new(),
+            cursor: None,
+        };
+        let mut current = Vec::new();
+        // Whether `{` and `}` have been seen
+        let mut has_before = false;
+        let mut has_after = false;
+        loop {
+            self.skip_whitespace_and_comments();
+            match self.peek() {
+                Some('{') => {
+                    if has_before || has_after || side.cursor.is_some() {
+                        return Err(self.error("unexpected '{'"));
+                    }
+                    self.bump();
+                    side.before = core::mem::take(&mut current);
+                    has_before = true;
+                }
+                Some('}') => {
+                    if has_after {
+                        return Err(self.error("unexpected '}'"));
+                    }
+                    self.bump();
+                    side.key = core::mem::take(&mut current);
+                    has_after = true;
+                }
+                Some('|') => {
+                    if has_after || side.cursor.is_some() {
+                        return Err(self.error("unexpected '|'"));
+                    }
+                    self.bump();
+                    side.cursor = Some(current.len());
+                }
+                Some(c) if is_rule_end(c) => break,
+                None => break,
+                Some(_) => self.parse_element(&mut current)?,
+            }
+        }
+        if has_after {
+            side.after = current;
+        } else {
+            side.key = current;
+        }
+        Ok(side)
+    }
+
+    /// Parses the value of a variable, up to the `;`.
+    fn parse_elements(&mut self) -> Result<Vec<Element>, Error> {
+        let mut elements = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            match self.peek() {
+                None | Some(';') => return Ok(elements),
+                Some(_) => self.parse_element(&mut elements)?,
+            }
+        }
+    }
+
+    /// Parses a character, a quoted or escaped string, a set, or a variable, and appends its
+    /// elements.
+    fn parse_element(&mut self, elements: &mut Vec<Element>) -> Result<(), Error> {
+        match self.peek() {
+            Some('[') => elements.push(Element::Set(self.parse_set()?)),
+            Some('$') => {
+                let start = self.offset;
+                self.bump();
+                let name = self.parse_while(is_name_char);
+                if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Err(self.error_at(start, "anchors and segments are not supported"));
+                }
+                match self.variables.get(name) {
+                    Some(value) => elements.extend(value.iter().cloned()),
+                    None => return Err(self.error_at(start, "undefined variable")),
+                }
+            }
+            Some('\'') => {
+                self.bump();
+                elements.extend(self.parse_quoted()?.chars().map(Element::Char));
+            }
+            Some('\\') => elements.push(Element::Char(self.parse_escape()?)),
+            Some(c) if c.is_ascii() && !c.is_ascii_alphanumeric() => {
+                return Err(self.error("unsupported or unquoted syntax character"))
+            }
+            Some(c) => {
+                self.bump();
+                elements.push(Element::Char(c));
+            }
+            None => return Err(self.error("unexpected end of rules")),
+        }
+        Ok(())
+    }
+
+    /// Parses quoted text after its opening apostrophe, up to and including the closing one.
+    /// `''` is a literal apostrophe, both inside and outside of quoted text.
+    fn parse_quoted(&mut self) -> Result<String, Error> {
+        let mut text = String::new();
+        if self.peek() == Some('\'') {
+            self.bump();
+            text.push('\'');
+            return Ok(text);
+        }
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated quoted text")),
+                Some('\'') if self.peek() == Some('\'') => {
+                    self.bump();
+                    text.push('\'');
+                }
+                Some('\'') => return Ok(text),
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    /// Parses an escape sequence such as `\u0301`, `\U0001F600`, `\x{301}`, or `\-`.
+    fn parse_escape(&mut self) -> Result<char, Error> {
+        let start = self.offset;
+        self.bump();
+        let invalid = |parser: &Self| parser.error_at(start, "invalid escape sequence");
+        let hex = match self.bump() {
+            Some('u') => self.parse_hex_digits(4),
+            Some('U') => self.parse_hex_digits(8),
+            Some('x') if self.peek() == Some('{') => {
+                self.bump();
+                let digits = self.parse_while(|c| c.is_ascii_hexdigit());
+                self.expect('}', "invalid escape sequence")?;
+                digits
+            }
+            Some('x') => self.parse_hex_digits(2),
+            Some(c) => return Ok(c),
+            None => return Err(invalid(self)),
+        };
+        u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| invalid(self))
+    }
+
+    /// Parses exactly `count` hexadecimal digits, or returns the digits that were found.
+    fn parse_hex_digits(&mut self, count: usize) -> &'a str {
+        let start = self.offset;
+        while self.offset - start < count && matches!(self.peek(), Some(c) if c.is_ascii_hexdigit())
+        {
+            self.bump();
+        }
+        let digits = &self.rules[start..self.offset];
+        if digits.len() == count {
+            digits
+        } else {
+            ""
+        }
+    }
+
+    /// Parses a set such as `[a-z]`, `[^aeiou]`, `[[a-z]-[aeiou]]`, or `[$vowel]`.
+    fn parse_set(&mut self) -> Result<CharSet, Error> {
+        let start = self.offset;
+        self.expect('[', "expected '['")?;
+        if self.peek() == Some(':') {
+            return Err(self.error_at(start, "property sets are not supported"));
+        }
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.bump();
+        }
+        let mut result = CharSet::default();
+        let mut operator = None;
+        // Whether the last item was a set, which may be followed by an operator.
+        let mut after_set = false;
+        loop {
+            self.skip_whitespace();
+            let operand = match self.peek() {
+                None => return Err(self.error_at(start, "unterminated set")),
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                Some('[') => self.parse_set()?,
+                Some('$') => {
+                    let mut elements = Vec::new();
+                    self.parse_element(&mut elements)?;
+                    let mut set = CharSet::default();
+                    for element in elements {
+                        set = set.union(&match element {
+                            Element::Set(other) => other,
+                            Element::Char(c) => CharSet::from_ranges(vec![(c as u32, c as u32)]),
+                        });
+                    }
+                    set
+                }
+                Some(c @ '-') | Some(c @ '&') if after_set && operator.is_none() => {
+                    self.bump();
+                    operator = Some(c);
+                    continue;
+                }
+                Some('\\') if self.rules[self.offset..].starts_with("\\p") => {
+                    return Err(self.error("property sets are not supported"))
+                }
+                Some(_) => {
+                    let chars = self.parse_set_chars()?;
+                    after_set = false;
+                    result = result.union(&CharSet::from_ranges(chars));
+                    continue;
+                }
+            };
+            result = match operator.take() {
+                Some('-') => result.difference(&operand),
+                Some(_) => result.intersection(&operand),
+                None => result.union(&operand),
+            };
+            after_set = true;
+        }
+        if operator.is_some() {
+            return Err(self.error_at(start, "expected a set after the operator"));
+        }
+        Ok(if negated { result.complement() } else { result })
+    }
+
+    /// Parses a character, a range of characters such as `a-z`, or quoted text within a set.
+    fn parse_set_chars(&mut self) -> Result<Vec<(u32, u32)>, Error> {
+        let first = match self.parse_set_char()? {
+            SetChar::Char(c) => c,
+            SetChar::Quoted(text) => {
+                return Ok(text.chars().map(|c| (c as u32, c as u32)).collect())
+            }
+        };
+        let before_hyphen = self.offset;
+        self.skip_whitespace();
+        if self.peek() == Some('-') {
+            self.bump();
+            self.skip_whitespace();
+            if self.peek() != Some(']') {
+                let range_start = self.offset;
+                let last = match self.parse_set_char()? {
+                    SetChar::Char(c) if c >= first => c,
+                    _ => return Err(self.error_at(range_start, "invalid range")),
+                };
+                return Ok(vec![(first as u32, last as u32)]);
+            }
+        }
+        // A hyphen at the end of a set is literal, and is parsed as the next character.
+        self.offset = before_hyphen;
+        Ok(vec![(first as u32, first as u32)])
+    }
+
+    fn parse_set_char(&mut self) -> Result<SetChar, Error> {
+        match self.peek() {
+            Some('\'') => {
+                self.bump();
+                Ok(SetChar::Quoted(self.parse_quoted()?))
+            }
+            Some('\\') => Ok(SetChar::Char(self.parse_escape()?)),
+            Some('[' | ']' | '$' | '{' | '}' | '^' | '&') => {
+                Err(self.error("unquoted syntax character in set"))
+            }
+            Some(c) => {
+                self.bump();
+                Ok(SetChar::Char(c))
+            }
+            None => Err(self.error("unterminated set")),
+        }
+    }
+}
+
+enum SetChar {
+    Char(char),
+    Quoted(String),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '/')
+}
+
+/// Returns whether a character ends a side of a conversion rule.
+fn is_rule_end(c: char) -> bool {
+    matches!(c, ';' | '>' | '<' | '→' | '←' | '↔')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(char, char)]) -> CharSet {
+        CharSet::from_ranges(
+            ranges
+                .iter()
+                .map(|&(start, end)| (start as u32, end as u32))
+                .collect(),
+        )
+    }
+
+    fn parse_set(source: &str) -> CharSet {
+        let mut parser = Parser {
+            id: "test",
+            rules: source,
+            offset: 0,
+            variables: BTreeMap::new(),
+        };
+        parser.parse_set().unwrap()
+    }
+
+    fn chars(text: &str) -> Vec<Element> {
+        text.chars().map(Element::Char).collect()
+    }
+
+    #[test]
+    fn test_sets() {
+        assert_eq!(parse_set("[a-z]"), set(&[('a', 'z')]));
+        assert_eq!(
+            parse_set("[c a b-d \\u0300-\\u036F]"),
+            set(&[('a', 'd'), ('\u{300}', '\u{36F}')])
+        );
+        assert_eq!(parse_set("[a-]"), set(&[('-', '-'), ('a', 'a')]));
+        assert_eq!(
+            parse_set("['[]' ''x]"),
+            set(&[('\'', '\''), ('[', '['), (']', ']'), ('x', 'x')])
+        );
+        assert!(parse_set("[[a-z]-[aeiou]]").contains('b'));
+        assert!(!parse_set("[[a-z]-[aeiou]]").contains('e'));
+        assert_eq!(parse_set("[[a-m]&[h-z]]"), set(&[('h', 'm')]));
+        assert_eq!(parse_set("[^a-y]"), set(&[('\0', '`'), ('z', char::MAX)]));
+        assert_eq!(parse_set("[^\\x{0}-\\U0010FFFF]"), CharSet::default());
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules = "
+            # Variables can contain sets and strings
+            $vowel = [aeiou] ;
+            $ks = k s ;
+            :: [a-z] ;
+            :: NFD (NFC) ;
+            $vowel { c } $vowel > g ;
+            x <> $ks ;
+            'q' } u → k | w ;
+            ch < ç ;
+            :: Null () ;
+            :: Latin-ASCII ;
+        ";
+        let vowel = Element::Set(set(&[
+            ('a', 'a'),
+            ('e', 'e'),
+            ('i', 'i'),
+            ('o', 'o'),
+            ('u', 'u'),
+        ]));
+        assert_eq!(
+            parse("test", rules, Direction::Forward).unwrap(),
+            ParsedRules {
+                filter: Some(set(&[('a', 'z')])),
+                phases: vec![
+                    Phase::Transform("NFD".into()),
+                    Phase::Rules(vec![
+                        Rule {
+                            before: vec![vowel.clone()],
+                            key: chars("c"),
+                            after: vec![vowel],
+                            replacement: vec!['g'],
+                            cursor: 1,
+                        },
+                        Rule {
+                            before: vec![],
+                            key: chars("x"),
+                            after: vec![],
+                            replacement: vec!['k', 's'],
+                            cursor: 2,
+                        },
+                        Rule {
+                            before: vec![],
+                            key: chars("q"),
+                            after: chars("u"),
+                            replacement: vec!['k', 'w'],
+                            cursor: 1,
+                        },
+                    ]),
+                    Phase::Transform("Null".into()),
+                    Phase::Transform("Latin-ASCII".into()),
+                ],
+            }
+        );
+        assert_eq!(
+            parse("test", rules, Direction::Reverse).unwrap(),
+            ParsedRules {
+                filter: None,
+                phases: vec![
+                    Phase::Transform("ASCII-Latin".into()),
+                    Phase::Rules(vec![
+                        Rule {
+                            before: vec![],
+                            key: chars("ks"),
+                            after: vec![],
+                            replacement: vec!['x'],
+                            cursor: 1,
+                        },
+                        Rule {
+                            before: vec![],
+                            key: chars("ç"),
+                            after: vec![],
+                            replacement: vec!['c', 'h'],
+                            cursor: 2,
+                        },
+                    ]),
+                    Phase::Transform("NFC".into()),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let rule = Rule {
+            before: chars("a"),
+            key: chars("bc"),
+            after: chars("d"),
+            replacement: vec![],
+            cursor: 0,
+        };
+        let text: Vec<char> = "abcdabc".chars().collect();
+        assert!(rule.matches(&text, 1));
+        assert!(!rule.matches(&text, 0));
+        assert!(!rule.matches(&text, 2));
+        // The context after the key is missing at the end of the text
+        assert!(!rule.matches(&text, 5));
+    }
+
+    #[test]
+    fn test_errors() {
+        for (rules, offset, reason) in [
+            ("a > b", 5, "expected ';'"),
+            ("a b ;", 4, "expected '>', '<', or '<>'"),
+            ("a > [b] ;", 0, "sets are not allowed in replacements"),
+            ("> b ;", 0, "a rule must match at least one character"),
+            (
+                "a > | b ;",
+                0,
+                "the cursor must not precede the replacement",
+            ),
+            ("a } b } c > d ;", 6, "unexpected '}'"),
+            ("$x > y ;", 0, "undefined variable"),
+            ("a $1 > b ;", 2, "anchors and segments are not supported"),
+            ("(a) > b ;", 0, "unsupported or unquoted syntax character"),
+            ("[:Latin:] > b ;", 0, "property sets are not supported"),
+            ("[z-a] > b ;", 3, "invalid range"),
+            ("[a > b ;", 0, "unterminated set"),
+            ("'a > b ;", 8, "unterminated quoted text"),
+            ("\\u12 > b ;", 0, "invalid escape sequence"),
+            ("a > b ; :: [a] ;", 8, "a global filter must come first"),
+            (":: ;", 3, "expected a transform ID"),
+        ] {
+            match parse("test", rules, Direction::Forward) {
+                Err(Error::Syntax {
+                    offset: actual_offset,
+                    reason: actual_reason,
+                    ..
+                }) => assert_eq!(
+                    (actual_offset, actual_reason),
+                    (offset, reason),
+                    "{}",
+                    rules
+                ),
+                result => panic!("{}: {:?}", rules, result),
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::registry::RegisteredKeys;
+use icu_provider::yoke::{self, *};
+
+pub mod key {
+    //! Resource keys for [`icu_transliterator`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the rules of a transform, requested with the ID of the transform, such as
+    /// `Hiragana-Katakana`, as the variant.
+    pub const RULES_V1: ResourceKey = resource_key!(Transliterator, "rules", 1);
+}
+
+/// The [`ResourceKeys`](icu_provider::ResourceKey) defined by [`icu_transliterator`](crate),
+/// grouped by data struct.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[icu_provider::registered_keys!(
+    TransliteratorRulesV1Marker,
+    [key::RULES_V1]
+)];
+
+/// The rules of a transform, in the syntax of the CLDR transform rules.
+///
+/// The rules of a transform `Source-Target` can also be run in the reverse direction, as the
+/// transform `Target-Source`; see [`Transliterator`](crate::Transliterator).
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct TransliteratorRulesV1<'data> {
+    /// The source text of the rules.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub rules: Cow<'data, str>,
+}
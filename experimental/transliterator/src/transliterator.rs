@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::parser::{self, CharSet, Direction, Phase as ParsedPhase, Rule};
+use crate::provider::{key, TransliteratorRulesV1Marker};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_normalizer::provider::{
+    CanonicalCombiningClassV1Marker, CanonicalCompositionV1Marker, CanonicalDecompositionV1Marker,
+};
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_provider::prelude::*;
+
+/// The maximum depth of transforms that call other transforms.
+const MAX_NESTING: usize = 16;
+
+enum Phase<'data> {
+    Rules(Vec<Rule>),
+    Nfd(DecomposingNormalizer<'data>),
+    Nfc(ComposingNormalizer<'data>),
+    Lower,
+    Upper,
+    Transform(Box<Transliterator<'data>>),
+}
+
+/// A transliterator, which converts text from one script or form to another by running the
+/// rules of a transform, such as `Hiragana-Katakana` or `Cyrillic-Latin`.
+///
+/// The rules are written in the syntax of the [CLDR transform rules] and loaded from the data
+/// provider with the ID of the transform. A transform `Source-Target` whose rules are not
+/// available is created by running the rules of `Target-Source` in the reverse direction.
+///
+/// The following parts of the syntax are supported:
+///
+/// * Conversion rules `a > b ;`, `a < b ;`, and `a <> b ;`, with contexts `x { a } y`, and a
+///   cursor `|` in the replacement, after which the rules continue.
+/// * Literal characters, quoted text `'...'`, escapes such as `\u0301` and `\x{1F600}`, and
+///   sets such as `[a-z]`, `[^aeiou]`, and `[[a-z]-[aeiou]]`.
+/// * Variables `$name = ... ;`.
+/// * A global filter `:: [...] ;` before the first rule, and `:: ([...]) ;` for the reverse
+///   direction; characters outside the filter are not changed.
+/// * Calls of other transforms `:: Id ;` and `:: Id (ReverseId) ;`, including the built-in
+///   transforms `NFD`, `NFC`, `Lower`, `Upper`, and `Null`. The rules between two calls are
+///   run together in a single pass over the text.
+///
+/// Property sets such as `[:Latin:]`, anchors, segments, and quantifiers are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use icu_transliterator::Transliterator;
+///
+/// let provider = icu_provider_fs::FsDataProvider::try_new(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/data"
+/// ))
+/// .expect("Data directory should exist");
+///
+/// let transliterator = Transliterator::try_new("Katakana-Hiragana", &provider)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(transliterator.transliterate("カタカナ and ひらがな"), "かたかな and ひらがな");
+/// ```
+///
+/// [CLDR transform rules]: https://unicode.org/reports/tr35/tr35-general.html#Transforms
+pub struct Transliterator<'data> {
+    filter: Option<CharSet>,
+    phases: Vec<Phase<'data>>,
+}
+
+impl<'data> Transliterator<'data> {
+    /// Creates a transliterator for the transform with the given ID, such as
+    /// `Cyrillic-Latin`.
+    ///
+    /// The data provider needs to provide the rules of the transform and of all transforms
+    /// that it calls, and the normalization data if any of them calls `NFD` or `NFC`.
+    pub fn try_new<D>(id: &str, data_provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, TransliteratorRulesV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, CanonicalCompositionV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_nested(id, data_provider, 0)
+    }
+
+    fn try_new_nested<D>(id: &str, data_provider: &D, depth: usize) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, TransliteratorRulesV1Marker>
+            + DataProvider<'data, CanonicalCombiningClassV1Marker>
+            + DataProvider<'data, CanonicalDecompositionV1Marker>
+            + DataProvider<'data, CanonicalCompositionV1Marker>
+            + ?Sized,
+    {
+        if depth > MAX_NESTING {
+            return Err(Error::NestingLimit(id.into()));
+        }
+        let parsed = if let Some(payload) = load_rules(id, data_provider)? {
+            parser::parse(id, &payload.get().rules, Direction::Forward)?
+        } else if let Some(payload) = load_rules(&parser::inverse_id(id), data_provider)? {
+            parser::parse(id, &payload.get().rules, Direction::Reverse)?
+        } else {
+            return Err(Error::UnknownTransform(id.into()));
+        };
+
+        let mut phases = Vec::with_capacity(parsed.phases.len());
+        for phase in parsed.phases {
+            phases.push(match phase {
+                ParsedPhase::Rules(rules) => Phase::Rules(rules),
+                ParsedPhase::Transform(id) => match id.as_str() {
+                    "NFD" => Phase::Nfd(DecomposingNormalizer::try_new_nfd(data_provider)?),
+                    "NFC" => Phase::Nfc(ComposingNormalizer::try_new_nfc(data_provider)?),
+                    "Lower" => Phase::Lower,
+                    "Upper" => Phase::Upper,
+                    "Null" => continue,
+                    _ => Phase::Transform(Box::new(Self::try_new_nested(
+                        &id,
+                        data_provider,
+                        depth + 1,
+                    )?)),
+                },
+            });
+        }
+        Ok(Self {
+            filter: parsed.filter,
+            phases,
+        })
+    }
+
+    /// Transliterates a string.
+    pub fn transliterate(&self, text: &str) -> String {
+        let filter = match &self.filter {
+            Some(filter) => filter,
+            None => return self.transliterate_unfiltered(text),
+        };
+        // The text is split into runs of characters that are in the filter, which are
+        // transliterated separately, and runs of characters that are not, which are kept.
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while !rest.is_empty() {
+            let start = rest.find(|c| filter.contains(c)).unwrap_or(rest.len());
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = rest.find(|c| !filter.contains(c)).unwrap_or(rest.len());
+            result.push_str(&self.transliterate_unfiltered(&rest[..end]));
+            rest = &rest[end..];
+        }
+        result
+    }
+
+    fn transliterate_unfiltered(&self, text: &str) -> String {
+        let mut text = String::from(text);
+        for phase in &self.phases {
+            text = match phase {
+                Phase::Rules(rules) => apply_rules(rules, &text),
+                Phase::Nfd(normalizer) => normalizer.normalize(&text),
+                Phase::Nfc(normalizer) => normalizer.normalize(&text),
+                Phase::Lower => text.chars().flat_map(char::to_lowercase).collect(),
+                Phase::Upper => text.chars().flat_map(char::to_uppercase).collect(),
+                Phase::Transform(transliterator) => transliterator.transliterate(&text),
+            };
+        }
+        text
+    }
+}
+
+/// Loads the rules of a transform, returning `None` if the data provider does not have them.
+fn load_rules<'data, D>(
+    id: &str,
+    data_provider: &D,
+) -> Result<Option<DataPayload<'data, TransliteratorRulesV1Marker>>, Error>
+where
+    D: DataProvider<'data, TransliteratorRulesV1Marker> + ?Sized,
+{
+    let request = DataRequest {
+        resource_path: ResourcePath {
+            key: key::RULES_V1,
+            options: ResourceOptions {
+                variant: Some(Cow::Owned(id.into())),
+                langid: None,
+            },
+        },
+    };
+    match data_provider.load_payload_with_context(&request) {
+        Ok(response) => Ok(Some(response.take_payload()?)),
+        Err(e) if matches!(e.without_context(), DataError::MissingResourceOptions(_)) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Runs conversion rules over a text in a single pass.
+///
+/// At each position, the first rule that matches is applied, and the position moves to the
+/// cursor of its replacement; if no rule matches, the position moves forward by one
+/// character. The context before a rule's key is therefore matched against text that has
+/// already been converted.
+fn apply_rules(rules: &[Rule], text: &str) -> String {
+    let mut chars: Vec<char> = text.chars().collect();
+    let mut position = 0;
+    while position < chars.len() {
+        match rules.iter().find(|rule| rule.matches(&chars, position)) {
+            Some(rule) => {
+                chars.splice(
+                    position..position + rule.key.len(),
+                    rule.replacement.iter().copied(),
+                );
+                position += rule.cursor;
+            }
+            None => position += 1,
+        }
+    }
+    chars.into_iter().collect()
+}
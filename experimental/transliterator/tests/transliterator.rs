@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::fork::ForkByKeyProvider;
+use icu_provider_fs::FsDataProvider;
+use icu_transliterator::{Transliterator, TransliteratorError};
+
+fn get_provider() -> ForkByKeyProvider<FsDataProvider, FsDataProvider> {
+    let rules = FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+        .expect("Data directory should exist");
+    let normalizer =
+        FsDataProvider::try_new(concat!(env!("CARGO_MANIFEST_DIR"), "/../normalizer/data"))
+            .expect("Data directory should exist");
+    ForkByKeyProvider(rules, normalizer)
+}
+
+fn transliterate(id: &str, text: &str) -> String {
+    Transliterator::try_new(id, &get_provider())
+        .expect("Data should load successfully")
+        .transliterate(text)
+}
+
+#[test]
+fn test_hiragana_katakana() {
+    assert_eq!(
+        transliterate("Hiragana-Katakana", "ひらがなとカタカナ、ゝゞ"),
+        "ヒラガナトカタカナ、ヽヾ"
+    );
+    assert_eq!(
+        transliterate("Katakana-Hiragana", "ヴァイオリンとヷ"),
+        "ゔぁいおりんとわ\u{3099}"
+    );
+}
+
+#[test]
+fn test_cyrillic_latin() {
+    assert_eq!(
+        transliterate(
+            "Cyrillic-Latin",
+            "Съешь же ещё этих мягких французских булок."
+        ),
+        "Sʺešʹ že eŝë ètih mâgkih francuzskih bulok."
+    );
+    // Decomposed input is normalized before the rules are run
+    assert_eq!(transliterate("Cyrillic-Latin", "Е\u{0308}ж"), "Ëž");
+    // Characters outside of the filter are kept
+    assert_eq!(
+        transliterate("Cyrillic-Latin", "Москва (Moscow), 2021"),
+        "Moskva (Moscow), 2021"
+    );
+}
+
+#[test]
+fn test_latin_cyrillic() {
+    let text = "Съешь же ещё этих мягких французских булок, да выпей йоду.";
+    let latin = transliterate("Cyrillic-Latin", text);
+    assert_eq!(transliterate("Latin-Cyrillic", &latin), text);
+    // Decomposed input is normalized before the rules are run
+    assert_eq!(transliterate("Latin-Cyrillic", "Z\u{030C}u\u{0302}"), "Жю");
+}
+
+#[test]
+fn test_cyrillic_ascii() {
+    assert_eq!(
+        transliterate("Cyrillic-ASCII", "Съешь щи, Ёжик"),
+        "S\"es' si, Ezik"
+    );
+}
+
+#[test]
+fn test_unknown_transform() {
+    assert!(matches!(
+        Transliterator::try_new("Greek-Latin", &get_provider()),
+        Err(TransliteratorError::UnknownTransform(id)) if id == "Greek-Latin"
+    ));
+}
@@ -0,0 +1,89 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#include "../../include/ICU4XDateTimeFormat.h"
+#include <string.h>
+#include <stdio.h>
+
+const char* path = "../../../../../provider/testdata/data/json/";
+int main() {
+    ICU4XLocale* locale = ICU4XLocale_create("en", 2);
+    ICU4XCreateDataProviderResult result = ICU4XDataProvider_create_fs(path, strlen(path));
+    if (!result.success) {
+        printf("Failed to create FsDataProvider\n");
+        return 1;
+    }
+    ICU4XDataProvider* provider = result.provider;
+
+    ICU4XCreateGregorianDateTimeResult datetime_result = ICU4XGregorianDateTime_try_new(2021, 7, 15, 13, 45, 30);
+    if (!datetime_result.success) {
+        printf("Failed to create GregorianDateTime\n");
+        return 1;
+    }
+    ICU4XGregorianDateTime* datetime = datetime_result.datetime;
+
+    ICU4XDateTimeFormatOptions opts = {ICU4XDateLength_Medium, ICU4XTimeLength_Short};
+
+    ICU4XDateTimeFormatResult dtf_result = ICU4XDateTimeFormat_try_new(locale, provider, opts);
+    if (!dtf_result.success)  {
+        printf("Failed to create DateTimeFormat\n");
+        return 1;
+    }
+    ICU4XDateTimeFormat* dtf = dtf_result.dtf;
+    char output[40];
+
+    DiplomatWriteable write = diplomat_simple_writeable(output, 40);
+
+    bool success = ICU4XDateTimeFormat_format_datetime(dtf, datetime, &write).is_ok;
+    if (!success) {
+        printf("Failed to write result of DateTimeFormat::format to string.\n");
+        return 1;
+    }
+    printf("Output is %s\n", output);
+
+    const char* expected = "Jul 15, 2021, 1:45 PM";
+    if (strcmp(output, expected) != 0) {
+        printf("Output does not match expected output!\n");
+        return 1;
+    }
+
+    ICU4XDateTimeFormat_destroy(dtf);
+
+    opts.time_length = ICU4XTimeLength_None;
+    opts.date_length = ICU4XDateLength_Full;
+    dtf_result = ICU4XDateTimeFormat_try_new(locale, provider, opts);
+    if (!dtf_result.success)  {
+        printf("Failed to create DateTimeFormat\n");
+        return 1;
+    }
+    dtf = dtf_result.dtf;
+
+    write = diplomat_simple_writeable(output, 40);
+
+    success = ICU4XDateTimeFormat_format_datetime(dtf, datetime, &write).is_ok;
+    if (!success) {
+        printf("Failed to write result of DateTimeFormat::format to string.\n");
+        return 1;
+    }
+    printf("Output with full date and no time is %s\n", output);
+
+    expected = "Thursday, July 15, 2021";
+    if (strcmp(output, expected) != 0) {
+        printf("Output does not match expected output!\n");
+        return 1;
+    }
+
+    datetime_result = ICU4XGregorianDateTime_try_new(2021, 13, 1, 0, 0, 0);
+    if (datetime_result.success) {
+        printf("GregorianDateTime should reject month 13\n");
+        return 1;
+    }
+
+    ICU4XDateTimeFormat_destroy(dtf);
+    ICU4XGregorianDateTime_destroy(datetime);
+    ICU4XDataProvider_destroy(provider);
+    ICU4XLocale_destroy(locale);
+
+    return 0;
+}
@@ -0,0 +1,29 @@
+#ifndef ICU4XDateTimeFormat_H
+#define ICU4XDateTimeFormat_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct ICU4XDateTimeFormat ICU4XDateTimeFormat;
+#include "ICU4XLocale.h"
+#include "ICU4XDataProvider.h"
+#include "ICU4XDateTimeFormatOptions.h"
+#include "ICU4XDateTimeFormatResult.h"
+#include "ICU4XGregorianDateTime.h"
+#include "result_void_void.h"
+
+ICU4XDateTimeFormatResult ICU4XDateTimeFormat_try_new(const ICU4XLocale* locale, const ICU4XDataProvider* provider, ICU4XDateTimeFormatOptions options);
+
+decimal_ffi_result_void_void ICU4XDateTimeFormat_format_datetime(const ICU4XDateTimeFormat* self, const ICU4XGregorianDateTime* value, DiplomatWriteable* write);
+void ICU4XDateTimeFormat_destroy(ICU4XDateTimeFormat* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
@@ -0,0 +1,26 @@
+#ifndef ICU4XDateTimeFormatOptions_H
+#define ICU4XDateTimeFormatOptions_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#include "ICU4XDateLength.h"
+#include "ICU4XTimeLength.h"
+
+typedef struct ICU4XDateTimeFormatOptions {
+    ICU4XDateLength date_length;
+    ICU4XTimeLength time_length;
+} ICU4XDateTimeFormatOptions;
+
+ICU4XDateTimeFormatOptions ICU4XDateTimeFormatOptions_default();
+void ICU4XDateTimeFormatOptions_destroy(ICU4XDateTimeFormatOptions* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
@@ -0,0 +1,24 @@
+#ifndef ICU4XCreateGregorianDateTimeResult_H
+#define ICU4XCreateGregorianDateTimeResult_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+typedef struct ICU4XGregorianDateTime ICU4XGregorianDateTime;
+
+typedef struct ICU4XCreateGregorianDateTimeResult {
+    ICU4XGregorianDateTime* datetime;
+    bool success;
+} ICU4XCreateGregorianDateTimeResult;
+
+void ICU4XCreateGregorianDateTimeResult_destroy(ICU4XCreateGregorianDateTimeResult* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
@@ -0,0 +1,42 @@
+#ifndef ICU4XCreateGregorianDateTimeResult_HPP
+#define ICU4XCreateGregorianDateTimeResult_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <span>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XCreateGregorianDateTimeResult.h"
+}
+
+class ICU4XGregorianDateTime;
+
+/**
+ * A destruction policy for using ICU4XCreateGregorianDateTimeResult with std::unique_ptr.
+ */
+struct ICU4XCreateGregorianDateTimeResultDeleter {
+  void operator()(capi::ICU4XCreateGregorianDateTimeResult* l) const noexcept {
+    capi::ICU4XCreateGregorianDateTimeResult_destroy(l);
+  }
+};
+struct ICU4XCreateGregorianDateTimeResult {
+ public:
+
+  /**
+   * Will be `None` if `success` is `false`, do not use in that case.
+   */
+  std::optional<ICU4XGregorianDateTime> datetime;
+
+  /**
+   * Whether the fields were in range.
+   */
+  bool success;
+};
+
+
+#endif
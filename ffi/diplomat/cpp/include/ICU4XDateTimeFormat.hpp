@@ -0,0 +1,86 @@
+#ifndef ICU4XDateTimeFormat_HPP
+#define ICU4XDateTimeFormat_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <span>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeFormat.h"
+}
+
+class ICU4XLocale;
+class ICU4XDataProvider;
+struct ICU4XDateTimeFormatOptions;
+struct ICU4XDateTimeFormatResult;
+class ICU4XGregorianDateTime;
+
+/**
+ * A destruction policy for using ICU4XDateTimeFormat with std::unique_ptr.
+ */
+struct ICU4XDateTimeFormatDeleter {
+  void operator()(capi::ICU4XDateTimeFormat* l) const noexcept {
+    capi::ICU4XDateTimeFormat_destroy(l);
+  }
+};
+class ICU4XDateTimeFormat {
+ public:
+
+  /**
+   * Creates a new [`ICU4XDateTimeFormat`] from locale data. See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html#method.try_new) for more information.
+   */
+  static ICU4XDateTimeFormatResult try_new(const ICU4XLocale& locale, const ICU4XDataProvider& provider, ICU4XDateTimeFormatOptions options);
+
+  /**
+   * Formats a [`ICU4XGregorianDateTime`] to a string. See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html#method.format) for more information.
+   */
+  template<typename W> diplomat::result<std::monostate, std::monostate> format_datetime_to_writeable(const ICU4XGregorianDateTime& value, W& write) const;
+
+  /**
+   * Formats a [`ICU4XGregorianDateTime`] to a string. See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html#method.format) for more information.
+   */
+  diplomat::result<std::string, std::monostate> format_datetime(const ICU4XGregorianDateTime& value) const;
+  inline const capi::ICU4XDateTimeFormat* AsFFI() const { return this->inner.get(); }
+  inline capi::ICU4XDateTimeFormat* AsFFIMut() { return this->inner.get(); }
+  inline ICU4XDateTimeFormat(capi::ICU4XDateTimeFormat* i) : inner(i) {}
+ private:
+  std::unique_ptr<capi::ICU4XDateTimeFormat, ICU4XDateTimeFormatDeleter> inner;
+};
+
+#include "ICU4XLocale.hpp"
+#include "ICU4XDataProvider.hpp"
+#include "ICU4XDateTimeFormatOptions.hpp"
+#include "ICU4XDateTimeFormatResult.hpp"
+#include "ICU4XGregorianDateTime.hpp"
+
+inline ICU4XDateTimeFormatResult ICU4XDateTimeFormat::try_new(const ICU4XLocale& locale, const ICU4XDataProvider& provider, ICU4XDateTimeFormatOptions options) {
+  ICU4XDateTimeFormatOptions diplomat_wrapped_struct_options = options;
+  capi::ICU4XDateTimeFormatResult diplomat_raw_struct_out_value = capi::ICU4XDateTimeFormat_try_new(locale.AsFFI(), provider.AsFFI(), capi::ICU4XDateTimeFormatOptions{ .date_length = static_cast<capi::ICU4XDateLength>(diplomat_wrapped_struct_options.date_length), .time_length = static_cast<capi::ICU4XTimeLength>(diplomat_wrapped_struct_options.time_length) });
+  auto diplomat_optional_raw_out_value_dtf = diplomat_raw_struct_out_value.dtf;
+  std::optional<ICU4XDateTimeFormat> diplomat_optional_out_value_dtf;
+  if (diplomat_optional_raw_out_value_dtf != nullptr) {
+    diplomat_optional_out_value_dtf = ICU4XDateTimeFormat(diplomat_optional_raw_out_value_dtf);
+  } else {
+    diplomat_optional_out_value_dtf = std::nullopt;
+  }
+  return ICU4XDateTimeFormatResult{ .dtf = std::move(diplomat_optional_out_value_dtf), .success = std::move(diplomat_raw_struct_out_value.success) };
+}
+template<typename W> inline diplomat::result<std::monostate, std::monostate> ICU4XDateTimeFormat::format_datetime_to_writeable(const ICU4XGregorianDateTime& value, W& write) const {
+  capi::DiplomatWriteable write_writer = diplomat::WriteableTrait<W>::Construct(write);
+  auto diplomat_result_raw_out_value = capi::ICU4XDateTimeFormat_format_datetime(this->inner.get(), value.AsFFI(), &write_writer);
+  diplomat::result<std::monostate, std::monostate> diplomat_result_out_value(diplomat_result_raw_out_value.is_ok);
+  return diplomat_result_out_value;
+}
+inline diplomat::result<std::string, std::monostate> ICU4XDateTimeFormat::format_datetime(const ICU4XGregorianDateTime& value) const {
+  std::string diplomat_writeable_string;
+  capi::DiplomatWriteable diplomat_writeable_out = diplomat::WriteableFromString(diplomat_writeable_string);
+  auto diplomat_result_raw_out_value = capi::ICU4XDateTimeFormat_format_datetime(this->inner.get(), value.AsFFI(), &diplomat_writeable_out);
+  diplomat::result<std::monostate, std::monostate> diplomat_result_out_value(diplomat_result_raw_out_value.is_ok);
+  return diplomat_result_out_value.replace_ok(std::move(diplomat_writeable_string));
+}
+#endif
@@ -0,0 +1,41 @@
+#ifndef ICU4XDateTimeFormatOptions_HPP
+#define ICU4XDateTimeFormatOptions_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <span>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeFormatOptions.h"
+}
+
+#include "ICU4XDateLength.hpp"
+#include "ICU4XTimeLength.hpp"
+struct ICU4XDateTimeFormatOptions;
+
+/**
+ * A destruction policy for using ICU4XDateTimeFormatOptions with std::unique_ptr.
+ */
+struct ICU4XDateTimeFormatOptionsDeleter {
+  void operator()(capi::ICU4XDateTimeFormatOptions* l) const noexcept {
+    capi::ICU4XDateTimeFormatOptions_destroy(l);
+  }
+};
+struct ICU4XDateTimeFormatOptions {
+ public:
+  ICU4XDateLength date_length;
+  ICU4XTimeLength time_length;
+  static ICU4XDateTimeFormatOptions default_();
+};
+
+
+inline ICU4XDateTimeFormatOptions ICU4XDateTimeFormatOptions::default_() {
+  capi::ICU4XDateTimeFormatOptions diplomat_raw_struct_out_value = capi::ICU4XDateTimeFormatOptions_default();
+  return ICU4XDateTimeFormatOptions{ .date_length = std::move(static_cast<ICU4XDateLength>(diplomat_raw_struct_out_value.date_length)), .time_length = std::move(static_cast<ICU4XTimeLength>(diplomat_raw_struct_out_value.time_length)) };
+}
+#endif
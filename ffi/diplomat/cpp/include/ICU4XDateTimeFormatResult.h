@@ -0,0 +1,24 @@
+#ifndef ICU4XDateTimeFormatResult_H
+#define ICU4XDateTimeFormatResult_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+typedef struct ICU4XDateTimeFormat ICU4XDateTimeFormat;
+
+typedef struct ICU4XDateTimeFormatResult {
+    ICU4XDateTimeFormat* dtf;
+    bool success;
+} ICU4XDateTimeFormatResult;
+
+void ICU4XDateTimeFormatResult_destroy(ICU4XDateTimeFormatResult* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
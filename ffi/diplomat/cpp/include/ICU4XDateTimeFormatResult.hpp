@@ -0,0 +1,42 @@
+#ifndef ICU4XDateTimeFormatResult_HPP
+#define ICU4XDateTimeFormatResult_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <span>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XDateTimeFormatResult.h"
+}
+
+class ICU4XDateTimeFormat;
+
+/**
+ * A destruction policy for using ICU4XDateTimeFormatResult with std::unique_ptr.
+ */
+struct ICU4XDateTimeFormatResultDeleter {
+  void operator()(capi::ICU4XDateTimeFormatResult* l) const noexcept {
+    capi::ICU4XDateTimeFormatResult_destroy(l);
+  }
+};
+struct ICU4XDateTimeFormatResult {
+ public:
+
+  /**
+   * The [`ICU4XDateTimeFormat`], exists if creation was successful.
+   */
+  std::optional<ICU4XDateTimeFormat> dtf;
+
+  /**
+   * Whether creating the [`ICU4XDateTimeFormat`] was successful.
+   */
+  bool success;
+};
+
+
+#endif
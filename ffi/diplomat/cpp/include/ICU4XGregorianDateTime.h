@@ -0,0 +1,22 @@
+#ifndef ICU4XGregorianDateTime_H
+#define ICU4XGregorianDateTime_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct ICU4XGregorianDateTime ICU4XGregorianDateTime;
+#include "ICU4XCreateGregorianDateTimeResult.h"
+
+ICU4XCreateGregorianDateTimeResult ICU4XGregorianDateTime_try_new(int32_t year, uint8_t month, uint8_t day, uint8_t hour, uint8_t minute, uint8_t second);
+void ICU4XGregorianDateTime_destroy(ICU4XGregorianDateTime* self);
+
+#ifdef __cplusplus
+}
+#endif
+#endif
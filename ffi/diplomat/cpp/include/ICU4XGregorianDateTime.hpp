@@ -0,0 +1,56 @@
+#ifndef ICU4XGregorianDateTime_HPP
+#define ICU4XGregorianDateTime_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <optional>
+#include <span>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+namespace capi {
+#include "ICU4XGregorianDateTime.h"
+}
+
+struct ICU4XCreateGregorianDateTimeResult;
+
+/**
+ * A destruction policy for using ICU4XGregorianDateTime with std::unique_ptr.
+ */
+struct ICU4XGregorianDateTimeDeleter {
+  void operator()(capi::ICU4XGregorianDateTime* l) const noexcept {
+    capi::ICU4XGregorianDateTime_destroy(l);
+  }
+};
+class ICU4XGregorianDateTime {
+ public:
+
+  /**
+   * Creates a new [`ICU4XGregorianDateTime`] from an ISO year, a month from 1 to 12, a day
+   * of the month, and a time of day.
+   * See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_calendar/struct.DateTime.html#method.new_gregorian_datetime_from_integers) for more information.
+   */
+  static ICU4XCreateGregorianDateTimeResult try_new(int32_t year, uint8_t month, uint8_t day, uint8_t hour, uint8_t minute, uint8_t second);
+  inline const capi::ICU4XGregorianDateTime* AsFFI() const { return this->inner.get(); }
+  inline capi::ICU4XGregorianDateTime* AsFFIMut() { return this->inner.get(); }
+  inline ICU4XGregorianDateTime(capi::ICU4XGregorianDateTime* i) : inner(i) {}
+ private:
+  std::unique_ptr<capi::ICU4XGregorianDateTime, ICU4XGregorianDateTimeDeleter> inner;
+};
+
+#include "ICU4XCreateGregorianDateTimeResult.hpp"
+
+inline ICU4XCreateGregorianDateTimeResult ICU4XGregorianDateTime::try_new(int32_t year, uint8_t month, uint8_t day, uint8_t hour, uint8_t minute, uint8_t second) {
+  capi::ICU4XCreateGregorianDateTimeResult diplomat_raw_struct_out_value = capi::ICU4XGregorianDateTime_try_new(year, month, day, hour, minute, second);
+  auto diplomat_optional_raw_out_value_datetime = diplomat_raw_struct_out_value.datetime;
+  std::optional<ICU4XGregorianDateTime> diplomat_optional_out_value_datetime;
+  if (diplomat_optional_raw_out_value_datetime != nullptr) {
+    diplomat_optional_out_value_datetime = ICU4XGregorianDateTime(diplomat_optional_raw_out_value_datetime);
+  } else {
+    diplomat_optional_out_value_datetime = std::nullopt;
+  }
+  return ICU4XCreateGregorianDateTimeResult{ .datetime = std::move(diplomat_optional_out_value_datetime), .success = std::move(diplomat_raw_struct_out_value.success) };
+}
+#endif
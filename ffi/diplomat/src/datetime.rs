@@ -0,0 +1,158 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[diplomat::bridge]
+pub mod ffi {
+    use alloc::boxed::Box;
+    use diplomat_runtime::DiplomatResult;
+    use icu_calendar::{DateTime, Gregorian};
+    use icu_datetime::{options::length, DateTimeFormat};
+    use writeable::Writeable;
+
+    use crate::{locale::ffi::ICU4XLocale, provider::ffi::ICU4XDataProvider};
+
+    #[diplomat::opaque]
+    /// An ICU4X date and time in the Gregorian calendar.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_calendar/struct.DateTime.html) for more information.
+    pub struct ICU4XGregorianDateTime(pub DateTime<Gregorian>);
+
+    /// A result type for `ICU4XGregorianDateTime::try_new`.
+    pub struct ICU4XCreateGregorianDateTimeResult {
+        /// Will be `None` if `success` is `false`, do not use in that case.
+        pub datetime: Option<Box<ICU4XGregorianDateTime>>,
+        /// Whether the fields were in range.
+        pub success: bool,
+    }
+
+    impl ICU4XGregorianDateTime {
+        /// Creates a new [`ICU4XGregorianDateTime`] from an ISO year, a month from 1 to 12, a day
+        /// of the month, and a time of day.
+        /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_calendar/struct.DateTime.html#method.new_gregorian_datetime_from_integers) for more information.
+        pub fn try_new(
+            year: i32,
+            month: u8,
+            day: u8,
+            hour: u8,
+            minute: u8,
+            second: u8,
+        ) -> ICU4XCreateGregorianDateTimeResult {
+            if let Ok(datetime) = DateTime::new_gregorian_datetime_from_integers(
+                year, month, day, hour, minute, second,
+            ) {
+                ICU4XCreateGregorianDateTimeResult {
+                    datetime: Some(Box::new(ICU4XGregorianDateTime(datetime))),
+                    success: true,
+                }
+            } else {
+                ICU4XCreateGregorianDateTimeResult {
+                    datetime: None,
+                    success: false,
+                }
+            }
+        }
+    }
+
+    #[diplomat::opaque]
+    /// An ICU4X DateTimeFormat object, capable of formatting a [`ICU4XGregorianDateTime`] as a string.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html) for more information.
+    pub struct ICU4XDateTimeFormat(pub DateTimeFormat<'static>);
+
+    pub struct ICU4XDateTimeFormatResult {
+        /// The [`ICU4XDateTimeFormat`], exists if creation was successful.
+        pub dtf: Option<Box<ICU4XDateTimeFormat>>,
+        /// Whether creating the [`ICU4XDateTimeFormat`] was successful.
+        pub success: bool,
+    }
+
+    /// FFI version of the date lengths of `length::Bag`, with `None` for a format without a date.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/options/length/enum.Date.html) for more information.
+    pub enum ICU4XDateLength {
+        Full,
+        Long,
+        Medium,
+        Short,
+        None,
+    }
+
+    /// FFI version of the time lengths of `length::Bag`, with `None` for a format without a time.
+    /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/options/length/enum.Time.html) for more information.
+    pub enum ICU4XTimeLength {
+        Full,
+        Long,
+        Medium,
+        Short,
+        None,
+    }
+
+    pub struct ICU4XDateTimeFormatOptions {
+        pub date_length: ICU4XDateLength,
+        pub time_length: ICU4XTimeLength,
+    }
+
+    impl ICU4XDateTimeFormatOptions {
+        pub fn default() -> ICU4XDateTimeFormatOptions {
+            ICU4XDateTimeFormatOptions {
+                date_length: ICU4XDateLength::Long,
+                time_length: ICU4XTimeLength::Long,
+            }
+        }
+    }
+
+    impl ICU4XDateTimeFormat {
+        /// Creates a new [`ICU4XDateTimeFormat`] from locale data. See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html#method.try_new) for more information.
+        pub fn try_new(
+            locale: &ICU4XLocale,
+            provider: &ICU4XDataProvider,
+            options: ICU4XDateTimeFormatOptions,
+        ) -> ICU4XDateTimeFormatResult {
+            let locale = locale.0.as_ref().clone();
+            let options = length::Bag {
+                date: match options.date_length {
+                    ICU4XDateLength::Full => Some(length::Date::Full),
+                    ICU4XDateLength::Long => Some(length::Date::Long),
+                    ICU4XDateLength::Medium => Some(length::Date::Medium),
+                    ICU4XDateLength::Short => Some(length::Date::Short),
+                    ICU4XDateLength::None => None,
+                },
+                time: match options.time_length {
+                    ICU4XTimeLength::Full => Some(length::Time::Full),
+                    ICU4XTimeLength::Long => Some(length::Time::Long),
+                    ICU4XTimeLength::Medium => Some(length::Time::Medium),
+                    ICU4XTimeLength::Short => Some(length::Time::Short),
+                    ICU4XTimeLength::None => None,
+                },
+                ..Default::default()
+            };
+
+            if let Result::Ok(dtf) = DateTimeFormat::try_new(locale, &provider.0, &options.into()) {
+                ICU4XDateTimeFormatResult {
+                    dtf: Some(Box::new(ICU4XDateTimeFormat(dtf))),
+                    success: true,
+                }
+            } else {
+                ICU4XDateTimeFormatResult {
+                    dtf: None,
+                    success: false,
+                }
+            }
+        }
+
+        /// Formats a [`ICU4XGregorianDateTime`] to a string. See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu/datetime/struct.DateTimeFormat.html#method.format) for more information.
+        pub fn format_datetime(
+            &self,
+            value: &ICU4XGregorianDateTime,
+            write: &mut diplomat_runtime::DiplomatWriteable,
+        ) -> DiplomatResult<(), ()> {
+            #[allow(unused_variables)]
+            let result = self
+                .0
+                .format(&value.0)
+                .write_to(write)
+                .map_err(|_| ())
+                .into();
+            write.flush();
+            result
+        }
+    }
+}
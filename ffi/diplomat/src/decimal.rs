@@ -109,6 +109,8 @@ pub mod ffi {
                         ICU4XFixedDecimalSignDisplay::ExceptZero => SignDisplay::ExceptZero,
                         ICU4XFixedDecimalSignDisplay::Negative => SignDisplay::Negative,
                     },
+                    // The numbering system is selected with the -u-nu- keyword of the locale.
+                    numbering_system: None,
                 },
             ) {
                 ICU4XFixedDecimalFormatResult {
@@ -33,6 +33,7 @@ extern crate std as rust_std;
 extern crate alloc;
 
 pub mod custom_writeable;
+pub mod datetime;
 pub mod decimal;
 pub mod fixed_decimal;
 pub mod locale;
@@ -57,6 +57,28 @@ export class ICU4XCreateFixedDecimalResult {
   }
 }
 
+const ICU4XCreateGregorianDateTimeResult_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XCreateGregorianDateTimeResult_destroy(underlying);
+});
+
+export class ICU4XCreateGregorianDateTimeResult {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get datetime() {
+    return (() => {
+      const out = new ICU4XGregorianDateTime((new Uint32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]);
+      out.owner = null;
+      return out;
+    })();
+  }
+
+  get success() {
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 4, 1))[0] == 1;
+  }
+}
+
 const ICU4XCreatePluralOperandsResult_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XCreatePluralOperandsResult_destroy(underlying);
 });
@@ -197,6 +219,118 @@ export class ICU4XDataProvider {
   }
 }
 
+const ICU4XDateLength_js_to_rust = {
+  "Full": 0,
+  "Long": 1,
+  "Medium": 2,
+  "Short": 3,
+  "None": 4,
+};
+const ICU4XDateLength_rust_to_js = {
+  0: "Full",
+  1: "Long",
+  2: "Medium",
+  3: "Short",
+  4: "None",
+};
+
+const ICU4XDateTimeFormat_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeFormat_destroy(underlying);
+});
+
+export class ICU4XDateTimeFormat {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  static try_new(locale, provider, options) {
+    const diplomat_ICU4XDateTimeFormatOptions_extracted_date_length = options["date_length"];
+    const diplomat_ICU4XDateTimeFormatOptions_extracted_time_length = options["time_length"];
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5, 4);
+      wasm.ICU4XDateTimeFormat_try_new(diplomat_receive_buffer, locale.underlying, provider.underlying, ICU4XDateLength_js_to_rust[diplomat_ICU4XDateTimeFormatOptions_extracted_date_length], ICU4XTimeLength_js_to_rust[diplomat_ICU4XDateTimeFormatOptions_extracted_time_length]);
+      const out = new ICU4XDateTimeFormatResult(diplomat_receive_buffer);
+      const out_dtf_value = out.dtf;
+      ICU4XDateTimeFormat_box_destroy_registry.register(out_dtf_value, out_dtf_value.underlying);
+      Object.defineProperty(out, "dtf", { value: out_dtf_value });
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 5,
+        align: 4,
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+
+  format_datetime(value) {
+    const diplomat_out = diplomatRuntime.withWriteable(wasm, (writeable) => {
+      return (() => {
+        const is_ok = wasm.ICU4XDateTimeFormat_format_datetime(this.underlying, value.underlying, writeable) == 1;
+        if (!is_ok) {
+          throw {};
+        }
+      })();
+    });
+    return diplomat_out;
+  }
+}
+
+const ICU4XDateTimeFormatOptions_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeFormatOptions_destroy(underlying);
+});
+
+export class ICU4XDateTimeFormatOptions {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  static default() {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(8, 4);
+      wasm.ICU4XDateTimeFormatOptions_default(diplomat_receive_buffer);
+      const out = new ICU4XDateTimeFormatOptions(diplomat_receive_buffer);
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 8,
+        align: 4,
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+
+  get date_length() {
+    return ICU4XDateLength_rust_to_js[(new Int32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]];
+  }
+
+  get time_length() {
+    return ICU4XTimeLength_rust_to_js[(new Int32Array(wasm.memory.buffer, this.underlying + 4, 1))[0]];
+  }
+}
+
+const ICU4XDateTimeFormatResult_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XDateTimeFormatResult_destroy(underlying);
+});
+
+export class ICU4XDateTimeFormatResult {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  get dtf() {
+    return (() => {
+      const out = new ICU4XDateTimeFormat((new Uint32Array(wasm.memory.buffer, this.underlying + 0, 1))[0]);
+      out.owner = null;
+      return out;
+    })();
+  }
+
+  get success() {
+    return (new Uint8Array(wasm.memory.buffer, this.underlying + 4, 1))[0] == 1;
+  }
+}
+
 const ICU4XFixedDecimal_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XFixedDecimal_destroy(underlying);
 });
@@ -404,6 +538,34 @@ const ICU4XFixedDecimalSignDisplay_rust_to_js = {
   4: "Negative",
 };
 
+const ICU4XGregorianDateTime_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ICU4XGregorianDateTime_destroy(underlying);
+});
+
+export class ICU4XGregorianDateTime {
+  constructor(underlying) {
+    this.underlying = underlying;
+  }
+
+  static try_new(year, month, day, hour, minute, second) {
+    const diplomat_out = (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5, 4);
+      wasm.ICU4XGregorianDateTime_try_new(diplomat_receive_buffer, year, month, day, hour, minute, second);
+      const out = new ICU4XCreateGregorianDateTimeResult(diplomat_receive_buffer);
+      const out_datetime_value = out.datetime;
+      ICU4XGregorianDateTime_box_destroy_registry.register(out_datetime_value, out_datetime_value.underlying);
+      Object.defineProperty(out, "datetime", { value: out_datetime_value });
+      diplomat_alloc_destroy_registry.register(out, {
+        ptr: out.underlying,
+        size: 5,
+        align: 4,
+      });
+      return out;
+    })();
+    return diplomat_out;
+  }
+}
+
 const ICU4XLocale_box_destroy_registry = new FinalizationRegistry(underlying => {
   wasm.ICU4XLocale_destroy(underlying);
 });
@@ -950,3 +1112,18 @@ export class ICU4XStaticDataProvider {
     return diplomat_out;
   }
 }
+
+const ICU4XTimeLength_js_to_rust = {
+  "Full": 0,
+  "Long": 1,
+  "Medium": 2,
+  "Short": 3,
+  "None": 4,
+};
+const ICU4XTimeLength_rust_to_js = {
+  0: "Full",
+  1: "Long",
+  2: "Medium",
+  3: "Short",
+  4: "None",
+};
@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import { ICU4XDataProvider } from "./api.mjs"
+
+async function readBytes(source) {
+  if (source instanceof Uint8Array) {
+    return source;
+  }
+  if (source instanceof ArrayBuffer) {
+    return new Uint8Array(source);
+  }
+  if (typeof Response !== 'undefined' && source instanceof Response) {
+    if (!source.ok) {
+      throw new Error(`Could not fetch the ICU4X data: ${source.status} ${source.statusText}`);
+    }
+    return new Uint8Array(await source.arrayBuffer());
+  }
+  const isUrl = /^https?:\/\//.test(source);
+  if (!isUrl && typeof process !== 'undefined' && process.versions && process.versions.node) {
+    const fs = await import("fs");
+    const nodeBuffer = await fs.promises.readFile(source);
+    return new Uint8Array(nodeBuffer.buffer, nodeBuffer.byteOffset, nodeBuffer.length);
+  }
+  return readBytes(await fetch(source));
+}
+
+/**
+ * Loads an ICU4X data provider from a postcard blob, as exported by `icu4x-datagen`.
+ *
+ * The source may be the bytes of the blob, a `Response` or the promise returned by `fetch()`,
+ * or a path or URL to the blob. Paths are read from the file system in Node.js and fetched in browsers.
+ *
+ * Rejects if the blob cannot be read or is not valid ICU4X data.
+ */
+export async function loadDataProvider(source) {
+  const bytes = await readBytes(await source);
+  const result = ICU4XDataProvider.create_from_byte_slice(bytes);
+  if (!result.success) {
+    throw new Error("The ICU4X data is not a valid postcard blob");
+  }
+  return result.provider;
+}
@@ -0,0 +1,162 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Type declarations for the JavaScript API in `api.mjs` and `data-provider.mjs`.
+//
+// Methods that write a string throw if ICU4X fails to format; constructors that can fail
+// return a result object with a `success` flag, and the object should not be used otherwise.
+
+export type ICU4XCanonicalizationResult = "Modified" | "Unmodified";
+
+export class ICU4XCreateDataProviderResult {
+  readonly provider: ICU4XDataProvider;
+  readonly success: boolean;
+}
+
+export class ICU4XCreateFixedDecimalResult {
+  readonly fd: ICU4XFixedDecimal;
+  readonly success: boolean;
+}
+
+export class ICU4XCreateGregorianDateTimeResult {
+  readonly datetime: ICU4XGregorianDateTime;
+  readonly success: boolean;
+}
+
+export class ICU4XCreatePluralOperandsResult {
+  readonly operands: ICU4XPluralOperands;
+  readonly success: boolean;
+}
+
+export class ICU4XCreatePluralRulesResult {
+  readonly rules: ICU4XPluralRules;
+  readonly success: boolean;
+}
+
+export class ICU4XCreateStaticDataProviderResult {
+  readonly provider: ICU4XStaticDataProvider;
+  readonly success: boolean;
+}
+
+export class ICU4XDataProvider {
+  static create_fs(path: string): ICU4XCreateDataProviderResult;
+  static create_static(): ICU4XCreateDataProviderResult;
+  static create_from_byte_slice(blob: Uint8Array): ICU4XCreateDataProviderResult;
+}
+
+export type ICU4XDateLength = "Full" | "Long" | "Medium" | "Short" | "None";
+
+export class ICU4XDateTimeFormat {
+  static try_new(locale: ICU4XLocale, provider: ICU4XDataProvider, options: ICU4XDateTimeFormatOptions): ICU4XDateTimeFormatResult;
+  format_datetime(value: ICU4XGregorianDateTime): string;
+}
+
+export class ICU4XDateTimeFormatOptions {
+  static default(): ICU4XDateTimeFormatOptions;
+  readonly date_length: ICU4XDateLength;
+  readonly time_length: ICU4XTimeLength;
+}
+
+export class ICU4XDateTimeFormatResult {
+  readonly dtf: ICU4XDateTimeFormat;
+  readonly success: boolean;
+}
+
+export class ICU4XFixedDecimal {
+  static create(v: number): ICU4XFixedDecimal;
+  static create_fromstr(v: string): ICU4XCreateFixedDecimalResult;
+  multiply_pow10(power: number): boolean;
+  negate(): void;
+  to_string(): string;
+}
+
+export class ICU4XFixedDecimalFormat {
+  static try_new(locale: ICU4XLocale, provider: ICU4XDataProvider, options: ICU4XFixedDecimalFormatOptions): ICU4XFixedDecimalFormatResult;
+  static try_new_from_static(locale: ICU4XLocale, provider: ICU4XStaticDataProvider, options: ICU4XFixedDecimalFormatOptions): ICU4XFixedDecimalFormatResult;
+  format(value: ICU4XFixedDecimal): string;
+}
+
+export type ICU4XFixedDecimalGroupingStrategy = "Auto" | "Never" | "Always" | "Min2";
+
+export class ICU4XFixedDecimalFormatOptions {
+  static default(): ICU4XFixedDecimalFormatOptions;
+  readonly grouping_strategy: ICU4XFixedDecimalGroupingStrategy;
+  readonly sign_display: ICU4XFixedDecimalSignDisplay;
+}
+
+export class ICU4XFixedDecimalFormatResult {
+  readonly fdf: ICU4XFixedDecimalFormat;
+  readonly success: boolean;
+}
+
+export type ICU4XFixedDecimalSignDisplay = "Auto" | "Never" | "Always" | "ExceptZero" | "Negative";
+
+export class ICU4XGregorianDateTime {
+  static try_new(year: number, month: number, day: number, hour: number, minute: number, second: number): ICU4XCreateGregorianDateTimeResult;
+}
+
+export class ICU4XLocale {
+  static create(name: string): ICU4XLocale;
+  static create_en(): ICU4XLocale;
+  static create_bn(): ICU4XLocale;
+  static und(): ICU4XLocale;
+  clone(): ICU4XLocale;
+  basename(): string;
+  get_unicode_extension(bytes: string): string;
+  language(): string;
+  set_language(bytes: string): void;
+  region(): string;
+  set_region(bytes: string): void;
+  script(): string;
+  set_script(bytes: string): void;
+  tostring(): string;
+}
+
+export class ICU4XLocaleCanonicalizer {
+  static create(provider: ICU4XDataProvider): ICU4XLocaleCanonicalizer;
+  canonicalize(locale: ICU4XLocale): ICU4XCanonicalizationResult;
+  maximize(locale: ICU4XLocale): ICU4XCanonicalizationResult;
+  minimize(locale: ICU4XLocale): ICU4XCanonicalizationResult;
+}
+
+export class ICU4XPluralCategories {
+  readonly zero: boolean;
+  readonly one: boolean;
+  readonly two: boolean;
+  readonly few: boolean;
+  readonly many: boolean;
+  readonly other: boolean;
+}
+
+export type ICU4XPluralCategory = "Zero" | "One" | "Two" | "Few" | "Many" | "Other";
+
+export class ICU4XPluralOperands {
+  static create(s: string): ICU4XCreatePluralOperandsResult;
+  readonly i: bigint;
+  readonly v: number;
+  readonly w: number;
+  readonly f: bigint;
+  readonly t: bigint;
+  readonly c: number;
+}
+
+export class ICU4XPluralRules {
+  static create(locale: ICU4XLocale, provider: ICU4XDataProvider, ty: ICU4XPluralRuleType): ICU4XCreatePluralRulesResult;
+  select(op: ICU4XPluralOperands): ICU4XPluralCategory;
+  categories(): ICU4XPluralCategories;
+}
+
+export type ICU4XPluralRuleType = "Cardinal" | "Ordinal";
+
+export class ICU4XStaticDataProvider {
+  static create(): ICU4XCreateStaticDataProviderResult;
+}
+
+export type ICU4XTimeLength = "Full" | "Long" | "Medium" | "Short" | "None";
+
+/**
+ * Loads an ICU4X data provider from a postcard blob: its bytes, a `Response` or the promise
+ * returned by `fetch()`, or a path or URL to it.
+ */
+export function loadDataProvider(source: Uint8Array | ArrayBuffer | Response | Promise<Response> | string): Promise<ICU4XDataProvider>;
@@ -2,10 +2,5 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-#[macro_use]
-extern crate lazy_static;
-
-mod list_formatter;
-mod patterns;
-
-pub use crate::list_formatter::ListFormatter;
+export * from "./api.mjs"
+export { loadDataProvider } from "./data-provider.mjs"
@@ -7,6 +7,7 @@ import { promises as fsPromises } from 'fs';
 
 import { ICU4XFixedDecimal, ICU4XLocale, ICU4XDataProvider, ICU4XFixedDecimalFormat, ICU4XFixedDecimalFormatOptions } from "../lib/api.mjs"
 
+import { loadDataProvider } from "../lib/data-provider.mjs"
 import { TESTDATA_POSTCARD_PATH } from "../lib/paths.mjs"
 
 test("use create_from_byte_slice to format a simple decimal", async t => {
@@ -23,6 +24,17 @@ test("use create_from_byte_slice to format a simple decimal", async t => {
   t.is(format.format(decimal), "১২.৩৪");
 });
 
+test("use loadDataProvider to format a simple decimal", async t => {
+  const locale = ICU4XLocale.create("bn");
+  const provider = await loadDataProvider(TESTDATA_POSTCARD_PATH);
+  const format = ICU4XFixedDecimalFormat.try_new(locale, provider, ICU4XFixedDecimalFormatOptions.default()).fdf;
+
+  const decimal = ICU4XFixedDecimal.create(1234);
+  decimal.multiply_pow10(-2);
+
+  t.is(format.format(decimal), "১২.৩৪");
+});
+
 test("fail to create from invalid buffer", t => {
   const arrayBuffer = new ArrayBuffer(8);
   const bytes = new Uint8Array(arrayBuffer);
@@ -33,3 +45,8 @@ test("fail to create from invalid buffer", t => {
   const result = ICU4XDataProvider.create_from_byte_slice(bytes);
   t.assert(!result.success);
 });
+
+test("reject loading an invalid buffer", async t => {
+  const bytes = new Uint8Array([0, 1, 2, 3, 4, 5, 6, 7]);
+  await t.throwsAsync(loadDataProvider(bytes));
+});
@@ -0,0 +1,29 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import test from 'ava';
+
+import { ICU4XDataProvider, ICU4XDateTimeFormat, ICU4XGregorianDateTime, ICU4XLocale } from "../lib/api.mjs"
+
+const locale = ICU4XLocale.create("en");
+const dataProvider = ICU4XDataProvider.create_static().provider;
+const datetime = ICU4XGregorianDateTime.try_new(2021, 7, 15, 13, 45, 0).datetime;
+
+test("format a date and time", t => {
+  const options = { date_length: "Medium", time_length: "Short" };
+  const format = ICU4XDateTimeFormat.try_new(locale, dataProvider, options).dtf;
+
+  t.is(format.format_datetime(datetime), "Jul 15, 2021, 1:45 PM");
+});
+
+test("format a date without a time", t => {
+  const options = { date_length: "Full", time_length: "None" };
+  const format = ICU4XDateTimeFormat.try_new(locale, dataProvider, options).dtf;
+
+  t.is(format.format_datetime(datetime), "Thursday, July 15, 2021");
+});
+
+test("fail to create a date out of range", t => {
+  t.assert(!ICU4XGregorianDateTime.try_new(2021, 13, 1, 0, 0, 0).success);
+});
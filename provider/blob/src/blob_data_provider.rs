@@ -4,8 +4,10 @@
 
 use crate::blob_schema::BlobSchema;
 use crate::path_util;
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::String;
+use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_provider::serde::{SerdeDeDataProvider, SerdeDeDataReceiver};
 use serde::de::Deserialize;
@@ -67,26 +69,101 @@ pub struct BlobDataProvider {
 
 impl BlobDataProvider {
     /// Create a [`BlobDataProvider`] from an `Rc` blob of ICU4X data.
+    ///
+    /// Returns [`DataError::MismatchedSchemaVersion`] if the blob was built for a different
+    /// version of the data structs.
     pub fn new_from_rc_blob(blob: Rc<[u8]>) -> Result<Self, DataError> {
-        Ok(BlobDataProvider {
-            blob: Yoke::try_attach_to_cart_badly(blob, |bytes| {
+        let blob: Yoke<BlobSchema<'static>, Rc<[u8]>> =
+            Yoke::try_attach_to_cart_badly(blob, |bytes| {
                 BlobSchema::deserialize(&mut postcard::Deserializer::from_bytes(bytes))
             })
-            .map_err(DataError::new_resc_error)?,
+            .map_err(DataError::new_resc_error)?;
+        blob.get().check_schema_version()?;
+        Ok(BlobDataProvider { blob })
+    }
+
+    /// Returns the resource keys in the blob, in sorted order.
+    ///
+    /// Returns `None` if the blob was generated without a manifest of keys and locales.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_blob::BlobDataProvider;
+    /// use std::rc::Rc;
+    ///
+    /// let blob = std::fs::read(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.postcard",
+    /// ))
+    /// .expect("File should exist");
+    /// let provider = BlobDataProvider::new_from_rc_blob(Rc::from(blob))
+    ///     .expect("Deserialization should succeed");
+    ///
+    /// let keys: Vec<&str> = provider.keys().expect("Blob has a manifest").collect();
+    /// assert_eq!(keys, vec!["core/helloworld@1"]);
+    ///
+    /// let locales: Vec<&str> = provider.locales().expect("Blob has a manifest").collect();
+    /// assert!(locales.contains(&"la"));
+    /// ```
+    pub fn keys(&self) -> Option<impl Iterator<Item = &str> + '_> {
+        self.blob.get().keys().map(|keys| keys.iter())
+    }
+
+    /// Returns the locales in the blob, in sorted order.
+    ///
+    /// Returns `None` if the blob was generated without a manifest of keys and locales.
+    pub fn locales(&self) -> Option<impl Iterator<Item = &str> + '_> {
+        self.blob.get().locales().map(|locales| locales.iter())
+    }
+
+    /// Returns the resource paths in the blob, such as `"/core/helloworld@1/la"`, along with
+    /// their postcard-serialized data structs, in sorted order.
+    ///
+    /// This is useful for comparing the contents of two blobs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_blob::BlobDataProvider;
+    /// use std::rc::Rc;
+    ///
+    /// let blob = std::fs::read(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.postcard",
+    /// ))
+    /// .expect("File should exist");
+    /// let provider = BlobDataProvider::new_from_rc_blob(Rc::from(blob))
+    ///     .expect("Deserialization should succeed");
+    ///
+    /// let (path, resource) = provider
+    ///     .resources()
+    ///     .find(|(path, _)| path.ends_with("/la"))
+    ///     .expect("Resource should exist");
+    /// assert_eq!(path, "/core/helloworld@1/la");
+    /// assert!(!resource.is_empty());
+    /// ```
+    pub fn resources(&self) -> impl Iterator<Item = (&str, &[u8])> + '_ {
+        let blob = self.blob.get();
+        blob.resource_paths().map(move |path| {
+            let resource = blob
+                .get_resource(path)
+                .expect("Every resource path has a resource");
+            (path, resource)
         })
     }
 
     /// Gets the buffer for the given DataRequest out of the BlobSchema and returns it yoked
     /// to the buffer backing the BlobSchema.
-    fn get_file(&self, req: &DataRequest) -> Result<Yoke<&'static [u8], Rc<[u8]>>, DataError> {
+    pub(crate) fn get_file(
+        &self,
+        req: &DataRequest,
+    ) -> Result<Yoke<&'static [u8], Rc<[u8]>>, DataError> {
         let path = path_util::resource_path_to_string(&req.resource_path);
         self.blob
             .try_project_cloned_with_capture::<&'static [u8], String, ()>(
                 path,
-                move |blob, path, _| {
-                    let BlobSchema::V001(blob) = blob;
-                    blob.resources.get(&*path).ok_or(()).map(|v| *v)
-                },
+                move |blob, path, _| blob.get_resource(&path).ok_or(()),
             )
             .map_err(|_| DataError::MissingResourceKey(req.resource_path.key))
     }
@@ -112,6 +189,7 @@ where
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(payload),
         })
@@ -131,6 +209,18 @@ impl SerdeDeDataProvider for BlobDataProvider {
         })?;
         Ok(DataResponseMetadata {
             data_langid: req.resource_path.options.langid.clone(),
+            ..Default::default()
         })
     }
 }
+
+impl IterableDataProviderCore for BlobDataProvider {
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        let options =
+            path_util::supported_options_for_key(self.blob.get().resource_paths(), resc_key)?;
+        Ok(Box::new(options.into_iter()))
+    }
+}
@@ -2,19 +2,194 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use litemap::LiteMap;
+use alloc::boxed::Box;
+use icu_provider::prelude::*;
+use zerovec::map::ZeroVecLike;
+use zerovec::ule::AsULE;
+use zerovec::{VarZeroVec, ZeroMap};
 
 /// A versioned Serde schema for ICU4X data blobs.
+///
+/// A blob is this enum serialized with [`postcard`]. The variant index is written first, as a
+/// varint, and serves as the version header of the blob: readers match on it to decode the rest
+/// of the blob, and new versions are only ever appended. Readers support all versions listed
+/// here.
+///
+/// [`postcard`]: https://docs.rs/postcard
 #[derive(serde::Serialize, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
 pub enum BlobSchema<'data> {
     #[serde(borrow)]
     V001(BlobSchemaV1<'data>),
+    #[serde(borrow)]
+    V002(BlobSchemaV2<'data>),
+    #[serde(borrow)]
+    V003(BlobSchemaV3<'data>),
+    #[serde(borrow)]
+    V004(BlobSchemaV4<'data>),
+}
+
+impl<'data> BlobSchema<'data> {
+    /// Returns the postcard-serialized data struct for the given resource path, borrowed from
+    /// the buffer the blob was deserialized from.
+    pub fn get_resource(&self, path: &str) -> Option<&'data [u8]> {
+        match self {
+            BlobSchema::V001(blob) => blob.resources.get_borrowed(path),
+            BlobSchema::V002(blob) => blob.resources.get_borrowed(path),
+            BlobSchema::V003(blob) => blob.resources.get_borrowed(path),
+            BlobSchema::V004(blob) => blob
+                .resources
+                .get(path)
+                .map(u32::from_unaligned)
+                .and_then(|index| blob.buffers.get_borrowed(index as usize)),
+        }
+    }
+
+    /// Returns the resource paths in the blob, in sorted order.
+    pub fn resource_paths(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match self {
+            BlobSchema::V001(blob) => Box::new(blob.resources.iter_keys()),
+            BlobSchema::V002(blob) => Box::new(blob.resources.iter_keys()),
+            BlobSchema::V003(blob) => Box::new(blob.resources.iter_keys()),
+            BlobSchema::V004(blob) => Box::new(blob.resources.iter_keys()),
+        }
+    }
+
+    /// Returns the sorted list of resource keys in the blob, if the blob has a manifest.
+    pub fn keys(&self) -> Option<&VarZeroVec<'data, str>> {
+        match self {
+            BlobSchema::V001(_) => None,
+            BlobSchema::V002(blob) => Some(&blob.keys),
+            BlobSchema::V003(blob) => Some(&blob.keys),
+            BlobSchema::V004(blob) => Some(&blob.keys),
+        }
+    }
+
+    /// Returns the sorted list of locales in the blob, if the blob has a manifest.
+    pub fn locales(&self) -> Option<&VarZeroVec<'data, str>> {
+        match self {
+            BlobSchema::V001(_) => None,
+            BlobSchema::V002(blob) => Some(&blob.locales),
+            BlobSchema::V003(blob) => Some(&blob.locales),
+            BlobSchema::V004(blob) => Some(&blob.locales),
+        }
+    }
+
+    /// Returns the version of the data structs in the blob, if the blob records it.
+    pub fn schema_version(&self) -> Option<&str> {
+        match self {
+            BlobSchema::V001(_) | BlobSchema::V002(_) => None,
+            BlobSchema::V003(blob) => Some(blob.schema_version),
+            BlobSchema::V004(blob) => Some(blob.schema_version),
+        }
+    }
+
+    /// Checks that the data structs in the blob can be loaded by this release of ICU4X.
+    ///
+    /// Blobs that do not record a schema version are not checked.
+    pub fn check_schema_version(&self) -> Result<(), DataError> {
+        match self.schema_version() {
+            Some(schema_version) => icu_provider::schema::check_schema_version(schema_version),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Version 1 of the ICU4X data blob schema.
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(serde::Serialize, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
 pub struct BlobSchemaV1<'data> {
-    // TODO(#829): Use ZeroMap instead of LiteMap.
+    /// Map from resource path to the postcard-serialized data struct.
+    #[serde(borrow)]
+    pub resources: ZeroMap<'data, str, [u8]>,
+}
+
+/// Version 2 of the ICU4X data blob schema.
+///
+/// In addition to the resources, this version contains a manifest of the keys and locales in
+/// the blob.
+#[derive(serde::Serialize, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+pub struct BlobSchemaV2<'data> {
+    /// Sorted list of the resource keys in the blob, such as `"decimal/symbols@1"`.
+    #[serde(borrow)]
+    pub keys: VarZeroVec<'data, str>,
+    /// Sorted list of the locales in the blob, such as `"en-US"`.
+    #[serde(borrow)]
+    pub locales: VarZeroVec<'data, str>,
+    /// Map from resource path to the postcard-serialized data struct.
     #[serde(borrow)]
-    pub resources: LiteMap<&'data str, &'data [u8]>,
+    pub resources: ZeroMap<'data, str, [u8]>,
+}
+
+/// Version 3 of the ICU4X data blob schema.
+///
+/// In addition to the contents of version 2, this version records the version of the data
+/// structs, which is checked when the blob is loaded.
+#[derive(serde::Serialize, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+pub struct BlobSchemaV3<'data> {
+    /// The version of the data structs, see [`icu_provider::schema`].
+    pub schema_version: &'data str,
+    /// Sorted list of the resource keys in the blob, such as `"decimal/symbols@1"`.
+    #[serde(borrow)]
+    pub keys: VarZeroVec<'data, str>,
+    /// Sorted list of the locales in the blob, such as `"en-US"`.
+    #[serde(borrow)]
+    pub locales: VarZeroVec<'data, str>,
+    /// Map from resource path to the postcard-serialized data struct.
+    #[serde(borrow)]
+    pub resources: ZeroMap<'data, str, [u8]>,
+}
+
+/// Version 4 of the ICU4X data blob schema.
+///
+/// In addition to the contents of version 3, this version stores each distinct data struct only
+/// once. Resource paths with identical data, such as regional variants of a language sharing
+/// the same symbols, refer to the same buffer.
+#[derive(serde::Serialize, serde::Deserialize, yoke::Yokeable)]
+#[yoke(prove_covariance_manually)]
+pub struct BlobSchemaV4<'data> {
+    /// The version of the data structs, see [`icu_provider::schema`].
+    pub schema_version: &'data str,
+    /// Sorted list of the resource keys in the blob, such as `"decimal/symbols@1"`.
+    #[serde(borrow)]
+    pub keys: VarZeroVec<'data, str>,
+    /// Sorted list of the locales in the blob, such as `"en-US"`.
+    #[serde(borrow)]
+    pub locales: VarZeroVec<'data, str>,
+    /// Map from resource path to the index of its data struct in `buffers`.
+    #[serde(borrow)]
+    pub resources: ZeroMap<'data, str, u32>,
+    /// The distinct postcard-serialized data structs.
+    #[serde(borrow)]
+    pub buffers: VarZeroVec<'data, [u8]>,
+}
+
+#[test]
+fn test_v003() {
+    use crate::StaticDataProvider;
+    use icu_locid_macros::langid;
+    use icu_provider::hello_world::*;
+    use serde::Deserialize;
+
+    // A blob that was exported before the data structs were deduplicated
+    let blob: &'static [u8] = include_bytes!("../tests/data/hello_world_v3.postcard");
+    assert!(matches!(
+        BlobSchema::deserialize(&mut postcard::Deserializer::from_bytes(blob)),
+        Ok(BlobSchema::V003(_))
+    ));
+
+    let provider = StaticDataProvider::new_from_static_blob(blob).expect("Blob should load");
+    let payload: DataPayload<HelloWorldV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::HELLO_WORLD_V1,
+                options: langid!("la").into(),
+            },
+        })
+        .expect("Data should be valid")
+        .take_payload()
+        .expect("Data should be present");
+    assert_eq!("Ave, munde", payload.get().message);
 }
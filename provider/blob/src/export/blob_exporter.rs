@@ -8,11 +8,20 @@ use icu_provider::export::DataExporter;
 use icu_provider::prelude::*;
 use icu_provider::serde::SerdeSeDataStructMarker;
 use litemap::LiteMap;
+use std::collections::BTreeSet;
+use writeable::Writeable;
+use zerovec::{VarZeroVec, ZeroMap};
 
 /// A data exporter that writes data to a single-file blob.
 /// See the module-level docs for an example.
+///
+/// The blob contains a manifest of the exported keys and locales and the version of the data
+/// structs in addition to the data. Identical data structs, such as the symbols shared by many
+/// regional variants of a language, are only stored once.
 pub struct BlobExporter<'w> {
     resources: LiteMap<String, Vec<u8>>,
+    keys: BTreeSet<String>,
+    locales: BTreeSet<String>,
     sink: Box<dyn std::io::Write + 'w>,
 }
 
@@ -21,11 +30,26 @@ impl<'w> BlobExporter<'w> {
     pub fn new_with_sink(sink: Box<dyn std::io::Write + 'w>) -> Self {
         Self {
             resources: LiteMap::new(),
+            keys: BTreeSet::new(),
+            locales: BTreeSet::new(),
             sink,
         }
     }
 }
 
+impl BlobExporter<'_> {
+    /// Adds a postcard-serialized data struct to the blob.
+    pub(crate) fn put_buffer(&mut self, req: DataRequest, buffer: Vec<u8>) {
+        let path = path_util::resource_path_to_string(&req.resource_path);
+        log::trace!("Adding: {}", path);
+        self.resources.insert(path, buffer);
+        self.keys.insert(req.resource_path.key.write_to_string());
+        if let Some(langid) = &req.resource_path.options.langid {
+            self.locales.insert(langid.to_string());
+        }
+    }
+}
+
 impl Drop for BlobExporter<'_> {
     fn drop(&mut self) {
         if !self.resources.is_empty() {
@@ -49,30 +73,95 @@ impl<'data> DataExporter<'data, SerdeSeDataStructMarker> for BlobExporter<'_> {
         req: DataRequest,
         obj: DataPayload<'data, SerdeSeDataStructMarker>,
     ) -> Result<(), DataError> {
-        let path = path_util::resource_path_to_string(&req.resource_path);
-        log::trace!("Adding: {}", path);
         let buffer = serialize(obj.get().as_serialize())?;
-        self.resources.insert(path, buffer);
+        self.put_buffer(req, buffer);
         Ok(())
     }
 
     fn close(&mut self) -> Result<(), DataError> {
-        // Convert from LiteMap<String, Vec> to LiteMap<&str, &[]>
-        let mut schema = BlobSchemaV1 {
-            resources: LiteMap::with_capacity(self.resources.len()),
+        // Convert from LiteMap<String, Vec> to ZeroMap<str, u32> and VarZeroVec<[u8]>,
+        // storing each distinct buffer only once
+        let keys: Vec<&str> = self.keys.iter().map(String::as_str).collect();
+        let locales: Vec<&str> = self.locales.iter().map(String::as_str).collect();
+        let buffers: Vec<&[u8]> = self
+            .resources
+            .iter_values()
+            .map(Vec::as_slice)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        log::info!(
+            "Deduplicated {} resources into {} buffers",
+            self.resources.len(),
+            buffers.len()
+        );
+        let mut schema = BlobSchemaV4 {
+            schema_version: icu_provider::schema::DATA_SCHEMA_VERSION,
+            keys: VarZeroVec::from(keys.as_slice()),
+            locales: VarZeroVec::from(locales.as_slice()),
+            resources: ZeroMap::with_capacity(self.resources.len()),
+            buffers: VarZeroVec::from(buffers.as_slice()),
         };
         for (k, v) in self.resources.iter() {
+            let index = buffers
+                .binary_search(&v.as_slice())
+                .expect("All buffers were collected") as u32;
             schema
                 .resources
-                .try_append(k, v)
+                .try_append(k.as_str(), &index)
                 .ok_or(())
                 .expect_err("Same order");
         }
-        let blob = BlobSchema::V001(schema);
+        let blob = BlobSchema::V004(schema);
         log::info!("Serializing blob to output stream...");
         let vec = serialize(&blob)?;
         self.sink.write(&vec).map_err(|e| e.to_string())?;
         self.resources.clear();
+        self.keys.clear();
+        self.locales.clear();
         Ok(())
     }
 }
+
+#[test]
+fn test_deduplication() {
+    use icu_locid::LanguageIdentifier;
+    use icu_locid_macros::langid;
+    use icu_provider::hello_world::key;
+    use serde::Deserialize;
+    use zerovec::ule::AsULE;
+
+    let request = |langid: LanguageIdentifier| DataRequest {
+        resource_path: ResourcePath {
+            key: key::HELLO_WORLD_V1,
+            options: langid.into(),
+        },
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    {
+        let mut exporter = BlobExporter::new_with_sink(Box::new(&mut buffer));
+        exporter.put_buffer(request(langid!("de")), b"Hallo Welt".to_vec());
+        exporter.put_buffer(request(langid!("en")), b"Hello World".to_vec());
+        exporter.put_buffer(request(langid!("en-GB")), b"Hello World".to_vec());
+        exporter
+            .close()
+            .expect("Should successfully dump to buffer");
+    }
+
+    let blob = BlobSchema::deserialize(&mut postcard::Deserializer::from_bytes(&buffer))
+        .expect("Blob should deserialize");
+    let blob = match blob {
+        BlobSchema::V004(blob) => blob,
+        _ => panic!("Blobs should be exported with the latest schema"),
+    };
+    let index = |langid: LanguageIdentifier| {
+        let path = path_util::resource_path_to_string(&request(langid).resource_path);
+        blob.resources
+            .get(&*path)
+            .map(u32::from_unaligned)
+            .expect("Resource should be present")
+    };
+    assert_eq!(blob.buffers.len(), 2);
+    assert_eq!(index(langid!("en")), index(langid!("en-GB")));
+    assert_ne!(index(langid!("de")), index(langid!("en")));
+}
@@ -9,6 +9,10 @@
 //!
 //! For a command-line user interface, see the `icu_datagen` crate.
 //!
+//! To make a smaller blob with some of the keys and locales of an existing blob, such as an
+//! official release of ICU4X data, use [`slice_blob()`]. This does not require the sources the
+//! blob was generated from.
+//!
 //! # Examples
 //!
 //! ```
@@ -48,5 +52,7 @@
 //! ```
 
 mod blob_exporter;
+mod slice;
 
 pub use blob_exporter::BlobExporter;
+pub use slice::slice_blob;
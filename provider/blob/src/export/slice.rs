@@ -0,0 +1,91 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::BlobExporter;
+use crate::BlobDataProvider;
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+
+/// Copies the data of the given keys from an existing blob to a [`BlobExporter`], keeping only
+/// the given locales. Returns the number of resources that were copied.
+///
+/// The data is copied without deserializing it, so the data structs of the keys do not need to
+/// be known. Resources that do not depend on the locale are always kept, and all locales are
+/// kept if `locales` is `None`. Keys that are not in the blob are skipped.
+///
+/// The locales that the kept locales fall back to, such as `en` for `en-GB`, are not added; to
+/// keep them, include them in `locales`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_provider::export::DataExporter;
+/// use icu_provider::hello_world::key;
+/// use icu_provider_blob::export::{slice_blob, BlobExporter};
+/// use icu_provider_blob::BlobDataProvider;
+/// use std::rc::Rc;
+///
+/// let blob = std::fs::read(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/tests/data/hello_world.postcard",
+/// ))
+/// .expect("File should exist");
+/// let source = BlobDataProvider::new_from_rc_blob(Rc::from(blob))
+///     .expect("Deserialization should succeed");
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// {
+///     let mut exporter = BlobExporter::new_with_sink(Box::new(&mut buffer));
+///     let count = slice_blob(
+///         &source,
+///         &[key::HELLO_WORLD_V1],
+///         Some(&[langid!("de"), langid!("ru")]),
+///         &mut exporter,
+///     )
+///     .expect("Slicing should succeed");
+///     assert_eq!(count, 2);
+///     exporter.close().expect("Should successfully dump to buffer");
+/// }
+///
+/// let provider = BlobDataProvider::new_from_rc_blob(Rc::from(buffer))
+///     .expect("Deserialization should succeed");
+/// let locales: Vec<&str> = provider.locales().expect("Blob has a manifest").collect();
+/// assert_eq!(locales, vec!["de", "ru"]);
+/// ```
+pub fn slice_blob(
+    source: &BlobDataProvider,
+    keys: &[ResourceKey],
+    locales: Option<&[LanguageIdentifier]>,
+    exporter: &mut BlobExporter,
+) -> Result<usize, DataError> {
+    let mut count = 0;
+    for key in keys {
+        let all_options = match source.supported_options_for_key(key) {
+            Ok(all_options) => all_options,
+            Err(DataError::MissingResourceKey(_)) => {
+                log::warn!("Skipping key not in the blob: {}", key);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        for options in all_options {
+            let selected = match (&options.langid, locales) {
+                (Some(langid), Some(locales)) => locales.contains(langid),
+                _ => true,
+            };
+            if !selected {
+                continue;
+            }
+            let req = DataRequest {
+                resource_path: ResourcePath { key: *key, options },
+            };
+            let buffer = source.get_file(&req)?.get().to_vec();
+            exporter.put_buffer(req, buffer);
+            count += 1;
+        }
+    }
+    Ok(count)
+}
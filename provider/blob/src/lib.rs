@@ -10,6 +10,20 @@
 //! 1. [`BlobDataProvider`] supports data blobs loaded dynamically at runtime.
 //! 2. [`StaticDataProvider`] supports data blobs baked into the binary at compile time.
 //!
+//! A blob is a single [`postcard`] buffer containing the data for all resource paths. The paths
+//! and the data are stored in zero-copy structures such as [`ZeroMap`], so loading data from a
+//! blob does not copy it. Resource paths with identical data, which is common among regional
+//! variants of a language, share a single copy of it.
+//!
+//! The blob starts with a version header, so that the layout can evolve while older blobs remain
+//! readable. Blobs generated by current versions of [`icu4x-datagen`] also contain a manifest of
+//! the keys and locales they cover, which can be read with [`BlobDataProvider::keys`] and
+//! [`BlobDataProvider::locales`]. Both providers also implement [`IterableDataProviderCore`],
+//! which lists the supported options for each key.
+//!
+//! Blobs also record the version of the data structs they were built with. Loading a blob built
+//! by an incompatible release of ICU4X fails with [`DataError::MismatchedSchemaVersion`].
+//!
 //! To build blob data, use the `--format blob` option of [`icu4x-datagen`]. For example, to build
 //! "hello world" data, run:
 //!
@@ -33,6 +47,10 @@
 //!
 //! [`ICU4X`]: ../icu/index.html
 //! [`DataProvider`]: icu_provider::prelude::DataProvider
+//! [`DataError::MismatchedSchemaVersion`]: icu_provider::DataError::MismatchedSchemaVersion
+//! [`IterableDataProviderCore`]: icu_provider::iter::IterableDataProviderCore
+//! [`postcard`]: https://docs.rs/postcard
+//! [`ZeroMap`]: zerovec::ZeroMap
 //! [`icu4x-datagen`]: https://github.com/unicode-org/icu4x/tree/main/tools/datagen#readme
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::string::String;
+use alloc::vec::Vec;
 use icu_provider::prelude::*;
 use writeable::Writeable;
 
@@ -14,3 +15,35 @@ pub fn resource_path_to_string(resource_path: &ResourcePath) -> String {
         .expect("impl Write for String is infallible");
     output
 }
+
+/// Collects the [`ResourceOptions`] of all resource paths belonging to the given key.
+///
+/// Returns [`DataError::MissingResourceKey`] if no path belongs to the key.
+pub fn supported_options_for_key<'a>(
+    paths: impl Iterator<Item = &'a str>,
+    resc_key: &ResourceKey,
+) -> Result<Vec<ResourceOptions>, DataError> {
+    let prefix = resource_path_to_string(&ResourcePath {
+        key: *resc_key,
+        options: ResourceOptions::default(),
+    });
+    let mut result = Vec::new();
+    for path in paths {
+        let options_str = match path.strip_prefix(&*prefix) {
+            Some("") => "",
+            Some(suffix) => match suffix.strip_prefix('/') {
+                Some(options_str) => options_str,
+                None => continue,
+            },
+            None => continue,
+        };
+        let components = options_str.split('/').filter(|s| !s.is_empty());
+        result.push(
+            ResourceOptions::try_from_components(components).map_err(DataError::new_resc_error)?,
+        );
+    }
+    if result.is_empty() {
+        return Err(DataError::MissingResourceKey(*resc_key));
+    }
+    Ok(result)
+}
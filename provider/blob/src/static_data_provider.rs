@@ -4,6 +4,8 @@
 
 use crate::blob_schema::BlobSchema;
 use crate::path_util;
+use alloc::boxed::Box;
+use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_provider::serde::{SerdeDeDataProvider, SerdeDeDataReceiver};
 use serde::de::Deserialize;
@@ -49,6 +51,32 @@ use serde::de::Deserialize;
 /// assert_eq!(response.get().message, "Ave, munde");
 /// ```
 ///
+/// List the locales supported for a key, for example to populate a language picker:
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::iter::IterableDataProviderCore;
+/// use icu_provider_blob::StaticDataProvider;
+/// use icu_locid_macros::langid;
+///
+/// const HELLO_WORLD_BLOB: &[u8] = include_bytes!(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/tests/data/hello_world.postcard"
+/// ));
+///
+/// let provider = StaticDataProvider::new_from_static_blob(&HELLO_WORLD_BLOB)
+///     .expect("Deserialization should succeed");
+///
+/// let langids: Vec<_> = provider
+///     .supported_options_for_key(&key::HELLO_WORLD_V1)
+///     .expect("Key should be supported")
+///     .filter_map(|options| options.langid)
+///     .collect();
+///
+/// assert!(langids.contains(&langid!("la")));
+/// ```
+///
 /// [`BlobDataProvider`]: crate::BlobDataProvider
 pub struct StaticDataProvider {
     blob: BlobSchema<'static>,
@@ -56,20 +84,36 @@ pub struct StaticDataProvider {
 
 impl StaticDataProvider {
     /// Create a [`StaticDataProvider`] from a `'static` blob of ICU4X data.
+    ///
+    /// Returns [`DataError::MismatchedSchemaVersion`] if the blob was built for a different
+    /// version of the data structs.
     pub fn new_from_static_blob(blob: &'static [u8]) -> Result<Self, DataError> {
-        Ok(StaticDataProvider {
-            blob: BlobSchema::deserialize(&mut postcard::Deserializer::from_bytes(blob))
-                .map_err(DataError::new_resc_error)?,
-        })
+        let blob = BlobSchema::deserialize(&mut postcard::Deserializer::from_bytes(blob))
+            .map_err(DataError::new_resc_error)?;
+        blob.check_schema_version()?;
+        Ok(StaticDataProvider { blob })
+    }
+
+    /// Returns the resource keys in the blob, in sorted order.
+    ///
+    /// Returns `None` if the blob was generated without a manifest of keys and locales.
+    pub fn keys(&self) -> Option<impl Iterator<Item = &str> + '_> {
+        self.blob.keys().map(|keys| keys.iter())
+    }
+
+    /// Returns the locales in the blob, in sorted order.
+    ///
+    /// Returns `None` if the blob was generated without a manifest of keys and locales.
+    pub fn locales(&self) -> Option<impl Iterator<Item = &str> + '_> {
+        self.blob.locales().map(|locales| locales.iter())
     }
 
     fn get_file(&self, req: &DataRequest) -> Result<&'static [u8], DataError> {
         let path = path_util::resource_path_to_string(&req.resource_path);
-        let BlobSchema::V001(blob) = &self.blob;
-        blob.resources
-            .get(&*path)
+        // The blob is deserialized from a static buffer, so its resources are borrowed.
+        self.blob
+            .get_resource(&path)
             .ok_or(DataError::MissingResourceKey(req.resource_path.key))
-            .map(|v| *v)
     }
 }
 
@@ -86,6 +130,7 @@ where
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(data)),
         })
@@ -105,6 +150,17 @@ impl SerdeDeDataProvider for StaticDataProvider {
 
         Ok(DataResponseMetadata {
             data_langid: req.resource_path.options.langid.clone(),
+            ..Default::default()
         })
     }
 }
+
+impl IterableDataProviderCore for StaticDataProvider {
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        let options = path_util::supported_options_for_key(self.blob.resource_paths(), resc_key)?;
+        Ok(Box::new(options.into_iter()))
+    }
+}
@@ -20,6 +20,23 @@ pub trait CldrPaths: std::fmt::Debug {
     /// Path to checkout of cldr-numbers:
     /// <https://github.com/unicode-cldr/cldr-numbers-full>
     fn cldr_numbers(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of cldr-localenames:
+    /// <https://github.com/unicode-cldr/cldr-localenames-full>
+    fn cldr_localenames(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of cldr-misc:
+    /// <https://github.com/unicode-cldr/cldr-misc-full>
+    fn cldr_misc(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of cldr-units:
+    /// <https://github.com/unicode-cldr/cldr-units-full>
+    fn cldr_units(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of the cldr-cal package of a non-Gregorian calendar, such as
+    /// cldr-cal-japanese for the "japanese" calendar:
+    /// <https://github.com/unicode-cldr/cldr-cal-japanese-full>
+    fn cldr_cal(&self, calendar: &str) -> Result<PathBuf, Error>;
 }
 
 /// An implementation of [`CldrPaths`] for multiple separate local CLDR JSON directories per
@@ -44,6 +61,14 @@ pub struct CldrPathsLocal {
     pub cldr_core: Result<PathBuf, MissingSourceError>,
     pub cldr_dates: Result<PathBuf, MissingSourceError>,
     pub cldr_numbers: Result<PathBuf, MissingSourceError>,
+    pub cldr_localenames: Result<PathBuf, MissingSourceError>,
+    pub cldr_misc: Result<PathBuf, MissingSourceError>,
+    pub cldr_units: Result<PathBuf, MissingSourceError>,
+    pub cldr_cal_buddhist: Result<PathBuf, MissingSourceError>,
+    pub cldr_cal_coptic: Result<PathBuf, MissingSourceError>,
+    pub cldr_cal_ethiopic: Result<PathBuf, MissingSourceError>,
+    pub cldr_cal_islamic: Result<PathBuf, MissingSourceError>,
+    pub cldr_cal_japanese: Result<PathBuf, MissingSourceError>,
 }
 
 impl CldrPaths for CldrPathsLocal {
@@ -56,6 +81,26 @@ impl CldrPaths for CldrPathsLocal {
     fn cldr_numbers(&self) -> Result<PathBuf, Error> {
         self.cldr_numbers.clone().map_err(|e| e.into())
     }
+    fn cldr_localenames(&self) -> Result<PathBuf, Error> {
+        self.cldr_localenames.clone().map_err(|e| e.into())
+    }
+    fn cldr_misc(&self) -> Result<PathBuf, Error> {
+        self.cldr_misc.clone().map_err(|e| e.into())
+    }
+    fn cldr_units(&self) -> Result<PathBuf, Error> {
+        self.cldr_units.clone().map_err(|e| e.into())
+    }
+    fn cldr_cal(&self, calendar: &str) -> Result<PathBuf, Error> {
+        match calendar {
+            "buddhist" => self.cldr_cal_buddhist.clone(),
+            "coptic" => self.cldr_cal_coptic.clone(),
+            "ethiopic" => self.cldr_cal_ethiopic.clone(),
+            "islamic" => self.cldr_cal_islamic.clone(),
+            "japanese" => self.cldr_cal_japanese.clone(),
+            _ => Err(MissingSourceError { src: "cldr-cal" }),
+        }
+        .map_err(|e| e.into())
+    }
 }
 
 impl Default for CldrPathsLocal {
@@ -66,6 +111,26 @@ impl Default for CldrPathsLocal {
             cldr_numbers: Err(MissingSourceError {
                 src: "cldr-numbers",
             }),
+            cldr_localenames: Err(MissingSourceError {
+                src: "cldr-localenames",
+            }),
+            cldr_misc: Err(MissingSourceError { src: "cldr-misc" }),
+            cldr_units: Err(MissingSourceError { src: "cldr-units" }),
+            cldr_cal_buddhist: Err(MissingSourceError {
+                src: "cldr-cal-buddhist",
+            }),
+            cldr_cal_coptic: Err(MissingSourceError {
+                src: "cldr-cal-coptic",
+            }),
+            cldr_cal_ethiopic: Err(MissingSourceError {
+                src: "cldr-cal-ethiopic",
+            }),
+            cldr_cal_islamic: Err(MissingSourceError {
+                src: "cldr-cal-islamic",
+            }),
+            cldr_cal_japanese: Err(MissingSourceError {
+                src: "cldr-cal-japanese",
+            }),
         }
     }
 }
@@ -110,6 +175,30 @@ impl CldrPaths for CldrPathsAllInOne {
             .clone()
             .join(format!("cldr-numbers-{}", self.locale_subset)))
     }
+    fn cldr_localenames(&self) -> Result<PathBuf, Error> {
+        Ok(self
+            .cldr_json_root
+            .clone()
+            .join(format!("cldr-localenames-{}", self.locale_subset)))
+    }
+    fn cldr_misc(&self) -> Result<PathBuf, Error> {
+        Ok(self
+            .cldr_json_root
+            .clone()
+            .join(format!("cldr-misc-{}", self.locale_subset)))
+    }
+    fn cldr_units(&self) -> Result<PathBuf, Error> {
+        Ok(self
+            .cldr_json_root
+            .clone()
+            .join(format!("cldr-units-{}", self.locale_subset)))
+    }
+    fn cldr_cal(&self, calendar: &str) -> Result<PathBuf, Error> {
+        Ok(self
+            .cldr_json_root
+            .clone()
+            .join(format!("cldr-cal-{}-{}", calendar, self.locale_subset)))
+    }
 }
 
 #[cfg(test)]
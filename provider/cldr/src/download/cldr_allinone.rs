@@ -16,6 +16,9 @@ use std::path::PathBuf;
 ///
 /// Downloads a single zip file for all components, as used in CLDR 38 and later.
 ///
+/// Set [`sha256`](Self::sha256) to verify the downloaded file, and [`offline`](Self::offline)
+/// to only use files that have already been downloaded.
+///
 /// # Examples
 ///
 /// ```
@@ -26,8 +29,9 @@ use std::path::PathBuf;
 /// use icu_locid_macros::langid;
 /// use std::path::PathBuf;
 ///
-/// let downloader = CldrAllInOneDownloader::try_new_from_github("38.1.0", "modern")
+/// let mut downloader = CldrAllInOneDownloader::try_new_from_github("38.1.0", "modern")
 ///     .expect("Cache directory not found");
+/// downloader.offline = true;
 ///
 /// fn demo(downloader: CldrAllInOneDownloader) {
 ///     use std::borrow::Cow;
@@ -69,6 +73,14 @@ pub struct CldrAllInOneDownloader {
 
     /// CLDR JSON locale subset: "full" or "modern"
     pub locale_subset: String,
+
+    /// The expected SHA-256 checksum of the remote zip file, as a hex string. If set, the zip
+    /// file is verified before it is unpacked.
+    pub sha256: Option<String>,
+
+    /// If true, only previously downloaded files in [`Self::cache_dir`] are used, and an error
+    /// is returned if the zip file has not been downloaded yet.
+    pub offline: bool,
 }
 
 impl CldrAllInOneDownloader {
@@ -92,12 +104,21 @@ impl CldrAllInOneDownloader {
                 github_tag, github_tag, locale_subset
             ),
             locale_subset: locale_subset.to_string(),
+            sha256: None,
+            offline: false,
         })
     }
 
+    /// Downloads and unpacks the zip file, unless it is already in the cache, and returns the
+    /// paths to the unpacked CLDR JSON data.
     pub fn download(self) -> Result<CldrPathsAllInOne, Error> {
         // TODO(#297): Implement this async.
-        let downloaded = io_util::download_and_unzip(&self.url, &self.cache_dir)?;
+        let downloaded = io_util::download_and_unzip(
+            &self.url,
+            &self.cache_dir,
+            self.sha256.as_deref(),
+            self.offline,
+        )?;
         Ok(CldrPathsAllInOne {
             cldr_json_root: downloaded,
             locale_subset: self.locale_subset,
@@ -16,6 +16,14 @@ pub enum Error {
     HttpStatus(reqwest::StatusCode, String),
     #[displaydoc("dirs::cache_dir() returned None")]
     NoCacheDir,
+    #[displaydoc("Checksum mismatch for {path:?}: expected SHA-256 {expected}, found {actual}")]
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[displaydoc("Not in the cache, and downloading is disabled: {0}")]
+    Offline(String),
 }
 
 impl std::error::Error for Error {}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::error::Error;
+use sha2::{Digest, Sha256};
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
@@ -80,11 +81,57 @@ fn test_unzip_sync() -> Result<(), Error> {
     Ok(())
 }
 
+// Compute the SHA-256 checksum of a file as a lowercase hex string.
+fn sha256_file(path: &Path) -> Result<String, Error> {
+    let mut file = File::open(path).map_err(|e| (e, path))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(|e| (e, path))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[test]
+fn test_sha256_file() -> Result<(), Error> {
+    let temp_file = mktemp::Temp::new_file().map_err(|e| Error::Io(e, None))?;
+    fs::write(&temp_file, b"abc").map_err(|e| (e, &temp_file))?;
+    assert_eq!(
+        sha256_file(&temp_file)?,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    Ok(())
+}
+
+// Check that the file at path has the expected SHA-256 checksum, if one is given.
+fn verify_sha256(path: &Path, expected_sha256: Option<&str>) -> Result<(), Error> {
+    let expected = match expected_sha256 {
+        Some(expected) => expected.to_ascii_lowercase(),
+        None => return Ok(()),
+    };
+    let actual = sha256_file(path)?;
+    if actual != expected {
+        return Err(Error::ChecksumMismatch {
+            path: path.to_path_buf(),
+            expected,
+            actual,
+        });
+    }
+    log::debug!("Checksum verified: {:?}", path);
+    Ok(())
+}
+
 /// Downloads and unpacks a zip file, returning the path to the unpacked directory.
 ///
 /// `cache_dir` is a directory where both the zip file and the unpacked directory will be
 /// saved. If the zip file has already been downloaded, it will not be downloaded again.
-pub fn download_and_unzip(zip_file_url: &str, cache_dir: &Path) -> Result<PathBuf, Error> {
+///
+/// If `expected_sha256` is given, the zip file is verified against it before being unpacked,
+/// and a freshly downloaded zip file that does not match is deleted. If `offline` is true,
+/// nothing is downloaded, and an error is returned if the zip file is not in the cache.
+pub fn download_and_unzip(
+    zip_file_url: &str,
+    cache_dir: &Path,
+    expected_sha256: Option<&str>,
+    offline: bool,
+) -> Result<PathBuf, Error> {
     fs::create_dir_all(cache_dir).map_err(|e| (e, cache_dir))?;
 
     let zip_dir = cache_dir.to_path_buf().join("zips");
@@ -98,19 +145,69 @@ pub fn download_and_unzip(zip_file_url: &str, cache_dir: &Path) -> Result<PathBu
     zip_path.set_extension("zip");
     let dir_path = data_dir.join(&basename);
 
-    if !zip_path.exists() {
-        download_sync(zip_file_url, &zip_path)?;
-        log::debug!("Saved as: {:?}", &zip_path);
-    } else {
-        log::debug!("Data already downloaded: {:?}", &zip_path);
+    if dir_path.exists() {
+        log::debug!("Data already unzipped as: {:?}", &dir_path);
+        return Ok(dir_path);
     }
 
-    if !dir_path.exists() {
-        unzip_sync(&zip_path, &dir_path)?;
-        log::debug!("Unzipped as: {:?}", &dir_path);
+    if zip_path.exists() {
+        log::debug!("Data already downloaded: {:?}", &zip_path);
+        verify_sha256(&zip_path, expected_sha256)?;
+    } else if offline {
+        return Err(Error::Offline(zip_file_url.to_string()));
     } else {
-        log::debug!("Data already unzipped as: {:?}", &dir_path);
+        // Download to a temporary name so that an interrupted download is not mistaken for a
+        // cached file.
+        let partial_path = zip_path.with_extension("part");
+        download_sync(zip_file_url, &partial_path)?;
+        if let Err(e) = verify_sha256(&partial_path, expected_sha256) {
+            fs::remove_file(&partial_path).map_err(|e| (e, &partial_path))?;
+            return Err(e);
+        }
+        fs::rename(&partial_path, &zip_path).map_err(|e| (e, &zip_path))?;
+        log::debug!("Saved as: {:?}", &zip_path);
     }
 
+    // Unzip to a temporary name for the same reason.
+    let partial_dir_path = dir_path.with_extension("part");
+    if partial_dir_path.exists() {
+        fs::remove_dir_all(&partial_dir_path).map_err(|e| (e, &partial_dir_path))?;
+    }
+    unzip_sync(&zip_path, &partial_dir_path)?;
+    fs::rename(&partial_dir_path, &dir_path).map_err(|e| (e, &dir_path))?;
+    log::debug!("Unzipped as: {:?}", &dir_path);
+
     Ok(dir_path)
 }
+
+#[test]
+fn test_download_and_unzip_offline() -> Result<(), Error> {
+    let cache_dir = mktemp::Temp::new_dir().map_err(|e| Error::Io(e, None))?;
+    let url = "https://example.com/dummy.zip";
+
+    // Nothing is cached yet.
+    assert!(matches!(
+        download_and_unzip(url, &cache_dir, None, true),
+        Err(Error::Offline(_))
+    ));
+
+    // Place the zip file into the cache as if it had been downloaded.
+    let zip_dir = cache_dir.to_path_buf().join("zips");
+    fs::create_dir_all(&zip_dir).map_err(|e| (e, &zip_dir))?;
+    let mut zip_path = zip_dir.join(&*urlencoding::encode(url));
+    zip_path.set_extension("zip");
+    fs::copy("./tests/testdata/dummy.zip", &zip_path).map_err(|e| (e, &zip_path))?;
+
+    assert!(matches!(
+        download_and_unzip(url, &cache_dir, Some("00"), true),
+        Err(Error::ChecksumMismatch { .. })
+    ));
+
+    let expected_sha256 = sha256_file(&zip_path)?;
+    let dir_path = download_and_unzip(url, &cache_dir, Some(&expected_sha256), true)?;
+    assert_files_eq(
+        &PathBuf::from("./tests/testdata/dummy.pdf"),
+        &dir_path.join("dummy.pdf"),
+    );
+    Ok(())
+}
@@ -26,6 +26,7 @@ mod error;
 mod reader;
 mod support;
 
+pub mod locales;
 pub mod transform;
 
 #[cfg(feature = "download")]
@@ -37,3 +38,4 @@ pub use cldr_paths::CldrPathsLocal;
 pub use error::Error as CldrError;
 pub use transform::get_all_cldr_keys;
 pub use transform::CldrJsonDataProvider;
+pub use transform::CLDR_KEY_REGISTRY;
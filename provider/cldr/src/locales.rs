@@ -0,0 +1,226 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Selection of the locales to include in a data bundle.
+//!
+//! A data bundle is self-consistent if it contains every locale that the locales in the bundle
+//! fall back to. Use [`ParentLocales::with_ancestors()`] to add them to a list of locales, for
+//! example to include `en-001`, `en`, and `und` when `en-GB` is requested.
+//!
+//! Use [`locales_at_coverage_level()`] to select the locales that CLDR supports at a given
+//! [`CoverageLevel`].
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_locid::LanguageIdentifier;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub use icu_provider::fallback::ParentLocales;
+
+/// A CLDR coverage level, describing how complete the data of a locale is.
+///
+/// Each level includes the data of the levels below it: every locale at [`Modern`] coverage
+/// also has [`Moderate`] and [`Basic`] coverage.
+///
+/// [`Modern`]: CoverageLevel::Modern
+/// [`Moderate`]: CoverageLevel::Moderate
+/// [`Basic`]: CoverageLevel::Basic
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CoverageLevel {
+    /// Locales with the data needed for minimal support.
+    Basic,
+    /// Locales with the data needed for most common uses.
+    Moderate,
+    /// Locales with the data needed for full support of modern uses.
+    Modern,
+}
+
+impl FromStr for CoverageLevel {
+    type Err = Error;
+
+    /// Parses a coverage level as it is written in CLDR, such as `"modern"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "moderate" => Ok(Self::Moderate),
+            "modern" => Ok(Self::Modern),
+            _ => Err(Error::Custom(
+                format!("Unknown coverage level: {}", s),
+                None,
+            )),
+        }
+    }
+}
+
+/// Serde structs for the CLDR JSON coverage levels file.
+///
+/// Sample file:
+/// <https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-core/coverageLevels.json>
+mod coverage_json {
+    use super::CldrLangID;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        #[serde(rename = "effectiveCoverageLevels")]
+        pub effective_coverage_levels: BTreeMap<CldrLangID, String>,
+    }
+}
+
+/// Returns the locales that CLDR supports at the given coverage level or above, in sorted order.
+///
+/// The coverage levels are read from the `coverageLevels.json` file of cldr-core.
+pub fn locales_at_coverage_level(
+    cldr_paths: &dyn CldrPaths,
+    level: CoverageLevel,
+) -> Result<Vec<LanguageIdentifier>, Error> {
+    let path = cldr_paths.cldr_core()?.join("coverageLevels.json");
+    let data: coverage_json::Resource =
+        serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, &path))?;
+    Ok(filter_coverage_levels(data, level))
+}
+
+fn filter_coverage_levels(
+    data: coverage_json::Resource,
+    level: CoverageLevel,
+) -> Vec<LanguageIdentifier> {
+    data.effective_coverage_levels
+        .into_iter()
+        // Levels below basic, such as "core", are not supported.
+        .filter(|(_, locale_level)| {
+            locale_level
+                .parse::<CoverageLevel>()
+                .map_or(false, |locale_level| locale_level >= level)
+        })
+        .map(|(cldr_langid, _)| cldr_langid.langid)
+        .collect()
+}
+
+/// Serde structs for the CLDR JSON parent locales file.
+///
+/// Sample file:
+/// <https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-core/supplemental/parentLocales.json>
+mod parent_locales_json {
+    use super::CldrLangID;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ParentLocales {
+        #[serde(rename = "parentLocale")]
+        pub parent_locale: BTreeMap<CldrLangID, CldrLangID>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(rename = "parentLocales")]
+        pub parent_locales: ParentLocales,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+impl TryFrom<&dyn CldrPaths> for ParentLocales {
+    type Error = Error;
+
+    /// Reads the explicit parent locales from the `parentLocales.json` file of cldr-core.
+    ///
+    /// If the file is not present, as in some partial copies of CLDR, the parent locales built
+    /// into [`ParentLocales::default()`] are used.
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core()?
+            .join("supplemental")
+            .join("parentLocales.json");
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data: parent_locales_json::Resource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, &path))?;
+        Ok(Self::from(data))
+    }
+}
+
+impl From<parent_locales_json::Resource> for ParentLocales {
+    fn from(data: parent_locales_json::Resource) -> Self {
+        data.supplemental
+            .parent_locales
+            .parent_locale
+            .into_iter()
+            .map(|(child, parent)| (child.langid, parent.langid))
+            .collect()
+    }
+}
+
+#[test]
+fn test_coverage_levels() {
+    use icu_locid_macros::langid;
+
+    let json_str = r#"{
+        "effectiveCoverageLevels": {
+            "de": "modern",
+            "haw": "moderate",
+            "kl": "basic",
+            "xx": "core"
+        }
+    }"#;
+    let parse = || serde_json::from_str::<coverage_json::Resource>(json_str).unwrap();
+
+    assert_eq!(
+        filter_coverage_levels(parse(), CoverageLevel::Modern),
+        vec![langid!("de")]
+    );
+    assert_eq!(
+        filter_coverage_levels(parse(), CoverageLevel::Moderate),
+        vec![langid!("de"), langid!("haw")]
+    );
+    assert_eq!(
+        filter_coverage_levels(parse(), CoverageLevel::Basic),
+        vec![langid!("de"), langid!("haw"), langid!("kl")]
+    );
+}
+
+#[test]
+fn test_parent_locales() {
+    use icu_locid_macros::langid;
+
+    let json_str = r#"{
+        "supplemental": {
+            "parentLocales": {
+                "parentLocale": {
+                    "az-Arab": "root",
+                    "en-001": "en",
+                    "en-GB": "en-001"
+                }
+            }
+        }
+    }"#;
+    let parent_locales = ParentLocales::from(
+        serde_json::from_str::<parent_locales_json::Resource>(json_str).unwrap(),
+    );
+
+    assert_eq!(
+        parent_locales.with_ancestors(&[langid!("en-GB"), langid!("az-Arab-IR")]),
+        vec![
+            langid!("und"),
+            langid!("az-Arab"),
+            langid!("az-Arab-IR"),
+            langid!("en"),
+            langid!("en-001"),
+            langid!("en-GB"),
+        ]
+    );
+    assert_eq!(
+        parent_locales.parent(&langid!("ca-ES-valencia")),
+        Some(langid!("ca-ES"))
+    );
+    assert_eq!(parent_locales.parent(&langid!("und")), None);
+}
@@ -72,6 +72,7 @@ impl<'data> DataProvider<'data, AliasesV1Marker> for AliasesProvider<'data> {
             Ok(DataResponse {
                 metadata: DataResponseMetadata {
                     data_langid: langid.clone(),
+                    ..Default::default()
                 },
                 payload: Some(DataPayload::from_owned(AliasesV1::from(&self.data))),
             })
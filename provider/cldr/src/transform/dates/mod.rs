@@ -2,10 +2,72 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use std::borrow::Cow;
+
 pub mod patterns;
 pub mod skeletons;
 pub mod symbols;
 
+/// The non-Gregorian calendars whose patterns are read from the cldr-cal packages, by their CLDR
+/// identifier. The identifier is also the variant of the resources of the calendar.
+pub const CALENDARS: [&str; 5] = ["buddhist", "coptic", "ethiopic", "islamic", "japanese"];
+
+/// The patterns of each calendar and locale, sorted by the variant and the language identifier.
+/// The variant is [`None`] for the Gregorian calendar.
+type CalendarPatternsData = Vec<(
+    Option<Cow<'static, str>>,
+    CldrLangID,
+    cldr_json::CalendarPatterns,
+)>;
+
+/// Reads the patterns of the Gregorian calendar, and of the [`CALENDARS`] whose cldr-cal packages
+/// are available.
+fn read_calendar_patterns(cldr_paths: &dyn CldrPaths) -> Result<CalendarPatternsData, Error> {
+    let mut data = vec![];
+    read_patterns_of_calendar(&mut data, cldr_paths.cldr_dates()?, "gregorian", None)?;
+    for calendar in CALENDARS.iter() {
+        // The cldr-cal packages are optional.
+        match cldr_paths.cldr_cal(calendar) {
+            Ok(path) if path.exists() => {
+                read_patterns_of_calendar(&mut data, path, calendar, Some(calendar))?
+            }
+            _ => continue,
+        }
+    }
+    data.sort_by(|(v1, l1, _), (v2, l2, _)| (v1, l1).cmp(&(v2, l2)));
+    Ok(data)
+}
+
+fn read_patterns_of_calendar(
+    data: &mut CalendarPatternsData,
+    root: std::path::PathBuf,
+    calendar: &str,
+    variant: Option<&'static str>,
+) -> Result<(), Error> {
+    let locale_dirs = get_subdirectories(&root.join("main"))?;
+    for dir in locale_dirs {
+        let path = dir.join(format!("ca-{}.json", calendar));
+
+        let resource: cldr_json::CalendarResource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+        for (langid, lang_dates) in resource.main.0 {
+            data.extend(
+                lang_dates
+                    .dates
+                    .calendars
+                    .into_iter()
+                    .filter(|(name, _)| name == calendar)
+                    .map(|(_, patterns)| (variant.map(Cow::Borrowed), langid.clone(), patterns)),
+            );
+        }
+    }
+    Ok(())
+}
+
 /// Serde structs for the CLDR JSON dates files.
 pub(self) mod cldr_json {
     use crate::cldr_langid::CldrLangID;
@@ -103,6 +165,28 @@ pub(self) mod cldr_json {
         ["midnight", midnight, Option<Cow<'static, str>>],
     );
 
+    pub mod eras {
+        use super::*;
+
+        #[derive(Debug, PartialEq, Clone, Deserialize)]
+        pub struct Symbols {
+            #[serde(rename = "0")]
+            pub bce: String,
+            #[serde(rename = "1")]
+            pub ce: String,
+        }
+
+        #[derive(Debug, PartialEq, Clone, Deserialize)]
+        pub struct Widths {
+            #[serde(rename = "eraAbbr")]
+            pub abbreviated: Symbols,
+            #[serde(rename = "eraNarrow")]
+            pub narrow: Symbols,
+            #[serde(rename = "eraNames")]
+            pub wide: Symbols,
+        }
+    }
+
     #[derive(PartialEq, Debug, Deserialize)]
     #[serde(untagged)]
     pub enum LengthPattern {
@@ -159,12 +243,7 @@ pub(self) mod cldr_json {
         pub days: days::Contexts,
         #[serde(rename = "dayPeriods")]
         pub day_periods: day_periods::Contexts,
-        #[serde(rename = "dateFormats")]
-        pub date_formats: LengthPatterns,
-        #[serde(rename = "timeFormats")]
-        pub time_formats: LengthPatterns,
-        #[serde(rename = "dateTimeFormats")]
-        pub datetime_formats: DateTimeFormats,
+        pub eras: eras::Widths,
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
@@ -189,4 +268,40 @@ pub(self) mod cldr_json {
     pub struct Resource {
         pub main: LangData,
     }
+
+    /// This struct represents the patterns of the CLDR ca-*.json data of any calendar at the key
+    /// "main.LANGID.dates.calendars.CALENDAR", where "CALENDAR" is the calendar identifier.
+    ///
+    /// e.g.
+    /// https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-cal-japanese-full/main/en/ca-japanese.json
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CalendarPatterns {
+        #[serde(rename = "dateFormats")]
+        pub date_formats: LengthPatterns,
+        #[serde(rename = "timeFormats")]
+        pub time_formats: LengthPatterns,
+        #[serde(rename = "dateTimeFormats")]
+        pub datetime_formats: DateTimeFormats,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CalendarDates {
+        #[serde(with = "tuple_vec_map")]
+        pub calendars: Vec<(String, CalendarPatterns)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangCalendarDates {
+        pub dates: CalendarDates,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CalendarLangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangCalendarDates)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CalendarResource {
+        pub main: CalendarLangData,
+    }
 }
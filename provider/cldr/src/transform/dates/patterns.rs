@@ -2,11 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::cldr_json;
+use super::{cldr_json, read_calendar_patterns, CalendarPatternsData};
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
+use icu_datetime::options::preferences::HourCycle;
 use icu_datetime::pattern::CoarseHourCycle;
 use icu_datetime::{pattern, provider::*};
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -23,26 +23,14 @@ pub const ALL_KEYS: [ResourceKey; 1] = [
 /// A data provider reading from CLDR JSON dates files.
 #[derive(PartialEq, Debug)]
 pub struct DatePatternsProvider<'data> {
-    data: Vec<(CldrLangID, cldr_json::LangDates)>,
+    data: CalendarPatternsData,
     _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
 }
 
 impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
     type Error = Error;
     fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
-        let mut data = vec![];
-
-        let path = cldr_paths.cldr_dates()?.join("main");
-
-        let locale_dirs = get_subdirectories(&path)?;
-
-        for dir in locale_dirs {
-            let path = dir.join("ca-gregorian.json");
-
-            let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
-            data.append(&mut resource.main.0);
-        }
+        let data = read_calendar_patterns(cldr_paths)?;
 
         Ok(Self {
             data,
@@ -64,19 +52,21 @@ impl<'data> DataProvider<'data, gregory::DatePatternsV1Marker> for DatePatternsP
     ) -> Result<DataResponse<'data, gregory::DatePatternsV1Marker>, DataError> {
         DatePatternsProvider::supports_key(&req.resource_path.key)?;
         let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
-        let dates = match self
+        let variant = &req.resource_path.options.variant;
+        let patterns = match self
             .data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+            .binary_search_by(|(v, lid, _)| (v, lid).cmp(&(variant, &cldr_langid)))
         {
-            Ok(idx) => &self.data[idx].1.dates,
+            Ok(idx) => &self.data[idx].2,
             Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
         };
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(gregory::DatePatternsV1::from(
-                dates,
+                patterns,
             ))),
         })
     }
@@ -95,8 +85,8 @@ impl<'data> IterableDataProviderCore for DatePatternsProvider<'data> {
         let list: Vec<ResourceOptions> = self
             .data
             .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
+            .map(|(v, l, _)| ResourceOptions {
+                variant: v.clone(),
                 // TODO: Avoid the clone
                 langid: Some(l.langid.clone()),
             })
@@ -105,7 +95,7 @@ impl<'data> IterableDataProviderCore for DatePatternsProvider<'data> {
     }
 }
 
-impl From<&cldr_json::LengthPatterns> for gregory::patterns::LengthPatternsV1 {
+impl From<&cldr_json::LengthPatterns> for gregory::patterns::LengthPatternsV1<'static> {
     fn from(other: &cldr_json::LengthPatterns) -> Self {
         // TODO(#308): Support numbering system variations. We currently throw them away.
         Self {
@@ -117,7 +107,7 @@ impl From<&cldr_json::LengthPatterns> for gregory::patterns::LengthPatternsV1 {
     }
 }
 
-impl From<&cldr_json::DateTimeFormats> for gregory::patterns::LengthPatternsV1 {
+impl From<&cldr_json::DateTimeFormats> for gregory::patterns::LengthPatternsV1<'static> {
     fn from(other: &cldr_json::DateTimeFormats) -> Self {
         // TODO(#308): Support numbering system variations. We currently throw them away.
         Self {
@@ -129,17 +119,16 @@ impl From<&cldr_json::DateTimeFormats> for gregory::patterns::LengthPatternsV1 {
     }
 }
 
-impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
-    fn from(other: &cldr_json::Dates) -> Self {
+impl From<&cldr_json::CalendarPatterns> for gregory::DatePatternsV1<'static> {
+    fn from(other: &cldr_json::CalendarPatterns) -> Self {
         let length_combinations_v1 =
-            gregory::patterns::LengthPatternsV1::from(&other.calendars.gregorian.datetime_formats);
-        let skeletons_v1 =
-            gregory::DateSkeletonPatternsV1::from(&other.calendars.gregorian.datetime_formats);
+            gregory::patterns::LengthPatternsV1::from(&other.datetime_formats);
+        let skeletons_v1 = gregory::DateSkeletonPatternsV1::from(&other.datetime_formats);
 
-        let pattern_str_full = other.calendars.gregorian.time_formats.full.get_pattern();
-        let pattern_str_long = other.calendars.gregorian.time_formats.long.get_pattern();
-        let pattern_str_medium = other.calendars.gregorian.time_formats.medium.get_pattern();
-        let pattern_str_short = other.calendars.gregorian.time_formats.short.get_pattern();
+        let pattern_str_full = other.time_formats.full.get_pattern();
+        let pattern_str_long = other.time_formats.long.get_pattern();
+        let pattern_str_medium = other.time_formats.medium.get_pattern();
+        let pattern_str_short = other.time_formats.short.get_pattern();
 
         use pattern::reference::Pattern;
 
@@ -173,6 +162,15 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
 
         let preferred_hour_cycle =
             preferred_hour_cycle.expect("Could not find a preferred hour cycle.");
+        let preferred_fine_hour_cycle = [
+            &pattern_full,
+            &pattern_long,
+            &pattern_medium,
+            &pattern_short,
+        ]
+        .iter()
+        .find_map(|pattern| HourCycle::determine(pattern))
+        .expect("Could not find a preferred hour cycle.");
         let alt_hour_cycle = if preferred_hour_cycle == CoarseHourCycle::H11H12 {
             CoarseHourCycle::H23H24
         } else {
@@ -180,7 +178,7 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
         };
 
         let (time_h11_h12, time_h23_h24) = {
-            let time = (&other.calendars.gregorian.time_formats).into();
+            let time = (&other.time_formats).into();
             let alt_time = gregory::patterns::LengthPatternsV1 {
                 full: alt_hour_cycle
                     .apply_on_pattern(
@@ -227,15 +225,41 @@ impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
         };
 
         Self {
-            date: (&other.calendars.gregorian.date_formats).into(),
-            time_h11_h12,
-            time_h23_h24,
-            preferred_hour_cycle,
+            date: (&other.date_formats).into(),
+            time_h11: with_hour_cycle(&time_h11_h12, HourCycle::H11),
+            time_h12: with_hour_cycle(&time_h11_h12, HourCycle::H12),
+            time_h23: with_hour_cycle(&time_h23_h24, HourCycle::H23),
+            time_h24: with_hour_cycle(&time_h23_h24, HourCycle::H24),
+            preferred_hour_cycle: preferred_fine_hour_cycle,
             length_combinations: length_combinations_v1,
         }
     }
 }
 
+/// Precomputes the time patterns of an hour cycle by replacing the hour symbols of patterns
+/// with the same coarse hour cycle, so that no patterns are rewritten at runtime.
+fn with_hour_cycle(
+    time: &gregory::patterns::LengthPatternsV1,
+    hour_cycle: HourCycle,
+) -> gregory::patterns::LengthPatternsV1<'static> {
+    let apply = |pattern_str: &str| -> Cow<'static, str> {
+        let mut pattern = pattern::reference::Pattern::from_bytes(pattern_str)
+            .expect("Failed to create a Pattern from bytes.");
+        if HourCycle::determine(&pattern) == Some(hour_cycle) {
+            // Keep the pattern string of the locale as it is.
+            return Cow::Owned(pattern_str.into());
+        }
+        hour_cycle.apply_on_pattern(&mut pattern);
+        Cow::Owned(pattern.to_string())
+    };
+    gregory::patterns::LengthPatternsV1 {
+        full: apply(&time.full),
+        long: apply(&time.long),
+        medium: apply(&time.medium),
+        short: apply(&time.short),
+    }
+}
+
 #[test]
 fn test_basic() {
     use icu_locid_macros::langid;
@@ -285,3 +309,95 @@ fn test_with_numbering_system() {
     // TODO(#308): Support numbering system variations. We currently throw them away.
     assert_eq!("d/M/yy", cs_dates.get().date.short);
 }
+
+#[test]
+fn test_hour_cycles() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let ja_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_DATE_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("ja")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let ja_dates = ja_dates.get();
+    assert_eq!(HourCycle::H23, ja_dates.preferred_hour_cycle);
+    assert_eq!("H:mm", ja_dates.time_h23.short);
+    assert_eq!("k:mm", ja_dates.time_h24.short);
+    assert_eq!("aK:mm", ja_dates.time_h11.short);
+    assert_eq!("ah:mm", ja_dates.time_h12.short);
+}
+
+#[test]
+fn test_calendar_variant() {
+    use crate::CldrPathsLocal;
+    use icu_locid_macros::langid;
+
+    // Create a cldr-cal-japanese package from the Gregorian data with a distinct pattern.
+    let cldr_dates = crate::cldr_paths::for_test().cldr_dates().unwrap();
+    let mut json: serde_json::Value = serde_json::from_reader(
+        crate::reader::open_reader(&cldr_dates.join("main/en/ca-gregorian.json")).unwrap(),
+    )
+    .unwrap();
+    let calendars = &mut json["main"]["en"]["dates"]["calendars"];
+    let mut japanese = calendars["gregorian"].take();
+    japanese["dateFormats"]["medium"] = "MMM d, y G".into();
+    *calendars = serde_json::json!({ "japanese": japanese });
+    let cldr_cal_japanese = std::env::temp_dir().join("icu4x-test-cldr-cal-japanese");
+    std::fs::create_dir_all(cldr_cal_japanese.join("main/en")).unwrap();
+    std::fs::write(
+        cldr_cal_japanese.join("main/en/ca-japanese.json"),
+        json.to_string(),
+    )
+    .unwrap();
+
+    let cldr_paths = CldrPathsLocal {
+        cldr_dates: Ok(cldr_dates),
+        cldr_cal_japanese: Ok(cldr_cal_japanese),
+        ..Default::default()
+    };
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |variant: Option<&'static str>| {
+        let dates: DataPayload<gregory::DatePatternsV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::GREGORY_DATE_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: variant.map(Cow::Borrowed),
+                        langid: Some(langid!("en")),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        dates.get().date.medium.to_string()
+    };
+    assert_eq!("MMM d, y", load(None));
+    assert_eq!("MMM d, y G", load(Some("japanese")));
+
+    let options = provider
+        .supported_options_for_key(&key::GREGORY_DATE_PATTERNS_V1)
+        .unwrap()
+        .filter(|options| options.variant.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        options,
+        vec![ResourceOptions {
+            variant: Some(Cow::Borrowed("japanese")),
+            langid: Some(langid!("en")),
+        }]
+    );
+}
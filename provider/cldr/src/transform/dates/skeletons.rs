@@ -2,10 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::cldr_json;
+use super::{cldr_json, read_calendar_patterns, CalendarPatternsData};
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_datetime::{provider::*, skeleton::SkeletonError};
 use icu_plurals::PluralCategory;
@@ -22,26 +21,14 @@ pub const ALL_KEYS: [ResourceKey; 1] = [
 /// A data provider reading from CLDR JSON dates files.
 #[derive(PartialEq, Debug)]
 pub struct DateSkeletonPatternsProvider<'data> {
-    data: Vec<(CldrLangID, cldr_json::LangDates)>,
+    data: CalendarPatternsData,
     _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
 }
 
 impl TryFrom<&dyn CldrPaths> for DateSkeletonPatternsProvider<'_> {
     type Error = Error;
     fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
-        let mut data = vec![];
-
-        let path = cldr_paths.cldr_dates()?.join("main");
-
-        let locale_dirs = get_subdirectories(&path)?;
-
-        for dir in locale_dirs {
-            let path = dir.join("ca-gregorian.json");
-
-            let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
-            data.append(&mut resource.main.0);
-        }
+        let data = read_calendar_patterns(cldr_paths)?;
 
         Ok(Self {
             data,
@@ -65,19 +52,21 @@ impl<'data> DataProvider<'data, gregory::DateSkeletonPatternsV1Marker>
     ) -> Result<DataResponse<'data, gregory::DateSkeletonPatternsV1Marker>, DataError> {
         DateSkeletonPatternsProvider::supports_key(&req.resource_path.key)?;
         let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
-        let dates = match self
+        let variant = &req.resource_path.options.variant;
+        let patterns = match self
             .data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+            .binary_search_by(|(v, lid, _)| (v, lid).cmp(&(variant, &cldr_langid)))
         {
-            Ok(idx) => &self.data[idx].1.dates,
+            Ok(idx) => &self.data[idx].2,
             Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
         };
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(
-                gregory::DateSkeletonPatternsV1::from(&dates.calendars.gregorian.datetime_formats),
+                gregory::DateSkeletonPatternsV1::from(&patterns.datetime_formats),
             )),
         })
     }
@@ -96,8 +85,8 @@ impl<'data> IterableDataProviderCore for DateSkeletonPatternsProvider<'data> {
         let list: Vec<ResourceOptions> = self
             .data
             .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
+            .map(|(v, l, _)| ResourceOptions {
+                variant: v.clone(),
                 // TODO: Avoid the clone
                 langid: Some(l.langid.clone()),
             })
@@ -10,7 +10,6 @@ use crate::CldrPaths;
 use icu_datetime::provider::*;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
 use icu_provider::prelude::*;
-use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::marker::PhantomData;
 
@@ -73,6 +72,7 @@ impl<'data> DataProvider<'data, gregory::DateSymbolsV1Marker> for DateSymbolsPro
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(gregory::DateSymbolsV1::from(dates))),
         })
@@ -102,31 +102,48 @@ impl<'data> IterableDataProviderCore for DateSymbolsProvider<'data> {
     }
 }
 
-impl From<&cldr_json::Dates> for gregory::DateSymbolsV1 {
+impl From<&cldr_json::Dates> for gregory::DateSymbolsV1<'static> {
     fn from(other: &cldr_json::Dates) -> Self {
         Self {
             months: (&other.calendars.gregorian.months).into(),
             weekdays: (&other.calendars.gregorian.days).into(),
             day_periods: (&other.calendars.gregorian.day_periods).into(),
+            eras: (&other.calendars.gregorian.eras).into(),
+        }
+    }
+}
+
+impl From<&cldr_json::eras::Symbols> for gregory::eras::SymbolsV1<'static> {
+    fn from(other: &cldr_json::eras::Symbols) -> Self {
+        Self((&[other.bce.as_str(), other.ce.as_str()][..]).into())
+    }
+}
+
+impl From<&cldr_json::eras::Widths> for gregory::eras::ErasV1<'static> {
+    fn from(other: &cldr_json::eras::Widths) -> Self {
+        Self {
+            abbreviated: (&other.abbreviated).into(),
+            narrow: (&other.narrow).into(),
+            wide: (&other.wide).into(),
         }
     }
 }
 
 macro_rules! symbols_from {
     ([$name: ident, $name2: ident $(,)?], [ $($element: ident),+ $(,)? ] $(,)?) => {
-        impl From<&cldr_json::$name::Symbols> for gregory::$name2::SymbolsV1 {
+        impl From<&cldr_json::$name::Symbols> for gregory::$name2::SymbolsV1<'static> {
             fn from(other: &cldr_json::$name::Symbols) -> Self {
-                Self([
+                Self((&[
                     $(
-                        Cow::Owned(other.$element.clone()),
+                        other.$element.as_str(),
                     )*
-                ])
+                ][..]).into())
             }
         }
         symbols_from!([$name, $name2]);
     };
     ([$name: ident, $name2: ident $(,)?], { $($element: ident),+ $(,)? } $(,)?) => {
-        impl From<&cldr_json::$name::Symbols> for gregory::$name2::SymbolsV1 {
+        impl From<&cldr_json::$name::Symbols> for gregory::$name2::SymbolsV1<'static> {
             fn from(other: &cldr_json::$name::Symbols) -> Self {
                 Self {
                     $(
@@ -149,7 +166,7 @@ macro_rules! symbols_from {
             }
         }
 
-        impl From<&cldr_json::$name::Contexts> for gregory::$name2::ContextsV1 {
+        impl From<&cldr_json::$name::Contexts> for gregory::$name2::ContextsV1<'static> {
             fn from(other: &cldr_json::$name::Contexts) -> Self {
                 Self {
                     format: (&other.format).into(),
@@ -185,7 +202,7 @@ macro_rules! symbols_from {
             }
         }
 
-        impl From<&cldr_json::$name::FormatWidths> for gregory::$name2::FormatWidthsV1 {
+        impl From<&cldr_json::$name::FormatWidths> for gregory::$name2::FormatWidthsV1<'static> {
             fn from(other: &cldr_json::$name::FormatWidths) -> Self {
                 Self {
                     abbreviated: (&other.abbreviated).into(),
@@ -196,7 +213,7 @@ macro_rules! symbols_from {
             }
         }
 
-        impl From<&cldr_json::$name::StandAloneWidths> for gregory::$name2::StandAloneWidthsV1 {
+        impl From<&cldr_json::$name::StandAloneWidths> for gregory::$name2::StandAloneWidthsV1<'static> {
             fn from(other: &cldr_json::$name::StandAloneWidths) -> Self {
                 Self {
                     abbreviated: other.abbreviated.as_ref().map(|width| width.into()),
@@ -250,12 +267,23 @@ fn test_basic() {
         .take_payload()
         .unwrap();
 
-    assert_eq!("srpna", cs_dates.get().months.format.wide.0[7]);
+    assert_eq!(Some("srpna"), cs_dates.get().months.format.wide.0.get(7));
 
     assert_eq!(
-        "po",
-        cs_dates.get().weekdays.format.short.as_ref().unwrap().0[1]
+        Some("po"),
+        cs_dates
+            .get()
+            .weekdays
+            .format
+            .short
+            .as_ref()
+            .unwrap()
+            .0
+            .get(1)
     );
+
+    assert_eq!(Some("př. n. l."), cs_dates.get().eras.abbreviated.0.get(0));
+    assert_eq!(Some("našeho letopočtu"), cs_dates.get().eras.wide.0.get(1));
 }
 
 #[test]
@@ -0,0 +1,480 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_displaynames::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 5] = [
+    key::TERRITORIES_V1,     //
+    key::SCRIPTS_V1,         //
+    key::LANGUAGES_V1,       //
+    key::VARIANTS_V1,        //
+    key::LOCALE_PATTERNS_V1, //
+];
+
+/// The CLDR JSON files containing the `localeDisplayNames` data for a locale.
+const DISPLAY_NAMES_FILES: [&str; 5] = [
+    "territories.json",
+    "scripts.json",
+    "languages.json",
+    "variants.json",
+    "localeDisplayNames.json",
+];
+
+/// A data provider reading from CLDR JSON locale display name files.
+#[derive(PartialEq, Debug)]
+pub struct DisplayNamesProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangDisplayNames)>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for DisplayNamesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_localenames()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let mut merged: Option<(CldrLangID, cldr_json::LangDisplayNames)> = None;
+            for file in DISPLAY_NAMES_FILES.iter() {
+                let path = dir.join(file);
+                let resource: cldr_json::Resource =
+                    serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                for (langid, names) in resource.main.0 {
+                    match merged {
+                        Some((_, ref mut existing)) => existing
+                            .locale_display_names
+                            .merge(names.locale_display_names),
+                        None => merged = Some((langid, names)),
+                    }
+                }
+            }
+            data.extend(merged);
+        }
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for DisplayNamesProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of all `localeDisplayNames`
+    /// files merged into a single `localeDisplayNames` object.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let resource: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data: resource.main.0,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for DisplayNamesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if resc_key.category != ResourceCategory::DisplayNames || resc_key.version != 1 {
+            return Err(resc_key.into());
+        }
+        if !ALL_KEYS.contains(resc_key) {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DisplayNamesProvider<'data> {
+    fn get_names_for(
+        &self,
+        req: &DataRequest,
+    ) -> Result<&cldr_json::LocaleDisplayNames, DataError> {
+        DisplayNamesProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => Ok(&self.data[idx].1.locale_display_names),
+            Err(_) => Err(DataError::MissingResourceOptions(req.clone())),
+        }
+    }
+}
+
+impl<'data> DataProvider<'data, DisplayNamesV1Marker> for DisplayNamesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DisplayNamesV1Marker>, DataError> {
+        let names = self.get_names_for(req)?;
+        let source = match req.resource_path.key {
+            key::TERRITORIES_V1 => &names.territories,
+            key::SCRIPTS_V1 => &names.scripts,
+            key::LANGUAGES_V1 => &names.languages,
+            key::VARIANTS_V1 => &names.variants,
+            _ => return Err((&req.resource_path.key).into()),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(display_names_from_cldr(source))),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, LocaleDisplayPatternsV1Marker> for DisplayNamesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, LocaleDisplayPatternsV1Marker>, DataError> {
+        if req.resource_path.key != key::LOCALE_PATTERNS_V1 {
+            return Err((&req.resource_path.key).into());
+        }
+        let names = self.get_names_for(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(LocaleDisplayPatternsV1::from(
+                names,
+            ))),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(DisplayNamesProvider<'data>, {
+    key::TERRITORIES_V1 => DisplayNamesV1Marker,
+    key::SCRIPTS_V1 => DisplayNamesV1Marker,
+    key::LANGUAGES_V1 => DisplayNamesV1Marker,
+    key::VARIANTS_V1 => DisplayNamesV1Marker,
+    key::LOCALE_PATTERNS_V1 => LocaleDisplayPatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for DisplayNamesProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        DisplayNamesProvider::supports_key(resc_key)?;
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Builds [`DisplayNamesV1`] from a CLDR map of codes to names, sorting the `-alt-short` and
+/// `-alt-stand-alone` alternates into their own maps.
+fn display_names_from_cldr(other: &BTreeMap<String, String>) -> DisplayNamesV1<'static> {
+    let mut result = DisplayNamesV1::default();
+    for (code, name) in other.iter() {
+        let name = Cow::Owned(name.clone());
+        if let Some(code) = code.strip_suffix("-alt-short") {
+            result
+                .short_names
+                .insert(Cow::Owned(code.to_string()), name);
+        } else if let Some(code) = code.strip_suffix("-alt-stand-alone") {
+            result
+                .stand_alone_names
+                .insert(Cow::Owned(code.to_string()), name);
+        } else if !code.contains("-alt-") {
+            result.names.insert(Cow::Owned(code.clone()), name);
+        }
+        // Other alternates, such as "-alt-variant", are not yet supported.
+    }
+    result
+}
+
+/// Returns the BCP-47 Unicode extension key for a CLDR key name, such as `"ca"` for
+/// `"calendar"`.
+fn bcp47_key(cldr_key: &str) -> &str {
+    match cldr_key {
+        "calendar" => "ca",
+        "colAlternate" => "ka",
+        "colBackwards" => "kb",
+        "colCaseFirst" => "kf",
+        "colCaseLevel" => "kc",
+        "colNormalization" => "kk",
+        "colNumeric" => "kn",
+        "colReorder" => "kr",
+        "colStrength" => "ks",
+        "collation" => "co",
+        "currency" => "cu",
+        "numbers" => "nu",
+        "timezone" => "tz",
+        "variableTop" => "vt",
+        _ => cldr_key,
+    }
+}
+
+/// Returns the BCP-47 Unicode extension value for a CLDR type name, such as `"gregory"` for
+/// `"gregorian"`.
+fn bcp47_type(cldr_type: &str) -> &str {
+    match cldr_type {
+        "ethiopic-amete-alem" => "ethioaa",
+        "gregorian" => "gregory",
+        "islamicc" => "islamic-civil",
+        "dictionary" => "dict",
+        "gb2312han" => "gb2312",
+        "phonebook" => "phonebk",
+        "traditional" => "trad",
+        _ => cldr_type,
+    }
+}
+
+impl From<&cldr_json::LocaleDisplayNames> for LocaleDisplayPatternsV1<'static> {
+    fn from(other: &cldr_json::LocaleDisplayNames) -> Self {
+        let mut result = Self::default();
+        if let Some(ref patterns) = other.locale_display_pattern {
+            result.locale_pattern = Cow::Owned(patterns.locale_pattern.clone());
+            result.locale_separator = Cow::Owned(patterns.locale_separator.clone());
+            result.locale_key_type_pattern = Cow::Owned(patterns.locale_key_type_pattern.clone());
+        }
+        for (key, name) in other.keys.iter() {
+            result.key_names.insert(
+                Cow::Owned(bcp47_key(key).to_string()),
+                Cow::Owned(name.clone()),
+            );
+        }
+        for (key, types) in other.types.iter() {
+            let key = bcp47_key(key);
+            for (value, name) in types.iter() {
+                if value.contains("-alt-") {
+                    continue;
+                }
+                result.type_names.insert(
+                    Cow::Owned(format!("{}-{}", key, bcp47_type(value))),
+                    Cow::Owned(name.clone()),
+                );
+            }
+        }
+        result
+    }
+}
+
+/// Serde structs for the CLDR JSON localenames files.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize, Clone)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LocaleDisplayPattern {
+        pub locale_pattern: String,
+        pub locale_separator: String,
+        pub locale_key_type_pattern: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize, Clone)]
+    pub struct LocaleDisplayNames {
+        #[serde(default)]
+        pub territories: BTreeMap<String, String>,
+        #[serde(default)]
+        pub scripts: BTreeMap<String, String>,
+        #[serde(default)]
+        pub languages: BTreeMap<String, String>,
+        #[serde(default)]
+        pub variants: BTreeMap<String, String>,
+        #[serde(rename = "localeDisplayPattern")]
+        pub locale_display_pattern: Option<LocaleDisplayPattern>,
+        #[serde(default)]
+        pub keys: BTreeMap<String, String>,
+        #[serde(default)]
+        pub types: BTreeMap<String, BTreeMap<String, String>>,
+    }
+
+    impl LocaleDisplayNames {
+        /// Merges the contents of another `localeDisplayNames` file for the same locale.
+        pub fn merge(&mut self, other: LocaleDisplayNames) {
+            self.territories.extend(other.territories);
+            self.scripts.extend(other.scripts);
+            self.languages.extend(other.languages);
+            self.variants.extend(other.variants);
+            if other.locale_display_pattern.is_some() {
+                self.locale_display_pattern = other.locale_display_pattern;
+            }
+            self.keys.extend(other.keys);
+            self.types.extend(other.types);
+        }
+    }
+
+    #[derive(PartialEq, Debug, Deserialize, Clone)]
+    pub struct LangDisplayNames {
+        #[serde(rename = "localeDisplayNames")]
+        pub locale_display_names: LocaleDisplayNames,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangDisplayNames)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let json_str = r#"{
+        "main": {
+            "en": {
+                "localeDisplayNames": {
+                    "territories": {
+                        "419": "Latin America",
+                        "DE": "Germany",
+                        "GB": "United Kingdom",
+                        "GB-alt-short": "UK",
+                        "CD-alt-variant": "Congo (DRC)"
+                    },
+                    "scripts": {
+                        "Hant": "Traditional",
+                        "Hant-alt-stand-alone": "Traditional Han",
+                        "Latn": "Latin"
+                    },
+                    "languages": {
+                        "en-GB": "British English",
+                        "zh": "Chinese",
+                        "zh-Hant": "Traditional Chinese"
+                    },
+                    "variants": {
+                        "POSIX": "Computer"
+                    },
+                    "localeDisplayPattern": {
+                        "localePattern": "{0} ({1})",
+                        "localeSeparator": "{0}, {1}",
+                        "localeKeyTypePattern": "{0}: {1}"
+                    },
+                    "keys": {
+                        "calendar": "Calendar",
+                        "numbers": "Numbers"
+                    },
+                    "types": {
+                        "calendar": {
+                            "buddhist": "Buddhist Calendar",
+                            "gregorian": "Gregorian Calendar"
+                        }
+                    }
+                }
+            }
+        }
+    }"#;
+    let provider = DisplayNamesProvider::try_from(json_str).unwrap();
+
+    let territories: DataPayload<DisplayNamesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::TERRITORIES_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(territories.get().get_name("DE"), Some("Germany"));
+    assert_eq!(territories.get().get_name("419"), Some("Latin America"));
+    assert_eq!(territories.get().get_short_name("GB"), Some("UK"));
+    assert_eq!(territories.get().get_short_name("DE"), Some("Germany"));
+    assert_eq!(territories.get().get_name("CD"), None);
+
+    let scripts: DataPayload<DisplayNamesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::SCRIPTS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(scripts.get().get_name("Hant"), Some("Traditional"));
+    assert_eq!(
+        scripts.get().get_stand_alone_name("Hant"),
+        Some("Traditional Han")
+    );
+    assert_eq!(scripts.get().get_stand_alone_name("Latn"), Some("Latin"));
+
+    let languages: DataPayload<DisplayNamesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::LANGUAGES_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(languages.get().get_name("zh"), Some("Chinese"));
+    assert_eq!(
+        languages.get().get_name("zh-Hant"),
+        Some("Traditional Chinese")
+    );
+
+    let patterns: DataPayload<LocaleDisplayPatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::LOCALE_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(patterns.get().locale_pattern, "{0} ({1})");
+    assert_eq!(
+        patterns.get().key_names.get("nu").map(|s| &**s),
+        Some("Numbers")
+    );
+    assert_eq!(
+        patterns.get().type_names.get("ca-gregory").map(|s| &**s),
+        Some("Gregorian Calendar")
+    );
+    assert_eq!(
+        patterns.get().type_names.get("ca-buddhist").map(|s| &**s),
+        Some("Buddhist Calendar")
+    );
+}
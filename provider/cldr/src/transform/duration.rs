@@ -0,0 +1,551 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_durationformat::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::UNITS_V1, //
+];
+
+/// A data provider reading the units of time from CLDR JSON unit files.
+#[derive(PartialEq, Debug)]
+pub struct DurationProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangUnits)>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for DurationProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_units()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("units.json");
+            let resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.extend(resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for DurationProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `units.json` file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let resource: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data: resource.main.0,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for DurationProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if resc_key.category != ResourceCategory::Duration || resc_key.version != 1 {
+            return Err(resc_key.into());
+        }
+        if !ALL_KEYS.contains(resc_key) {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, DurationUnitsV1Marker> for DurationProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DurationUnitsV1Marker>, DataError> {
+        DurationProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let units = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1.units,
+            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(DurationUnitsV1 {
+                long: (&units.long).into(),
+                short: (&units.short).into(),
+                narrow: (&units.narrow).into(),
+                hms: Cow::Owned(units.hms.pattern.clone()),
+                hm: Cow::Owned(units.hm.pattern.clone()),
+                ms: Cow::Owned(units.ms.pattern.clone()),
+            })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(DurationProvider<'data>, {
+    _ => DurationUnitsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for DurationProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        DurationProvider::supports_key(resc_key)?;
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON unit files.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use icu_durationformat::provider::{PluralPatterns, UnitPatterns};
+    use serde::Deserialize;
+    use std::borrow::Cow;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Unit {
+        #[serde(rename = "unitPattern-count-zero")]
+        pub zero: Option<String>,
+        #[serde(rename = "unitPattern-count-one")]
+        pub one: Option<String>,
+        #[serde(rename = "unitPattern-count-two")]
+        pub two: Option<String>,
+        #[serde(rename = "unitPattern-count-few")]
+        pub few: Option<String>,
+        #[serde(rename = "unitPattern-count-many")]
+        pub many: Option<String>,
+        #[serde(rename = "unitPattern-count-other")]
+        pub other: String,
+    }
+
+    impl From<&Unit> for PluralPatterns<'static> {
+        fn from(other: &Unit) -> Self {
+            let pattern = |pattern: &Option<String>| pattern.clone().map(Cow::Owned);
+            Self {
+                zero: pattern(&other.zero),
+                one: pattern(&other.one),
+                two: pattern(&other.two),
+                few: pattern(&other.few),
+                many: pattern(&other.many),
+                other: Cow::Owned(other.other.clone()),
+            }
+        }
+    }
+
+    /// The units of time in one width; the other units in the file are ignored.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Units {
+        #[serde(rename = "duration-year")]
+        pub year: Unit,
+        #[serde(rename = "duration-month")]
+        pub month: Unit,
+        #[serde(rename = "duration-week")]
+        pub week: Unit,
+        #[serde(rename = "duration-day")]
+        pub day: Unit,
+        #[serde(rename = "duration-hour")]
+        pub hour: Unit,
+        #[serde(rename = "duration-minute")]
+        pub minute: Unit,
+        #[serde(rename = "duration-second")]
+        pub second: Unit,
+        #[serde(rename = "duration-millisecond")]
+        pub millisecond: Unit,
+        #[serde(rename = "duration-microsecond")]
+        pub microsecond: Unit,
+        #[serde(rename = "duration-nanosecond")]
+        pub nanosecond: Unit,
+    }
+
+    impl From<&Units> for UnitPatterns<'static> {
+        fn from(other: &Units) -> Self {
+            Self {
+                years: (&other.year).into(),
+                months: (&other.month).into(),
+                weeks: (&other.week).into(),
+                days: (&other.day).into(),
+                hours: (&other.hour).into(),
+                minutes: (&other.minute).into(),
+                seconds: (&other.second).into(),
+                milliseconds: (&other.millisecond).into(),
+                microseconds: (&other.microsecond).into(),
+                nanoseconds: (&other.nanosecond).into(),
+            }
+        }
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct DurationUnit {
+        #[serde(rename = "durationUnitPattern")]
+        pub pattern: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct UnitsData {
+        pub long: Units,
+        pub short: Units,
+        pub narrow: Units,
+        #[serde(rename = "durationUnit-type-hms")]
+        pub hms: DurationUnit,
+        #[serde(rename = "durationUnit-type-hm")]
+        pub hm: DurationUnit,
+        #[serde(rename = "durationUnit-type-ms")]
+        pub ms: DurationUnit,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangUnits {
+        pub units: UnitsData,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(#[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangUnits)>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use crate::transform::ListProvider;
+    use icu_durationformat::options::*;
+    use icu_durationformat::{Duration, DurationFormat};
+    use icu_locid_macros::langid;
+
+    let json_str = r#"{
+        "main": {
+            "en": {
+                "units": {
+                    "long": {
+                        "duration-year": {
+                            "unitPattern-count-one": "{0} year",
+                            "unitPattern-count-other": "{0} years"
+                        },
+                        "duration-month": {
+                            "unitPattern-count-one": "{0} month",
+                            "unitPattern-count-other": "{0} months"
+                        },
+                        "duration-week": {
+                            "unitPattern-count-one": "{0} week",
+                            "unitPattern-count-other": "{0} weeks"
+                        },
+                        "duration-day": {
+                            "unitPattern-count-one": "{0} day",
+                            "unitPattern-count-other": "{0} days"
+                        },
+                        "duration-hour": {
+                            "unitPattern-count-one": "{0} hour",
+                            "unitPattern-count-other": "{0} hours"
+                        },
+                        "duration-minute": {
+                            "unitPattern-count-one": "{0} minute",
+                            "unitPattern-count-other": "{0} minutes"
+                        },
+                        "duration-second": {
+                            "unitPattern-count-one": "{0} second",
+                            "unitPattern-count-other": "{0} seconds"
+                        },
+                        "duration-millisecond": {
+                            "unitPattern-count-one": "{0} millisecond",
+                            "unitPattern-count-other": "{0} milliseconds"
+                        },
+                        "duration-microsecond": {
+                            "unitPattern-count-one": "{0} microsecond",
+                            "unitPattern-count-other": "{0} microseconds"
+                        },
+                        "duration-nanosecond": {
+                            "unitPattern-count-one": "{0} nanosecond",
+                            "unitPattern-count-other": "{0} nanoseconds"
+                        }
+                    },
+                    "short": {
+                        "duration-year": {
+                            "unitPattern-count-one": "{0} yr",
+                            "unitPattern-count-other": "{0} yrs"
+                        },
+                        "duration-month": {
+                            "unitPattern-count-one": "{0} mth",
+                            "unitPattern-count-other": "{0} mths"
+                        },
+                        "duration-week": {
+                            "unitPattern-count-one": "{0} wk",
+                            "unitPattern-count-other": "{0} wks"
+                        },
+                        "duration-day": {
+                            "unitPattern-count-one": "{0} day",
+                            "unitPattern-count-other": "{0} days"
+                        },
+                        "duration-hour": {
+                            "unitPattern-count-one": "{0} hr",
+                            "unitPattern-count-other": "{0} hr"
+                        },
+                        "duration-minute": {
+                            "unitPattern-count-one": "{0} min",
+                            "unitPattern-count-other": "{0} min"
+                        },
+                        "duration-second": {
+                            "unitPattern-count-one": "{0} sec",
+                            "unitPattern-count-other": "{0} sec"
+                        },
+                        "duration-millisecond": {
+                            "unitPattern-count-one": "{0} ms",
+                            "unitPattern-count-other": "{0} ms"
+                        },
+                        "duration-microsecond": {
+                            "unitPattern-count-one": "{0} \u03bcs",
+                            "unitPattern-count-other": "{0} \u03bcs"
+                        },
+                        "duration-nanosecond": {
+                            "unitPattern-count-one": "{0} ns",
+                            "unitPattern-count-other": "{0} ns"
+                        }
+                    },
+                    "narrow": {
+                        "duration-year": {
+                            "unitPattern-count-one": "{0}y",
+                            "unitPattern-count-other": "{0}y"
+                        },
+                        "duration-month": {
+                            "unitPattern-count-one": "{0}m",
+                            "unitPattern-count-other": "{0}m"
+                        },
+                        "duration-week": {
+                            "unitPattern-count-one": "{0}w",
+                            "unitPattern-count-other": "{0}w"
+                        },
+                        "duration-day": {
+                            "unitPattern-count-one": "{0}d",
+                            "unitPattern-count-other": "{0}d"
+                        },
+                        "duration-hour": {
+                            "unitPattern-count-one": "{0}h",
+                            "unitPattern-count-other": "{0}h"
+                        },
+                        "duration-minute": {
+                            "unitPattern-count-one": "{0}m",
+                            "unitPattern-count-other": "{0}m"
+                        },
+                        "duration-second": {
+                            "unitPattern-count-one": "{0}s",
+                            "unitPattern-count-other": "{0}s"
+                        },
+                        "duration-millisecond": {
+                            "unitPattern-count-one": "{0}ms",
+                            "unitPattern-count-other": "{0}ms"
+                        },
+                        "duration-microsecond": {
+                            "unitPattern-count-one": "{0}\u03bcs",
+                            "unitPattern-count-other": "{0}\u03bcs"
+                        },
+                        "duration-nanosecond": {
+                            "unitPattern-count-one": "{0}ns",
+                            "unitPattern-count-other": "{0}ns"
+                        }
+                    },
+                    "durationUnit-type-hm": {
+                        "durationUnitPattern": "h:mm"
+                    },
+                    "durationUnit-type-hms": {
+                        "durationUnitPattern": "h:mm:ss"
+                    },
+                    "durationUnit-type-ms": {
+                        "durationUnitPattern": "m:ss"
+                    }
+                }
+            }
+        }
+    }"#;
+    let provider = DurationProvider::try_from(json_str).unwrap();
+
+    let list_json_str = r#"{
+        "main": {
+            "en": {
+                "listPatterns": {
+                    "listPattern-type-standard": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, and {1}",
+                        "2": "{0}, and {1}"
+                    },
+                    "listPattern-type-standard-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, and {1}",
+                        "2": "{0}, and {1}"
+                    },
+                    "listPattern-type-standard-narrow": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, and {1}",
+                        "2": "{0}, and {1}"
+                    },
+                    "listPattern-type-or": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, or {1}",
+                        "2": "{0}, or {1}"
+                    },
+                    "listPattern-type-or-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, or {1}",
+                        "2": "{0}, or {1}"
+                    },
+                    "listPattern-type-or-narrow": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, or {1}",
+                        "2": "{0}, or {1}"
+                    },
+                    "listPattern-type-unit": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, {1}",
+                        "2": "{0}, {1}"
+                    },
+                    "listPattern-type-unit-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, {1}",
+                        "2": "{0}, {1}"
+                    },
+                    "listPattern-type-unit-narrow": {
+                        "start": "{0} {1}",
+                        "middle": "{0} {1}",
+                        "end": "{0} {1}",
+                        "2": "{0} {1}"
+                    }
+                }
+            }
+        }
+    }"#;
+    let list_provider = ListProvider::try_from(list_json_str).unwrap();
+    let testdata_provider = icu_testdata::get_provider();
+
+    let format = |options: &DurationFormatOptions, duration: &Duration| {
+        DurationFormat::try_new(
+            langid!("en"),
+            &provider,
+            &list_provider,
+            &testdata_provider,
+            &testdata_provider,
+            options,
+        )
+        .unwrap()
+        .format(duration)
+    };
+    let options = |style| DurationFormatOptions {
+        style,
+        ..Default::default()
+    };
+
+    let duration = Duration {
+        hours: 1,
+        minutes: 23,
+        seconds: 4,
+        ..Default::default()
+    };
+    assert_eq!(
+        format(&options(Style::Long), &duration),
+        "1 hour, 23 minutes, 4 seconds"
+    );
+    assert_eq!(
+        format(&options(Style::Short), &duration),
+        "1 hr, 23 min, 4 sec"
+    );
+    assert_eq!(format(&options(Style::Narrow), &duration), "1h 23m 4s");
+    assert_eq!(format(&options(Style::Digital), &duration), "1:23:04");
+
+    let duration = Duration {
+        negative: true,
+        days: 2,
+        minutes: 5,
+        milliseconds: 1500,
+        ..Default::default()
+    };
+    assert_eq!(
+        format(&options(Style::Long), &duration),
+        "-2 days, 5 minutes, 1,500 milliseconds"
+    );
+    assert_eq!(
+        format(&options(Style::Digital), &duration),
+        "-2 days, 0:05:01.5"
+    );
+
+    let minutes_and_seconds = DurationFormatOptions {
+        style: Style::Digital,
+        hours: FieldOptions {
+            display: Some(FieldDisplay::Auto),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        format(
+            &minutes_and_seconds,
+            &Duration {
+                minutes: 3,
+                seconds: 7,
+                ..Default::default()
+            }
+        ),
+        "3:07"
+    );
+
+    assert_eq!(
+        format(&options(Style::Short), &Duration::default()),
+        "0 sec"
+    );
+
+    let numeric_days = DurationFormatOptions {
+        days: FieldOptions {
+            style: Some(FieldStyle::Numeric),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(DurationFormat::try_new(
+        langid!("en"),
+        &provider,
+        &list_provider,
+        &testdata_provider,
+        &testdata_provider,
+        &numeric_days,
+    )
+    .is_err());
+}
@@ -60,6 +60,7 @@ impl<'data> DataProvider<'data, LikelySubtagsV1Marker> for LikelySubtagsProvider
             Ok(DataResponse {
                 metadata: DataResponseMetadata {
                     data_langid: langid.clone(),
+                    ..Default::default()
                 },
                 payload: Some(DataPayload::from_owned(LikelySubtagsV1::from(&self.data))),
             })
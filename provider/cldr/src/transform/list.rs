@@ -0,0 +1,353 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_list::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 3] = [
+    key::AND_V1,  //
+    key::OR_V1,   //
+    key::UNIT_V1, //
+];
+
+/// A data provider reading from CLDR JSON list pattern files.
+#[derive(PartialEq, Debug)]
+pub struct ListProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangListPatterns)>,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for ListProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_misc()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("listPatterns.json");
+            let resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.extend(resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for ListProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `listPatterns.json` file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let resource: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data: resource.main.0,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for ListProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if resc_key.category != ResourceCategory::List || resc_key.version != 1 {
+            return Err(resc_key.into());
+        }
+        if !ALL_KEYS.contains(resc_key) {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, ListFormatterPatternsV1Marker> for ListProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ListFormatterPatternsV1Marker>, DataError> {
+        ListProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let patterns = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1.list_patterns,
+            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+
+        // The CLDR list pattern types of the wide, short, and narrow widths.
+        let (wide, short, narrow) = match req.resource_path.key {
+            key::AND_V1 => (
+                &patterns.standard,
+                &patterns.standard_short,
+                &patterns.standard_narrow,
+            ),
+            key::OR_V1 => (&patterns.or, &patterns.or_short, &patterns.or_narrow),
+            key::UNIT_V1 => (&patterns.unit, &patterns.unit_short, &patterns.unit_narrow),
+            _ => return Err((&req.resource_path.key).into()),
+        };
+
+        let mut data = ListFormatterPatternsV1 {
+            wide: wide
+                .try_into_patterns()
+                .map_err(DataError::new_resc_error)?,
+            short: short
+                .try_into_patterns()
+                .map_err(DataError::new_resc_error)?,
+            narrow: narrow
+                .try_into_patterns()
+                .map_err(DataError::new_resc_error)?,
+        };
+
+        if cldr_langid.langid.language == "es" {
+            for patterns in [&mut data.wide, &mut data.short, &mut data.narrow] {
+                add_spanish_special_cases(patterns);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(ListProvider<'data>, {
+    _ => ListFormatterPatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for ListProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        ListProvider::supports_key(resc_key)?;
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Matches words that start with the sound "i", such as "Ibiza" and "hijo", but not "hielo".
+const SPANISH_E_CONDITION: &str = r"^(?i)(i|hi([^ae]|$))";
+
+/// Matches words that start with the sound "o", such as "otro", "hoy", "8" (ocho) and "11" (once),
+/// including numbers such as "11.000" (once mil), but not "110" (ciento diez).
+const SPANISH_U_CONDITION: &str = r"^(?i)(o|ho|8|11(\.?\d\d\d)*(,\d*)?([^.,\d]|$))";
+
+/// Adds the special cases of Spanish, in which "y" becomes "e" before the sound "i", and "o"
+/// becomes "u" before the sound "o".
+fn add_spanish_special_cases(patterns: &mut ListJoinerPatterns) {
+    for pattern in [
+        &mut patterns.start,
+        &mut patterns.middle,
+        &mut patterns.end,
+        &mut patterns.pair,
+    ] {
+        let (condition, replacement) = if pattern.default.between.contains(" y ") {
+            (
+                SPANISH_E_CONDITION,
+                pattern.default.between.replace(" y ", " e "),
+            )
+        } else if pattern.default.between.contains(" o ") {
+            (
+                SPANISH_U_CONDITION,
+                pattern.default.between.replace(" o ", " u "),
+            )
+        } else {
+            continue;
+        };
+        pattern.special_case = Some(SpecialCasePattern {
+            condition: Cow::Borrowed(condition),
+            pattern: ListJoinerPattern {
+                between: Cow::Owned(replacement),
+                ..pattern.default.clone()
+            },
+        });
+    }
+}
+
+/// Serde structs for the CLDR JSON list pattern files.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use icu_list::provider::{ListJoinerPattern, ListJoinerPatterns};
+    use icu_list::ListError;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ListPattern {
+        pub start: String,
+        pub middle: String,
+        pub end: String,
+        #[serde(rename = "2")]
+        pub pair: String,
+    }
+
+    impl ListPattern {
+        pub fn try_into_patterns(&self) -> Result<ListJoinerPatterns<'static>, ListError> {
+            Ok(ListJoinerPatterns {
+                start: self.start.parse::<ListJoinerPattern>()?.into(),
+                middle: self.middle.parse::<ListJoinerPattern>()?.into(),
+                end: self.end.parse::<ListJoinerPattern>()?.into(),
+                pair: self.pair.parse::<ListJoinerPattern>()?.into(),
+            })
+        }
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ListPatterns {
+        #[serde(rename = "listPattern-type-standard")]
+        pub standard: ListPattern,
+        #[serde(rename = "listPattern-type-standard-short")]
+        pub standard_short: ListPattern,
+        #[serde(rename = "listPattern-type-standard-narrow")]
+        pub standard_narrow: ListPattern,
+        #[serde(rename = "listPattern-type-or")]
+        pub or: ListPattern,
+        #[serde(rename = "listPattern-type-or-short")]
+        pub or_short: ListPattern,
+        #[serde(rename = "listPattern-type-or-narrow")]
+        pub or_narrow: ListPattern,
+        #[serde(rename = "listPattern-type-unit")]
+        pub unit: ListPattern,
+        #[serde(rename = "listPattern-type-unit-short")]
+        pub unit_short: ListPattern,
+        #[serde(rename = "listPattern-type-unit-narrow")]
+        pub unit_narrow: ListPattern,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangListPatterns {
+        #[serde(rename = "listPatterns")]
+        pub list_patterns: ListPatterns,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangListPatterns)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_list::{ListFormatter, Type, Width};
+    use icu_locid_macros::langid;
+    use writeable::assert_writeable_eq;
+
+    let json_str = r#"{
+        "main": {
+            "es": {
+                "listPatterns": {
+                    "listPattern-type-standard": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} y {1}",
+                        "2": "{0} y {1}"
+                    },
+                    "listPattern-type-standard-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} y {1}",
+                        "2": "{0} y {1}"
+                    },
+                    "listPattern-type-standard-narrow": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} y {1}",
+                        "2": "{0} y {1}"
+                    },
+                    "listPattern-type-or": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} o {1}",
+                        "2": "{0} o {1}"
+                    },
+                    "listPattern-type-or-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} o {1}",
+                        "2": "{0} o {1}"
+                    },
+                    "listPattern-type-or-narrow": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} o {1}",
+                        "2": "{0} o {1}"
+                    },
+                    "listPattern-type-unit": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0} y {1}",
+                        "2": "{0} y {1}"
+                    },
+                    "listPattern-type-unit-short": {
+                        "start": "{0}, {1}",
+                        "middle": "{0}, {1}",
+                        "end": "{0}, {1}",
+                        "2": "{0} y {1}"
+                    },
+                    "listPattern-type-unit-narrow": {
+                        "start": "{0} {1}",
+                        "middle": "{0} {1}",
+                        "end": "{0} {1}",
+                        "2": "{0} {1}"
+                    }
+                }
+            }
+        }
+    }"#;
+    let provider = ListProvider::try_from(json_str).unwrap();
+
+    let and = ListFormatter::try_new(langid!("es"), &provider, Type::And, Width::Wide).unwrap();
+    assert_writeable_eq!("Mallorca y Menorca", and.format(&["Mallorca", "Menorca"]));
+    assert_writeable_eq!("Mallorca e Ibiza", and.format(&["Mallorca", "Ibiza"]));
+    assert_writeable_eq!(
+        "Valencia, Mallorca e Ibiza",
+        and.format(&["Valencia", "Mallorca", "Ibiza"])
+    );
+    assert_writeable_eq!("agua y hielo", and.format(&["agua", "hielo"]));
+    assert_writeable_eq!("padre e hijo", and.format(&["padre", "hijo"]));
+
+    let or = ListFormatter::try_new(langid!("es"), &provider, Type::Or, Width::Short).unwrap();
+    assert_writeable_eq!("7 u 8", or.format(&["7", "8"]));
+    assert_writeable_eq!("siete u ocho", or.format(&["siete", "ocho"]));
+    assert_writeable_eq!("uno o dos", or.format(&["uno", "dos"]));
+    assert_writeable_eq!("7 u 11", or.format(&["7", "11"]));
+    assert_writeable_eq!("7 o 110", or.format(&["7", "110"]));
+    assert_writeable_eq!("7 u 11.000", or.format(&["7", "11.000"]));
+    assert_writeable_eq!("7 o 1104324", or.format(&["7", "1104324"]));
+    assert_writeable_eq!("7 u 11043234", or.format(&["7", "11043234"]));
+
+    let unit = ListFormatter::try_new(langid!("es"), &provider, Type::Unit, Width::Narrow).unwrap();
+    assert_writeable_eq!("3 m 2 cm 1 mm", unit.format(&["3 m", "2 cm", "1 mm"]));
+}
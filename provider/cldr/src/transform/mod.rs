@@ -4,19 +4,33 @@
 
 mod aliases;
 mod dates;
+mod displaynames;
+mod duration;
 mod likelysubtags;
+mod list;
 mod numbers;
+mod plural_ranges;
 mod plurals;
+mod region_containment;
+mod region_info;
 mod time_zones;
+mod windows_zones;
 
 pub use aliases::AliasesProvider;
 pub use dates::{
     patterns::DatePatternsProvider, skeletons::DateSkeletonPatternsProvider,
     symbols::DateSymbolsProvider,
 };
+pub use displaynames::DisplayNamesProvider;
+pub use duration::DurationProvider;
 pub use likelysubtags::LikelySubtagsProvider;
+pub use list::ListProvider;
 pub use numbers::NumbersProvider;
+pub use plural_ranges::PluralRangesProvider;
 pub use plurals::PluralsProvider;
+pub use region_containment::RegionContainmentProvider;
+pub use region_info::RegionInfoProvider;
+pub use windows_zones::WindowsZonesProvider;
 
 use crate::support::LazyCldrProvider;
 use crate::CldrPaths;
@@ -26,18 +40,37 @@ use icu_provider::serde::SerdeSeDataStructMarker;
 
 use self::time_zones::TimeZonesProvider;
 
+/// Registry of the [`ResourceKeys`](ResourceKey) supported by the CLDR transformers.
+///
+/// Each transformer advertises the keys it is able to produce in its `ALL_KEYS` constant, which
+/// is listed here. [`CldrJsonDataProvider`] supports exactly the keys in this registry.
+///
+/// This lists the data sources, not the keys: every key here is also in the `KEY_REGISTRY` of
+/// the component that defines it, from which datagen selects the keys to export.
+pub const CLDR_KEY_REGISTRY: &[&[ResourceKey]] = &[
+    &aliases::ALL_KEYS,
+    &dates::symbols::ALL_KEYS,
+    &dates::skeletons::ALL_KEYS,
+    &dates::patterns::ALL_KEYS,
+    &displaynames::ALL_KEYS,
+    &duration::ALL_KEYS,
+    &likelysubtags::ALL_KEYS,
+    &list::ALL_KEYS,
+    &numbers::ALL_KEYS,
+    &plurals::ALL_KEYS,
+    &plural_ranges::ALL_KEYS,
+    &region_containment::ALL_KEYS,
+    &region_info::ALL_KEYS,
+    &time_zones::ALL_KEYS,
+    &windows_zones::ALL_KEYS,
+];
+
 /// Returns a list of all [`ResourceKeys`](ResourceKey) that this provider can produce.
 pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
-    let mut result: Vec<ResourceKey> = vec![];
-    result.extend(&aliases::ALL_KEYS);
-    result.extend(&dates::symbols::ALL_KEYS);
-    result.extend(&dates::skeletons::ALL_KEYS);
-    result.extend(&dates::patterns::ALL_KEYS);
-    result.extend(&likelysubtags::ALL_KEYS);
-    result.extend(&numbers::ALL_KEYS);
-    result.extend(&plurals::ALL_KEYS);
-    result.extend(&time_zones::ALL_KEYS);
-    result
+    CLDR_KEY_REGISTRY
+        .iter()
+        .flat_map(|keys| keys.iter().copied())
+        .collect()
 }
 
 #[derive(Debug)]
@@ -47,10 +80,17 @@ pub struct CldrJsonDataProvider<'a, 'data> {
     date_symbols: LazyCldrProvider<DateSymbolsProvider<'data>>,
     date_skeletons: LazyCldrProvider<DateSkeletonPatternsProvider<'data>>,
     date_patterns: LazyCldrProvider<DatePatternsProvider<'data>>,
+    displaynames: LazyCldrProvider<DisplayNamesProvider<'data>>,
+    duration: LazyCldrProvider<DurationProvider<'data>>,
     likelysubtags: LazyCldrProvider<LikelySubtagsProvider<'data>>,
+    list: LazyCldrProvider<ListProvider<'data>>,
     numbers: LazyCldrProvider<NumbersProvider>,
     plurals: LazyCldrProvider<PluralsProvider<'data>>,
+    plural_ranges: LazyCldrProvider<PluralRangesProvider<'data>>,
+    region_containment: LazyCldrProvider<RegionContainmentProvider<'data>>,
+    region_info: LazyCldrProvider<RegionInfoProvider<'data>>,
     time_zones: LazyCldrProvider<TimeZonesProvider<'data>>,
+    windows_zones: LazyCldrProvider<WindowsZonesProvider<'data>>,
 }
 
 impl<'a> CldrJsonDataProvider<'a, '_> {
@@ -61,10 +101,17 @@ impl<'a> CldrJsonDataProvider<'a, '_> {
             date_symbols: Default::default(),
             date_skeletons: Default::default(),
             date_patterns: Default::default(),
+            displaynames: Default::default(),
+            duration: Default::default(),
             likelysubtags: Default::default(),
+            list: Default::default(),
             numbers: Default::default(),
             plurals: Default::default(),
+            plural_ranges: Default::default(),
+            region_containment: Default::default(),
+            region_info: Default::default(),
             time_zones: Default::default(),
+            windows_zones: Default::default(),
         }
     }
 }
@@ -86,18 +133,42 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.date_patterns.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.displaynames.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
+        if let Some(result) = self.duration.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.likelysubtags.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.list.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.numbers.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
         if let Some(result) = self.plurals.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.plural_ranges.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
+        if let Some(result) = self
+            .region_containment
+            .try_load_serde(req, self.cldr_paths)?
+        {
+            return Ok(result);
+        }
+        if let Some(result) = self.region_info.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.time_zones.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.windows_zones.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         Err(DataError::MissingResourceKey(req.resource_path.key))
     }
 }
@@ -131,12 +202,27 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .displaynames
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .duration
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .likelysubtags
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self.list.try_supported_options(resc_key, self.cldr_paths)? {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .numbers
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -149,12 +235,36 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .plural_ranges
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .region_containment
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .region_info
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .time_zones
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .windows_zones
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         Err(DataError::MissingResourceKey(*resc_key))
     }
 }
@@ -162,8 +272,45 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
 impl<'a, 'd> KeyedDataProvider for CldrJsonDataProvider<'a, 'd> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
         PluralsProvider::supports_key(resc_key)
+            .or_else(|err| PluralRangesProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DateSymbolsProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DateSkeletonPatternsProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DatePatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| DisplayNamesProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| DurationProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| ListProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| RegionContainmentProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| RegionInfoProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| WindowsZonesProvider::or_else_supports_key(err, resc_key))
+    }
+}
+
+#[test]
+fn test_keys_registered() {
+    use icu_provider::registry;
+    use std::collections::BTreeSet;
+
+    let component_registries = [
+        icu_datetime::provider::KEY_REGISTRY,
+        icu_decimal::provider::KEY_REGISTRY,
+        icu_displaynames::provider::KEY_REGISTRY,
+        icu_durationformat::provider::KEY_REGISTRY,
+        icu_list::provider::KEY_REGISTRY,
+        icu_locale_canonicalizer::provider::KEY_REGISTRY,
+        icu_plurals::provider::KEY_REGISTRY,
+        icu_timezone::provider::KEY_REGISTRY,
+    ];
+    let registered_keys: BTreeSet<ResourceKey> = component_registries
+        .iter()
+        .flat_map(|component_registry| registry::iter_keys(component_registry))
+        .map(|info| info.key)
+        .collect();
+
+    for key in get_all_cldr_keys() {
+        assert!(
+            registered_keys.contains(&key),
+            "{} is not in the key registry of its component",
+            key
+        );
     }
 }
@@ -28,11 +28,31 @@ pub mod numbers_json {
         pub minus_sign: String,
         #[serde(rename = "plusSign")]
         pub plus_sign: String,
+        pub infinity: String,
+        pub nan: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MiscPatterns {
+        // This list is not comprehensive; add more fields when needed
+        /// Pattern such as `"~{0}"`
+        pub approximately: String,
+        /// Pattern such as `"{0}–{1}"`
+        pub range: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CompactDecimalFormats {
+        /// Map from keys such as `"1000-count-one"` to patterns such as `"0K"`
+        #[serde(rename = "decimalFormat")]
+        pub decimal_format: HashMap<String, String>,
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct DecimalFormats {
         pub standard: String,
+        pub short: Option<CompactDecimalFormats>,
+        pub long: Option<CompactDecimalFormats>,
     }
 
     #[derive(PartialEq, Debug, Default)]
@@ -41,6 +61,8 @@ pub mod numbers_json {
         pub symbols: HashMap<TinyStr8, Symbols>,
         /// Map from numbering system to decimal formats
         pub formats: HashMap<TinyStr8, DecimalFormats>,
+        /// Map from numbering system to miscellaneous patterns
+        pub misc_patterns: HashMap<TinyStr8, MiscPatterns>,
     }
 
     pub struct NumberingSystemDataVisitor;
@@ -76,6 +98,10 @@ pub mod numbers_json {
                         let value: DecimalFormats = access.next_value()?;
                         result.formats.insert(numsys, value);
                     }
+                    "miscPatterns" => {
+                        let value: MiscPatterns = access.next_value()?;
+                        result.misc_patterns.insert(numsys, value);
+                    }
                     _ => {
                         // When needed, consume "scientificFormats", "percentFormats", ...
                         // For now, ignore them.
@@ -102,6 +128,13 @@ pub mod numbers_json {
         #[serde(rename = "minimumGroupingDigits")]
         #[serde(deserialize_with = "deserialize_number_from_string")]
         pub minimum_grouping_digits: u8,
+        /// Map from a type such as `"native"` to a numbering system
+        #[serde(rename = "otherNumberingSystems", default)]
+        pub other_numbering_systems: HashMap<String, TinyStr8>,
+        /// Map from a plural category such as `"one"` to an ordinal minimal pair such as
+        /// `"Take the {0}st right."`, along with other minimal pairs
+        #[serde(rename = "minimalPairs", default)]
+        pub minimal_pairs: HashMap<String, String>,
         #[serde(flatten)]
         pub numsys_data: NumberingSystemData,
     }
@@ -7,8 +7,10 @@ use crate::error::Error;
 use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_decimal::provider::*;
+use icu_plurals::PluralCategory;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
 use icu_provider::prelude::*;
+use litemap::LiteMap;
 use std::borrow::Cow;
 use std::convert::TryFrom;
 use tinystr::TinyStr8;
@@ -17,8 +19,11 @@ mod cldr_serde;
 mod decimal_pattern;
 
 /// All keys that this module is able to produce.
-pub const ALL_KEYS: [ResourceKey; 1] = [
-    key::SYMBOLS_V1, //
+pub const ALL_KEYS: [ResourceKey; 4] = [
+    key::SYMBOLS_V1,
+    key::COMPACT_SHORT_V1,
+    key::COMPACT_LONG_V1,
+    key::ORDINALS_V1,
 ];
 
 /// A data provider reading from CLDR JSON plural rule files.
@@ -68,6 +73,37 @@ impl KeyedDataProvider for NumbersProvider {
 }
 
 impl NumbersProvider {
+    /// Returns the CLDR numbers data for the locale of the request.
+    fn get_numbers(
+        &self,
+        req: &DataRequest,
+    ) -> Result<&cldr_serde::numbers_json::Numbers, DataError> {
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        match self
+            .cldr_numbers_data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => Ok(&self.cldr_numbers_data[idx].1.numbers),
+            Err(_) => Err(DataError::MissingResourceOptions(req.clone())),
+        }
+    }
+
+    /// Returns the numbering systems that a locale has data for: those with symbols and the
+    /// other numbering systems of the locale. Only numbering systems with digits are included.
+    fn numbering_systems_for(&self, numbers: &cldr_serde::numbers_json::Numbers) -> Vec<TinyStr8> {
+        let mut nsnames: Vec<TinyStr8> = numbers
+            .numsys_data
+            .symbols
+            .keys()
+            .chain(numbers.other_numbering_systems.values())
+            .copied()
+            .filter(|nsname| self.get_digits_for_numbering_system(*nsname).is_some())
+            .collect();
+        nsnames.sort();
+        nsnames.dedup();
+        nsnames
+    }
+
     /// Returns the digits for the given numbering system name.
     fn get_digits_for_numbering_system(&self, nsname: TinyStr8) -> Option<[char; 10]> {
         match self
@@ -106,17 +142,16 @@ impl<'data> DataProvider<'data, DecimalSymbolsV1Marker> for NumbersProvider {
     ) -> Result<DataResponse<'data, DecimalSymbolsV1Marker>, DataError> {
         Self::supports_key(&req.resource_path.key)?;
         let langid = req.try_langid()?;
-        let cldr_langid: CldrLangID = langid.clone().into();
-        let numbers = match self
-            .cldr_numbers_data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
-        {
-            Ok(idx) => &self.cldr_numbers_data[idx].1.numbers,
-            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        let numbers = self.get_numbers(req)?;
+        let nsname = match req.resource_path.options.variant.as_deref() {
+            Some(variant) => match variant.parse::<TinyStr8>() {
+                Ok(nsname) if self.numbering_systems_for(numbers).contains(&nsname) => nsname,
+                _ => return Err(DataError::MissingResourceOptions(req.clone())),
+            },
+            None => numbers.default_numbering_system,
         };
-        let nsname = numbers.default_numbering_system;
 
-        let mut result = DecimalSymbolsV1::try_from(numbers)
+        let mut result = decimal_symbols(numbers, nsname)
             .map_err(|s| Error::Custom(s.to_string(), Some(langid.clone())))
             .map_err(DataError::new_resc_error)?;
         result.digits = self
@@ -132,67 +167,320 @@ impl<'data> DataProvider<'data, DecimalSymbolsV1Marker> for NumbersProvider {
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CompactDecimalPatternDataV1Marker> for NumbersProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CompactDecimalPatternDataV1Marker>, DataError> {
+        Self::supports_key(&req.resource_path.key)?;
+        let langid = req.try_langid()?;
+        let numbers = self.get_numbers(req)?;
+
+        // TODO(#510): Select from non-default numbering systems
+        let formats = numbers
+            .numsys_data
+            .formats
+            .get(&numbers.default_numbering_system)
+            .ok_or_else(|| {
+                Error::Custom(
+                    "Could not find formats for default numbering system".to_string(),
+                    Some(langid.clone()),
+                )
+            })
+            .map_err(DataError::new_resc_error)?;
+        let compact_formats = match req.resource_path.key {
+            key::COMPACT_SHORT_V1 => &formats.short,
+            key::COMPACT_LONG_V1 => &formats.long,
+            _ => return Err((&req.resource_path.key).into()),
+        };
+        let result = match compact_formats {
+            Some(compact_formats) => CompactDecimalPatternDataV1::try_from(compact_formats)
+                .map_err(|s| Error::Custom(s.to_string(), Some(langid.clone())))
+                .map_err(DataError::new_resc_error)?,
+            None => CompactDecimalPatternDataV1 {
+                patterns: LiteMap::new(),
+            },
+        };
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(result)),
         })
     }
 }
 
+impl<'data> DataProvider<'data, OrdinalAffixesV1Marker> for NumbersProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, OrdinalAffixesV1Marker>, DataError> {
+        Self::supports_key(&req.resource_path.key)?;
+        let numbers = self.get_numbers(req)?;
+
+        let mut affixes = LiteMap::new();
+        for (count, pair) in numbers.minimal_pairs.iter() {
+            // The ordinal minimal pairs are keyed by plural category alone; the other keys
+            // belong to cardinal, gender, and case minimal pairs.
+            let category = match PluralCategory::from_tr35_string(count) {
+                Some(category) => category,
+                None => continue,
+            };
+            if let Some(parsed) = parse_ordinal_minimal_pair(pair) {
+                affixes.insert(category, parsed);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(OrdinalAffixesV1 { affixes })),
+        })
+    }
+}
+
 icu_provider::impl_dyn_provider!(NumbersProvider, {
-    _ => DecimalSymbolsV1Marker,
+    key::SYMBOLS_V1 => DecimalSymbolsV1Marker,
+    key::COMPACT_SHORT_V1 => CompactDecimalPatternDataV1Marker,
+    key::COMPACT_LONG_V1 => CompactDecimalPatternDataV1Marker,
+    key::ORDINALS_V1 => OrdinalAffixesV1Marker,
 }, SERDE_SE, 'data);
 
 impl<'data> IterableDataProviderCore for NumbersProvider {
     #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
     fn supported_options_for_key(
         &self,
-        _resc_key: &ResourceKey,
+        resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
-        let list: Vec<ResourceOptions> = self
-            .cldr_numbers_data
-            .iter()
-            .map(|(l, _)| ResourceOptions {
-                variant: None,
-                // TODO(#568): Avoid the clone
-                langid: Some(l.langid.clone()),
-            })
-            .collect();
+        let list: Vec<ResourceOptions> = if *resc_key == key::SYMBOLS_V1 {
+            // Symbols are also available for the other numbering systems of each locale,
+            // with the numbering system as the variant.
+            self.cldr_numbers_data
+                .iter()
+                .flat_map(|(l, lang)| {
+                    std::iter::once(None)
+                        .chain(
+                            self.numbering_systems_for(&lang.numbers)
+                                .into_iter()
+                                .map(|nsname| Some(Cow::Owned(nsname.to_string()))),
+                        )
+                        .map(move |variant| ResourceOptions {
+                            variant,
+                            // TODO(#568): Avoid the clone
+                            langid: Some(l.langid.clone()),
+                        })
+                })
+                .collect()
+        } else {
+            self.cldr_numbers_data
+                .iter()
+                .map(|(l, _)| ResourceOptions {
+                    variant: None,
+                    // TODO(#568): Avoid the clone
+                    langid: Some(l.langid.clone()),
+                })
+                .collect()
+        };
         Ok(Box::new(list.into_iter()))
     }
 }
 
-impl TryFrom<&cldr_serde::numbers_json::Numbers> for DecimalSymbolsV1<'static> {
-    type Error = Cow<'static, str>;
+/// Builds the symbols of a locale for a numbering system. If the locale has no symbols or
+/// formats for the numbering system, those of its default numbering system are used.
+fn decimal_symbols(
+    numbers: &cldr_serde::numbers_json::Numbers,
+    nsname: TinyStr8,
+) -> Result<DecimalSymbolsV1<'static>, Cow<'static, str>> {
+    let symbols = numbers
+        .numsys_data
+        .symbols
+        .get(&nsname)
+        .or_else(|| {
+            numbers
+                .numsys_data
+                .symbols
+                .get(&numbers.default_numbering_system)
+        })
+        .ok_or("Could not find symbols for default numbering system")?;
+    let formats = numbers
+        .numsys_data
+        .formats
+        .get(&nsname)
+        .or_else(|| {
+            numbers
+                .numsys_data
+                .formats
+                .get(&numbers.default_numbering_system)
+        })
+        .ok_or("Could not find formats for default numbering system")?;
+    let misc_patterns = numbers
+        .numsys_data
+        .misc_patterns
+        .get(&nsname)
+        .or_else(|| {
+            numbers
+                .numsys_data
+                .misc_patterns
+                .get(&numbers.default_numbering_system)
+        })
+        .ok_or("Could not find misc patterns for default numbering system")?;
+    let parsed_pattern: decimal_pattern::DecimalPattern = formats
+        .standard
+        .parse()
+        .map_err(|s: decimal_pattern::Error| s.to_string())?;
 
-    fn try_from(other: &cldr_serde::numbers_json::Numbers) -> Result<Self, Self::Error> {
-        // TODO(#510): Select from non-default numbering systems
-        let symbols = other
-            .numsys_data
-            .symbols
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find symbols for default numbering system")?;
-        let formats = other
-            .numsys_data
-            .formats
-            .get(&other.default_numbering_system)
-            .ok_or("Could not find formats for default numbering system")?;
-        let parsed_pattern: decimal_pattern::DecimalPattern = formats
-            .standard
-            .parse()
-            .map_err(|s: decimal_pattern::Error| s.to_string())?;
+    Ok(DecimalSymbolsV1 {
+        minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
+        plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
+        decimal_separator: Cow::Owned(symbols.decimal.clone()),
+        grouping_separator: Cow::Owned(symbols.group.clone()),
+        grouping_sizes: GroupingSizesV1 {
+            primary: parsed_pattern.positive.primary_grouping,
+            secondary: parsed_pattern.positive.secondary_grouping,
+            min_grouping: numbers.minimum_grouping_digits,
+        },
+        digits: Default::default(), // to be filled in
+        infinity: Cow::Owned(symbols.infinity.clone()),
+        nan: Cow::Owned(symbols.nan.clone()),
+        range_pattern: parse_range_pattern(&misc_patterns.range)
+            .ok_or_else(|| format!("Invalid range pattern: {}", misc_patterns.range))?,
+        approximately_affixes: parse_approximately_pattern(&misc_patterns.approximately)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid approximately pattern: {}",
+                    misc_patterns.approximately
+                )
+            })?,
+    })
+}
 
-        Ok(Self {
-            minus_sign_affixes: parsed_pattern.localize_sign(&symbols.minus_sign),
-            plus_sign_affixes: parsed_pattern.localize_sign(&symbols.plus_sign),
-            decimal_separator: Cow::Owned(symbols.decimal.clone()),
-            grouping_separator: Cow::Owned(symbols.group.clone()),
-            grouping_sizes: GroupingSizesV1 {
-                primary: parsed_pattern.positive.primary_grouping,
-                secondary: parsed_pattern.positive.secondary_grouping,
-                min_grouping: other.minimum_grouping_digits,
+/// Removes the quotes around literal text in a pattern affix; `''` is a literal quote.
+fn unquote(affix: &str) -> String {
+    let mut result = String::with_capacity(affix.len());
+    let mut chars = affix.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            result.push(c);
+        } else if chars.peek() == Some(&'\'') {
+            result.push('\'');
+            chars.next();
+        }
+    }
+    result
+}
+
+/// Parses a compact decimal pattern such as `"00K"` applying to numbers of the given magnitude.
+fn parse_compact_pattern(
+    pattern: &str,
+    magnitude: i16,
+) -> Result<CompactPatternV1<'static>, Cow<'static, str>> {
+    // Only the positive subpattern is used; the sign is added by the decimal formatter.
+    let pattern = pattern.split(';').next().unwrap_or(pattern);
+    if pattern == "0" {
+        // The number is not compacted.
+        return Ok(CompactPatternV1 {
+            exponent: 0,
+            affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed(""),
             },
-            digits: Default::default(), // to be filled in
-        })
+        });
+    }
+    let start = pattern
+        .find('0')
+        .ok_or_else(|| format!("Compact pattern without digits: {}", pattern))?;
+    let zeros = pattern[start..].bytes().take_while(|b| *b == b'0').count();
+    Ok(CompactPatternV1 {
+        exponent: magnitude - (zeros as i16 - 1),
+        affixes: AffixesV1 {
+            prefix: Cow::Owned(unquote(&pattern[..start])),
+            suffix: Cow::Owned(unquote(&pattern[start + zeros..])),
+        },
+    })
+}
+
+/// Extracts the affixes attached to the number in an ordinal minimal pair, such as the "st" of
+/// `"Take the {0}st right."`. Returns `None` if the minimal pair has no placeholder.
+fn parse_ordinal_minimal_pair(pair: &str) -> Option<AffixesV1<'static>> {
+    let (before, after) = pair.split_once("{0}")?;
+    let prefix_start = before
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let suffix_end = after.find(char::is_whitespace).unwrap_or(after.len());
+    Some(AffixesV1 {
+        prefix: Cow::Owned(before[prefix_start..].to_string()),
+        suffix: Cow::Owned(after[..suffix_end].to_string()),
+    })
+}
+
+/// Splits a range pattern such as `"{0}–{1}"` into the strings around and between the numbers.
+fn parse_range_pattern(pattern: &str) -> Option<RangePatternV1<'static>> {
+    let (prefix, rest) = pattern.split_once("{0}")?;
+    let (separator, suffix) = rest.split_once("{1}")?;
+    Some(RangePatternV1 {
+        prefix: Cow::Owned(prefix.to_string()),
+        separator: Cow::Owned(separator.to_string()),
+        suffix: Cow::Owned(suffix.to_string()),
+    })
+}
+
+/// Splits a pattern such as `"~{0}"` into the affixes around the number.
+fn parse_approximately_pattern(pattern: &str) -> Option<AffixesV1<'static>> {
+    let (prefix, suffix) = pattern.split_once("{0}")?;
+    Some(AffixesV1 {
+        prefix: Cow::Owned(prefix.to_string()),
+        suffix: Cow::Owned(suffix.to_string()),
+    })
+}
+
+impl TryFrom<&cldr_serde::numbers_json::CompactDecimalFormats>
+    for CompactDecimalPatternDataV1<'static>
+{
+    type Error = Cow<'static, str>;
+
+    fn try_from(
+        other: &cldr_serde::numbers_json::CompactDecimalFormats,
+    ) -> Result<Self, Self::Error> {
+        let mut patterns: LiteMap<i16, LiteMap<PluralCategory, CompactPatternV1>> = LiteMap::new();
+        for (type_and_count, pattern) in other.decimal_format.iter() {
+            if type_and_count.contains("-alt-") {
+                continue;
+            }
+            let (number, count) = type_and_count
+                .split_once("-count-")
+                .ok_or_else(|| format!("Invalid compact pattern key: {}", type_and_count))?;
+            // Explicit counts such as "1" are not plural categories and are not supported.
+            let category = match PluralCategory::from_tr35_string(count) {
+                Some(category) => category,
+                None => continue,
+            };
+            let magnitude = number.len() as i16 - 1;
+            let parsed = parse_compact_pattern(pattern, magnitude)?;
+            match patterns.get_mut(&magnitude) {
+                Some(by_category) => {
+                    by_category.insert(category, parsed);
+                }
+                None => {
+                    let mut by_category = LiteMap::new();
+                    by_category.insert(category, parsed);
+                    patterns.insert(magnitude, by_category);
+                }
+            }
+        }
+        Ok(Self { patterns })
     }
 }
 
@@ -219,4 +507,208 @@ fn test_basic() {
 
     assert_eq!(ar_decimal.get().decimal_separator, "٫");
     assert_eq!(ar_decimal.get().digits[0], '٠');
+    assert_eq!(ar_decimal.get().minus_sign_affixes.prefix, "\u{061C}-");
+    assert_eq!(ar_decimal.get().plus_sign_affixes.prefix, "\u{061C}+");
+    assert_eq!(ar_decimal.get().plus_sign_affixes.suffix, "");
+    assert_eq!(ar_decimal.get().infinity, "∞");
+    assert_eq!(ar_decimal.get().nan, "ليس\u{a0}رقم");
+    assert_eq!(ar_decimal.get().range_pattern.separator, "–");
+    assert_eq!(ar_decimal.get().approximately_affixes.prefix, "~");
+}
+
+#[test]
+fn test_misc_patterns() {
+    let range = parse_range_pattern("{0}～{1}").unwrap();
+    assert_eq!(range.prefix, "");
+    assert_eq!(range.separator, "～");
+    assert_eq!(range.suffix, "");
+    let range = parse_range_pattern("از {0} تا {1}").unwrap();
+    assert_eq!(range.prefix, "از ");
+    assert_eq!(range.separator, " تا ");
+    assert_eq!(parse_range_pattern("{1}–{0}"), None);
+
+    let approximately = parse_approximately_pattern("約 {0}").unwrap();
+    assert_eq!(approximately.prefix, "約 ");
+    assert_eq!(approximately.suffix, "");
+}
+
+#[test]
+fn test_compact() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = NumbersProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |key, langid| -> DataPayload<CompactDecimalPatternDataV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en_short = load(key::COMPACT_SHORT_V1, langid!("en"));
+    let pattern = en_short
+        .get()
+        .patterns_for(4)
+        .and_then(|patterns| patterns.get(&PluralCategory::One))
+        .unwrap();
+    assert_eq!(pattern.exponent, 3);
+    assert_eq!(pattern.affixes.suffix, "K");
+
+    let fr_long = load(key::COMPACT_LONG_V1, langid!("fr"));
+    let patterns = fr_long.get().patterns_for(6).unwrap();
+    assert_eq!(
+        patterns.get(&PluralCategory::One).unwrap().affixes.suffix,
+        " million"
+    );
+    assert_eq!(
+        patterns.get(&PluralCategory::Other).unwrap().affixes.suffix,
+        " millions"
+    );
+
+    // In Japanese, numbers below 10,000 are not compacted.
+    let ja_short = load(key::COMPACT_SHORT_V1, langid!("ja"));
+    let pattern = ja_short
+        .get()
+        .patterns_for(3)
+        .and_then(|patterns| patterns.get(&PluralCategory::Other))
+        .unwrap();
+    assert_eq!(pattern.exponent, 0);
+    assert_eq!(
+        ja_short.get().patterns_for(5).unwrap()[&PluralCategory::Other].exponent,
+        4
+    );
+}
+
+#[test]
+fn test_ordinals() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = NumbersProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid| -> DataPayload<OrdinalAffixesV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::ORDINALS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en = load(langid!("en"));
+    assert_eq!(en.get().affixes.len(), 4);
+    assert_eq!(en.get().get(PluralCategory::Two).unwrap().suffix, "nd");
+    assert_eq!(en.get().get(PluralCategory::Other).unwrap().suffix, "th");
+
+    let fr = load(langid!("fr"));
+    assert_eq!(fr.get().get(PluralCategory::One).unwrap().suffix, "re");
+    assert_eq!(fr.get().get(PluralCategory::Few).unwrap().suffix, "e");
+
+    let es = load(langid!("es"));
+    assert_eq!(es.get().get(PluralCategory::Other).unwrap().suffix, ".ª");
+
+    // Japanese ordinals are separate words.
+    let ja = load(langid!("ja"));
+    assert_eq!(ja.get().get(PluralCategory::Other).unwrap().suffix, "");
+}
+
+#[test]
+fn test_parse_ordinal_minimal_pair() {
+    let parse = |pair| {
+        let affixes = parse_ordinal_minimal_pair(pair).unwrap();
+        (affixes.prefix.into_owned(), affixes.suffix.into_owned())
+    };
+    assert_eq!(
+        parse("Take\u{a0}the\u{a0}{0}st\u{a0}right."),
+        ("".to_string(), "st".to_string())
+    );
+    assert_eq!(parse("{0}-м"), ("".to_string(), "-м".to_string()));
+    assert_eq!(parse("第{0} 個"), ("第".to_string(), "".to_string()));
+    assert!(parse_ordinal_minimal_pair("no placeholder").is_none());
+}
+
+#[test]
+fn test_parse_compact_pattern() {
+    let pattern = parse_compact_pattern("00 'mil'", 4).unwrap();
+    assert_eq!(pattern.exponent, 3);
+    assert_eq!(pattern.affixes.prefix, "");
+    assert_eq!(pattern.affixes.suffix, " mil");
+
+    let pattern = parse_compact_pattern("¤0K", 3).unwrap();
+    assert_eq!(pattern.affixes.prefix, "¤");
+    assert_eq!(pattern.affixes.suffix, "K");
+
+    assert!(parse_compact_pattern("K", 3).is_err());
+}
+
+#[test]
+fn test_numbering_systems() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = NumbersProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let request = |variant: Option<&'static str>, langid| DataRequest {
+        resource_path: ResourcePath {
+            key: key::SYMBOLS_V1,
+            options: ResourceOptions {
+                variant: variant.map(Cow::Borrowed),
+                langid: Some(langid),
+            },
+        },
+    };
+    let load = |variant, langid| -> DataPayload<DecimalSymbolsV1Marker> {
+        provider
+            .load_payload(&request(variant, langid))
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    assert_eq!(load(None, langid!("th")).get().digits[1], '1');
+    assert_eq!(load(Some("thai"), langid!("th")).get().digits[1], '๑');
+
+    let ar_latn = load(Some("latn"), langid!("ar-EG"));
+    assert_eq!(ar_latn.get().digits[0], '0');
+    assert_eq!(ar_latn.get().decimal_separator, ".");
+
+    // Numbering systems that the locale does not use are not supported.
+    let result: Result<DataResponse<DecimalSymbolsV1Marker>, DataError> =
+        provider.load_payload(&request(Some("deva"), langid!("en")));
+    assert!(matches!(result, Err(DataError::MissingResourceOptions(_))));
+
+    // Algorithmic numbering systems have no digits.
+    let result: Result<DataResponse<DecimalSymbolsV1Marker>, DataError> =
+        provider.load_payload(&request(Some("jpan"), langid!("ja")));
+    assert!(matches!(result, Err(DataError::MissingResourceOptions(_))));
+
+    let th_options: Vec<ResourceOptions> = provider
+        .supported_options_for_key(&key::SYMBOLS_V1)
+        .unwrap()
+        .filter(|options| options.langid == Some(langid!("th")))
+        .collect();
+    assert_eq!(
+        th_options
+            .iter()
+            .map(|options| options.variant.as_deref())
+            .collect::<Vec<_>>(),
+        vec![None, Some("latn"), Some("thai")]
+    );
 }
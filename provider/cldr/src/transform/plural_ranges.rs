@@ -0,0 +1,217 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_plurals::provider::*;
+use icu_plurals::PluralCategory;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::RANGES_V1, //
+];
+
+/// A data provider reading from the CLDR JSON plural ranges file.
+#[derive(PartialEq, Debug)]
+pub struct PluralRangesProvider<'data> {
+    ranges: cldr_json::Ranges,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for PluralRangesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core()?
+            .join("supplemental")
+            .join("pluralRanges.json");
+        let data: cldr_json::Resource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+        Ok(Self {
+            ranges: data.supplemental.plurals,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for PluralRangesProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `pluralRanges.json` file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let data: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            ranges: data.supplemental.plurals,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for PluralRangesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if *resc_key != key::RANGES_V1 {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, PluralRangesV1Marker> for PluralRangesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralRangesV1Marker>, DataError> {
+        PluralRangesProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid = req.try_langid()?.clone().into();
+        let (_, r) = match self
+            .ranges
+            .0
+            .binary_search_by_key(&&cldr_langid, |(l, _)| l)
+        {
+            Ok(idx) => &self.ranges.0[idx],
+            Err(_) => return Err(req.clone().into()),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(
+                PluralRangesV1::try_from(r).map_err(DataError::new_resc_error)?,
+            )),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(PluralRangesProvider<'data>, {
+    _ => PluralRangesV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for PluralRangesProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        PluralRangesProvider::supports_key(resc_key)?;
+        let list: Vec<ResourceOptions> = self
+            .ranges
+            .0
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+impl TryFrom<&cldr_json::LocalePluralRanges> for PluralRangesV1 {
+    type Error = Error;
+    fn try_from(other: &cldr_json::LocalePluralRanges) -> Result<Self, Self::Error> {
+        let parse_category = |s: &str| {
+            PluralCategory::from_tr35_string(s)
+                .ok_or_else(|| Error::Custom(format!("Unknown plural category: {}", s), None))
+        };
+        let mut result = Self::default();
+        for (range, category) in other.0.iter() {
+            let (start, end) = range
+                .strip_prefix("pluralRange-start-")
+                .and_then(|s| s.split_once("-end-"))
+                .ok_or_else(|| Error::Custom(format!("Invalid plural range: {}", range), None))?;
+            result.insert(
+                parse_category(start)?,
+                parse_category(end)?,
+                parse_category(category)?,
+            );
+        }
+        Ok(result)
+    }
+}
+
+/// Serde structs for the CLDR JSON plural ranges file.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    /// Mapping from keys such as `"pluralRange-start-one-end-other"` to plural categories.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LocalePluralRanges(pub BTreeMap<String, String>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Ranges(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LocalePluralRanges)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        pub plurals: Ranges,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let json_str = r#"{
+        "supplemental": {
+            "plurals": {
+                "lv": {
+                    "pluralRange-start-zero-end-zero": "other",
+                    "pluralRange-start-zero-end-one": "one",
+                    "pluralRange-start-zero-end-other": "other",
+                    "pluralRange-start-one-end-zero": "other",
+                    "pluralRange-start-one-end-one": "one",
+                    "pluralRange-start-one-end-other": "other"
+                }
+            }
+        }
+    }"#;
+    let provider = PluralRangesProvider::try_from(json_str).unwrap();
+
+    let lv_ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::RANGES_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("lv")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        lv_ranges
+            .get()
+            .get(PluralCategory::Zero, PluralCategory::One),
+        Some(PluralCategory::One)
+    );
+    assert_eq!(
+        lv_ranges
+            .get()
+            .get(PluralCategory::One, PluralCategory::Zero),
+        Some(PluralCategory::Other)
+    );
+    assert_eq!(
+        lv_ranges
+            .get()
+            .get(PluralCategory::Other, PluralCategory::One),
+        None
+    );
+}
@@ -61,6 +61,10 @@ impl<'data> KeyedDataProvider for PluralsProvider<'data> {
         if resc_key.category != ResourceCategory::Plurals || resc_key.version != 1 {
             return Err(resc_key.into());
         }
+        // Plural ranges are served by `PluralRangesProvider`.
+        if *resc_key == key::RANGES_V1 {
+            return Err(resc_key.into());
+        }
         Ok(())
     }
 }
@@ -92,6 +96,7 @@ impl<'data> DataProvider<'data, PluralRuleStringsV1Marker> for PluralsProvider<'
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(PluralRuleStringsV1::from(r))),
         })
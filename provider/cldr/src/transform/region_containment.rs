@@ -0,0 +1,177 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_locale_canonicalizer::provider::*;
+use icu_locid::subtags::Region;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use tinystr::TinyStr4;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::REGION_CONTAINMENT_V1];
+
+/// A data provider reading from the CLDR JSON territory containment file.
+#[derive(PartialEq, Debug)]
+pub struct RegionContainmentProvider<'data> {
+    data: cldr_json::Resource,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for RegionContainmentProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core()?
+            .join("supplemental")
+            .join("territoryContainment.json");
+        let data: cldr_json::Resource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for RegionContainmentProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `territoryContainment.json`
+    /// file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let data: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for RegionContainmentProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::REGION_CONTAINMENT_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, RegionContainmentV1Marker> for RegionContainmentProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, RegionContainmentV1Marker>, DataError> {
+        RegionContainmentProvider::supports_key(&req.resource_path.key)?;
+        if !req.resource_path.options.is_empty() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let mut contains: Vec<(TinyStr4, Vec<TinyStr4>)> = Vec::new();
+        for (region, group) in self.data.supplemental.territory_containment.iter() {
+            // Alternative groupings, such as `EU-status-grouping` for the groupings that the
+            // EU is part of, or `151-status-deprecated`, are not part of the hierarchy.
+            if region.contains('-') {
+                continue;
+            }
+            let region: Region = region.parse().map_err(DataError::new_resc_error)?;
+            let contained = group
+                .contains
+                .iter()
+                .map(|region| region.parse::<Region>().map(TinyStr4::from))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(DataError::new_resc_error)?;
+            contains.push((region.into(), contained));
+        }
+        contains.sort_unstable_by_key(|k| k.0);
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: None,
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(RegionContainmentV1 { contains })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(RegionContainmentProvider<'data>, {
+    _ => RegionContainmentV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for RegionContainmentProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON territory containment file.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Group {
+        #[serde(rename = "_contains")]
+        pub contains: Vec<String>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(with = "tuple_vec_map", rename = "territoryContainment")]
+        pub territory_containment: Vec<(String, Group)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locale_canonicalizer::RegionContainment;
+    use icu_locid_macros::region;
+
+    // An excerpt of territoryContainment.json
+    let json_str = r#"{
+        "supplemental": {
+            "territoryContainment": {
+                "001": { "_contains": ["019", "002", "150", "142", "009"] },
+                "001-status-grouping": { "_contains": ["EU", "EZ", "UN"] },
+                "150": { "_contains": ["154", "155", "151", "039"] },
+                "151": { "_contains": ["BG", "BY", "CZ", "HU", "MD", "PL", "RO", "RU", "SK", "UA"] },
+                "151-status-deprecated": { "_contains": ["SU"] },
+                "155": { "_contains": ["AT", "BE", "CH", "DE", "FR", "LI", "LU", "MC", "NL"] },
+                "EU": { "_contains": ["AT", "BE", "BG", "CZ", "DE", "FR", "HU", "LU", "NL", "PL", "RO", "SK"] }
+            }
+        }
+    }"#;
+    let provider = RegionContainmentProvider::try_from(json_str).unwrap();
+    let containment = RegionContainment::new(&provider).unwrap();
+
+    assert_eq!(
+        containment.regions_in(region!("150")).collect::<Vec<_>>(),
+        vec![
+            region!("154"),
+            region!("155"),
+            region!("151"),
+            region!("039")
+        ]
+    );
+    assert!(containment
+        .regions_in(region!("EU"))
+        .any(|r| r == region!("PL")));
+    assert_eq!(containment.regions_in(region!("DE")).count(), 0);
+
+    assert!(containment.contains(region!("001"), region!("CH")));
+    assert!(containment.contains(region!("EU"), region!("DE")));
+    assert!(!containment.contains(region!("EU"), region!("CH")));
+    assert!(!containment.contains(region!("150"), region!("150")));
+    // Alternative groupings are not part of the hierarchy
+    assert!(!containment.contains(region!("001"), region!("EU")));
+    assert!(!containment.contains(region!("151"), region!("SU")));
+}
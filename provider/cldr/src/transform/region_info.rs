@@ -0,0 +1,247 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_locale_canonicalizer::provider::*;
+use icu_locid::subtags::Region;
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use tinystr::TinyStr4;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::REGION_INFO_V1];
+
+/// A data provider reading from the CLDR JSON territory info file.
+#[derive(PartialEq, Debug)]
+pub struct RegionInfoProvider<'data> {
+    data: cldr_json::Resource,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for RegionInfoProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core()?
+            .join("supplemental")
+            .join("territoryInfo.json");
+        let data: cldr_json::Resource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for RegionInfoProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `territoryInfo.json` file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let data: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for RegionInfoProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::REGION_INFO_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, RegionInfoV1Marker> for RegionInfoProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, RegionInfoV1Marker>, DataError> {
+        RegionInfoProvider::supports_key(&req.resource_path.key)?;
+        if !req.resource_path.options.is_empty() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let mut data = RegionInfoV1::default();
+        for (region, territory) in self.data.supplemental.territory_info.iter() {
+            let region: TinyStr4 = region
+                .parse::<Region>()
+                .map_err(DataError::new_resc_error)?
+                .into();
+            if let Some(population) = &territory.population {
+                // Populations are integers, but are parsed as floating point numbers in case
+                // CLDR writes them in exponent notation.
+                let population: f64 = population.parse().map_err(DataError::new_resc_error)?;
+                data.population.push((region, population.round() as u64));
+            }
+            if let Some(literacy) = &territory.literacy_percent {
+                let literacy: f64 = literacy.parse().map_err(DataError::new_resc_error)?;
+                data.literacy_permille
+                    .push((region, (literacy * 10.0).round() as u16));
+            }
+            let mut languages: Vec<(f64, LanguageIdentifier)> = Vec::new();
+            for (language, population) in territory.language_population.iter() {
+                if !matches!(
+                    population.official_status.as_deref(),
+                    Some("official") | Some("de_facto_official")
+                ) {
+                    continue;
+                }
+                let percent: f64 = population
+                    .population_percent
+                    .parse()
+                    .map_err(DataError::new_resc_error)?;
+                let language: LanguageIdentifier =
+                    language.parse().map_err(DataError::new_resc_error)?;
+                languages.push((percent, language));
+            }
+            if !languages.is_empty() {
+                // The sort is stable, so languages with the same share keep the order of CLDR.
+                languages.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+                data.official_languages.push((
+                    region,
+                    languages
+                        .into_iter()
+                        .map(|(_, language)| language)
+                        .collect(),
+                ));
+            }
+        }
+        data.population.sort_unstable_by_key(|k| k.0);
+        data.literacy_permille.sort_unstable_by_key(|k| k.0);
+        data.official_languages.sort_unstable_by_key(|k| k.0);
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: None,
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(RegionInfoProvider<'data>, {
+    _ => RegionInfoV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for RegionInfoProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON territory info file.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LanguagePopulation {
+        #[serde(rename = "_populationPercent")]
+        pub population_percent: String,
+        /// One of `official`, `de_facto_official`, `official_regional`, or
+        /// `official_minority`, if the language has an official status.
+        #[serde(rename = "_officialStatus")]
+        pub official_status: Option<String>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Territory {
+        #[serde(rename = "_population")]
+        pub population: Option<String>,
+        #[serde(rename = "_literacyPercent")]
+        pub literacy_percent: Option<String>,
+        #[serde(with = "tuple_vec_map", rename = "languagePopulation", default)]
+        pub language_population: Vec<(String, LanguagePopulation)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(with = "tuple_vec_map", rename = "territoryInfo")]
+        pub territory_info: Vec<(String, Territory)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locale_canonicalizer::RegionInfo;
+    use icu_locid_macros::{langid, region};
+
+    // An excerpt of territoryInfo.json
+    let json_str = r#"{
+        "supplemental": {
+            "territoryInfo": {
+                "AQ": {
+                    "_gdp": "0",
+                    "_literacyPercent": "99",
+                    "_population": "300"
+                },
+                "CH": {
+                    "_gdp": "590700000000",
+                    "_literacyPercent": "99",
+                    "_population": "8563760",
+                    "languagePopulation": {
+                        "de": { "_populationPercent": "30", "_officialStatus": "official" },
+                        "gsw": { "_populationPercent": "68" },
+                        "en": { "_populationPercent": "61" },
+                        "fr": { "_populationPercent": "23", "_officialStatus": "official" },
+                        "it": { "_populationPercent": "8.1", "_officialStatus": "official" },
+                        "rm": { "_populationPercent": "0.54", "_officialStatus": "official_regional" }
+                    }
+                },
+                "IN": {
+                    "_gdp": "9474000000000",
+                    "_literacyPercent": "74.4",
+                    "_population": "1389637446",
+                    "languagePopulation": {
+                        "hi": { "_populationPercent": "43", "_officialStatus": "official" },
+                        "en": { "_populationPercent": "19", "_officialStatus": "official" },
+                        "bn": { "_populationPercent": "7.4", "_officialStatus": "official_regional" }
+                    }
+                },
+                "US": {
+                    "_gdp": "22675300000000",
+                    "_literacyPercent": "99",
+                    "_population": "337341954",
+                    "languagePopulation": {
+                        "en": { "_populationPercent": "96", "_officialStatus": "de_facto_official" },
+                        "es": { "_populationPercent": "9.6", "_officialStatus": "official_regional" }
+                    }
+                }
+            }
+        }
+    }"#;
+    let provider = RegionInfoProvider::try_from(json_str).unwrap();
+    let info = RegionInfo::new(&provider).unwrap();
+
+    assert_eq!(info.population(region!("IN")), Some(1_389_637_446));
+    assert_eq!(info.population(region!("ZZ")), None);
+    assert_eq!(info.literacy_percent(region!("IN")), Some(74.4));
+    assert_eq!(info.literacy_percent(region!("CH")), Some(99.0));
+
+    assert_eq!(
+        info.languages_of(region!("IN")),
+        [langid!("hi"), langid!("en")]
+    );
+    assert_eq!(
+        info.languages_of(region!("CH")),
+        [langid!("de"), langid!("fr"), langid!("it")]
+    );
+    assert_eq!(info.languages_of(region!("US")), [langid!("en")]);
+    assert!(info.languages_of(region!("AQ")).is_empty());
+}
@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [key::WINDOWS_ZONES_V1];
+
+/// A data provider reading from the CLDR JSON Windows time zone mapping file.
+#[derive(PartialEq, Debug)]
+pub struct WindowsZonesProvider<'data> {
+    data: cldr_json::Resource,
+    _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for WindowsZonesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core()?
+            .join("supplemental")
+            .join("windowsZones.json");
+        let data: cldr_json::Resource =
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<&str> for WindowsZonesProvider<'_> {
+    type Error = Error;
+    /// Attempt to parse a JSON string containing the contents of a `windowsZones.json` file.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let data: cldr_json::Resource =
+            serde_json::from_str(input).map_err(|e| Error::Json(e, None))?;
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for WindowsZonesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::WINDOWS_ZONES_V1.match_key(*resc_key)
+    }
+}
+
+impl<'data> DataProvider<'data, WindowsZonesV1Marker> for WindowsZonesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, WindowsZonesV1Marker>, DataError> {
+        WindowsZonesProvider::supports_key(&req.resource_path.key)?;
+        if !req.resource_path.options.is_empty() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let mut data = WindowsZonesV1::default();
+        for map_timezone in &self.data.supplemental.windows_zones.map_timezones {
+            let map_zone = &map_timezone.map_zone;
+            data.iana_ids.insert(
+                Cow::Owned(format!("{}/{}", map_zone.windows_id, map_zone.territory)),
+                Cow::Owned(map_zone.iana_ids.clone()),
+            );
+            for iana_id in map_zone.iana_ids.split_ascii_whitespace() {
+                // An IANA time zone is listed both for its region and, if it is the default
+                // one, for 001, but always with the same Windows time zone
+                if !data.windows_ids.contains_key(iana_id) {
+                    data.windows_ids.insert(
+                        Cow::Owned(iana_id.to_string()),
+                        Cow::Owned(map_zone.windows_id.clone()),
+                    );
+                }
+            }
+        }
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: None,
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(WindowsZonesProvider<'data>, {
+    _ => WindowsZonesV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for WindowsZonesProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON Windows time zone mapping file.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MapZone {
+        #[serde(rename = "_other")]
+        pub windows_id: String,
+        #[serde(rename = "_territory")]
+        pub territory: String,
+        /// The IANA time zone IDs, separated by spaces.
+        #[serde(rename = "_type")]
+        pub iana_ids: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct MapTimezone {
+        #[serde(rename = "mapZone")]
+        pub map_zone: MapZone,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WindowsZones {
+        #[serde(rename = "mapTimezones")]
+        pub map_timezones: Vec<MapTimezone>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        #[serde(rename = "windowsZones")]
+        pub windows_zones: WindowsZones,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::region;
+    use icu_timezone::WindowsZones;
+
+    // An excerpt of windowsZones.json
+    let json_str = r#"{
+        "supplemental": {
+            "windowsZones": {
+                "mapTimezones": [
+                    { "mapZone": { "_other": "Eastern Standard Time", "_type": "America/New_York", "_territory": "001" } },
+                    { "mapZone": { "_other": "Eastern Standard Time", "_type": "America/Toronto", "_territory": "CA" } },
+                    { "mapZone": { "_other": "Eastern Standard Time", "_type": "America/New_York America/Detroit", "_territory": "US" } },
+                    { "mapZone": { "_other": "India Standard Time", "_type": "Asia/Calcutta", "_territory": "001" } },
+                    { "mapZone": { "_other": "India Standard Time", "_type": "Asia/Calcutta", "_territory": "IN" } },
+                    { "mapZone": { "_other": "W. Europe Standard Time", "_type": "Europe/Berlin", "_territory": "001" } },
+                    { "mapZone": { "_other": "W. Europe Standard Time", "_type": "Europe/Berlin Europe/Busingen", "_territory": "DE" } },
+                    { "mapZone": { "_other": "W. Europe Standard Time", "_type": "Europe/Zurich", "_territory": "CH" } }
+                ]
+            }
+        }
+    }"#;
+    let provider = WindowsZonesProvider::try_from(json_str).unwrap();
+    let windows_zones = WindowsZones::try_new(&provider).unwrap();
+
+    assert_eq!(
+        windows_zones.iana_id("Eastern Standard Time", None),
+        Some("America/New_York")
+    );
+    assert_eq!(
+        windows_zones.iana_id("Eastern Standard Time", Some(region!("CA"))),
+        Some("America/Toronto")
+    );
+    // Regions without a mapping of their own use the default one
+    assert_eq!(
+        windows_zones.iana_id("India Standard Time", Some(region!("LK"))),
+        Some("Asia/Calcutta")
+    );
+    assert_eq!(
+        windows_zones
+            .iana_ids("W. Europe Standard Time", Some(region!("DE")))
+            .collect::<Vec<_>>(),
+        vec!["Europe/Berlin", "Europe/Busingen"]
+    );
+    assert_eq!(windows_zones.iana_id("Mars Standard Time", None), None);
+
+    assert_eq!(
+        windows_zones.windows_id("America/Detroit"),
+        Some("Eastern Standard Time")
+    );
+    assert_eq!(
+        windows_zones.windows_id("Europe/Zurich"),
+        Some("W. Europe Standard Time")
+    );
+    assert_eq!(windows_zones.windows_id("Asia/Kolkata"), None);
+}
@@ -0,0 +1,192 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data providers that load data asynchronously, such as over the network.
+//!
+//! ICU4X components are constructed synchronously from a [`DataProvider`]. To use data from an
+//! [`AsyncDataProvider`], the data is first fetched into a [`PrefetchedDataProvider`], which
+//! can then be used to construct components.
+//!
+//! *Enabled with the "async" feature*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::async_provider::*;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_locid_macros::langid;
+//!
+//! async fn load_hello_world() -> Result<DataPayload<'static, HelloWorldV1Marker>, DataError> {
+//!     // An asynchronous provider; in a web app, this could fetch data over the network.
+//!     let async_provider =
+//!         SyncProviderAdapter(HelloWorldProvider::new_with_placeholder_data());
+//!
+//!     let request = DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: ResourceOptions {
+//!                 variant: None,
+//!                 langid: Some(langid!("de")),
+//!             },
+//!         },
+//!     };
+//!
+//!     let mut provider = PrefetchedDataProvider::new();
+//!     provider
+//!         .prefetch::<HelloWorldV1Marker, _>(&async_provider, request.clone())
+//!         .await?;
+//!
+//!     // The prefetched data is loaded synchronously.
+//!     provider.load_payload(&request)?.take_payload()
+//! }
+//! ```
+
+use crate::error::Error;
+use crate::prelude::*;
+use crate::yoke::trait_hack::YokeTraitHack;
+use crate::yoke::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::future::Future;
+use core::pin::Pin;
+
+/// The future returned by [`AsyncDataProvider::load_payload`].
+pub type LoadPayloadFuture<'a, 'data, M> =
+    Pin<Box<dyn Future<Output = Result<DataResponse<'data, M>, Error>> + 'a>>;
+
+/// A data provider that loads a payload of a specific type asynchronously.
+///
+/// This is the asynchronous counterpart of [`DataProvider`]. Since the future is boxed, the
+/// trait can be implemented with an `async` block:
+///
+/// ```
+/// use icu_provider::async_provider::*;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use std::borrow::Cow;
+///
+/// struct NetworkProvider;
+///
+/// impl NetworkProvider {
+///     async fn fetch(&self, _req: &DataRequest) -> Result<String, DataError> {
+///         // Fetch data over the network here.
+///         Ok("Hello World".to_string())
+///     }
+/// }
+///
+/// impl AsyncDataProvider<'static, HelloWorldV1Marker> for NetworkProvider {
+///     fn load_payload<'a>(
+///         &'a self,
+///         req: &'a DataRequest,
+///     ) -> LoadPayloadFuture<'a, 'static, HelloWorldV1Marker>
+///     where
+///         'static: 'a,
+///     {
+///         Box::pin(async move {
+///             let message = self.fetch(req).await?;
+///             Ok(DataResponse {
+///                 metadata: Default::default(),
+///                 payload: Some(DataPayload::from_owned(HelloWorldV1 {
+///                     message: Cow::Owned(message),
+///                 })),
+///             })
+///         })
+///     }
+/// }
+/// ```
+pub trait AsyncDataProvider<'data, M>
+where
+    M: DataMarker<'data>,
+{
+    /// Query the provider for data, returning a future resolving to the result.
+    ///
+    /// The future resolves to [`Ok`] if the request successfully loaded data. If data failed to
+    /// load, it resolves to an Error with more information.
+    fn load_payload<'a>(&'a self, req: &'a DataRequest) -> LoadPayloadFuture<'a, 'data, M>
+    where
+        'data: 'a,
+        M: 'a;
+}
+
+/// An [`AsyncDataProvider`] wrapping a synchronous [`DataProvider`].
+///
+/// The returned futures are immediately ready.
+pub struct SyncProviderAdapter<P>(pub P);
+
+impl<'data, M, P> AsyncDataProvider<'data, M> for SyncProviderAdapter<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M>,
+{
+    fn load_payload<'a>(&'a self, req: &'a DataRequest) -> LoadPayloadFuture<'a, 'data, M>
+    where
+        'data: 'a,
+        M: 'a,
+    {
+        Box::pin(core::future::ready(self.0.load_payload(req)))
+    }
+}
+
+/// A [`DataProvider`] serving data that was loaded ahead of time from an [`AsyncDataProvider`].
+///
+/// Data of any type can be prefetched into the same provider. Requests that were not prefetched
+/// fail with [`DataError::MissingResourceKey`] or [`DataError::MissingResourceOptions`].
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Default)]
+pub struct PrefetchedDataProvider {
+    /// The prefetched responses, each a `DataResponse<'static, M>` for the marker `M` that was
+    /// used to prefetch it.
+    responses: Vec<(DataRequest, Box<dyn Any>)>,
+}
+
+impl PrefetchedDataProvider {
+    /// Creates an empty [`PrefetchedDataProvider`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the data for a request from an [`AsyncDataProvider`] and stores it, replacing any
+    /// data previously stored for the same request.
+    pub async fn prefetch<M, P>(&mut self, provider: &P, req: DataRequest) -> Result<(), Error>
+    where
+        M: DataMarker<'static> + 'static,
+        P: AsyncDataProvider<'static, M> + ?Sized,
+    {
+        let response: Box<dyn Any> = Box::new(provider.load_payload(&req).await?);
+        match self.responses.iter_mut().find(|(r, _)| *r == req) {
+            Some((_, existing)) => *existing = response,
+            None => self.responses.push((req, response)),
+        }
+        Ok(())
+    }
+}
+
+impl<M> DataProvider<'static, M> for PrefetchedDataProvider
+where
+    M: DataMarker<'static> + 'static,
+    for<'a> YokeTraitHack<<M::Yokeable as Yokeable<'a>>::Output>: Clone,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'static, M>, Error> {
+        match self.responses.iter().find(|(r, _)| r == req) {
+            Some((_, response)) => response
+                .downcast_ref::<DataResponse<'static, M>>()
+                .cloned()
+                .ok_or_else(|| Error::MismatchedType {
+                    actual: Some((**response).type_id()),
+                    generic: Some(TypeId::of::<DataResponse<'static, M>>()),
+                }),
+            None if self
+                .responses
+                .iter()
+                .any(|(r, _)| r.resource_path.key == req.resource_path.key) =>
+            {
+                Err(Error::MissingResourceOptions(req.clone()))
+            }
+            None => Err(Error::MissingResourceKey(req.resource_path.key)),
+        }
+    }
+}
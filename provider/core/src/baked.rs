@@ -0,0 +1,329 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data baked into the binary as Rust source code.
+//!
+//! Data structs implementing [`Bake`] can be written as Rust expressions. `icu4x-datagen` uses
+//! this to generate a Rust source file containing a `static` table of data structs, which is
+//! served by [`BakedDataProvider`] without any deserialization. Since the data structs are
+//! compiled into the binary, this is well suited for `no_std` targets and for minimal startup
+//! latency.
+//!
+//! To generate baked data, use the `--format baked` option of `icu4x-datagen`. The generated file
+//! refers to data structs by their absolute paths, so the crates defining them must be
+//! dependencies of the crate including the file. It also refers to `::alloc`, which requires
+//! `extern crate alloc;` in the crate root.
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate alloc;
+//!
+//! use icu_locid_macros::langid;
+//! use icu_provider::baked::BakedDataProvider;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//!
+//! // Usually generated by icu4x-datagen and included with `include!`.
+//! static DATA: &icu_provider::baked::BakedData = &[
+//!     (
+//!         "core/helloworld@1/de",
+//!         &::icu_provider::hello_world::HelloWorldV1 {
+//!             message: ::alloc::borrow::Cow::Borrowed("Hallo Welt"),
+//!         },
+//!     ),
+//!     (
+//!         "core/helloworld@1/ja",
+//!         &::icu_provider::hello_world::HelloWorldV1 {
+//!             message: ::alloc::borrow::Cow::Borrowed("こんにちは世界"),
+//!         },
+//!     ),
+//! ];
+//!
+//! fn main() {
+//!     let provider = BakedDataProvider::new(DATA);
+//!
+//!     let payload: DataPayload<HelloWorldV1Marker> = provider
+//!         .load_payload(&DataRequest {
+//!             resource_path: ResourcePath {
+//!                 key: key::HELLO_WORLD_V1,
+//!                 options: langid!("ja").into(),
+//!             },
+//!         })
+//!         .expect("Loading should succeed")
+//!         .take_payload()
+//!         .expect("Data should be present");
+//!
+//!     assert_eq!("こんにちは世界", payload.get().message);
+//! }
+//! ```
+
+use crate::error::Error;
+use crate::prelude::*;
+use alloc::borrow::{Cow, ToOwned};
+use core::any::{Any, TypeId};
+use core::fmt;
+use writeable::Writeable;
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use litemap::LiteMap;
+
+/// A table of data structs baked into the binary, sorted by resource path.
+///
+/// This is the type of the table generated by `icu4x-datagen --format baked`.
+pub type BakedData = [(&'static str, &'static (dyn Any + Send + Sync))];
+
+/// A data struct that can be written as a Rust expression, for use in baked data.
+///
+/// The expression must be valid in a `static` initializer and evaluate to a value equal to
+/// `self`. Types are referred to by their absolute paths, and strings are written as
+/// [`Cow::Borrowed`] so that loading the baked data does not copy them.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::baked::Bake;
+/// use icu_provider::hello_world::HelloWorldV1;
+///
+/// let data = HelloWorldV1 {
+///     message: "Hello World".into(),
+/// };
+/// let mut output = String::new();
+/// data.bake(&mut output).expect("impl Write for String is infallible");
+///
+/// assert_eq!(
+///     "::icu_provider::hello_world::HelloWorldV1 { \
+///         message: ::alloc::borrow::Cow::Borrowed(\"Hello World\") }",
+///     output
+/// );
+/// ```
+pub trait Bake {
+    /// Writes a Rust expression evaluating to `self` to a sink.
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result;
+}
+
+macro_rules! impl_bake_display {
+    ($($ty:ty),+) => {
+        $(
+            impl Bake for $ty {
+                fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+                    write!(sink, "{}", self)
+                }
+            }
+        )+
+    };
+}
+
+impl_bake_display!(bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Bake for char {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        // The Debug representation is an escaped character literal.
+        write!(sink, "{:?}", self)
+    }
+}
+
+impl Bake for str {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        // The Debug representation is an escaped string literal.
+        write!(sink, "{:?}", self)
+    }
+}
+
+impl<T> Bake for &T
+where
+    T: Bake + ?Sized,
+{
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        (*self).bake(sink)
+    }
+}
+
+impl<B> Bake for Cow<'_, B>
+where
+    B: Bake + ToOwned + ?Sized,
+{
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::alloc::borrow::Cow::Borrowed(")?;
+        (**self).bake(sink)?;
+        sink.write_char(')')
+    }
+}
+
+impl<T> Bake for Option<T>
+where
+    T: Bake,
+{
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Some(value) => {
+                sink.write_str("Some(")?;
+                value.bake(sink)?;
+                sink.write_char(')')
+            }
+            None => sink.write_str("None"),
+        }
+    }
+}
+
+impl<T, const N: usize> Bake for [T; N]
+where
+    T: Bake,
+{
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_char('[')?;
+        for (i, value) in self.iter().enumerate() {
+            if i != 0 {
+                sink.write_str(", ")?;
+            }
+            value.bake(sink)?;
+        }
+        sink.write_char(']')
+    }
+}
+
+/// A data provider serving data structs baked into the binary.
+///
+/// The data is generated by `icu4x-datagen --format baked`. Payloads are cloned from the
+/// `static` data structs; their strings are borrowed rather than copied.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, Copy)]
+pub struct BakedDataProvider {
+    data: &'static BakedData,
+}
+
+impl BakedDataProvider {
+    /// Creates a [`BakedDataProvider`] from a table of baked data.
+    ///
+    /// The table must be sorted by resource path, as generated by `icu4x-datagen`.
+    pub fn new(data: &'static BakedData) -> Self {
+        Self { data }
+    }
+
+    /// Returns whether the table contains any data struct for the key.
+    fn has_key(&self, key: &ResourceKey) -> bool {
+        let key = key.write_to_string();
+        self.data.iter().any(|(p, _)| {
+            p.strip_prefix(&*key)
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+impl<M> DataProvider<'static, M> for BakedDataProvider
+where
+    M: DataMarker<'static>,
+    M::Yokeable: Clone,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'static, M>, Error> {
+        let path = req.resource_path.write_to_string();
+        let index = match self.data.binary_search_by(|(p, _)| (*p).cmp(&*path)) {
+            Ok(index) => index,
+            Err(_) if self.has_key(&req.resource_path.key) => {
+                return Err(Error::MissingResourceOptions(req.clone()))
+            }
+            Err(_) => return Err(Error::MissingResourceKey(req.resource_path.key)),
+        };
+        let data = self.data[index].1;
+        let data = data
+            .downcast_ref::<M::Yokeable>()
+            .ok_or_else(|| Error::MismatchedType {
+                actual: Some(data.type_id()),
+                generic: Some(TypeId::of::<M::Yokeable>()),
+            })?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(data.clone())),
+        })
+    }
+}
+
+/// A data exporter that writes data structs implementing [`Bake`] to a Rust source file, to be
+/// served by [`BakedDataProvider`].
+///
+/// The file defines a `static DATA: &`[`BakedData`] table and can be included with `include!`.
+///
+/// For a command-line user interface, see the `--format baked` option of `icu4x-datagen`.
+///
+/// *Enabled with the "std" feature*
+#[cfg(feature = "std")]
+pub struct BakedExporter<'w> {
+    entries: LiteMap<String, String>,
+    sink: Box<dyn std::io::Write + 'w>,
+}
+
+#[cfg(feature = "std")]
+impl<'w> BakedExporter<'w> {
+    /// Create a [`BakedExporter`] that writes to the given I/O stream.
+    pub fn new_with_sink(sink: Box<dyn std::io::Write + 'w>) -> Self {
+        Self {
+            entries: LiteMap::new(),
+            sink,
+        }
+    }
+
+    /// Writes the Rust source file containing all data structs exported so far.
+    ///
+    /// This is equivalent to [`DataExporter::close`](crate::export::DataExporter::close), but
+    /// does not require a type argument.
+    pub fn close(&mut self) -> Result<(), Error> {
+        use std::io::Write;
+        let mut write = || -> std::io::Result<()> {
+            writeln!(self.sink, "// @generated by icu4x-datagen. Do not edit.")?;
+            writeln!(self.sink)?;
+            writeln!(self.sink, "#[rustfmt::skip]")?;
+            writeln!(
+                self.sink,
+                "pub static DATA: &::icu_provider::baked::BakedData = &["
+            )?;
+            for (path, expression) in self.entries.iter() {
+                writeln!(self.sink, "    ({:?}, &{}),", path, expression)?;
+            }
+            writeln!(self.sink, "];")
+        };
+        write().map_err(|e| e.to_string())?;
+        self.entries.clear();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for BakedExporter<'_> {
+    fn drop(&mut self) {
+        if !self.entries.is_empty() {
+            panic!("Please call close before dropping BakedExporter");
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'data, M> crate::export::DataExporter<'data, M> for BakedExporter<'_>
+where
+    M: DataMarker<'data>,
+    for<'a> &'a <M::Yokeable as crate::yoke::Yokeable<'a>>::Output: Bake,
+{
+    fn put_payload(
+        &mut self,
+        req: DataRequest,
+        payload: DataPayload<'data, M>,
+    ) -> Result<(), Error> {
+        let mut expression = String::new();
+        Bake::bake(&payload.get(), &mut expression).expect("impl Write for String is infallible");
+        self.entries
+            .insert(req.resource_path.write_to_string(), expression);
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        BakedExporter::close(self)
+    }
+}
@@ -13,7 +13,9 @@ use crate::resource::ResourcePath;
 use crate::yoke::trait_hack::YokeTraitHack;
 use crate::yoke::*;
 
+use alloc::boxed::Box;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 
 use core::convert::TryFrom;
 use core::fmt;
@@ -29,11 +31,7 @@ pub struct DataRequest {
 
 impl fmt::Display for DataRequest {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}/{}",
-            self.resource_path.key, self.resource_path.options
-        )
+        fmt::Display::fmt(&self.resource_path, f)
     }
 }
 
@@ -93,6 +91,11 @@ impl DataRequest {
 pub struct DataResponseMetadata {
     /// The language of the returned data, or None if the resource key isn't localized.
     pub data_langid: Option<LanguageIdentifier>,
+    /// The languages whose data was requested but unavailable before the returned data was
+    /// found, starting with the requested language. Empty if no fallback happened.
+    ///
+    /// See [`LocaleFallbackProvider`](crate::fallback::LocaleFallbackProvider).
+    pub fallback_trace: Vec<LanguageIdentifier>,
 }
 
 pub(crate) enum DataPayloadInner<'data, M>
@@ -102,6 +105,24 @@ where
     RcStruct(Yoke<M::Yokeable, Rc<M::Cart>>),
     Owned(Yoke<M::Yokeable, ()>),
     RcBuf(Yoke<M::Yokeable, Rc<[u8]>>),
+    RcByteBuffer(Yoke<M::Yokeable, Rc<dyn ByteBuffer>>),
+}
+
+/// A byte buffer that can back a [`DataPayload`] without copying, such as a memory-mapped file.
+///
+/// This trait is implemented for all types that can be borrowed as a byte slice. See
+/// [`DataPayload::try_from_byte_buffer()`].
+pub trait ByteBuffer: AsRef<[u8]> {}
+
+impl<T> ByteBuffer for T where T: AsRef<[u8]> + ?Sized {}
+
+// This is safe because `dyn ByteBuffer` has no lifetime parameters.
+unsafe impl<'a> IsCovariant<'a> for dyn ByteBuffer {}
+
+impl ZeroCopyFrom<dyn ByteBuffer> for &'static [u8] {
+    fn zero_copy_from<'b>(cart: &'b (dyn ByteBuffer + 'static)) -> &'b [u8] {
+        cart.as_ref()
+    }
 }
 
 /// A container for data payloads returned from a [`DataProvider`].
@@ -113,6 +134,8 @@ where
 /// 1. Fully-owned structured data ([`DataPayload::from_owned()`])
 /// 2. Partially-owned structured data in an [`Rc`] ([`DataPayload::from_partial_owned()`])
 /// 3. A reference-counted byte buffer ([`DataPayload::try_from_rc_buffer()`])
+/// 4. A reference-counted [`ByteBuffer`], such as a memory-mapped file
+///    ([`DataPayload::try_from_byte_buffer()`])
 ///
 /// The type of the data stored in [`DataPayload`], and the type of the structured data store
 /// (cart), is determined by the [`DataMarker`] type parameter.
@@ -192,6 +215,7 @@ where
             RcStruct(yoke) => RcStruct(yoke.clone()),
             Owned(yoke) => Owned(yoke.clone()),
             RcBuf(yoke) => RcBuf(yoke.clone()),
+            RcByteBuffer(yoke) => RcByteBuffer(yoke.clone()),
         };
         Self { inner: new_inner }
     }
@@ -369,6 +393,50 @@ where
         })
     }
 
+    /// Convert a [`ByteBuffer`] into a [`DataPayload`], borrowing from the buffer without
+    /// copying it. A function must be provided to perform the conversion. This can often be a
+    /// Serde deserialization operation.
+    ///
+    /// This is useful for buffers that are not heap-allocated, such as memory-mapped files. For
+    /// buffers that are already in an [`Rc`]`<[u8]>`, use
+    /// [`try_from_rc_buffer()`](Self::try_from_rc_buffer).
+    ///
+    /// This constructor creates `'static` payloads; borrowing is handled by [`Yoke`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use std::rc::Rc;
+    ///
+    /// let json_buffer: Rc<dyn ByteBuffer> =
+    ///     Rc::new("{\"message\":\"Hello World\"}".as_bytes().to_vec());
+    ///
+    /// let payload = DataPayload::<HelloWorldV1Marker>::try_from_byte_buffer(
+    ///     json_buffer,
+    ///     |bytes| {
+    ///         serde_json::from_slice(bytes)
+    ///     }
+    /// )
+    /// .expect("JSON is valid");
+    ///
+    /// assert_eq!("Hello World", payload.get().message);
+    /// # } // feature = "provider_serde"
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_from_byte_buffer<E>(
+        buffer: Rc<dyn ByteBuffer>,
+        f: for<'de> fn(&'de [u8]) -> Result<<M::Yokeable as Yokeable<'de>>::Output, E>,
+    ) -> Result<Self, E> {
+        let yoke = Yoke::<&'static [u8], Rc<dyn ByteBuffer>>::attach_to_rc_cart(buffer)
+            .try_project_with_capture(f, |bytes, f, _| f(bytes))?;
+        Ok(Self {
+            inner: DataPayloadInner::RcByteBuffer(yoke),
+        })
+    }
+
     /// Convert a fully owned (`'static`) data struct into a DataPayload.
     ///
     /// This constructor creates `'static` payloads.
@@ -437,6 +505,7 @@ where
             RcStruct(yoke) => yoke.with_mut(f),
             Owned(yoke) => yoke.with_mut(f),
             RcBuf(yoke) => yoke.with_mut(f),
+            RcByteBuffer(yoke) => yoke.with_mut(f),
         }
     }
 
@@ -462,6 +531,32 @@ where
             RcStruct(yoke) => yoke.get(),
             Owned(yoke) => yoke.get(),
             RcBuf(yoke) => yoke.get(),
+            RcByteBuffer(yoke) => yoke.get(),
+        }
+    }
+
+    /// Returns the length in bytes of the buffer backing this [`DataPayload`], or [`None`] if
+    /// the payload is not backed by a buffer.
+    ///
+    /// Payloads deserialized from a buffer, such as those returned by `FsDataProvider`, borrow
+    /// from that buffer, so its length approximates the memory used by the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::marker::CowStrMarker;
+    ///
+    /// let payload = DataPayload::<CowStrMarker>::from_static_str("Demo");
+    ///
+    /// assert_eq!(None, payload.buffer_len());
+    /// ```
+    pub fn buffer_len(&self) -> Option<usize> {
+        use DataPayloadInner::*;
+        match &self.inner {
+            RcStruct(_) | Owned(_) => None,
+            RcBuf(yoke) => Some(yoke.backing_cart().len()),
+            RcByteBuffer(yoke) => Some((**yoke.backing_cart()).as_ref().len()),
         }
     }
 
@@ -481,6 +576,9 @@ where
     /// - [`DataPayload::map_project_with_capture()`] to pass context to the mapping function
     /// - [`DataPayload::map_project_cloned_with_capture()`] to do both of these things
     ///
+    /// Each of these methods also has a `try_` version, such as [`DataPayload::try_map_project()`],
+    /// which bubbles up an error from the mapping function.
+    ///
     /// # Examples
     ///
     /// Map from `HelloWorldV1` to a `Cow<str>` containing just the message:
@@ -534,6 +632,9 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.project(f)),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.project(f)),
+            },
         }
     }
 
@@ -592,6 +693,9 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.project_cloned(f)),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.project_cloned(f)),
+            },
         }
     }
 
@@ -684,6 +788,9 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.project_with_capture(capture, f)),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.project_with_capture(capture, f)),
+            },
         }
     }
 
@@ -750,9 +857,136 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.project_cloned_with_capture(capture, f)),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.project_cloned_with_capture(capture, f)),
+            },
         }
     }
 
+    /// Version of [`DataPayload::map_project()`] that moves `self` and bubbles up an error
+    /// from `f`.
+    ///
+    /// # Examples
+    ///
+    /// Same example as above, but fail if the message is empty:
+    ///
+    /// ```
+    /// // Same imports and definitions as above
+    /// # use icu_provider::hello_world::*;
+    /// # use icu_provider::prelude::*;
+    /// # use std::borrow::Cow;
+    /// # struct HelloWorldV1MessageMarker;
+    /// # impl<'data> DataMarker<'data> for HelloWorldV1MessageMarker {
+    /// #     type Yokeable = Cow<'static, str>;
+    /// #     type Cart = HelloWorldV1<'data>;
+    /// # }
+    ///
+    /// let p1: DataPayload<HelloWorldV1Marker> = DataPayload::from_owned(HelloWorldV1 {
+    ///     message: Cow::Borrowed("Hello World")
+    /// });
+    ///
+    /// let p2: DataPayload<HelloWorldV1MessageMarker> = p1.try_map_project(|obj, _| {
+    ///     if obj.message.is_empty() {
+    ///         return Err(());
+    ///     }
+    ///     Ok(obj.message)
+    /// })?;
+    ///
+    /// assert_eq!("Hello World", p2.get());
+    /// # Ok::<(), ()>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_map_project<M2, E>(
+        self,
+        f: for<'a> fn(
+            <M::Yokeable as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> Result<<M2::Yokeable as Yokeable<'a>>::Output, E>,
+    ) -> Result<DataPayload<'data, M2>, E>
+    where
+        M2: DataMarker<'data, Cart = M::Cart>,
+    {
+        use DataPayloadInner::*;
+        Ok(match self.inner {
+            RcStruct(yoke) => DataPayload {
+                inner: RcStruct(yoke.try_project(f)?),
+            },
+            Owned(yoke) => DataPayload {
+                inner: Owned(yoke.try_project(f)?),
+            },
+            RcBuf(yoke) => DataPayload {
+                inner: RcBuf(yoke.try_project(f)?),
+            },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.try_project(f)?),
+            },
+        })
+    }
+
+    /// Version of [`DataPayload::map_project()`] that borrows `self` and bubbles up an error
+    /// from `f`.
+    ///
+    /// # Examples
+    ///
+    /// Same example as above, but do not move out of `p1`:
+    ///
+    /// ***[#1061](https://github.com/unicode-org/icu4x/issues/1061): The following example
+    /// requires Rust 1.57.***
+    ///
+    /// ```ignore
+    /// // Same imports and definitions as above
+    /// # use icu_provider::hello_world::*;
+    /// # use icu_provider::prelude::*;
+    /// # use std::borrow::Cow;
+    /// # struct HelloWorldV1MessageMarker;
+    /// # impl<'data> DataMarker<'data> for HelloWorldV1MessageMarker {
+    /// #     type Yokeable = Cow<'static, str>;
+    /// #     type Cart = HelloWorldV1<'data>;
+    /// # }
+    ///
+    /// let p1: DataPayload<HelloWorldV1Marker> = DataPayload::from_owned(HelloWorldV1 {
+    ///     message: Cow::Borrowed("Hello World")
+    /// });
+    ///
+    /// let p2: DataPayload<HelloWorldV1MessageMarker> = p1.try_map_project_cloned(|obj, _| {
+    ///     if obj.message.is_empty() {
+    ///         return Err("Example error");
+    ///     }
+    ///     Ok(obj.message.clone())
+    /// })?;
+    ///
+    /// // Note: p1 is still valid.
+    /// assert_eq!(p1.get().message, *p2.get());
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_map_project_cloned<'this, M2, E>(
+        &'this self,
+        f: for<'a> fn(
+            &'this <M::Yokeable as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> Result<<M2::Yokeable as Yokeable<'a>>::Output, E>,
+    ) -> Result<DataPayload<'data, M2>, E>
+    where
+        M2: DataMarker<'data, Cart = M::Cart>,
+    {
+        use DataPayloadInner::*;
+        Ok(match &self.inner {
+            RcStruct(yoke) => DataPayload {
+                inner: RcStruct(yoke.try_project_cloned(f)?),
+            },
+            Owned(yoke) => DataPayload {
+                inner: Owned(yoke.try_project_cloned(f)?),
+            },
+            RcBuf(yoke) => DataPayload {
+                inner: RcBuf(yoke.try_project_cloned(f)?),
+            },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.try_project_cloned(f)?),
+            },
+        })
+    }
+
     /// Version of [`DataPayload::map_project()`] that moves `self`, takes a `capture`
     /// parameter to pass additional data to `f`, and bubbles up an error from `f`.
     ///
@@ -850,6 +1084,9 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.try_project_with_capture(capture, f)?),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.try_project_with_capture(capture, f)?),
+            },
         })
     }
 
@@ -920,6 +1157,9 @@ where
             RcBuf(yoke) => DataPayload {
                 inner: RcBuf(yoke.try_project_cloned_with_capture(capture, f)?),
             },
+            RcByteBuffer(yoke) => DataPayload {
+                inner: RcByteBuffer(yoke.try_project_cloned_with_capture(capture, f)?),
+            },
         })
     }
 }
@@ -1007,7 +1247,43 @@ fn test_debug() {
             message: Cow::Borrowed("foo"),
         })),
     };
-    assert_eq!("DataResponse { metadata: DataResponseMetadata { data_langid: None }, payload: Some(HelloWorldV1 { message: \"foo\" }) }", format!("{:?}", resp));
+    assert_eq!("DataResponse { metadata: DataResponseMetadata { data_langid: None, fallback_trace: [] }, payload: Some(HelloWorldV1 { message: \"foo\" }) }", format!("{:?}", resp));
+}
+
+#[test]
+fn test_try_map_project() {
+    use crate::hello_world::*;
+    use alloc::borrow::Cow;
+    struct HelloWorldV1MessageMarker;
+    impl<'data> DataMarker<'data> for HelloWorldV1MessageMarker {
+        type Yokeable = Cow<'static, str>;
+        type Cart = HelloWorldV1<'data>;
+    }
+
+    let payload = |message| {
+        DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+            message: Cow::Borrowed(message),
+        })
+    };
+    // The closures are not shared, since a `fn` pointer with the signature of the callback
+    // would not be normalized by older compilers.
+    let p1: DataPayload<HelloWorldV1MessageMarker> = payload("Hello World")
+        .try_map_project(|obj, _| {
+            if obj.message.is_empty() {
+                return Err(());
+            }
+            Ok(obj.message)
+        })
+        .unwrap();
+    assert_eq!("Hello World", p1.get());
+    let result: Result<DataPayload<HelloWorldV1MessageMarker>, ()> =
+        payload("").try_map_project(|obj, _| {
+            if obj.message.is_empty() {
+                return Err(());
+            }
+            Ok(obj.message)
+        });
+    assert!(result.is_err());
 }
 
 /// A generic data provider that loads a payload of a specific type.
@@ -1026,4 +1302,29 @@ where
     /// Returns [`Ok`] if the request successfully loaded data. If data failed to load, returns an
     /// Error with more information.
     fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, Error>;
+
+    /// Query the provider for data like [`DataProvider::load_payload()`], annotating errors with
+    /// the request and the type of the provider.
+    ///
+    /// Components should load data with this function, so that errors surfaced to applications
+    /// identify the data that failed to load. See [`Error::Context`].
+    fn load_payload_with_context(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, M>, Error> {
+        self.load_payload(req)
+            .map_err(|err| err.with_context::<Self>(req))
+    }
+}
+
+/// Forwards requests to the boxed provider, which may be a trait object such as
+/// `dyn SerdeDeDataProvider`.
+impl<'data, M, P> DataProvider<'data, M> for Box<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M> + ?Sized,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, Error> {
+        (**self).load_payload(req)
+    }
 }
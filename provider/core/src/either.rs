@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A provider that is one of two providers chosen at runtime.
+//!
+//! [`EitherProvider`] makes it possible to pick a data provider at runtime, such as an
+//! `FsDataProvider` during development and a `BlobDataProvider` in production, without making
+//! every function that uses the provider generic over both types.
+//!
+//! If the set of possible providers is open-ended, box them as a trait object instead. Any
+//! provider of serialized data can be used as a `Box<dyn `[`SerdeDeDataProvider`]`>`, which
+//! implements [`DataProvider`] for all data structs that can be deserialized; use
+//! [`IterableSerdeDeDataProvider`] to also list the supported options.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::baked::{BakedData, BakedDataProvider};
+//! use icu_provider::either::EitherProvider;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//!
+//! static BAKED: &BakedData = &[(
+//!     "core/helloworld@1/de",
+//!     &HelloWorldV1 {
+//!         message: std::borrow::Cow::Borrowed("Servus Welt"),
+//!     },
+//! )];
+//!
+//! fn get_provider(
+//!     use_baked: bool,
+//! ) -> EitherProvider<BakedDataProvider, HelloWorldProvider<'static>> {
+//!     if use_baked {
+//!         EitherProvider::A(BakedDataProvider::new(BAKED))
+//!     } else {
+//!         EitherProvider::B(HelloWorldProvider::new_with_placeholder_data())
+//!     }
+//! }
+//!
+//! let load = |use_baked| -> DataPayload<HelloWorldV1Marker> {
+//!     get_provider(use_baked)
+//!         .load_payload(&DataRequest {
+//!             resource_path: ResourcePath {
+//!                 key: key::HELLO_WORLD_V1,
+//!                 options: langid!("de").into(),
+//!             },
+//!         })
+//!         .expect("Loading should succeed")
+//!         .take_payload()
+//!         .expect("Data should be present")
+//! };
+//!
+//! assert_eq!("Servus Welt", load(true).get().message);
+//! assert_eq!("Hallo Welt", load(false).get().message);
+//! ```
+//!
+//! [`SerdeDeDataProvider`]: crate::serde::SerdeDeDataProvider
+//! [`IterableSerdeDeDataProvider`]: crate::serde::IterableSerdeDeDataProvider
+
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use alloc::boxed::Box;
+
+/// A provider that is either of two types, chosen at runtime.
+///
+/// Requests are forwarded to whichever provider is present.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EitherProvider<P0, P1> {
+    /// A value of the first provider type.
+    A(P0),
+    /// A value of the second provider type.
+    B(P1),
+}
+
+impl<'data, M, P0, P1> DataProvider<'data, M> for EitherProvider<P0, P1>
+where
+    M: DataMarker<'data>,
+    P0: DataProvider<'data, M>,
+    P1: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        match self {
+            EitherProvider::A(p) => p.load_payload(req),
+            EitherProvider::B(p) => p.load_payload(req),
+        }
+    }
+}
+
+#[cfg(feature = "provider_serde")]
+impl<P0, P1> crate::serde::SerdeDeDataProvider for EitherProvider<P0, P1>
+where
+    P0: crate::serde::SerdeDeDataProvider,
+    P1: crate::serde::SerdeDeDataProvider,
+{
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn crate::serde::SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, DataError> {
+        match self {
+            EitherProvider::A(p) => p.load_to_receiver(req, receiver),
+            EitherProvider::B(p) => p.load_to_receiver(req, receiver),
+        }
+    }
+}
+
+impl<P0, P1> IterableDataProviderCore for EitherProvider<P0, P1>
+where
+    P0: IterableDataProviderCore,
+    P1: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        match self {
+            EitherProvider::A(p) => p.supported_options_for_key(resc_key),
+            EitherProvider::B(p) => p.supported_options_for_key(resc_key),
+        }
+    }
+}
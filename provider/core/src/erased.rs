@@ -83,6 +83,7 @@ where
             RcStruct(yoke) => Rc::from(yoke),
             Owned(yoke) => Rc::from(yoke),
             RcBuf(yoke) => Rc::from(yoke),
+            RcByteBuffer(yoke) => Rc::from(yoke),
         };
         DataPayload::from_partial_owned(cart)
     }
@@ -176,6 +177,20 @@ impl<'data> DataPayload<'static, ErasedDataStructMarker> {
                     },
                     Err(any_rc) => any_rc,
                 };
+                // Check for Case 5: an RcByteBuffer Yoke.
+                let y2 = any_rc.downcast::<Yoke<M::Yokeable, Rc<dyn ByteBuffer>>>();
+                let any_rc = match y2 {
+                    Ok(rc_yoke) => match Rc::try_unwrap(rc_yoke) {
+                        Ok(yoke) => {
+                            return Ok(DataPayload {
+                                inner: RcByteBuffer(yoke),
+                            })
+                        }
+                        // Note: We could consider cloning the Yoke instead of erroring out.
+                        Err(_) => return Err(Error::MultipleReferences),
+                    },
+                    Err(any_rc) => any_rc,
+                };
                 // None of the downcasts succeeded; return an error.
                 Err(Error::MismatchedType {
                     actual: Some(any_rc.type_id()),
@@ -186,7 +201,7 @@ impl<'data> DataPayload<'static, ErasedDataStructMarker> {
             // contains a reference.
             Owned(_) => unreachable!(),
             // This is unreachable because ErasedDataStruct needs to reference an object.
-            RcBuf(_) => unreachable!(),
+            RcBuf(_) | RcByteBuffer(_) => unreachable!(),
         }
     }
 }
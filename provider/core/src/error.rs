@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::prelude::*;
+use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
 use core::any::TypeId;
@@ -48,6 +49,16 @@ pub enum Error {
         generic: Option<TypeId>,
     },
 
+    /// The data was built for a different version of the data structs. See [`crate::schema`].
+    #[displaydoc("Data built for ICU4X {data}, runtime expects {runtime}")]
+    MismatchedSchemaVersion {
+        /// The schema version recorded in the data.
+        data: String,
+
+        /// The schema version of the data structs in this binary.
+        runtime: &'static str,
+    },
+
     /// The requested operation failed to unwrap an Rc backing the data payload.
     #[displaydoc("Could not unwrap Rc due to multiple references")]
     MultipleReferences,
@@ -60,10 +71,34 @@ pub enum Error {
     /// The data provider encountered some other error when loading the resource, such as I/O.
     #[displaydoc("Failed to load resource: {0}")]
     Resource(String),
+
+    /// An error returned by a data provider, annotated with the request and the type of the
+    /// provider.
+    ///
+    /// Returned by [`DataProvider::load_payload_with_context()`]. Use
+    /// [`Error::without_context()`] to inspect the underlying error.
+    #[displaydoc("{provider} failed to load {req}: {error}")]
+    Context {
+        /// The type name of the data provider, as returned by [`core::any::type_name`].
+        provider: &'static str,
+
+        /// The request that failed.
+        req: DataRequest,
+
+        /// The error returned by the data provider.
+        error: Box<Error>,
+    },
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Context { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(feature = "erased-serde")]
 impl From<erased_serde::Error> for Error {
@@ -79,6 +114,52 @@ impl From<String> for Error {
 }
 
 impl Error {
+    /// Annotates this error with the request that failed and the type of the provider `P`.
+    ///
+    /// Errors that already have context are returned unchanged, so that the innermost provider
+    /// is reported.
+    pub fn with_context<P: ?Sized>(self, req: &DataRequest) -> Self {
+        match self {
+            Self::Context { .. } => self,
+            _ => Self::Context {
+                provider: core::any::type_name::<P>(),
+                req: req.clone(),
+                error: Box::new(self),
+            },
+        }
+    }
+
+    /// Returns the underlying error, without the context added by [`Error::with_context()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::prelude::*;
+    ///
+    /// const OTHER_KEY: ResourceKey = icu_provider::resource_key!(x, "demo", "other", 1);
+    ///
+    /// let provider = HelloWorldProvider::new_with_placeholder_data();
+    /// let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+    ///     provider.load_payload_with_context(&DataRequest::from(OTHER_KEY));
+    /// let err = result.expect_err("The key is not supported");
+    ///
+    /// assert!(matches!(err, DataError::Context { .. }));
+    /// assert!(matches!(
+    ///     err.without_context(),
+    ///     DataError::MissingResourceKey(_)
+    /// ));
+    /// let message = err.to_string();
+    /// assert!(message.contains("HelloWorldProvider"));
+    /// assert!(message.contains("failed to load x-demo/other@1"));
+    /// ```
+    pub fn without_context(&self) -> &Self {
+        match self {
+            Self::Context { error, .. } => error.without_context(),
+            _ => self,
+        }
+    }
+
     pub fn new_resc_error<T>(err: T) -> Self
     where
         T: core::fmt::Display,
@@ -0,0 +1,466 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that fall back to more general locales when data is missing.
+//!
+//! A provider often does not have data for every locale that is requested, such as `en-IN` when
+//! only `en` was exported. Wrapping it in a [`LocaleFallbackProvider`] retries such requests with
+//! more general locales, following the CLDR inheritance rules of [`ParentLocales`], until data is
+//! found. The locales that were tried without success are recorded in
+//! [`DataResponseMetadata::fallback_trace`], and the locale of the returned data in
+//! [`DataResponseMetadata::data_langid`], so that an app can tell the user which locale is shown.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::fallback::LocaleFallbackProvider;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//!
+//! let provider = LocaleFallbackProvider::new(HelloWorldProvider::new_with_placeholder_data());
+//!
+//! let response: DataResponse<HelloWorldV1Marker> = provider
+//!     .load_payload(&DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: langid!("en-IN").into(),
+//!         },
+//!     })
+//!     .expect("Loading should succeed");
+//!
+//! assert_eq!(response.metadata.data_langid, Some(langid!("en")));
+//! assert_eq!(
+//!     response.metadata.fallback_trace,
+//!     vec![langid!("en-IN"), langid!("en-001")]
+//! );
+//! assert_eq!("Hello World", response.take_payload().unwrap().get().message);
+//! ```
+
+use crate::prelude::*;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use icu_locid::subtags::Script;
+use icu_locid::LanguageIdentifier;
+use litemap::LiteMap;
+
+/// The explicit parent locales of CLDR, from `parentLocales.json` of cldr-core, with `root`
+/// written as `und`.
+const CLDR_PARENT_LOCALES: &[(&str, &str)] = &[
+    ("az-Arab", "und"),
+    ("az-Cyrl", "und"),
+    ("blt-Latn", "und"),
+    ("bm-Nkoo", "und"),
+    ("bs-Cyrl", "und"),
+    ("byn-Latn", "und"),
+    ("cu-Glag", "und"),
+    ("dje-Arab", "und"),
+    ("dyo-Arab", "und"),
+    ("en-150", "en-001"),
+    ("en-150", "en-001"),
+    ("en-AG", "en-001"),
+    ("en-AI", "en-001"),
+    ("en-AT", "en-150"),
+    ("en-AU", "en-001"),
+    ("en-BB", "en-001"),
+    ("en-BE", "en-150"),
+    ("en-BM", "en-001"),
+    ("en-BS", "en-001"),
+    ("en-BW", "en-001"),
+    ("en-BZ", "en-001"),
+    ("en-CA", "en-001"),
+    ("en-CC", "en-001"),
+    ("en-CH", "en-150"),
+    ("en-CK", "en-001"),
+    ("en-CM", "en-001"),
+    ("en-CX", "en-001"),
+    ("en-CY", "en-001"),
+    ("en-DE", "en-150"),
+    ("en-DG", "en-001"),
+    ("en-DK", "en-150"),
+    ("en-DM", "en-001"),
+    ("en-Dsrt", "und"),
+    ("en-ER", "en-001"),
+    ("en-FI", "en-150"),
+    ("en-FJ", "en-001"),
+    ("en-FK", "en-001"),
+    ("en-FM", "en-001"),
+    ("en-GB", "en-001"),
+    ("en-GD", "en-001"),
+    ("en-GG", "en-001"),
+    ("en-GH", "en-001"),
+    ("en-GI", "en-001"),
+    ("en-GM", "en-001"),
+    ("en-GY", "en-001"),
+    ("en-HK", "en-001"),
+    ("en-IE", "en-001"),
+    ("en-IL", "en-001"),
+    ("en-IM", "en-001"),
+    ("en-IN", "en-001"),
+    ("en-IO", "en-001"),
+    ("en-JE", "en-001"),
+    ("en-JM", "en-001"),
+    ("en-KE", "en-001"),
+    ("en-KI", "en-001"),
+    ("en-KN", "en-001"),
+    ("en-KY", "en-001"),
+    ("en-LC", "en-001"),
+    ("en-LR", "en-001"),
+    ("en-LS", "en-001"),
+    ("en-MG", "en-001"),
+    ("en-MO", "en-001"),
+    ("en-MS", "en-001"),
+    ("en-MT", "en-001"),
+    ("en-MU", "en-001"),
+    ("en-MV", "en-001"),
+    ("en-MW", "en-001"),
+    ("en-MY", "en-001"),
+    ("en-NA", "en-001"),
+    ("en-NF", "en-001"),
+    ("en-NG", "en-001"),
+    ("en-NL", "en-150"),
+    ("en-NR", "en-001"),
+    ("en-NU", "en-001"),
+    ("en-NZ", "en-001"),
+    ("en-PG", "en-001"),
+    ("en-PK", "en-001"),
+    ("en-PN", "en-001"),
+    ("en-PW", "en-001"),
+    ("en-RW", "en-001"),
+    ("en-SB", "en-001"),
+    ("en-SC", "en-001"),
+    ("en-SD", "en-001"),
+    ("en-SE", "en-150"),
+    ("en-SG", "en-001"),
+    ("en-SH", "en-001"),
+    ("en-SI", "en-150"),
+    ("en-SL", "en-001"),
+    ("en-SS", "en-001"),
+    ("en-SX", "en-001"),
+    ("en-SZ", "en-001"),
+    ("en-Shaw", "und"),
+    ("en-TC", "en-001"),
+    ("en-TK", "en-001"),
+    ("en-TO", "en-001"),
+    ("en-TT", "en-001"),
+    ("en-TV", "en-001"),
+    ("en-TZ", "en-001"),
+    ("en-UG", "en-001"),
+    ("en-VC", "en-001"),
+    ("en-VG", "en-001"),
+    ("en-VU", "en-001"),
+    ("en-WS", "en-001"),
+    ("en-ZA", "en-001"),
+    ("en-ZM", "en-001"),
+    ("en-ZW", "en-001"),
+    ("es-AR", "es-419"),
+    ("es-BO", "es-419"),
+    ("es-BR", "es-419"),
+    ("es-BZ", "es-419"),
+    ("es-CL", "es-419"),
+    ("es-CO", "es-419"),
+    ("es-CR", "es-419"),
+    ("es-CU", "es-419"),
+    ("es-DO", "es-419"),
+    ("es-EC", "es-419"),
+    ("es-GT", "es-419"),
+    ("es-HN", "es-419"),
+    ("es-MX", "es-419"),
+    ("es-NI", "es-419"),
+    ("es-PA", "es-419"),
+    ("es-PE", "es-419"),
+    ("es-PR", "es-419"),
+    ("es-PY", "es-419"),
+    ("es-SV", "es-419"),
+    ("es-US", "es-419"),
+    ("es-UY", "es-419"),
+    ("es-VE", "es-419"),
+    ("ff-Adlm", "und"),
+    ("ff-Arab", "und"),
+    ("ha-Arab", "und"),
+    ("iu-Latn", "und"),
+    ("kk-Arab", "und"),
+    ("ks-Deva", "und"),
+    ("ku-Arab", "und"),
+    ("ky-Arab", "und"),
+    ("ky-Latn", "und"),
+    ("ml-Arab", "und"),
+    ("mn-Mong", "und"),
+    ("mni-Mtei", "und"),
+    ("ms-Arab", "und"),
+    ("pa-Arab", "und"),
+    ("pt-AO", "pt-PT"),
+    ("pt-CH", "pt-PT"),
+    ("pt-CV", "pt-PT"),
+    ("pt-FR", "pt-PT"),
+    ("pt-GQ", "pt-PT"),
+    ("pt-GW", "pt-PT"),
+    ("pt-LU", "pt-PT"),
+    ("pt-MO", "pt-PT"),
+    ("pt-MZ", "pt-PT"),
+    ("pt-ST", "pt-PT"),
+    ("pt-TL", "pt-PT"),
+    ("sat-Deva", "und"),
+    ("sd-Deva", "und"),
+    ("sd-Khoj", "und"),
+    ("sd-Sind", "und"),
+    ("shi-Latn", "und"),
+    ("so-Arab", "und"),
+    ("sr-Latn", "und"),
+    ("sw-Arab", "und"),
+    ("tg-Arab", "und"),
+    ("ug-Cyrl", "und"),
+    ("uz-Arab", "und"),
+    ("uz-Cyrl", "und"),
+    ("vai-Latn", "und"),
+    ("wo-Arab", "und"),
+    ("yo-Arab", "und"),
+    ("yue-Hans", "und"),
+    ("zh-Hant", "und"),
+    ("zh-Hant-MO", "zh-Hant-HK"),
+];
+
+/// The regions in which Chinese is written in the Traditional script, so that for example `zh-TW`
+/// falls back to `zh-Hant-TW` instead of Simplified Chinese `zh`.
+const TRADITIONAL_CHINESE_REGIONS: &[&str] = &["HK", "MO", "TW"];
+
+/// The locales that locales fall back to, following the CLDR inheritance rules.
+///
+/// A locale falls back to its explicit parent in CLDR, such as `en-001` for `en-GB`, or otherwise
+/// to the locale with its most specific subtag removed: variants first, then the region, then
+/// the script, and finally the language, which leaves `und`. Every locale eventually falls back
+/// to `und`.
+///
+/// [`ParentLocales::default()`] contains the parent locales of CLDR. Other tables, such as the
+/// one of a specific CLDR release, can be collected from `(child, parent)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_provider::fallback::ParentLocales;
+///
+/// let parent_locales = ParentLocales::default();
+///
+/// assert_eq!(parent_locales.parent(&langid!("en-GB")), Some(langid!("en-001")));
+/// assert_eq!(parent_locales.parent(&langid!("es-MX")), Some(langid!("es-419")));
+/// assert_eq!(parent_locales.parent(&langid!("zh-TW")), Some(langid!("zh-Hant-TW")));
+/// assert_eq!(parent_locales.parent(&langid!("zh-Hant")), Some(langid!("und")));
+/// assert_eq!(parent_locales.parent(&langid!("sr-Cyrl-RS")), Some(langid!("sr-Cyrl")));
+/// assert_eq!(parent_locales.parent(&langid!("und")), None);
+///
+/// assert_eq!(
+///     parent_locales.with_ancestors(&[langid!("en-GB"), langid!("de")]),
+///     vec![
+///         langid!("und"),
+///         langid!("de"),
+///         langid!("en"),
+///         langid!("en-001"),
+///         langid!("en-GB"),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParentLocales {
+    parents: LiteMap<LanguageIdentifier, LanguageIdentifier>,
+}
+
+impl Default for ParentLocales {
+    fn default() -> Self {
+        CLDR_PARENT_LOCALES
+            .iter()
+            .map(|(child, parent)| {
+                (
+                    child.parse().expect("Valid CLDR locale"),
+                    parent.parse().expect("Valid CLDR locale"),
+                )
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<(LanguageIdentifier, LanguageIdentifier)> for ParentLocales {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (LanguageIdentifier, LanguageIdentifier)>,
+    {
+        Self {
+            parents: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl ParentLocales {
+    /// Returns the locale that `langid` falls back to, or `None` for `und`.
+    pub fn parent(&self, langid: &LanguageIdentifier) -> Option<LanguageIdentifier> {
+        if let Some(parent) = self.parents.get(langid) {
+            return Some(parent.clone());
+        }
+        let mut parent = langid.clone();
+        if !parent.variants.is_empty() {
+            parent.variants.clear();
+        } else if let Some(region) = parent.region {
+            if parent.language == "zh"
+                && parent.script.is_none()
+                && TRADITIONAL_CHINESE_REGIONS.iter().any(|r| region == *r)
+            {
+                parent.script = Some(Script::from_bytes(b"Hant").expect("Valid script"));
+            } else {
+                parent.region = None;
+            }
+        } else if parent.script.is_some() {
+            parent.script = None;
+        } else if parent != LanguageIdentifier::und() {
+            parent = LanguageIdentifier::und();
+        } else {
+            return None;
+        }
+        Some(parent)
+    }
+
+    /// Returns the given locales together with all locales they fall back to, in sorted order
+    /// and without duplicates.
+    pub fn with_ancestors(&self, locales: &[LanguageIdentifier]) -> Vec<LanguageIdentifier> {
+        let mut result: Vec<LanguageIdentifier> = Vec::new();
+        for langid in locales {
+            let mut next = Some(langid.clone());
+            while let Some(langid) = next {
+                next = self.parent(&langid);
+                result.push(langid);
+            }
+        }
+        result.sort();
+        result.dedup();
+        result
+    }
+}
+
+/// A data provider that retries requests for missing locales with more general locales.
+///
+/// Only [`DataError::MissingResourceOptions`] errors cause a retry; other errors are returned
+/// immediately. If no locale has data, the error of the original request is returned.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocaleFallbackProvider<P> {
+    /// The data provider to which requests are delegated.
+    pub inner: P,
+    parent_locales: ParentLocales,
+}
+
+impl<P> LocaleFallbackProvider<P> {
+    /// Creates a [`LocaleFallbackProvider`] that delegates requests to `inner`, falling back
+    /// with the parent locales of CLDR.
+    pub fn new(inner: P) -> Self {
+        Self::new_with_parent_locales(inner, ParentLocales::default())
+    }
+
+    /// Creates a [`LocaleFallbackProvider`] that delegates requests to `inner`, falling back
+    /// with the given parent locales.
+    pub fn new_with_parent_locales(inner: P, parent_locales: ParentLocales) -> Self {
+        LocaleFallbackProvider {
+            inner,
+            parent_locales,
+        }
+    }
+}
+
+impl<'data, M, P> DataProvider<'data, M> for LocaleFallbackProvider<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let first_error = match self.inner.load_payload(req) {
+            Err(err @ DataError::MissingResourceOptions(_)) => err,
+            result => return result,
+        };
+        let mut fallback_req = req.clone();
+        let mut fallback_trace = Vec::new();
+        while let Some(langid) = fallback_req.resource_path.options.langid.take() {
+            fallback_req.resource_path.options.langid = self.parent_locales.parent(&langid);
+            fallback_trace.push(langid);
+            if fallback_req.resource_path.options.langid.is_none() {
+                break;
+            }
+            match self.inner.load_payload(&fallback_req) {
+                Ok(mut response) => {
+                    response.metadata.fallback_trace = fallback_trace;
+                    return Ok(response);
+                }
+                Err(DataError::MissingResourceOptions(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Err(first_error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hello_world::{key, HelloWorldProvider, HelloWorldV1Marker};
+    use icu_locid_macros::langid;
+
+    fn load(
+        langid: LanguageIdentifier,
+    ) -> Result<DataResponse<'static, HelloWorldV1Marker>, DataError> {
+        LocaleFallbackProvider::new(HelloWorldProvider::new_with_placeholder_data()).load_payload(
+            &DataRequest {
+                resource_path: ResourcePath {
+                    key: key::HELLO_WORLD_V1,
+                    options: langid.into(),
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn test_no_fallback() {
+        let response = load(langid!("de")).expect("Loading should succeed");
+        assert_eq!(response.metadata.data_langid, Some(langid!("de")));
+        assert!(response.metadata.fallback_trace.is_empty());
+    }
+
+    #[test]
+    fn test_fallback_trace() {
+        let response = load(langid!("ru-Cyrl-RU-fonipa")).expect("Loading should succeed");
+        assert_eq!(response.metadata.data_langid, Some(langid!("ru")));
+        assert_eq!(
+            response.metadata.fallback_trace,
+            vec![
+                langid!("ru-Cyrl-RU-fonipa"),
+                langid!("ru-Cyrl-RU"),
+                langid!("ru-Cyrl")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parent_locales() {
+        // en-GB falls back to en through en-001.
+        let response = load(langid!("en-GB")).expect("Loading should succeed");
+        assert_eq!(response.metadata.data_langid, Some(langid!("en")));
+        assert_eq!(
+            response.metadata.fallback_trace,
+            vec![langid!("en-GB"), langid!("en-001")]
+        );
+
+        // zh-TW does not fall back to the Simplified Chinese data of zh.
+        assert!(load(langid!("zh-TW")).is_err());
+    }
+
+    #[test]
+    fn test_no_data() {
+        // The hello world provider has no root data, so requests for unknown languages fail with
+        // the error of the original request.
+        let err = load(langid!("xx-XX")).expect_err("Loading should fail");
+        match err {
+            DataError::MissingResourceOptions(req) => {
+                assert_eq!(req.resource_path.options.langid, Some(langid!("xx-XX")))
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
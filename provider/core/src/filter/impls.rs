@@ -8,6 +8,7 @@ use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 
 use icu_locid::LanguageIdentifier;
 
@@ -226,4 +227,123 @@ where
             description: "Locale is required".to_string(),
         }
     }
+
+    /// Filter out data requests with certain keys according to the predicate function. The
+    /// predicate should return `true` to allow a key and `false` to reject a key.
+    ///
+    /// Keys that are rejected will not appear in iterators: requesting the supported options for
+    /// a rejected key returns an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::filter::Filterable;
+    /// use icu_provider::iter::IterableDataProviderCore;
+    /// use icu_locid_macros::langid;
+    ///
+    /// let provider = HelloWorldProvider::new_with_placeholder_data()
+    ///     .filterable()
+    ///     .filter_by_key(|key| *key != key::HELLO_WORLD_V1);
+    ///
+    /// // Hello World requests should fail:
+    /// let req = DataRequest {
+    ///     resource_path: ResourcePath {
+    ///         key: key::HELLO_WORLD_V1,
+    ///         options: langid!("de").into(),
+    ///     }
+    /// };
+    /// let response: Result<DataResponse<HelloWorldV1Marker>, _> =
+    ///     provider.load_payload(&req);
+    /// assert!(matches!(response, Err(DataError::FilteredResource(_, _))));
+    ///
+    /// // The key should have no supported options:
+    /// let count = provider.supported_options_for_key(&key::HELLO_WORLD_V1)
+    ///     .expect("Should successfully make an iterator of supported options")
+    ///     .count();
+    /// assert_eq!(0, count);
+    /// ```
+    pub fn filter_by_key<'a>(
+        self,
+        predicate: impl Fn(&ResourceKey) -> bool + 'a,
+    ) -> RequestFilterDataProvider<D, Box<dyn Fn(&DataRequest) -> bool + 'a>>
+    where
+        F: 'a,
+    {
+        self.filter_by_key_with_description(predicate, "Key filter".to_string())
+    }
+
+    /// Same as [`Self::filter_by_key`] but with an extra argument to set a custom
+    /// description for debugging.
+    pub fn filter_by_key_with_description<'a>(
+        self,
+        predicate: impl Fn(&ResourceKey) -> bool + 'a,
+        description: String,
+    ) -> RequestFilterDataProvider<D, Box<dyn Fn(&DataRequest) -> bool + 'a>>
+    where
+        F: 'a,
+    {
+        let old_predicate = self.predicate;
+        RequestFilterDataProvider {
+            inner: self.inner,
+            predicate: Box::new(move |request| -> bool {
+                if !(old_predicate)(request) {
+                    return false;
+                }
+                predicate(&request.resource_path.key)
+            }),
+            description,
+        }
+    }
+
+    /// Filter out data requests except those for a key in the allowlist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::filter::Filterable;
+    /// use icu_locid_macros::langid;
+    ///
+    /// const OTHER_KEY: ResourceKey = icu_provider::resource_key!(x, "demo", "other", 1);
+    ///
+    /// let allowlist = [OTHER_KEY];
+    /// let provider = HelloWorldProvider::new_with_placeholder_data()
+    ///     .filterable()
+    ///     .filter_by_key_allowlist_strict(&allowlist);
+    ///
+    /// // Hello World requests should fail:
+    /// let req = DataRequest {
+    ///     resource_path: ResourcePath {
+    ///         key: key::HELLO_WORLD_V1,
+    ///         options: langid!("de").into(),
+    ///     }
+    /// };
+    /// let response: Result<DataResponse<HelloWorldV1Marker>, _> =
+    ///     provider.load_payload(&req);
+    /// assert!(matches!(response, Err(DataError::FilteredResource(_, _))));
+    /// assert_eq!(
+    ///     "Resource was filtered: Key filter (allowlist: [x-demo/other@1]): core/helloworld@1/de",
+    ///     response.unwrap_err().to_string()
+    /// );
+    /// ```
+    pub fn filter_by_key_allowlist_strict<'a>(
+        self,
+        allowlist: &'a [ResourceKey],
+    ) -> RequestFilterDataProvider<D, Box<dyn Fn(&DataRequest) -> bool + 'a>>
+    where
+        F: 'a,
+    {
+        let description = format!(
+            "Key filter (allowlist: [{}])",
+            allowlist
+                .iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        self.filter_by_key_with_description(move |key| allowlist.contains(key), description)
+    }
 }
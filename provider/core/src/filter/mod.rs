@@ -18,6 +18,9 @@
 //! // now call .filterable() on any object to get a RequestFilterDataProvider
 //! ```
 //!
+//! Filters can be chained. Requests can be filtered by locale, for example with
+//! [`filter_by_langid`], and by key, for example with [`filter_by_key_allowlist_strict`].
+//!
 //! # Examples
 //!
 //! ```
@@ -30,9 +33,18 @@
 //! HelloWorldProvider::new_with_placeholder_data()
 //!     .filterable()
 //!     .filter_by_langid(|langid| langid.language == language!("de"));
+//!
+//! // Only return German data for the Hello World key:
+//! let allowed_keys = [key::HELLO_WORLD_V1];
+//! HelloWorldProvider::new_with_placeholder_data()
+//!     .filterable()
+//!     .filter_by_key_allowlist_strict(&allowed_keys)
+//!     .filter_by_langid(|langid| langid.language == language!("de"));
 //! ```
 //!
 //! [`IterableDataProvider`]: crate::iter::IterableDataProvider
+//! [`filter_by_langid`]: RequestFilterDataProvider::filter_by_langid
+//! [`filter_by_key_allowlist_strict`]: RequestFilterDataProvider::filter_by_key_allowlist_strict
 
 mod impls;
 
@@ -0,0 +1,170 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that load data in a different locale than the one requested.
+//!
+//! Formatters request their data in the locale they are constructed with. Wrapping their
+//! provider in a [`ForcedLocaleProvider`] decouples the locale of the data from that display
+//! locale, either for all keys or for individual keys. For example, a number can be formatted
+//! with German symbols but English plural rules, or a pseudo-localization pipeline can serve all
+//! data from one source locale regardless of the locale under test.
+//!
+//! Only requests with a language identifier are changed, so locale-invariant data such as
+//! Unicode properties is unaffected. The variant of the request is kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::forced_locale::ForcedLocaleProvider;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//!
+//! let provider = ForcedLocaleProvider::new(HelloWorldProvider::new_with_placeholder_data())
+//!     .force_key(key::HELLO_WORLD_V1, langid!("de"));
+//!
+//! let payload: DataPayload<HelloWorldV1Marker> = provider
+//!     .load_payload(&DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: langid!("ja").into(),
+//!         },
+//!     })
+//!     .expect("Loading should succeed")
+//!     .take_payload()
+//!     .expect("Data should be present");
+//!
+//! assert_eq!("Hallo Welt", payload.get().message);
+//! ```
+
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use icu_locid::LanguageIdentifier;
+
+/// A data provider that replaces the language identifier of requests before delegating them to
+/// an inner provider.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForcedLocaleProvider<P> {
+    /// The data provider to which requests are delegated.
+    pub inner: P,
+
+    /// The language identifier used for keys without an entry in `keys`, or `None` to keep the
+    /// language identifier of the request.
+    pub all_keys: Option<LanguageIdentifier>,
+
+    /// The language identifiers used for individual keys, which take precedence over `all_keys`.
+    pub keys: Vec<(ResourceKey, LanguageIdentifier)>,
+}
+
+impl<P> ForcedLocaleProvider<P> {
+    /// Creates a [`ForcedLocaleProvider`] that does not change any request yet.
+    pub fn new(inner: P) -> Self {
+        ForcedLocaleProvider {
+            inner,
+            all_keys: None,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Loads the data of all keys in `langid`, except for keys forced with
+    /// [`force_key`](Self::force_key).
+    pub fn force_all_keys(mut self, langid: LanguageIdentifier) -> Self {
+        self.all_keys = Some(langid);
+        self
+    }
+
+    /// Loads the data of `key` in `langid`.
+    pub fn force_key(mut self, key: ResourceKey, langid: LanguageIdentifier) -> Self {
+        match self.keys.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = langid,
+            None => self.keys.push((key, langid)),
+        }
+        self
+    }
+
+    /// Returns the language identifier to load the data of `key` in, if it is forced.
+    fn forced_langid(&self, key: &ResourceKey) -> Option<&LanguageIdentifier> {
+        self.keys
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, langid)| langid)
+            .or_else(|| self.all_keys.as_ref())
+    }
+}
+
+impl<'data, M, P> DataProvider<'data, M> for ForcedLocaleProvider<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        match self.forced_langid(&req.resource_path.key) {
+            Some(langid) if req.resource_path.options.langid.is_some() => {
+                let mut req = req.clone();
+                req.resource_path.options.langid = Some(langid.clone());
+                self.inner.load_payload(&req)
+            }
+            _ => self.inner.load_payload(req),
+        }
+    }
+}
+
+impl<P> IterableDataProviderCore for ForcedLocaleProvider<P>
+where
+    P: IterableDataProviderCore,
+{
+    /// Returns the options supported by the inner provider. Requests for any language identifier
+    /// succeed for a forced key if they succeed for the forced language identifier.
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hello_world::{key, HelloWorldProvider, HelloWorldV1Marker};
+    use alloc::string::String;
+    use icu_locid_macros::langid;
+
+    fn load<P>(provider: &P, options: ResourceOptions) -> String
+    where
+        P: DataProvider<'static, HelloWorldV1Marker>,
+    {
+        let payload: DataPayload<HelloWorldV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::HELLO_WORLD_V1,
+                    options,
+                },
+            })
+            .expect("Loading should succeed")
+            .take_payload()
+            .expect("Data should be present");
+        String::from(&*payload.get().message)
+    }
+
+    #[test]
+    fn test_key_takes_precedence() {
+        let provider = ForcedLocaleProvider::new(HelloWorldProvider::new_with_placeholder_data())
+            .force_all_keys(langid!("ja"))
+            .force_key(key::HELLO_WORLD_V1, langid!("de"));
+        assert_eq!("Hallo Welt", load(&provider, langid!("en").into()));
+    }
+
+    #[test]
+    fn test_other_keys_unchanged() {
+        const OTHER_KEY: ResourceKey = crate::resource_key!(x, "demo", "other", 1);
+        let provider = ForcedLocaleProvider::new(HelloWorldProvider::new_with_placeholder_data())
+            .force_key(OTHER_KEY, langid!("de"));
+        assert_eq!("Hello World", load(&provider, langid!("en").into()));
+    }
+}
@@ -0,0 +1,307 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that combine multiple other providers.
+//!
+//! A fork provider sends each request to one of its inner providers based on the
+//! [`ResourceKey`]: the inner providers are tried in order, and the first one that supports the
+//! key handles the request. A provider is considered not to support a key if it returns
+//! [`DataError::MissingResourceKey`]; all other errors are returned to the caller.
+//!
+//! This makes it possible, for example, to serve some keys from a [`BakedDataProvider`] and
+//! others from an `FsDataProvider`.
+//!
+//! There are two fork providers:
+//!
+//! - [`ForkByKeyProvider`] combines two providers of possibly different types.
+//! - [`MultiForkByKeyProvider`] combines any number of providers of the same type.
+//!
+//! To override individual resources rather than whole keys, use [`OverlayDataProvider`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::fork::ForkByKeyProvider;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_provider::struct_provider::StructProvider;
+//!
+//! const ALT_KEY: ResourceKey = icu_provider::resource_key!(x, "demo", "alt", 1);
+//!
+//! let provider = ForkByKeyProvider(
+//!     HelloWorldProvider::new_with_placeholder_data(),
+//!     StructProvider {
+//!         key: ALT_KEY,
+//!         data: DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+//!             message: "Hello Alt".into(),
+//!         }),
+//!     },
+//! );
+//!
+//! // Served by the first provider:
+//! let payload: DataPayload<HelloWorldV1Marker> = provider
+//!     .load_payload(&DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: langid!("de").into(),
+//!         },
+//!     })
+//!     .expect("Loading should succeed")
+//!     .take_payload()
+//!     .expect("Data should be present");
+//! assert_eq!("Hallo Welt", payload.get().message);
+//!
+//! // Served by the second provider:
+//! let payload: DataPayload<HelloWorldV1Marker> = provider
+//!     .load_payload(&DataRequest::from(ALT_KEY))
+//!     .expect("Loading should succeed")
+//!     .take_payload()
+//!     .expect("Data should be present");
+//! assert_eq!("Hello Alt", payload.get().message);
+//! ```
+//!
+//! [`BakedDataProvider`]: crate::baked::BakedDataProvider
+
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// A provider that returns data from one of two child providers based on the key.
+///
+/// The first provider is tried first. If it returns [`DataError::MissingResourceKey`], the
+/// request is sent to the second provider.
+///
+/// To combine more than two providers of the same type, use [`MultiForkByKeyProvider`]. To
+/// combine more than two providers of different types, nest [`ForkByKeyProvider`]s.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForkByKeyProvider<P0, P1>(pub P0, pub P1);
+
+impl<'data, M, P0, P1> DataProvider<'data, M> for ForkByKeyProvider<P0, P1>
+where
+    M: DataMarker<'data>,
+    P0: DataProvider<'data, M>,
+    P1: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        match self.0.load_payload(req) {
+            Err(DataError::MissingResourceKey(_)) => self.1.load_payload(req),
+            result => result,
+        }
+    }
+}
+
+impl<P0, P1> IterableDataProviderCore for ForkByKeyProvider<P0, P1>
+where
+    P0: IterableDataProviderCore,
+    P1: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        match self.0.supported_options_for_key(resc_key) {
+            Err(DataError::MissingResourceKey(_)) => self.1.supported_options_for_key(resc_key),
+            result => result,
+        }
+    }
+}
+
+/// A provider that returns data from the first of its child providers supporting the key.
+///
+/// The providers are tried in order until one of them returns something other than
+/// [`DataError::MissingResourceKey`]. If none of them support the key, the request fails with
+/// [`DataError::MissingResourceKey`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::fork::MultiForkByKeyProvider;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider::struct_provider::StructProvider;
+///
+/// const KEY_A: ResourceKey = icu_provider::resource_key!(x, "demo", "a", 1);
+/// const KEY_B: ResourceKey = icu_provider::resource_key!(x, "demo", "b", 1);
+///
+/// let provider = MultiForkByKeyProvider {
+///     providers: vec![
+///         StructProvider {
+///             key: KEY_A,
+///             data: DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+///                 message: "Hello A".into(),
+///             }),
+///         },
+///         StructProvider {
+///             key: KEY_B,
+///             data: DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+///                 message: "Hello B".into(),
+///             }),
+///         },
+///     ],
+/// };
+///
+/// let payload: DataPayload<HelloWorldV1Marker> = provider
+///     .load_payload(&DataRequest::from(KEY_B))
+///     .expect("Loading should succeed")
+///     .take_payload()
+///     .expect("Data should be present");
+/// assert_eq!("Hello B", payload.get().message);
+///
+/// let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+///     provider.load_payload(&DataRequest::from(key::HELLO_WORLD_V1));
+/// assert!(matches!(result, Err(DataError::MissingResourceKey(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MultiForkByKeyProvider<P> {
+    /// The child providers, in the order in which they are tried.
+    pub providers: Vec<P>,
+}
+
+impl<'data, M, P> DataProvider<'data, M> for MultiForkByKeyProvider<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        for provider in self.providers.iter() {
+            match provider.load_payload(req) {
+                Err(DataError::MissingResourceKey(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(DataError::MissingResourceKey(req.resource_path.key))
+    }
+}
+
+impl<P> IterableDataProviderCore for MultiForkByKeyProvider<P>
+where
+    P: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        for provider in self.providers.iter() {
+            match provider.supported_options_for_key(resc_key) {
+                Err(DataError::MissingResourceKey(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(DataError::MissingResourceKey(*resc_key))
+    }
+}
+
+/// A provider that serves data from an overlay provider, falling back to a base provider for
+/// resources missing from the overlay.
+///
+/// Unlike [`ForkByKeyProvider`], the fallback happens per resource: a request is sent to the
+/// base provider if the overlay returns either [`DataError::MissingResourceKey`] or
+/// [`DataError::MissingResourceOptions`]. This allows overriding individual resources, such as
+/// custom data for one locale, without regenerating the whole data set.
+///
+/// The supported options of the two providers are merged, with the options of the base provider
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_provider::baked::{BakedData, BakedDataProvider};
+/// use icu_provider::fork::OverlayDataProvider;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+///
+/// // Customized data for German.
+/// static PATCH: &BakedData = &[(
+///     "core/helloworld@1/de",
+///     &HelloWorldV1 {
+///         message: std::borrow::Cow::Borrowed("Servus Welt"),
+///     },
+/// )];
+///
+/// let provider = OverlayDataProvider {
+///     overlay: BakedDataProvider::new(PATCH),
+///     base: HelloWorldProvider::new_with_placeholder_data(),
+/// };
+///
+/// let load = |langid| -> DataPayload<HelloWorldV1Marker> {
+///     provider
+///         .load_payload(&DataRequest {
+///             resource_path: ResourcePath {
+///                 key: key::HELLO_WORLD_V1,
+///                 options: ResourceOptions {
+///                     variant: None,
+///                     langid: Some(langid),
+///                 },
+///             },
+///         })
+///         .expect("Loading should succeed")
+///         .take_payload()
+///         .expect("Data should be present")
+/// };
+///
+/// // Served by the overlay:
+/// assert_eq!("Servus Welt", load(langid!("de")).get().message);
+///
+/// // Served by the base provider:
+/// assert_eq!("こんにちは世界", load(langid!("ja")).get().message);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayDataProvider<P0, P1> {
+    /// The provider consulted first.
+    pub overlay: P0,
+    /// The provider serving resources missing from the overlay.
+    pub base: P1,
+}
+
+impl<'data, M, P0, P1> DataProvider<'data, M> for OverlayDataProvider<P0, P1>
+where
+    M: DataMarker<'data>,
+    P0: DataProvider<'data, M>,
+    P1: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        match self.overlay.load_payload(req) {
+            Err(DataError::MissingResourceKey(_)) | Err(DataError::MissingResourceOptions(_)) => {
+                self.base.load_payload(req)
+            }
+            result => result,
+        }
+    }
+}
+
+impl<P0, P1> IterableDataProviderCore for OverlayDataProvider<P0, P1>
+where
+    P0: IterableDataProviderCore,
+    P1: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        let overlay_options: Vec<ResourceOptions> =
+            match self.overlay.supported_options_for_key(resc_key) {
+                Ok(iter) => iter.collect(),
+                Err(DataError::MissingResourceKey(_)) => Vec::new(),
+                Err(err) => return Err(err),
+            };
+        let mut options: Vec<ResourceOptions> = match self.base.supported_options_for_key(resc_key)
+        {
+            Ok(iter) => iter.collect(),
+            // The key is supported by the overlay only.
+            Err(DataError::MissingResourceKey(_)) if !overlay_options.is_empty() => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        for option in overlay_options {
+            if !options.contains(&option) {
+                options.push(option);
+            }
+        }
+        Ok(Box::new(options.into_iter()))
+    }
+}
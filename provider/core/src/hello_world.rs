@@ -4,6 +4,7 @@
 
 //! Data provider returning multilingual "Hello World" strings for testing.
 
+use crate::baked::Bake;
 use crate::iter::IterableDataProviderCore;
 use crate::prelude::*;
 use crate::yoke::{self, *};
@@ -12,7 +13,7 @@ use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::ToString;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{self, Debug};
 use core::str::FromStr;
 use icu_locid::LanguageIdentifier;
 use litemap::LiteMap;
@@ -22,6 +23,10 @@ pub mod key {
     pub const HELLO_WORLD_V1: ResourceKey = resource_key!(Core, "helloworld", 1);
 }
 
+/// The entry of [`HelloWorldV1Marker`] in the [key registry](crate::registry::KEY_REGISTRY).
+pub(crate) const KEY_REGISTRY_ENTRY: crate::registry::RegisteredKeys =
+    crate::registered_keys!(HelloWorldV1Marker, [key::HELLO_WORLD_V1]);
+
 /// A struct containing "Hello World" in the requested language.
 #[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
 #[cfg_attr(
@@ -41,6 +46,14 @@ impl Default for HelloWorldV1<'_> {
     }
 }
 
+impl Bake for HelloWorldV1<'_> {
+    fn bake<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str("::icu_provider::hello_world::HelloWorldV1 { message: ")?;
+        self.message.bake(sink)?;
+        sink.write_str(" }")
+    }
+}
+
 /// Marker type for [`HelloWorldV1`].
 pub struct HelloWorldV1Marker;
 
@@ -133,6 +146,7 @@ impl<'data, 't> DataProvider<'data, HelloWorldV1Marker> for HelloWorldProvider<'
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: Some(langid.clone()),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_partial_owned(Rc::from(data))),
         })
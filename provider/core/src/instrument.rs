@@ -0,0 +1,177 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that report every data load to a callback.
+//!
+//! [`InstrumentedDataProvider`] wraps another provider and calls a function with a [`LoadEvent`]
+//! after each load, describing the request, how long it took, whether it succeeded, and the size
+//! of the payload. This makes it possible to profile data access patterns, for example by
+//! recording the events as metrics or as spans of a tracing framework.
+//!
+//! *Enabled with the "std" feature*
+//!
+//! # Examples
+//!
+//! Count the loads that did not find any data:
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::instrument::InstrumentedDataProvider;
+//! use icu_provider::prelude::*;
+//! use std::cell::Cell;
+//!
+//! let misses = Cell::new(0);
+//! let provider = InstrumentedDataProvider::new(
+//!     HelloWorldProvider::new_with_placeholder_data(),
+//!     |event| {
+//!         if event.is_miss() {
+//!             misses.set(misses.get() + 1);
+//!         }
+//!     },
+//! );
+//!
+//! let load = |langid| -> Result<DataResponse<HelloWorldV1Marker>, DataError> {
+//!     provider.load_payload(&DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: ResourceOptions::from(langid),
+//!         },
+//!     })
+//! };
+//!
+//! load(langid!("de")).expect("German data is available");
+//! load(langid!("tlh")).expect_err("Klingon data is not available");
+//!
+//! assert_eq!(1, misses.get());
+//! ```
+
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use alloc::boxed::Box;
+use std::time::{Duration, Instant};
+
+/// A description of a single data load, passed to the callback of an
+/// [`InstrumentedDataProvider`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct LoadEvent<'a> {
+    /// The request that was loaded.
+    pub req: &'a DataRequest,
+
+    /// The time taken by the inner provider to handle the request.
+    pub duration: Duration,
+
+    /// The error returned by the inner provider, or [`None`] if the load succeeded.
+    pub error: Option<&'a DataError>,
+
+    /// The length in bytes of the buffer backing the payload, if the load succeeded and the
+    /// payload is backed by a buffer. See [`DataPayload::buffer_len()`].
+    pub payload_size: Option<usize>,
+}
+
+impl LoadEvent<'_> {
+    /// Returns whether the load succeeded.
+    pub fn is_hit(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns whether the load failed because the provider does not have the requested data,
+    /// as opposed to failing for another reason, such as invalid data.
+    pub fn is_miss(&self) -> bool {
+        matches!(
+            self.error.map(DataError::without_context),
+            Some(DataError::MissingResourceKey(_)) | Some(DataError::MissingResourceOptions(_))
+        )
+    }
+}
+
+/// A data provider that calls a function with a [`LoadEvent`] after every load.
+///
+/// The callback is called on the thread that loads the data, after the inner provider returns;
+/// it should be fast, such as incrementing counters or queueing the event for later processing.
+///
+/// See the [module-level documentation](self) for an example.
+pub struct InstrumentedDataProvider<D, F>
+where
+    F: Fn(&LoadEvent),
+{
+    /// The data provider to which we delegate requests.
+    pub inner: D,
+
+    /// The function called after every load.
+    pub callback: F,
+}
+
+impl<D, F> InstrumentedDataProvider<D, F>
+where
+    F: Fn(&LoadEvent),
+{
+    /// Creates an [`InstrumentedDataProvider`] calling `callback` after every load from `inner`.
+    pub fn new(inner: D, callback: F) -> Self {
+        Self { inner, callback }
+    }
+}
+
+impl<'data, D, F, M> DataProvider<'data, M> for InstrumentedDataProvider<D, F>
+where
+    F: Fn(&LoadEvent),
+    M: DataMarker<'data>,
+    D: DataProvider<'data, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let start = Instant::now();
+        let result = self.inner.load_payload(req);
+        let duration = start.elapsed();
+        (self.callback)(&LoadEvent {
+            req,
+            duration,
+            error: result.as_ref().err(),
+            payload_size: result
+                .as_ref()
+                .ok()
+                .and_then(|response| response.payload.as_ref())
+                .and_then(DataPayload::buffer_len),
+        });
+        result
+    }
+}
+
+#[cfg(feature = "provider_serde")]
+impl<D, F> crate::serde::SerdeDeDataProvider for InstrumentedDataProvider<D, F>
+where
+    F: Fn(&LoadEvent),
+    D: crate::serde::SerdeDeDataProvider,
+{
+    /// Note: The [`LoadEvent`]s emitted by this function never have a `payload_size`.
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn crate::serde::SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, DataError> {
+        let start = Instant::now();
+        let result = self.inner.load_to_receiver(req, receiver);
+        let duration = start.elapsed();
+        (self.callback)(&LoadEvent {
+            req,
+            duration,
+            error: result.as_ref().err(),
+            payload_size: None,
+        });
+        result
+    }
+}
+
+impl<D, F> IterableDataProviderCore for InstrumentedDataProvider<D, F>
+where
+    F: Fn(&LoadEvent),
+    D: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
@@ -20,6 +20,18 @@ pub trait IterableDataProviderCore {
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, Error>;
 }
 
+impl<P> IterableDataProviderCore for Box<P>
+where
+    P: IterableDataProviderCore + ?Sized,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, Error> {
+        (**self).supported_options_for_key(resc_key)
+    }
+}
+
 /// A super-trait combining [`DataProvider`] and [`IterableDataProviderCore`], auto-implemented
 /// for all types implementing both of those traits.
 pub trait IterableDataProvider<'data, M>:
@@ -33,13 +33,27 @@
 //!   data directly from CLDR source files.
 //! - [`FsDataProvider`](../icu_provider_fs/struct.FsDataProvider.html) reads structured data from the
 //!   filesystem. It can also write out that filesystem structure. More efficient than CldrJsonDataProvider.
+//! - [`BakedDataProvider`] serves data structs compiled into the binary as Rust source, without any
+//!   deserialization.
 //!
 //! This crate also contains some concrete implementations for testing purposes:
 //!
 //! - [`InvariantDataProvider`] returns fixed data that does not vary by locale.
 //! - [`StructProvider`] wraps a particular instance of a struct and returns it.
+//! - [`AnyPayloadProvider`] does the same for a struct whose type is only known at runtime.
 //! - [`HelloWorldProvider`] returns "hello world" strings in several languages.
 //!
+//! Providers can be combined with the fork providers in the [`fork`] module, such as
+//! [`ForkByKeyProvider`], which sends each request to one of several providers based on the key,
+//! and [`OverlayDataProvider`], which overrides individual resources of a base provider. To choose
+//! between two providers at runtime, use [`EitherProvider`]. To load data in a different locale
+//! than the one requested, such as German symbols with English plural rules, wrap a provider in a
+//! [`ForcedLocaleProvider`]. To load data of a more general locale when the requested locale has
+//! no data, such as `en` data for `en-IN`, wrap a provider in a [`LocaleFallbackProvider`].
+//!
+//! To profile data access, wrap a provider in an [`InstrumentedDataProvider`], which reports the
+//! duration, outcome and payload size of every load to a callback.
+//!
 //! ## Types and Lifetimes
 //!
 //! Types compatible with [`Yokeable`] can be passed through the data provider, so long as they are
@@ -65,7 +79,8 @@
 //!
 //! The trait [`SerdeDeDataProvider`] removes the type argument from `DataProvider` and requires
 //! that all data structs be deserializable via Serde. This allows for a Serde-enabled provider
-//! to be saved as a trait object without being specific to a data struct type.
+//! to be saved as a trait object without being specific to a data struct type. A
+//! `Box<dyn SerdeDeDataProvider>` can be passed anywhere a [`DataProvider`] is expected.
 //!
 //! ### `DataProvider<dyn SerdeSeDataStruct>`
 //!
@@ -76,6 +91,9 @@
 //!
 //! This trait is normally implemented using the [`impl_dyn_provider!`] macro.
 //!
+//! To share a [`SerdeDeDataProvider`] between threads, such as in a web server, wrap it in a
+//! [`SharedDataProvider`], which also caches the data it loads.
+//!
 //! ### `DataProvider<dyn ErasedDataStruct>`
 //!
 //! The trait [`ErasedDataProvider`] removes the type argument from [`DataProvider`] and requires
@@ -87,6 +105,29 @@
 //!
 //! This trait is normally implemented using the [`impl_dyn_provider!`] macro.
 //!
+//! ### Schema versions
+//!
+//! Serialized data records the version of the data structs it was built with. Providers reading
+//! serialized data check it when they are created, and fail with
+//! [`DataError::MismatchedSchemaVersion`] if the data was built by an incompatible release of
+//! ICU4X. For more information, see the [`schema`] module.
+//!
+//! ### Key registries
+//!
+//! Components list the [`ResourceKey`]s they define, along with the marker types of their data
+//! structs, so that tools can operate over all keys. For more information, see the [`registry`]
+//! module.
+//!
+//! ### `AsyncDataProvider`
+//!
+//! *Enabled with the "async" feature*
+//!
+//! The trait [`AsyncDataProvider`] loads data asynchronously, such as over the network in a web
+//! app. Data from an [`AsyncDataProvider`] is fetched ahead of time into a
+//! [`PrefetchedDataProvider`], which can then be used to construct ICU4X components.
+//!
+//! For more information, see the [`async_provider`] module.
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`DataProvider`]: data_provider::DataProvider
 //! [`Request`]: data_provider::DataRequest
@@ -97,19 +138,34 @@
 //! [`IterableDataProvider`]: iter::IterableDataProvider
 //! [`InvariantDataProvider`]: inv::InvariantDataProvider
 //! [`StructProvider`]: struct_provider::StructProvider
+//! [`AnyPayloadProvider`]: struct_provider::AnyPayloadProvider
+//! [`ForcedLocaleProvider`]: forced_locale::ForcedLocaleProvider
+//! [`LocaleFallbackProvider`]: fallback::LocaleFallbackProvider
+//! [`BakedDataProvider`]: baked::BakedDataProvider
 //! [`HelloWorldProvider`]: hello_world::HelloWorldProvider
+//! [`ForkByKeyProvider`]: fork::ForkByKeyProvider
+//! [`OverlayDataProvider`]: fork::OverlayDataProvider
+//! [`EitherProvider`]: either::EitherProvider
+//! [`InstrumentedDataProvider`]: instrument::InstrumentedDataProvider
 //! [`ErasedDataProvider`]: erased::ErasedDataProvider
 //! [`SerdeDeDataProvider`]: serde::SerdeDeDataProvider
 //! [`SerdeSeDataStruct`]: serde::SerdeSeDataStruct
+//! [`SharedDataProvider`]: shared::SharedDataProvider
 //! [`Yokeable`]: yoke::Yokeable
 //! [`impl_dyn_provider!`]: impl_dyn_provider
+//! [`AsyncDataProvider`]: async_provider::AsyncDataProvider
+//! [`PrefetchedDataProvider`]: async_provider::PrefetchedDataProvider
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 extern crate alloc;
 
+#[cfg(feature = "async")]
+pub mod async_provider;
+pub mod baked;
 #[macro_use]
 pub mod dynutil;
+pub mod either;
 
 pub(crate) mod data_provider;
 #[macro_use]
@@ -117,14 +173,23 @@ mod resource;
 #[macro_use]
 pub mod erased;
 pub mod export;
+pub mod fallback;
 pub mod filter;
+pub mod forced_locale;
+pub mod fork;
 pub mod hello_world;
+#[cfg(feature = "std")]
+pub mod instrument;
 pub mod inv;
 pub mod iter;
 #[macro_use]
 pub mod marker;
+pub mod registry;
+pub mod schema;
 #[cfg(feature = "provider_serde")]
 pub mod serde;
+#[cfg(all(feature = "std", feature = "provider_serde"))]
+pub mod shared;
 pub mod struct_provider;
 
 #[cfg(feature = "macros")]
@@ -136,6 +201,7 @@ pub use error::Error as DataError;
 
 pub mod prelude {
     //! Core selection of APIs and structures for [`DataProvider`].
+    pub use crate::data_provider::ByteBuffer;
     pub use crate::data_provider::DataPayload;
     pub use crate::data_provider::DataProvider;
     pub use crate::data_provider::DataRequest;
@@ -0,0 +1,104 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Registries of [`ResourceKeys`](ResourceKey) and the data structs they load.
+//!
+//! Each component lists the keys it defines in a `KEY_REGISTRY` constant in its `provider`
+//! module, grouped by data struct marker. Tools such as datagen can operate over all keys of
+//! the components they depend on by iterating these registries with [`iter_keys()`], instead of
+//! maintaining their own lists of keys.
+//!
+//! Since components depend on this crate, the registries of all components cannot be collected
+//! here; the `icu` crate collects the registries of the components it contains.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::hello_world::key::HELLO_WORLD_V1;
+//! use icu_provider::registry;
+//!
+//! let keys: Vec<registry::KeyInfo> = registry::iter_keys(registry::KEY_REGISTRY).collect();
+//!
+//! assert_eq!(keys.len(), 1);
+//! assert_eq!(keys[0].key, HELLO_WORLD_V1);
+//! assert_eq!(keys[0].marker, "icu_provider::hello_world::HelloWorldV1Marker");
+//! assert_eq!(keys[0].version(), 1);
+//! ```
+
+use crate::resource::ResourceKey;
+
+/// The [`ResourceKeys`](ResourceKey) whose data struct has a particular marker type.
+///
+/// Use [`registered_keys!`](crate::registered_keys) to create one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisteredKeys {
+    /// The path of the data struct marker type, such as
+    /// `"icu_provider::hello_world::HelloWorldV1Marker"`.
+    pub marker: &'static str,
+    /// The keys loading the data struct.
+    pub keys: &'static [ResourceKey],
+}
+
+/// A [`ResourceKey`] and the marker type of its data struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyInfo {
+    /// The key.
+    pub key: ResourceKey,
+    /// The path of the data struct marker type.
+    pub marker: &'static str,
+}
+
+impl KeyInfo {
+    /// Returns the version of the key, which changes when its data struct changes
+    /// incompatibly.
+    pub fn version(&self) -> u16 {
+        self.key.version
+    }
+}
+
+/// Creates a [`RegisteredKeys`] from a data struct marker type and an array of keys.
+///
+/// The marker must be given as a path relative to the module in which the macro is invoked.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::registry::RegisteredKeys;
+///
+/// mod provider {
+///     pub struct FooV1Marker;
+///     pub const FOO_V1: icu_provider::ResourceKey = icu_provider::resource_key!(x, "foo", "bar", 1);
+///     pub const KEY_REGISTRY: &[icu_provider::registry::RegisteredKeys] =
+///         &[icu_provider::registered_keys!(FooV1Marker, [FOO_V1])];
+/// }
+///
+/// assert!(provider::KEY_REGISTRY[0].marker.ends_with("::provider::FooV1Marker"));
+/// assert_eq!(provider::KEY_REGISTRY[0].keys, &[provider::FOO_V1]);
+/// ```
+#[macro_export]
+macro_rules! registered_keys {
+    ($marker:path, $keys:expr) => {
+        $crate::registry::RegisteredKeys {
+            marker: {
+                // Fails to compile if the marker does not exist.
+                let _ = ::core::marker::PhantomData::<$marker>;
+                concat!(module_path!(), "::", stringify!($marker))
+            },
+            keys: &$keys,
+        }
+    };
+}
+
+/// Returns every key in the given registry along with the marker of its data struct.
+pub fn iter_keys(registry: &[RegisteredKeys]) -> impl Iterator<Item = KeyInfo> + '_ {
+    registry.iter().flat_map(|registered| {
+        registered.keys.iter().map(move |key| KeyInfo {
+            key: *key,
+            marker: registered.marker,
+        })
+    })
+}
+
+/// The keys defined in this crate.
+pub const KEY_REGISTRY: &[RegisteredKeys] = &[crate::hello_world::KEY_REGISTRY_ENTRY];
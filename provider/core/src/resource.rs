@@ -14,7 +14,7 @@ use core::borrow::Borrow;
 use core::default::Default;
 use core::fmt;
 use core::fmt::Write;
-use icu_locid::LanguageIdentifier;
+use icu_locid::{LanguageIdentifier, ParserError};
 use tinystr::{TinyStr16, TinyStr4};
 use writeable::{LengthHint, Writeable};
 
@@ -22,12 +22,21 @@ use writeable::{LengthHint, Writeable};
 #[non_exhaustive]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
 pub enum ResourceCategory {
+    Bidi,
+    Collator,
     Core,
     DateTime,
     Decimal,
+    DisplayNames,
+    Duration,
+    List,
     LocaleCanonicalizer,
+    Normalizer,
     Plurals,
+    Segmenter,
+    Spoof,
     TimeZone,
+    Transliterator,
     UnicodeSet,
     PrivateUse(TinyStr4),
 }
@@ -36,12 +45,21 @@ impl ResourceCategory {
     /// Gets or builds a string form of this [`ResourceCategory`].
     pub fn as_str(&self) -> Cow<'static, str> {
         match self {
+            Self::Bidi => Cow::Borrowed("bidi"),
+            Self::Collator => Cow::Borrowed("collator"),
             Self::Core => Cow::Borrowed("core"),
             Self::DateTime => Cow::Borrowed("datetime"),
             Self::Decimal => Cow::Borrowed("decimal"),
+            Self::DisplayNames => Cow::Borrowed("displaynames"),
+            Self::Duration => Cow::Borrowed("duration"),
+            Self::List => Cow::Borrowed("list"),
             Self::LocaleCanonicalizer => Cow::Borrowed("locale_canonicalizer"),
+            Self::Normalizer => Cow::Borrowed("normalizer"),
             Self::Plurals => Cow::Borrowed("plurals"),
+            Self::Segmenter => Cow::Borrowed("segmenter"),
+            Self::Spoof => Cow::Borrowed("spoof"),
             Self::TimeZone => Cow::Borrowed("time_zone"),
+            Self::Transliterator => Cow::Borrowed("translit"),
             Self::UnicodeSet => Cow::Borrowed("uniset"),
             Self::PrivateUse(id) => {
                 let mut result = String::from("x-");
@@ -331,6 +349,48 @@ impl ResourceOptions {
     pub fn is_empty(&self) -> bool {
         self == &Self::default()
     }
+
+    /// Parses a [`ResourceOptions`] from its standard path components, as returned by
+    /// [`ResourceOptions::get_components()`].
+    ///
+    /// The last component is parsed as the language identifier, and the component before it, if
+    /// any, is the variant. Options with a variant but no language identifier cannot be parsed
+    /// with this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use icu_provider::prelude::*;
+    /// use icu_locid_macros::langid;
+    ///
+    /// let resc_options = ResourceOptions::try_from_components(["GBP", "pt-BR"].iter().copied())
+    ///     .expect("Valid components");
+    ///
+    /// assert_eq!(
+    ///     ResourceOptions {
+    ///         variant: Some(Cow::Borrowed("GBP")),
+    ///         langid: Some(langid!("pt_BR")),
+    ///     },
+    ///     resc_options
+    /// );
+    /// ```
+    pub fn try_from_components<'a>(
+        mut components: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParserError> {
+        let (variant, langid) = match (components.next(), components.next()) {
+            (None, _) => (None, None),
+            (Some(langid), None) => (None, Some(langid)),
+            (Some(variant), Some(langid)) => (Some(variant), Some(langid)),
+        };
+        if components.next().is_some() {
+            return Err(ParserError::InvalidSubtag);
+        }
+        Ok(Self {
+            variant: variant.map(|s| Cow::Owned(s.to_string())),
+            langid: langid.map(str::parse).transpose()?,
+        })
+    }
 }
 
 /// The standard components of a [`ResourceOptions`] path.
@@ -496,6 +556,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_options_from_components() {
+        for cas in get_options_test_cases().iter() {
+            let components = cas.resc_options.get_components();
+            assert_eq!(
+                Ok(cas.resc_options.clone()),
+                ResourceOptions::try_from_components(components.iter())
+            );
+        }
+        assert_eq!(
+            Ok(ResourceOptions::default()),
+            ResourceOptions::try_from_components(core::iter::empty())
+        );
+        assert_eq!(
+            Err(ParserError::InvalidSubtag),
+            ResourceOptions::try_from_components(["a", "b", "en"].iter().copied())
+        );
+    }
+
     #[test]
     fn test_resource_path_to_string() {
         for key_cas in get_key_test_cases().iter() {
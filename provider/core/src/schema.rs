@@ -0,0 +1,52 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Versioning of serialized data.
+//!
+//! Data structs may change incompatibly between minor releases of ICU4X 0.x, in which case data
+//! serialized by one release cannot be deserialized by another. Deserializing such data fails
+//! deep inside the deserializer, with an error that does not point to the cause. To report a
+//! meaningful error instead, data containers record the [`DATA_SCHEMA_VERSION`] of the ICU4X
+//! release that built them, and providers check it with [`check_schema_version()`] before
+//! loading any data.
+//!
+//! For example, the manifest of an `FsDataProvider` directory and the header of a postcard
+//! blob contain the schema version.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::prelude::*;
+//! use icu_provider::schema;
+//!
+//! assert!(schema::check_schema_version(schema::DATA_SCHEMA_VERSION).is_ok());
+//! assert!(matches!(
+//!     schema::check_schema_version("0.1"),
+//!     Err(DataError::MismatchedSchemaVersion { .. })
+//! ));
+//! ```
+
+use crate::error::Error;
+use alloc::string::ToString;
+
+/// The version of the data structs serialized by this release of ICU4X, as `"major.minor"`.
+pub const DATA_SCHEMA_VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION_MAJOR"),
+    ".",
+    env!("CARGO_PKG_VERSION_MINOR")
+);
+
+/// Checks that data built with the given schema version can be loaded by this release of ICU4X.
+///
+/// Returns [`Error::MismatchedSchemaVersion`] if the versions differ.
+pub fn check_schema_version(data_version: &str) -> Result<(), Error> {
+    if data_version == DATA_SCHEMA_VERSION {
+        Ok(())
+    } else {
+        Err(Error::MismatchedSchemaVersion {
+            data: data_version.to_string(),
+            runtime: DATA_SCHEMA_VERSION,
+        })
+    }
+}
@@ -9,7 +9,9 @@
 //! Providers that involve a [`serde::Deserializer`] to produce data from an opaque source should
 //! implement [`SerdeDeDataProvider`]. For example, `FsDataProvider` implements [`SerdeDeDataProvider`].
 //!
-//! [`SerdeDeDataProvider`] can be made into a trait object. It is used over FFI.
+//! [`SerdeDeDataProvider`] can be made into a trait object. It is used over FFI. Providers that
+//! can also enumerate their data, such as `FsDataProvider`, can be made into an
+//! [`IterableSerdeDeDataProvider`] trait object instead.
 //!
 //! ## Serializing
 //!
@@ -19,9 +21,12 @@
 //!
 //! [`DataProvider`]`<dyn `[`SerdeSeDataStruct`]`>` is used by data exporters such as `FilesystemExporter`.
 
+use crate::data_provider::DataPayloadInner;
 use crate::error::Error;
+use crate::iter::IterableDataProviderCore;
 use crate::prelude::*;
 use crate::yoke::*;
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 
 use core::ops::Deref;
@@ -81,6 +86,22 @@ pub trait SerdeDeDataReceiver {
         ),
     ) -> Result<(), Error>;
 
+    /// Receives a reference-counted [`ByteBuffer`], such as a buffer shared with other threads.
+    ///
+    /// This function has behavior identical to that of [`SerdeDeDataReceiver::receive_rc_buffer`].
+    /// The default implementation copies the buffer into an `Rc<[u8]>`; receivers that can borrow
+    /// from the buffer directly should override it.
+    fn receive_byte_buffer(
+        &mut self,
+        buffer: Rc<dyn ByteBuffer>,
+        f1: for<'de> fn(
+            bytes: &'de [u8],
+            f2: &mut dyn FnMut(&mut dyn erased_serde::Deserializer<'de>),
+        ),
+    ) -> Result<(), Error> {
+        self.receive_rc_buffer((*buffer).as_ref().into(), f1)
+    }
+
     /// Receives a `&'static` byte buffer via an [`erased_serde::Deserializer`].
     ///
     /// Note: Since the purpose of this function is to handle zero-copy deserialization of static
@@ -174,6 +195,35 @@ where
         Ok(())
     }
 
+    fn receive_byte_buffer(
+        &mut self,
+        buffer: Rc<dyn ByteBuffer>,
+        f1: for<'de> fn(
+            bytes: &'de [u8],
+            f2: &mut dyn FnMut(&mut dyn erased_serde::Deserializer<'de>),
+        ),
+    ) -> Result<(), Error> {
+        let yoke = Yoke::<&'static [u8], Rc<dyn ByteBuffer>>::attach_to_rc_cart(buffer)
+            .try_project_with_capture(f1, move |bytes, f1, _| {
+                let mut holder = None;
+                f1(bytes, &mut |deserializer| {
+                    holder.replace(
+                    erased_serde::deserialize::<YokeTraitHack<<M::Yokeable as Yokeable>::Output>>(
+                        deserializer,
+                    )
+                    .map(|w| w.0),
+                );
+                });
+                // The holder is guaranteed to be populated so long as the lambda function was invoked,
+                // which is in the contract of `receive_byte_buffer`.
+                holder.unwrap()
+            })?;
+        self.replace(DataPayload {
+            inner: DataPayloadInner::RcByteBuffer(yoke),
+        });
+        Ok(())
+    }
+
     fn receive_static(
         &mut self,
         deserializer: &mut dyn erased_serde::Deserializer<'static>,
@@ -199,6 +249,19 @@ pub trait SerdeDeDataProvider {
     ) -> Result<DataResponseMetadata, Error>;
 }
 
+impl<P> SerdeDeDataProvider for Box<P>
+where
+    P: SerdeDeDataProvider + ?Sized,
+{
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, Error> {
+        (**self).load_to_receiver(req, receiver)
+    }
+}
+
 /// Note: This impl returns `'static` payloads because borrowing is handled by [`Yoke`].
 impl<'data, M> DataProvider<'data, M> for dyn SerdeDeDataProvider + 'static
 where
@@ -217,6 +280,36 @@ where
     }
 }
 
+/// A [`SerdeDeDataProvider`] that can also list the options it supports for each key.
+///
+/// This trait is auto-implemented for all types implementing both [`SerdeDeDataProvider`] and
+/// [`IterableDataProviderCore`]. Use it as a trait object to load data and enumerate the
+/// available locales through a single type-erased provider.
+pub trait IterableSerdeDeDataProvider: SerdeDeDataProvider + IterableDataProviderCore {}
+
+impl<T> IterableSerdeDeDataProvider for T where
+    T: SerdeDeDataProvider + IterableDataProviderCore + ?Sized
+{
+}
+
+/// Note: This impl returns `'static` payloads because borrowing is handled by [`Yoke`].
+impl<'data, M> DataProvider<'data, M> for dyn IterableSerdeDeDataProvider + 'static
+where
+    M: DataMarker<'data>,
+    M::Yokeable: serde::de::Deserialize<'static>,
+    // Actual bound:
+    //     for<'de> <M::Yokeable as Yokeable<'de>>::Output: serde::de::Deserialize<'de>,
+    // Necessary workaround bound (see `yoke::trait_hack` docs):
+    for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::de::Deserialize<'de>,
+{
+    /// Serve objects implementing [`serde::Deserialize<'de>`] from an [`IterableSerdeDeDataProvider`].
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, Error> {
+        let mut payload = None;
+        let metadata = self.load_to_receiver(req, &mut payload)?;
+        Ok(DataResponse { metadata, payload })
+    }
+}
+
 /// Auto-implemented trait for all data structs that support [`serde::Serialize`]. This trait is
 /// usually used as a trait object in [`DataProvider`]`<dyn `[`SerdeSeDataStruct`]`>`.
 ///
@@ -292,6 +385,7 @@ where
             RcStruct(yoke) => Rc::from(yoke),
             Owned(yoke) => Rc::from(yoke),
             RcBuf(yoke) => Rc::from(yoke),
+            RcByteBuffer(yoke) => Rc::from(yoke),
         };
         DataPayload::from_partial_owned(cart)
     }
@@ -0,0 +1,550 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that can be shared between threads.
+//!
+//! Data payloads are reference-counted with [`Rc`], so components and their data cannot be sent
+//! between threads. In a multithreaded app, such as a web server, each thread constructs its own
+//! components instead. A [`SharedDataProvider`] lets all threads load their data from a single
+//! provider: it is [`Send`] and [`Sync`], and cloning it only clones a handle to the same
+//! provider.
+//!
+//! A [`SharedDataProvider`] also caches the serialized data that it loads, so that constructing
+//! the same component in many threads reads each resource only once. Payloads loaded from the
+//! cache borrow the cached buffer without copying it. To avoid loading data while serving
+//! requests, the cache can be filled ahead of time with [`SharedDataProvider::prefetch()`].
+//!
+//! *Enabled with the "std" and "provider_serde" features*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::serde::SerdeDeDataProvider;
+//! use icu_provider::shared::SharedDataProvider;
+//!
+//! fn serve<P: SerdeDeDataProvider + Send + Sync + 'static>(provider: P) {
+//!     let provider = SharedDataProvider::new(provider);
+//!
+//!     let handles: Vec<_> = (0..4)
+//!         .map(|_| {
+//!             let provider = provider.clone();
+//!             std::thread::spawn(move || {
+//!                 // Construct components with `&provider` and use them in this thread.
+//!             })
+//!         })
+//!         .collect();
+//!     for handle in handles {
+//!         handle.join().expect("Thread should not panic");
+//!     }
+//! }
+//! ```
+
+use crate::data_provider::ByteBuffer;
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use crate::serde::{SerdeDeDataProvider, SerdeDeDataReceiver};
+use crate::yoke::trait_hack::YokeTraitHack;
+use crate::yoke::{Yoke, Yokeable};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use icu_locid::LanguageIdentifier;
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+use std::thread::JoinHandle;
+
+/// The function that maps a serialized buffer to a deserializer, as passed to a
+/// [`SerdeDeDataReceiver`].
+type DeserializerFn =
+    for<'de> fn(bytes: &'de [u8], f2: &mut dyn FnMut(&mut dyn erased_serde::Deserializer<'de>));
+
+/// A serialized resource in the cache of a [`SharedDataProvider`].
+struct CachedResource {
+    buffer: Arc<[u8]>,
+    f1: DeserializerFn,
+    metadata: DataResponseMetadata,
+}
+
+/// A data provider that can be shared between threads, caching the serialized data it loads.
+///
+/// The inner provider must be [`Send`] and [`Sync`], such as `FsDataProvider` or
+/// `StaticDataProvider`. Providers holding reference-counted buffers, such as
+/// `BlobDataProvider`, cannot be shared.
+///
+/// Only data received as a byte buffer is cached; data that the inner provider deserializes from
+/// `'static` memory is already shared, and is loaded from the inner provider every time. Errors
+/// are not cached.
+///
+/// See the [module-level documentation](self) for an example.
+pub struct SharedDataProvider<P> {
+    inner: Arc<P>,
+    cache: Arc<RwLock<HashMap<String, CachedResource>>>,
+}
+
+impl<P> Clone for SharedDataProvider<P> {
+    /// Returns a handle to the same provider and cache.
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+impl<P> core::fmt::Debug for SharedDataProvider<P>
+where
+    P: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("SharedDataProvider")
+            .field("inner", &self.inner)
+            .field("cached_resources", &self.cached_len())
+            .finish()
+    }
+}
+
+impl<P> SharedDataProvider<P>
+where
+    P: SerdeDeDataProvider + Send + Sync,
+{
+    /// Creates a [`SharedDataProvider`] that delegates requests to `inner`.
+    pub fn new(inner: P) -> Self {
+        Self::from_arc(Arc::new(inner))
+    }
+
+    /// Creates a [`SharedDataProvider`] that delegates requests to a provider that is already
+    /// shared.
+    pub fn from_arc(inner: Arc<P>) -> Self {
+        Self {
+            inner,
+            cache: Default::default(),
+        }
+    }
+}
+
+impl<P> SharedDataProvider<P>
+where
+    P: SerdeDeDataProvider,
+{
+    /// Loads the data of every key in every locale into the cache, so that later requests for it
+    /// do not wait for the inner provider. Returns the number of resources that were loaded.
+    ///
+    /// The data is deserialized to check that it is valid, and then discarded; payloads loaded
+    /// later borrow from the cached buffers. Locales for which the inner provider has no data
+    /// are skipped. Resources that the inner provider returns as static data are counted, but
+    /// not cached, since they have no buffer to share.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_provider::serde::SerdeDeDataProvider;
+    /// use icu_provider::shared::SharedDataProvider;
+    /// use icu_provider::ResourceKey;
+    ///
+    /// fn warm_up<P: SerdeDeDataProvider + Send + Sync>(
+    ///     provider: &SharedDataProvider<P>,
+    ///     keys: &[ResourceKey],
+    /// ) {
+    ///     let count = provider
+    ///         .prefetch(keys, &[langid!("en"), langid!("de")])
+    ///         .expect("Data should be valid");
+    ///     println!("Prefetched {} resources", count);
+    /// }
+    /// ```
+    pub fn prefetch(
+        &self,
+        keys: &[ResourceKey],
+        locales: &[LanguageIdentifier],
+    ) -> Result<usize, DataError> {
+        self.prefetch_requests(prefetch_requests(keys, locales))
+    }
+
+    fn prefetch_requests(
+        &self,
+        requests: impl Iterator<Item = DataRequest>,
+    ) -> Result<usize, DataError> {
+        let mut count = 0;
+        for req in requests {
+            match self.load_to_receiver(&req, &mut ValidatingReceiver) {
+                Ok(_) => count += 1,
+                Err(err)
+                    if matches!(err.without_context(), DataError::MissingResourceOptions(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(count)
+    }
+}
+
+impl<P> SharedDataProvider<P>
+where
+    P: SerdeDeDataProvider + Send + Sync + 'static,
+{
+    /// Like [`prefetch()`](Self::prefetch), but loads the data on `threads` background threads,
+    /// returning a handle to wait for the number of resources that were loaded.
+    ///
+    /// The provider can be used while the data is prefetched; requests for data that is not
+    /// cached yet are loaded from the inner provider.
+    pub fn prefetch_in_background(
+        &self,
+        keys: &[ResourceKey],
+        locales: &[LanguageIdentifier],
+        threads: usize,
+    ) -> JoinHandle<Result<usize, DataError>> {
+        let requests: Vec<DataRequest> = prefetch_requests(keys, locales).collect();
+        let threads = threads.max(1);
+        let workers: Vec<_> = (0..threads)
+            .map(|i| {
+                let provider = self.clone();
+                let requests: Vec<DataRequest> =
+                    requests.iter().skip(i).step_by(threads).cloned().collect();
+                std::thread::spawn(move || provider.prefetch_requests(requests.into_iter()))
+            })
+            .collect();
+        std::thread::spawn(move || {
+            let mut count = 0;
+            for worker in workers {
+                count += worker.join().expect("Prefetching should not panic")?;
+            }
+            Ok(count)
+        })
+    }
+}
+
+/// Returns the requests for every key in every locale.
+fn prefetch_requests<'a>(
+    keys: &'a [ResourceKey],
+    locales: &'a [LanguageIdentifier],
+) -> impl Iterator<Item = DataRequest> + 'a {
+    keys.iter().flat_map(move |key| {
+        locales.iter().map(move |langid| DataRequest {
+            resource_path: ResourcePath {
+                key: *key,
+                options: langid.clone().into(),
+            },
+        })
+    })
+}
+
+impl<P> SharedDataProvider<P> {
+    /// Returns the provider to which requests are delegated.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the number of resources in the cache. Static data is not cached.
+    pub fn cached_len(&self) -> usize {
+        self.cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Removes all resources from the cache, such as after the data of the inner provider has
+    /// changed. Payloads that were already loaded keep the old data.
+    pub fn clear_cache(&self) {
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+/// A receiver that deserializes the data it receives without keeping it.
+struct ValidatingReceiver;
+
+impl ValidatingReceiver {
+    fn validate(bytes: &[u8], f1: DeserializerFn) -> Result<(), DataError> {
+        let mut result = None;
+        f1(bytes, &mut |deserializer| {
+            result = Some(erased_serde::deserialize::<serde::de::IgnoredAny>(
+                deserializer,
+            ));
+        });
+        // The result is populated so long as the function was invoked, which is in the contract
+        // of `SerdeDeDataReceiver`.
+        result.ok_or(DataError::MissingPayload)??;
+        Ok(())
+    }
+}
+
+impl SerdeDeDataReceiver for ValidatingReceiver {
+    fn receive_rc_buffer(
+        &mut self,
+        rc_buffer: Rc<[u8]>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        Self::validate(&rc_buffer, f1)
+    }
+
+    fn receive_yoked_buffer(
+        &mut self,
+        yoked_buffer: Yoke<&'static [u8], Rc<[u8]>>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        Self::validate(yoked_buffer.get(), f1)
+    }
+
+    fn receive_byte_buffer(
+        &mut self,
+        buffer: Rc<dyn ByteBuffer>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        Self::validate((*buffer).as_ref(), f1)
+    }
+
+    fn receive_static(
+        &mut self,
+        deserializer: &mut dyn erased_serde::Deserializer<'static>,
+    ) -> Result<(), DataError> {
+        erased_serde::deserialize::<serde::de::IgnoredAny>(deserializer)?;
+        Ok(())
+    }
+}
+
+/// A receiver that records the buffer passed to another receiver.
+struct CachingReceiver<'a> {
+    receiver: &'a mut dyn SerdeDeDataReceiver,
+    received: Option<(Arc<[u8]>, DeserializerFn)>,
+}
+
+impl SerdeDeDataReceiver for CachingReceiver<'_> {
+    fn receive_rc_buffer(
+        &mut self,
+        rc_buffer: Rc<[u8]>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        self.received = Some((Arc::from(&*rc_buffer), f1));
+        self.receiver.receive_rc_buffer(rc_buffer, f1)
+    }
+
+    fn receive_yoked_buffer(
+        &mut self,
+        yoked_buffer: Yoke<&'static [u8], Rc<[u8]>>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        self.received = Some((Arc::from(*yoked_buffer.get()), f1));
+        self.receiver.receive_yoked_buffer(yoked_buffer, f1)
+    }
+
+    fn receive_byte_buffer(
+        &mut self,
+        buffer: Rc<dyn ByteBuffer>,
+        f1: DeserializerFn,
+    ) -> Result<(), DataError> {
+        self.received = Some((Arc::from((*buffer).as_ref()), f1));
+        self.receiver.receive_byte_buffer(buffer, f1)
+    }
+
+    fn receive_static(
+        &mut self,
+        deserializer: &mut dyn erased_serde::Deserializer<'static>,
+    ) -> Result<(), DataError> {
+        self.receiver.receive_static(deserializer)
+    }
+}
+
+impl<P> SerdeDeDataProvider for SharedDataProvider<P>
+where
+    P: SerdeDeDataProvider,
+{
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, DataError> {
+        let cache_key = req.resource_path.to_string();
+        let cached = self
+            .cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&cache_key)
+            .map(|cached| (cached.buffer.clone(), cached.f1, cached.metadata.clone()));
+        if let Some((buffer, f1, metadata)) = cached {
+            receiver.receive_byte_buffer(Rc::new(buffer), f1)?;
+            return Ok(metadata);
+        }
+        let mut caching_receiver = CachingReceiver {
+            receiver,
+            received: None,
+        };
+        let metadata = self.inner.load_to_receiver(req, &mut caching_receiver)?;
+        if let Some((buffer, f1)) = caching_receiver.received {
+            self.cache
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(
+                    cache_key,
+                    CachedResource {
+                        buffer,
+                        f1,
+                        metadata: metadata.clone(),
+                    },
+                );
+        }
+        Ok(metadata)
+    }
+}
+
+/// Note: This impl returns `'static` payloads because borrowing is handled by [`Yoke`].
+impl<'data, M, P> DataProvider<'data, M> for SharedDataProvider<P>
+where
+    M: DataMarker<'data>,
+    M::Yokeable: serde::de::Deserialize<'static>,
+    // Actual bound:
+    //     for<'de> <M::Yokeable as Yokeable<'de>>::Output: serde::de::Deserialize<'de>,
+    // Necessary workaround bound (see `yoke::trait_hack` docs):
+    for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::de::Deserialize<'de>,
+    P: SerdeDeDataProvider,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let mut payload = None;
+        let metadata = self.load_to_receiver(req, &mut payload)?;
+        Ok(DataResponse { metadata, payload })
+    }
+}
+
+impl<P> IterableDataProviderCore for SharedDataProvider<P>
+where
+    P: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hello_world::{key, HelloWorldV1Marker};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use icu_locid_macros::langid;
+
+    /// A provider of JSON hello world data that counts its loads.
+    #[derive(Default)]
+    struct CountingJsonProvider {
+        loads: AtomicUsize,
+    }
+
+    impl SerdeDeDataProvider for CountingJsonProvider {
+        fn load_to_receiver(
+            &self,
+            req: &DataRequest,
+            receiver: &mut dyn SerdeDeDataReceiver,
+        ) -> Result<DataResponseMetadata, DataError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            let json: &[u8] = match req.resource_path.options.langid {
+                Some(ref langid) if *langid == langid!("de") => b"{\"message\":\"Hallo Welt\"}",
+                Some(ref langid) if *langid == langid!("xx") => b"{\"message\":",
+                _ => return Err(DataError::MissingResourceOptions(req.clone())),
+            };
+            receiver.receive_rc_buffer(json.into(), |bytes, f2| {
+                let mut d = serde_json::Deserializer::from_slice(bytes);
+                f2(&mut <dyn erased_serde::Deserializer>::erase(&mut d))
+            })?;
+            Ok(DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn load(
+        provider: &SharedDataProvider<CountingJsonProvider>,
+        langid: icu_locid::LanguageIdentifier,
+    ) -> Result<DataResponse<'static, HelloWorldV1Marker>, DataError> {
+        provider.load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::HELLO_WORLD_V1,
+                options: langid.into(),
+            },
+        })
+    }
+
+    #[test]
+    fn test_send_sync() {
+        static_assertions::assert_impl_all!(SharedDataProvider<CountingJsonProvider>: Send, Sync);
+    }
+
+    #[test]
+    fn test_cache() {
+        let provider = SharedDataProvider::new(CountingJsonProvider::default());
+
+        for _ in 0..3 {
+            let response = load(&provider, langid!("de")).expect("Loading should succeed");
+            assert_eq!(response.metadata.data_langid, Some(langid!("de")));
+            assert_eq!("Hallo Welt", response.take_payload().unwrap().get().message);
+        }
+        assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 1);
+        assert_eq!(provider.cached_len(), 1);
+
+        // Errors are not cached.
+        load(&provider, langid!("tlh")).expect_err("Klingon data is not available");
+        load(&provider, langid!("tlh")).expect_err("Klingon data is not available");
+        assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 3);
+
+        provider.clear_cache();
+        load(&provider, langid!("de")).expect("Loading should succeed");
+        assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_threads() {
+        let provider = SharedDataProvider::new(CountingJsonProvider::default());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let provider = provider.clone();
+                std::thread::spawn(move || {
+                    let response = load(&provider, langid!("de")).expect("Loading should succeed");
+                    assert_eq!("Hallo Welt", response.take_payload().unwrap().get().message);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().expect("Thread should not panic");
+        }
+
+        assert_eq!(provider.cached_len(), 1);
+        assert!(provider.inner().loads.load(Ordering::SeqCst) <= 4);
+    }
+
+    #[test]
+    fn test_prefetch() {
+        let provider = SharedDataProvider::new(CountingJsonProvider::default());
+
+        let count = provider
+            .prefetch(&[key::HELLO_WORLD_V1], &[langid!("de"), langid!("tlh")])
+            .expect("Prefetching should succeed");
+        assert_eq!(count, 1);
+        assert_eq!(provider.cached_len(), 1);
+        assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 2);
+
+        load(&provider, langid!("de")).expect("Loading should succeed");
+        assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 2);
+
+        provider
+            .prefetch(&[key::HELLO_WORLD_V1], &[langid!("xx")])
+            .expect_err("The data is not valid JSON");
+    }
+
+    #[test]
+    fn test_prefetch_in_background() {
+        let provider = SharedDataProvider::new(CountingJsonProvider::default());
+
+        let count = provider
+            .prefetch_in_background(&[key::HELLO_WORLD_V1], &[langid!("de"), langid!("tlh")], 4)
+            .join()
+            .expect("Prefetching should not panic")
+            .expect("Prefetching should succeed");
+        assert_eq!(count, 1);
+        assert_eq!(provider.cached_len(), 1);
+    }
+}
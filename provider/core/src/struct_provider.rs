@@ -2,12 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Data provider always serving the same struct.
+//! Data providers always serving the same struct.
 
 use crate::error::Error;
 use crate::prelude::*;
 use crate::yoke::trait_hack::YokeTraitHack;
 use crate::yoke::*;
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
 
 /// A data provider that returns clones of a constant data payload.
 ///
@@ -59,3 +61,105 @@ where
         })
     }
 }
+
+/// A data provider that returns clones of a constant data payload of any type, which is chosen
+/// when the provider is constructed rather than in its type.
+///
+/// Unlike [`StructProvider`], an [`AnyPayloadProvider`] can be stored alongside providers of other
+/// data structs, for example in a `Vec` or behind a `dyn` reference. Requests for the key with a
+/// different data marker fail with [`DataError::MismatchedType`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::struct_provider::AnyPayloadProvider;
+/// use std::borrow::Cow;
+///
+/// let provider = AnyPayloadProvider::new(
+///     key::HELLO_WORLD_V1,
+///     DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+///         message: Cow::Borrowed("hello world"),
+///     }),
+/// );
+///
+/// let payload: DataPayload<HelloWorldV1Marker> = provider
+///     .load_payload(&DataRequest::from(key::HELLO_WORLD_V1))
+///     .expect("Load should succeed")
+///     .take_payload()
+///     .expect("Data should be present");
+///
+/// assert_eq!(payload.get().message, "hello world");
+/// ```
+pub struct AnyPayloadProvider {
+    key: ResourceKey,
+    data: Box<dyn Any>,
+}
+
+impl AnyPayloadProvider {
+    /// Creates an [`AnyPayloadProvider`] serving `data` for `key`.
+    pub fn new<M>(key: ResourceKey, data: DataPayload<'static, M>) -> Self
+    where
+        M: DataMarker<'static> + 'static,
+    {
+        AnyPayloadProvider {
+            key,
+            data: Box::new(data),
+        }
+    }
+}
+
+impl<M> DataProvider<'static, M> for AnyPayloadProvider
+where
+    M: DataMarker<'static> + 'static,
+    for<'a> YokeTraitHack<<M::Yokeable as Yokeable<'a>>::Output>: Clone,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'static, M>, Error> {
+        req.resource_path.key.match_key(self.key)?;
+        let payload = self
+            .data
+            .downcast_ref::<DataPayload<'static, M>>()
+            .ok_or_else(|| Error::MismatchedType {
+                actual: Some((*self.data).type_id()),
+                generic: Some(TypeId::of::<DataPayload<'static, M>>()),
+            })?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(payload.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hello_world::{key, HelloWorldV1, HelloWorldV1Marker};
+    use crate::marker::CowStringMarker;
+    use alloc::borrow::Cow;
+
+    #[test]
+    fn test_any_payload_mismatched_type() {
+        let provider = AnyPayloadProvider::new(
+            key::HELLO_WORLD_V1,
+            DataPayload::<HelloWorldV1Marker>::from_owned(HelloWorldV1 {
+                message: Cow::Borrowed("hello world"),
+            }),
+        );
+        let result: Result<DataResponse<CowStringMarker>, Error> =
+            provider.load_payload(&DataRequest::from(key::HELLO_WORLD_V1));
+        assert!(matches!(result, Err(Error::MismatchedType { .. })));
+    }
+
+    #[test]
+    fn test_any_payload_mismatched_key() {
+        let provider = AnyPayloadProvider::new(
+            key::HELLO_WORLD_V1,
+            DataPayload::<CowStringMarker>::from_owned(Cow::Borrowed("hello world")),
+        );
+        let result: Result<DataResponse<CowStringMarker>, Error> = provider.load_payload(
+            &DataRequest::from(crate::resource_key!(x, "xyz", "example", 1)),
+        );
+        assert!(matches!(result, Err(Error::MissingResourceKey(_))));
+    }
+}
@@ -0,0 +1,101 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid_macros::langid;
+use icu_provider::async_provider::*;
+use icu_provider::hello_world::*;
+use icu_provider::marker::CowStringMarker;
+use icu_provider::prelude::*;
+use std::future::Future;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// Polls a future to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn noop(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    #[allow(unsafe_code)]
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn hello_world_request(lang: icu_locid::LanguageIdentifier) -> DataRequest {
+    DataRequest {
+        resource_path: ResourcePath {
+            key: key::HELLO_WORLD_V1,
+            options: ResourceOptions {
+                variant: None,
+                langid: Some(lang),
+            },
+        },
+    }
+}
+
+#[test]
+fn test_sync_provider_adapter() {
+    let provider = SyncProviderAdapter(HelloWorldProvider::new_with_placeholder_data());
+    let response: DataResponse<HelloWorldV1Marker> =
+        block_on(provider.load_payload(&hello_world_request(langid!("de"))))
+            .expect("Loading should succeed");
+    assert_eq!("Hallo Welt", response.take_payload().unwrap().get().message);
+}
+
+#[test]
+fn test_prefetch() {
+    let async_provider = SyncProviderAdapter(HelloWorldProvider::new_with_placeholder_data());
+    let mut provider = PrefetchedDataProvider::new();
+    block_on(
+        provider
+            .prefetch::<HelloWorldV1Marker, _>(&async_provider, hello_world_request(langid!("de"))),
+    )
+    .expect("Prefetching should succeed");
+    block_on(
+        provider
+            .prefetch::<HelloWorldV1Marker, _>(&async_provider, hello_world_request(langid!("ja"))),
+    )
+    .expect("Prefetching should succeed");
+
+    let payload: DataPayload<HelloWorldV1Marker> = provider
+        .load_payload(&hello_world_request(langid!("ja")))
+        .expect("Loading should succeed")
+        .take_payload()
+        .expect("Data should be present");
+    assert_eq!("こんにちは世界", payload.get().message);
+
+    // Requests that were not prefetched fail.
+    let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+        provider.load_payload(&hello_world_request(langid!("ru")));
+    assert!(matches!(result, Err(DataError::MissingResourceOptions(_))));
+
+    // Prefetched data cannot be loaded as a different type.
+    let result: Result<DataResponse<CowStringMarker>, DataError> =
+        provider.load_payload(&hello_world_request(langid!("de")));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_prefetch_error() {
+    let async_provider = SyncProviderAdapter(HelloWorldProvider::new_with_placeholder_data());
+    let mut provider = PrefetchedDataProvider::new();
+    let result = block_on(
+        provider
+            .prefetch::<HelloWorldV1Marker, _>(&async_provider, hello_world_request(langid!("xx"))),
+    );
+    assert!(result.is_err());
+
+    let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+        provider.load_payload(&hello_world_request(langid!("de")));
+    assert!(matches!(result, Err(DataError::MissingResourceKey(_))));
+}
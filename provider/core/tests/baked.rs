@@ -0,0 +1,88 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+extern crate alloc;
+
+mod data {
+    include!("data/hello_world.rs");
+}
+
+use icu_locid_macros::langid;
+use icu_provider::baked::*;
+use icu_provider::hello_world::*;
+use icu_provider::marker::CowStringMarker;
+use icu_provider::prelude::*;
+
+fn hello_world_request(lang: icu_locid::LanguageIdentifier) -> DataRequest {
+    DataRequest {
+        resource_path: ResourcePath {
+            key: key::HELLO_WORLD_V1,
+            options: lang.into(),
+        },
+    }
+}
+
+#[test]
+fn test_load() {
+    let provider = BakedDataProvider::new(data::DATA);
+
+    let payload: DataPayload<HelloWorldV1Marker> = provider
+        .load_payload(&hello_world_request(langid!("de")))
+        .expect("Loading should succeed")
+        .take_payload()
+        .expect("Data should be present");
+    assert_eq!("Hallo Welt", payload.get().message);
+
+    let payload: DataPayload<HelloWorldV1Marker> = provider
+        .load_payload(&hello_world_request(langid!("fa")))
+        .expect("Loading should succeed")
+        .take_payload()
+        .expect("Data should be present");
+    assert_eq!("سلام دنیا\u{200e}", payload.get().message);
+}
+
+#[test]
+fn test_errors() {
+    let provider = BakedDataProvider::new(data::DATA);
+
+    let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+        provider.load_payload(&hello_world_request(langid!("xx")));
+    assert!(matches!(result, Err(DataError::MissingResourceOptions(_))));
+
+    let result: Result<DataResponse<HelloWorldV1Marker>, DataError> =
+        provider.load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: icu_provider::resource_key!(Core, "goodbyeworld", 1),
+                options: langid!("de").into(),
+            },
+        });
+    assert!(matches!(result, Err(DataError::MissingResourceKey(_))));
+
+    let result: Result<DataResponse<CowStringMarker>, DataError> =
+        provider.load_payload(&hello_world_request(langid!("de")));
+    assert!(matches!(result, Err(DataError::MismatchedType { .. })));
+}
+
+#[test]
+fn test_export() {
+    let mut buffer: Vec<u8> = Vec::new();
+    {
+        let mut exporter = BakedExporter::new_with_sink(Box::new(&mut buffer));
+        icu_provider::export::export_from_iterable::<_, _, HelloWorldV1Marker>(
+            &key::HELLO_WORLD_V1,
+            &HelloWorldProvider::new_with_placeholder_data(),
+            &mut exporter,
+        )
+        .expect("Should successfully export");
+        exporter
+            .close()
+            .expect("Should successfully write to buffer");
+    }
+
+    // The exported data equals the pre-computed data used in the tests above.
+    assert_eq!(
+        include_str!("data/hello_world.rs"),
+        String::from_utf8(buffer).expect("Output should be UTF-8")
+    );
+}
@@ -0,0 +1,20 @@
+// @generated by icu4x-datagen. Do not edit.
+
+#[rustfmt::skip]
+pub static DATA: &::icu_provider::baked::BakedData = &[
+    ("core/helloworld@1/bn", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("ওহে বিশ\u{9cd}ব") }),
+    ("core/helloworld@1/cs", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Ahoj světe") }),
+    ("core/helloworld@1/de", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Hallo Welt") }),
+    ("core/helloworld@1/el", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Καλημέρα κόσμε") }),
+    ("core/helloworld@1/en", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Hello World") }),
+    ("core/helloworld@1/eo", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Saluton, Mondo") }),
+    ("core/helloworld@1/fa", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("سلام دنیا\u{200e}") }),
+    ("core/helloworld@1/fi", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("hei maailma") }),
+    ("core/helloworld@1/is", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Halló, heimur") }),
+    ("core/helloworld@1/ja", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("こんにちは世界") }),
+    ("core/helloworld@1/la", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Ave, munde") }),
+    ("core/helloworld@1/ro", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Salut,lume!") }),
+    ("core/helloworld@1/ru", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Привет, мир") }),
+    ("core/helloworld@1/vi", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("Xin chào thế giới") }),
+    ("core/helloworld@1/zh", &::icu_provider::hello_world::HelloWorldV1 { message: ::alloc::borrow::Cow::Borrowed("你好世界") }),
+];
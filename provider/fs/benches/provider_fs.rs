@@ -40,6 +40,10 @@ fn overview_bench(c: &mut Criterion) {
         {
             bincode_bench(c);
         }
+        #[cfg(feature = "provider_postcard")]
+        {
+            postcard_bench(c);
+        }
     }
 }
 
@@ -129,5 +133,48 @@ fn bincode_bench(c: &mut Criterion) {
     });
 }
 
+#[cfg(all(feature = "bench", feature = "provider_postcard"))]
+fn postcard_bench(c: &mut Criterion) {
+    let provider = FsDataProvider::try_new("./tests/testdata/postcard")
+        .expect("Loading file from testdata directory");
+
+    c.bench_function("postcard/generic", |b| {
+        b.iter(|| {
+            let _: DataPayload<PluralRuleStringsV1Marker> = black_box(&provider)
+                .load_payload(&DataRequest {
+                    resource_path: ResourcePath {
+                        key: key::CARDINAL_V1,
+                        options: ResourceOptions {
+                            variant: None,
+                            langid: Some(langid!("sr")),
+                        },
+                    },
+                })
+                .expect("The data should be valid")
+                .take_payload()
+                .expect("Loading was successful");
+        });
+    });
+
+    c.bench_function("postcard/erased_serde", |b| {
+        b.iter(|| {
+            let _: DataPayload<PluralRuleStringsV1Marker> =
+                black_box(&provider as &dyn SerdeDeDataProvider)
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: key::CARDINAL_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(langid!("sr")),
+                            },
+                        },
+                    })
+                    .expect("The data should be valid")
+                    .take_payload()
+                    .expect("Loading was successful");
+        });
+    });
+}
+
 criterion_group!(benches, overview_bench,);
 criterion_main!(benches);
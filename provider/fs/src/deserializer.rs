@@ -24,6 +24,9 @@ pub enum Error {
     #[cfg(feature = "provider_bincode")]
     #[displaydoc("{0}")]
     Bincode(bincode::Error),
+    #[cfg(feature = "provider_postcard")]
+    #[displaydoc("{0}")]
+    Postcard(postcard::Error),
     #[displaydoc("{0}")]
     DataProvider(DataError),
     #[allow(dead_code)]
@@ -47,6 +50,13 @@ impl From<bincode::Error> for Error {
     }
 }
 
+#[cfg(feature = "provider_postcard")]
+impl From<postcard::Error> for Error {
+    fn from(e: postcard::Error) -> Self {
+        Error::Postcard(e)
+    }
+}
+
 impl From<DataError> for Error {
     fn from(e: DataError) -> Self {
         Error::DataProvider(e)
@@ -65,6 +75,10 @@ impl Error {
             Self::Bincode(err) => {
                 CrateError::Deserializer(format!("{}", err), Some(path.as_ref().to_path_buf()))
             }
+            #[cfg(feature = "provider_postcard")]
+            Self::Postcard(err) => {
+                CrateError::Deserializer(format!("{}", err), Some(path.as_ref().to_path_buf()))
+            }
             Self::DataProvider(err) => {
                 CrateError::Deserializer(format!("{}", err), Some(path.as_ref().to_path_buf()))
             }
@@ -94,6 +108,14 @@ macro_rules! get_bincode_deserializer_zc {
     }};
 }
 
+/// Get a Postcard zero-copy Deserializer. Implemeted as a macro because the return type is complex/private.
+#[cfg(feature = "provider_postcard")]
+macro_rules! get_postcard_deserializer_zc {
+    ($bytes:tt) => {
+        postcard::Deserializer::from_bytes($bytes)
+    };
+}
+
 /// Returns an error if the syntax option is not supported.
 pub fn check_format_supported(syntax_option: &SyntaxOption) -> Result<(), crate::error::Error> {
     #[allow(unused_imports)]
@@ -107,6 +129,10 @@ pub fn check_format_supported(syntax_option: &SyntaxOption) -> Result<(), crate:
         SyntaxOption::Bincode => Ok(()),
         #[cfg(not(feature = "provider_bincode"))]
         SyntaxOption::Bincode => Err(Error::UnknownSyntax(SyntaxOption::Bincode)),
+        #[cfg(feature = "provider_postcard")]
+        SyntaxOption::Postcard => Ok(()),
+        #[cfg(not(feature = "provider_postcard"))]
+        SyntaxOption::Postcard => Err(Error::UnknownSyntax(SyntaxOption::Postcard)),
     }
 }
 
@@ -139,6 +165,14 @@ where
         },
         #[cfg(not(feature = "provider_bincode"))]
         SyntaxOption::Bincode => |_| Err(Error::UnknownSyntax(SyntaxOption::Bincode)),
+        #[cfg(feature = "provider_postcard")]
+        SyntaxOption::Postcard => |bytes| {
+            let mut d = get_postcard_deserializer_zc!(bytes);
+            let data = YokeTraitHack::<<M::Yokeable as Yokeable>::Output>::deserialize(&mut d)?;
+            Ok(data.0)
+        },
+        #[cfg(not(feature = "provider_postcard"))]
+        SyntaxOption::Postcard => |_| Err(Error::UnknownSyntax(SyntaxOption::Postcard)),
     }
 }
 
@@ -171,5 +205,15 @@ pub fn deserialize_into_receiver(
         }
         #[cfg(not(feature = "provider_bincode"))]
         SyntaxOption::Bincode => Err(Error::UnknownSyntax(SyntaxOption::Bincode)),
+        #[cfg(feature = "provider_postcard")]
+        SyntaxOption::Postcard => {
+            receiver.receive_rc_buffer(rc_buffer, |bytes, f2| {
+                let mut d = get_postcard_deserializer_zc!(bytes);
+                f2(&mut <dyn erased_serde::Deserializer>::erase(&mut d))
+            })?;
+            Ok(())
+        }
+        #[cfg(not(feature = "provider_postcard"))]
+        SyntaxOption::Postcard => Err(Error::UnknownSyntax(SyntaxOption::Postcard)),
     }
 }
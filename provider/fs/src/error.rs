@@ -19,6 +19,9 @@ pub enum Error {
     Serializer(erased_serde::Error, Option<PathBuf>),
     #[displaydoc("Unknown syntax {0:?}. Do you need to enable a feature?")]
     UnknownSyntax(SyntaxOption),
+    #[cfg(feature = "watch")]
+    #[displaydoc("Watcher error: {0}")]
+    Watcher(notify::Error),
 }
 
 impl std::error::Error for Error {}
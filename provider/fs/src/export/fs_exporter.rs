@@ -13,7 +13,7 @@ use icu_provider::prelude::*;
 use icu_provider::serde::SerdeSeDataStructMarker;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -52,7 +52,7 @@ impl Default for ExporterOptions {
 /// See the module-level docs for an example.
 pub struct FilesystemExporter {
     root: PathBuf,
-    manifest: Manifest,
+    manifest: Manifest<'static>,
     alias_collection: Option<AliasCollection<Vec<u8>>>,
     serializer: Box<dyn AbstractSerializer>,
 }
@@ -99,35 +99,14 @@ impl FilesystemExporter {
             manifest: Manifest {
                 aliasing: options.aliasing,
                 syntax: serializer.deref().clone(),
+                schema_version: Some(icu_provider::schema::DATA_SCHEMA_VERSION),
             },
             alias_collection: None,
             serializer,
         };
 
-        match options.overwrite {
-            OverwriteOption::CheckEmpty => {
-                if result.root.exists() {
-                    fs::remove_dir(&result.root).map_err(|e| (e, &result.root))?;
-                }
-            }
-            OverwriteOption::RemoveAndReplace => {
-                if result.root.exists() {
-                    fs::remove_dir_all(&result.root).map_err(|e| (e, &result.root))?;
-                }
-            }
-        };
-        fs::create_dir_all(&result.root).map_err(|e| (e, &result.root))?;
-
-        let mut manifest_path = result.root.to_path_buf();
-        manifest_path.push(MANIFEST_FILE);
-        let mut manifest_file =
-            fs::File::create(&manifest_path).map_err(|e| (e, &manifest_path))?;
-        let manifest_serializer = json::Serializer::new(json::Options {
-            style: json::StyleOption::Pretty,
-        });
-        manifest_serializer
-            .serialize(&result.manifest, &mut manifest_file)
-            .map_err(|e| (e, manifest_path))?;
+        create_root(&result.root, options.overwrite)?;
+        write_manifest(&result.root, &result.manifest)?;
         Ok(result)
     }
 
@@ -171,3 +150,34 @@ impl FilesystemExporter {
         Ok(())
     }
 }
+
+/// Creates the output directory, removing an existing directory as specified by `overwrite`.
+pub(crate) fn create_root(root: &Path, overwrite: OverwriteOption) -> Result<(), Error> {
+    match overwrite {
+        OverwriteOption::CheckEmpty => {
+            if root.exists() {
+                fs::remove_dir(root).map_err(|e| (e, root))?;
+            }
+        }
+        OverwriteOption::RemoveAndReplace => {
+            if root.exists() {
+                fs::remove_dir_all(root).map_err(|e| (e, root))?;
+            }
+        }
+    };
+    fs::create_dir_all(root).map_err(|e| (e, root))?;
+    Ok(())
+}
+
+/// Writes the manifest to the output directory.
+pub(crate) fn write_manifest(root: &Path, manifest: &Manifest) -> Result<(), Error> {
+    let manifest_path = root.join(MANIFEST_FILE);
+    let mut manifest_file = fs::File::create(&manifest_path).map_err(|e| (e, &manifest_path))?;
+    let manifest_serializer = json::Serializer::new(json::Options {
+        style: json::StyleOption::Pretty,
+    });
+    manifest_serializer
+        .serialize(manifest, &mut manifest_file)
+        .map_err(|e| (e, manifest_path))?;
+    Ok(())
+}
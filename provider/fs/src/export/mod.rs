@@ -9,6 +9,9 @@
 //!
 //! For a command-line user interface, see the `icu_datagen` crate.
 //!
+//! To make a smaller data directory with some of the keys and locales of an existing one, use
+//! [`slice_dir()`]. This does not require the sources the data was generated from.
+//!
 //! # Examples
 //!
 //! ```
@@ -70,4 +73,6 @@
 mod aliasing;
 pub mod fs_exporter;
 pub mod serializers;
+mod slice;
 pub use fs_exporter::FilesystemExporter;
+pub use slice::slice_dir;
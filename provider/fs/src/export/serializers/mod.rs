@@ -7,6 +7,9 @@ pub mod json;
 #[cfg(feature = "provider_bincode")]
 pub mod bincode;
 
+#[cfg(feature = "provider_postcard")]
+pub mod postcard;
+
 use crate::manifest::SyntaxOption;
 use displaydoc::Display;
 use std::io;
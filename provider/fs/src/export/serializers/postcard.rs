@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::AbstractSerializer;
+use super::Error;
+use crate::manifest::SyntaxOption;
+use std::io;
+use std::ops::Deref;
+
+/// A serializer for Postcard.
+pub struct Serializer {
+    syntax: SyntaxOption,
+}
+
+/// Options bag for initializing a [`postcard::Serializer`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Deref for Serializer {
+    type Target = SyntaxOption;
+
+    fn deref(&self) -> &Self::Target {
+        &self.syntax
+    }
+}
+
+impl AbstractSerializer for Serializer {
+    fn serialize(
+        &self,
+        obj: &dyn erased_serde::Serialize,
+        sink: &mut dyn io::Write,
+    ) -> Result<(), Error> {
+        // Postcard serializes into a buffer, which is then written to the sink.
+        let mut serializer = postcard::Serializer {
+            output: postcard::flavors::AllocVec(Vec::new()),
+        };
+        obj.erased_serialize(&mut <dyn erased_serde::Serializer>::erase(&mut serializer))?;
+        sink.write_all(&serializer.output.0)?;
+        Ok(())
+    }
+}
+
+impl Serializer {
+    pub fn new(_options: Options) -> Self {
+        Self {
+            syntax: SyntaxOption::Postcard,
+        }
+    }
+}
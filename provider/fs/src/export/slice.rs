@@ -0,0 +1,105 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::fs_exporter::{self, OverwriteOption};
+use crate::error::Error;
+use crate::manifest::{AliasOption, Manifest};
+use crate::FsDataProvider;
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/// Copies the data files of the given keys from an existing data directory to a new directory
+/// at `root`, keeping only the given locales. Returns the number of files that were copied.
+///
+/// The files are copied without deserializing them, so the new directory has the syntax of the
+/// source directory, and the data structs of the keys do not need to be known. Symlinks in the
+/// source directory are replaced by copies of the files they point to, and the data schema
+/// version of the source manifest, if any, is kept. Resources that do not depend on the locale
+/// are always kept, and all locales are kept if `locales` is `None`. Keys that are not in the
+/// source directory are skipped.
+///
+/// The locales that the kept locales fall back to, such as `en` for `en-GB`, are not added; to
+/// keep them, include them in `locales`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_plurals::provider::key;
+/// use icu_provider_fs::export::fs_exporter::OverwriteOption;
+/// use icu_provider_fs::export::slice_dir;
+/// use icu_provider_fs::FsDataProvider;
+///
+/// let source = FsDataProvider::try_new("./tests/testdata/json")
+///     .expect("Should successfully read from filesystem");
+/// let demo_path = std::env::temp_dir().join("icu4x_slice_demo");
+///
+/// let count = slice_dir(
+///     &source,
+///     &[key::CARDINAL_V1, key::ORDINAL_V1],
+///     Some(&[langid!("ru")]),
+///     &demo_path,
+///     OverwriteOption::RemoveAndReplace,
+/// )
+/// .expect("Slicing should succeed");
+/// assert_eq!(count, 1);
+///
+/// // Clean up from demo
+/// std::fs::remove_dir_all(&demo_path).expect("Should clean up test directory");
+/// ```
+pub fn slice_dir(
+    source: &FsDataProvider,
+    keys: &[ResourceKey],
+    locales: Option<&[LanguageIdentifier]>,
+    root: &Path,
+    overwrite: OverwriteOption,
+) -> Result<usize, Error> {
+    fs_exporter::create_root(root, overwrite)?;
+    fs_exporter::write_manifest(
+        root,
+        &Manifest {
+            aliasing: AliasOption::NoAliases,
+            syntax: source.syntax().clone(),
+            schema_version: source.schema_version(),
+        },
+    )?;
+    let extension = source.syntax().get_file_extension();
+    let mut count = 0;
+    for key in keys {
+        let all_options = match source.supported_options_for_key(key) {
+            Ok(all_options) => all_options,
+            Err(DataError::MissingResourceKey(_)) => {
+                log::warn!("Skipping key not in the source directory: {}", key);
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        for options in all_options {
+            let selected = match (&options.langid, locales) {
+                (Some(langid), Some(locales)) => locales.contains(langid),
+                _ => true,
+            };
+            if !selected {
+                continue;
+            }
+            let mut path_buf = root.to_path_buf();
+            path_buf.extend(key.get_components().iter());
+            path_buf.extend(options.get_components().iter());
+            path_buf.set_extension(extension);
+            let source_path = source.get_path(&DataRequest {
+                resource_path: ResourcePath { key: *key, options },
+            })?;
+            log::trace!("Copying: {}", source_path.display());
+            if let Some(parent_dir) = path_buf.parent() {
+                fs::create_dir_all(&parent_dir).map_err(|e| (e, parent_dir))?;
+            }
+            fs::copy(&source_path, &path_buf).map_err(|e| (e, &source_path))?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
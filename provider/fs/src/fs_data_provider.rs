@@ -5,18 +5,22 @@
 use crate::deserializer;
 use crate::error::Error;
 use crate::manifest::Manifest;
+use crate::manifest::SyntaxOption;
 use crate::manifest::MANIFEST_FILE;
+use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_provider::serde::*;
 use icu_provider::yoke::trait_hack::YokeTraitHack;
 use icu_provider::yoke::Yokeable;
 
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::fs;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::Read;
-use std::path::PathBuf;
+use std::iter;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 /// A data provider that reads ICU4X data from a filesystem directory.
@@ -32,7 +36,11 @@ use std::rc::Rc;
 #[derive(Debug, PartialEq)]
 pub struct FsDataProvider {
     res_root: PathBuf,
-    manifest: Manifest,
+    syntax: SyntaxOption,
+    #[cfg(feature = "export")]
+    schema_version: Option<String>,
+    #[cfg(feature = "mmap")]
+    mmap: bool,
 }
 
 impl FsDataProvider {
@@ -54,18 +62,78 @@ impl FsDataProvider {
             .map(|(obj, _)| obj)
             .map_err(|e| (e, &manifest_path))?;
         deserializer::check_format_supported(&manifest.syntax)?;
+        if let Some(schema_version) = manifest.schema_version {
+            icu_provider::schema::check_schema_version(schema_version)?;
+        }
         Ok(Self {
             res_root: root_path_buf,
-            manifest,
+            syntax: manifest.syntax,
+            #[cfg(feature = "export")]
+            schema_version: manifest.schema_version.map(String::from),
+            #[cfg(feature = "mmap")]
+            mmap: false,
         })
     }
 
+    /// Create a new [`FsDataProvider`] given a filesystem directory, memory-mapping data files
+    /// instead of reading them into memory.
+    ///
+    /// Payloads borrow directly from the mapped files without copying them onto the heap. Since
+    /// the operating system shares the mapped pages, this greatly reduces resident memory when
+    /// many processes use the same data directory. It is most effective with the `bincode` and
+    /// `postcard` syntaxes, which borrow all strings from the file.
+    ///
+    /// The data files must not be modified or truncated while payloads loaded from them are
+    /// alive; replace the data directory atomically instead.
+    ///
+    /// Only [`DataProvider`] uses memory-mapping; [`SerdeDeDataProvider`] still reads the files
+    /// into memory.
+    ///
+    /// *Enabled with the "mmap" feature*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_fs::FsDataProvider;
+    ///
+    /// let provider = FsDataProvider::try_new_mmap("/path/to/data/directory")
+    ///     .expect_err("Specify a real directory in the line above");
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn try_new_mmap<T: Into<PathBuf>>(root: T) -> Result<Self, Error> {
+        Ok(Self {
+            mmap: true,
+            ..Self::try_new(root)?
+        })
+    }
+
+    /// The syntax of the data files, as declared in the manifest.
+    #[cfg(any(feature = "watch", feature = "export"))]
+    pub(crate) fn syntax(&self) -> &SyntaxOption {
+        &self.syntax
+    }
+
+    /// The data schema version, as declared in the manifest.
+    #[cfg(feature = "export")]
+    pub(crate) fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+
     fn get_reader(&self, req: &DataRequest) -> Result<(impl Read, PathBuf), DataError> {
+        let path_buf = self.get_path(req)?;
+        let file = match File::open(&path_buf) {
+            Ok(file) => file,
+            Err(err) => return Err(DataError::new_resc_error(err)),
+        };
+        Ok((BufReader::new(file), path_buf))
+    }
+
+    pub(crate) fn get_path(&self, req: &DataRequest) -> Result<PathBuf, DataError> {
         type Error = DataError;
         let mut path_buf = self.res_root.clone();
         path_buf.extend(req.resource_path.key.get_components().iter());
         if req.resource_path.options.is_empty() {
-            path_buf.set_extension(self.manifest.syntax.get_file_extension());
+            path_buf.set_extension(self.syntax.get_file_extension());
         }
         if !path_buf.exists() {
             return Err(Error::MissingResourceKey(req.resource_path.key));
@@ -73,19 +141,18 @@ impl FsDataProvider {
         if !req.resource_path.options.is_empty() {
             // TODO: Implement proper locale fallback
             path_buf.extend(req.resource_path.options.get_components().iter());
-            path_buf.set_extension(self.manifest.syntax.get_file_extension());
+            path_buf.set_extension(self.syntax.get_file_extension());
         }
         if !path_buf.exists() {
             return Err(Error::MissingResourceOptions(req.clone()));
         }
-        let file = match File::open(&path_buf) {
-            Ok(file) => file,
-            Err(err) => return Err(Error::new_resc_error(err)),
-        };
-        Ok((BufReader::new(file), path_buf))
+        Ok(path_buf)
     }
 
-    fn get_rc_buffer(&self, req: &DataRequest) -> Result<(Rc<[u8]>, PathBuf), DataError> {
+    pub(crate) fn get_rc_buffer(
+        &self,
+        req: &DataRequest,
+    ) -> Result<(Rc<[u8]>, PathBuf), DataError> {
         let (mut reader, path_buf) = self.get_reader(req)?;
         let mut buffer = Vec::<u8>::new();
         reader
@@ -94,6 +161,22 @@ impl FsDataProvider {
         let rc_buffer: Rc<[u8]> = buffer.into();
         Ok((rc_buffer, path_buf))
     }
+
+    #[cfg(feature = "mmap")]
+    fn get_mmap_buffer(
+        &self,
+        req: &DataRequest,
+    ) -> Result<(Rc<dyn ByteBuffer>, PathBuf), DataError> {
+        let path_buf = self.get_path(req)?;
+        let file = File::open(&path_buf)
+            .map_err(|e| DataError::new_resc_error(Error::Io(e, Some(path_buf.clone()))))?;
+        // Safety: The mapped file must not be modified while the map is alive. This is a
+        // documented requirement of `try_new_mmap`.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::Mmap::map(&file) }
+            .map_err(|e| DataError::new_resc_error(Error::Io(e, Some(path_buf.clone()))))?;
+        Ok((Rc::new(mmap), path_buf))
+    }
 }
 
 /// Note: This impl returns `'static` payloads because borrowing is handled by [`Yoke`].
@@ -106,15 +189,31 @@ where
     for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::de::Deserialize<'de>,
 {
     fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let metadata = DataResponseMetadata {
+            data_langid: req.resource_path.options.langid.clone(),
+            ..Default::default()
+        };
+        #[cfg(feature = "mmap")]
+        if self.mmap {
+            let (buffer, path_buf) = self.get_mmap_buffer(req)?;
+            return Ok(DataResponse {
+                metadata,
+                payload: Some(
+                    DataPayload::try_from_byte_buffer(
+                        buffer,
+                        deserializer::deserialize_zero_copy::<M>(&self.syntax),
+                    )
+                    .map_err(|e: deserializer::Error| e.into_resource_error(&path_buf))?,
+                ),
+            });
+        }
         let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
         Ok(DataResponse {
-            metadata: DataResponseMetadata {
-                data_langid: req.resource_path.options.langid.clone(),
-            },
+            metadata,
             payload: Some(
                 DataPayload::try_from_rc_buffer(
                     rc_buffer,
-                    deserializer::deserialize_zero_copy::<M>(&self.manifest.syntax),
+                    deserializer::deserialize_zero_copy::<M>(&self.syntax),
                 )
                 .map_err(|e: deserializer::Error| e.into_resource_error(&path_buf))?,
             ),
@@ -129,10 +228,65 @@ impl SerdeDeDataProvider for FsDataProvider {
         receiver: &mut dyn SerdeDeDataReceiver,
     ) -> Result<DataResponseMetadata, DataError> {
         let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
-        deserializer::deserialize_into_receiver(rc_buffer, &self.manifest.syntax, receiver)
+        deserializer::deserialize_into_receiver(rc_buffer, &self.syntax, receiver)
             .map_err(|err| err.into_resource_error(&path_buf))?;
         Ok(DataResponseMetadata {
             data_langid: req.resource_path.options.langid.clone(),
+            ..Default::default()
         })
     }
 }
+
+impl IterableDataProviderCore for FsDataProvider {
+    /// Lists the data files of the key in the directory, sorted by their path.
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        let mut path_buf = self.res_root.clone();
+        path_buf.extend(resc_key.get_components().iter());
+        let extension = self.syntax.get_file_extension();
+        let mut options = Vec::new();
+        if path_buf.with_extension(extension).is_file() {
+            options.push(ResourceOptions::default());
+        }
+        if path_buf.is_dir() {
+            collect_options(&path_buf, &mut Vec::new(), extension, &mut options)?;
+        }
+        if options.is_empty() {
+            return Err(DataError::MissingResourceKey(*resc_key));
+        }
+        options.sort_by_cached_key(|options| options.to_string());
+        Ok(Box::new(options.into_iter()))
+    }
+}
+
+/// Recursively collects the options of the data files in `dir`, whose path components relative
+/// to the directory of the key are `prefix`.
+fn collect_options(
+    dir: &Path,
+    prefix: &mut Vec<String>,
+    extension: &str,
+    options: &mut Vec<ResourceOptions>,
+) -> Result<(), DataError> {
+    let io_error = |e: std::io::Error| DataError::new_resc_error(Error::from((e, dir)));
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            if let Some(name) = path.file_name().and_then(OsStr::to_str) {
+                prefix.push(name.to_string());
+                collect_options(&path, prefix, extension, options)?;
+                prefix.pop();
+            }
+        } else if path.extension().and_then(OsStr::to_str) == Some(extension) {
+            if let Some(stem) = path.file_stem().and_then(OsStr::to_str) {
+                let components = prefix.iter().map(String::as_str).chain(iter::once(stem));
+                options.push(
+                    ResourceOptions::try_from_components(components)
+                        .map_err(DataError::new_resc_error)?,
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -54,8 +54,26 @@
 //!
 //! * JSON - Textual format, easy to read
 //! * Bincode - Binary, fast resource format
+//! * Postcard - Binary, compact resource format
 //!
-//! The directory passed to the [`FsDataProvider`] constructor may contain either of them.
+//! The directory passed to the [`FsDataProvider`] constructor may contain any of them. The
+//! format is declared in *manifest.json*.
+//!
+//! # Memory-mapped files
+//!
+//! With the `mmap` feature, [`FsDataProvider::try_new_mmap`] memory-maps data files instead of
+//! reading them, so that payloads borrow from the mapped files without copying. This reduces
+//! resident memory when many processes share the same data directory.
+//!
+//! [`FsDataProvider::try_new_mmap`]: struct.FsDataProvider.html#method.try_new_mmap
+//!
+//! # Reloading data
+//!
+//! With the `watch` feature, [`ReloadingFsDataProvider`] caches data files in memory and watches
+//! the data directory for changes, so that long-running processes pick up data updates without
+//! restarting.
+//!
+//! [`ReloadingFsDataProvider`]: struct.ReloadingFsDataProvider.html
 //!
 //! # Exporting data
 //!
@@ -84,8 +102,11 @@
 //!   -s bincode
 //! ```
 //!
-//! *Notice:* In order to use `bincode` encoded data in production, [`icu_provider_fs`](crate) has to be
-//! added with `bincode` feature.
+//! Use `-s postcard` to export `postcard` format.
+//!
+//! *Notice:* In order to use `bincode` or `postcard` encoded data in production,
+//! [`icu_provider_fs`](crate) has to be added with the `provider_bincode` or `provider_postcard`
+//! feature.
 //!
 //! [`ICU4X`]: ../icu/index.html
 
@@ -93,9 +114,13 @@ mod deserializer;
 mod error;
 mod fs_data_provider;
 pub mod manifest;
+#[cfg(feature = "watch")]
+mod reloading;
 
 #[cfg(feature = "export")]
 pub mod export;
 
 pub use error::Error as FsDataError;
 pub use fs_data_provider::FsDataProvider;
+#[cfg(feature = "watch")]
+pub use reloading::ReloadingFsDataProvider;
@@ -24,6 +24,8 @@ pub enum SyntaxOption {
     Json,
     /// Serialize using Bincode.
     Bincode,
+    /// Serialize using Postcard.
+    Postcard,
     // Future: Consider adding a custom format option here.
     // Custom {
     //     file_extension: String,
@@ -36,15 +38,23 @@ impl SyntaxOption {
         match self {
             Self::Json => "json",
             Self::Bincode => "bincode",
+            Self::Postcard => "postcard",
         }
     }
 }
 
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub(crate) struct Manifest {
+pub(crate) struct Manifest<'a> {
     /// Strategy for de-duplicating locale data.
     pub aliasing: AliasOption,
     /// Which data serialization file format is used.
     pub syntax: SyntaxOption,
+    /// The version of the data structs, see [`icu_provider::schema`]. Manifests written before
+    /// this field was added do not have it, and are not checked.
+    ///
+    /// This borrows from the manifest file, since `serde-json-core` can only deserialize
+    /// borrowed strings.
+    #[serde(borrow)]
+    pub schema_version: Option<&'a str>,
 }
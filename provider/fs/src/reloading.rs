@@ -0,0 +1,174 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::deserializer;
+use crate::error::Error;
+use crate::fs_data_provider::FsDataProvider;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_provider::serde::*;
+use icu_provider::yoke::trait_hack::YokeTraitHack;
+use icu_provider::yoke::Yokeable;
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// How long the watcher waits for a burst of file system events to settle before reporting them.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(500);
+
+/// A data provider that reads ICU4X data from a filesystem directory, caching the data files in
+/// memory and reloading them when the directory changes.
+///
+/// The directory is watched for changes in a background thread. When any file changes, the
+/// whole cache is invalidated, so that subsequent requests read the new data. This lets
+/// long-running servers pick up data updates without restarting. Payloads that were loaded
+/// before the change keep the old data.
+///
+/// The manifest is only read when the provider is created; changing the syntax of the data
+/// requires creating a new provider.
+///
+/// *Enabled with the "watch" feature*
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider_fs::ReloadingFsDataProvider;
+///
+/// let provider = ReloadingFsDataProvider::try_new("/path/to/data/directory")
+///     .expect_err("Specify a real directory in the line above");
+/// ```
+pub struct ReloadingFsDataProvider {
+    inner: FsDataProvider,
+    cache: RefCell<HashMap<PathBuf, Rc<[u8]>>>,
+    events: Receiver<DebouncedEvent>,
+    // Dropping the watcher stops watching the directory.
+    _watcher: RecommendedWatcher,
+}
+
+impl fmt::Debug for ReloadingFsDataProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ReloadingFsDataProvider")
+            .field("inner", &self.inner)
+            .field("cached_files", &self.cache.borrow().len())
+            .finish()
+    }
+}
+
+impl ReloadingFsDataProvider {
+    /// Create a new [`ReloadingFsDataProvider`] given a filesystem directory, and start watching
+    /// the directory for changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_fs::ReloadingFsDataProvider;
+    ///
+    /// let provider = ReloadingFsDataProvider::try_new("/path/to/data/directory")
+    ///     .expect_err("Specify a real directory in the line above");
+    /// ```
+    pub fn try_new<T: Into<PathBuf>>(root: T) -> Result<Self, Error> {
+        let root_path_buf: PathBuf = root.into();
+        let inner = FsDataProvider::try_new(root_path_buf.clone())?;
+        let (sender, events) = mpsc::channel();
+        let mut watcher: RecommendedWatcher =
+            Watcher::new(sender, DEBOUNCE_DELAY).map_err(Error::Watcher)?;
+        watcher
+            .watch(&root_path_buf, RecursiveMode::Recursive)
+            .map_err(Error::Watcher)?;
+        Ok(Self {
+            inner,
+            cache: RefCell::new(HashMap::new()),
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Clears the cache if the watcher reported any change since the last call.
+    fn process_events(&self) {
+        let changed = self.events.try_iter().any(|event| {
+            // Notices are sent ahead of the debounced events and can be ignored.
+            !matches!(
+                event,
+                DebouncedEvent::NoticeWrite(_) | DebouncedEvent::NoticeRemove(_)
+            )
+        });
+        if changed {
+            self.cache.borrow_mut().clear();
+        }
+    }
+
+    fn get_rc_buffer(&self, req: &DataRequest) -> Result<(Rc<[u8]>, PathBuf), DataError> {
+        self.process_events();
+        let path_buf = self.inner.get_path(req)?;
+        if let Some(rc_buffer) = self.cache.borrow().get(&path_buf) {
+            return Ok((rc_buffer.clone(), path_buf));
+        }
+        let (rc_buffer, path_buf) = self.inner.get_rc_buffer(req)?;
+        self.cache
+            .borrow_mut()
+            .insert(path_buf.clone(), rc_buffer.clone());
+        Ok((rc_buffer, path_buf))
+    }
+}
+
+/// Note: This impl returns `'static` payloads because borrowing is handled by [`Yoke`].
+///
+/// [`Yoke`]: icu_provider::yoke::Yoke
+impl<'data, M> DataProvider<'data, M> for ReloadingFsDataProvider
+where
+    M: DataMarker<'data>,
+    // Actual bound:
+    //     for<'de> <M::Yokeable as Yokeable<'de>>::Output: serde::de::Deserialize<'de>,
+    // Necessary workaround bound (see `yoke::trait_hack` docs):
+    for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::de::Deserialize<'de>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(
+                DataPayload::try_from_rc_buffer(
+                    rc_buffer,
+                    deserializer::deserialize_zero_copy::<M>(self.inner.syntax()),
+                )
+                .map_err(|e: deserializer::Error| e.into_resource_error(&path_buf))?,
+            ),
+        })
+    }
+}
+
+impl SerdeDeDataProvider for ReloadingFsDataProvider {
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, DataError> {
+        let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
+        deserializer::deserialize_into_receiver(rc_buffer, self.inner.syntax(), receiver)
+            .map_err(|err| err.into_resource_error(&path_buf))?;
+        Ok(DataResponseMetadata {
+            data_langid: req.resource_path.options.langid.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+impl IterableDataProviderCore for ReloadingFsDataProvider {
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        // The directory is listed on every call, so there is nothing to invalidate.
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
@@ -25,7 +25,7 @@ const EXPECTED_RU_DATA: PluralRuleStringsV1 = PluralRuleStringsV1 {
     )),
 };
 
-#[cfg(feature = "provider_bincode")]
+#[cfg(any(feature = "provider_bincode", feature = "provider_postcard"))]
 const EXPECTED_SR_DATA: PluralRuleStringsV1 = PluralRuleStringsV1 {
     zero: None,
     one: Some(Cow::Borrowed(
@@ -86,6 +86,76 @@ fn test_json_dyn_erased_serde() {
     assert_eq!(plurals_data.get(), &EXPECTED_RU_DATA);
 }
 
+#[cfg(feature = "provider_json")]
+#[test]
+fn test_json_boxed_dyn_erased_serde() {
+    use icu_provider::either::EitherProvider;
+
+    let provider: Box<dyn IterableSerdeDeDataProvider> = Box::new(
+        FsDataProvider::try_new("./tests/testdata/json")
+            .expect("Loading file from testdata directory"),
+    );
+    let provider: EitherProvider<_, FsDataProvider> = EitherProvider::A(provider);
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("ru")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_RU_DATA);
+}
+
+#[cfg(feature = "provider_json")]
+#[test]
+fn test_json_schema_version() {
+    // The manifests of these directories record the current schema version.
+    for root in &["./tests/testdata/json", "../testdata/data/json"] {
+        let manifest = std::fs::read_to_string(format!("{}/manifest.json", root))
+            .expect("The manifest should be readable");
+        assert!(manifest.contains("\"schema_version\""));
+        FsDataProvider::try_new(*root).expect("The schema version should match");
+    }
+}
+
+#[cfg(feature = "provider_json")]
+#[test]
+fn test_json_schema_mismatch() {
+    let err = FsDataProvider::try_new("./tests/testdata/schema_mismatch")
+        .expect_err("The data was built for a different schema version");
+    assert!(matches!(
+        err,
+        icu_provider_fs::FsDataError::DataProvider(DataError::MismatchedSchemaVersion { .. })
+    ));
+}
+
+#[cfg(feature = "provider_json")]
+#[test]
+fn test_json_supported_options() {
+    use icu_provider::iter::IterableDataProviderCore;
+
+    let provider = FsDataProvider::try_new("./tests/testdata/json")
+        .expect("Loading file from testdata directory");
+    let provider: &dyn IterableSerdeDeDataProvider = &provider;
+
+    let options: Vec<ResourceOptions> = provider
+        .supported_options_for_key(&key::CARDINAL_V1)
+        .expect("The key should be supported")
+        .collect();
+    assert_eq!(options, vec![ResourceOptions::from(langid!("ru"))]);
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("ru")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_RU_DATA);
+
+    assert!(matches!(
+        provider.supported_options_for_key(&key::ORDINAL_V1),
+        Err(DataError::MissingResourceKey(_))
+    ));
+}
+
 #[cfg(feature = "provider_json")]
 #[test]
 fn test_json_errors() {
@@ -187,3 +257,155 @@ fn test_bincode_dyn_erased_serde() {
         .expect("The data should be present");
     assert_eq!(plurals_data.get(), &EXPECTED_SR_DATA);
 }
+
+#[test]
+#[cfg(feature = "provider_postcard")]
+fn test_postcard() {
+    let provider = FsDataProvider::try_new("./tests/testdata/postcard")
+        .expect("Loading file from testdata directory");
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("sr")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_SR_DATA);
+}
+
+#[test]
+#[cfg(feature = "provider_postcard")]
+fn test_postcard_dyn_erased_serde() {
+    let provider = FsDataProvider::try_new("./tests/testdata/postcard")
+        .expect("Loading file from testdata directory");
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = (&provider
+        as &dyn SerdeDeDataProvider)
+        .load_payload(&get_request(langid!("sr")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_SR_DATA);
+}
+
+#[cfg(not(feature = "provider_postcard"))]
+#[test]
+fn test_postcard_feature() {
+    FsDataProvider::try_new("./tests/testdata/postcard").expect_err("Postcard is not enabled");
+}
+
+#[test]
+#[cfg(all(feature = "mmap", feature = "provider_bincode"))]
+fn test_bincode_mmap() {
+    let provider = FsDataProvider::try_new_mmap("./tests/testdata/bincode")
+        .expect("Loading file from testdata directory");
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("sr")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_SR_DATA);
+
+    assert!(matches!(
+        DataProvider::<PluralRuleStringsV1Marker>::load_payload(
+            &provider,
+            &get_request(langid!("zh"))
+        ),
+        Err(DataError::MissingResourceOptions(_))
+    ));
+}
+
+#[test]
+#[cfg(all(feature = "mmap", feature = "provider_json"))]
+fn test_json_mmap() {
+    let provider = FsDataProvider::try_new_mmap("./tests/testdata/json")
+        .expect("Loading file from testdata directory");
+
+    let plurals_data: DataPayload<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("ru")))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(plurals_data.get(), &EXPECTED_RU_DATA);
+}
+
+#[test]
+#[cfg(all(feature = "watch", feature = "provider_json"))]
+fn test_json_reloading() {
+    use icu_provider_fs::ReloadingFsDataProvider;
+    use std::fs;
+    use std::time::{Duration, Instant};
+
+    // Work on a copy of the test data, since the test modifies it.
+    let root = std::env::temp_dir().join(format!("icu4x_fs_reloading_{}", std::process::id()));
+    let data_dir = root.join("plurals").join("cardinal@1");
+    fs::create_dir_all(&data_dir).expect("Creating temporary directory");
+    fs::copy(
+        "./tests/testdata/json/manifest.json",
+        root.join("manifest.json"),
+    )
+    .expect("Copying manifest");
+    fs::copy(
+        "./tests/testdata/json/plurals/cardinal@1/ru.json",
+        data_dir.join("ru.json"),
+    )
+    .expect("Copying data file");
+
+    let provider = ReloadingFsDataProvider::try_new(&root).expect("Watching temporary directory");
+    let load = || -> DataPayload<PluralRuleStringsV1Marker> {
+        provider
+            .load_payload(&get_request(langid!("ru")))
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("The data should be present")
+    };
+
+    let old_data = load();
+    assert_eq!(old_data.get(), &EXPECTED_RU_DATA);
+
+    fs::write(data_dir.join("ru.json"), r#"{"one":"n = 1"}"#).expect("Updating data file");
+
+    // Wait for the watcher to report the change.
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let new_data = loop {
+        let data = load();
+        if data.get() != &EXPECTED_RU_DATA || Instant::now() > deadline {
+            break data;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    assert_eq!(new_data.get().one.as_deref(), Some("n = 1"));
+
+    // Payloads loaded before the change are not affected.
+    assert_eq!(old_data.get(), &EXPECTED_RU_DATA);
+
+    fs::remove_dir_all(&root).expect("Removing temporary directory");
+}
+
+#[cfg(feature = "provider_json")]
+#[test]
+fn test_json_shared() {
+    use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+    use icu_provider::shared::SharedDataProvider;
+
+    let provider = SharedDataProvider::new(
+        FsDataProvider::try_new("./tests/testdata/json")
+            .expect("Loading file from testdata directory"),
+    );
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let provider = provider.clone();
+            std::thread::spawn(move || {
+                let pr = PluralRules::try_new(langid!("ru"), &provider, PluralRuleType::Cardinal)
+                    .expect("The data should be valid");
+                assert_eq!(pr.select(3_usize), PluralCategory::Few);
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Thread should not panic");
+    }
+
+    assert_eq!(provider.cached_len(), 1);
+}
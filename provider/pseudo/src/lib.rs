@@ -0,0 +1,62 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! `icu_provider_pseudo` contains [`PseudoLocalizeProvider`], an implementation of the [`ICU4X`]
+//! [`DataProvider`] interface that pseudo-localizes the strings of another data provider.
+//!
+//! Pseudo-localization replaces the letters of strings with accented look-alikes, encloses them
+//! in brackets, and makes them longer, while keeping them readable. Formatting with
+//! pseudo-localized data shows strings that do not come from ICU4X data, strings that are
+//! truncated or concatenated, and layouts without room for longer translations, without adding
+//! fake locales to the data.
+//!
+//! The transformation applies to the string values of the data structs of the keys passed to
+//! [`PseudoLocalizeProvider::new`]. Only pass keys whose strings are text: strings that have a
+//! syntax, such as date patterns or number symbols, cannot be parsed after they are
+//! pseudo-localized. Placeholders in braces, such as `{0}`, are kept unchanged.
+//!
+//! To test pseudo-localization with a locale that has no data, such as `en-XA`, wrap the inner
+//! provider in a [`ForcedLocaleProvider`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_provider_pseudo::PseudoLocalizeProvider;
+//!
+//! let provider = PseudoLocalizeProvider::new(
+//!     HelloWorldProvider::new_with_placeholder_data(),
+//!     &[key::HELLO_WORLD_V1],
+//!     Default::default(),
+//! );
+//!
+//! let payload: DataPayload<HelloWorldV1Marker> = provider
+//!     .load_payload(&DataRequest {
+//!         resource_path: ResourcePath {
+//!             key: key::HELLO_WORLD_V1,
+//!             options: langid!("en").into(),
+//!         },
+//!     })
+//!     .expect("Loading should succeed")
+//!     .take_payload()
+//!     .expect("Data should be present");
+//!
+//! assert_eq!("[Ĥéļļö Ŵöŕļđ~~~~]", payload.get().message);
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [`DataProvider`]: icu_provider::prelude::DataProvider
+//! [`ForcedLocaleProvider`]: icu_provider::forced_locale::ForcedLocaleProvider
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod provider;
+mod transform;
+
+pub use provider::PseudoLocalizeProvider;
+pub use transform::{pseudo_localize, PseudoLocalizeOptions};
@@ -0,0 +1,94 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::{pseudo_localize, PseudoLocalizeOptions};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_provider::yoke::Yokeable;
+use serde_json::Value;
+
+/// A data provider that pseudo-localizes the strings in the data of some keys of an inner
+/// provider.
+///
+/// See the [crate-level documentation](crate) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudoLocalizeProvider<P> {
+    /// The data provider to which requests are delegated.
+    pub inner: P,
+
+    /// The keys whose strings are pseudo-localized. The data of other keys is returned unchanged.
+    pub keys: Vec<ResourceKey>,
+
+    /// The transformations applied to the strings.
+    pub options: PseudoLocalizeOptions,
+}
+
+impl<P> PseudoLocalizeProvider<P> {
+    /// Creates a [`PseudoLocalizeProvider`] that pseudo-localizes the strings of `keys`.
+    pub fn new(inner: P, keys: &[ResourceKey], options: PseudoLocalizeOptions) -> Self {
+        PseudoLocalizeProvider {
+            inner,
+            keys: keys.to_vec(),
+            options,
+        }
+    }
+}
+
+impl<'data, M, P> DataProvider<'data, M> for PseudoLocalizeProvider<P>
+where
+    M: DataMarker<'data>,
+    P: DataProvider<'data, M>,
+    M::Yokeable: serde::Deserialize<'static>,
+    for<'a> &'a <M::Yokeable as Yokeable<'a>>::Output: serde::Serialize,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, M>, DataError> {
+        let mut response = self.inner.load_payload(req)?;
+        if !self.keys.contains(&req.resource_path.key) {
+            return Ok(response);
+        }
+        if let Some(payload) = response.payload.take() {
+            // Round-trip the data struct through a JSON value, which owns its strings.
+            let mut value =
+                serde_json::to_value(payload.get()).map_err(DataError::new_resc_error)?;
+            transform_strings(&mut value, &self.options);
+            let data = <M::Yokeable as serde::Deserialize>::deserialize(value)
+                .map_err(DataError::new_resc_error)?;
+            response.payload = Some(DataPayload::from_owned(data));
+        }
+        Ok(response)
+    }
+}
+
+impl<P> IterableDataProviderCore for PseudoLocalizeProvider<P>
+where
+    P: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
+
+/// Pseudo-localizes the string values in a JSON value. Object keys are field names and are not
+/// changed.
+fn transform_strings(value: &mut Value, options: &PseudoLocalizeOptions) {
+    match value {
+        Value::String(s) => *s = pseudo_localize(s, options),
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                transform_strings(value, options);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                transform_strings(value, options);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => (),
+    }
+}
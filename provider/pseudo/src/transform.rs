@@ -0,0 +1,133 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+
+/// Accented look-alikes of `A` to `Z`: ÅƁÇĐÉƑĜĤÎĴĶĻṀÑÖÞǪŔŠŦÛṼŴẊÝŽ
+const ACCENTED_UPPER: [char; 26] = [
+    '\u{00C5}', '\u{0181}', '\u{00C7}', '\u{0110}', '\u{00C9}', '\u{0191}', '\u{011C}', '\u{0124}',
+    '\u{00CE}', '\u{0134}', '\u{0136}', '\u{013B}', '\u{1E40}', '\u{00D1}', '\u{00D6}', '\u{00DE}',
+    '\u{01EA}', '\u{0154}', '\u{0160}', '\u{0166}', '\u{00DB}', '\u{1E7C}', '\u{0174}', '\u{1E8A}',
+    '\u{00DD}', '\u{017D}',
+];
+
+/// Accented look-alikes of `a` to `z`: åƀçđéƒĝĥîĵķļṁñöþǫŕšŧûṽŵẋýž
+const ACCENTED_LOWER: [char; 26] = [
+    '\u{00E5}', '\u{0180}', '\u{00E7}', '\u{0111}', '\u{00E9}', '\u{0192}', '\u{011D}', '\u{0125}',
+    '\u{00EE}', '\u{0135}', '\u{0137}', '\u{013C}', '\u{1E41}', '\u{00F1}', '\u{00F6}', '\u{00FE}',
+    '\u{01EB}', '\u{0155}', '\u{0161}', '\u{0167}', '\u{00FB}', '\u{1E7D}', '\u{0175}', '\u{1E8B}',
+    '\u{00FD}', '\u{017E}',
+];
+
+/// The character appended to elongate strings.
+const ELONGATION_CHAR: char = '~';
+
+/// The transformations applied by [`pseudo_localize`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PseudoLocalizeOptions {
+    /// Whether to replace the ASCII letters with accented look-alikes, which shows text that
+    /// did not go through localization and checks that non-ASCII text renders.
+    pub accents: bool,
+
+    /// Whether to enclose strings in square brackets, which shows text that is truncated or
+    /// concatenated from several strings.
+    pub brackets: bool,
+
+    /// How much longer to make strings, in percent of their length. Translations are often
+    /// longer than English, so this shows layouts without room for them.
+    pub elongation_percent: u8,
+}
+
+impl Default for PseudoLocalizeOptions {
+    /// Enables all transformations, elongating strings by 30%.
+    fn default() -> Self {
+        Self {
+            accents: true,
+            brackets: true,
+            elongation_percent: 30,
+        }
+    }
+}
+
+/// Pseudo-localizes a string.
+///
+/// Placeholders in braces, such as `{0}`, are kept unchanged and do not count towards the
+/// length of the string.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider_pseudo::{pseudo_localize, PseudoLocalizeOptions};
+///
+/// let options = PseudoLocalizeOptions::default();
+/// assert_eq!(pseudo_localize("Hello {0}", &options), "[Ĥéļļö {0}~~]");
+/// ```
+pub fn pseudo_localize(input: &str, options: &PseudoLocalizeOptions) -> String {
+    let mut result = String::with_capacity(input.len() * 2);
+    if options.brackets {
+        result.push('[');
+    }
+    let mut len = 0;
+    let mut in_placeholder = false;
+    for c in input.chars() {
+        match c {
+            '{' => in_placeholder = true,
+            '}' if in_placeholder => {
+                in_placeholder = false;
+                result.push(c);
+                continue;
+            }
+            _ => (),
+        }
+        if in_placeholder {
+            result.push(c);
+            continue;
+        }
+        len += 1;
+        result.push(match c {
+            'A'..='Z' if options.accents => ACCENTED_UPPER[c as usize - 'A' as usize],
+            'a'..='z' if options.accents => ACCENTED_LOWER[c as usize - 'a' as usize],
+            _ => c,
+        });
+    }
+    let elongation = (len * options.elongation_percent as usize + 99) / 100;
+    result.extend(core::iter::repeat(ELONGATION_CHAR).take(elongation));
+    if options.brackets {
+        result.push(']');
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        let none = PseudoLocalizeOptions {
+            accents: false,
+            brackets: false,
+            elongation_percent: 0,
+        };
+        assert_eq!(pseudo_localize("Hello {0}", &none), "Hello {0}");
+
+        let accents = PseudoLocalizeOptions {
+            accents: true,
+            ..none
+        };
+        assert_eq!(pseudo_localize("AZaz09", &accents), "ÅŽåž09");
+
+        let elongation = PseudoLocalizeOptions {
+            elongation_percent: 100,
+            ..none
+        };
+        assert_eq!(pseudo_localize("ab{0}", &elongation), "ab{0}~~");
+    }
+
+    #[test]
+    fn test_unclosed_placeholder() {
+        let options = PseudoLocalizeOptions::default();
+        assert_eq!(pseudo_localize("{a", &options), "[{a]");
+    }
+}
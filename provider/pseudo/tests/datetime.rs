@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::DateTime;
+use icu_datetime::options::length;
+use icu_datetime::provider::key::GREGORY_DATE_SYMBOLS_V1;
+use icu_datetime::DateTimeFormat;
+use icu_locid_macros::langid;
+use icu_provider_pseudo::PseudoLocalizeProvider;
+use writeable::Writeable;
+
+#[test]
+fn test_pseudo_localized_date_symbols() {
+    let provider = PseudoLocalizeProvider::new(
+        icu_testdata::get_provider(),
+        &[GREGORY_DATE_SYMBOLS_V1],
+        Default::default(),
+    );
+    let options = length::Bag {
+        date: Some(length::Date::Full),
+        time: None,
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+        .expect("Patterns should not be pseudo-localized");
+
+    let datetime = DateTime::new_gregorian_datetime_from_integers(2021, 7, 11, 12, 0, 0)
+        .expect("Failed to construct DateTime");
+    assert_eq!(
+        "[Šûñđåý~~], [Ĵûļý~~] 11, 2021",
+        dtf.format(&datetime).write_to_string()
+    );
+}
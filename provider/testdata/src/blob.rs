@@ -14,6 +14,9 @@ const STATIC_STR_DATA: &[u8] = include_bytes!(concat!(
     "/data/testdata.postcard"
 ));
 
+const STATIC_UPROPS_DATA: &[u8] =
+    include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/uprops.postcard"));
+
 /// Get a `DataProvider`, loading from the statically initialized bincode blob.
 /// Panics if unable to load the data.
 pub fn get_static_provider() -> StaticDataProvider {
@@ -28,3 +31,11 @@ pub fn get_smaller_static_provider() -> StaticDataProvider {
     StaticDataProvider::new_from_static_blob(SMALLER_STATIC_STR_DATA)
         .expect("Deserialization should succeed")
 }
+
+/// Get a `DataProvider`, loading from the statically initialized postcard blob
+/// containing the Unicode property sets and maps built from `data/uprops`.
+/// Panics if unable to load the data.
+pub fn get_static_properties_provider() -> StaticDataProvider {
+    StaticDataProvider::new_from_static_blob(STATIC_UPROPS_DATA)
+        .expect("Deserialization should succeed")
+}
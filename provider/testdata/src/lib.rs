@@ -13,6 +13,8 @@
 //!
 //! - `json` for the ICU4X JSON test data
 //! - `cldr` for the source CLDR JSON
+//! - `uprops` for the source Unicode property TOML files, which are also built into
+//!   `uprops.postcard` for `get_static_properties_provider()` with the `static` feature
 //!
 //! ## Pointing to custom test data
 //!
@@ -79,6 +81,6 @@ mod blob;
 mod fs;
 
 #[cfg(feature = "static")]
-pub use blob::{get_smaller_static_provider, get_static_provider};
+pub use blob::{get_smaller_static_provider, get_static_properties_provider, get_static_provider};
 #[cfg(feature = "fs")]
 pub use fs::get_provider;
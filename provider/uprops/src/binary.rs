@@ -6,11 +6,13 @@ use crate::error::Error;
 use crate::uprops_serde;
 use icu_properties::provider::UnicodePropertyV1;
 use icu_properties::provider::UnicodePropertyV1Marker;
+use icu_properties::provider::{UnicodePropertyStringsV1, UnicodePropertyStringsV1Marker};
 use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_uniset::UnicodeSetBuilder;
 use std::fs;
 use std::path::PathBuf;
+use zerovec::VarZeroVec;
 
 pub struct BinaryPropertiesDataProvider {
     root_dir: PathBuf,
@@ -38,8 +40,14 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for BinaryPropertiesDat
             .get_toml_data(&req.resource_path.key.sub_category)
             .map_err(DataError::new_resc_error)?;
 
+        let property = toml_data
+            .binary_property
+            .into_iter()
+            .next()
+            .ok_or(DataError::MissingResourceKey(req.resource_path.key))?;
+
         let mut builder = UnicodeSetBuilder::new();
-        for (start, end) in toml_data.binary_property.data.ranges {
+        for (start, end) in property.ranges {
             builder.add_range_u32(&(start..=end));
         }
         let uniset = builder.build();
@@ -47,6 +55,7 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for BinaryPropertiesDat
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(
                 UnicodePropertyV1::from_owned_uniset(uniset),
@@ -55,6 +64,49 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for BinaryPropertiesDat
     }
 }
 
+impl<'data> DataProvider<'data, UnicodePropertyStringsV1Marker> for BinaryPropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyStringsV1Marker>, DataError> {
+        let toml_data: uprops_serde::binary::Main = self
+            .get_toml_data(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        let property = toml_data
+            .binary_property
+            .into_iter()
+            .next()
+            .ok_or(DataError::MissingResourceKey(req.resource_path.key))?;
+
+        let mut builder = UnicodeSetBuilder::new();
+        for (start, end) in property.ranges {
+            builder.add_range_u32(&(start..=end));
+        }
+        let mut strings: Vec<&str> = vec![];
+        for s in &property.strings {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => builder.add_char(c),
+                _ => strings.push(s),
+            }
+        }
+        strings.sort_unstable();
+        strings.dedup();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(UnicodePropertyStringsV1 {
+                inv_list: builder.build(),
+                strings: VarZeroVec::from(strings.as_slice()),
+            })),
+        })
+    }
+}
+
 icu_provider::impl_dyn_provider!(BinaryPropertiesDataProvider, {
     _ => UnicodePropertyV1Marker,
 }, SERDE_SE, 'data);
@@ -97,3 +149,31 @@ fn test_basic() {
 
     assert!(!whitespace.contains('A'));
 }
+
+#[test]
+fn test_strings() {
+    use icu_properties::provider::key;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = BinaryPropertiesDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, UnicodePropertyStringsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::BASIC_EMOJI_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    let basic_emoji = payload.get();
+
+    assert!(basic_emoji.contains("\u{1F600}")); // U+1F600 GRINNING FACE
+    assert!(basic_emoji.contains("\u{2764}\u{FE0F}")); // U+2764 HEAVY BLACK HEART
+    assert!(!basic_emoji.contains("\u{2764}"));
+    assert!(!basic_emoji.contains("\u{1F1E8}")); // U+1F1E8 REGIONAL INDICATOR SYMBOL LETTER C
+    assert!(!basic_emoji.contains("#\u{FE0F}"));
+    assert!(!basic_emoji.contains(""));
+}
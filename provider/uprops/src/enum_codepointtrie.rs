@@ -0,0 +1,192 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::uprops_serde;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType, TrieValue};
+use icu_codepointtrie::provider::{UnicodePropertyMapV1, UnicodePropertyMapV1Marker};
+use icu_properties::{GeneralSubcategory, Script};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::ZeroVec;
+
+/// Conversion from the raw property values stored in the TOML files.
+trait TryFromU32: Sized {
+    fn try_from_u32(value: u32) -> Result<Self, Error>;
+}
+
+impl TryFromU32 for GeneralSubcategory {
+    fn try_from_u32(value: u32) -> Result<Self, Error> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| GeneralSubcategory::try_from(value).ok())
+            .ok_or(Error::InvalidValue(value))
+    }
+}
+
+impl TryFromU32 for Script {
+    fn try_from_u32(value: u32) -> Result<Self, Error> {
+        u16::try_from(value)
+            .map(Script::from)
+            .map_err(|_| Error::InvalidValue(value))
+    }
+}
+
+pub struct EnumeratedPropertyCodePointTrieProvider {
+    root_dir: PathBuf,
+}
+
+/// A data provider reading code point tries from .toml files produced by the ICU4C
+/// icuwriteuprops tool.
+impl EnumeratedPropertyCodePointTrieProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        EnumeratedPropertyCodePointTrieProvider { root_dir }
+    }
+
+    fn get_toml_data(&self, name: &str) -> Result<uprops_serde::enumerated::Main, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_code_point_trie<T: TrieValue + TryFromU32>(
+        &self,
+        name: &str,
+    ) -> Result<CodePointTrie<'static, T>, Error> {
+        let serialized = self
+            .get_toml_data(name)?
+            .enum_property
+            .into_iter()
+            .next()
+            .and_then(|property| property.code_point_trie)
+            .ok_or_else(|| Error::MissingCodePointTrie(name.to_string()))?;
+
+        let header = CodePointTrieHeader {
+            high_start: serialized.high_start,
+            shifted12_high_start: serialized.shifted12_high_start,
+            index3_null_offset: serialized.index3_null_offset,
+            data_null_offset: serialized.data_null_offset,
+            null_value: serialized.null_value,
+            trie_type: TrieType::try_from(serialized.trie_type_enum_val)?,
+        };
+
+        let raw_data: Vec<u32> = if let Some(data_8) = serialized.data_8 {
+            data_8.into_iter().map(u32::from).collect()
+        } else if let Some(data_16) = serialized.data_16 {
+            data_16.into_iter().map(u32::from).collect()
+        } else if let Some(data_32) = serialized.data_32 {
+            data_32
+        } else {
+            return Err(Error::MissingCodePointTrie(name.to_string()));
+        };
+        let data = raw_data
+            .into_iter()
+            .map(T::try_from_u32)
+            .collect::<Result<Vec<T>, Error>>()?;
+
+        Ok(CodePointTrie::try_new(
+            header,
+            ZeroVec::clone_from_slice(&serialized.index),
+            ZeroVec::clone_from_slice(&data),
+        )?)
+    }
+
+    fn load_code_point_map<'data, T: TrieValue + TryFromU32>(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker<T>>, DataError> {
+        let codepoint_trie = self
+            .get_code_point_trie(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(UnicodePropertyMapV1 {
+                codepoint_trie,
+            })),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker<GeneralSubcategory>>
+    for EnumeratedPropertyCodePointTrieProvider
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker<GeneralSubcategory>>, DataError>
+    {
+        self.load_code_point_map(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker<Script>>
+    for EnumeratedPropertyCodePointTrieProvider
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker<Script>>, DataError> {
+        self.load_code_point_map(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_properties::provider::key;
+
+    #[test]
+    fn test_general_category() {
+        let root_dir = icu_testdata::paths::data_root().join("uprops");
+        let provider = EnumeratedPropertyCodePointTrieProvider::new(root_dir);
+
+        let payload: DataPayload<'_, UnicodePropertyMapV1Marker<GeneralSubcategory>> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::GENERAL_CATEGORY_V1,
+                    options: ResourceOptions::default(),
+                },
+            })
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("Loading was successful");
+
+        let trie = &payload.get().codepoint_trie;
+        assert_eq!(trie.get('꣓' as u32), GeneralSubcategory::Digit); // U+A8D3 SAURASHTRA DIGIT THREE
+        assert_eq!(trie.get('≈' as u32), GeneralSubcategory::MathSymbol); // U+2248 ALMOST EQUAL TO
+        assert_eq!(trie.get('A' as u32), GeneralSubcategory::UppercaseLetter);
+        assert_eq!(trie.get(0xe0001), GeneralSubcategory::Format); // U+E0001 LANGUAGE TAG
+        assert_eq!(trie.get(0x10ffff), GeneralSubcategory::Unassigned);
+    }
+
+    #[test]
+    fn test_script() {
+        let root_dir = icu_testdata::paths::data_root().join("uprops");
+        let provider = EnumeratedPropertyCodePointTrieProvider::new(root_dir);
+
+        let payload: DataPayload<'_, UnicodePropertyMapV1Marker<Script>> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::SCRIPT_V1,
+                    options: ResourceOptions::default(),
+                },
+            })
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("Loading was successful");
+
+        let trie = &payload.get().codepoint_trie;
+        assert_eq!(trie.get('A' as u32), Script::Latin);
+        assert_eq!(trie.get('\u{0e01}' as u32), Script::Thai); // U+0E01 THAI CHARACTER KO KAI
+        assert_eq!(trie.get('١' as u32), Script::Arabic); // U+0661 ARABIC-INDIC DIGIT ONE
+        assert_eq!(trie.get(' ' as u32), Script::Common);
+    }
+}
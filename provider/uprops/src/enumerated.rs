@@ -9,6 +9,7 @@ use icu_properties::provider::UnicodePropertyV1Marker;
 use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 use icu_uniset::UnicodeSetBuilder;
+use serde::de::DeserializeOwned;
 use std::fs;
 use std::path::PathBuf;
 
@@ -21,7 +22,7 @@ impl EnumeratedPropertiesDataProvider {
     pub fn new(root_dir: PathBuf) -> Self {
         EnumeratedPropertiesDataProvider { root_dir }
     }
-    fn get_toml_data(&self, name: &str) -> Result<uprops_serde::enumerated::Main, Error> {
+    fn get_toml_data<T: DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
         let mut path: PathBuf = self.root_dir.clone().join(name);
         path.set_extension("toml");
         let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
@@ -79,17 +80,42 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for EnumeratedPropertie
             (parts[0], parts[1])
         };
 
-        let toml_data: uprops_serde::enumerated::Main = self
-            .get_toml_data(prop_name)
-            .map_err(DataError::new_resc_error)?;
-
-        let valid_names = expand_groupings(prop_name, prop_value);
-
         let mut builder = UnicodeSetBuilder::new();
-        let ranges = toml_data.enum_property.data.ranges;
-        for range in ranges {
-            if valid_names.iter().any(|&name| name == range.name) {
-                builder.add_range_u32(&(range.a..=range.b));
+        if prop_name == "scx" {
+            // Script_Extensions maps each code point to a list of scripts, and the set of a
+            // script contains the code points whose list contains it.
+            let toml_data: uprops_serde::script_extensions::Main = self
+                .get_toml_data(prop_name)
+                .map_err(DataError::new_resc_error)?;
+
+            let property = toml_data
+                .script_extensions_property
+                .into_iter()
+                .next()
+                .ok_or(DataError::MissingResourceKey(req.resource_path.key))?;
+
+            for range in property.ranges {
+                if range.names.iter().any(|name| name == prop_value) {
+                    builder.add_range_u32(&(range.a..=range.b));
+                }
+            }
+        } else {
+            let toml_data: uprops_serde::enumerated::Main = self
+                .get_toml_data(prop_name)
+                .map_err(DataError::new_resc_error)?;
+
+            let valid_names = expand_groupings(prop_name, prop_value);
+
+            let property = toml_data
+                .enum_property
+                .into_iter()
+                .next()
+                .ok_or(DataError::MissingResourceKey(req.resource_path.key))?;
+
+            for range in property.ranges {
+                if valid_names.iter().any(|&name| name == range.name) {
+                    builder.add_range_u32(&(range.a..=range.b));
+                }
             }
         }
         let uniset = builder.build();
@@ -97,6 +123,7 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for EnumeratedPropertie
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
             },
             payload: Some(DataPayload::from_owned(
                 UnicodePropertyV1::from_owned_uniset(uniset),
@@ -180,6 +207,35 @@ mod tests {
         assert!(!thai.contains('\u{0e3f}')); // U+0E50 THAI CURRENCY SYMBOL BAHT
     }
 
+    #[test]
+    fn test_script_extensions() {
+        use icu_uniset::UnicodeSet;
+        use std::convert::TryInto;
+
+        let root_dir = icu_testdata::paths::data_root().join("uprops");
+        let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+        let payload: DataPayload<'_, UnicodePropertyV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::SCRIPT_EXTENSIONS_ARABIC_V1,
+                    options: ResourceOptions::default(),
+                },
+            })
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("Loading was successful");
+
+        let arabic: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+
+        assert!(arabic.contains('\u{0627}')); // U+0627 ARABIC LETTER ALEF
+        assert!(arabic.contains('\u{060c}')); // U+060C ARABIC COMMA
+        assert!(arabic.contains('\u{0640}')); // U+0640 ARABIC TATWEEL
+
+        assert!(!arabic.contains('A'));
+        assert!(!arabic.contains('\u{0710}')); // U+0710 SYRIAC LETTER ALAPH
+    }
+
     #[test]
     fn test_gc_groupings() {
         use icu_uniset::{UnicodeSet, UnicodeSetBuilder};
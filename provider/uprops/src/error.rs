@@ -12,6 +12,20 @@ pub enum Error {
     Io(std::io::Error, PathBuf),
     #[displaydoc("{0}: {1:?}")]
     Toml(toml::de::Error, PathBuf),
+    #[displaydoc("Missing code point trie for property: {0}")]
+    MissingCodePointTrie(String),
+    #[displaydoc("Missing value names for property: {0}")]
+    MissingPropertyNames(String),
+    #[displaydoc("Invalid property value: {0}")]
+    InvalidValue(u32),
+    #[displaydoc("{0}")]
+    CodePointTrie(icu_codepointtrie::error::Error),
 }
 
 impl std::error::Error for Error {}
+
+impl From<icu_codepointtrie::error::Error> for Error {
+    fn from(e: icu_codepointtrie::error::Error) -> Self {
+        Error::CodePointTrie(e)
+    }
+}
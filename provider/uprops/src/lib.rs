@@ -8,6 +8,12 @@
 //! the necessary Unicode properties and then pass the path into the
 //! [`PropertiesDataProvider`].
 //!
+//! Binary properties and the values of enumerated properties are served as sets of code points.
+//! Enumerated properties are also served as maps from code points to property values, backed by
+//! the code point tries in the TOML files.
+//!
+//! To generate ICU4X data for all properties, use the `--uprops-root` option of `icu4x-datagen`.
+//!
 //! **Important:** This data provider implementation is not optimized
 //! for production use.  It is much more efficient if you use
 //! [`FsDataProvider`] or [`StaticDataProvider`] instead.
@@ -18,9 +24,12 @@
 //! [`StaticDataProvider`]: ../icu_provider_blob/struct.StaticDataProvider.html
 
 mod binary;
+mod enum_codepointtrie;
 mod enumerated;
 mod error;
+mod names;
 mod provider;
 mod uprops_serde;
 
+pub use provider::get_all_uprops_keys;
 pub use provider::PropertiesDataProvider;
@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::uprops_serde;
+use icu_properties::provider::{PropertyValueNamesV1, PropertyValueNamesV1Marker};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::{VarZeroVec, ZeroVec};
+
+pub struct PropertyValueNamesDataProvider {
+    root_dir: PathBuf,
+}
+
+/// A data provider reading the names of enumerated property values from .toml files.
+impl PropertyValueNamesDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        PropertyValueNamesDataProvider { root_dir }
+    }
+
+    fn get_toml_data(&self, name: &str) -> Result<uprops_serde::names::Main, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_names(&self, name: &str) -> Result<PropertyValueNamesV1<'static>, Error> {
+        let property = self
+            .get_toml_data(name)?
+            .enum_property_names
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::MissingPropertyNames(name.to_string()))?;
+
+        let mut names: Vec<(String, u16)> = vec![];
+        let mut short_names: Vec<&str> = vec![];
+        let mut long_names: Vec<&str> = vec![];
+        for value in &property.values {
+            let v = u16::try_from(value.v).map_err(|_| Error::InvalidValue(value.v))?;
+            let index = usize::from(v);
+            if short_names.len() <= index {
+                short_names.resize(index + 1, "");
+                long_names.resize(index + 1, "");
+            }
+            short_names[index] = &value.short;
+            long_names[index] = &value.long;
+            for name in [&value.short, &value.long]
+                .iter()
+                .copied()
+                .chain(&value.aliases)
+            {
+                names.push((PropertyValueNamesV1::normalize_name(name), v));
+            }
+        }
+        // Names that differ only in case or separators, such as the short and long names of
+        // Ahom, are the same after normalization.
+        names.sort_unstable();
+        names.dedup();
+
+        let normalized_names: Vec<&str> = names.iter().map(|(name, _)| name.as_str()).collect();
+        let normalized_name_values: Vec<u16> = names.iter().map(|(_, v)| *v).collect();
+        Ok(PropertyValueNamesV1 {
+            normalized_names: VarZeroVec::from(normalized_names.as_slice()),
+            normalized_name_values: ZeroVec::clone_from_slice(&normalized_name_values),
+            short_names: VarZeroVec::from(short_names.as_slice()),
+            long_names: VarZeroVec::from(long_names.as_slice()),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, PropertyValueNamesV1Marker> for PropertyValueNamesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PropertyValueNamesV1Marker>, DataError> {
+        let names = self
+            .get_names(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+                ..Default::default()
+            },
+            payload: Some(DataPayload::from_owned(names)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_properties::provider::key;
+
+    #[test]
+    fn test_script_names() {
+        let root_dir = icu_testdata::paths::data_root().join("uprops");
+        let provider = PropertyValueNamesDataProvider::new(root_dir);
+
+        let payload: DataPayload<'_, PropertyValueNamesV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::SCRIPT_NAMES_V1,
+                    options: ResourceOptions::default(),
+                },
+            })
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("Loading was successful");
+
+        let names = payload.get();
+        assert_eq!(names.get_value("oldhungarian"), Some(76));
+        assert_eq!(names.get_value("hung"), Some(76));
+        assert_eq!(names.get_value("qaai"), Some(1));
+        assert_eq!(names.get_value("ahom"), Some(161));
+        assert_eq!(names.get_value("hungarian"), None);
+        assert_eq!(names.short_names.get(76), Some("Hung"));
+        assert_eq!(names.long_names.get(76), Some("Old_Hungarian"));
+        assert_eq!(names.long_names.get(0), Some("Common"));
+    }
+}
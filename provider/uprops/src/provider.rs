@@ -3,23 +3,66 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::binary::BinaryPropertiesDataProvider;
+use crate::enum_codepointtrie::EnumeratedPropertyCodePointTrieProvider;
 use crate::enumerated::EnumeratedPropertiesDataProvider;
-use icu_properties::provider::UnicodePropertyV1Marker;
+use crate::names::PropertyValueNamesDataProvider;
+use icu_codepointtrie::provider::UnicodePropertyMapV1Marker;
+use icu_properties::provider::{
+    key, PropertyValueNamesV1Marker, UnicodePropertyStringsV1Marker, UnicodePropertyV1Marker,
+};
+use icu_properties::{GeneralSubcategory, Script};
 use icu_provider::iter::IterableDataProviderCore;
 use icu_provider::prelude::*;
 
 use std::path::PathBuf;
 
+/// Returns a list of all [`ResourceKeys`](ResourceKey) that [`PropertiesDataProvider`] can
+/// produce, given the TOML files for all properties.
+pub fn get_all_uprops_keys() -> Vec<ResourceKey> {
+    let mut result: Vec<ResourceKey> = vec![];
+    result.extend(&key::ALL_KEYS);
+    result.extend(&key::ALL_MAP_KEYS);
+    result.extend(&key::ALL_NAMES_KEYS);
+    result.extend(&key::ALL_STRINGS_KEYS);
+    result
+}
+
 pub struct PropertiesDataProvider {
+    root_dir: PathBuf,
     binary: BinaryPropertiesDataProvider,
     enumerated: EnumeratedPropertiesDataProvider,
+    code_point_tries: EnumeratedPropertyCodePointTrieProvider,
+    names: PropertyValueNamesDataProvider,
 }
 
 impl PropertiesDataProvider {
     pub fn new(root_dir: PathBuf) -> Self {
         let binary = BinaryPropertiesDataProvider::new(root_dir.clone());
-        let enumerated = EnumeratedPropertiesDataProvider::new(root_dir);
-        Self { binary, enumerated }
+        let enumerated = EnumeratedPropertiesDataProvider::new(root_dir.clone());
+        let code_point_tries = EnumeratedPropertyCodePointTrieProvider::new(root_dir.clone());
+        let names = PropertyValueNamesDataProvider::new(root_dir.clone());
+        Self {
+            root_dir,
+            binary,
+            enumerated,
+            code_point_tries,
+            names,
+        }
+    }
+
+    /// Returns the path of the TOML file containing the data for the given key.
+    ///
+    /// Enumerated property keys are of the form "name=value", and their data is in the file
+    /// for "name".
+    fn get_toml_path(&self, resc_key: &ResourceKey) -> PathBuf {
+        let name = resc_key
+            .sub_category
+            .split('=')
+            .next()
+            .expect("split always returns at least one element");
+        let mut path = self.root_dir.join(name);
+        path.set_extension("toml");
+        path
     }
 }
 
@@ -36,16 +79,103 @@ impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for PropertiesDataProvi
     }
 }
 
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker<GeneralSubcategory>>
+    for PropertiesDataProvider
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker<GeneralSubcategory>>, DataError>
+    {
+        self.code_point_tries.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker<Script>> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker<Script>>, DataError> {
+        self.code_point_tries.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyStringsV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyStringsV1Marker>, DataError> {
+        self.binary.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, PropertyValueNamesV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PropertyValueNamesV1Marker>, DataError> {
+        self.names.load_payload(req)
+    }
+}
+
 icu_provider::impl_dyn_provider!(PropertiesDataProvider, {
+    key::GENERAL_CATEGORY_V1 => UnicodePropertyMapV1Marker<GeneralSubcategory>,
+    key::SCRIPT_V1 => UnicodePropertyMapV1Marker<Script>,
+    key::SCRIPT_NAMES_V1 => PropertyValueNamesV1Marker,
+    key::BASIC_EMOJI_V1 => UnicodePropertyStringsV1Marker,
+    key::EMOJI_KEYCAP_SEQUENCE_V1 => UnicodePropertyStringsV1Marker,
+    key::RGI_EMOJI_MODIFIER_SEQUENCE_V1 => UnicodePropertyStringsV1Marker,
+    key::RGI_EMOJI_FLAG_SEQUENCE_V1 => UnicodePropertyStringsV1Marker,
+    key::RGI_EMOJI_ZWJ_SEQUENCE_V1 => UnicodePropertyStringsV1Marker,
     _ => UnicodePropertyV1Marker,
 }, SERDE_SE, 'data);
 
 impl IterableDataProviderCore for PropertiesDataProvider {
     fn supported_options_for_key(
         &self,
-        _resc_key: &ResourceKey,
+        resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        // Keys are only supported if the directory contains their data.
+        if resc_key.category != ResourceCategory::UnicodeSet
+            || !self.get_toml_path(resc_key).exists()
+        {
+            return Err(DataError::MissingResourceKey(*resc_key));
+        }
         let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
         Ok(Box::new(list.into_iter()))
     }
 }
+
+#[test]
+fn test_supported_keys() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = PropertiesDataProvider::new(root_dir);
+
+    assert!(provider
+        .supported_options_for_key(&key::WHITE_SPACE_V1)
+        .is_ok());
+    assert!(provider
+        .supported_options_for_key(&key::GENERAL_CATEGORY_NUMBER_V1)
+        .is_ok());
+    assert!(provider
+        .supported_options_for_key(&key::GENERAL_CATEGORY_V1)
+        .is_ok());
+    assert!(provider
+        .supported_options_for_key(&key::SCRIPT_NAMES_V1)
+        .is_ok());
+
+    assert!(provider
+        .supported_options_for_key(&key::BASIC_EMOJI_V1)
+        .is_ok());
+
+    // The test data does not contain the Hyphen property.
+    assert!(matches!(
+        provider.supported_options_for_key(&key::HYPHEN_V1),
+        Err(DataError::MissingResourceKey(_))
+    ));
+    // The test data does not contain the RGI_Emoji_ZWJ_Sequence property.
+    assert!(matches!(
+        provider.supported_options_for_key(&key::RGI_EMOJI_ZWJ_SEQUENCE_V1),
+        Err(DataError::MissingResourceKey(_))
+    ));
+}
@@ -8,16 +8,14 @@ pub mod binary {
         pub long_name: String,
         pub short_name: String,
         pub ranges: Vec<(u32, u32)>,
-    }
-
-    #[derive(serde::Deserialize)]
-    pub struct Level1 {
-        pub data: BinaryProperty,
+        #[serde(default)]
+        pub strings: Vec<String>,
     }
 
     #[derive(serde::Deserialize)]
     pub struct Main {
-        pub binary_property: Level1,
+        #[serde(default)]
+        pub binary_property: Vec<BinaryProperty>,
     }
 }
 
@@ -35,16 +33,82 @@ pub mod enumerated {
         pub long_name: String,
         pub short_name: String,
         pub ranges: Vec<EnumeratedPropertyMapRange>,
+        pub code_point_trie: Option<super::code_point_trie::SerializedCodePointTrie>,
     }
 
     #[derive(serde::Deserialize)]
-    pub struct Level1 {
-        pub data: EnumeratedPropertyMap,
+    pub struct Main {
+        #[serde(default)]
+        pub enum_property: Vec<EnumeratedPropertyMap>,
+    }
+}
+
+pub mod script_extensions {
+    #[derive(serde::Deserialize)]
+    pub struct ScriptExtensionsRange {
+        pub a: u32,
+        pub b: u32,
+        pub names: Vec<String>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct ScriptExtensionsProperty {
+        pub long_name: String,
+        pub short_name: String,
+        pub ranges: Vec<ScriptExtensionsRange>,
     }
 
     #[derive(serde::Deserialize)]
     pub struct Main {
-        pub enum_property: Level1,
-        // omitted: enum_property.code_point_trie
+        #[serde(default)]
+        pub script_extensions_property: Vec<ScriptExtensionsProperty>,
+    }
+}
+
+pub mod names {
+    #[derive(serde::Deserialize)]
+    pub struct PropertyValueNames {
+        pub v: u32,
+        pub short: String,
+        pub long: String,
+        #[serde(default)]
+        pub aliases: Vec<String>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct EnumPropertyNames {
+        pub long_name: String,
+        pub short_name: String,
+        pub values: Vec<PropertyValueNames>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        #[serde(default)]
+        pub enum_property_names: Vec<EnumPropertyNames>,
+    }
+}
+
+pub mod code_point_trie {
+    /// A [`CodePointTrie`](icu_codepointtrie::codepointtrie::CodePointTrie) as serialized by
+    /// icuwriteuprops. Exactly one of the data arrays is present, depending on the value width.
+    #[derive(serde::Deserialize)]
+    pub struct SerializedCodePointTrie {
+        pub index: Vec<u16>,
+        pub data_8: Option<Vec<u8>>,
+        pub data_16: Option<Vec<u16>>,
+        pub data_32: Option<Vec<u32>>,
+        #[serde(rename = "highStart")]
+        pub high_start: u32,
+        #[serde(rename = "shifted12HighStart")]
+        pub shifted12_high_start: u16,
+        #[serde(rename = "type")]
+        pub trie_type_enum_val: u8,
+        #[serde(rename = "index3NullOffset")]
+        pub index3_null_offset: u16,
+        #[serde(rename = "dataNullOffset")]
+        pub data_null_offset: u32,
+        #[serde(rename = "nullValue")]
+        pub null_value: u32,
     }
 }
@@ -0,0 +1,241 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Generates the property TOML files in provider/testdata/data/uprops that are not
+# exported by the ICU4C icuwriteuprops tool:
+#
+# - scx.toml for the Script_Extensions property, in the same style as sc.toml,
+#   with the list of scripts of each range
+# - sc_names.toml for the names of the values of the Script property
+# - Basic_Emoji.toml and EKeycap_Seq.toml for the Basic_Emoji and
+#   Emoji_Keycap_Sequence properties of UTS #51, with their sequences as `strings`
+#
+# Usage: generate_data.py <unicore directory>
+#
+# The properties are read from the unicore directory of a Perl installation,
+# e.g. /usr/share/perl/5.36.0/unicore.
+#
+# The Unicode version of the unicore directory must match the version of the
+# other property TOML files.
+#
+# The emoji sequence properties are defined by emoji-sequences.txt, which is
+# not part of unicore. Basic_Emoji and Emoji_Keycap_Sequence are instead
+# derived from the Emoji, EPres, and EComp TOML files, following the
+# definitions in UTS #51:
+#
+# - Basic_Emoji: the EPres characters other than regional indicators, and the
+#   other Emoji characters that are not EComp, followed by U+FE0F
+# - Emoji_Keycap_Sequence: [#*0-9] followed by U+FE0F U+20E3
+#
+# The RGI_* sequence properties cannot be derived from character properties,
+# and are not generated.
+
+import os
+import re
+import sys
+import tomllib
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..")
+OUT_DIR = os.path.join(ROOT_DIR, "provider", "testdata", "data", "uprops")
+KEYS_RS = os.path.join(ROOT_DIR, "components", "properties", "src", "provider.rs")
+SCRIPTS_RS = os.path.join(ROOT_DIR, "components", "properties", "src", "props.rs")
+
+HEADER = """\
+# Copyright (C) 2021 and later: Unicode, Inc. and others.
+# License & terms of use: http://www.unicode.org/copyright.html
+#
+# file name: {name}
+#
+# machine-generated by: provider/uprops/tools/generate_data.py from Unicode {version}
+
+"""
+
+SCX_HEADER = """\
+[[script_extensions_property]]
+long_name = "Script_Extensions"
+short_name = "scx"
+# Code points `a` through `b` have the scripts `names`.
+ranges = [
+"""
+
+NAMES_HEADER = """\
+[[enum_property_names]]
+long_name = "Script"
+short_name = "sc"
+# The value `v` has the names `short`, `long`, and `aliases`.
+values = [
+"""
+
+
+STRINGS_HEADER = """\
+[[binary_property]]
+long_name = "{long_name}"
+short_name = "{short_name}"
+# Inclusive ranges of the code points in the set.
+ranges = [
+"""
+
+def short_names():
+    """Returns the short names of the scripts, keyed by their upper case long names,
+    from the Script resource keys of icu_properties."""
+    with open(KEYS_RS) as f:
+        return dict(re.findall(r'\(SCRIPT_(\w+)_V1, "sc=(\w+)"\)', f.read()))
+
+
+def script_codes():
+    """Returns the UScriptCode values of the scripts, keyed by their names
+    without underscores, from the Script constants of icu_properties."""
+    with open(SCRIPTS_RS) as f:
+        return {
+            name: int(code)
+            for name, code in re.findall(
+                r"pub const (\w+): Script = Script\((\d+)\);", f.read()
+            )
+        }
+
+
+def value_aliases(unicore_dir, prop):
+    """Returns the lists of names of the values of a property, with the short
+    name first, from the PropValueAliases of the Unicode::UCD module."""
+    with open(os.path.join(unicore_dir, "UCD.pl")) as f:
+        source = f.read()
+    source = source[source.index("%Unicode::UCD::prop_value_aliases = (") :]
+    start = source.index("\n'%s' => \n{" % prop)
+    body = source[start : source.index("\n},", start)]
+    # 'copt' => \n[\n'Copt',\n'Coptic',\n'Qaac',\n],
+    return [
+        re.findall(r"'([^']*)'", names)
+        for names in re.findall(r"'\w+' => \n\[\n((?:'[^']*',\n)+)\]", body)
+    ]
+
+
+def read_table(unicore_dir, name):
+    """Returns the (first, last, value) ranges of a unicore/To table, and the
+    value of the code points that are not listed."""
+    with open(os.path.join(unicore_dir, "To", name + ".pl")) as f:
+        source = f.read()
+    missing = source.split("{'missing'} = '")[1].split("'")[0]
+    table = source.split("return <<'END';\n")[1].split("\nEND")[0]
+    ranges = []
+    for line in table.splitlines():
+        # 60C\t\tArabic Nko Syriac, with an empty end for single code points
+        first, last, value = line.split("\t")
+        ranges.append((int(first, 16), int(last or first, 16), value))
+    return ranges, missing
+
+
+def write_script_extensions(unicore_dir, version):
+    names = short_names()
+
+    def scripts(value):
+        return ", ".join('"%s"' % names[name.upper()] for name in value.split())
+
+    ranges, missing = read_table(unicore_dir, "Scx")
+    lines = []
+    next_cp = 0
+    for first, last, value in ranges:
+        if first > next_cp:
+            lines.append((next_cp, first - 1, missing))
+        lines.append((first, last, value))
+        next_cp = last + 1
+    if next_cp <= 0x10FFFF:
+        lines.append((next_cp, 0x10FFFF, missing))
+
+    with open(os.path.join(OUT_DIR, "scx.toml"), "w") as f:
+        f.write(HEADER.format(name="scx", version=version))
+        f.write(SCX_HEADER)
+        for first, last, value in lines:
+            f.write("  {a=%#x, b=%#x, names=[%s]},\n" % (first, last, scripts(value)))
+        f.write("]\n")
+
+
+def write_script_names(unicore_dir, version):
+    codes = script_codes()
+    values = []
+    for names in value_aliases(unicore_dir, "sc"):
+        short, long, aliases = names[0], names[-1], []
+        if len(names) > 2:
+            long, aliases = names[1], names[2:]
+        # Scripts without a Script constant, such as Katakana_Or_Hiragana, are skipped.
+        if long.replace("_", "") in codes:
+            values.append((codes[long.replace("_", "")], short, long, aliases))
+
+    with open(os.path.join(OUT_DIR, "sc_names.toml"), "w") as f:
+        f.write(HEADER.format(name="sc_names", version=version))
+        f.write(NAMES_HEADER)
+        for v, short, long, aliases in sorted(values):
+            f.write('  {v=%d, short="%s", long="%s"' % (v, short, long))
+            if aliases:
+                f.write(", aliases=[%s]" % ", ".join('"%s"' % a for a in aliases))
+            f.write("},\n")
+        f.write("]\n")
+
+
+def binary_property(name):
+    """Returns the set of code points of a binary property TOML file."""
+    with open(os.path.join(OUT_DIR, name + ".toml"), "rb") as f:
+        ranges = tomllib.load(f)["binary_property"][0]["ranges"]
+    return {cp for first, last in ranges for cp in range(first, last + 1)}
+
+
+def write_strings_property(name, long_name, version, code_points, strings):
+    ranges = []
+    for cp in sorted(code_points):
+        if ranges and ranges[-1][1] == cp - 1:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp])
+
+    def escape(string):
+        return "".join("\\u%04X" % ord(c) if ord(c) <= 0xFFFF else "\\U%08X" % ord(c) for c in string)
+
+    with open(os.path.join(OUT_DIR, name + ".toml"), "w") as f:
+        f.write(HEADER.format(name=name, version=version))
+        f.write(STRINGS_HEADER.format(long_name=long_name, short_name=name))
+        for first, last in ranges:
+            f.write("  [%#x, %#x],\n" % (first, last))
+        f.write("]\n")
+        f.write("# Strings of more than one code point in the set.\n")
+        f.write("strings = [\n")
+        for string in sorted(strings):
+            f.write('  "%s",\n' % escape(string))
+        f.write("]\n")
+
+
+def write_emoji_sequences(version):
+    emoji = binary_property("Emoji")
+    presentation = binary_property("EPres")
+    component = binary_property("EComp")
+    regional_indicators = set(range(0x1F1E6, 0x1F200))
+
+    write_strings_property(
+        "Basic_Emoji",
+        "Basic_Emoji",
+        version,
+        presentation - regional_indicators,
+        [chr(cp) + "\uFE0F" for cp in emoji - presentation - component],
+    )
+    write_strings_property(
+        "EKeycap_Seq",
+        "Emoji_Keycap_Sequence",
+        version,
+        set(),
+        [c + "\uFE0F\u20E3" for c in "#*0123456789"],
+    )
+
+
+def main(unicore_dir):
+    with open(os.path.join(unicore_dir, "version")) as f:
+        version = f.read().strip()
+
+    write_script_extensions(unicore_dir, version)
+    write_script_names(unicore_dir, version)
+    write_emoji_sequences(version)
+
+    print("Generated Script_Extensions, Script names, and emoji sequences for Unicode", version)
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
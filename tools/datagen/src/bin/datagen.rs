@@ -3,26 +3,40 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use anyhow::Context;
-use clap::{App, Arg, ArgGroup, ArgMatches};
+use clap::{App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_locid::LanguageIdentifier;
+use icu_plurals::provider::PluralRuleStringsV1Marker;
+use icu_provider::baked::{Bake, BakedExporter};
 use icu_provider::export::DataExporter;
 use icu_provider::filter::Filterable;
-use icu_provider::hello_world::{self, HelloWorldProvider};
+use icu_provider::hello_world::{self, HelloWorldProvider, HelloWorldV1Marker};
 use icu_provider::iter::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_provider::registry::{self, RegisteredKeys};
 use icu_provider::serde::SerdeSeDataStructMarker;
-use icu_provider_blob::export::BlobExporter;
+use icu_provider::yoke::Yokeable;
+use icu_provider_blob::export::{slice_blob, BlobExporter};
+use icu_provider_blob::BlobDataProvider;
 use icu_provider_cldr::download::CldrAllInOneDownloader;
 use icu_provider_cldr::get_all_cldr_keys;
+use icu_provider_cldr::locales::{self, ParentLocales};
+use icu_provider_cldr::transform::{NumbersProvider, PluralsProvider};
 use icu_provider_cldr::CldrJsonDataProvider;
 use icu_provider_cldr::CldrPaths;
 use icu_provider_cldr::CldrPathsAllInOne;
 use icu_provider_fs::export::fs_exporter;
 use icu_provider_fs::export::serializers;
-use icu_provider_fs::export::FilesystemExporter;
+use icu_provider_fs::export::{slice_dir, FilesystemExporter};
 use icu_provider_fs::manifest;
+use icu_provider_fs::FsDataProvider;
+use icu_provider_uprops::{get_all_uprops_keys, PropertiesDataProvider};
 use simple_logger::SimpleLogger;
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
 use writeable::Writeable;
 
@@ -31,6 +45,7 @@ fn main() -> anyhow::Result<()> {
         .version("0.0.1")
         .author("The ICU4X Project Developers")
         .about("Export CLDR JSON into the ICU4X data schema")
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(
             Arg::with_name("VERBOSE")
                 .short("v")
@@ -43,13 +58,22 @@ fn main() -> anyhow::Result<()> {
                 .long("dry-run")
                 .help("Do not touch the filesystem (consider using with -v)."),
         )
+        .arg(Arg::with_name("VERIFY").long("verify").help(
+            "Instead of writing data, compare the generated data with the existing data \
+                    at the output location, and fail if they differ. The differing resources are \
+                    logged. Only the selected keys are compared.",
+        ))
         .arg(
             Arg::with_name("FORMAT")
                 .long("format")
                 .takes_value(true)
                 .possible_value("dir")
                 .possible_value("blob")
-                .help("Output to a directory on the filesystem or a single blob.")
+                .possible_value("baked")
+                .help(
+                    "Output to a directory on the filesystem, a single blob, or a Rust source \
+                    file with data baked into the binary.",
+                )
                 .default_value("dir"),
         )
         .arg(
@@ -73,13 +97,14 @@ fn main() -> anyhow::Result<()> {
                 .takes_value(true)
                 .possible_value("json")
                 .possible_value("bincode")
+                .possible_value("postcard")
                 .help("File format syntax for data files."),
         )
         .arg(
             Arg::with_name("PRETTY")
                 .short("p")
                 .long("pretty")
-                .help("Whether to pretty-print the output JSON files. Ignored for Bincode."),
+                .help("Whether to pretty-print the output JSON files. Ignored otherwise."),
         )
         .arg(
             Arg::with_name("CLDR_TAG")
@@ -92,6 +117,30 @@ fn main() -> anyhow::Result<()> {
                 )
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("CLDR_SHA256")
+                .long("cldr-sha256")
+                .value_name("HASH")
+                .help(
+                    "Expected SHA-256 checksum of the CLDR JSON zip file downloaded with \
+                    '--cldr-tag'. The download fails if the checksum does not match.",
+                )
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("CLDR_CACHE_DIR")
+                .long("cldr-cache-dir")
+                .value_name("PATH")
+                .help(
+                    "Directory in which CLDR JSON data downloaded with '--cldr-tag' is cached. \
+                    Defaults to the 'icu4x/cldr' directory of the system cache directory.",
+                )
+                .takes_value(true),
+        )
+        .arg(Arg::with_name("OFFLINE").long("offline").help(
+            "Do not download anything. CLDR JSON data for '--cldr-tag' must already be \
+                    in the cache.",
+        ))
         .arg(
             Arg::with_name("CLDR_ROOT")
                 .long("cldr-root")
@@ -116,6 +165,21 @@ fn main() -> anyhow::Result<()> {
                 .help("CLDR JSON locale subset; defaults to 'full'")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("UPROPS_ROOT")
+                .long("uprops-root")
+                .value_name("PATH")
+                .help(
+                    "Path to a directory of Unicode property TOML files exported by the ICU4C \
+                    icuwriteuprops tool. Required for Unicode property keys.",
+                )
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("UPROPS_TESTDATA")
+                .long("uprops-testdata")
+                .help("Load Unicode property data from the icu_testdata project."),
+        )
         .arg(
             Arg::with_name("KEYS")
                 .short("k")
@@ -123,8 +187,8 @@ fn main() -> anyhow::Result<()> {
                 .multiple(true)
                 .takes_value(true)
                 .help(
-                    "Include this resource key in the output. Accepts multiple arguments. \
-                    Also see --key-file.",
+                    "Include this resource key in the output, for example 'decimal/symbols@1'. \
+                    Accepts multiple arguments. Also see --key-file.",
                 ),
         )
         .arg(
@@ -133,7 +197,7 @@ fn main() -> anyhow::Result<()> {
                 .takes_value(true)
                 .help(
                     "Path to text file with resource keys to include, one per line. Empty lines \
-                    and lines starting with '#' are ignored. Also see --key.",
+                    and lines starting with '#' are ignored. Also see --keys.",
                 ),
         )
         .arg(
@@ -141,11 +205,11 @@ fn main() -> anyhow::Result<()> {
                 .long("hello-world-key")
                 .help("Whether to include the 'hello world' key."),
         )
-        .arg(
-            Arg::with_name("ALL_KEYS")
-                .long("all-keys")
-                .help("Include all keys known to ICU4X."),
-        )
+        .arg(Arg::with_name("ALL_KEYS").long("all-keys").help(
+            "Include all keys known to ICU4X. CLDR keys are only included if --cldr-tag, \
+                    --cldr-root, or --cldr-testdata is present, and Unicode property keys are \
+                    only included if --uprops-root or --uprops-testdata is present.",
+        ))
         .group(
             ArgGroup::with_name("KEY_MODE")
                 .arg("KEYS")
@@ -161,8 +225,22 @@ fn main() -> anyhow::Result<()> {
                 .multiple(true)
                 .takes_value(true)
                 .help(
-                    "Include this locale in the output. Accepts multiple arguments. \
-                    Omit this option to include all locales.",
+                    "Include this locale and the locales it falls back to, such as 'en' and \
+                    'und' for 'en-GB', in the output. Accepts multiple arguments.",
+                ),
+        )
+        .arg(
+            Arg::with_name("LOCALE_PRESET")
+                .long("locale-preset")
+                .takes_value(true)
+                .possible_value("full")
+                .possible_value("modern")
+                .possible_value("moderate")
+                .possible_value("basic")
+                .help(
+                    "Include the locales with at least this CLDR coverage level, and the locales \
+                    they fall back to. 'full' includes all locales, like --all-locales. \
+                    Requires a CLDR source.",
                 ),
         )
         .arg(
@@ -178,6 +256,7 @@ fn main() -> anyhow::Result<()> {
         .group(
             ArgGroup::with_name("LOCALE_MODE")
                 .arg("LOCALES")
+                .arg("LOCALE_PRESET")
                 .arg("TEST_LOCALES")
                 .arg("ALL_LOCALES")
                 .required(true),
@@ -189,7 +268,7 @@ fn main() -> anyhow::Result<()> {
                 .help(
                     "Path to output directory or file. Must be empty or non-existent, unless \
                     --overwrite is present, in which case the directory is deleted first. \
-                    For --format blob, omit this option to dump to stdout.",
+                    For --format blob or --format baked, omit this option to dump to stdout.",
                 )
                 .takes_value(true),
         )
@@ -203,6 +282,95 @@ fn main() -> anyhow::Result<()> {
                 .arg("OUTPUT")
                 .arg("OUTPUT_TESTDATA"),
         )
+        .subcommand(
+            SubCommand::with_name("slice")
+                .about(
+                    "Copy some of the keys and locales of existing ICU4X data, such as a data \
+                    release, to a smaller data set, without the sources of the data.",
+                )
+                .arg(
+                    Arg::with_name("INPUT")
+                        .long("input")
+                        .value_name("PATH")
+                        .help("Path to an ICU4X blob file or data directory.")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("OUTPUT")
+                        .short("o")
+                        .long("out")
+                        .value_name("PATH")
+                        .help(
+                            "Path to the output blob file or data directory, which has the \
+                            format of the input. Must be non-existent, unless --overwrite is \
+                            present.",
+                        )
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("OVERWRITE")
+                        .short("W")
+                        .long("overwrite")
+                        .help("Delete the output before writing data."),
+                )
+                .arg(
+                    Arg::with_name("KEYS")
+                        .short("k")
+                        .long("keys")
+                        .multiple(true)
+                        .takes_value(true)
+                        .help(
+                            "Include this resource key in the output, for example \
+                            'decimal/symbols@1'. Accepts multiple arguments.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("KEY_FILE")
+                        .long("key-file")
+                        .takes_value(true)
+                        .help(
+                            "Path to text file with resource keys to include, one per line. \
+                            Empty lines and lines starting with '#' are ignored.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("ALL_KEYS")
+                        .long("all-keys")
+                        .help("Include all keys in the input."),
+                )
+                .group(
+                    ArgGroup::with_name("KEY_MODE")
+                        .arg("KEYS")
+                        .arg("KEY_FILE")
+                        .arg("ALL_KEYS")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("LOCALES")
+                        .short("l")
+                        .long("locales")
+                        .multiple(true)
+                        .takes_value(true)
+                        .help(
+                            "Include this locale and the locales it falls back to by removing \
+                            subtags, such as 'en' and 'und' for 'en-GB', in the output. \
+                            Accepts multiple arguments.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("ALL_LOCALES")
+                        .long("all-locales")
+                        .help("Include all locales in the input."),
+                )
+                .group(
+                    ArgGroup::with_name("LOCALE_MODE")
+                        .arg("LOCALES")
+                        .arg("ALL_LOCALES")
+                        .required(true),
+                ),
+        )
         .get_matches();
 
     if matches.is_present("VERBOSE") {
@@ -218,35 +386,124 @@ fn main() -> anyhow::Result<()> {
             .unwrap()
     }
 
-    if matches.is_present("KEY_FILE") {
-        anyhow::bail!("Key file is not yet supported (see #192)",);
-    }
-
     if matches.is_present("DRY_RUN") {
         anyhow::bail!("Dry-run is not yet supported");
     }
 
-    // TODO: Build up this list from --keys and --key-file
+    if let Some(matches) = matches.subcommand_matches("slice") {
+        return slice(matches);
+    }
 
     let format = matches
         .value_of("FORMAT")
         .expect("Option has default value");
 
+    let selected_keys: Option<HashSet<String>> = if let Some(key_strs) = matches.values_of("KEYS") {
+        Some(key_strs.map(String::from).collect())
+    } else if let Some(path) = matches.value_of_os("KEY_FILE") {
+        Some(read_key_file(path)?)
+    } else {
+        None
+    };
+
+    let uprops_root = get_uprops_root(&matches);
+
+    let (cldr_keys, uprops_keys): (Vec<ResourceKey>, Vec<ResourceKey>) =
+        if matches.is_present("ALL_KEYS") {
+            let cldr_keys = if has_cldr_source(&matches) {
+                get_cldr_keys(None)
+            } else {
+                vec![]
+            };
+            let uprops_keys = if uprops_root.is_some() {
+                get_uprops_keys(None)
+            } else {
+                vec![]
+            };
+            for key in all_keys() {
+                if !cldr_keys.contains(&key)
+                    && !uprops_keys.contains(&key)
+                    && key != hello_world::key::HELLO_WORLD_V1
+                {
+                    log::info!("Skipping key without a data source: {}", key);
+                }
+            }
+            (cldr_keys, uprops_keys)
+        } else if let Some(ref selected_keys) = selected_keys {
+            validate_keys(selected_keys)?;
+            let cldr_keys = get_cldr_keys(Some(selected_keys));
+            let uprops_keys = get_uprops_keys(Some(selected_keys));
+            let mut unsourced_keys: Vec<&str> = selected_keys
+                .iter()
+                .filter(|key| {
+                    !cldr_keys
+                        .iter()
+                        .chain(uprops_keys.iter())
+                        .chain(std::iter::once(&hello_world::key::HELLO_WORLD_V1))
+                        .any(|supported| supported.write_to_string() == **key)
+                })
+                .map(String::as_str)
+                .collect();
+            if !unsourced_keys.is_empty() {
+                unsourced_keys.sort_unstable();
+                anyhow::bail!(
+                    "No data source for resource keys: {}",
+                    unsourced_keys.join(", ")
+                );
+            }
+            (cldr_keys, uprops_keys)
+        } else {
+            (vec![], vec![])
+        };
+
+    let include_hello_world = matches.is_present("HELLO_WORLD")
+        || selected_keys.as_ref().map_or(false, |keys| {
+            keys.contains(&*hello_world::key::HELLO_WORLD_V1.write_to_string())
+        });
+
     let locales_vec = if let Some(locale_strs) = matches.values_of("LOCALES") {
-        Some(
-            locale_strs
-                .map(|s| LanguageIdentifier::from_str(s).with_context(|| s.to_string()))
-                .collect::<Result<Vec<LanguageIdentifier>, anyhow::Error>>()?,
-        )
+        let locales = locale_strs
+            .map(|s| LanguageIdentifier::from_str(s).with_context(|| s.to_string()))
+            .collect::<Result<Vec<LanguageIdentifier>, anyhow::Error>>()?;
+        Some(get_parent_locales(&matches)?.with_ancestors(&locales))
+    } else if let Some(preset) = matches.value_of("LOCALE_PRESET") {
+        get_preset_locales(&matches, preset)?
     } else if matches.is_present("TEST_LOCALES") {
         Some(icu_testdata::metadata::load()?.package_metadata.locales)
     } else {
         None
     };
 
+    if format == "baked" {
+        let mut exporter = get_baked_exporter(&matches)?;
+
+        if !cldr_keys.is_empty() {
+            export_cldr_baked(&matches, &mut exporter, locales_vec.as_deref(), &cldr_keys)?;
+        }
+
+        for key in uprops_keys.iter() {
+            log::warn!("Skipping key not supported in baked data: {}", key);
+        }
+
+        if include_hello_world {
+            export_baked::<HelloWorldV1Marker, _>(
+                &hello_world::key::HELLO_WORLD_V1,
+                HelloWorldProvider::new_with_placeholder_data(),
+                &mut exporter,
+                locales_vec.as_deref(),
+            )?;
+        }
+
+        exporter.close()?;
+
+        return Ok(());
+    }
+
+    let mut verifier = None;
     let mut anchor1;
     let mut anchor2;
     let exporter: &mut dyn DataExporter<SerdeSeDataStructMarker> = match format {
+        _ if matches.is_present("VERIFY") => verifier.insert(get_verifying_exporter(&matches)?),
         "dir" => {
             anchor1 = get_fs_exporter(&matches)?;
             &mut anchor1
@@ -258,34 +515,101 @@ fn main() -> anyhow::Result<()> {
         _ => unreachable!(),
     };
 
-    if matches.is_present("ALL_KEYS") || matches.is_present("KEYS") {
-        let keys = matches.values_of("KEYS").map(|values| values.collect());
-        export_cldr(&matches, exporter, locales_vec.as_deref(), keys.as_ref())?;
+    if !cldr_keys.is_empty() {
+        export_cldr(&matches, exporter, locales_vec.as_deref(), &cldr_keys)?;
     }
 
-    if matches.is_present("HELLO_WORLD") {
+    if !uprops_keys.is_empty() {
+        export_uprops(uprops_root, exporter, &uprops_keys)?;
+    }
+
+    if include_hello_world {
         export_hello_world(&matches, exporter, locales_vec.as_deref())?;
     }
 
     exporter.close()?;
 
+    if let Some(verifier) = verifier {
+        let mut keys: Vec<ResourceKey> = cldr_keys;
+        keys.extend(uprops_keys);
+        if include_hello_world {
+            keys.push(hello_world::key::HELLO_WORLD_V1);
+        }
+        verifier.verify(&keys, read_existing_resources(&matches)?)?;
+    }
+
     Ok(())
 }
 
-fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
+/// Runs the `slice` subcommand.
+fn slice(matches: &ArgMatches) -> anyhow::Result<()> {
+    let selected_keys: Option<HashSet<String>> = if let Some(key_strs) = matches.values_of("KEYS") {
+        Some(key_strs.map(String::from).collect())
+    } else if let Some(path) = matches.value_of_os("KEY_FILE") {
+        Some(read_key_file(path)?)
+    } else {
+        None
+    };
+    if let Some(ref selected_keys) = selected_keys {
+        validate_keys(selected_keys)?;
+    }
+    let keys: Vec<ResourceKey> = all_keys()
+        .filter(|key| {
+            selected_keys
+                .as_ref()
+                .map_or(true, |keys| keys.contains(&*key.write_to_string()))
+        })
+        .collect();
+
+    let locales_vec = if let Some(locale_strs) = matches.values_of("LOCALES") {
+        let locales = locale_strs
+            .map(|s| LanguageIdentifier::from_str(s).with_context(|| s.to_string()))
+            .collect::<Result<Vec<LanguageIdentifier>, anyhow::Error>>()?;
+        Some(ParentLocales::default().with_ancestors(&locales))
+    } else {
+        None
+    };
+
+    let input = Path::new(matches.value_of_os("INPUT").expect("Option is required"));
+    let output = PathBuf::from(matches.value_of_os("OUTPUT").expect("Option is required"));
+
+    let count = if input.is_dir() {
+        log::info!("Slicing filesystem tree at: {}", input.display());
+        let source = FsDataProvider::try_new(input)?;
+        let overwrite = if matches.is_present("OVERWRITE") {
+            fs_exporter::OverwriteOption::RemoveAndReplace
+        } else {
+            fs_exporter::OverwriteOption::CheckEmpty
+        };
+        slice_dir(&source, &keys, locales_vec.as_deref(), &output, overwrite)?
+    } else {
+        log::info!("Slicing blob at: {}", input.display());
+        let blob = std::fs::read(input).with_context(|| input.display().to_string())?;
+        let source = BlobDataProvider::new_from_rc_blob(Rc::from(blob))?;
+        let mut exporter = BlobExporter::new_with_sink(get_file_sink(matches, Some(output))?);
+        let count = slice_blob(&source, &keys, locales_vec.as_deref(), &mut exporter)?;
+        exporter.close()?;
+        count
+    };
+    log::info!("Copied {} resources", count);
+
+    Ok(())
+}
+
+fn get_fs_output_path(matches: &ArgMatches) -> anyhow::Result<PathBuf> {
     let syntax = matches.value_of("SYNTAX").unwrap_or("json");
 
-    let output_path: PathBuf = if matches.is_present("OUTPUT_TESTDATA") {
+    Ok(if matches.is_present("OUTPUT_TESTDATA") {
         icu_testdata::paths::data_root().join(syntax)
     } else if let Some(v) = matches.value_of_os("OUTPUT") {
         PathBuf::from(v)
     } else {
         anyhow::bail!("--out must be specified for --format=dir");
-    };
-
-    log::info!("Writing to filesystem tree at: {}", output_path.display());
+    })
+}
 
-    let serializer: Box<dyn serializers::AbstractSerializer> = match matches.value_of("SYNTAX") {
+fn get_fs_serializer(matches: &ArgMatches) -> Box<dyn serializers::AbstractSerializer> {
+    match matches.value_of("SYNTAX") {
         Some("json") | None => {
             let mut options = serializers::json::Options::default();
             if matches.is_present("PRETTY") {
@@ -297,8 +621,20 @@ fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
             let options = serializers::bincode::Options::default();
             Box::new(serializers::bincode::Serializer::new(options))
         }
+        Some("postcard") => {
+            let options = serializers::postcard::Options::default();
+            Box::new(serializers::postcard::Serializer::new(options))
+        }
         _ => unreachable!(),
-    };
+    }
+}
+
+fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
+    let output_path = get_fs_output_path(matches)?;
+
+    log::info!("Writing to filesystem tree at: {}", output_path.display());
+
+    let serializer = get_fs_serializer(matches);
 
     let mut options = fs_exporter::ExporterOptions::default();
     options.root = output_path;
@@ -317,46 +653,237 @@ fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
     Ok(exporter)
 }
 
+fn get_blob_output_path(matches: &ArgMatches) -> Option<PathBuf> {
+    if matches.is_present("OUTPUT_TESTDATA") {
+        Some(icu_testdata::paths::data_root().join("testdata.postcard"))
+    } else {
+        matches.value_of_os("OUTPUT").map(PathBuf::from)
+    }
+}
+
 fn get_blob_exporter(matches: &ArgMatches) -> anyhow::Result<BlobExporter<'static>> {
     if matches.value_of("SYNTAX") == Some("json") {
         anyhow::bail!("Cannot use --format=blob with --syntax=json");
     }
 
-    let output_path: Option<PathBuf> = if matches.is_present("OUTPUT_TESTDATA") {
-        Some(icu_testdata::paths::data_root().join("testdata.postcard"))
-    } else {
-        matches.value_of_os("OUTPUT").map(PathBuf::from)
-    };
+    let output_path = get_blob_output_path(matches);
 
     match output_path {
         Some(ref p) => log::info!("Writing blob to filesystem at: {}", p.display()),
         None => log::info!("Writing blob to standard out"),
     };
 
-    let sink: Box<dyn std::io::Write> = if let Some(path_buf) = output_path {
+    Ok(BlobExporter::new_with_sink(get_file_sink(
+        matches,
+        output_path,
+    )?))
+}
+
+/// A data exporter that keeps the serialized data structs in memory, so that they can be
+/// compared with an existing output.
+struct VerifyingExporter {
+    serializer: Box<dyn serializers::AbstractSerializer>,
+    resources: BTreeMap<String, Vec<u8>>,
+}
+
+impl<'data> DataExporter<'data, SerdeSeDataStructMarker> for VerifyingExporter {
+    fn put_payload(
+        &mut self,
+        req: DataRequest,
+        obj: DataPayload<'data, SerdeSeDataStructMarker>,
+    ) -> Result<(), DataError> {
+        let mut buffer = Vec::new();
+        self.serializer
+            .serialize(obj.get().as_serialize(), &mut buffer)
+            .map_err(DataError::new_resc_error)?;
+        self.resources
+            .insert(req.resource_path.write_to_string(), buffer);
+        Ok(())
+    }
+}
+
+impl VerifyingExporter {
+    /// Compares the generated resources of the given keys with the existing resources, logging
+    /// every resource that was added, removed, or changed.
+    fn verify(
+        self,
+        keys: &[ResourceKey],
+        existing: BTreeMap<String, Vec<u8>>,
+    ) -> anyhow::Result<()> {
+        let key_strs: Vec<String> = keys.iter().map(Writeable::write_to_string).collect();
+        let is_selected = |path: &str| {
+            key_strs.iter().any(|key| {
+                path.strip_prefix(key.as_str())
+                    .map_or(false, |rest| rest.is_empty() || rest.starts_with('/'))
+            })
+        };
+
+        let mut differences = 0;
+        for (path, buffer) in self.resources.iter() {
+            match existing.get(path) {
+                None => log::warn!("Added: {}", path),
+                Some(existing_buffer) if existing_buffer != buffer => {
+                    log::warn!("Changed: {}", path)
+                }
+                Some(_) => continue,
+            }
+            differences += 1;
+        }
+        for path in existing.keys() {
+            if is_selected(path) && !self.resources.contains_key(path) {
+                log::warn!("Removed: {}", path);
+                differences += 1;
+            }
+        }
+
+        if differences > 0 {
+            anyhow::bail!("{} resources differ from the existing data", differences);
+        }
+        log::info!("Verified {} resources", self.resources.len());
+        Ok(())
+    }
+}
+
+fn get_verifying_exporter(matches: &ArgMatches) -> anyhow::Result<VerifyingExporter> {
+    let serializer: Box<dyn serializers::AbstractSerializer> = match matches.value_of("FORMAT") {
+        Some("dir") => {
+            if matches.value_of("ALIASING") == Some("symlink") {
+                anyhow::bail!("Cannot use --verify with --aliasing=symlink");
+            }
+            get_fs_serializer(matches)
+        }
+        Some("blob") => {
+            if matches.value_of("SYNTAX") == Some("json") {
+                anyhow::bail!("Cannot use --format=blob with --syntax=json");
+            }
+            // Blobs store data structs in Postcard.
+            Box::new(serializers::postcard::Serializer::new(
+                serializers::postcard::Options::default(),
+            ))
+        }
+        _ => anyhow::bail!("--verify is only supported for --format=dir and --format=blob"),
+    };
+    Ok(VerifyingExporter {
+        serializer,
+        resources: BTreeMap::new(),
+    })
+}
+
+/// Reads the serialized data structs of the existing output, keyed by resource path.
+fn read_existing_resources(matches: &ArgMatches) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+    let mut resources = BTreeMap::new();
+    if matches.value_of("FORMAT") == Some("blob") {
+        let path = match get_blob_output_path(matches) {
+            Some(path) => path,
+            None => anyhow::bail!("--out must be specified for --verify"),
+        };
+        log::info!("Verifying blob at: {}", path.display());
+        let blob = std::fs::read(&path).with_context(|| path.display().to_string())?;
+        let provider = BlobDataProvider::new_from_rc_blob(Rc::from(blob))?;
+        for (path, buffer) in provider.resources() {
+            let path = path.strip_prefix('/').unwrap_or(path);
+            resources.insert(path.to_string(), buffer.to_vec());
+        }
+    } else {
+        let root = get_fs_output_path(matches)?;
+        log::info!("Verifying filesystem tree at: {}", root.display());
+        let serializer = get_fs_serializer(matches);
+        let extension = serializer.get_file_extension();
+        // The manifest is at the root and data files are in key directories below it.
+        for entry in std::fs::read_dir(&root).with_context(|| root.display().to_string())? {
+            let path = entry?.path();
+            if path.is_dir() {
+                read_dir_resources(&root, &path, extension, &mut resources)?;
+            }
+        }
+    }
+    Ok(resources)
+}
+
+/// Recursively reads the data files with the given extension in `dir`, keyed by their path
+/// relative to `root`.
+fn read_dir_resources(
+    root: &Path,
+    dir: &Path,
+    extension: &str,
+    resources: &mut BTreeMap<String, Vec<u8>>,
+) -> anyhow::Result<()> {
+    for entry in std::fs::read_dir(dir).with_context(|| dir.display().to_string())? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir_resources(root, &path, extension, resources)?;
+        } else if path.extension() == Some(OsStr::new(extension)) {
+            let resource_path = path
+                .with_extension("")
+                .strip_prefix(root)?
+                .iter()
+                .map(|component| component.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let buffer = std::fs::read(&path).with_context(|| path.display().to_string())?;
+            resources.insert(resource_path, buffer);
+        }
+    }
+    Ok(())
+}
+
+fn get_baked_exporter(matches: &ArgMatches) -> anyhow::Result<BakedExporter<'static>> {
+    if matches.is_present("SYNTAX") {
+        anyhow::bail!("Cannot use --format=baked with --syntax");
+    }
+
+    if matches.is_present("OUTPUT_TESTDATA") {
+        anyhow::bail!("Cannot use --format=baked with --out-testdata");
+    }
+
+    let output_path: Option<PathBuf> = matches.value_of_os("OUTPUT").map(PathBuf::from);
+
+    match output_path {
+        Some(ref p) => log::info!("Writing baked data to filesystem at: {}", p.display()),
+        None => log::info!("Writing baked data to standard out"),
+    };
+
+    Ok(BakedExporter::new_with_sink(get_file_sink(
+        matches,
+        output_path,
+    )?))
+}
+
+/// Opens the output file, or standard out if no path is given.
+fn get_file_sink(
+    matches: &ArgMatches,
+    output_path: Option<PathBuf>,
+) -> anyhow::Result<Box<dyn std::io::Write>> {
+    if let Some(path_buf) = output_path {
         if !matches.is_present("OVERWRITE") && path_buf.exists() {
             anyhow::bail!("Output path is present: {:?}", path_buf);
         }
         let context = path_buf.to_string_lossy().to_string();
         let temp = std::fs::File::create(path_buf).with_context(|| context)?;
-        Box::new(temp)
+        Ok(Box::new(temp))
     } else {
         let temp = std::io::stdout();
-        Box::new(temp)
-    };
+        Ok(Box::new(temp))
+    }
+}
 
-    Ok(BlobExporter::new_with_sink(sink))
+/// Whether any source of CLDR JSON data was given on the command line.
+fn has_cldr_source(matches: &ArgMatches) -> bool {
+    matches.is_present("CLDR_TAG")
+        || matches.is_present("CLDR_ROOT")
+        || matches.is_present("CLDR_TESTDATA")
 }
 
-fn export_cldr<'data>(
-    matches: &ArgMatches,
-    exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
-    allowed_locales: Option<&[LanguageIdentifier]>,
-    allowed_keys: Option<&HashSet<&str>>,
-) -> anyhow::Result<()> {
+fn get_cldr_paths(matches: &ArgMatches) -> anyhow::Result<Box<dyn CldrPaths>> {
     let locale_subset = matches.value_of("CLDR_LOCALE_SUBSET").unwrap_or("full");
-    let cldr_paths: Box<dyn CldrPaths> = if let Some(tag) = matches.value_of("CLDR_TAG") {
-        Box::new(CldrAllInOneDownloader::try_new_from_github(tag, locale_subset)?.download()?)
+    Ok(if let Some(tag) = matches.value_of("CLDR_TAG") {
+        let mut downloader = CldrAllInOneDownloader::try_new_from_github(tag, locale_subset)?;
+        if let Some(cache_dir) = matches.value_of("CLDR_CACHE_DIR") {
+            downloader.cache_dir = PathBuf::from(cache_dir);
+        }
+        downloader.sha256 = matches.value_of("CLDR_SHA256").map(String::from);
+        downloader.offline = matches.is_present("OFFLINE");
+        Box::new(downloader.download()?)
     } else if let Some(path) = matches.value_of("CLDR_ROOT") {
         Box::new(CldrPathsAllInOne {
             cldr_json_root: PathBuf::from(path),
@@ -369,17 +896,49 @@ fn export_cldr<'data>(
         })
     } else {
         anyhow::bail!("Either --cldr-tag or --cldr-root must be specified",)
-    };
-
-    let keys = get_all_cldr_keys();
+    })
+}
 
-    let keys = if let Some(allowed_keys) = allowed_keys {
-        keys.into_iter()
-            .filter(|k| allowed_keys.contains(&*k.writeable_to_string()))
-            .collect()
+/// Returns the parent locales of the CLDR source, or the ones built into ICU4X if there is no
+/// CLDR source.
+fn get_parent_locales(matches: &ArgMatches) -> anyhow::Result<ParentLocales> {
+    if has_cldr_source(matches) {
+        Ok(ParentLocales::try_from(get_cldr_paths(matches)?.as_ref())?)
     } else {
-        keys
-    };
+        Ok(ParentLocales::default())
+    }
+}
+
+/// Returns the locales of a --locale-preset, or `None` for all locales.
+fn get_preset_locales(
+    matches: &ArgMatches,
+    preset: &str,
+) -> anyhow::Result<Option<Vec<LanguageIdentifier>>> {
+    if !has_cldr_source(matches) {
+        anyhow::bail!("--locale-preset requires --cldr-tag, --cldr-root, or --cldr-testdata");
+    }
+    if preset == "full" {
+        return Ok(None);
+    }
+    let cldr_paths = get_cldr_paths(matches)?;
+    let locales = locales::locales_at_coverage_level(cldr_paths.as_ref(), preset.parse()?)?;
+    log::info!(
+        "Selected {} locales with {} coverage",
+        locales.len(),
+        preset
+    );
+    Ok(Some(
+        ParentLocales::try_from(cldr_paths.as_ref())?.with_ancestors(&locales),
+    ))
+}
+
+fn export_cldr<'data>(
+    matches: &ArgMatches,
+    exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
+    allowed_locales: Option<&[LanguageIdentifier]>,
+    keys: &[ResourceKey],
+) -> anyhow::Result<()> {
+    let cldr_paths = get_cldr_paths(matches)?;
 
     let raw_provider = CldrJsonDataProvider::new(cldr_paths.as_ref());
     let filtered_provider;
@@ -402,6 +961,43 @@ fn export_cldr<'data>(
     Ok(())
 }
 
+fn get_uprops_root(matches: &ArgMatches) -> Option<PathBuf> {
+    if let Some(path) = matches.value_of_os("UPROPS_ROOT") {
+        Some(PathBuf::from(path))
+    } else if matches.is_present("UPROPS_TESTDATA") {
+        Some(icu_testdata::paths::data_root().join("uprops"))
+    } else {
+        None
+    }
+}
+
+fn export_uprops<'data>(
+    uprops_root: Option<PathBuf>,
+    exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
+    keys: &[ResourceKey],
+) -> anyhow::Result<()> {
+    let uprops_root = match uprops_root {
+        Some(path) => path,
+        None => anyhow::bail!(
+            "Either --uprops-root or --uprops-testdata must be specified for Unicode property keys"
+        ),
+    };
+    let provider = PropertiesDataProvider::new(uprops_root);
+
+    for key in keys.iter() {
+        // Property data has no locales, so the locale filter does not apply.
+        match icu_provider::export::export_from_iterable(key, &provider, exporter) {
+            Ok(()) => log::info!("Wrote key: {}", key),
+            Err(DataError::MissingResourceKey(_)) => {
+                log::warn!("Skipping key without source data: {}", key)
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(())
+}
+
 fn export_hello_world<'data>(
     _: &ArgMatches,
     exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
@@ -426,3 +1022,142 @@ fn export_hello_world<'data>(
 
     Ok(())
 }
+
+/// Reads a key file: one resource key per line, ignoring empty lines and '#' comments.
+fn read_key_file(path: &OsStr) -> anyhow::Result<HashSet<String>> {
+    let contents =
+        std::fs::read_to_string(path).with_context(|| path.to_string_lossy().to_string())?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// The key registries of the experimental components, which are not part of the `icu` crate.
+const EXPERIMENTAL_KEY_REGISTRY: &[&[RegisteredKeys]] = &[
+    icu_bidi::provider::KEY_REGISTRY,
+    icu_collator::provider::KEY_REGISTRY,
+    icu_displaynames::provider::KEY_REGISTRY,
+    icu_durationformat::provider::KEY_REGISTRY,
+    icu_list::provider::KEY_REGISTRY,
+    icu_normalizer::provider::KEY_REGISTRY,
+    icu_segmenter::provider::KEY_REGISTRY,
+    icu_spoof::provider::KEY_REGISTRY,
+    icu_timezone::provider::KEY_REGISTRY,
+    icu_transliterator::provider::KEY_REGISTRY,
+];
+
+/// Returns every resource key known to ICU4X: the keys of the components in the `icu` crate and
+/// of the experimental components, and the hello world key.
+fn all_keys() -> impl Iterator<Item = ResourceKey> {
+    icu::provider::all_keys()
+        .chain(
+            EXPERIMENTAL_KEY_REGISTRY
+                .iter()
+                .flat_map(|component_registry| registry::iter_keys(component_registry)),
+        )
+        .chain(registry::iter_keys(registry::KEY_REGISTRY))
+        .map(|info| info.key)
+}
+
+/// Checks that every selected key is registered by a component.
+fn validate_keys(selected_keys: &HashSet<String>) -> anyhow::Result<()> {
+    let known_keys: HashSet<String> = all_keys().map(|key| key.write_to_string()).collect();
+    let mut unknown_keys: Vec<&str> = selected_keys
+        .iter()
+        .filter(|key| !known_keys.contains(*key))
+        .map(String::as_str)
+        .collect();
+    if !unknown_keys.is_empty() {
+        unknown_keys.sort_unstable();
+        anyhow::bail!("Unknown resource keys: {}", unknown_keys.join(", "));
+    }
+    Ok(())
+}
+
+/// Returns the registered keys that the CLDR transformers support, either all of them or the
+/// ones in `allowed_keys`.
+fn get_cldr_keys(allowed_keys: Option<&HashSet<String>>) -> Vec<ResourceKey> {
+    let cldr_keys = get_all_cldr_keys();
+    all_keys()
+        .filter(|key| cldr_keys.contains(key))
+        .filter(|key| {
+            allowed_keys.map_or(true, |allowed_keys| {
+                allowed_keys.contains(&*key.write_to_string())
+            })
+        })
+        .collect()
+}
+
+/// Returns the registered keys that the Unicode property provider supports, either all of them
+/// or the ones in `allowed_keys`.
+fn get_uprops_keys(allowed_keys: Option<&HashSet<String>>) -> Vec<ResourceKey> {
+    let uprops_keys = get_all_uprops_keys();
+    all_keys()
+        .filter(|key| uprops_keys.contains(key))
+        .filter(|key| {
+            allowed_keys.map_or(true, |allowed_keys| {
+                allowed_keys.contains(&*key.write_to_string())
+            })
+        })
+        .collect()
+}
+
+fn export_cldr_baked(
+    matches: &ArgMatches,
+    exporter: &mut BakedExporter,
+    allowed_locales: Option<&[LanguageIdentifier]>,
+    keys: &[ResourceKey],
+) -> anyhow::Result<()> {
+    let cldr_paths = get_cldr_paths(matches)?;
+
+    for key in keys.iter() {
+        match *key {
+            icu_decimal::provider::key::SYMBOLS_V1 => {
+                export_baked::<DecimalSymbolsV1Marker, _>(
+                    key,
+                    NumbersProvider::try_from(cldr_paths.as_ref())?,
+                    exporter,
+                    allowed_locales,
+                )?;
+            }
+            icu_plurals::provider::key::CARDINAL_V1 | icu_plurals::provider::key::ORDINAL_V1 => {
+                export_baked::<PluralRuleStringsV1Marker, _>(
+                    key,
+                    PluralsProvider::try_from(cldr_paths.as_ref())?,
+                    exporter,
+                    allowed_locales,
+                )?;
+            }
+            _ => log::warn!("Skipping key not supported in baked data: {}", key),
+        }
+    }
+
+    Ok(())
+}
+
+/// Exports a key whose data struct implements [`Bake`].
+fn export_baked<'data, M, P>(
+    key: &ResourceKey,
+    provider: P,
+    exporter: &mut BakedExporter,
+    allowed_locales: Option<&[LanguageIdentifier]>,
+) -> anyhow::Result<()>
+where
+    M: DataMarker<'data>,
+    P: IterableDataProvider<'data, M>,
+    for<'a> &'a <M::Yokeable as Yokeable<'a>>::Output: Bake,
+{
+    log::info!("Writing key: {}", key);
+    if let Some(allowlist) = allowed_locales {
+        let provider = provider
+            .filterable()
+            .filter_by_langid_allowlist_strict(allowlist);
+        icu_provider::export::export_from_iterable(key, &provider, exporter)?;
+    } else {
+        icu_provider::export::export_from_iterable(key, &provider, exporter)?;
+    }
+    Ok(())
+}
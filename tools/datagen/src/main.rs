@@ -24,7 +24,78 @@
 //!    --out /tmp/icu4x_data/json
 //!```
 //!
-//! Generate ICU4X Bincode blob (single file):
+//! Generate ICU4X JSON file tree for the locales with modern coverage in CLDR. The locales that
+//! these locales fall back to, such as `en-001` and `und` for `en-GB`, are included automatically,
+//! as they are with `--locales`:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 40.0.0 \
+//!    --all-keys \
+//!    --locale-preset modern \
+//!    --out /tmp/icu4x_data/modern
+//!```
+//!
+//! Generate ICU4X JSON file tree with only the date and time keys for a few locales, using a key
+//! file listing one key per line:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cat /tmp/datetime_keys.txt
+//!# Date and time keys
+//!datetime/gregory_lengths@1
+//!datetime/gregory_skltns@1
+//!datetime/gregory_symbols@1
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --key-file /tmp/datetime_keys.txt \
+//!    --locales en de fr ja \
+//!    --out /tmp/icu4x_data/datetime
+//!```
+//!
+//! The CLDR JSON data for `--cldr-tag` is downloaded once and cached. Verify the download against
+//! a known checksum with `--cldr-sha256`, and use `--offline` to only use the cached data, such as
+//! on a build machine without network access:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --cldr-cache-dir /tmp/icu4x_cache \
+//!    --offline \
+//!    --all-keys \
+//!    --all-locales \
+//!    --out /tmp/icu4x_data/json
+//!```
+//!
+//! Generate ICU4X JSON file tree with all Unicode property sets and maps, from TOML files exported
+//! by the ICU4C icuwriteuprops tool:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --uprops-root /path/to/uprops \
+//!    --all-keys \
+//!    --all-locales \
+//!    --out /tmp/icu4x_data/uprops
+//!```
+//!
+//! Check that an existing ICU4X JSON file tree is up to date, without writing any files. The
+//! resources that would be added, removed, or changed are logged, and the command fails if there
+//! are any:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --all-keys \
+//!    --all-locales \
+//!    --out /tmp/icu4x_data/json \
+//!    --verify
+//!```
+//!
+//! Generate ICU4X Postcard blob (single file, including a manifest of keys and locales):
 //!
 //!```bash
 //!# Run from the icu4x project folder
@@ -36,6 +107,19 @@
 //!    --out /tmp/icu4x_data/icu4x_data.postcard
 //!```
 //!
+//! Copy the German and French data of an existing blob, such as the one generated above, to a
+//! smaller blob, without the CLDR sources. The input can also be a data directory, in which case
+//! the output is a data directory:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- slice \
+//!    --input /tmp/icu4x_data/icu4x_data.postcard \
+//!    --all-keys \
+//!    --locales de fr \
+//!    --out /tmp/icu4x_data/icu4x_data_de_fr.postcard
+//!```
+//!
 //! Generate ICU4X Bincode file tree:
 //!
 //!```bash
@@ -47,6 +131,30 @@
 //!    --syntax bincode \
 //!    --out /tmp/icu4x_data/bincode
 //!```
+//!
+//! Generate ICU4X Postcard file tree:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --all-keys \
+//!    --all-locales \
+//!    --syntax postcard \
+//!    --out /tmp/icu4x_data/postcard
+//!```
+//!
+//! Generate a Rust source file with decimal symbols baked in, for use with `BakedDataProvider`:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --keys decimal/symbols@1 \
+//!    --all-locales \
+//!    --format baked \
+//!    --out /tmp/icu4x_data/baked.rs
+//!```
 
 fn main() {
     panic!("Please run a more specific binary")
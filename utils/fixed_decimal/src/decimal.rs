@@ -35,9 +35,8 @@ const_assert!(core::mem::size_of::<usize>() >= core::mem::size_of::<u16>());
 /// To create a [`FixedDecimal`] with fraction digits, either create it from an integer and then
 /// call [`FixedDecimal::multiplied_pow10`], or create it from a string.
 ///
-/// Floating point numbers will be supported pending a resolution to
-/// [#166](https://github.com/unicode-org/icu4x/issues/166). In the mean time, a third-party
-/// float-to-string library may be used.
+/// Floating point numbers can be converted with [`FixedDecimal::new_from_f64`] when the `ryu`
+/// feature is enabled.
 ///
 /// # Examples
 ///
@@ -363,6 +362,290 @@ impl FixedDecimal {
         }
     }
 
+    /// Returns the magnitude of the most significant nonzero digit, or 0 if the number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// assert_eq!(2, FixedDecimal::from(120).nonzero_magnitude_start());
+    /// assert_eq!(-2, "0.012".parse::<FixedDecimal>().unwrap().nonzero_magnitude_start());
+    /// assert_eq!(0, FixedDecimal::from(0).nonzero_magnitude_start());
+    /// ```
+    pub fn nonzero_magnitude_start(&self) -> i16 {
+        if self.digits.is_empty() {
+            0
+        } else {
+            self.magnitude
+        }
+    }
+
+    /// Returns the magnitude of the least significant nonzero digit, or 0 if the number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// assert_eq!(1, FixedDecimal::from(120).nonzero_magnitude_end());
+    /// assert_eq!(-3, "0.012".parse::<FixedDecimal>().unwrap().nonzero_magnitude_end());
+    /// assert_eq!(0, FixedDecimal::from(0).nonzero_magnitude_end());
+    /// ```
+    pub fn nonzero_magnitude_end(&self) -> i16 {
+        if self.digits.is_empty() {
+            0
+        } else {
+            // The digits fit between the lower and upper magnitudes, which are i16.
+            (self.magnitude as i32 - self.digits.len() as i32 + 1) as i16
+        }
+    }
+
+    /// Rounds the number to the given power of 10 using the half-even rounding mode
+    /// ("banker's rounding"), modifying self.
+    ///
+    /// Digits below `position` are removed, and trailing zeros are added so that the digit at
+    /// `position` is visible. If rounding up would overflow the highest magnitude supported by
+    /// the `FixedDecimal`, the number is rounded down instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "1.235".parse().unwrap();
+    /// dec.half_even(-2);
+    /// assert_eq!("1.24", dec.to_string());
+    ///
+    /// let mut dec: FixedDecimal = "1.245".parse().unwrap();
+    /// dec.half_even(-2);
+    /// assert_eq!("1.24", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(9950);
+    /// dec.half_even(2);
+    /// assert_eq!("10000", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(7);
+    /// dec.half_even(-2);
+    /// assert_eq!("7.00", dec.to_string());
+    /// ```
+    pub fn half_even(&mut self, position: i16) {
+        // Number of digits at magnitudes >= position; may be zero or negative.
+        let kept = self.magnitude as i32 - position as i32 + 1;
+        if !self.digits.is_empty() && kept < self.digits.len() as i32 {
+            let round_up = if kept < 0 {
+                // The most significant digit is below the digit after `position`.
+                false
+            } else {
+                let kept = kept as usize;
+                let first_dropped = self.digits[kept];
+                let last_kept = if kept == 0 { 0 } else { self.digits[kept - 1] };
+                // Since there are no trailing zeros, any further digit is nonzero.
+                let round_up = first_dropped > 5
+                    || (first_dropped == 5 && (self.digits.len() > kept + 1 || last_kept % 2 == 1));
+                let carries_out = self.digits[..kept].iter().all(|d| *d == 9);
+                round_up && !(carries_out && self.magnitude == i16::MAX)
+            };
+            self.digits.truncate(cmp::max(kept, 0) as usize);
+            if round_up {
+                loop {
+                    match self.digits.last_mut() {
+                        Some(9) => {
+                            self.digits.pop();
+                        }
+                        Some(digit) => {
+                            *digit += 1;
+                            break;
+                        }
+                        None => {
+                            // The carry reached the digit at magnitude + 1.
+                            self.digits.push(1);
+                            self.magnitude += 1;
+                            break;
+                        }
+                    }
+                }
+            } else {
+                while let Some(0) = self.digits.last() {
+                    self.digits.pop();
+                }
+            }
+            if self.digits.is_empty() {
+                // The number was rounded to zero; drop the leading zeros of the old magnitude.
+                self.magnitude = 0;
+                self.upper_magnitude = 0;
+            }
+        }
+        self.lower_magnitude = cmp::min(position, 0);
+        self.upper_magnitude = cmp::max(self.upper_magnitude, self.magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Removes the trailing zeros after the decimal separator, modifying self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "1.2500".parse().unwrap();
+    /// dec.trim_end();
+    /// assert_eq!("1.25", dec.to_string());
+    ///
+    /// let mut dec: FixedDecimal = "100.0".parse().unwrap();
+    /// dec.trim_end();
+    /// assert_eq!("100", dec.to_string());
+    /// ```
+    pub fn trim_end(&mut self) {
+        self.lower_magnitude = if self.digits.is_empty() {
+            0
+        } else {
+            let lowest = self.magnitude as i32 - self.digits.len() as i32 + 1;
+            cmp::min(0, lowest) as i16
+        };
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Zero-pads the number on the left so that it has at least `position` digits before the
+    /// decimal separator, modifying self.
+    ///
+    /// Leading zeros beyond `position` are removed, but nonzero digits are never removed; see
+    /// [`FixedDecimal::set_max_position`] for that. A `position` of zero or less has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// dec.pad_start(4);
+    /// assert_eq!("0042", dec.to_string());
+    ///
+    /// dec.pad_start(1);
+    /// assert_eq!("42", dec.to_string());
+    /// ```
+    pub fn pad_start(&mut self, position: i16) {
+        if position <= 0 {
+            return;
+        }
+        self.upper_magnitude = cmp::max(position - 1, self.magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Zero-pads the number on the right so that the digit at the magnitude `position` is
+    /// visible, modifying self.
+    ///
+    /// Trailing zeros below `position` are removed, but nonzero digits are never removed; see
+    /// [`FixedDecimal::trunc`] for that. A positive `position` has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "1.5".parse().unwrap();
+    /// dec.pad_end(-3);
+    /// assert_eq!("1.500", dec.to_string());
+    ///
+    /// dec.pad_end(0);
+    /// assert_eq!("1.5", dec.to_string());
+    /// ```
+    pub fn pad_end(&mut self, position: i16) {
+        if position > 0 {
+            return;
+        }
+        self.lower_magnitude = cmp::min(position, self.nonzero_magnitude_end());
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Truncates the number on the right at the magnitude `position`, rounding toward zero,
+    /// modifying self.
+    ///
+    /// Digits below `position` are removed, and trailing zeros are added so that the digit at
+    /// `position` is visible, as with [`FixedDecimal::half_even`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "-1.789".parse().unwrap();
+    /// dec.trunc(-1);
+    /// assert_eq!("-1.7", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(1299);
+    /// dec.trunc(2);
+    /// assert_eq!("1200", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from(7);
+    /// dec.trunc(-2);
+    /// assert_eq!("7.00", dec.to_string());
+    /// ```
+    pub fn trunc(&mut self, position: i16) {
+        // Number of digits at magnitudes >= position; may be zero or negative.
+        let kept = self.magnitude as i32 - position as i32 + 1;
+        if kept < self.digits.len() as i32 {
+            self.digits.truncate(cmp::max(kept, 0) as usize);
+            while let Some(0) = self.digits.last() {
+                self.digits.pop();
+            }
+            if self.digits.is_empty() {
+                // The number was truncated to zero; drop the leading zeros of the old magnitude.
+                self.magnitude = 0;
+                self.upper_magnitude = 0;
+            }
+        }
+        self.lower_magnitude = cmp::min(position, 0);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Truncates the number on the left so that only the digits below the magnitude `position`
+    /// remain, and zero-pads it so that it has `position` digits before the decimal separator,
+    /// modifying self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec: FixedDecimal = "4235.970".parse().unwrap();
+    /// dec.set_max_position(2);
+    /// assert_eq!("35.970", dec.to_string());
+    ///
+    /// dec.set_max_position(4);
+    /// assert_eq!("0035.970", dec.to_string());
+    ///
+    /// dec.set_max_position(-1);
+    /// assert_eq!("0.070", dec.to_string());
+    /// ```
+    pub fn set_max_position(&mut self, position: i16) {
+        if !self.digits.is_empty() && self.magnitude >= position {
+            // Number of digits at magnitudes >= position; at least one.
+            let removed = (self.magnitude as i32 - position as i32 + 1) as usize;
+            if removed >= self.digits.len() {
+                self.digits.clear();
+                self.magnitude = 0;
+            } else {
+                self.digits.drain(..removed);
+                // The new most significant digit is at position - 1, which cannot overflow
+                // because a digit of the number is below position.
+                self.magnitude = position - 1;
+                while let Some(0) = self.digits.first() {
+                    self.digits.remove(0);
+                    self.magnitude -= 1;
+                }
+            }
+        }
+        self.upper_magnitude = cmp::max(position.saturating_sub(1), 0);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
     /// Assert that the invariants among struct fields are enforced. Returns true if all are okay.
     /// Call this in any method that mutates the struct fields.
     ///
@@ -424,7 +707,7 @@ impl writeable::Writeable for FixedDecimal {
     /// use writeable::LengthHint;
     ///
     /// let dec = FixedDecimal::from(-5000).multiplied_pow10(-2).expect("Bounds are small");
-    /// let result = dec.writeable_to_string();
+    /// let result = dec.write_to_string();
     /// assert_eq!(LengthHint::Exact(6), dec.write_len());
     /// ```
     fn write_len(&self) -> writeable::LengthHint {
@@ -442,6 +725,85 @@ impl fmt::Display for FixedDecimal {
     }
 }
 
+/// Specifies the precision of a floating point value when constructing a [`FixedDecimal`].
+///
+/// Rounding is performed on the shortest decimal representation of the value, using the
+/// half-even rounding mode. For example, 2.675 is rounded to 2.68, even though the closest
+/// [`f64`] to 2.675 is slightly below it.
+///
+/// See [`FixedDecimal::new_from_f64`].
+#[cfg(feature = "ryu")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoublePrecision {
+    /// Use the shortest representation that round-trips to the same [`f64`].
+    Floating,
+
+    /// Round to the given power of 10; for example, `Magnitude(-2)` keeps two fraction digits.
+    /// Trailing zeros are added to display digits down to that magnitude.
+    Magnitude(i16),
+
+    /// Round to the given number of significant digits, which is at least 1. Trailing zeros are
+    /// added to display that number of digits.
+    SignificantDigits(u8),
+}
+
+#[cfg(feature = "ryu")]
+impl FixedDecimal {
+    /// Constructs a [`FixedDecimal`] from an [`f64`] with the given precision.
+    ///
+    /// The digits are computed with the shortest representation that round-trips to the same
+    /// [`f64`], so that, for example, `0.1` becomes "0.1" and not the exact binary value.
+    ///
+    /// Returns [`Error::Limit`] if the value is infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{DoublePrecision, FixedDecimal};
+    ///
+    /// let dec = FixedDecimal::new_from_f64(0.1 + 0.2, DoublePrecision::Floating).unwrap();
+    /// assert_eq!("0.30000000000000004", dec.to_string());
+    ///
+    /// let dec = FixedDecimal::new_from_f64(1.5, DoublePrecision::Magnitude(-2)).unwrap();
+    /// assert_eq!("1.50", dec.to_string());
+    ///
+    /// let dec = FixedDecimal::new_from_f64(123456.0, DoublePrecision::SignificantDigits(2)).unwrap();
+    /// assert_eq!("120000", dec.to_string());
+    ///
+    /// let dec = FixedDecimal::new_from_f64(-1.2e-7, DoublePrecision::Floating).unwrap();
+    /// assert_eq!("-0.00000012", dec.to_string());
+    /// ```
+    pub fn new_from_f64(float: f64, precision: DoublePrecision) -> Result<Self, Error> {
+        if !float.is_finite() {
+            return Err(Error::Limit);
+        }
+        let mut buffer = ryu::Buffer::new();
+        let formatted = buffer.format_finite(float);
+        // ryū uses scientific notation, such as "1.2e-7", for large and small values.
+        let (significand, exponent) = match formatted.split_once('e') {
+            Some((significand, exponent)) => (
+                significand,
+                exponent.parse::<i16>().map_err(|_| Error::Limit)?,
+            ),
+            None => (formatted, 0),
+        };
+        let mut result = Self::from_str(significand)?.multiplied_pow10(exponent)?;
+        // ryū always writes a fraction digit, such as "1.0".
+        result.trim_end();
+        match precision {
+            DoublePrecision::Floating => {}
+            DoublePrecision::Magnitude(position) => result.half_even(position),
+            DoublePrecision::SignificantDigits(digits) => {
+                let position = result
+                    .nonzero_magnitude_start()
+                    .saturating_sub(i16::from(digits.max(1)) - 1);
+                result.half_even(position);
+            }
+        }
+        Ok(result)
+    }
+}
+
 impl FromStr for FixedDecimal {
     type Err = Error;
     fn from_str(input_str: &str) -> Result<Self, Self::Err> {
@@ -1006,3 +1368,308 @@ fn test_signum_zero() {
         assert_eq!(cas.expected_signum, signum, "{:?}", cas);
     }
 }
+
+#[test]
+fn test_half_even() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub position: i16,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "3.45",
+            position: -1,
+            expected: "3.4",
+        },
+        TestCase {
+            input: "3.451",
+            position: -1,
+            expected: "3.5",
+        },
+        TestCase {
+            input: "3.55",
+            position: -1,
+            expected: "3.6",
+        },
+        TestCase {
+            input: "-3.55",
+            position: -1,
+            expected: "-3.6",
+        },
+        TestCase {
+            input: "0.5",
+            position: 0,
+            expected: "0",
+        },
+        TestCase {
+            input: "1.5",
+            position: 0,
+            expected: "2",
+        },
+        TestCase {
+            input: "9.99",
+            position: -1,
+            expected: "10.0",
+        },
+        TestCase {
+            input: "0.0072",
+            position: -2,
+            expected: "0.01",
+        },
+        TestCase {
+            input: "0.0042",
+            position: -2,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "1234",
+            position: 2,
+            expected: "1200",
+        },
+        TestCase {
+            input: "1250",
+            position: 2,
+            expected: "1200",
+        },
+        TestCase {
+            input: "560",
+            position: 3,
+            expected: "1000",
+        },
+        TestCase {
+            input: "42",
+            position: 4,
+            expected: "0",
+        },
+        TestCase {
+            input: "1.2",
+            position: -3,
+            expected: "1.200",
+        },
+        TestCase {
+            input: "0",
+            position: -1,
+            expected: "0.0",
+        },
+    ];
+    for cas in &cases {
+        let mut dec = FixedDecimal::from_str(cas.input).unwrap();
+        dec.half_even(cas.position);
+        assert_eq!(cas.expected, dec.to_string(), "{:?}", cas);
+    }
+}
+
+#[test]
+fn test_pad() {
+    let mut dec: FixedDecimal = "-0.42".parse().unwrap();
+    dec.pad_start(3);
+    assert_eq!("-000.42", dec.to_string());
+    dec.pad_end(-4);
+    assert_eq!("-000.4200", dec.to_string());
+    dec.pad_start(0);
+    dec.pad_end(1);
+    assert_eq!("-000.4200", dec.to_string());
+    dec.pad_start(1);
+    dec.pad_end(-1);
+    assert_eq!("-0.42", dec.to_string());
+
+    let mut dec = FixedDecimal::from(0);
+    dec.pad_start(core::i16::MAX);
+    dec.pad_end(core::i16::MIN);
+    assert_eq!(core::i16::MIN..=core::i16::MAX - 1, dec.magnitude_range());
+}
+
+#[test]
+fn test_trunc() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub position: i16,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "3.49",
+            position: -1,
+            expected: "3.4",
+        },
+        TestCase {
+            input: "-3.99",
+            position: 0,
+            expected: "-3",
+        },
+        TestCase {
+            input: "0.0099",
+            position: -2,
+            expected: "0.00",
+        },
+        TestCase {
+            input: "1909",
+            position: 1,
+            expected: "1900",
+        },
+        TestCase {
+            input: "42",
+            position: 4,
+            expected: "0",
+        },
+        TestCase {
+            input: "1.2",
+            position: -3,
+            expected: "1.200",
+        },
+        TestCase {
+            input: "0",
+            position: -1,
+            expected: "0.0",
+        },
+    ];
+    for cas in &cases {
+        let mut dec = FixedDecimal::from_str(cas.input).unwrap();
+        dec.trunc(cas.position);
+        assert_eq!(cas.expected, dec.to_string(), "{:?}", cas);
+    }
+}
+
+#[test]
+fn test_set_max_position() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub position: i16,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "1000.5",
+            position: 3,
+            expected: "000.5",
+        },
+        TestCase {
+            input: "-1024",
+            position: 2,
+            expected: "-24",
+        },
+        TestCase {
+            input: "1024",
+            position: 0,
+            expected: "0",
+        },
+        TestCase {
+            input: "0.125",
+            position: -1,
+            expected: "0.025",
+        },
+        TestCase {
+            input: "0.125",
+            position: -3,
+            expected: "0.000",
+        },
+        TestCase {
+            input: "12",
+            position: 3,
+            expected: "012",
+        },
+        TestCase {
+            input: "0",
+            position: 2,
+            expected: "00",
+        },
+        TestCase {
+            input: "12",
+            position: core::i16::MIN,
+            expected: "0",
+        },
+    ];
+    for cas in &cases {
+        let mut dec = FixedDecimal::from_str(cas.input).unwrap();
+        dec.set_max_position(cas.position);
+        assert_eq!(cas.expected, dec.to_string(), "{:?}", cas);
+    }
+}
+
+#[cfg(feature = "ryu")]
+#[test]
+fn test_float() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: f64,
+        pub precision: DoublePrecision,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: 1.0,
+            precision: DoublePrecision::Floating,
+            expected: "1",
+        },
+        TestCase {
+            input: 0.0,
+            precision: DoublePrecision::Floating,
+            expected: "0",
+        },
+        TestCase {
+            input: -0.0,
+            precision: DoublePrecision::Floating,
+            expected: "-0",
+        },
+        TestCase {
+            input: 1234.5678,
+            precision: DoublePrecision::Floating,
+            expected: "1234.5678",
+        },
+        TestCase {
+            input: 1e21,
+            precision: DoublePrecision::Floating,
+            expected: "1000000000000000000000",
+        },
+        TestCase {
+            input: 1234.5678,
+            precision: DoublePrecision::Magnitude(-2),
+            expected: "1234.57",
+        },
+        TestCase {
+            input: 1234.5678,
+            precision: DoublePrecision::Magnitude(2),
+            expected: "1200",
+        },
+        TestCase {
+            input: 0.125,
+            precision: DoublePrecision::Magnitude(-2),
+            expected: "0.12",
+        },
+        TestCase {
+            input: 1234.5678,
+            precision: DoublePrecision::SignificantDigits(6),
+            expected: "1234.57",
+        },
+        TestCase {
+            input: 0.5,
+            precision: DoublePrecision::SignificantDigits(3),
+            expected: "0.500",
+        },
+        TestCase {
+            input: 99.99,
+            precision: DoublePrecision::SignificantDigits(2),
+            expected: "100",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::new_from_f64(cas.input, cas.precision).unwrap();
+        assert_eq!(cas.expected, dec.to_string(), "{:?}", cas);
+    }
+
+    let dec = FixedDecimal::new_from_f64(5e-324, DoublePrecision::Floating).unwrap();
+    assert_eq!(format!("0.{}5", "0".repeat(323)), dec.to_string());
+
+    assert_eq!(
+        Error::Limit,
+        FixedDecimal::new_from_f64(f64::NAN, DoublePrecision::Floating).unwrap_err()
+    );
+    assert_eq!(
+        Error::Limit,
+        FixedDecimal::new_from_f64(f64::INFINITY, DoublePrecision::Floating).unwrap_err()
+    );
+}
@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Decimal numbers that may be infinite or NaN.
+
+use core::fmt;
+
+#[cfg(feature = "ryu")]
+use crate::decimal::DoublePrecision;
+use crate::decimal::FixedDecimal;
+#[cfg(feature = "ryu")]
+use crate::Error;
+use crate::Signum;
+
+/// A decimal number that, like an [`f64`], may also be positive or negative infinity or NaN
+/// ("not a number").
+///
+/// Finite values are represented by a [`FixedDecimal`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::{FixedDecimal, FloatingDecimal};
+///
+/// let dec = FloatingDecimal::from(FixedDecimal::from(42));
+/// assert!(dec.is_finite());
+/// assert_eq!("42", dec.to_string());
+///
+/// assert_eq!("-inf", FloatingDecimal::NegativeInfinity.to_string());
+/// assert_eq!("NaN", FloatingDecimal::NaN.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum FloatingDecimal {
+    /// A finite value.
+    Finite(FixedDecimal),
+    /// Positive infinity.
+    Infinity,
+    /// Negative infinity.
+    NegativeInfinity,
+    /// A value that is not a number, such as the result of dividing zero by zero.
+    NaN,
+}
+
+impl FloatingDecimal {
+    /// Returns whether the value is neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Self::Finite(_))
+    }
+
+    /// Returns the finite value, or `None` if the value is infinite or NaN.
+    pub fn as_finite(&self) -> Option<&FixedDecimal> {
+        match self {
+            Self::Finite(dec) => Some(dec),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Signum`] of the value, or `None` if the value is NaN, which has no sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FloatingDecimal, Signum};
+    ///
+    /// assert_eq!(Some(Signum::BelowZero), FloatingDecimal::NegativeInfinity.signum());
+    /// assert_eq!(None, FloatingDecimal::NaN.signum());
+    /// ```
+    pub fn signum(&self) -> Option<Signum> {
+        match self {
+            Self::Finite(dec) => Some(dec.signum()),
+            Self::Infinity => Some(Signum::AboveZero),
+            Self::NegativeInfinity => Some(Signum::BelowZero),
+            Self::NaN => None,
+        }
+    }
+
+    /// Constructs a [`FloatingDecimal`] from an [`f64`] with the given precision.
+    ///
+    /// Unlike [`FixedDecimal::new_from_f64`], infinite and NaN values are supported. Finite
+    /// values are converted with [`FixedDecimal::new_from_f64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{DoublePrecision, FloatingDecimal};
+    ///
+    /// let dec = FloatingDecimal::new_from_f64(1.5, DoublePrecision::Floating).unwrap();
+    /// assert_eq!("1.5", dec.to_string());
+    ///
+    /// let dec = FloatingDecimal::new_from_f64(f64::NEG_INFINITY, DoublePrecision::Floating).unwrap();
+    /// assert_eq!(FloatingDecimal::NegativeInfinity, dec);
+    /// ```
+    #[cfg(feature = "ryu")]
+    pub fn new_from_f64(float: f64, precision: DoublePrecision) -> Result<Self, Error> {
+        if float.is_nan() {
+            Ok(Self::NaN)
+        } else if float == f64::INFINITY {
+            Ok(Self::Infinity)
+        } else if float == f64::NEG_INFINITY {
+            Ok(Self::NegativeInfinity)
+        } else {
+            FixedDecimal::new_from_f64(float, precision).map(Self::Finite)
+        }
+    }
+}
+
+impl From<FixedDecimal> for FloatingDecimal {
+    fn from(dec: FixedDecimal) -> Self {
+        Self::Finite(dec)
+    }
+}
+
+impl writeable::Writeable for FloatingDecimal {
+    /// Renders a finite value like [`FixedDecimal`], and other values like [`f64`] does: as
+    /// `inf`, `-inf`, or `NaN`.
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Self::Finite(dec) => dec.write_to(sink),
+            Self::Infinity => sink.write_str("inf"),
+            Self::NegativeInfinity => sink.write_str("-inf"),
+            Self::NaN => sink.write_str("NaN"),
+        }
+    }
+
+    fn write_len(&self) -> writeable::LengthHint {
+        match self {
+            Self::Finite(dec) => dec.write_len(),
+            Self::Infinity | Self::NaN => writeable::LengthHint::Exact(3),
+            Self::NegativeInfinity => writeable::LengthHint::Exact(4),
+        }
+    }
+}
+
+/// Renders the `FloatingDecimal` according to the syntax documented in
+/// `FloatingDecimal::write_to`.
+impl fmt::Display for FloatingDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeable::Writeable::write_to(self, f)
+    }
+}
+
+#[cfg(feature = "ryu")]
+#[test]
+fn test_new_from_f64() {
+    let cases = [
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "inf"),
+        (f64::NEG_INFINITY, "-inf"),
+        (-0.0, "-0"),
+        (1e-3, "0.001"),
+    ];
+    for (float, expected) in cases.iter() {
+        let dec = FloatingDecimal::new_from_f64(*float, DoublePrecision::Floating).unwrap();
+        assert_eq!(*expected, dec.to_string(), "{:?}", float);
+        assert_eq!(
+            writeable::LengthHint::Exact(expected.len()),
+            writeable::Writeable::write_len(&dec)
+        );
+    }
+}
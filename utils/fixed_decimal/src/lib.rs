@@ -8,6 +8,9 @@
 //! appropriate for formatting and plural rule selection. It is optimized for operations involving
 //! the individual digits of a number.
 //!
+//! Infinite and NaN values, such as those of an [`f64`], can be represented with
+//! [`FloatingDecimal`].
+//!
 //! # Examples
 //!
 //! ```
@@ -41,11 +44,15 @@
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 pub mod decimal;
+pub mod floating;
 pub mod signum;
 mod uint_iterator;
 
+#[cfg(feature = "ryu")]
+pub use decimal::DoublePrecision;
 pub use decimal::FixedDecimal;
 use displaydoc::Display;
+pub use floating::FloatingDecimal;
 pub use signum::Signum;
 
 #[derive(Display, Debug, PartialEq)]
@@ -227,6 +227,23 @@ impl UnicodeSetBuilder {
         self.remove(start, end);
     }
 
+    /// Remove the range of characters, represented as u32, from the [`UnicodeSetBuilder`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSetBuilder;
+    /// let mut builder = UnicodeSetBuilder::new();
+    /// builder.add_range(&('A'..='Z'));
+    /// builder.remove_range_u32(&(0x41..=0x43));
+    /// let check = builder.build();
+    /// assert_eq!(check.iter_chars().next(), Some('D'));
+    /// ```
+    pub fn remove_range_u32(&mut self, range: &impl RangeBounds<u32>) {
+        let (start, end) = deconstruct_range(range);
+        self.remove(start, end);
+    }
+
     /// Remove the [`UnicodeSet`] from the [`UnicodeSetBuilder`]
     ///
     /// # Examples
@@ -293,6 +310,27 @@ impl UnicodeSetBuilder {
         self.remove(end, (char::MAX as u32) + 1);
     }
 
+    /// Retain the range of characters, represented as u32, located within the [`UnicodeSetBuilder`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSetBuilder;
+    /// let mut builder = UnicodeSetBuilder::new();
+    /// builder.add_range(&('A'..='Z'));
+    /// builder.retain_range_u32(&(0x41..=0x42));
+    /// let set = builder.build();
+    /// let mut check = set.iter_chars();
+    /// assert_eq!(check.next(), Some('A'));
+    /// assert_eq!(check.next(), Some('B'));
+    /// assert_eq!(check.next(), None);
+    /// ```
+    pub fn retain_range_u32(&mut self, range: &impl RangeBounds<u32>) {
+        let (start, end) = deconstruct_range(range);
+        self.remove(0, start);
+        self.remove(end, (char::MAX as u32) + 1);
+    }
+
     /// Retain the elements in the specified set within the [`UnicodeSetBuilder`]
     ///
     /// # Examples
@@ -429,6 +467,32 @@ impl UnicodeSetBuilder {
         self.complement_list(to_complement.iter().copied());
     }
 
+    /// Computes the complement of the builder within the range, so that the builder contains the
+    /// elements of the range that were not in the builder. Elements outside of the range are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSetBuilder;
+    /// let mut builder = UnicodeSetBuilder::new();
+    /// builder.add_range(&('A'..='Z'));
+    /// builder.add_range(&('a'..='z'));
+    /// builder.complement_in_range(&('\0'..='\x7F'));
+    /// let check = builder.build();
+    /// assert!(check.contains('0'));
+    /// assert!(!check.contains('A'));
+    /// assert!(!check.contains('\u{80}'));
+    /// ```
+    pub fn complement_in_range(&mut self, range: &impl RangeBounds<char>) {
+        let (start, end) = deconstruct_range(range);
+        self.remove(0, start);
+        self.remove(end, (char::MAX as u32) + 1);
+        if start < end {
+            self.complement_list([start, end].iter().copied());
+        }
+    }
+
     /// Complements the set in the builder, adding any elements in the set if not in the builder, and
     /// removing them otherwise.
     ///
@@ -791,6 +855,14 @@ mod tests {
         assert_eq!(builder.intervals, expected);
     }
 
+    #[test]
+    fn test_remove_range_u32() {
+        let mut builder = generate_tester(vec![0x41, 0x5A]);
+        builder.remove_range_u32(&(0x41..0x4C));
+        let expected = vec![0x4C, 0x5A];
+        assert_eq!(builder.intervals, expected);
+    }
+
     #[test]
     fn test_remove_set() {
         let mut builder = generate_tester(vec![0xA, 0x14, 0x28, 0x32, 70, 80]);
@@ -823,6 +895,14 @@ mod tests {
         assert!(builder.intervals.is_empty());
     }
 
+    #[test]
+    fn test_retain_range_u32() {
+        let mut builder = generate_tester(vec![0x41, 0x5A]);
+        builder.retain_range_u32(&(0x43..0x46));
+        let expected = vec![0x43, 0x46];
+        assert_eq!(builder.intervals, expected);
+    }
+
     #[test]
     fn test_retain_set() {
         let mut builder = generate_tester(vec![0xA, 0x14, 0x28, 0x32, 70, 80]);
@@ -902,6 +982,40 @@ mod tests {
         assert_eq!(builder.intervals, expected);
     }
 
+    #[test]
+    fn test_complement_in_range() {
+        let mut builder = generate_tester(vec![0x30, 0x3A, 0x46, 0x4C, 0x61, 0x7B]);
+        builder.complement_in_range(&('A'..='Z'));
+        let expected = vec![0x41, 0x46, 0x4C, 0x5B];
+        assert_eq!(builder.intervals, expected);
+    }
+
+    #[test]
+    fn test_complement_in_range_empty() {
+        let mut builder = generate_tester(vec![]);
+        builder.complement_in_range(&('A'..='Z'));
+        let expected = vec![0x41, 0x5B];
+        assert_eq!(builder.intervals, expected);
+
+        builder.complement_in_range(&('A'..='Z'));
+        assert!(builder.intervals.is_empty());
+    }
+
+    #[test]
+    fn test_complement_in_range_inverted() {
+        let mut builder = generate_tester(vec![0x41, 0x46]);
+        builder.complement_in_range(&('Z'..'A'));
+        assert!(builder.intervals.is_empty());
+    }
+
+    #[test]
+    fn test_complement_in_range_full() {
+        let mut builder = generate_tester(vec![0x41, 0x46]);
+        builder.complement_in_range(&(..));
+        let expected = vec![0x0, 0x41, 0x46, (char::MAX as u32) + 1];
+        assert_eq!(builder.intervals, expected);
+    }
+
     #[test]
     fn test_complement_set() {
         let mut builder = generate_tester(vec![0x43, 0x4E]);
@@ -31,6 +31,17 @@
 //! assert!(set.contains('A'));
 //! ```
 //!
+//! Small sets can also be compiled in as `const` or `static` items with the [`unicodeset!`] macro,
+//! without a data provider.
+//!
+//! ```
+//! use icu_uniset::{unicodeset, UnicodeSet};
+//!
+//! static UPPERCASE_ASCII: UnicodeSet = unicodeset![0x41, 0x5B];
+//!
+//! assert!(UPPERCASE_ASCII.contains('A'));
+//! ```
+//!
 //! ## Querying a `UnicodeSet`
 //!
 //! Currently, you can check if a character/range of characters exists in the [`UnicodeSet`], or iterate through the characters.
@@ -73,6 +84,11 @@ use icu_provider::DataError;
 pub use uniset::UnicodeSet;
 pub use utils::*;
 
+/// The little-endian representation of a code point in an inversion list, used by
+/// [`unicodeset!`].
+#[doc(hidden)]
+pub use zerovec::ule::PlainOldULE as InversionListULE;
+
 /// Custom Errors for [`UnicodeSet`].
 #[derive(Display, Debug)]
 pub enum UnicodeSetError {
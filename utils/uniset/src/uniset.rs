@@ -4,10 +4,15 @@
 
 #[cfg(feature = "serde")]
 use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
 use core::{char, ops::RangeBounds, ops::RangeInclusive};
 use icu_provider::yoke::{self, *};
-use zerovec::{ule::AsULE, ZeroVec};
+use zerovec::{
+    ule::{AsULE, PlainOldULE},
+    ZeroVec,
+};
 
 use super::UnicodeSetError;
 use crate::utils::{deconstruct_range, is_valid_zv};
@@ -21,6 +26,48 @@ const BMP_INV_LIST_SLICE: &[u32] = &[0x0, BMP_MAX + 1];
 /// Represents the inversion list for all of the code points in the Unicode range.
 const ALL_SLICE: &[u32] = &[0x0, (char::MAX as u32) + 1];
 
+/// Represents the inversion list for the ASCII code points.
+const ASCII_INV_LIST_SLICE: &[u32] = &[0x0, 0x80];
+
+/// Creates a [`UnicodeSet`] from an [inversion list](https://en.wikipedia.org/wiki/Inversion_list)
+/// of [`u32`] code points, which can be used in `const` and `static` items.
+///
+/// The inversion list is validated when the constant is evaluated; see
+/// [`UnicodeSet::from_inversion_list_const`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_uniset::{unicodeset, UnicodeSet};
+///
+/// // The code points of the Pattern_White_Space property.
+/// static PATTERN_WHITE_SPACE: UnicodeSet = unicodeset![
+///     0x0009, 0x000E, 0x0020, 0x0021, 0x0085, 0x0086, 0x200E, 0x2010, 0x2028, 0x202A,
+/// ];
+///
+/// assert!(PATTERN_WHITE_SPACE.contains(' '));
+/// assert!(PATTERN_WHITE_SPACE.contains('\u{2029}'));
+/// assert!(!PATTERN_WHITE_SPACE.contains('a'));
+/// assert_eq!(PATTERN_WHITE_SPACE.size(), 11);
+/// ```
+///
+/// An invalid inversion list fails to compile:
+///
+/// ```compile_fail
+/// use icu_uniset::{unicodeset, UnicodeSet};
+///
+/// static UNSORTED: UnicodeSet = unicodeset![0x44, 0x41];
+/// ```
+#[macro_export]
+macro_rules! unicodeset {
+    ($($code_point:expr),* $(,)?) => {{
+        const INV_LIST: &[$crate::InversionListULE<4>] = &[
+            $($crate::InversionListULE(($code_point as u32).to_le_bytes())),*
+        ];
+        $crate::UnicodeSet::from_inversion_list_const(INV_LIST)
+    }};
+}
+
 /// A membership wrapper for [`UnicodeSet`].
 ///
 /// Provides exposure to membership functions and constructors from serialized [`UnicodeSets`](UnicodeSet)
@@ -182,6 +229,56 @@ impl<'data> UnicodeSet<'data> {
         UnicodeSet::from_inversion_list(inv_list_zv)
     }
 
+    /// Returns a new [`UnicodeSet`] by borrowing an [inversion list](https://en.wikipedia.org/wiki/Inversion_list)
+    /// of code points in their little-endian representation, in a `const` context.
+    ///
+    /// This allows small sets to be compiled into a binary as `const` or `static` items, without
+    /// a data provider. Use the [`unicodeset!`](crate::unicodeset) macro to write the inversion
+    /// list as [`u32`] code points.
+    ///
+    /// The inversion list must be of even length, sorted ascending non-overlapping,
+    /// and within the bounds of `0x0 -> 0x10FFFF` inclusive, and end points being exclusive.
+    /// Otherwise, the evaluation of the constant fails at compile time, or this function panics
+    /// if it is called at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSet;
+    /// use zerovec::ule::PlainOldULE;
+    ///
+    /// static BMP: UnicodeSet = UnicodeSet::from_inversion_list_const(&[
+    ///     PlainOldULE(0x0u32.to_le_bytes()),
+    ///     PlainOldULE(0x10000u32.to_le_bytes()),
+    /// ]);
+    /// assert!(BMP.contains_u32(0xFFFF));
+    /// assert!(!BMP.contains_u32(0x10000));
+    /// ```
+    pub const fn from_inversion_list_const(inv_list: &'data [PlainOldULE<4>]) -> Self {
+        let mut valid = inv_list.len() % 2 == 0;
+        let mut size = 0;
+        let mut i = 0;
+        while i + 1 < inv_list.len() {
+            let start = u32::from_le_bytes(inv_list[i].0);
+            let end = u32::from_le_bytes(inv_list[i + 1].0);
+            if start >= end || (i > 0 && u32::from_le_bytes(inv_list[i - 1].0) >= start) {
+                valid = false;
+            }
+            size += (end - start) as usize;
+            i += 2;
+        }
+        if i > 0 && u32::from_le_bytes(inv_list[i - 1].0) > (char::MAX as u32) + 1 {
+            valid = false;
+        }
+        // `panic!` can't be used in a `const fn` yet, so an invalid inversion list is reported
+        // with an out-of-bounds index instead.
+        let _invalid_inversion_list = [0u8][!valid as usize];
+        Self {
+            inv_list: ZeroVec::Borrowed(inv_list),
+            size,
+        }
+    }
+
     /// Returns an owned inversion list representing the current [`UnicodeSet`]
     pub fn get_inversion_list(&self) -> Vec<u32> {
         let result: Vec<u32> = self.as_inversion_list().to_vec(); // Only crate public, to not leak impl
@@ -523,6 +620,105 @@ impl<'data> UnicodeSet<'data> {
                 .take_while(|&x| self.contains(x) == contained)
                 .count()
     }
+
+    /// Returns the byte index of the first character of `s` that is not in the set, or `None`
+    /// if all of its characters are in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSet;
+    /// let example_list = [0x61, 0x7B]; // a..=z
+    /// let example = UnicodeSet::from_inversion_list_slice(&example_list).unwrap();
+    /// assert_eq!(example.find_first_not_in("abc"), None);
+    /// assert_eq!(example.find_first_not_in("abc-def"), Some(3));
+    /// assert_eq!(example.find_first_not_in("übung"), Some(0));
+    /// ```
+    pub fn find_first_not_in(&self, s: &str) -> Option<usize> {
+        s.char_indices()
+            .find(|&(_, c)| !self.contains(c))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns whether all characters of `s` are in the set. This is the case for the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSet;
+    /// let example_list = [0x30, 0x3A, 0x41, 0x5B, 0x5F, 0x60, 0x61, 0x7B]; // [0-9A-Z_a-z]
+    /// let identifier_chars = UnicodeSet::from_inversion_list_slice(&example_list).unwrap();
+    /// assert!(identifier_chars.is_all_in("snake_case_1"));
+    /// assert!(!identifier_chars.is_all_in("kebab-case"));
+    /// assert!(identifier_chars.is_all_in(""));
+    /// ```
+    pub fn is_all_in(&self, s: &str) -> bool {
+        self.find_first_not_in(s).is_none()
+    }
+
+    /// Returns `s` with all leading and trailing characters that are in the set removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSet;
+    /// let example_list = [0x9, 0xE, 0x20, 0x21, 0x3000, 0x3001]; // ASCII and ideographic spaces
+    /// let spaces = UnicodeSet::from_inversion_list_slice(&example_list).unwrap();
+    /// assert_eq!(spaces.trim_matches("\u{3000} hello world\n"), "hello world");
+    /// assert_eq!(spaces.trim_matches("   "), "");
+    /// ```
+    pub fn trim_matches<'s>(&self, s: &'s str) -> &'s str {
+        s.trim_matches(|c| self.contains(c))
+    }
+
+    /// Returns a pattern for this [`UnicodeSet`] in the syntax of ICU4C's `UnicodeSet`, such as
+    /// `[\u0041-\u005A\u0061-\u007A]`.
+    ///
+    /// Every range is written with escaped code points, as `\uXXXX` for code points in the
+    /// Basic Multilingual Plane and `\UXXXXXXXX` otherwise, so that the pattern is the same for
+    /// equal sets. The sets of all code points and of the ASCII code points are written with
+    /// their property names, as `[:Any:]` and `[:ASCII:]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_uniset::UnicodeSet;
+    ///
+    /// let letters = UnicodeSet::from_inversion_list_slice(&[0x41, 0x5B, 0x61, 0x7B]).unwrap();
+    /// assert_eq!(letters.to_pattern_string(), r"[\u0041-\u005A\u0061-\u007A]");
+    ///
+    /// let emoji = UnicodeSet::from_inversion_list_slice(&[0x1F600, 0x1F601]).unwrap();
+    /// assert_eq!(emoji.to_pattern_string(), r"[\U0001F600]");
+    ///
+    /// assert_eq!(UnicodeSet::all().to_pattern_string(), "[:Any:]");
+    /// ```
+    pub fn to_pattern_string(&self) -> String {
+        if self.inv_list == ALL_SLICE {
+            return String::from("[:Any:]");
+        } else if self.inv_list == ASCII_INV_LIST_SLICE {
+            return String::from("[:ASCII:]");
+        }
+
+        fn write_code_point(pattern: &mut String, cp: u32) {
+            // Writing to a String cannot fail.
+            let _ = if cp <= BMP_MAX {
+                write!(pattern, "\\u{:04X}", cp)
+            } else {
+                write!(pattern, "\\U{:08X}", cp)
+            };
+        }
+
+        let mut pattern = String::from("[");
+        for range in self.iter_ranges() {
+            write_code_point(&mut pattern, *range.start());
+            if range.start() != range.end() {
+                pattern.push('-');
+                write_code_point(&mut pattern, *range.end());
+            }
+        }
+        pattern.push(']');
+        pattern
+    }
 }
 
 #[cfg(test)]
@@ -550,6 +746,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unicodeset_const() {
+        static SET: UnicodeSet = crate::unicodeset![0x41, 0x44, 0x61, 0x64, 0x10000, 0x10001];
+        let expected =
+            UnicodeSet::from_inversion_list_slice(&[0x41, 0x44, 0x61, 0x64, 0x10000, 0x10001])
+                .unwrap();
+        assert_eq!(SET, expected);
+        assert_eq!(SET.size(), 7);
+        assert!(SET.contains('B'));
+        assert!(!SET.contains('D'));
+
+        const EMPTY: UnicodeSet = crate::unicodeset![];
+        assert_eq!(EMPTY.size(), 0);
+        assert!(!EMPTY.contains('A'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unicodeset_const_unsorted() {
+        let _ = crate::unicodeset![0x41, 0x44, 0x42, 0x43];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unicodeset_const_odd_length() {
+        let _ = crate::unicodeset![0x41, 0x44, 0x61];
+    }
+
     // UnicodeSet membership functions
     #[test]
     fn test_unicodeset_contains_query() {
@@ -751,6 +975,32 @@ mod tests {
         assert_eq!(check.span_back("XYZABC", false), 6);
     }
 
+    #[test]
+    fn test_uniset_string_helpers_non_ascii() {
+        let greek = UnicodeSet::from_inversion_list_slice(&[0x370, 0x400]).unwrap();
+        assert_eq!(greek.find_first_not_in("αβγ-δ"), Some(6));
+        assert!(greek.is_all_in("αβγ"));
+        assert!(!greek.is_all_in("αβγ-δ"));
+        assert_eq!(greek.trim_matches("αβ-γ-δε"), "-γ-");
+    }
+
+    #[test]
+    fn test_uniset_to_pattern_string() {
+        let empty = UnicodeSet::from_inversion_list_slice(&[]).unwrap();
+        assert_eq!(empty.to_pattern_string(), "[]");
+        let ascii = UnicodeSet::from_inversion_list_slice(&[0x0, 0x80]).unwrap();
+        assert_eq!(ascii.to_pattern_string(), "[:ASCII:]");
+        assert_eq!(UnicodeSet::bmp().to_pattern_string(), r"[\u0000-\uFFFF]");
+        let mixed = UnicodeSet::from_inversion_list_slice(&[
+            0x20, 0x21, 0xFFFF, 0x10001, 0x10FFFF, 0x110000,
+        ])
+        .unwrap();
+        assert_eq!(
+            mixed.to_pattern_string(),
+            r"[\u0020\uFFFF-\U00010000\U0010FFFF]"
+        );
+    }
+
     #[test]
     fn test_uniset_to_inv_list() {
         let inv_list: Vec<u32> = vec![
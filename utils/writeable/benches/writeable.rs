@@ -45,15 +45,15 @@ fn overview_bench(c: &mut Criterion) {
             WriteableMessage {
                 message: black_box(SHORT_STR),
             }
-            .writeable_to_string();
+            .write_to_string();
             WriteableMessage {
                 message: black_box(MEDIUM_STR),
             }
-            .writeable_to_string();
+            .write_to_string();
             WriteableMessage {
                 message: black_box(LONG_STR),
             }
-            .writeable_to_string();
+            .write_to_string();
         });
     });
 
@@ -72,7 +72,7 @@ fn writeable_benches(c: &mut Criterion) {
             WriteableMessage {
                 message: black_box(SHORT_STR),
             }
-            .writeable_to_string()
+            .write_to_string()
         });
     });
     c.bench_function("writeable/to_string/medium", |b| {
@@ -80,7 +80,7 @@ fn writeable_benches(c: &mut Criterion) {
             WriteableMessage {
                 message: black_box(MEDIUM_STR),
             }
-            .writeable_to_string()
+            .write_to_string()
         });
     });
     c.bench_function("writeable/to_string/long", |b| {
@@ -88,14 +88,14 @@ fn writeable_benches(c: &mut Criterion) {
             WriteableMessage {
                 message: black_box(LONG_STR),
             }
-            .writeable_to_string()
+            .write_to_string()
         });
     });
 }
 
 #[cfg(feature = "bench")]
 fn writeable_dyn_benches(c: &mut Criterion) {
-    // Same as writeable_to_string, but casts to a dyn fmt::Write
+    // Same as write_to_string, but casts to a dyn fmt::Write
     fn writeable_dyn_to_string(w: &impl Writeable) -> String {
         let mut output = String::with_capacity(w.write_len().capacity());
         w.write_to(&mut output as &mut dyn fmt::Write)
@@ -29,5 +29,5 @@ fn main() {
     let writeable = WriteableMessage {
         message: "hello world",
     };
-    assert_eq!("hello world", writeable.writeable_to_string());
+    assert_eq!("hello world", writeable.write_to_string());
 }
@@ -15,9 +15,9 @@
 //! 1. More efficient, since the sink can pre-allocate bytes.
 //! 2. Smaller code, since the format machinery can be short-circuited.
 //!
-//! Types implementing Writeable have a defaulted writeable_to_string function.
+//! Types implementing Writeable have a defaulted write_to_string function.
 //! If desired, types implementing Writeable can manually implement ToString
-//! to wrap writeable_to_string.
+//! to wrap write_to_string.
 //!
 //! # Examples
 //!
@@ -71,6 +71,10 @@ pub enum LengthHint {
 
     /// An exact length hint. This value is expected to equal the actual length from write_to.
     Exact(usize),
+
+    /// A lower bound on the length. The actual length from write_to is expected to be greater
+    /// than or equal to this value.
+    AtLeast(usize),
 }
 
 impl LengthHint {
@@ -88,14 +92,14 @@ impl LengthHint {
     pub fn capacity(&self) -> usize {
         match self {
             Self::Undefined => 0,
-            Self::Exact(len) => *len,
+            Self::Exact(len) | Self::AtLeast(len) => *len,
         }
     }
 
     /// Returns whether the LengthHint indicates that the string is exactly 0 bytes long.
     pub fn is_zero(&self) -> bool {
         match self {
-            Self::Undefined => false,
+            Self::Undefined | Self::AtLeast(_) => false,
             Self::Exact(len) => *len == 0,
         }
     }
@@ -117,19 +121,26 @@ pub trait Writeable {
     /// but smaller and faster.
     ///
     /// Not intended to be overriden.
-    fn writeable_to_string(&self) -> String {
+    fn write_to_string(&self) -> String {
         let mut output = String::with_capacity(self.write_len().capacity());
         self.write_to(&mut output)
             .expect("impl Write for String is infallible");
         output
     }
+
+    /// Creates a new String with the data from this Writeable.
+    #[deprecated(since = "0.3.0", note = "use `write_to_string` instead")]
+    fn writeable_to_string(&self) -> String {
+        self.write_to_string()
+    }
 }
 
 /// Testing macro for types implementing Writeable. The first argument should be a string, and
 /// the second argument should be a `&dyn Writeable`.
 ///
-/// The macro tests for equality of both string content and string length. If your Writeable
-/// implementation returns an inexact string length, don't use this macro.
+/// The macro tests for equality of both string content and string length. A
+/// [`LengthHint::AtLeast`] is checked to be no greater than the string length. If your
+/// Writeable implementation returns any other inexact string length, don't use this macro.
 ///
 /// # Examples
 ///
@@ -158,9 +169,11 @@ macro_rules! assert_writeable_eq {
         {
             use $crate::Writeable;
             let writeable = $actual_writeable;
-            assert_eq!($expected_str, writeable.writeable_to_string());
-            if let $crate::LengthHint::Exact(len) = writeable.write_len() {
-                assert_eq!($expected_str.len(), len);
+            assert_eq!($expected_str, writeable.write_to_string());
+            match writeable.write_len() {
+                $crate::LengthHint::Exact(len) => assert_eq!($expected_str.len(), len),
+                $crate::LengthHint::AtLeast(len) => assert!(len <= $expected_str.len()),
+                $crate::LengthHint::Undefined => {}
             }
         }
     };
@@ -169,9 +182,11 @@ macro_rules! assert_writeable_eq {
         {
             use $crate::Writeable;
             let writeable = $actual_writeable;
-            assert_eq!($expected_str, writeable.writeable_to_string(), $($arg)+);
-            if let $crate::LengthHint::Exact(len) = writeable.write_len() {
-                assert_eq!($expected_str.len(), len, $($arg)+);
+            assert_eq!($expected_str, writeable.write_to_string(), $($arg)+);
+            match writeable.write_len() {
+                $crate::LengthHint::Exact(len) => assert_eq!($expected_str.len(), len, $($arg)+),
+                $crate::LengthHint::AtLeast(len) => assert!(len <= $expected_str.len(), $($arg)+),
+                $crate::LengthHint::Undefined => {}
             }
         }
     };
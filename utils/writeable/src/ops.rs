@@ -8,12 +8,17 @@ impl core::ops::Add<LengthHint> for LengthHint {
     type Output = Self;
 
     fn add(self, other: LengthHint) -> Self {
-        match self {
-            LengthHint::Undefined => LengthHint::Undefined,
-            LengthHint::Exact(len1) => match other {
-                LengthHint::Undefined => LengthHint::Undefined,
-                LengthHint::Exact(len2) => LengthHint::Exact(len1 + len2),
-            },
+        match (self, other) {
+            (LengthHint::Exact(len1), LengthHint::Exact(len2)) => LengthHint::Exact(len1 + len2),
+            (LengthHint::Exact(len1), LengthHint::AtLeast(len2))
+            | (LengthHint::AtLeast(len1), LengthHint::Exact(len2))
+            | (LengthHint::AtLeast(len1), LengthHint::AtLeast(len2)) => {
+                LengthHint::AtLeast(len1 + len2)
+            }
+            // An unknown length added to a lower bound is still bounded by it.
+            (LengthHint::AtLeast(len), LengthHint::Undefined)
+            | (LengthHint::Undefined, LengthHint::AtLeast(len)) => LengthHint::AtLeast(len),
+            (LengthHint::Undefined, _) | (_, LengthHint::Undefined) => LengthHint::Undefined,
         }
     }
 }
@@ -40,6 +45,7 @@ impl core::ops::Add<usize> for LengthHint {
         match self {
             LengthHint::Undefined => LengthHint::Undefined,
             LengthHint::Exact(len) => LengthHint::Exact(len + other),
+            LengthHint::AtLeast(len) => LengthHint::AtLeast(len + other),
         }
     }
 }
@@ -101,6 +107,31 @@ mod tests {
         assert_eq!(LengthHint::Undefined, len);
     }
 
+    #[test]
+    fn test_add_at_least() {
+        assert_eq!(LengthHint::AtLeast(5), LengthHint::AtLeast(3) + 2);
+        assert_eq!(
+            LengthHint::AtLeast(5),
+            LengthHint::AtLeast(3) + LengthHint::Exact(2)
+        );
+        assert_eq!(
+            LengthHint::AtLeast(5),
+            LengthHint::Exact(3) + LengthHint::AtLeast(2)
+        );
+        assert_eq!(
+            LengthHint::AtLeast(5),
+            LengthHint::AtLeast(3) + LengthHint::AtLeast(2)
+        );
+        assert_eq!(
+            LengthHint::AtLeast(3),
+            LengthHint::AtLeast(3) + LengthHint::Undefined
+        );
+        assert_eq!(
+            LengthHint::AtLeast(2),
+            LengthHint::Undefined + LengthHint::AtLeast(2)
+        );
+    }
+
     #[test]
     fn test_sum() {
         let lens = vec![
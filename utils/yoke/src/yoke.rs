@@ -532,6 +532,22 @@ fn test_clone() {
     assert_eq!(y4.get(), "foobar");
 }
 
+#[test]
+fn test_try_project() {
+    use alloc::borrow::Cow;
+    let y1 = Yoke::<Cow<'static, str>, Rc<String>>::attach_to_rc_cart(Rc::new("foo".to_string()));
+    let y2 = Yoke::<Cow<'static, str>, Rc<String>>::attach_to_rc_cart(Rc::new(String::new()));
+
+    // The error of the callback is bubbled up, and the result keeps the cart otherwise
+    let y1: Yoke<Cow<'static, str>, Rc<String>> = y1
+        .try_project(|s, _| if s.is_empty() { Err(()) } else { Ok(s) })
+        .unwrap();
+    assert_eq!(y1.get(), "foo");
+    let result: Result<Yoke<Cow<'static, str>, Rc<String>>, ()> =
+        y2.try_project(|s, _| if s.is_empty() { Err(()) } else { Ok(s) });
+    assert!(result.is_err());
+}
+
 impl<Y: for<'a> Yokeable<'a>, C> Yoke<Y, C> {
     /// Allows one to "project" a yoke to perform a transformation on the data, potentially
     /// looking at a subfield, and producing a new yoke. This will move cart, and the provided
@@ -700,6 +716,60 @@ impl<Y: for<'a> Yokeable<'a>, C> Yoke<Y, C> {
         }
     }
 
+    /// A version of [`Yoke::project`] that bubbles up an error from the callback function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use yoke::Yoke;
+    /// fn parse_number(y: Yoke<&'static str, Rc<[u8]>>) -> Result<Yoke<&'static str, Rc<[u8]>>, ()> {
+    ///     y.try_project(|s, _| s.split(' ').next().ok_or(()))
+    /// }
+    ///
+    /// let rc: Rc<[u8]> = Rc::from(&b"42 apples"[..]);
+    /// let y = Yoke::<&'static str, _>::attach_to_cart_badly(rc, |b| core::str::from_utf8(b).unwrap());
+    /// assert_eq!(*parse_number(y).unwrap().get(), "42");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_project<P, E>(
+        self,
+        f: for<'a> fn(
+            <Y as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> Result<<P as Yokeable<'a>>::Output, E>,
+    ) -> Result<Yoke<P, C>, E>
+    where
+        P: for<'a> Yokeable<'a>,
+    {
+        let p = f(self.yokeable.transform_owned(), PhantomData)?;
+        Ok(Yoke {
+            yokeable: unsafe { P::make(p) },
+            cart: self.cart,
+        })
+    }
+
+    /// A version of [`Yoke::project_cloned`] that bubbles up an error from the callback
+    /// function.
+    #[allow(clippy::type_complexity)]
+    pub fn try_project_cloned<'this, P, E>(
+        &'this self,
+        f: for<'a> fn(
+            &'this <Y as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> Result<<P as Yokeable<'a>>::Output, E>,
+    ) -> Result<Yoke<P, C>, E>
+    where
+        P: for<'a> Yokeable<'a>,
+        C: CloneableCart,
+    {
+        let p = f(self.get(), PhantomData)?;
+        Ok(Yoke {
+            yokeable: unsafe { P::make(p) },
+            cart: self.cart.clone(),
+        })
+    }
+
     /// A version of [`Yoke::project`] that takes a capture and bubbles up an error
     /// from the callback function.
     #[allow(clippy::type_complexity)]
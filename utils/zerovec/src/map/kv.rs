@@ -88,6 +88,7 @@ macro_rules! impl_sized_kv {
     };
 }
 
+impl_sized_kv!(u8);
 impl_sized_kv!(u16);
 impl_sized_kv!(u32);
 impl_sized_kv!(u64);
@@ -132,6 +132,38 @@ where
         self.values.get(index)
     }
 
+    /// Get the value associated with `key` with the lifetime of the buffer this map was
+    /// deserialized from, if it exists.
+    ///
+    /// Returns `None` if the map owns its values, such as after it has been mutated.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// // Example byte buffer representing the map { 1: "one" }
+    /// let BINCODE_BYTES: &[u8; 31] = &[
+    ///     4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+    ///     1, 0, 0, 0, 0, 0, 0, 0, 111, 110, 101
+    /// ];
+    ///
+    /// let mut zero_map: ZeroMap<u32, str> = bincode::deserialize(BINCODE_BYTES)
+    ///     .expect("Should deserialize successfully");
+    /// let one: &'static str = zero_map.get_borrowed(&1).expect("Value should be borrowed");
+    /// assert_eq!(one, "one");
+    /// assert_eq!(zero_map.get_borrowed(&2), None);
+    ///
+    /// zero_map.insert(&2, "two");
+    /// assert_eq!(zero_map.get_borrowed(&1), None);
+    /// assert_eq!(zero_map.get(&1), Some("one"));
+    /// ```
+    pub fn get_borrowed(&self, key: &K::NeedleType) -> Option<&'a V::GetType>
+    where
+        V::GetType: 'a,
+    {
+        let index = self.keys.binary_search(key).ok()?;
+        self.values.get_borrowed(index)
+    }
+
     /// Returns whether `key` is contained in this map
     ///
     /// ```rust
@@ -40,12 +40,12 @@ where
 }
 
 /// Modified example from https://serde.rs/deserialize-map.html
-struct ZeroMapMapVisitor<K: ?Sized, V: ?Sized> {
+struct ZeroMapMapVisitor<'a, K: ?Sized, V: ?Sized> {
     #[allow(clippy::type_complexity)] // it's a marker type, complexity doesn't matter
-    marker: PhantomData<fn() -> (Box<K>, Box<V>)>,
+    marker: PhantomData<fn() -> (&'a (), Box<K>, Box<V>)>,
 }
 
-impl<K: ?Sized, V: ?Sized> ZeroMapMapVisitor<K, V> {
+impl<'a, K: ?Sized, V: ?Sized> ZeroMapMapVisitor<'a, K, V> {
     fn new() -> Self {
         ZeroMapMapVisitor {
             marker: PhantomData,
@@ -53,15 +53,15 @@ impl<K: ?Sized, V: ?Sized> ZeroMapMapVisitor<K, V> {
     }
 }
 
-impl<'de, K: ?Sized, V: ?Sized> Visitor<'de> for ZeroMapMapVisitor<K, V>
+impl<'a, 'de, K: ?Sized, V: ?Sized> Visitor<'de> for ZeroMapMapVisitor<'a, K, V>
 where
     K: Ord,
-    K: ZeroMapKV<'de>,
-    V: ZeroMapKV<'de>,
+    K: ZeroMapKV<'a>,
+    V: ZeroMapKV<'a>,
     K::OwnedType: Deserialize<'de>,
     V::OwnedType: Deserialize<'de>,
 {
-    type Value = ZeroMap<'de, K, V>;
+    type Value = ZeroMap<'a, K, V>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a map produced by ZeroMap")
@@ -94,15 +94,16 @@ where
 }
 
 /// This impl can be made available by enabling the optional `serde` feature of the `zerovec` crate
-impl<'de, K: ?Sized, V: ?Sized> Deserialize<'de> for ZeroMap<'de, K, V>
+impl<'de, 'a, K: ?Sized, V: ?Sized> Deserialize<'de> for ZeroMap<'a, K, V>
 where
     K: Ord,
     K::Container: Deserialize<'de>,
     V::Container: Deserialize<'de>,
-    K: ZeroMapKV<'de>,
-    V: ZeroMapKV<'de>,
+    K: ZeroMapKV<'a>,
+    V: ZeroMapKV<'a>,
     K::OwnedType: Deserialize<'de>,
     V::OwnedType: Deserialize<'de>,
+    'de: 'a,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -26,6 +26,11 @@ pub trait ZeroVecLike<'a, T: ?Sized> {
     fn binary_search(&self, k: &Self::NeedleType) -> Result<usize, usize>;
     /// Get element at `index`
     fn get(&self, index: usize) -> Option<&Self::GetType>;
+    /// Get element at `index` with the lifetime of the borrowed buffer, returning `None` if
+    /// `index` is out of bounds or if the vector owns its data
+    fn get_borrowed(&self, index: usize) -> Option<&'a Self::GetType>
+    where
+        Self::GetType: 'a;
     /// Insert an element at `index`
     fn insert(&mut self, index: usize, value: &T);
     /// Remove the element at `index` (panicking if nonexistant)
@@ -65,6 +70,15 @@ where
     fn get(&self, index: usize) -> Option<&T::ULE> {
         self.get_ule_ref(index)
     }
+    fn get_borrowed(&self, index: usize) -> Option<&'a T::ULE>
+    where
+        T::ULE: 'a,
+    {
+        match self {
+            ZeroVec::Borrowed(slice) => slice.get(index),
+            ZeroVec::Owned(_) => None,
+        }
+    }
     fn insert(&mut self, index: usize, value: &T) {
         self.to_mut().insert(index, value.as_unaligned())
     }
@@ -115,6 +129,12 @@ where
     fn get(&self, index: usize) -> Option<&T> {
         self.get(index)
     }
+    fn get_borrowed(&self, index: usize) -> Option<&'a T>
+    where
+        T: 'a,
+    {
+        self.get_borrowed(index)
+    }
     fn insert(&mut self, index: usize, value: &T) {
         self.make_mut().insert(index, value)
     }
@@ -105,9 +105,16 @@ pub use ule::VarZeroVecULE;
 /// for more information.
 ///
 /// [`ule`]: crate::ule
-#[derive(Clone)]
 pub struct VarZeroVec<'a, T: ?Sized>(VarZeroVecInner<'a, T>);
 
+// #[derive()] won't work here since we do not want it to be
+// bound on T: Clone
+impl<'a, T: ?Sized> Clone for VarZeroVec<'a, T> {
+    fn clone(&self) -> Self {
+        VarZeroVec(self.0.clone())
+    }
+}
+
 /// Implementation details of VarZeroVec
 ///
 /// Should not be made public.
@@ -128,7 +135,6 @@ pub struct VarZeroVec<'a, T: ?Sized>(VarZeroVecInner<'a, T>);
 /// index of the thing in `things`.
 ///
 /// The actual implementation details of this can be found in the `components` module
-#[derive(Clone)]
 enum VarZeroVecInner<'a, T: ?Sized> {
     Owned(VarZeroVecOwned<T>),
     /// This is *basically* an `&'a [u8]` to a zero copy buffer, but split out into
@@ -138,6 +144,15 @@ enum VarZeroVecInner<'a, T: ?Sized> {
     Borrowed(SliceComponents<'a, T>),
 }
 
+impl<'a, T: ?Sized> Clone for VarZeroVecInner<'a, T> {
+    fn clone(&self) -> Self {
+        match *self {
+            VarZeroVecInner::Owned(ref o) => VarZeroVecInner::Owned(o.clone()),
+            VarZeroVecInner::Borrowed(b) => VarZeroVecInner::Borrowed(b),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum VarZeroVecError<E> {
     FormatError,
@@ -318,6 +333,15 @@ impl<'a, T: VarULE + ?Sized> VarZeroVec<'a, T> {
         self.get_components().get(idx)
     }
 
+    /// Get one of VarZeroVec's elements with the lifetime of the buffer it was parsed from,
+    /// returning None if the index is out of bounds or if the VarZeroVec owns its data.
+    pub(crate) fn get_borrowed(&self, idx: usize) -> Option<&'a T> {
+        match self.0 {
+            VarZeroVecInner::Borrowed(components) => components.get(idx),
+            VarZeroVecInner::Owned(_) => None,
+        }
+    }
+
     /// Convert this into a mutable vector of the owned `T` type, cloning if necessary.
     ///
     ///
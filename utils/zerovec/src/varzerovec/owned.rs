@@ -12,13 +12,23 @@ use core::ops::Range;
 use core::ptr;
 use core::slice;
 
-#[derive(Clone)]
 pub struct VarZeroVecOwned<T: ?Sized> {
     marker: PhantomData<Box<T>>,
     // safety invariant: must parse into a valid SliceComponents
     entire_slice: Vec<u8>,
 }
 
+// #[derive()] won't work here since we do not want it to be
+// bound on T: Clone
+impl<T: ?Sized> Clone for VarZeroVecOwned<T> {
+    fn clone(&self) -> Self {
+        VarZeroVecOwned {
+            marker: PhantomData,
+            entire_slice: self.entire_slice.clone(),
+        }
+    }
+}
+
 // The effect of a shift on the indices in the varzerovec.
 #[derive(PartialEq)]
 enum ShiftType {